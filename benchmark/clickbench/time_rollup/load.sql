@@ -0,0 +1,7 @@
+COPY INTO hits
+FROM 's3://repo.databend.rs/hits_p/' CONNECTION =(REGION = 'us-east-2') PATTERN = '.*[.]tsv' FILE_FORMAT =(
+        type = 'TSV' field_delimiter = '\t' record_delimiter = '\n' skip_header = 1
+    );
+-- sort the blocks by EventTime, so that the time rollups can group by ranges.
+ALTER TABLE hits RECLUSTER FINAL;
+ANALYZE TABLE hits;
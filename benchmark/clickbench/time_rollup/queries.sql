@@ -0,0 +1,6 @@
+set enable_streaming_time_rollup = 0;SELECT DATE_TRUNC('minute', EventTime) AS M, COUNT(*) AS PageViews FROM hits GROUP BY DATE_TRUNC('minute', EventTime) ORDER BY M LIMIT 10;
+set enable_streaming_time_rollup = 1;SELECT DATE_TRUNC('minute', EventTime) AS M, COUNT(*) AS PageViews FROM hits GROUP BY DATE_TRUNC('minute', EventTime) ORDER BY M LIMIT 10;
+set enable_streaming_time_rollup = 0;SELECT DATE_TRUNC('second', EventTime) AS S, COUNT(*), SUM(IsRefresh), AVG(ResolutionWidth) FROM hits GROUP BY DATE_TRUNC('second', EventTime) ORDER BY S LIMIT 10;
+set enable_streaming_time_rollup = 1;SELECT DATE_TRUNC('second', EventTime) AS S, COUNT(*), SUM(IsRefresh), AVG(ResolutionWidth) FROM hits GROUP BY DATE_TRUNC('second', EventTime) ORDER BY S LIMIT 10;
+set enable_streaming_time_rollup = 0;SELECT DATE_TRUNC('hour', EventTime) AS H, COUNT(DISTINCT UserID), MAX(ResolutionWidth) FROM hits WHERE CounterID = 62 GROUP BY DATE_TRUNC('hour', EventTime) ORDER BY H;
+set enable_streaming_time_rollup = 1;SELECT DATE_TRUNC('hour', EventTime) AS H, COUNT(DISTINCT UserID), MAX(ResolutionWidth) FROM hits WHERE CounterID = 62 GROUP BY DATE_TRUNC('hour', EventTime) ORDER BY H;
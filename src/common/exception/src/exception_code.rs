@@ -149,6 +149,8 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    ColumnReferencedByInvertedIndex(1122),
+    InvalidBucketSpec(1123),
 
     // Data Related Errors
 
@@ -214,6 +216,10 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    TableTimeTravelBeforeCreation(2017),
+    TableHistoricalDataPurged(2018),
+    UnknownTableSnapshot(2019),
+    DuplicatedCommit(2020),
 
     // User api error codes.
     UnknownUser(2201),
@@ -225,6 +231,20 @@ build_exceptions! {
     NetworkPolicyAlreadyExists(2208),
     IllegalNetworkPolicy(2209),
     NetworkPolicyIsUsedByUser(2210),
+    UnknownPasswordPolicy(2211),
+    PasswordPolicyAlreadyExists(2212),
+    IllegalPasswordPolicy(2213),
+    PasswordPolicyIsUsedByUser(2214),
+    InvalidPassword(2215),
+    PasswordMustBeChanged(2216),
+    UnknownRowAccessPolicy(2217),
+    RowAccessPolicyAlreadyExists(2218),
+    IllegalRowAccessPolicy(2219),
+    ColumnIsUsedByRowAccessPolicy(2220),
+    UnknownSettingsProfile(2221),
+    SettingsProfileAlreadyExists(2222),
+    IllegalSettingsProfile(2223),
+    SettingsProfileIsInUse(2224),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -278,6 +298,16 @@ build_exceptions! {
     IllegalConnection(2511),
     ConnectionAlreadyExists(2512),
 
+    // Stage and file format dependency error codes.
+    StageHasDependents(2513),
+    FileFormatHasDependents(2514),
+
+    // External volume error codes.
+    UnknownExternalVolume(2515),
+    IllegalExternalVolume(2516),
+    ExternalVolumeAlreadyExists(2517),
+    ExternalVolumeHasDependents(2518),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
@@ -291,6 +321,7 @@ build_exceptions! {
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
     UnsupportedEngineParams(2703),
+    DatabaseHasDependents(2704),
 
     // Share error codes.
     ShareAlreadyExists(2705),
@@ -321,6 +352,7 @@ build_exceptions! {
     StreamAlreadyExists(2732),
     IllegalStream(2733),
     StreamVersionMismatched(2734),
+    HistoryProtectedByStream(2735),
 
     // Variable error codes.
     UnknownVariable(2801),
@@ -351,4 +383,10 @@ build_exceptions! {
 build_exceptions! {
     // A task that already stopped and can not stopped twice.
     AlreadyStopped(5002),
+
+    // Http session token errors.
+    SessionTokenExpired(5100),
+    RefreshTokenExpired(5101),
+    SessionTokenRevoked(5102),
+    InvalidSessionToken(5103),
 }
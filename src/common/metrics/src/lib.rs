@@ -48,6 +48,7 @@ pub use registry::reset_global_prometheus_registry;
 
 pub use crate::metrics::cache;
 pub use crate::metrics::cluster;
+pub use crate::metrics::function;
 /// Metrics.
 pub use crate::metrics::http;
 pub use crate::metrics::interpreter;
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use lazy_static::lazy_static;
+use prometheus_client::encoding::EncodeLabelSet;
+
+use crate::register_counter_family;
+use crate::Counter;
+use crate::Family;
+
+#[derive(Clone, Debug, EncodeLabelSet, Hash, PartialEq, Eq)]
+struct FunctionLabels {
+    function_name: String,
+}
+
+lazy_static! {
+    static ref FUNCTION_RESULT_CACHE_HIT_COUNT: Family<FunctionLabels, Counter> =
+        register_counter_family("function_result_cache_hit_count");
+    static ref FUNCTION_RESULT_CACHE_MISS_COUNT: Family<FunctionLabels, Counter> =
+        register_counter_family("function_result_cache_miss_count");
+}
+
+/// Rows of the function calls whose results are served by the function result cache.
+pub fn metrics_inc_function_result_cache_hit_count(c: u64, function_name: &str) {
+    FUNCTION_RESULT_CACHE_HIT_COUNT
+        .get_or_create(&FunctionLabels {
+            function_name: function_name.to_string(),
+        })
+        .inc_by(c);
+}
+
+/// Rows of the function calls evaluated while the function result cache is enabled.
+pub fn metrics_inc_function_result_cache_miss_count(c: u64, function_name: &str) {
+    FUNCTION_RESULT_CACHE_MISS_COUNT
+        .get_or_create(&FunctionLabels {
+            function_name: function_name.to_string(),
+        })
+        .inc_by(c);
+}
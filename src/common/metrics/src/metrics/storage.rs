@@ -100,6 +100,10 @@ static ref MERGE_INTO_MATCHED_OPERATION_MILLISECONDS: Histogram =
     static ref COMMIT_COPIED_FILES: Counter = register_counter("fuse_commit_copied_files");
     static ref COMMIT_MILLISECONDS: Counter = register_counter("fuse_commit_milliseconds");
     static ref COMMIT_ABORTS: Counter = register_counter("fuse_commit_aborts");
+    static ref COMMIT_LAST_SNAPSHOT_HINT_RETRY: Counter =
+        register_counter("fuse_commit_last_snapshot_hint_retry");
+    static ref COMMIT_LAST_SNAPSHOT_HINT_FAILURES: Counter =
+        register_counter("fuse_commit_last_snapshot_hint_failures");
     static ref REMOTE_IO_SEEKS: Counter = register_counter("fuse_remote_io_seeks");
     static ref REMOTE_IO_SEEKS_AFTER_MERGED: Counter =
         register_counter("fuse_remote_io_seeks_after_merged");
@@ -107,6 +111,11 @@ static ref MERGE_INTO_MATCHED_OPERATION_MILLISECONDS: Histogram =
     static ref REMOTE_IO_READ_BYTES_AFTER_MERGED: Counter =
         register_counter("fuse_remote_io_read_bytes_after_merged");
     static ref REMOTE_IO_READ_PARTS: Counter = register_counter("fuse_remote_io_read_parts");
+    static ref REMOTE_IO_PREFETCH_PARTS: Counter =
+        register_counter("fuse_remote_io_prefetch_parts");
+    static ref REMOTE_IO_PREFETCH_HITS: Counter = register_counter("fuse_remote_io_prefetch_hits");
+    static ref REMOTE_IO_PREFETCH_MISSES: Counter =
+        register_counter("fuse_remote_io_prefetch_misses");
     static ref REMOTE_IO_READ_MILLISECONDS: Histogram =
         register_histogram_in_milliseconds("fuse_remote_io_read_milliseconds");
     static ref REMOTE_IO_DESERIALIZE_MILLISECONDS: Histogram =
@@ -397,6 +406,14 @@ pub fn metrics_inc_commit_aborts() {
     COMMIT_ABORTS.inc();
 }
 
+pub fn metrics_inc_commit_last_snapshot_hint_retry() {
+    COMMIT_LAST_SNAPSHOT_HINT_RETRY.inc();
+}
+
+pub fn metrics_inc_commit_last_snapshot_hint_failures() {
+    COMMIT_LAST_SNAPSHOT_HINT_FAILURES.inc();
+}
+
 pub fn metrics_inc_remote_io_seeks(c: u64) {
     REMOTE_IO_SEEKS.inc_by(c);
 }
@@ -417,6 +434,20 @@ pub fn metrics_inc_remote_io_read_parts(c: u64) {
     REMOTE_IO_READ_PARTS.inc_by(c);
 }
 
+pub fn metrics_inc_remote_io_prefetch_parts(c: u64) {
+    REMOTE_IO_PREFETCH_PARTS.inc_by(c);
+}
+
+/// Counts the prefetched partitions that were ready when the decoder asked for them.
+pub fn metrics_inc_remote_io_prefetch_hits(c: u64) {
+    REMOTE_IO_PREFETCH_HITS.inc_by(c);
+}
+
+/// Counts the prefetched partitions that were still being read when the decoder asked for them.
+pub fn metrics_inc_remote_io_prefetch_misses(c: u64) {
+    REMOTE_IO_PREFETCH_MISSES.inc_by(c);
+}
+
 pub fn metrics_inc_remote_io_read_milliseconds(c: u64) {
     REMOTE_IO_READ_MILLISECONDS.observe(c as f64);
 }
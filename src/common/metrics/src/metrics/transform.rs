@@ -52,6 +52,10 @@ lazy_static! {
     static ref EXCHANGE_WRITE_BYTES: Counter = register_counter("transform_exchange_write_bytes");
     static ref EXCHANGE_READ_COUNT: Counter = register_counter("transform_exchange_read_count");
     static ref EXCHANGE_READ_BYTES: Counter = register_counter("transform_exchange_read_bytes");
+    static ref EXCHANGE_SKEWED_JOIN_COUNT: Counter =
+        register_counter("transform_exchange_skewed_join_count");
+    static ref EXCHANGE_HOT_KEY_COUNT: Counter =
+        register_counter("transform_exchange_hot_key_count");
 }
 
 pub fn metrics_inc_aggregate_partial_spill_count() {
@@ -148,3 +152,11 @@ pub fn metrics_inc_exchange_read_count(v: usize) {
 pub fn metrics_inc_exchange_read_bytes(c: usize) {
     EXCHANGE_READ_BYTES.inc_by(c as u64);
 }
+
+pub fn metrics_inc_exchange_skewed_join_count() {
+    EXCHANGE_SKEWED_JOIN_COUNT.inc();
+}
+
+pub fn metrics_inc_exchange_hot_key_count(c: usize) {
+    EXCHANGE_HOT_KEY_COUNT.inc_by(c as u64);
+}
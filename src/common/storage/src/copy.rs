@@ -20,10 +20,21 @@ use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::StageFileInfo;
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct CopyStatus {
     /// Key is file path.
     pub files: DashMap<String, FileStatus>,
+    /// Files to copy, key is file path, value is file size.
+    #[serde(default)]
+    pub files_to_copy: DashMap<String, u64>,
+    /// Files not copied since they have been copied before, key is file path, value is file size.
+    #[serde(default)]
+    pub duplicated_files: DashMap<String, u64>,
+    /// Files written by unloading, key is file path.
+    #[serde(default)]
+    pub unloaded_files: DashMap<String, UnloadedFileInfo>,
 }
 
 impl CopyStatus {
@@ -42,13 +53,51 @@ impl CopyStatus {
         for (k, v) in other.files.into_iter() {
             self.add_chunk(&k, v);
         }
+        for (k, v) in other.files_to_copy.into_iter() {
+            self.files_to_copy.insert(k, v);
+        }
+        for (k, v) in other.duplicated_files.into_iter() {
+            self.duplicated_files.insert(k, v);
+        }
+        for (k, v) in other.unloaded_files.into_iter() {
+            self.unloaded_files.insert(k, v);
+        }
+    }
+
+    pub fn add_files_to_copy(&self, files: &[StageFileInfo]) {
+        for file in files {
+            self.files_to_copy.insert(file.path.clone(), file.size);
+        }
+    }
+
+    pub fn add_duplicated_files(&self, files: &[StageFileInfo]) {
+        for file in files {
+            self.duplicated_files.insert(file.path.clone(), file.size);
+        }
     }
+
+    pub fn add_unloaded_file(&self, file_path: &str, num_rows: usize, size: usize) {
+        self.unloaded_files
+            .insert(file_path.to_string(), UnloadedFileInfo { num_rows, size });
+    }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct UnloadedFileInfo {
+    pub num_rows: usize,
+    pub size: usize,
 }
 
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct FileStatus {
     pub num_rows_loaded: usize,
     pub error: Option<FileErrorsInfo>,
+    /// Rows parsed but not loaded, since the file is skipped.
+    #[serde(default)]
+    pub num_rows_skipped: usize,
+    /// All the errors of the file, only kept when validating the files.
+    #[serde(default)]
+    pub errors: Vec<FileErrorInfo>,
 }
 
 impl FileStatus {
@@ -69,8 +118,19 @@ impl FileStatus {
         };
     }
 
-    fn merge(&mut self, other: FileStatus) {
+    /// Adds the error, and keeps it besides the first error of the file.
+    pub fn add_and_keep_error(&mut self, error: FileParseError, line: usize) {
+        self.errors.push(FileErrorInfo {
+            error: error.clone(),
+            line,
+        });
+        self.add_error(error, line);
+    }
+
+    pub fn merge(&mut self, other: FileStatus) {
         self.num_rows_loaded += other.num_rows_loaded;
+        self.num_rows_skipped += other.num_rows_skipped;
+        self.errors.extend(other.errors);
         match (&mut self.error, other.error) {
             (None, Some(e)) => self.error = Some(e),
             (Some(e1), Some(e2)) => e1.merge(e2),
@@ -139,6 +199,15 @@ pub enum FileParseError {
 }
 
 impl FileParseError {
+    pub fn column_name(&self) -> Option<&str> {
+        match self {
+            FileParseError::ColumnDecodeError { column_name, .. }
+            | FileParseError::ColumnMissingError { column_name, .. }
+            | FileParseError::ColumnDataNotDrained { column_name, .. } => Some(column_name),
+            _ => None,
+        }
+    }
+
     pub fn to_error_code(&self, mode: &OnErrorMode, file_path: &str, line: usize) -> ErrorCode {
         let pos: String = format!("at file '{}', line {}", file_path, line);
         let message = match mode {
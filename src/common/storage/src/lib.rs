@@ -71,6 +71,7 @@ mod statistics;
 pub use copy::CopyStatus;
 pub use copy::FileParseError;
 pub use copy::FileStatus;
+pub use copy::UnloadedFileInfo;
 pub use merge::MergeStatus;
 pub use statistics::Datum;
 pub use statistics::F64;
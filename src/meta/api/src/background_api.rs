@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_meta_app::background::AcquireBackgroundJobLeaseReply;
+use common_meta_app::background::AcquireBackgroundJobLeaseReq;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundTaskInfo;
 use common_meta_app::background::CreateBackgroundJobReply;
@@ -24,6 +26,7 @@ use common_meta_app::background::GetBackgroundTaskReply;
 use common_meta_app::background::GetBackgroundTaskReq;
 use common_meta_app::background::ListBackgroundJobsReq;
 use common_meta_app::background::ListBackgroundTasksReq;
+use common_meta_app::background::UpdateBackgroundJobMessageReq;
 use common_meta_app::background::UpdateBackgroundJobParamsReq;
 use common_meta_app::background::UpdateBackgroundJobReply;
 use common_meta_app::background::UpdateBackgroundJobStatusReq;
@@ -54,6 +57,17 @@ pub trait BackgroundApi: Send + Sync {
         req: UpdateBackgroundJobParamsReq,
     ) -> Result<UpdateBackgroundJobReply, KVAppError>;
 
+    async fn update_background_job_message(
+        &self,
+        req: UpdateBackgroundJobMessageReq,
+    ) -> Result<UpdateBackgroundJobReply, KVAppError>;
+
+    // Acquire or renew the lease of a background job, the lease expires after `req.lease`.
+    async fn acquire_background_job_lease(
+        &self,
+        req: AcquireBackgroundJobLeaseReq,
+    ) -> Result<AcquireBackgroundJobLeaseReply, KVAppError>;
+
     async fn get_background_job(
         &self,
         req: GetBackgroundJobReq,
@@ -18,9 +18,12 @@ use chrono::Utc;
 use common_meta_app::app_error::AppError;
 use common_meta_app::app_error::BackgroundJobAlreadyExists;
 use common_meta_app::app_error::UnknownBackgroundJob;
+use common_meta_app::background::AcquireBackgroundJobLeaseReply;
+use common_meta_app::background::AcquireBackgroundJobLeaseReq;
 use common_meta_app::background::BackgroundJobId;
 use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobLeaseIdent;
 use common_meta_app::background::BackgroundTaskIdent;
 use common_meta_app::background::BackgroundTaskInfo;
 use common_meta_app::background::CreateBackgroundJobReply;
@@ -33,6 +36,7 @@ use common_meta_app::background::GetBackgroundTaskReply;
 use common_meta_app::background::GetBackgroundTaskReq;
 use common_meta_app::background::ListBackgroundJobsReq;
 use common_meta_app::background::ListBackgroundTasksReq;
+use common_meta_app::background::UpdateBackgroundJobMessageReq;
 use common_meta_app::background::UpdateBackgroundJobParamsReq;
 use common_meta_app::background::UpdateBackgroundJobReply;
 use common_meta_app::background::UpdateBackgroundJobStatusReq;
@@ -187,6 +191,77 @@ impl<KV: kvapi::KVApi<Error = MetaError>> BackgroundApi for KV {
         .await
     }
 
+    #[minitrace::trace]
+    async fn update_background_job_message(
+        &self,
+        req: UpdateBackgroundJobMessageReq,
+    ) -> Result<UpdateBackgroundJobReply, KVAppError> {
+        let name = &req.job_name;
+        update_background_job(self, name, |info| {
+            if info.message == req.message {
+                return false;
+            }
+            info.message = req.message;
+            info.last_updated = Some(Utc::now());
+            true
+        })
+        .await
+    }
+
+    #[minitrace::trace]
+    async fn acquire_background_job_lease(
+        &self,
+        req: AcquireBackgroundJobLeaseReq,
+    ) -> Result<AcquireBackgroundJobLeaseReply, KVAppError> {
+        debug!(req = as_debug!(&req); "BackgroundApi: {}", func_name!());
+        let lease_key = BackgroundJobLeaseIdent::from(&req.job_name);
+        let key = lease_key.to_string_key();
+
+        // The lease can be taken if nobody holds it, or renewed by its current holder.
+        let seq = match self.get_kv(&key).await? {
+            None => 0,
+            Some(seq_v) => {
+                let holder = String::from_utf8_lossy(&seq_v.data).to_string();
+                if holder != req.holder {
+                    return Ok(AcquireBackgroundJobLeaseReply {
+                        acquired: false,
+                        holder,
+                    });
+                }
+                seq_v.seq
+            }
+        };
+
+        let expire_at = Utc::now().timestamp() as u64 + req.lease.as_secs();
+        let resp = self
+            .upsert_kv(UpsertKVReq::new(
+                key.as_str(),
+                MatchSeq::Exact(seq),
+                Operation::Update(req.holder.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            ))
+            .await?;
+
+        if resp.is_changed() {
+            return Ok(AcquireBackgroundJobLeaseReply {
+                acquired: true,
+                holder: req.holder,
+            });
+        }
+
+        // Another node took the lease concurrently.
+        let holder = match resp.result {
+            Some(seq_v) => String::from_utf8_lossy(&seq_v.data).to_string(),
+            None => "".to_string(),
+        };
+        Ok(AcquireBackgroundJobLeaseReply {
+            acquired: false,
+            holder,
+        })
+    }
+
     #[minitrace::trace]
     async fn get_background_job(
         &self,
@@ -14,6 +14,7 @@
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_meta_app::background::AcquireBackgroundJobLeaseReq;
 use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobParams;
@@ -31,6 +32,7 @@ use common_meta_app::background::GetBackgroundTaskReq;
 use common_meta_app::background::ListBackgroundJobsReq;
 use common_meta_app::background::ListBackgroundTasksReq;
 use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobMessageReq;
 use common_meta_app::background::UpdateBackgroundJobParamsReq;
 use common_meta_app::background::UpdateBackgroundJobStatusReq;
 use common_meta_app::background::UpdateBackgroundTaskReq;
@@ -331,6 +333,48 @@ impl BackgroundApiTestSuite {
             )
         }
 
+        info!("--- update a background job message");
+        {
+            let req = UpdateBackgroundJobMessageReq {
+                job_name: job_ident.clone(),
+                message: "compacted 1 table(s)".to_string(),
+            };
+
+            mt.update_background_job_message(req).await?;
+            let res = mt
+                .get_background_job(GetBackgroundJobReq {
+                    name: job_ident.clone(),
+                })
+                .await?;
+            assert_eq!("compacted 1 table(s)", res.info.message);
+        }
+
+        info!("--- acquire the lease of a background job");
+        {
+            let req = AcquireBackgroundJobLeaseReq {
+                job_name: job_ident.clone(),
+                holder: "node1".to_string(),
+                lease: std::time::Duration::from_secs(60),
+            };
+            let res = mt.acquire_background_job_lease(req.clone()).await?;
+            assert!(res.acquired);
+
+            // the holder can renew the lease
+            let res = mt.acquire_background_job_lease(req).await?;
+            assert!(res.acquired);
+
+            // other nodes can not take the lease before it expires
+            let res = mt
+                .acquire_background_job_lease(AcquireBackgroundJobLeaseReq {
+                    job_name: job_ident.clone(),
+                    holder: "node2".to_string(),
+                    lease: std::time::Duration::from_secs(60),
+                })
+                .await?;
+            assert!(!res.acquired);
+            assert_eq!("node1", res.holder);
+        }
+
         info!("--- list background jobs when their is 1 tasks");
         {
             let req = ListBackgroundJobsReq {
@@ -62,6 +62,8 @@ use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTableWithOptionsReply;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockMeta;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -201,6 +203,12 @@ pub trait SchemaApi: Send + Sync {
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
+    /// List the tables of a database by filters and pagination.
+    async fn list_tables_with_options(
+        &self,
+        req: ListTableWithOptionsReq,
+    ) -> Result<ListTableWithOptionsReply, KVAppError>;
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
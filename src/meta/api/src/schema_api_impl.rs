@@ -28,6 +28,7 @@ use common_meta_app::app_error::DatabaseAlreadyExists;
 use common_meta_app::app_error::DropDbWithDropTime;
 use common_meta_app::app_error::DropIndexWithDropTime;
 use common_meta_app::app_error::DropTableWithDropTime;
+use common_meta_app::app_error::DuplicatedCommit;
 use common_meta_app::app_error::DuplicatedUpsertFiles;
 use common_meta_app::app_error::GetIndexWithDropTime;
 use common_meta_app::app_error::IndexAlreadyExists;
@@ -85,6 +86,7 @@ use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::DeduplicatedLabel;
 use common_meta_app::schema::DeleteLockRevReq;
 use common_meta_app::schema::DropCatalogReply;
 use common_meta_app::schema::DropCatalogReq;
@@ -123,6 +125,8 @@ use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTableWithOptionsReply;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockMeta;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -168,6 +172,7 @@ use common_meta_app::share::ShareTableInfoMap;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::txn_condition::Target;
 use common_meta_types::txn_op::Request;
 use common_meta_types::txn_op_response::Response;
 use common_meta_types::ConditionResult;
@@ -222,6 +227,7 @@ use crate::util::get_table_names_by_ids;
 use crate::util::get_virtual_column_by_id_or_err;
 use crate::util::list_tables_from_share_db;
 use crate::util::list_tables_from_unshare_db;
+use crate::util::list_tables_with_options_from_unshare_db;
 use crate::util::mget_pb_values;
 use crate::util::remove_table_from_share;
 use crate::util::txn_trials;
@@ -2287,6 +2293,47 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(tb_infos)
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn list_tables_with_options(
+        &self,
+        req: ListTableWithOptionsReq,
+    ) -> Result<ListTableWithOptionsReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let tenant_dbname = &req.inner;
+
+        // Get db by name to ensure presence
+        let (_db_id_seq, db_id, _db_meta_seq, db_meta) = get_db_or_err(
+            self,
+            tenant_dbname,
+            format!("list_tables_with_options: {}", &tenant_dbname),
+        )
+        .await?;
+
+        match db_meta.from_share {
+            None => {
+                list_tables_with_options_from_unshare_db(self, db_id, tenant_dbname, &req.options)
+                    .await
+            }
+            Some(share) => {
+                // The tables of a share database are listed from the share meta.
+                let tb_infos = list_tables_from_share_db(self, share, tenant_dbname).await?;
+                let (table_infos, next_token) = req.options.apply(tb_infos, |info| {
+                    (
+                        info.name.as_str(),
+                        info.ident.table_id,
+                        info.meta.engine.as_str(),
+                    )
+                });
+                Ok(ListTableWithOptionsReply {
+                    table_infos,
+                    next_token,
+                })
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_table_by_id(
@@ -2845,7 +2892,13 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
             }
 
-            if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+            if let Some(deduplicated_label) = &req.deduplicated_label {
+                // the statement with this label has not been committed
+                txn_req.condition.push(TxnCondition {
+                    key: deduplicated_label.label.clone(),
+                    expected: Eq as i32,
+                    target: Some(Target::Seq(0)),
+                });
                 txn_req
                     .if_then
                     .push(build_upsert_table_deduplicated_label(deduplicated_label))
@@ -2881,6 +2934,18 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     // check table version
                     if req_seq.match_seq(tb_meta_seq).is_ok() {
                         // if table version does match, but tx failed,
+                        if let Some(deduplicated_label) = &req.deduplicated_label {
+                            if self.get_kv(&deduplicated_label.label).await?.is_some() {
+                                // report the statement with this label has been committed
+                                return Err(KVAppError::AppError(AppError::from(
+                                    DuplicatedCommit::new(
+                                        req.table_id,
+                                        &deduplicated_label.label,
+                                        "update_table_meta",
+                                    ),
+                                )));
+                            }
+                        }
                         if fail_if_duplicated {
                             // report file duplication error
                             return Err(KVAppError::AppError(AppError::from(
@@ -4088,12 +4153,14 @@ fn build_upsert_table_copied_file_info_conditions(
     Ok((condition, if_then))
 }
 
-fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
-    let expire_at = Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60);
+fn build_upsert_table_deduplicated_label(deduplicated_label: &DeduplicatedLabel) -> TxnOp {
+    let expire_at = deduplicated_label
+        .expire_at
+        .or_else(|| Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60));
     TxnOp {
         request: Some(Request::Put(TxnPutRequest {
-            key: deduplicated_label,
-            value: 1_i8.to_le_bytes().to_vec(),
+            key: deduplicated_label.label.clone(),
+            value: deduplicated_label.result.clone(),
             prev_value: false,
             expire_at,
         })),
@@ -52,6 +52,7 @@ use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::DeduplicatedLabel;
 use common_meta_app::schema::DeleteLockRevReq;
 use common_meta_app::schema::DropCatalogReq;
 use common_meta_app::schema::DropDatabaseReq;
@@ -78,7 +79,9 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
+use common_meta_app::schema::ListTableOptions;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockKey;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -293,6 +296,7 @@ impl SchemaApiTestSuite {
         suite.table_list(&b.build().await).await?;
         suite.table_list_many(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
+        suite.table_list_with_options(&b.build().await).await?;
         suite
             .table_drop_undrop_list_history(&b.build().await)
             .await?;
@@ -2205,6 +2209,49 @@ impl SchemaApiTestSuite {
                 assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
             }
 
+            info!("--- update table meta: retry a committed update with the same label");
+            {
+                let deduplicated_label = DeduplicatedLabel {
+                    label: "table_update_meta_label".to_string(),
+                    result: b"result".to_vec(),
+                    expire_at: None,
+                };
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics.number_of_rows = 10;
+                mt.update_table_meta(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta: new_table_meta.clone(),
+                    copied_files: None,
+                    deduplicated_label: Some(deduplicated_label.clone()),
+                    update_stream_meta: vec![],
+                })
+                .await?;
+
+                // the reply of the first attempt is lost, the retry sees the committed table
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                let mut retried_table_meta = table.meta.clone();
+                retried_table_meta.statistics.number_of_rows = 20;
+                let res = mt
+                    .update_table_meta(UpdateTableMetaReq {
+                        table_id: table.ident.table_id,
+                        seq: MatchSeq::Exact(table.ident.seq),
+                        new_table_meta: retried_table_meta,
+                        copied_files: None,
+                        deduplicated_label: Some(deduplicated_label),
+                        update_stream_meta: vec![],
+                    })
+                    .await;
+
+                let err = ErrorCode::from(res.unwrap_err());
+                assert_eq!(ErrorCode::DUPLICATED_COMMIT, err.code());
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(table.meta, new_table_meta);
+            }
+
             info!("--- update table meta, with upsert file req");
             {
                 let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
@@ -5064,6 +5111,121 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_list_with_options<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+
+        let list = |options: ListTableOptions| async move {
+            let reply = mt
+                .list_tables_with_options(ListTableWithOptionsReq::new(tenant, db_name, options))
+                .await?;
+            let names = reply
+                .table_infos
+                .iter()
+                .map(|info| info.name.clone())
+                .collect::<Vec<_>>();
+            Ok::<_, KVAppError>((names, reply.next_token))
+        };
+
+        info!("--- prepare db");
+        {
+            self.create_database(mt, tenant, db_name, "eng1").await?;
+        }
+
+        info!("--- create tables: a1 a2 b1, drop a2");
+        {
+            let schema = Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]));
+
+            for (table_name, engine) in [("a1", "FUSE"), ("a2", "JSON"), ("b1", "FUSE")] {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: table_name.to_string(),
+                    },
+                    table_meta: TableMeta {
+                        schema: schema.clone(),
+                        engine: engine.to_string(),
+                        ..Default::default()
+                    },
+                };
+                mt.create_table(req).await?;
+            }
+
+            let tb_info = mt.get_table((tenant, db_name, "a2").into()).await?;
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.to_string(),
+                tb_id: tb_info.ident.table_id,
+            })
+            .await?;
+        }
+
+        info!("--- list with filters");
+        {
+            let (names, token) = list(ListTableOptions::default()).await?;
+            assert_eq!(vec!["a1", "b1"], names);
+            assert!(token.is_none());
+
+            let (names, _) = list(ListTableOptions {
+                name_prefix: Some("a".to_string()),
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["a1"], names);
+
+            let (names, _) = list(ListTableOptions {
+                name_prefix: Some("a".to_string()),
+                include_dropped: true,
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["a1", "a2"], names);
+
+            let (names, _) = list(ListTableOptions {
+                engine: Some("fuse".to_string()),
+                include_dropped: true,
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["a1", "b1"], names);
+
+            let (names, _) = list(ListTableOptions {
+                name_pattern: Some("_1".to_string()),
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["a1", "b1"], names);
+        }
+
+        info!("--- list with pagination");
+        {
+            let (names, token) = list(ListTableOptions {
+                page_size: Some(1),
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["a1"], names);
+            assert!(token.is_some());
+
+            let (names, token) = list(ListTableOptions {
+                page_size: Some(1),
+                continuation_token: token,
+                ..Default::default()
+            })
+            .await?;
+            assert_eq!(vec!["b1"], names);
+            assert!(token.is_none());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_list_all<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
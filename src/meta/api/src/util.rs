@@ -40,7 +40,11 @@ use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::IndexId;
 use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::ListTableOptions;
+use common_meta_app::schema::ListTableWithOptionsReply;
 use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableIdList;
+use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
@@ -896,6 +900,114 @@ pub async fn list_tables_from_unshare_db(
     .await
 }
 
+/// List the tables of a database by the filters and the pagination of `options`.
+///
+/// The table names are range scanned from the meta-service by the name prefix of the
+/// filters, and only the metas of the tables of the page are loaded.
+pub async fn list_tables_with_options_from_unshare_db(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    db_id: u64,
+    tenant_dbname: &DatabaseNameIdent,
+    options: &ListTableOptions,
+) -> Result<ListTableWithOptionsReply, KVAppError> {
+    let prefix = options.scan_prefix();
+
+    // The (name, table_id) of the tables to list.
+    let mut candidates = if options.include_dropped {
+        // The id list of a name contains both the current and the dropped tables.
+        let id_list_keys = list_keys(kv_api, &TableIdListKey {
+            db_id,
+            table_name: prefix,
+        })
+        .await?;
+
+        let mut candidates = vec![];
+        for chunk in id_list_keys.chunks(DEFAULT_MGET_SIZE) {
+            let keys = chunk
+                .iter()
+                .map(|key| key.to_string_key())
+                .collect::<Vec<_>>();
+            let id_lists: Vec<(u64, Option<TableIdList>)> = mget_pb_values(kv_api, &keys).await?;
+            for (key, (_, id_list)) in chunk.iter().zip(id_lists) {
+                if let Some(id_list) = id_list {
+                    candidates.extend(
+                        id_list
+                            .id_list
+                            .into_iter()
+                            .map(|table_id| (key.table_name.clone(), table_id)),
+                    );
+                }
+            }
+        }
+        candidates
+    } else {
+        let (dbid_tbnames, ids) = list_u64_value(kv_api, &DBIdTableName {
+            db_id,
+            table_name: prefix,
+        })
+        .await?;
+        dbid_tbnames
+            .into_iter()
+            .map(|dbid_tbname| dbid_tbname.table_name)
+            .zip(ids)
+            .collect::<Vec<_>>()
+    };
+    candidates.retain(|(name, table_id)| {
+        options.matches_name(name) && options.is_after_token(name, *table_id)
+    });
+    candidates.sort();
+    candidates.dedup();
+
+    // Load the metas batch by batch until the page is full.
+    let page_size = options.page_size.unwrap_or(usize::MAX);
+    let mut table_infos = vec![];
+    let mut remaining = candidates.as_slice();
+    while table_infos.len() < page_size && !remaining.is_empty() {
+        let batch_size = (page_size - table_infos.len())
+            .min(DEFAULT_MGET_SIZE)
+            .min(remaining.len());
+        let (batch, rest) = remaining.split_at(batch_size);
+        remaining = rest;
+
+        let ids = batch
+            .iter()
+            .map(|(_, table_id)| *table_id)
+            .collect::<Vec<_>>();
+        let dbid_tbnames = batch
+            .iter()
+            .map(|(name, _)| DBIdTableName {
+                db_id,
+                table_name: name.clone(),
+            })
+            .collect();
+        let infos = get_tableinfos_by_ids(
+            kv_api,
+            &ids,
+            tenant_dbname,
+            Some(dbid_tbnames),
+            DatabaseType::NormalDB,
+        )
+        .await?;
+        table_infos.extend(
+            infos
+                .into_iter()
+                .filter(|info| options.matches_engine(&info.meta.engine)),
+        );
+    }
+
+    let next_token = if remaining.is_empty() {
+        None
+    } else {
+        table_infos
+            .last()
+            .map(|info| ListTableOptions::make_token(&info.name, info.ident.table_id))
+    };
+    Ok(ListTableWithOptionsReply {
+        table_infos,
+        next_token,
+    })
+}
+
 pub async fn list_tables_from_share_db(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     share: ShareNameIdent,
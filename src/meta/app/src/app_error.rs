@@ -336,6 +336,26 @@ impl DuplicatedUpsertFiles {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "DuplicatedCommit: table {table_id} has been committed with label `{label}`, in operation `{context}`"
+)]
+pub struct DuplicatedCommit {
+    table_id: u64,
+    label: String,
+    context: String,
+}
+
+impl DuplicatedCommit {
+    pub fn new(table_id: u64, label: impl Into<String>, context: impl Into<String>) -> Self {
+        DuplicatedCommit {
+            table_id,
+            label: label.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownDatabase: `{db_name}` while `{context}`")]
 pub struct UnknownDatabase {
@@ -882,6 +902,9 @@ pub enum AppError {
     #[error(transparent)]
     DuplicatedUpsertFiles(#[from] DuplicatedUpsertFiles),
 
+    #[error(transparent)]
+    DuplicatedCommit(#[from] DuplicatedCommit),
+
     #[error(transparent)]
     TableAlreadyExists(#[from] TableAlreadyExists),
 
@@ -1106,6 +1129,8 @@ impl AppErrorMessage for UnknownStreamId {}
 
 impl AppErrorMessage for DuplicatedUpsertFiles {}
 
+impl AppErrorMessage for DuplicatedCommit {}
+
 impl AppErrorMessage for TableAlreadyExists {
     fn message(&self) -> String {
         format!("Table '{}' already exists", self.table_name)
@@ -1428,6 +1453,7 @@ impl From<AppError> for ErrorCode {
             }
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
             AppError::DuplicatedUpsertFiles(err) => ErrorCode::DuplicatedUpsertFiles(err.message()),
+            AppError::DuplicatedCommit(err) => ErrorCode::DuplicatedCommit(err.message()),
             AppError::CreateIndexWithDropTime(err) => {
                 ErrorCode::CreateIndexWithDropTime(err.message())
             }
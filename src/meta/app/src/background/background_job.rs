@@ -325,6 +325,22 @@ impl Display for UpdateBackgroundJobParamsReq {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateBackgroundJobMessageReq {
+    pub job_name: BackgroundJobIdent,
+    pub message: String,
+}
+
+impl Display for UpdateBackgroundJobMessageReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "update_background_job_message({}, {})",
+            self.job_name.name, self.message
+        )
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpdateBackgroundJobReq {
     pub job_name: BackgroundJobIdent,
@@ -377,13 +393,57 @@ impl Display for ListBackgroundJobsReq {
     }
 }
 
+// Lease
+/// A lease on a background job, only the holder of the lease runs the job in a cluster.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct BackgroundJobLeaseIdent {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl From<&BackgroundJobIdent> for BackgroundJobLeaseIdent {
+    fn from(ident: &BackgroundJobIdent) -> Self {
+        Self {
+            tenant: ident.tenant.clone(),
+            name: ident.name.clone(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireBackgroundJobLeaseReq {
+    pub job_name: BackgroundJobIdent,
+    // The node which tries to hold the lease
+    pub holder: String,
+    pub lease: std::time::Duration,
+}
+
+impl Display for AcquireBackgroundJobLeaseReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "acquire_background_job_lease({}, {}, {:?})",
+            self.job_name.name, self.holder, self.lease
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireBackgroundJobLeaseReply {
+    pub acquired: bool,
+    // The current holder of the lease
+    pub holder: String,
+}
+
 mod kvapi_key_impl {
     use common_meta_kvapi::kvapi;
 
     use crate::background::background_job::BackgroundJobId;
     use crate::background::background_job::BackgroundJobIdent;
+    use crate::background::background_job::BackgroundJobLeaseIdent;
     const PREFIX_BACKGROUND_JOB: &str = "__fd_background_job";
     const PREFIX_BACKGROUND_JOB_BY_ID: &str = "__fd_background_job_by_id";
+    const PREFIX_BACKGROUND_JOB_LEASE: &str = "__fd_background_job_lease";
 
     /// <prefix>/<tenant>/<background_job_ident> -> <id>
     impl kvapi::Key for BackgroundJobIdent {
@@ -425,4 +485,26 @@ mod kvapi_key_impl {
             Ok(BackgroundJobId { id })
         }
     }
+
+    /// <prefix>/<tenant>/<background_job_ident> -> <holder>
+    impl kvapi::Key for BackgroundJobLeaseIdent {
+        const PREFIX: &'static str = PREFIX_BACKGROUND_JOB_LEASE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let name = p.next_str()?;
+            p.done()?;
+
+            Ok(BackgroundJobLeaseIdent { tenant, name })
+        }
+    }
 }
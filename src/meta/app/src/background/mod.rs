@@ -15,9 +15,12 @@
 mod background_job;
 mod background_task;
 
+pub use background_job::AcquireBackgroundJobLeaseReply;
+pub use background_job::AcquireBackgroundJobLeaseReq;
 pub use background_job::BackgroundJobId;
 pub use background_job::BackgroundJobIdent;
 pub use background_job::BackgroundJobInfo;
+pub use background_job::BackgroundJobLeaseIdent;
 pub use background_job::BackgroundJobParams;
 pub use background_job::BackgroundJobState;
 pub use background_job::BackgroundJobStatus;
@@ -30,6 +33,7 @@ pub use background_job::GetBackgroundJobReply;
 pub use background_job::GetBackgroundJobReq;
 pub use background_job::ListBackgroundJobsReq;
 pub use background_job::ManualTriggerParams;
+pub use background_job::UpdateBackgroundJobMessageReq;
 pub use background_job::UpdateBackgroundJobParamsReq;
 pub use background_job::UpdateBackgroundJobReply;
 pub use background_job::UpdateBackgroundJobReq;
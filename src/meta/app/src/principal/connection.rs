@@ -24,6 +24,7 @@ pub struct UserDefinedConnection {
     pub name: String,
     pub storage_type: String,
     pub storage_params: BTreeMap<String, String>,
+    pub comment: String,
 }
 
 impl UserDefinedConnection {
@@ -35,9 +36,15 @@ impl UserDefinedConnection {
                 .into_iter()
                 .map(|(k, v)| (k.to_lowercase(), v))
                 .collect::<BTreeMap<_, _>>(),
+            comment: "".to_string(),
         }
     }
 
+    pub fn with_comment(mut self, comment: String) -> Self {
+        self.comment = comment;
+        self
+    }
+
     pub fn storage_params_display(&self) -> String {
         self.storage_params
             .iter()
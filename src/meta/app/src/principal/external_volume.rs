@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::storage::StorageParams;
+
+/// External volume is a named storage location which can be shared by stages and tables.
+///
+/// The stages and tables created on a volume only keep a [`VolumeLocation`], the storage
+/// params are resolved through the volume whenever they are accessed, so that altering the
+/// volume, e.g. rotating its credentials, takes effect on all of them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ExternalVolume {
+    pub name: String,
+    pub storage_params: StorageParams,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl ExternalVolume {
+    /// Returns the storage params of the location on this volume.
+    pub fn resolve(&self, location: &VolumeLocation) -> StorageParams {
+        let path = location.path.trim_matches('/');
+        if path.is_empty() {
+            return self.storage_params.clone();
+        }
+        self.storage_params
+            .clone()
+            .map_root(|root| format!("{}/{}/", root.trim_end_matches('/'), path))
+    }
+}
+
+/// A location relative to the root of an external volume.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct VolumeLocation {
+    pub volume: String,
+    pub path: String,
+}
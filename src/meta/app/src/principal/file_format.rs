@@ -252,6 +252,98 @@ impl FileFormatParams {
         }
     }
 
+    /// The options which parse back to the params with [`FileFormatParams::try_from_ast`],
+    /// the options equal to the defaults of the format type are left out.
+    pub fn to_ast(&self) -> FileFormatOptionsAst {
+        let mut options = BTreeMap::new();
+        options.insert(
+            "type".to_string(),
+            self.get_type().to_string().to_uppercase(),
+        );
+        let compression = self.compression();
+        if compression != StageFileCompression::None {
+            options.insert("compression".to_string(), compression.to_string());
+        }
+        let mut insert_if_changed = |key: &str, value: String, default: String| {
+            if value != default {
+                options.insert(key.to_string(), value);
+            }
+        };
+        match self {
+            FileFormatParams::Csv(p) => {
+                let default = CsvFileFormatParams::default();
+                insert_if_changed(
+                    OPT_SKIP_HEADER,
+                    p.headers.to_string(),
+                    default.headers.to_string(),
+                );
+                insert_if_changed(
+                    OPT_FIELD_DELIMITER,
+                    p.field_delimiter.clone(),
+                    default.field_delimiter,
+                );
+                insert_if_changed(
+                    OPT_RECORDE_DELIMITER,
+                    p.record_delimiter.clone(),
+                    default.record_delimiter,
+                );
+                insert_if_changed(OPT_NAN_DISPLAY, p.nan_display.clone(), default.nan_display);
+                insert_if_changed(OPT_ESCAPE, p.escape.clone(), default.escape);
+                insert_if_changed(OPT_QUOTE, p.quote.clone(), default.quote);
+                insert_if_changed(
+                    OPT_NULL_DISPLAY,
+                    p.null_display.clone(),
+                    default.null_display,
+                );
+                insert_if_changed(
+                    OPT_ERROR_ON_COLUMN_COUNT_MISMATCH,
+                    p.error_on_column_count_mismatch.to_string(),
+                    default.error_on_column_count_mismatch.to_string(),
+                );
+            }
+            FileFormatParams::Tsv(p) => {
+                let default = TsvFileFormatParams::default();
+                insert_if_changed(
+                    OPT_SKIP_HEADER,
+                    p.headers.to_string(),
+                    default.headers.to_string(),
+                );
+                insert_if_changed(
+                    OPT_FIELD_DELIMITER,
+                    p.field_delimiter.clone(),
+                    default.field_delimiter,
+                );
+                insert_if_changed(
+                    OPT_RECORDE_DELIMITER,
+                    p.record_delimiter.clone(),
+                    default.record_delimiter,
+                );
+                insert_if_changed(OPT_NAN_DISPLAY, p.nan_display.clone(), default.nan_display);
+                insert_if_changed(OPT_ESCAPE, p.escape.clone(), default.escape);
+                insert_if_changed(OPT_QUOTE, p.quote.clone(), default.quote);
+            }
+            FileFormatParams::Xml(p) => {
+                let default = XmlFileFormatParams::default();
+                insert_if_changed(OPT_ROW_TAG, p.row_tag.clone(), default.row_tag);
+            }
+            FileFormatParams::NdJson(p) => {
+                let default = NdJsonFileFormatParams::default();
+                insert_if_changed(
+                    MISSING_FIELD_AS,
+                    p.missing_field_as.to_string(),
+                    default.missing_field_as.to_string(),
+                );
+                insert_if_changed(
+                    NULL_FIELD_AS,
+                    p.null_field_as.to_string(),
+                    default.null_field_as.to_string(),
+                );
+            }
+            FileFormatParams::Json(_) | FileFormatParams::Parquet(_) => {}
+        }
+        FileFormatOptionsAst { options }
+    }
+
     pub fn check(&self) -> Result<()> {
         match self {
             FileFormatParams::Tsv(p) => {
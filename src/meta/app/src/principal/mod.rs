@@ -15,11 +15,15 @@
 //! Principal is a user or role that accesses an entity.
 
 mod connection;
+mod external_volume;
 mod file_format;
 mod network_policy;
 mod ownership_info;
+mod password_policy;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
+mod settings_profile;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -32,12 +36,17 @@ mod user_setting;
 mod user_stage;
 
 pub use connection::*;
+pub use external_volume::ExternalVolume;
+pub use external_volume::VolumeLocation;
 pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use ownership_info::OwnershipInfo;
+pub use password_policy::*;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicy;
+pub use settings_profile::SettingsProfile;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+// The defaults and the allowed ranges of the password policy options.
+pub const DEFAULT_PASSWORD_MIN_LENGTH: u64 = 8;
+pub const DEFAULT_PASSWORD_MAX_LENGTH: u64 = 256;
+pub const DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_NUMERIC_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_SPECIAL_CHARS: u64 = 0;
+pub const DEFAULT_PASSWORD_MAX_AGE_DAYS: u64 = 90;
+pub const DEFAULT_PASSWORD_MAX_RETRIES: u64 = 5;
+pub const DEFAULT_PASSWORD_LOCKOUT_TIME_MINS: u64 = 15;
+pub const DEFAULT_PASSWORD_HISTORY: u64 = 0;
+
+pub const MAX_PASSWORD_LENGTH: u64 = 256;
+pub const MAX_PASSWORD_AGE_DAYS: u64 = 999;
+pub const MAX_PASSWORD_RETRIES: u64 = 10;
+pub const MAX_PASSWORD_LOCKOUT_TIME_MINS: u64 = 999;
+pub const MAX_PASSWORD_HISTORY: u64 = 24;
+
+/// Password policy restricts the passwords of the users it is attached to.
+///
+/// `max_age_days`, `max_retries` and `history` are disabled when set to 0.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PasswordPolicy {
+    pub name: String,
+    pub min_length: u64,
+    pub max_length: u64,
+    pub min_upper_case_chars: u64,
+    pub min_lower_case_chars: u64,
+    pub min_numeric_chars: u64,
+    pub min_special_chars: u64,
+    pub max_age_days: u64,
+    pub max_retries: u64,
+    pub lockout_time_mins: u64,
+    pub history: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            name: "".to_string(),
+            min_length: DEFAULT_PASSWORD_MIN_LENGTH,
+            max_length: DEFAULT_PASSWORD_MAX_LENGTH,
+            min_upper_case_chars: DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS,
+            min_lower_case_chars: DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS,
+            min_numeric_chars: DEFAULT_PASSWORD_MIN_NUMERIC_CHARS,
+            min_special_chars: DEFAULT_PASSWORD_MIN_SPECIAL_CHARS,
+            max_age_days: DEFAULT_PASSWORD_MAX_AGE_DAYS,
+            max_retries: DEFAULT_PASSWORD_MAX_RETRIES,
+            lockout_time_mins: DEFAULT_PASSWORD_LOCKOUT_TIME_MINS,
+            history: DEFAULT_PASSWORD_HISTORY,
+            comment: "".to_string(),
+            create_on: DateTime::<Utc>::default(),
+            update_on: None,
+        }
+    }
+}
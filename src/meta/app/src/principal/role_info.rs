@@ -28,6 +28,9 @@ pub struct RoleInfo {
     pub name: String,
 
     pub grants: UserGrantSet,
+
+    // The settings profile applied to the sessions using the role.
+    pub settings_profile: Option<String>,
 }
 
 /// Error when ser/de RoleInfo
@@ -42,6 +45,7 @@ impl RoleInfo {
         Self {
             name: name.to_string(),
             grants: UserGrantSet::empty(),
+            settings_profile: None,
         }
     }
 
@@ -26,6 +26,7 @@ pub struct UserDefinedFileFormat {
     pub name: String,
     pub file_format_params: FileFormatParams,
     pub creator: UserIdentity,
+    pub comment: String,
 }
 
 impl UserDefinedFileFormat {
@@ -34,8 +35,14 @@ impl UserDefinedFileFormat {
             name: name.to_string(),
             file_format_params,
             creator,
+            comment: "".to_string(),
         }
     }
+
+    pub fn with_comment(mut self, comment: String) -> Self {
+        self.comment = comment;
+        self
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserDefinedFileFormat {
@@ -41,13 +41,25 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    /// The tables in a database, including those created after the grant, e.g.
+    /// `GRANT SELECT ON FUTURE TABLES IN DATABASE db TO ROLE r`.
+    ///
+    /// The privileges add up with the explicit grants on each table, and revoking one of them
+    /// leaves the other untouched. Ownership always takes precedence: the owner role of a table
+    /// passes every check on it, and a newly created table is still owned by the creating role
+    /// whatever future grants exist on its database.
+    FutureTables(String, String),
     UDF(String),
     Stage(String),
+    /// An external volume: USAGE allows creating stages and tables on it, while READ allows
+    /// reading its definition.
+    Volume(String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects. The FutureTables object contains all the
+    /// Table objects of its database, but not the database itself.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -58,6 +70,16 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::Database(lcat, ldb), GrantObject::FutureTables(rcat, rdb)) => {
+                lcat == rcat && ldb == rdb
+            }
+            (GrantObject::FutureTables(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
+                lcat == rcat && ldb == rdb
+            }
+            (GrantObject::FutureTables(lcat, ldb), GrantObject::FutureTables(rcat, rdb)) => {
+                lcat == rcat && ldb == rdb
+            }
+            (GrantObject::FutureTables(_, _), _) => false,
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
@@ -65,6 +87,7 @@ impl GrantObject {
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
+            (GrantObject::Volume(lvolume), GrantObject::Volume(rvolume)) => lvolume == rvolume,
             _ => false,
         }
     }
@@ -75,16 +98,24 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::FutureTables(_, _) => {
+                UserPrivilegeSet::available_privileges_on_future_tables()
+            }
             GrantObject::UDF(_) => UserPrivilegeSet::available_privileges_on_udf(),
             GrantObject::Stage(_) => UserPrivilegeSet::available_privileges_on_stage(),
+            GrantObject::Volume(_) => UserPrivilegeSet::available_privileges_on_volume(),
         }
     }
 
     pub fn catalog(&self) -> Option<String> {
         match self {
-            GrantObject::Global | GrantObject::Stage(_) | GrantObject::UDF(_) => None,
+            GrantObject::Global
+            | GrantObject::Stage(_)
+            | GrantObject::UDF(_)
+            | GrantObject::Volume(_) => None,
             GrantObject::Database(cat, _) => Some(cat.clone()),
             GrantObject::Table(cat, _, _) => Some(cat.clone()),
+            GrantObject::FutureTables(cat, _) => Some(cat.clone()),
         }
     }
 }
@@ -97,8 +128,12 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::FutureTables(ref cat, ref db) => {
+                write!(f, "FUTURE TABLES IN DATABASE '{}'.'{}'", cat, db)
+            }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
+            GrantObject::Volume(volume) => write!(f, "EXTERNAL VOLUME {volume}"),
         }
     }
 }
@@ -235,7 +270,7 @@ impl UserGrantSet {
             .map(|e| {
                 if e.matches_entry(object) {
                     let mut e = e.clone();
-                    e.privileges ^= privileges;
+                    e.privileges.remove(privileges);
                     e
                 } else {
                     e.clone()
@@ -15,6 +15,8 @@
 use core::fmt;
 use std::convert::TryFrom;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use enumflags2::bitflags;
@@ -26,6 +28,7 @@ use crate::principal::AuthInfo;
 use crate::principal::UserGrantSet;
 use crate::principal::UserIdentity;
 use crate::principal::UserQuota;
+use crate::principal::MAX_PASSWORD_HISTORY;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
@@ -41,6 +44,18 @@ pub struct UserInfo {
     pub quota: UserQuota,
 
     pub option: UserOption,
+
+    // The previous auth infos of the user, used to check the password history.
+    pub history_auth_infos: Vec<AuthInfo>,
+
+    // The time of the failed password authentications since the last success.
+    pub password_fails: Vec<DateTime<Utc>>,
+
+    // The time when the password is set.
+    pub password_update_on: Option<DateTime<Utc>>,
+
+    // The user can't login until this time, after too many failed password authentications.
+    pub lockout_time: Option<DateTime<Utc>>,
 }
 
 impl UserInfo {
@@ -57,6 +72,10 @@ impl UserInfo {
             grants,
             quota,
             option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on: None,
+            lockout_time: None,
         }
     }
 
@@ -77,12 +96,26 @@ impl UserInfo {
 
     pub fn update_auth_option(&mut self, auth: Option<AuthInfo>, option: Option<UserOption>) {
         if let Some(auth_info) = auth {
+            if matches!(auth_info, AuthInfo::Password { .. }) {
+                self.update_auth_history(&auth_info);
+            }
             self.auth_info = auth_info;
         };
         if let Some(user_option) = option {
             self.option = user_option;
         };
     }
+
+    // Keeps the replaced password for the history check of the password policy,
+    // and records when the new password is set.
+    fn update_auth_history(&mut self, auth_info: &AuthInfo) {
+        if matches!(self.auth_info, AuthInfo::Password { .. }) && *auth_info != self.auth_info {
+            self.history_auth_infos.insert(0, self.auth_info.clone());
+            self.history_auth_infos
+                .truncate(MAX_PASSWORD_HISTORY as usize);
+        }
+        self.password_update_on = Some(Utc::now());
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserInfo {
@@ -107,6 +140,10 @@ pub struct UserOption {
     default_role: Option<String>,
 
     network_policy: Option<String>,
+
+    password_policy: Option<String>,
+
+    settings_profile: Option<String>,
 }
 
 impl UserOption {
@@ -115,6 +152,8 @@ impl UserOption {
             flags,
             default_role: None,
             network_policy: None,
+            password_policy: None,
+            settings_profile: None,
         }
     }
 
@@ -137,6 +176,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_password_policy(mut self, password_policy: Option<String>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    pub fn with_settings_profile(mut self, settings_profile: Option<String>) -> Self {
+        self.settings_profile = settings_profile;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -154,6 +203,14 @@ impl UserOption {
         self.network_policy.as_ref()
     }
 
+    pub fn password_policy(&self) -> Option<&String> {
+        self.password_policy.as_ref()
+    }
+
+    pub fn settings_profile(&self) -> Option<&String> {
+        self.settings_profile.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -162,6 +219,14 @@ impl UserOption {
         self.network_policy = network_policy;
     }
 
+    pub fn set_password_policy(&mut self, password_policy: Option<String>) {
+        self.password_policy = password_policy;
+    }
+
+    pub fn set_settings_profile(&mut self, settings_profile: Option<String>) {
+        self.settings_profile = settings_profile;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -70,6 +70,9 @@ pub enum UserPrivilegeType {
     Read = 1 << 18,
     // Privilege to Write stage
     Write = 1 << 19,
+    // Privilege to Create/Drop/Attach row access policies, and to read the tables
+    // without the filtering of the row access policies.
+    ApplyRowAccessPolicy = 1 << 20,
 
     // TODO: remove this later
     Set = 1 << 4,
@@ -96,6 +99,7 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | Ownership
         | Read
         | Write
+        | ApplyRowAccessPolicy
     }
 );
 
@@ -122,6 +126,7 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Ownership => "OWNERSHIP",
             UserPrivilegeType::Read => "Read",
             UserPrivilegeType::Write => "Write",
+            UserPrivilegeType::ApplyRowAccessPolicy => "APPLY ROW ACCESS POLICY",
         })
     }
 }
@@ -146,7 +151,7 @@ impl UserPrivilegeSet {
     /// on databases and tables, and has some Global only privileges.
     pub fn available_privileges_on_global() -> Self {
         let database_privs = Self::available_privileges_on_database();
-        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant | CreateDataMask });
+        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant | CreateDataMask | ApplyRowAccessPolicy });
         (database_privs.privileges | privs).into()
     }
 
@@ -162,6 +167,12 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant | Ownership }).into()
     }
 
+    /// The ownership of a table always belongs to the role which creates it, so it can not be
+    /// granted on future tables.
+    pub fn available_privileges_on_future_tables() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
+    }
+
     pub fn available_privileges_on_stage() -> Self {
         make_bitflags!(UserPrivilegeType::{  Read | Write }).into()
     }
@@ -170,6 +181,10 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Usage }).into()
     }
 
+    pub fn available_privileges_on_volume() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage | Read }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
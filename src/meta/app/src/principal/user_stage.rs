@@ -27,6 +27,7 @@ use common_io::escape_string;
 
 use crate::principal::FileFormatParams;
 use crate::principal::UserIdentity;
+use crate::principal::VolumeLocation;
 use crate::storage::StorageParams;
 
 // -- Internal stage
@@ -569,6 +570,9 @@ pub struct StageInfo {
     /// TODO(xuanwo): stage doesn't have this info anymore, remove it.
     pub number_of_files: u64,
     pub creator: Option<UserIdentity>,
+    /// The location of the stage on an external volume, the stage params are resolved
+    /// through the volume if it is set.
+    pub volume_location: Option<VolumeLocation>,
 }
 
 impl StageInfo {
@@ -59,6 +59,7 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use table::BucketSpec;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -66,6 +67,7 @@ pub use table::CreateTableReply;
 pub use table::CreateTableReq;
 pub use table::DBIdTableName;
 pub use table::DatabaseType;
+pub use table::DeduplicatedLabel;
 pub use table::DropTableByIdReq;
 pub use table::DropTableReply;
 pub use table::DroppedId;
@@ -77,7 +79,10 @@ pub use table::GetTableCopiedFileReq;
 pub use table::GetTableReq;
 pub use table::ListDroppedTableReq;
 pub use table::ListDroppedTableResp;
+pub use table::ListTableOptions;
 pub use table::ListTableReq;
+pub use table::ListTableWithOptionsReply;
+pub use table::ListTableWithOptionsReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
@@ -91,10 +96,12 @@ pub use table::TableIdList;
 pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
+pub use table::TableIndex;
 pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
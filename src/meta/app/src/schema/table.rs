@@ -23,7 +23,9 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -240,6 +242,59 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub owner: Option<Ownership>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+    // The secondary indexes of the table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+    // How the rows of the table are distributed into buckets.
+    pub bucket_spec: Option<BucketSpec>,
+}
+
+/// The row access policy attached to a table, and the columns passed to the policy
+/// as its arguments.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+/// An inverted index built on some string columns of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub name: String,
+    pub column_ids: Vec<ColumnId>,
+    pub tokenizer: String,
+    // Changed every time the index is created, index files of other versions are stale.
+    pub version: String,
+    pub created_on: DateTime<Utc>,
+}
+
+/// Rows of a bucketed table are routed to `num_buckets` buckets by the hash of the key columns,
+/// every block holds the rows of a single bucket.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct BucketSpec {
+    pub column_ids: Vec<ColumnId>,
+    pub num_buckets: u64,
+}
+
+impl BucketSpec {
+    /// Returns the indices of the bucket keys in the fields of `schema`.
+    pub fn key_indices(&self, schema: &TableSchema) -> Result<Vec<FieldIndex>> {
+        self.column_ids
+            .iter()
+            .map(|column_id| {
+                schema
+                    .fields()
+                    .iter()
+                    .position(|field| field.column_id() == *column_id)
+                    .ok_or_else(|| {
+                        ErrorCode::InvalidBucketSpec(format!(
+                            "Bucket key with column id {} does not exist",
+                            column_id
+                        ))
+                    })
+            })
+            .collect()
+    }
 }
 
 impl TableMeta {
@@ -355,6 +410,9 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             owner: None,
+            row_access_policy: None,
+            indexes: BTreeMap::new(),
+            bucket_spec: None,
         }
     }
 }
@@ -612,7 +670,20 @@ pub struct UpdateTableMetaReq {
     pub new_table_meta: TableMeta,
     pub copied_files: Option<UpsertTableCopiedFileReq>,
     pub update_stream_meta: Vec<UpdateStreamMetaReq>,
-    pub deduplicated_label: Option<String>,
+    pub deduplicated_label: Option<DeduplicatedLabel>,
+}
+
+/// A label recorded in the transaction updating the table meta.
+///
+/// The commit fails with `DuplicatedCommit` if the label exists, a statement retried with
+/// the same label is committed at most once.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeduplicatedLabel {
+    pub label: String,
+    /// The result of the committing statement, returned to its retries.
+    pub result: Vec<u8>,
+    /// The unix timestamp in seconds at which the label expires.
+    pub expire_at: Option<u64>,
 }
 
 impl UpsertTableOptionReq {
@@ -726,6 +797,178 @@ impl ListTableReq {
     }
 }
 
+/// The filters and the pagination of listing the tables of a database.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListTableOptions {
+    /// Only list the tables of this engine, case-insensitive.
+    pub engine: Option<String>,
+    /// Only list the tables whose names start with this prefix.
+    pub name_prefix: Option<String>,
+    /// Only list the tables whose names match this `LIKE` pattern.
+    pub name_pattern: Option<String>,
+    /// Also list the dropped tables that are still retained.
+    pub include_dropped: bool,
+    /// Continue listing after the last table of the previous page.
+    pub continuation_token: Option<String>,
+    /// The max number of tables of a page, all the tables are listed if `None`.
+    pub page_size: Option<usize>,
+}
+
+impl ListTableOptions {
+    /// The longest name prefix that all the listed tables share, which can be used
+    /// to range scan the table names.
+    pub fn scan_prefix(&self) -> String {
+        let pattern_prefix = self.name_pattern.as_deref().map(|pattern| {
+            pattern
+                .split(|c| matches!(c, '%' | '_' | '\\'))
+                .next()
+                .unwrap_or_default()
+        });
+        match (self.name_prefix.as_deref(), pattern_prefix) {
+            (Some(a), Some(b)) if b.len() > a.len() => b.to_string(),
+            (Some(a), _) => a.to_string(),
+            (None, Some(b)) => b.to_string(),
+            (None, None) => "".to_string(),
+        }
+    }
+
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name_prefix
+            .as_ref()
+            .map_or(true, |prefix| name.starts_with(prefix.as_str()))
+            && self.name_pattern.as_ref().map_or(true, |pattern| {
+                like_match(pattern.as_bytes(), name.as_bytes())
+            })
+    }
+
+    pub fn matches_engine(&self, engine: &str) -> bool {
+        self.engine
+            .as_ref()
+            .map_or(true, |e| e.eq_ignore_ascii_case(engine))
+    }
+
+    /// Whether the table is after the continuation token, the tables are listed in
+    /// the order of `(name, table_id)`.
+    pub fn is_after_token(&self, name: &str, table_id: u64) -> bool {
+        match &self.continuation_token {
+            None => true,
+            Some(token) => {
+                let (token_id, token_name) = Self::parse_token(token);
+                (name, table_id) > (token_name, token_id)
+            }
+        }
+    }
+
+    pub fn make_token(name: &str, table_id: u64) -> String {
+        format!("{}:{}", table_id, name)
+    }
+
+    fn parse_token(token: &str) -> (u64, &str) {
+        token
+            .split_once(':')
+            .and_then(|(id, name)| Some((id.parse().ok()?, name)))
+            .unwrap_or((0, token))
+    }
+
+    /// Filter and paginate the listed tables in memory, for the catalogs that can't
+    /// list tables by the options natively.
+    ///
+    /// `key` returns the name, the id and the engine of a table.
+    pub fn apply<T>(
+        &self,
+        tables: Vec<T>,
+        key: impl Fn(&T) -> (&str, u64, &str),
+    ) -> (Vec<T>, Option<String>) {
+        let mut tables = tables
+            .into_iter()
+            .filter(|table| {
+                let (name, table_id, engine) = key(table);
+                self.matches_name(name)
+                    && self.matches_engine(engine)
+                    && self.is_after_token(name, table_id)
+            })
+            .collect::<Vec<_>>();
+        tables.sort_by(|a, b| {
+            let (a_name, a_id, _) = key(a);
+            let (b_name, b_id, _) = key(b);
+            (a_name, a_id).cmp(&(b_name, b_id))
+        });
+
+        match self.page_size {
+            Some(page_size) if tables.len() > page_size => {
+                tables.truncate(page_size);
+                let (name, table_id, _) = key(tables.last().unwrap());
+                let token = Self::make_token(name, table_id);
+                (tables, Some(token))
+            }
+            _ => (tables, None),
+        }
+    }
+}
+
+/// Match `s` with the SQL `LIKE` pattern, `%` matches any sequence of characters and `_`
+/// matches one character, `\` escapes the next character.
+fn like_match(pattern: &[u8], s: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => s.is_empty(),
+        Some((b'%', rest)) => (0..=s.len()).any(|i| like_match(rest, &s[i..])),
+        Some((b'_', rest)) => match s.first() {
+            // Skip a whole UTF-8 character.
+            Some(b) => {
+                let width = match *b {
+                    0xf0.. => 4,
+                    0xe0.. => 3,
+                    0xc0.. => 2,
+                    _ => 1,
+                };
+                s.len() >= width && like_match(rest, &s[width..])
+            }
+            None => false,
+        },
+        Some((b'\\', rest)) if !rest.is_empty() => {
+            s.first() == Some(&rest[0]) && like_match(&rest[1..], &s[1..])
+        }
+        Some((c, rest)) => s.first() == Some(c) && like_match(rest, &s[1..]),
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTableWithOptionsReq {
+    pub inner: DatabaseNameIdent,
+    pub options: ListTableOptions,
+}
+
+impl Deref for ListTableWithOptionsReq {
+    type Target = DatabaseNameIdent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ListTableWithOptionsReq {
+    pub fn new(
+        tenant: impl Into<String>,
+        db_name: impl Into<String>,
+        options: ListTableOptions,
+    ) -> ListTableWithOptionsReq {
+        ListTableWithOptionsReq {
+            inner: DatabaseNameIdent {
+                tenant: tenant.into(),
+                db_name: db_name.into(),
+            },
+            options,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListTableWithOptionsReply {
+    pub table_infos: Vec<Arc<TableInfo>>,
+    /// The token to list the next page, `None` if there are no more tables.
+    pub next_token: Option<String>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum TableInfoFilter {
     // if datatime is some, filter only dropped tables which drop time before that,
@@ -1046,8 +1289,49 @@ mod tests {
     use common_meta_kvapi::kvapi;
     use common_meta_kvapi::kvapi::Key;
 
+    use crate::schema::ListTableOptions;
     use crate::schema::TableCopiedFileNameIdent;
 
+    #[test]
+    fn test_list_table_options() {
+        let options = ListTableOptions {
+            name_pattern: Some("t\\_%".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.scan_prefix(), "t");
+        assert!(options.matches_name("t_1"));
+        assert!(!options.matches_name("t1"));
+
+        let options = ListTableOptions {
+            name_prefix: Some("t".to_string()),
+            name_pattern: Some("tb_%".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.scan_prefix(), "tb");
+        assert!(options.matches_name("tbé1"));
+        assert!(!options.matches_name("tb"));
+
+        let tables = vec![
+            ("b", 2, "FUSE"),
+            ("a", 3, "FUSE"),
+            ("c", 1, "MEMORY"),
+            ("a", 1, "FUSE"),
+        ];
+        let mut options = ListTableOptions {
+            engine: Some("fuse".to_string()),
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let (page, token) = options.apply(tables.clone(), |t| (t.0, t.1, t.2));
+        assert_eq!(page, vec![("a", 1, "FUSE"), ("a", 3, "FUSE")]);
+        assert_eq!(token.as_deref(), Some("3:a"));
+
+        options.continuation_token = token;
+        let (page, token) = options.apply(tables, |t| (t.0, t.1, t.2));
+        assert_eq!(page, vec![("b", 2, "FUSE")]);
+        assert_eq!(token, None);
+    }
+
     #[test]
     fn test_table_copied_file_name_ident_conversion() -> Result<(), kvapi::KeyError> {
         // test with a key has a file has multi path
@@ -31,6 +31,9 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max logical bytes(uncompressed) can be stored by the tables of the tenant.
+    pub max_storage_bytes: u64,
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use common_exception::Result;
 use common_meta_app::principal::check_record_delimiter;
+use common_meta_app::principal::FileFormatOptionsAst;
+use common_meta_app::principal::FileFormatParams;
 
 #[test]
 fn test_check_record_delimiter() {
@@ -22,3 +27,26 @@ fn test_check_record_delimiter() {
     assert!(check_record_delimiter("foo").is_err());
     assert!(check_record_delimiter("|\r").is_err());
 }
+
+#[test]
+fn test_file_format_params_to_ast() -> Result<()> {
+    let options = BTreeMap::from([
+        ("type".to_string(), "CSV".to_string()),
+        ("compression".to_string(), "gzip".to_string()),
+        ("skip_header".to_string(), "1".to_string()),
+        ("field_delimiter".to_string(), "|".to_string()),
+    ]);
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options.clone()), false)?;
+    assert_eq!(params.to_ast().options, options);
+    assert_eq!(
+        FileFormatParams::try_from_ast(params.to_ast(), false)?,
+        params
+    );
+
+    let params = FileFormatParams::default();
+    assert_eq!(
+        params.to_ast().options,
+        BTreeMap::from([("type".to_string(), "PARQUET".to_string())])
+    );
+    Ok(())
+}
@@ -77,6 +77,26 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Table("default".into(), "db2".into(), "c".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Database("default".into(), "db1".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            expect: true,
+        },
         Test {
             lhs: GrantObject::Stage("c".into()),
             rhs: GrantObject::Stage("c".into()),
@@ -230,3 +250,60 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_future_tables() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+    let future_tables = GrantObject::FutureTables("default".into(), "db1".into());
+
+    grants.grant_privileges(
+        &future_tables,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        &GrantObject::Table("default".into(), "db1".into(), "table1".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(2, grants.entries().len());
+    assert_eq!(
+        "GRANT SELECT ON FUTURE TABLES IN DATABASE 'default'.'db1'",
+        grants.entries()[0].to_string()
+    );
+
+    // applies to any table of the database, but not to the database itself
+    assert!(grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "created_later".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db2".into(), "table1".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Database("default".into(), "db1".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+
+    // revoking the future grant leaves the explicit table grant untouched
+    grants.revoke_privileges(
+        &future_tables,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(1, grants.entries().len());
+    assert!(grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "table1".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "created_later".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+
+    // revoking a privilege which is not granted changes nothing
+    grants.revoke_privileges(
+        &future_tables,
+        make_bitflags!(UserPrivilegeType::{Insert}).into(),
+    );
+    assert_eq!(1, grants.entries().len());
+    Ok(())
+}
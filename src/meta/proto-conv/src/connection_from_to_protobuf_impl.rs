@@ -34,6 +34,7 @@ impl FromToProto for mt::UserDefinedConnection {
             name: p.name,
             storage_type: p.storage_type,
             storage_params: p.storage_params,
+            comment: p.comment,
         })
     }
 
@@ -44,6 +45,7 @@ impl FromToProto for mt::UserDefinedConnection {
             name: self.name.clone(),
             storage_type: self.storage_type.clone(),
             storage_params: self.storage_params.clone(),
+            comment: self.comment.clone(),
         })
     }
 }
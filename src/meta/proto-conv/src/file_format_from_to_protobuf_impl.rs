@@ -188,6 +188,7 @@ impl FromToProto for mt::principal::UserDefinedFileFormat {
             name: p.name,
             file_format_params,
             creator,
+            comment: p.comment,
         })
     }
 
@@ -200,6 +201,7 @@ impl FromToProto for mt::principal::UserDefinedFileFormat {
             name: self.name.clone(),
             file_format_params: Some(file_format_params),
             creator: Some(creator),
+            comment: self.comment.clone(),
         })
     }
 }
@@ -53,6 +53,27 @@ impl FromToProto for mt::principal::StageParams {
     }
 }
 
+impl FromToProto for mt::principal::VolumeLocation {
+    type PB = pb::stage_info::VolumeLocation;
+    fn get_pb_ver(_p: &Self::PB) -> u64 {
+        0
+    }
+    fn from_pb(p: pb::stage_info::VolumeLocation) -> Result<Self, Incompatible>
+    where Self: Sized {
+        Ok(mt::principal::VolumeLocation {
+            volume: p.volume,
+            path: p.path,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::stage_info::VolumeLocation, Incompatible> {
+        Ok(pb::stage_info::VolumeLocation {
+            volume: self.volume.clone(),
+            path: self.path.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::OnErrorMode {
     type PB = pb::stage_info::OnErrorMode;
     fn get_pb_ver(_p: &Self::PB) -> u64 {
@@ -220,6 +241,10 @@ impl FromToProto for mt::principal::StageInfo {
                 Some(c) => Some(mt::principal::UserIdentity::from_pb(c)?),
                 None => None,
             },
+            volume_location: match p.volume_location {
+                Some(l) => Some(mt::principal::VolumeLocation::from_pb(l)?),
+                None => None,
+            },
         })
     }
 
@@ -241,6 +266,10 @@ impl FromToProto for mt::principal::StageInfo {
                 Some(c) => Some(mt::principal::UserIdentity::to_pb(c)?),
                 None => None,
             },
+            volume_location: match &self.volume_location {
+                Some(l) => Some(mt::principal::VolumeLocation::to_pb(l)?),
+                None => None,
+            },
         })
     }
 }
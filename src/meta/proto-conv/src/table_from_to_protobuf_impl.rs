@@ -15,6 +15,7 @@
 //! This mod is the key point about compatibility.
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
@@ -222,6 +223,19 @@ impl FromToProto for mt::TableMeta {
                 Some(owner) => Some(mt::Ownership::from_pb(owner)?),
                 None => None,
             },
+            row_access_policy: match p.row_access_policy {
+                Some(policy) => Some(mt::TableRowAccessPolicy::from_pb(policy)?),
+                None => None,
+            },
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+            bucket_spec: match p.bucket_spec {
+                Some(spec) => Some(mt::BucketSpec::from_pb(spec)?),
+                None => None,
+            },
         };
         Ok(v)
     }
@@ -262,6 +276,103 @@ impl FromToProto for mt::TableMeta {
                 Some(o) => Some(o.to_pb()?),
                 None => None,
             },
+            row_access_policy: match self.row_access_policy.as_ref() {
+                Some(policy) => Some(policy.to_pb()?),
+                None => None,
+            },
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+            bucket_spec: match self.bucket_spec.as_ref() {
+                Some(spec) => Some(spec.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            column_ids: p.column_ids,
+            tokenizer: p.tokenizer,
+            version: p.version,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+            tokenizer: self.tokenizer.clone(),
+            version: self.version.clone(),
+            created_on: self.created_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::BucketSpec {
+    type PB = pb::TableBucketSpec;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableBucketSpec) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            column_ids: p.column_ids,
+            num_buckets: p.num_buckets,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableBucketSpec, Incompatible> {
+        let p = pb::TableBucketSpec {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_ids: self.column_ids.clone(),
+            num_buckets: self.num_buckets,
         };
         Ok(p)
     }
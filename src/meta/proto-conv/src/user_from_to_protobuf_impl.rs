@@ -99,7 +99,9 @@ impl FromToProto for mt::principal::UserOption {
         Ok(mt::principal::UserOption::default()
             .with_flags(flags)
             .with_default_role(p.default_role)
-            .with_network_policy(p.network_policy))
+            .with_network_policy(p.network_policy)
+            .with_password_policy(p.password_policy)
+            .with_settings_profile(p.settings_profile))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -109,6 +111,8 @@ impl FromToProto for mt::principal::UserOption {
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
             network_policy: self.network_policy().cloned(),
+            password_policy: self.password_policy().cloned(),
+            settings_profile: self.settings_profile().cloned(),
         })
     }
 }
@@ -162,12 +166,18 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::FutureTables(
+                pb::grant_object::GrantFutureTablesObject { catalog, db },
+            )) => Ok(mt::principal::GrantObject::FutureTables(catalog, db)),
             Some(pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf })) => {
                 Ok(mt::principal::GrantObject::UDF(udf))
             }
             Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            Some(pb::grant_object::Object::Volume(pb::grant_object::GrantVolumeObject {
+                volume,
+            })) => Ok(mt::principal::GrantObject::Volume(volume)),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -192,6 +202,12 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::FutureTables(catalog, db) => Some(
+                pb::grant_object::Object::FutureTables(pb::grant_object::GrantFutureTablesObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                }),
+            ),
             mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
                 pb::grant_object::GrantUdfObject { udf: udf.clone() },
             )),
@@ -200,6 +216,11 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Volume(volume) => Some(pb::grant_object::Object::Volume(
+                pb::grant_object::GrantVolumeObject {
+                    volume: volume.clone(),
+                },
+            )),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -310,6 +331,24 @@ impl FromToProto for mt::principal::UserInfo {
             option: mt::principal::UserOption::from_pb(p.option.ok_or_else(|| Incompatible {
                 reason: "UserInfo.option cannot be None".to_string(),
             })?)?,
+            history_auth_infos: p
+                .history_auth_infos
+                .into_iter()
+                .map(mt::principal::AuthInfo::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: p
+                .password_fails
+                .into_iter()
+                .map(DateTime::<Utc>::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match p.password_update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            lockout_time: match p.lockout_time {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
         })
     }
 
@@ -323,6 +362,24 @@ impl FromToProto for mt::principal::UserInfo {
             grants: Some(mt::principal::UserGrantSet::to_pb(&self.grants)?),
             quota: Some(mt::principal::UserQuota::to_pb(&self.quota)?),
             option: Some(mt::principal::UserOption::to_pb(&self.option)?),
+            history_auth_infos: self
+                .history_auth_infos
+                .iter()
+                .map(mt::principal::AuthInfo::to_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: self
+                .password_fails
+                .iter()
+                .map(|t| t.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match &self.password_update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            lockout_time: match &self.lockout_time {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
         })
     }
 }
@@ -389,3 +446,141 @@ impl FromToProto for mt::principal::NetworkPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::PasswordPolicy {
+    type PB = pb::PasswordPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::PasswordPolicy) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::PasswordPolicy {
+            name: p.name.clone(),
+            min_length: p.min_length,
+            max_length: p.max_length,
+            min_upper_case_chars: p.min_upper_case_chars,
+            min_lower_case_chars: p.min_lower_case_chars,
+            min_numeric_chars: p.min_numeric_chars,
+            min_special_chars: p.min_special_chars,
+            max_age_days: p.max_age_days,
+            max_retries: p.max_retries,
+            lockout_time_mins: p.lockout_time_mins,
+            history: p.history,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::PasswordPolicy, Incompatible> {
+        Ok(pb::PasswordPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            min_length: self.min_length,
+            max_length: self.max_length,
+            min_upper_case_chars: self.min_upper_case_chars,
+            min_lower_case_chars: self.min_lower_case_chars,
+            min_numeric_chars: self.min_numeric_chars,
+            min_special_chars: self.min_special_chars,
+            max_age_days: self.max_age_days,
+            max_retries: self.max_retries,
+            lockout_time_mins: self.lockout_time_mins,
+            history: self.history,
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl FromToProto for mt::principal::RowAccessPolicy {
+    type PB = pb::RowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::RowAccessPolicy) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        if p.arg_names.len() != p.arg_types.len() {
+            return Err(Incompatible {
+                reason: format!(
+                    "RowAccessPolicy has {} argument names but {} argument types",
+                    p.arg_names.len(),
+                    p.arg_types.len()
+                ),
+            });
+        }
+        Ok(mt::principal::RowAccessPolicy {
+            name: p.name.clone(),
+            args: p.arg_names.into_iter().zip(p.arg_types).collect(),
+            body: p.body,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::RowAccessPolicy, Incompatible> {
+        Ok(pb::RowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            arg_names: self.args.iter().map(|(name, _)| name.clone()).collect(),
+            arg_types: self.args.iter().map(|(_, typ)| typ.clone()).collect(),
+            body: self.body.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl FromToProto for mt::principal::SettingsProfile {
+    type PB = pb::SettingsProfile;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::SettingsProfile) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::SettingsProfile {
+            name: p.name.clone(),
+            settings: p.settings,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::SettingsProfile, Incompatible> {
+        Ok(pb::SettingsProfile {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            settings: self.settings.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
@@ -95,6 +95,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (63, "2023-10-30: Add: connection.proto"),
     (64, "2023-11-16: Add: user.proto/NDJsonFileFormatParams add field `missing_field_as` and `null_field_as`", ),
     (65, "2023-11-16: Retype: use Datetime<Utc> instead of u64 to in lvt.time", ),
+    (66, "2023-11-21: Add: user.proto/PasswordPolicy, UserOption::password_policy and password states in UserInfo", ),
+    (67, "2023-11-22: Add: user.proto/RowAccessPolicy and table.proto/TableMeta::row_access_policy", ),
+    (68, "2023-11-24: Add: user.proto/GrantObject::future_tables", ),
+    (69, "2023-11-27: Add: connection.proto/UserDefinedConnection::comment, file_format.proto/UserDefinedFileFormat::comment", ),
+    (70, "2023-11-28: Add: external_volume.proto/ExternalVolume, stage.proto/StageInfo::volume_location, user.proto/GrantObject::volume", ),
+    (71, "2023-11-30: Add: table.proto/TableIndex and TableMeta::indexes", ),
+    (72, "2023-12-01: Add: table.proto/TableBucketSpec and TableMeta::bucket_spec", ),
+    (73, "2023-12-04: Add: user.proto/SettingsProfile and UserOption::settings_profile", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
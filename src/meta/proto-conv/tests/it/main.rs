@@ -69,3 +69,11 @@ mod v062_table_lock_meta;
 mod v063_connection;
 mod v064_ndjson_format_params;
 mod v065_least_visible_time;
+mod v066_password_policy;
+mod v067_row_access_policy;
+mod v068_future_tables_grant;
+mod v069_connection_file_format_comment;
+mod v070_external_volume;
+mod v071_table_index;
+mod v072_bucket_spec;
+mod v073_settings_profile;
@@ -213,6 +213,9 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
+        indexes: btreemap! {},
+        bucket_spec: None,
         owner: None,
     }
 }
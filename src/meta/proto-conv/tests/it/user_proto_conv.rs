@@ -67,6 +67,10 @@ fn test_user_info() -> mt::principal::UserInfo {
             max_storage_in_bytes: 20480,
         },
         option,
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
     }
 }
 
@@ -103,6 +107,7 @@ pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
             username: "databend".to_string(),
             hostname: "databend.rs".to_string(),
         }),
+        volume_location: None,
     }
 }
 
@@ -73,6 +73,7 @@ fn test_decode_v25_user_stage() -> anyhow::Result<()> {
             username: "databend".to_string(),
             hostname: "databend.rs".to_string(),
         }),
+        volume_location: None,
     };
     common::test_load_old(func_name!(), stage_info_v25.as_slice(), 25, want())?;
     common::test_pb_from_to(func_name!(), want())?;
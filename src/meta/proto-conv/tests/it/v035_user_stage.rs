@@ -70,6 +70,7 @@ fn test_decode_v35_user_stage() -> anyhow::Result<()> {
             username: "databend".to_string(),
             hostname: "databend.rs".to_string(),
         }),
+        volume_location: None,
     };
     common::test_load_old(func_name!(), stage_info_v35.as_slice(), 35, want())?;
     common::test_pb_from_to(func_name!(), want())?;
@@ -74,6 +74,7 @@ fn test_decode_v42_s3_stage_new_field() -> anyhow::Result<()> {
             username: "databend".to_string(),
             hostname: "databend.rs".to_string(),
         }),
+        volume_location: None,
     };
 
     common::test_load_old(func_name!(), stage_info_v42.as_slice(), 42, want())?;
@@ -90,6 +90,9 @@ fn test_decode_v55_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
+        indexes: btreemap! {},
+        bucket_spec: None,
         owner: Some(Ownership {
             owner_role_name: "role2".to_string(),
             updated_on: Default::default(),
@@ -38,6 +38,7 @@ fn test_decode_v62_connection() -> anyhow::Result<()> {
         name: "my_conn".to_string(),
         storage_type: "s3".to_string(),
         storage_params: BTreeMap::from([("key".to_string(), "val".to_string())]),
+        comment: "".to_string(),
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(
@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app as mt;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::principal::VolumeLocation;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use enumflags2::make_bitflags;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v70_external_volume() -> anyhow::Result<()> {
+    let external_volume_v70 = vec![
+        10, 6, 109, 121, 95, 118, 111, 108, 18, 52, 10, 50, 42, 9, 109, 121, 45, 98, 117, 99, 107,
+        101, 116, 50, 6, 47, 100, 97, 116, 97, 47, 90, 23, 97, 114, 110, 58, 97, 119, 115, 58, 105,
+        97, 109, 58, 58, 49, 50, 51, 58, 114, 111, 108, 101, 47, 114, 160, 6, 70, 168, 6, 24, 26,
+        10, 109, 121, 32, 99, 111, 109, 109, 101, 110, 116, 34, 23, 50, 48, 49, 52, 45, 49, 49, 45,
+        50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 70, 168, 6, 24,
+    ];
+    let want = || ExternalVolume {
+        name: "my_vol".to_string(),
+        storage_params: StorageParams::S3(StorageS3Config {
+            bucket: "my-bucket".to_string(),
+            root: "/data/".to_string(),
+            role_arn: "arn:aws:iam::123:role/r".to_string(),
+            ..Default::default()
+        }),
+        comment: "my comment".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), external_volume_v70.as_slice(), 70, want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v70_stage_volume_location() -> anyhow::Result<()> {
+    let stage_info_v70 = vec![
+        10, 8, 109, 121, 95, 115, 116, 97, 103, 101, 16, 1, 26, 56, 10, 54, 10, 52, 42, 9, 109,
+        121, 45, 98, 117, 99, 107, 101, 116, 50, 8, 47, 100, 97, 116, 97, 47, 112, 47, 90, 23, 97,
+        114, 110, 58, 97, 119, 115, 58, 105, 97, 109, 58, 58, 49, 50, 51, 58, 114, 111, 108, 101,
+        47, 114, 160, 6, 70, 168, 6, 24, 42, 4, 10, 2, 48, 1, 74, 8, 10, 6, 160, 6, 70, 168, 6, 24,
+        82, 12, 10, 6, 109, 121, 95, 118, 111, 108, 18, 2, 112, 47, 160, 6, 70, 168, 6, 24,
+    ];
+    let want = || mt::principal::StageInfo {
+        stage_name: "my_stage".to_string(),
+        stage_type: mt::principal::StageType::External,
+        stage_params: mt::principal::StageParams {
+            storage: StorageParams::S3(StorageS3Config {
+                bucket: "my-bucket".to_string(),
+                root: "/data/p/".to_string(),
+                role_arn: "arn:aws:iam::123:role/r".to_string(),
+                ..Default::default()
+            }),
+        },
+        is_temporary: false,
+        file_format_params: mt::principal::FileFormatParams::Parquet(
+            mt::principal::ParquetFileFormatParams {},
+        ),
+        copy_options: mt::principal::CopyOptions::default(),
+        comment: "".to_string(),
+        number_of_files: 0,
+        creator: None,
+        volume_location: Some(VolumeLocation {
+            volume: "my_vol".to_string(),
+            path: "p/".to_string(),
+        }),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), stage_info_v70.as_slice(), 70, want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v70_volume_grant() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 16, 58, 8, 10, 6, 109, 121, 95, 118, 111, 108, 160, 6, 70, 168, 6, 24, 16, 129, 128,
+        16, 160, 6, 70, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::GrantEntry::new(
+            mt::principal::GrantObject::Volume("my_vol".to_string()),
+            make_bitflags!(UserPrivilegeType::{Usage | Read}),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 70, want())
+}
@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use maplit::btreemap;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v73_settings_profile() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 11, 101, 116, 108, 95, 112, 114, 111, 102, 105, 108, 101, 18, 30, 10, 16, 109, 97, 120,
+        95, 109, 101, 109, 111, 114, 121, 95, 117, 115, 97, 103, 101, 18, 10, 49, 48, 55, 51, 55,
+        52, 49, 56, 50, 52, 18, 17, 10, 11, 109, 97, 120, 95, 116, 104, 114, 101, 97, 100, 115, 18,
+        2, 49, 54, 26, 12, 101, 116, 108, 32, 119, 111, 114, 107, 108, 111, 97, 100, 34, 23, 50,
+        48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 160, 6, 73, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::SettingsProfile {
+        name: "etl_profile".to_string(),
+        settings: btreemap! {
+            "max_memory_usage".to_string() => "1073741824".to_string(),
+            "max_threads".to_string() => "16".to_string(),
+        },
+        comment: "etl workload".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 73, want())
+}
+
+#[test]
+fn test_decode_v73_user_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 9, 116, 101, 115, 116, 95, 117, 115, 101, 114, 18, 1, 37, 26, 25, 18, 17, 10, 13, 116,
+        101, 115, 116, 95, 112, 97, 115, 115, 119, 111, 114, 100, 16, 1, 160, 6, 73, 168, 6, 24,
+        34, 26, 10, 18, 10, 8, 10, 0, 160, 6, 73, 168, 6, 24, 16, 2, 160, 6, 73, 168, 6, 24, 160,
+        6, 73, 168, 6, 24, 42, 15, 8, 10, 16, 128, 80, 24, 128, 160, 1, 160, 6, 73, 168, 6, 24, 50,
+        56, 8, 1, 18, 5, 114, 111, 108, 101, 49, 26, 8, 109, 121, 112, 111, 108, 105, 99, 121, 34,
+        16, 109, 121, 112, 97, 115, 115, 119, 111, 114, 100, 112, 111, 108, 105, 99, 121, 42, 11,
+        101, 116, 108, 95, 112, 114, 111, 102, 105, 108, 101, 160, 6, 73, 168, 6, 24, 58, 24, 18,
+        16, 10, 12, 111, 108, 100, 95, 112, 97, 115, 115, 119, 111, 114, 100, 16, 1, 160, 6, 73,
+        168, 6, 24, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48,
+        58, 48, 57, 32, 85, 84, 67, 82, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 73, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::UserInfo {
+        name: "test_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: common_meta_app::principal::AuthInfo::Password {
+            hash_value: [
+                116, 101, 115, 116, 95, 112, 97, 115, 115, 119, 111, 114, 100,
+            ]
+            .to_vec(),
+            hash_method: common_meta_app::principal::PasswordHashMethod::DoubleSha1,
+        },
+        grants: common_meta_app::principal::UserGrantSet::new(
+            vec![common_meta_app::principal::GrantEntry::new(
+                common_meta_app::principal::GrantObject::Global,
+                make_bitflags!(UserPrivilegeType::{Create}),
+            )],
+            HashSet::new(),
+        ),
+        quota: common_meta_app::principal::UserQuota {
+            max_cpu: 10,
+            max_memory_in_bytes: 10240,
+            max_storage_in_bytes: 20480,
+        },
+        option: common_meta_app::principal::UserOption::default()
+            .with_set_flag(common_meta_app::principal::UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".into()))
+            .with_network_policy(Some("mypolicy".to_string()))
+            .with_password_policy(Some("mypasswordpolicy".to_string()))
+            .with_settings_profile(Some("etl_profile".to_string())),
+        history_auth_infos: vec![common_meta_app::principal::AuthInfo::Password {
+            hash_value: [111, 108, 100, 95, 112, 97, 115, 115, 119, 111, 114, 100].to_vec(),
+            hash_method: common_meta_app::principal::PasswordHashMethod::DoubleSha1,
+        }],
+        password_fails: vec![Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()],
+        password_update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        lockout_time: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 73, want())
+}
@@ -79,6 +79,8 @@ pub struct NodeInfo {
     pub version: u32,
     pub flight_address: String,
     pub binary_version: String,
+    /// Labels of the node, e.g. the warehouse of the node, used to route the queries.
+    pub labels: Vec<String>,
 }
 
 impl NodeInfo {
@@ -87,6 +89,7 @@ impl NodeInfo {
         cpu_nums: u64,
         flight_address: String,
         binary_version: String,
+        labels: Vec<String>,
     ) -> NodeInfo {
         NodeInfo {
             id,
@@ -94,9 +97,14 @@ impl NodeInfo {
             version: 0,
             flight_address,
             binary_version,
+            labels,
         }
     }
 
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|l| l == label)
+    }
+
     pub fn ip_port(&self) -> Result<(String, u16), AddrParseError> {
         let addr = SocketAddr::from_str(&self.flight_address)?;
 
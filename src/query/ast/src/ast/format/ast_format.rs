@@ -690,6 +690,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let offset_node = FormatTreeNode::with_children(offset_format_ctx, vec![offset_child]);
             children.push(offset_node);
         }
+        if let Some(into) = &query.into {
+            let location_node =
+                FormatTreeNode::new(AstFormatContext::new(format!("Location {}", into.dst)));
+            let into_node = FormatTreeNode::with_children(
+                AstFormatContext::with_children("INTO".to_string(), 1),
+                vec![location_node],
+            );
+            children.push(into_node);
+        }
 
         let name = "Query".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
@@ -713,6 +722,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
             ExplainKind::AnalyzePlan => "Analyze",
+            ExplainKind::AnalyzeFragments => "AnalyzeFragments",
+            ExplainKind::DryRun => "DryRun",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
         let node = FormatTreeNode::with_children(format_ctx, vec![child]);
@@ -930,6 +941,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowFunctions".to_string());
     }
 
+    fn visit_describe_function(&mut self, name: &'ast Identifier) {
+        let ctx = AstFormatContext::new(format!("FunctionName {}", name));
+        let child = FormatTreeNode::new(ctx);
+
+        let format_ctx = AstFormatContext::with_children("DescribeFunction".to_string(), 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_table_functions(&mut self, show_options: &'ast Option<ShowOptions>) {
         self.visit_show_options(show_options, "ShowTableFunctions".to_string());
     }
@@ -1322,6 +1342,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let engine_node = FormatTreeNode::new(engine_format_ctx);
             children.push(engine_node);
         }
+        if let Some(volume_location) = &stmt.volume_location {
+            let volume_location_name = format!("Location {}", volume_location);
+            let volume_location_format_ctx = AstFormatContext::new(volume_location_name);
+            children.push(FormatTreeNode::new(volume_location_format_ctx));
+        }
         if !stmt.cluster_by.is_empty() {
             let mut cluster_by_children = Vec::with_capacity(stmt.cluster_by.len());
             for cluster_by in stmt.cluster_by.iter() {
@@ -1335,6 +1360,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if let Some(bucket_by) = &stmt.bucket_by {
+            let bucket_by_name = format!("BucketBy {}", bucket_by);
+            let bucket_by_format_ctx = AstFormatContext::new(bucket_by_name);
+            children.push(FormatTreeNode::new(bucket_by_format_ctx));
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1536,6 +1566,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action Add Row Access Policy {} On {}",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop Row Access Policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1764,6 +1812,62 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let columns_name = format!(
+            "Columns {}",
+            stmt.columns
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let columns_child = FormatTreeNode::new(AstFormatContext::new(columns_name));
+        let mut children = vec![index_child, table_child, columns_child];
+        if let Some(tokenizer) = &stmt.tokenizer {
+            let tokenizer_name = format!("Tokenizer {}", tokenizer);
+            children.push(FormatTreeNode::new(AstFormatContext::new(tokenizer_name)));
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_inverted_index(&mut self, stmt: &'ast RefreshInvertedIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index_name);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -1954,6 +2058,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_role(&mut self, stmt: &'ast AlterRoleStmt) {
+        let role_name = format!("Role {}", stmt.role_name);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let child = FormatTreeNode::new(role_format_ctx);
+
+        let name = "AlterRole".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_grant(&mut self, grant: &'ast GrantStmt) {
         let source_child = match &grant.source {
             AccountMgrSource::Role { role } => {
@@ -2237,6 +2352,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let location_format_ctx = AstFormatContext::new(location_name);
             children.push(FormatTreeNode::new(location_format_ctx));
         }
+        if let Some(volume_location) = &stmt.volume_location {
+            let volume_location_name = format!("Location {}", volume_location);
+            let volume_location_format_ctx = AstFormatContext::new(volume_location_name);
+            children.push(FormatTreeNode::new(volume_location_format_ctx));
+        }
         if !stmt.file_format_options.is_empty() {
             let mut file_formats_children = Vec::with_capacity(stmt.file_format_options.len());
             for (k, v) in stmt.file_format_options.iter() {
@@ -2306,6 +2426,54 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_stage(&mut self, stmt: &'ast AlterStageStmt) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
+        let stage_name_child = FormatTreeNode::new(stage_name_format_ctx);
+
+        let action_name = match &stmt.action {
+            AlterStageAction::RenameStage { new_stage_name } => {
+                format!("Action RenameTo {}", new_stage_name)
+            }
+            AlterStageAction::SetComment { comment } => format!("Action SetComment {}", comment),
+        };
+        let action_child = FormatTreeNode::new(AstFormatContext::new(action_name));
+
+        let name = "AlterStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stage_name_child, action_child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_create_stage(&mut self, stage_name: &'ast str) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stage_name));
+        let child = FormatTreeNode::new(stage_name_format_ctx);
+
+        let name = "ShowCreateStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_file_format(&mut self, stmt: &'ast AlterFileFormatStmt) {
+        let name_format_ctx = AstFormatContext::new(format!("FileFormatName {}", stmt.name));
+        let name_child = FormatTreeNode::new(name_format_ctx);
+
+        let action_name = match &stmt.action {
+            AlterFileFormatAction::RenameFileFormat { new_name } => {
+                format!("Action RenameTo {}", new_name)
+            }
+            AlterFileFormatAction::SetComment { comment } => {
+                format!("Action SetComment {}", comment)
+            }
+        };
+        let action_child = FormatTreeNode::new(AstFormatContext::new(action_name));
+
+        let name = "AlterFileFormat".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![name_child, action_child]);
+        self.children.push(node);
+    }
+
     fn visit_remove_stage(&mut self, location: &'ast str, pattern: &'ast str) {
         let location_format_ctx = AstFormatContext::new(format!("Location {}", location));
         let location_child = FormatTreeNode::new(location_format_ctx);
@@ -2471,7 +2639,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
     }
 
     fn visit_show_object_grant_privileges(&mut self, stmt: &'ast ShowObjectGrantPrivilegesStmt) {
-        let object_format_ctx = AstFormatContext::new(format!("Object {}", stmt.object));
+        let object_format_ctx = AstFormatContext::new(format!("Object {:?}", stmt.object));
         let child = FormatTreeNode::new(object_format_ctx);
 
         let name = "ShowObjectGrantPrivileges".to_string();
@@ -2520,6 +2688,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2566,6 +2764,98 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_password_policy(&mut self, stmt: &'ast CreatePasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreatePasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_password_policy(&mut self, stmt: &'ast AlterPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_password_policy(&mut self, stmt: &'ast DropPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_password_policy(&mut self, stmt: &'ast DescPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_password_policies(&mut self) {
+        let ctx = AstFormatContext::new("ShowPasswordPolicies".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_settings_profile(&mut self, stmt: &'ast CreateSettingsProfileStmt) {
+        let ctx = AstFormatContext::new(format!("SettingsProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateSettingsProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_settings_profile(&mut self, stmt: &'ast AlterSettingsProfileStmt) {
+        let ctx = AstFormatContext::new(format!("SettingsProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterSettingsProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_settings_profile(&mut self, stmt: &'ast DropSettingsProfileStmt) {
+        let ctx = AstFormatContext::new(format!("SettingsProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropSettingsProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_settings_profile(&mut self, stmt: &'ast DescSettingsProfileStmt) {
+        let ctx = AstFormatContext::new(format!("SettingsProfileName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescSettingsProfile".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_settings_profiles(&mut self) {
+        let ctx = AstFormatContext::new("ShowSettingsProfiles".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2952,6 +3242,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 location,
                 options,
                 alias,
+                travel_point,
             } => {
                 let mut children = Vec::new();
                 if let Some(files) = &options.files {
@@ -2963,6 +3254,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     let pattern = format!("pattern = {}", pattern);
                     children.push(FormatTreeNode::new(AstFormatContext::new(pattern)))
                 }
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
                 let stage_name = format!("Stage {:?}", location);
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
@@ -2995,6 +3290,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Stream {
+                catalog,
+                database,
+                name,
+            } => {
+                self.visit_table_ref(catalog, database, name);
+                let child = self.children.pop().unwrap();
+                let name = "Stream".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
         }
     }
 
@@ -29,7 +29,6 @@ use crate::ast::CreateTableSource;
 use crate::ast::CreateTableStmt;
 use crate::ast::CreateViewStmt;
 use crate::ast::StreamPoint;
-use crate::ast::TimeTravelPoint;
 
 pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
     RcDoc::text("CREATE")
@@ -71,6 +70,11 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(volume_location) = stmt.volume_location {
+            RcDoc::space().append(RcDoc::text(volume_location.to_string()))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.cluster_by.is_empty() {
             RcDoc::line()
                 .append(RcDoc::text("CLUSTER BY "))
@@ -80,6 +84,11 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(bucket_by) = stmt.bucket_by {
+            RcDoc::line().append(RcDoc::text(bucket_by.to_string()))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -215,10 +224,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             } else {
                 RcDoc::nil()
             }),
-        AlterTableAction::RevertTo { point } => match point {
-            TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
-            TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
-        },
+        AlterTableAction::RevertTo { point } => RcDoc::text(format!(" AT{point}")),
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
             doc = doc.append(RcDoc::text("SET OPTIONS: "));
@@ -227,6 +233,14 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(
+                interweave_comma(columns.into_iter().map(|c| RcDoc::text(c.to_string()))).group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
     }
 }
 
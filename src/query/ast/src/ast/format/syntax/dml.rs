@@ -226,6 +226,13 @@ pub(crate) fn pretty_copy_into_table(copy_stmt: CopyIntoTableStmt) -> RcDoc<'sta
         } else {
             RcDoc::nil()
         })
+        .append(if !copy_stmt.match_by_column_name.is_empty() {
+            RcDoc::line()
+                .append(RcDoc::text("MATCH_BY_COLUMN_NAME = "))
+                .append(RcDoc::text(copy_stmt.match_by_column_name))
+        } else {
+            RcDoc::nil()
+        })
         .append(if copy_stmt.size_limit != 0 {
             RcDoc::line()
                 .append(RcDoc::text("SIZE_LIMIT = "))
@@ -26,11 +26,11 @@ use crate::ast::JoinCondition;
 use crate::ast::JoinOperator;
 use crate::ast::OrderByExpr;
 use crate::ast::Query;
+use crate::ast::QueryInto;
 use crate::ast::SelectTarget;
 use crate::ast::SetExpr;
 use crate::ast::SetOperator;
 use crate::ast::TableReference;
-use crate::ast::TimeTravelPoint;
 use crate::ast::WindowDefinition;
 use crate::ast::With;
 use crate::ast::CTE;
@@ -41,6 +41,7 @@ pub(crate) fn pretty_query(query: Query) -> RcDoc<'static> {
         .append(pretty_order_by(query.order_by))
         .append(pretty_limit(query.limit))
         .append(pretty_offset(query.offset))
+        .append(pretty_into(query.into))
         .group()
 }
 
@@ -343,10 +344,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
-        .append(if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-            RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
-        } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
-            RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        .append(if let Some(travel_point) = travel_point {
+            RcDoc::text(format!(" AT{travel_point}"))
         } else {
             RcDoc::nil()
         })
@@ -442,8 +441,14 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             location,
             options,
             alias,
+            travel_point,
         } => RcDoc::text(location.to_string())
             .append(options.to_string())
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            })
             .append(if let Some(a) = alias {
                 RcDoc::text(format!(" AS {a}"))
             } else {
@@ -508,6 +513,14 @@ fn pretty_offset(offset: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_into(into: Option<QueryInto>) -> RcDoc<'static> {
+    if let Some(into) = into {
+        RcDoc::line().append(RcDoc::text(into.to_string()))
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_order_by_expr(order_by_expr: OrderByExpr) -> RcDoc<'static> {
     RcDoc::text(order_by_expr.expr.to_string())
         .append(if let Some(asc) = order_by_expr.asc {
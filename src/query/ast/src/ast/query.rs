@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -20,6 +21,7 @@ use common_exception::Span;
 use super::Lambda;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_dot_separated_list;
+use crate::ast::CopyIntoLocationOption;
 use crate::ast::Expr;
 use crate::ast::FileLocation;
 use crate::ast::Hint;
@@ -43,11 +45,66 @@ pub struct Query {
     pub limit: Vec<Expr>,
     // `OFFSET` expr
     pub offset: Option<Expr>,
+    // `INTO <location>` clause, only allowed in the top level query
+    pub into: Option<QueryInto>,
 
     // If ignore the result (not output).
     pub ignore_result: bool,
 }
 
+/// The `INTO <location>` clause of a query, which exports the result to the location
+/// the same way as `COPY INTO <location> FROM (<query>)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryInto {
+    pub span: Span,
+    pub dst: FileLocation,
+    pub file_format: BTreeMap<String, String>,
+    pub single: bool,
+    pub max_file_size: usize,
+    pub file_name_template: Option<String>,
+}
+
+impl QueryInto {
+    pub fn apply_option(&mut self, opt: CopyIntoLocationOption) {
+        match opt {
+            CopyIntoLocationOption::FileFormat(v) => self.file_format = v,
+            CopyIntoLocationOption::Single(v) => self.single = v,
+            CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
+            CopyIntoLocationOption::FileNameTemplate(v) => self.file_name_template = Some(v),
+        }
+    }
+}
+
+impl Display for QueryInto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "INTO {}", self.dst)?;
+
+        let mut options = vec![];
+        if !self.file_format.is_empty() {
+            let file_format = self
+                .file_format
+                .iter()
+                .map(|(k, v)| format!("{k} = '{v}'"))
+                .collect::<Vec<_>>();
+            options.push(format!("FILE_FORMAT => ({})", file_format.join(", ")));
+        }
+        if self.single {
+            options.push("SINGLE => true".to_string());
+        }
+        if self.max_file_size != 0 {
+            options.push(format!("MAX_FILE_SIZE => {}", self.max_file_size));
+        }
+        if let Some(file_name_template) = &self.file_name_template {
+            options.push(format!("FILE_NAME_TEMPLATE => '{file_name_template}'"));
+        }
+        if !options.is_empty() {
+            write!(f, " ({})", options.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct With {
     pub span: Span,
@@ -243,6 +300,12 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    /// The snapshot which the stream is created at.
+    Stream {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        name: Identifier,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -300,6 +363,7 @@ pub enum TableReference {
         location: FileLocation,
         options: SelectStageOptions,
         alias: Option<TableAlias>,
+        travel_point: Option<TimeTravelPoint>,
     },
 }
 
@@ -383,6 +447,7 @@ impl SetExpr {
                 order_by: vec![],
                 limit: vec![],
                 offset: None,
+                into: None,
                 ignore_result: false,
             },
         }
@@ -462,12 +527,8 @@ impl Display for TableReference {
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
 
-                if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-                    write!(f, " AT (SNAPSHOT => {sid})")?;
-                }
-
-                if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
-                    write!(f, " AT (TIMESTAMP => {ts})")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
                 }
 
                 if let Some(alias) = alias {
@@ -574,11 +635,15 @@ impl Display for TableReference {
                 location,
                 options,
                 alias,
+                travel_point,
             } => {
                 write!(f, "{location}")?;
                 if !options.is_empty() {
                     write!(f, "{options}")?;
                 }
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -792,6 +857,11 @@ impl Display for Query {
             write!(f, " OFFSET {offset}")?;
         }
 
+        // INTO clause
+        if let Some(into) = &self.into {
+            write!(f, " {into}")?;
+        }
+
         Ok(())
     }
 }
@@ -805,6 +875,18 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Stream {
+                catalog,
+                database,
+                name,
+            } => {
+                write!(f, " (STREAM => ")?;
+                write_dot_separated_list(
+                    f,
+                    catalog.iter().chain(database.iter()).chain(Some(name)),
+                )?;
+                write!(f, ")")?;
+            }
         }
 
         Ok(())
@@ -26,6 +26,7 @@ pub struct CreateConnectionStmt {
     pub name: Identifier,
     pub storage_type: String,
     pub storage_params: BTreeMap<String, String>,
+    pub comment: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -53,6 +54,9 @@ impl Display for CreateConnectionStmt {
         for (k, v) in &self.storage_params {
             write!(f, "{} = {}", k, mask_string(v, 3))?;
         }
+        if !self.comment.is_empty() {
+            write!(f, " COMMENT = '{}'", self.comment)?;
+        }
         Ok(())
     }
 }
@@ -103,6 +103,9 @@ pub struct CopyIntoTableStmt {
     pub disable_variant_check: bool,
     pub return_failed_only: bool,
     pub on_error: String,
+    /// TODO: parse into match_by_column_name directly.
+    pub match_by_column_name: String,
+    pub error_on_column_count_mismatch: bool,
 }
 
 impl CopyIntoTableStmt {
@@ -120,6 +123,10 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyIntoTableOption::ReturnFailedOnly(v) => self.return_failed_only = v,
             CopyIntoTableOption::OnError(v) => self.on_error = v,
+            CopyIntoTableOption::MatchByColumnName(v) => self.match_by_column_name = v,
+            CopyIntoTableOption::ErrorOnColumnCountMismatch(v) => {
+                self.error_on_column_count_mismatch = v
+            }
         }
     }
 
@@ -203,6 +210,14 @@ impl Display for CopyIntoTableStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = '{}'", self.on_error)?;
 
+        if !self.match_by_column_name.is_empty() {
+            write!(f, " MATCH_BY_COLUMN_NAME = {}", self.match_by_column_name)?;
+        }
+
+        if self.error_on_column_count_mismatch {
+            write!(f, " ERROR_ON_COLUMN_COUNT_MISMATCH = true")?;
+        }
+
         Ok(())
     }
 }
@@ -216,6 +231,7 @@ pub struct CopyIntoLocationStmt {
     pub file_format: BTreeMap<String, String>,
     pub single: bool,
     pub max_file_size: usize,
+    pub file_name_template: Option<String>,
 }
 
 impl Display for CopyIntoLocationStmt {
@@ -234,6 +250,9 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " SINGLE = {}", self.single)?;
         write!(f, " MAX_FILE_SIZE= {}", self.max_file_size)?;
+        if let Some(file_name_template) = &self.file_name_template {
+            write!(f, " FILE_NAME_TEMPLATE = '{}'", file_name_template)?;
+        }
 
         Ok(())
     }
@@ -245,6 +264,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::FileFormat(v) => self.file_format = v,
             CopyIntoLocationOption::Single(v) => self.single = v,
             CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
+            CopyIntoLocationOption::FileNameTemplate(v) => self.file_name_template = Some(v),
         }
     }
 }
@@ -484,10 +504,13 @@ pub enum CopyIntoTableOption {
     DisableVariantCheck(bool),
     ReturnFailedOnly(bool),
     OnError(String),
+    MatchByColumnName(String),
+    ErrorOnColumnCountMismatch(bool),
 }
 
 pub enum CopyIntoLocationOption {
     FileFormat(BTreeMap<String, String>),
     MaxFileSize(usize),
+    FileNameTemplate(String),
     Single(bool),
 }
@@ -98,6 +98,7 @@ pub struct DropDatabaseStmt {
     pub if_exists: bool,
     pub catalog: Option<Identifier>,
     pub database: Identifier,
+    pub cascade: bool,
 }
 
 impl Display for DropDatabaseStmt {
@@ -107,6 +108,9 @@ impl Display for DropDatabaseStmt {
             write!(f, "IF EXISTS ")?;
         }
         write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        if self.cascade {
+            write!(f, " CASCADE")?;
+        }
 
         Ok(())
     }
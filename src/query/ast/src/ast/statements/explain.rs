@@ -30,4 +30,9 @@ pub enum ExplainKind {
 
     // Explain analyze plan
     AnalyzePlan,
+    // Explain analyze fragments, with the data moved by the exchanges
+    AnalyzeFragments,
+
+    // Report what a mutating statement would change, without executing it
+    DryRun,
 }
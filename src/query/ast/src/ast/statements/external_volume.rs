@@ -0,0 +1,121 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_base::base::mask_string;
+
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateExternalVolumeStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub options: BTreeMap<String, String>,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterExternalVolumeStmt {
+    pub name: Identifier,
+    pub options: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropExternalVolumeStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescribeExternalVolumeStmt {
+    pub name: Identifier,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowExternalVolumesStmt {}
+
+/// A location on an external volume, e.g. `VOLUME = vol PATH = 'path/to/dir/'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeLocation {
+    pub volume: Identifier,
+    pub path: String,
+}
+
+fn write_volume_options(f: &mut Formatter, options: &BTreeMap<String, String>) -> std::fmt::Result {
+    write!(f, "(")?;
+    for (i, (k, v)) in options.iter().enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{} = '{}'", k, mask_string(v, 3))?;
+    }
+    write!(f, ")")
+}
+
+impl Display for CreateExternalVolumeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE EXTERNAL VOLUME ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} ", self.name)?;
+        write_volume_options(f, &self.options)?;
+        if !self.comment.is_empty() {
+            write!(f, " COMMENT = '{}'", self.comment)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for AlterExternalVolumeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER EXTERNAL VOLUME {} SET ", self.name)?;
+        write_volume_options(f, &self.options)
+    }
+}
+
+impl Display for DropExternalVolumeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP EXTERNAL VOLUME ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Display for DescribeExternalVolumeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE EXTERNAL VOLUME {}", self.name)
+    }
+}
+
+impl Display for ShowExternalVolumesStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW EXTERNAL VOLUMES")
+    }
+}
+
+impl Display for VolumeLocation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VOLUME = {}", self.volume)?;
+        if !self.path.is_empty() {
+            write!(f, " PATH = '{}'", self.path)?;
+        }
+        Ok(())
+    }
+}
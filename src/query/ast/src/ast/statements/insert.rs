@@ -32,6 +32,8 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    /// The statement is committed at most once for the same token.
+    pub idempotent_token: Option<String>,
 }
 
 impl Display for InsertStmt {
@@ -57,6 +59,9 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
+        if let Some(token) = &self.idempotent_token {
+            write!(f, " IDEMPOTENT_TOKEN = '{token}'")?;
+        }
         write!(f, " {}", self.source)
     }
 }
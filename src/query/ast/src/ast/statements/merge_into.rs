@@ -28,6 +28,7 @@ use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::TableAlias;
 use crate::ast::TableReference;
+use crate::ast::TimeTravelPoint;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct MergeUpdateExpr {
@@ -218,6 +219,7 @@ pub enum MergeSource {
         database: Option<Identifier>,
         table: Identifier,
         alias: Option<TableAlias>,
+        travel_point: Option<TimeTravelPoint>,
     },
 }
 
@@ -251,13 +253,14 @@ impl MergeSource {
                 database,
                 table,
                 alias,
+                travel_point,
             } => TableReference::Table {
                 span: None,
                 catalog: catalog.clone(),
                 database: database.clone(),
                 table: table.clone(),
                 alias: alias.clone(),
-                travel_point: None,
+                travel_point: travel_point.clone(),
                 pivot: None,
                 unpivot: None,
             },
@@ -293,11 +296,15 @@ impl Display for MergeSource {
                 database,
                 table,
                 alias,
+                travel_point,
             } => {
                 write_dot_separated_list(
                     f,
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
                 if alias.is_some() {
                     write!(f, " AS {}", alias.as_ref().unwrap())?;
                 }
@@ -21,15 +21,19 @@ mod data_mask;
 mod database;
 mod delete;
 mod explain;
+mod external_volume;
 mod hint;
 mod index;
 mod insert;
 mod kill;
 mod merge_into;
 mod network_policy;
+mod password_policy;
 mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
+mod settings_profile;
 mod share;
 mod show;
 mod stage;
@@ -53,15 +57,19 @@ pub use data_mask::*;
 pub use database::*;
 pub use delete::*;
 pub use explain::*;
+pub use external_volume::*;
 pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
 pub use merge_into::*;
 pub use network_policy::*;
+pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
+pub use settings_profile::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
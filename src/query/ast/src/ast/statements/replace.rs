@@ -32,6 +32,8 @@ pub struct ReplaceStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub delete_when: Option<Expr>,
+    /// The statement is committed at most once for the same token.
+    pub idempotent_token: Option<String>,
 }
 
 impl Display for ReplaceStmt {
@@ -62,6 +64,9 @@ impl Display for ReplaceStmt {
         if let Some(expr) = &self.delete_when {
             write!(f, "DELETE WHEN {expr} ")?;
         }
+        if let Some(token) = &self.idempotent_token {
+            write!(f, "IDEMPOTENT_TOKEN = '{token}' ")?;
+        }
 
         write!(f, "{}", self.source)
     }
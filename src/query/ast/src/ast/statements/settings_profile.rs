@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_comma_separated_map;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateSettingsProfileStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub settings: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateSettingsProfileStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE SETTINGS PROFILE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.settings.is_empty() {
+            write!(f, " SETTINGS = (")?;
+            write_comma_separated_map(f, &self.settings)?;
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterSettingsProfileStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterSettingsProfileAction,
+}
+
+impl Display for AlterSettingsProfileStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER SETTINGS PROFILE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} {}", self.name, self.action)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterSettingsProfileAction {
+    // The settings are added to the profile, or replace the ones already in it.
+    Set {
+        settings: BTreeMap<String, String>,
+        comment: Option<String>,
+    },
+    Unset {
+        settings: Vec<String>,
+        comment: bool,
+    },
+}
+
+impl Display for AlterSettingsProfileAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterSettingsProfileAction::Set { settings, comment } => {
+                write!(f, "SET")?;
+                if !settings.is_empty() {
+                    write!(f, " SETTINGS = (")?;
+                    write_comma_separated_map(f, settings)?;
+                    write!(f, ")")?;
+                }
+                if let Some(comment) = comment {
+                    write!(f, " COMMENT = '{}'", comment)?;
+                }
+            }
+            AlterSettingsProfileAction::Unset { settings, comment } => {
+                write!(f, "UNSET")?;
+                if !settings.is_empty() {
+                    write!(f, " SETTINGS (")?;
+                    write_comma_separated_list(f, settings)?;
+                    write!(f, ")")?;
+                }
+                if *comment {
+                    write!(f, " COMMENT")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropSettingsProfileStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropSettingsProfileStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP SETTINGS PROFILE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescSettingsProfileStmt {
+    pub name: String,
+}
+
+impl Display for DescSettingsProfileStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE SETTINGS PROFILE {}", self.name)?;
+
+        Ok(())
+    }
+}
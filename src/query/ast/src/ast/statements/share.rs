@@ -20,6 +20,7 @@ use common_meta_app::share::ShareGrantObjectName;
 use common_meta_app::share::ShareGrantObjectPrivilege;
 use itertools::Itertools;
 
+use super::AccountMgrLevel;
 use super::UriLocation;
 use crate::ast::Identifier;
 
@@ -211,12 +212,31 @@ impl Display for DropShareEndpointStmt {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ShowObjectGrantPrivilegesStmt {
-    pub object: ShareGrantObjectName,
+    pub object: AccountMgrLevel,
 }
 
 impl Display for ShowObjectGrantPrivilegesStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "SHOW GRANTS ON {}", self.object)?;
+        write!(f, "SHOW GRANTS ON ")?;
+        match &self.object {
+            AccountMgrLevel::Global => write!(f, "*.*")?,
+            AccountMgrLevel::Database(database) => {
+                write!(f, "DATABASE {}", database.as_deref().unwrap_or_default())?
+            }
+            AccountMgrLevel::Table(database, table) => {
+                write!(f, "TABLE ")?;
+                if let Some(database) = database {
+                    write!(f, "{database}.")?;
+                }
+                write!(f, "{table}")?
+            }
+            AccountMgrLevel::FutureTables(database) => {
+                write!(f, "FUTURE TABLES IN DATABASE {database}")?
+            }
+            AccountMgrLevel::UDF(udf) => write!(f, "UDF {udf}")?,
+            AccountMgrLevel::Stage(stage) => write!(f, "STAGE {stage}")?,
+            AccountMgrLevel::Volume(volume) => write!(f, "EXTERNAL VOLUME {volume}")?,
+        }
 
         Ok(())
     }
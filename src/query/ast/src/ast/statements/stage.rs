@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_quoted_list;
 use crate::ast::UriLocation;
+use crate::ast::VolumeLocation;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateStageStmt {
@@ -27,6 +28,7 @@ pub struct CreateStageStmt {
     pub stage_name: String,
 
     pub location: Option<UriLocation>,
+    pub volume_location: Option<VolumeLocation>,
 
     pub file_format_options: BTreeMap<String, String>,
     pub on_error: String,
@@ -47,6 +49,10 @@ impl Display for CreateStageStmt {
             write!(f, " {ul}")?;
         }
 
+        if let Some(vl) = &self.volume_location {
+            write!(f, " {vl}")?;
+        }
+
         if !self.file_format_options.is_empty() {
             write!(f, " FILE_FORMAT = (")?;
             write_comma_separated_map(f, &self.file_format_options)?;
@@ -73,6 +79,72 @@ impl Display for CreateStageStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterStageStmt {
+    pub if_exists: bool,
+    pub stage_name: String,
+    pub action: AlterStageAction,
+}
+
+impl Display for AlterStageStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER STAGE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.stage_name)?;
+        match &self.action {
+            AlterStageAction::RenameStage { new_stage_name } => {
+                write!(f, " RENAME TO {new_stage_name}")?;
+            }
+            AlterStageAction::SetComment { comment } => {
+                write!(f, " SET COMMENT = '{comment}'")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterStageAction {
+    RenameStage { new_stage_name: String },
+    SetComment { comment: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterFileFormatStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterFileFormatAction,
+}
+
+impl Display for AlterFileFormatStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER FILE FORMAT ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        match &self.action {
+            AlterFileFormatAction::RenameFileFormat { new_name } => {
+                write!(f, " RENAME TO {new_name}")?;
+            }
+            AlterFileFormatAction::SetComment { comment } => {
+                write!(f, " SET COMMENT = '{comment}'")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterFileFormatAction {
+    RenameFileFormat { new_name: String },
+    SetComment { comment: String },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectStageOption {
     Files(Vec<String>),
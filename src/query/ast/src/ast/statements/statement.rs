@@ -61,6 +61,9 @@ pub enum Statement {
     ShowFunctions {
         show_options: Option<ShowOptions>,
     },
+    DescribeFunction {
+        name: Identifier,
+    },
     ShowTableFunctions {
         show_options: Option<ShowOptions>,
     },
@@ -150,6 +153,9 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
+    RefreshInvertedIndex(RefreshInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -174,6 +180,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -198,6 +205,10 @@ pub enum Statement {
     DescribeStage {
         stage_name: String,
     },
+    AlterStage(AlterStageStmt),
+    ShowCreateStage {
+        stage_name: String,
+    },
     RemoveStage {
         location: String,
         pattern: String,
@@ -212,12 +223,21 @@ pub enum Statement {
     DescribeConnection(DescribeConnectionStmt),
     ShowConnections(ShowConnectionsStmt),
 
+    // ExternalVolume
+    CreateExternalVolume(CreateExternalVolumeStmt),
+    AlterExternalVolume(AlterExternalVolumeStmt),
+    DropExternalVolume(DropExternalVolumeStmt),
+    DescribeExternalVolume(DescribeExternalVolumeStmt),
+    ShowExternalVolumes(ShowExternalVolumesStmt),
+
     // UserDefinedFileFormat
     CreateFileFormat {
         if_not_exists: bool,
         name: String,
         file_format_options: FileFormatOptionsAst,
+        comment: String,
     },
+    AlterFileFormat(AlterFileFormatStmt),
     DropFileFormat {
         if_exists: bool,
         name: String,
@@ -244,6 +264,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // Row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -251,6 +276,20 @@ pub enum Statement {
     DescNetworkPolicy(DescNetworkPolicyStmt),
     ShowNetworkPolicies,
 
+    // password policy
+    CreatePasswordPolicy(CreatePasswordPolicyStmt),
+    AlterPasswordPolicy(AlterPasswordPolicyStmt),
+    DropPasswordPolicy(DropPasswordPolicyStmt),
+    DescPasswordPolicy(DescPasswordPolicyStmt),
+    ShowPasswordPolicies,
+
+    // settings profile
+    CreateSettingsProfile(CreateSettingsProfileStmt),
+    AlterSettingsProfile(AlterSettingsProfileStmt),
+    DropSettingsProfile(DropSettingsProfileStmt),
+    DescSettingsProfile(DescSettingsProfileStmt),
+    ShowSettingsProfiles,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -325,8 +364,10 @@ impl Display for Statement {
                     ExplainKind::Optimized => write!(f, " Optimized")?,
                     ExplainKind::Plan => (),
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
+                    ExplainKind::AnalyzeFragments => write!(f, " ANALYZE FRAGMENTS")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::DryRun => write!(f, " DRYRUN")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -377,6 +418,7 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::DescribeFunction { name } => write!(f, "DESCRIBE FUNCTION {name}")?,
             Statement::ShowTableFunctions { show_options } => {
                 write!(f, "SHOW TABLE_FUNCTIONS")?;
                 if let Some(show_options) = show_options {
@@ -463,6 +505,9 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -498,6 +543,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants { principal } => {
                 write!(f, "SHOW GRANTS")?;
@@ -544,10 +590,15 @@ impl Display for Statement {
                 }
             }
             Statement::DescribeStage { stage_name } => write!(f, "DESC STAGE {stage_name}")?,
+            Statement::AlterStage(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowCreateStage { stage_name } => {
+                write!(f, "SHOW CREATE STAGE {stage_name}")?
+            }
             Statement::CreateFileFormat {
                 if_not_exists,
                 name,
                 file_format_options,
+                comment,
             } => {
                 write!(f, "CREATE FILE_FORMAT")?;
                 if *if_not_exists {
@@ -555,7 +606,11 @@ impl Display for Statement {
                 }
                 write!(f, " {name}")?;
                 write!(f, " {file_format_options}")?;
+                if !comment.is_empty() {
+                    write!(f, " COMMENT = '{comment}'")?;
+                }
             }
+            Statement::AlterFileFormat(stmt) => write!(f, "{stmt}")?,
             Statement::DropFileFormat { if_exists, name } => {
                 write!(f, "DROP FILE_FORMAT")?;
                 if *if_exists {
@@ -581,11 +636,24 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowNetworkPolicies => write!(f, "SHOW NETWORK POLICIES")?,
+            Statement::CreatePasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowPasswordPolicies => write!(f, "SHOW PASSWORD POLICIES")?,
+            Statement::CreateSettingsProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterSettingsProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSettingsProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::DescSettingsProfile(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSettingsProfiles => write!(f, "SHOW SETTINGS PROFILES")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -600,6 +668,11 @@ impl Display for Statement {
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
             Statement::ShowConnections(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateExternalVolume(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterExternalVolume(stmt) => write!(f, "{stmt}")?,
+            Statement::DropExternalVolume(stmt) => write!(f, "{stmt}")?,
+            Statement::DescribeExternalVolume(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowExternalVolumes(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
@@ -28,6 +28,7 @@ use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
 use crate::ast::UriLocation;
+use crate::ast::VolumeLocation;
 
 #[derive(Debug, Clone, PartialEq)] // Tables
 pub struct ShowTablesStmt {
@@ -132,7 +133,9 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub volume_location: Option<VolumeLocation>,
     pub cluster_by: Vec<Expr>,
+    pub bucket_by: Option<BucketBy>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -164,6 +167,10 @@ impl Display for CreateTableStmt {
             write!(f, " {uri_location}")?;
         }
 
+        if let Some(volume_location) = &self.volume_location {
+            write!(f, " {volume_location}")?;
+        }
+
         if let Some(engine) = &self.engine {
             write!(f, " ENGINE = {engine}")?;
         }
@@ -174,6 +181,10 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if let Some(bucket_by) = &self.bucket_by {
+            write!(f, " {bucket_by}")?;
+        }
+
         // Format table options
         write_comma_separated_map(f, &self.table_options)?;
         if let Some(as_query) = &self.as_query {
@@ -184,6 +195,20 @@ impl Display for CreateTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct BucketBy {
+    pub columns: Vec<Identifier>,
+    pub num_buckets: u64,
+}
+
+impl Display for BucketBy {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "BUCKET BY (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ") INTO {} BUCKETS", self.num_buckets)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AttachTableStmt {
     pub catalog: Option<Identifier>,
@@ -365,6 +390,13 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -419,6 +451,14 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
@@ -692,19 +732,27 @@ impl Display for VacuumDropTableOption {
 #[derive(Debug, Clone, PartialEq)]
 pub enum OptimizeTableAction {
     All,
-    Purge { before: Option<TimeTravelPoint> },
-    Compact { target: CompactTarget },
+    Purge {
+        before: Option<TimeTravelPoint>,
+        force: bool,
+    },
+    Compact {
+        target: CompactTarget,
+    },
 }
 
 impl Display for OptimizeTableAction {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             OptimizeTableAction::All => write!(f, "ALL"),
-            OptimizeTableAction::Purge { before } => {
+            OptimizeTableAction::Purge { before, force } => {
                 write!(f, "PURGE")?;
                 if let Some(point) = before {
                     write!(f, " BEFORE {}", point)?;
                 }
+                if *force {
+                    write!(f, " FORCE")?;
+                }
                 Ok(())
             }
             OptimizeTableAction::Compact { target } => {
@@ -101,10 +101,40 @@ impl Display for AlterUserStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterRoleStmt {
+    pub role_name: String,
+    pub action: AlterRoleAction,
+}
+
+impl Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER ROLE '{}' {}", self.role_name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterRoleAction {
+    SetSettingsProfile(String),
+    UnsetSettingsProfile,
+}
+
+impl Display for AlterRoleAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterRoleAction::SetSettingsProfile(v) => write!(f, "SET PROFILE = '{}'", v),
+            AlterRoleAction::UnsetSettingsProfile => write!(f, "UNSET PROFILE"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GrantStmt {
     pub source: AccountMgrSource,
     pub principal: PrincipalIdentity,
+    /// `GRANT OWNERSHIP ... REVOKE CURRENT GRANTS` revokes all the privileges granted on the
+    /// object before transferring its ownership.
+    pub revoke_current_grants: bool,
 }
 
 impl Display for GrantStmt {
@@ -113,7 +143,11 @@ impl Display for GrantStmt {
         write!(f, "{}", self.source)?;
 
         write!(f, " TO")?;
-        write!(f, "{}", self.principal)
+        write!(f, "{}", self.principal)?;
+        if self.revoke_current_grants {
+            write!(f, " REVOKE CURRENT GRANTS")?;
+        }
+        Ok(())
     }
 }
 
@@ -152,8 +186,11 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    /// The tables created in the database in the future, e.g. `FUTURE TABLES IN DATABASE db`.
+    FutureTables(String),
     UDF(String),
     Stage(String),
+    Volume(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -168,6 +205,10 @@ pub enum UserOptionItem {
     DefaultRole(String),
     SetNetworkPolicy(String),
     UnsetNetworkPolicy,
+    SetPasswordPolicy(String),
+    UnsetPasswordPolicy,
+    SetSettingsProfile(String),
+    UnsetSettingsProfile,
 }
 
 impl UserOptionItem {
@@ -179,6 +220,10 @@ impl UserOptionItem {
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
             Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
             Self::UnsetNetworkPolicy => option.set_network_policy(None),
+            Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
+            Self::UnsetPasswordPolicy => option.set_password_policy(None),
+            Self::SetSettingsProfile(v) => option.set_settings_profile(Some(v.clone())),
+            Self::UnsetSettingsProfile => option.set_settings_profile(None),
         }
     }
 }
@@ -207,8 +252,12 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::FutureTables(database_name) => {
+                        write!(f, " FUTURE TABLES IN DATABASE {database_name}")?
+                    }
                     AccountMgrLevel::UDF(udf) => write!(f, " UDF {udf}")?,
                     AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}")?,
+                    AccountMgrLevel::Volume(volume) => write!(f, " EXTERNAL VOLUME {volume}")?,
                 }
             }
             AccountMgrSource::ALL { level, .. } => {
@@ -230,8 +279,12 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::FutureTables(database_name) => {
+                        write!(f, " FUTURE TABLES IN DATABASE {database_name}")?
+                    }
                     AccountMgrLevel::UDF(udf) => write!(f, " UDF {udf}")?,
                     AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}")?,
+                    AccountMgrLevel::Volume(volume) => write!(f, " EXTERNAL VOLUME {volume}")?,
                 }
             }
         }
@@ -247,6 +300,10 @@ impl Display for UserOptionItem {
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
             UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
             UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
+            UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
+            UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
+            UserOptionItem::SetSettingsProfile(v) => write!(f, "SET PROFILE = '{}'", v),
+            UserOptionItem::UnsetSettingsProfile => write!(f, "UNSET PROFILE"),
         }
     }
 }
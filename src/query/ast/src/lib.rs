@@ -29,7 +29,9 @@ pub use visitors::walk_query;
 pub use visitors::walk_query_mut;
 pub use visitors::walk_select_target;
 pub use visitors::walk_select_target_mut;
+pub use visitors::walk_statement;
 pub use visitors::walk_statement_mut;
+pub use visitors::walk_table_reference;
 pub use visitors::walk_table_reference_mut;
 pub use visitors::Visitor;
 pub use visitors::VisitorMut;
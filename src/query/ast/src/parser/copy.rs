@@ -79,6 +79,8 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
                 return_failed_only: Default::default(),
+                match_by_column_name: Default::default(),
+                error_on_column_count_mismatch: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -112,6 +114,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
                 file_format: Default::default(),
                 single: Default::default(),
                 max_file_size: Default::default(),
+                file_name_template: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -134,6 +137,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
                 [ VALIDATION_MODE = RETURN_ROWS ]
+                [ MATCH_BY_COLUMN_NAME = { CASE_SENSITIVE | CASE_INSENSITIVE | NONE } ]
                 [ copyOptions ]`"
     )(i)
 }
@@ -186,6 +190,18 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             rule! { RETURN_FAILED_ONLY ~ "=" ~ #literal_bool },
             |(_, _, return_failed_only)| CopyIntoTableOption::ReturnFailedOnly(return_failed_only),
         ),
+        map(
+            rule! { MATCH_BY_COLUMN_NAME ~ "=" ~ #ident },
+            |(_, _, match_by_column_name)| {
+                CopyIntoTableOption::MatchByColumnName(match_by_column_name.to_string())
+            },
+        ),
+        map(
+            rule! { ERROR_ON_COLUMN_COUNT_MISMATCH ~ "=" ~ #literal_bool },
+            |(_, _, error_on_column_count_mismatch)| {
+                CopyIntoTableOption::ErrorOnColumnCountMismatch(error_on_column_count_mismatch)
+            },
+        ),
     ))(i)
 }
 
@@ -198,6 +214,12 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! { MAX_FILE_SIZE ~ "=" ~ #literal_u64 },
             |(_, _, max_file_size)| CopyIntoLocationOption::MaxFileSize(max_file_size as usize),
         ),
+        map(
+            rule! { FILE_NAME_TEMPLATE ~ "=" ~ #literal_string },
+            |(_, _, file_name_template)| {
+                CopyIntoLocationOption::FileNameTemplate(file_name_template)
+            },
+        ),
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
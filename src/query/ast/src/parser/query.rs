@@ -23,6 +23,7 @@ use pratt::PrattParser;
 use pratt::Precedence;
 
 use super::stage::file_location;
+use super::stage::format_options;
 use super::stage::select_stage_option;
 use crate::ast::*;
 use crate::input::Input;
@@ -75,6 +76,7 @@ pub enum SetOperationElement {
     Offset {
         offset: Expr,
     },
+    Into(QueryInto),
     IgnoreResult,
     Group(SetExpr),
 }
@@ -169,6 +171,26 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         },
         |(_, offset)| SetOperationElement::Offset { offset },
     );
+    let into = map(
+        consumed(rule! {
+            INTO ~ ^#file_location
+            ~ ( "(" ~ ^#comma_separated_list1(query_into_option) ~ ^")" )?
+        }),
+        |(span, (_, dst, opt_options))| {
+            let mut into = QueryInto {
+                span: transform_span(span.0),
+                dst,
+                file_format: Default::default(),
+                single: Default::default(),
+                max_file_size: Default::default(),
+                file_name_template: Default::default(),
+            };
+            for opt in opt_options.map(|(_, opts, _)| opts).unwrap_or_default() {
+                into.apply_option(opt);
+            }
+            SetOperationElement::Into(into)
+        },
+    );
     let ignore_result = map(
         rule! {
             IGNORE_RESULT
@@ -191,6 +213,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         | #order_by
         | #limit
         | #offset
+        | #into
         | #ignore_result
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
@@ -217,6 +240,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
             SetOperationElement::OrderBy { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Limit { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Offset { .. } => Affix::Postfix(Precedence(5)),
+            SetOperationElement::Into(_) => Affix::Postfix(Precedence(5)),
             SetOperationElement::IgnoreResult => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
@@ -305,6 +329,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if query.offset.is_some() {
                     return Err("ORDER BY must appear before OFFSET");
                 }
+                if query.into.is_some() {
+                    return Err("ORDER BY must appear before INTO");
+                }
                 query.order_by = order_by;
             }
             SetOperationElement::Limit { limit } => {
@@ -317,6 +344,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if query.offset.is_some() {
                     return Err("LIMIT must appear before OFFSET");
                 }
+                if query.into.is_some() {
+                    return Err("LIMIT must appear before INTO");
+                }
                 query.limit = limit;
             }
             SetOperationElement::Offset { offset } => {
@@ -326,8 +356,17 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if query.offset.is_some() {
                     return Err("duplicated OFFSET clause");
                 }
+                if query.into.is_some() {
+                    return Err("OFFSET must appear before INTO");
+                }
                 query.offset = Some(offset);
             }
+            SetOperationElement::Into(into) => {
+                if query.into.is_some() {
+                    return Err("duplicated INTO clause");
+                }
+                query.into = Some(into);
+            }
             SetOperationElement::IgnoreResult => {
                 query.ignore_result = true;
             }
@@ -337,6 +376,29 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
     }
 }
 
+fn query_into_option(i: Input) -> IResult<CopyIntoLocationOption> {
+    alt((
+        map(
+            rule! { FILE_FORMAT ~ ^"=>" ~ ^"(" ~ ^#format_options ~ ^")" },
+            |(_, _, _, options, _)| CopyIntoLocationOption::FileFormat(options),
+        ),
+        map(
+            rule! { SINGLE ~ ^"=>" ~ ^#literal_bool },
+            |(_, _, single)| CopyIntoLocationOption::Single(single),
+        ),
+        map(
+            rule! { MAX_FILE_SIZE ~ ^"=>" ~ ^#literal_u64 },
+            |(_, _, max_file_size)| CopyIntoLocationOption::MaxFileSize(max_file_size as usize),
+        ),
+        map(
+            rule! { FILE_NAME_TEMPLATE ~ ^"=>" ~ ^#literal_string },
+            |(_, _, file_name_template)| {
+                CopyIntoLocationOption::FileNameTemplate(file_name_template)
+            },
+        ),
+    ))(i)
+}
+
 pub fn row_values(i: Input) -> IResult<Vec<Expr>> {
     map(
         rule! {"(" ~ #comma_separated_list1(expr) ~ ")"},
@@ -506,9 +568,17 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_stream = map(
+        rule! { "(" ~ STREAM ~ "=>" ~ #dot_separated_idents_1_to_3 ~ ")" },
+        |(_, _, _, (catalog, database, name), _)| TimeTravelPoint::Stream {
+            catalog,
+            database,
+            name,
+        },
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_stream
     )(i)
 }
 
@@ -657,6 +727,7 @@ pub enum TableReferenceElement {
         location: FileLocation,
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
+        travel_point: Option<TimeTravelPoint>,
     },
 }
 
@@ -750,9 +821,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_stage = map(
         rule! {
-            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ #table_alias?
+            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ (AT ~ ^#travel_point)? ~ #table_alias?
         },
-        |(location, options, alias)| {
+        |(location, options, travel_point_opt, alias)| {
             let options = options
                 .map(|(_, options, _)| options.into_iter().map(|(option, _)| option).collect())
                 .unwrap_or_default();
@@ -760,6 +831,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 location,
                 alias,
                 options,
+                travel_point: travel_point_opt.map(|p| p.1),
             }
         },
     );
@@ -859,6 +931,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 location,
                 options,
                 alias,
+                travel_point,
             } => {
                 let options = SelectStageOptions::from(options);
                 TableReference::Location {
@@ -866,6 +939,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     location,
                     options,
                     alias,
+                    travel_point,
                 }
             }
             _ => unreachable!(),
@@ -20,6 +20,7 @@ use nom::combinator::map;
 use crate::ast::FileLocation;
 use crate::ast::SelectStageOption;
 use crate::ast::UriLocation;
+use crate::ast::VolumeLocation;
 use crate::input::Input;
 use crate::parser::expr::*;
 use crate::parser::token::*;
@@ -180,6 +181,18 @@ pub fn uri_location(i: Input) -> IResult<UriLocation> {
     })(i)
 }
 
+pub fn volume_location(i: Input) -> IResult<VolumeLocation> {
+    map(
+        rule! {
+            VOLUME ~ ^"=" ~ ^#ident ~ ( PATH ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, volume, opt_path)| VolumeLocation {
+            volume,
+            path: opt_path.map(|(_, _, path)| path).unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn string_location(i: Input) -> IResult<FileLocation> {
     map_res(
         rule! {
@@ -49,7 +49,7 @@ use crate::ErrorKind;
 
 pub enum ShowGrantOption {
     PrincipalIdentity(PrincipalIdentity),
-    ShareGrantObjectName(ShareGrantObjectName),
+    GrantObjectName(AccountMgrLevel),
     ShareName(String),
 }
 
@@ -62,7 +62,7 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO | DRYRUN )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -88,6 +88,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::OPTIMIZED) => ExplainKind::Optimized,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::DRYRUN) => ExplainKind::DryRun,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -97,10 +98,16 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     );
     let explain_analyze = map(
         rule! {
-            EXPLAIN ~ ANALYZE ~ #statement
+            EXPLAIN ~ ANALYZE ~ FRAGMENTS? ~ #statement
         },
-        |(_, _, statement)| Statement::ExplainAnalyze {
-            query: Box::new(statement.stmt),
+        |(_, _, opt_fragments, statement)| match opt_fragments {
+            Some(_) => Statement::Explain {
+                kind: ExplainKind::AnalyzeFragments,
+                query: Box::new(statement.stmt),
+            },
+            None => Statement::ExplainAnalyze {
+                query: Box::new(statement.stmt),
+            },
         },
     );
 
@@ -202,9 +209,19 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #dot_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ #idempotent_token?
             ~ #insert_source
         },
-        |(_, opt_hints, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(
+            _,
+            opt_hints,
+            overwrite,
+            _,
+            (catalog, database, table),
+            opt_columns,
+            idempotent_token,
+            source,
+        )| {
             Statement::Insert(InsertStmt {
                 hints: opt_hints,
                 catalog,
@@ -215,6 +232,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     .unwrap_or_default(),
                 source,
                 overwrite: overwrite.kind == OVERWRITE,
+                idempotent_token,
             })
         },
     );
@@ -226,6 +244,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ (ON ~ CONFLICT? ~ "(" ~ #comma_separated_list1(ident) ~ ")")
             ~ (DELETE ~ WHEN ~ ^#expr)?
+            ~ #idempotent_token?
             ~ #insert_source
         },
         |(
@@ -236,6 +255,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             opt_columns,
             (_, _, _, on_conflict_columns, _),
             opt_delete_when,
+            idempotent_token,
             source,
         )| {
             Statement::Replace(ReplaceStmt {
@@ -249,6 +269,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     .unwrap_or_default(),
                 source,
                 delete_when: opt_delete_when.map(|(_, _, expr)| expr),
+                idempotent_token,
             })
         },
     );
@@ -344,6 +365,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
         |(_, _, show_options)| Statement::ShowFunctions { show_options },
     );
+    let describe_function = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ FUNCTION ~ #ident
+        },
+        |(_, _, name)| Statement::DescribeFunction { name },
+    );
     let show_table_functions = map(
         rule! {
             SHOW ~ TABLE_FUNCTIONS ~ #show_options?
@@ -516,13 +543,14 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     );
     let drop_database = map(
         rule! {
-            DROP ~ ( DATABASE | SCHEMA ) ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2
+            DROP ~ ( DATABASE | SCHEMA ) ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2 ~ CASCADE?
         },
-        |(_, _, opt_if_exists, (catalog, database))| {
+        |(_, _, opt_if_exists, (catalog, database), opt_cascade)| {
             Statement::DropDatabase(DropDatabaseStmt {
                 if_exists: opt_if_exists.is_some(),
                 catalog,
                 database,
+                cascade: opt_cascade.is_some(),
             })
         },
     );
@@ -676,7 +704,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
+            ~ ( #volume_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ #bucket_by?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -689,7 +719,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             source,
             engine,
             uri_location,
+            volume_location,
             opt_cluster_by,
+            bucket_by,
             opt_table_options,
             opt_as_query,
         )| {
@@ -701,9 +733,11 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 source,
                 engine,
                 uri_location,
+                volume_location,
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
+                bucket_by,
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -945,6 +979,69 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ( TOKENIZER ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            opt_tokenizer,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                tokenizer: opt_tokenizer.map(|(_, _, tokenizer)| tokenizer),
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_inverted_index = map(
+        rule! {
+            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
     let create_virtual_column = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMN ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1068,14 +1165,21 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ #role_name ~ #alter_role_action
+        },
+        |(_, _, role_name, action)| Statement::AlterRole(AlterRoleStmt { role_name, action }),
+    );
     let grant = map(
         rule! {
-            GRANT ~ #grant_source ~ TO ~ #grant_option
+            GRANT ~ #grant_source ~ TO ~ #grant_option ~ ( REVOKE ~ CURRENT ~ GRANTS )?
         },
-        |(_, source, _, grant_option)| {
+        |(_, source, _, grant_option, revoke_current_grants)| {
             Statement::Grant(GrantStmt {
                 source,
                 principal: grant_option,
+                revoke_current_grants: revoke_current_grants.is_some(),
             })
         },
     );
@@ -1087,7 +1191,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             Some(ShowGrantOption::PrincipalIdentity(principal)) => Statement::ShowGrants {
                 principal: Some(principal),
             },
-            Some(ShowGrantOption::ShareGrantObjectName(object)) => {
+            Some(ShowGrantOption::GrantObjectName(object)) => {
                 Statement::ShowObjectGrantPrivileges(ShowObjectGrantPrivilegesStmt { object })
             }
             Some(ShowGrantOption::ShareName(share_name)) => {
@@ -1152,6 +1256,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             CREATE ~ STAGE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ ( #stage_name )
             ~ ( (URL ~ ^"=")? ~ #uri_location )?
+            ~ ( #volume_location )?
             ~ ( #file_format_clause )?
             ~ ( ON_ERROR ~ ^"=" ~ ^#ident )?
             ~ ( SIZE_LIMIT ~ ^"=" ~ ^#literal_u64 )?
@@ -1164,6 +1269,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             opt_if_not_exists,
             stage,
             url_opt,
+            volume_location,
             file_format_opt,
             on_error_opt,
             size_limit_opt,
@@ -1174,6 +1280,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 if_not_exists: opt_if_not_exists.is_some(),
                 stage_name: stage.to_string(),
                 location: url_opt.map(|(_, location)| location),
+                volume_location,
                 file_format_options: file_format_opt.unwrap_or_default(),
                 on_error: on_error_opt.map(|v| v.2.to_string()).unwrap_or_default(),
                 size_limit: size_limit_opt.map(|v| v.2 as usize).unwrap_or_default(),
@@ -1224,6 +1331,28 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let alter_stage = map(
+        rule! {
+            ALTER ~ STAGE ~ ( IF ~ ^EXISTS )? ~ #stage_name ~ #alter_stage_action
+        },
+        |(_, _, opt_if_exists, stage_name, action)| {
+            Statement::AlterStage(AlterStageStmt {
+                if_exists: opt_if_exists.is_some(),
+                stage_name: stage_name.to_string(),
+                action,
+            })
+        },
+    );
+
+    let show_create_stage = map(
+        rule! {
+            SHOW ~ CREATE ~ STAGE ~ #ident
+        },
+        |(_, _, _, stage_name)| Statement::ShowCreateStage {
+            stage_name: stage_name.to_string(),
+        },
+    );
+
     // connections
     let connection_opt = connection_opt("=");
     let create_connection = map_res(
@@ -1232,13 +1361,15 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ #ident ~ STORAGE_TYPE ~ "=" ~  #literal_string ~ #connection_opt*
         },
         |(_, _, opt_if_not_exists, connection_name, _, _, storage_type, options)| {
-            let options =
+            let mut options =
                 BTreeMap::from_iter(options.iter().map(|(k, v)| (k.to_lowercase(), v.clone())));
+            let comment = options.remove("comment").unwrap_or_default();
             Ok(Statement::CreateConnection(CreateConnectionStmt {
                 if_not_exists: opt_if_not_exists.is_some(),
                 name: connection_name,
                 storage_type,
                 storage_params: options,
+                comment,
             }))
         },
     );
@@ -1269,6 +1400,56 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _)| Statement::ShowConnections(ShowConnectionsStmt {}),
     );
 
+    // external volumes
+    let create_external_volume = map(
+        rule! {
+            CREATE ~ EXTERNAL ~ VOLUME ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ #connection_options
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, opt_if_not_exists, name, options, opt_comment)| {
+            Statement::CreateExternalVolume(CreateExternalVolumeStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                options,
+                comment: opt_comment
+                    .map(|(_, _, comment)| comment)
+                    .unwrap_or_default(),
+            })
+        },
+    );
+    let alter_external_volume = map(
+        rule! {
+            ALTER ~ EXTERNAL ~ VOLUME ~ #ident ~ SET ~ ^#connection_options
+        },
+        |(_, _, _, name, _, options)| {
+            Statement::AlterExternalVolume(AlterExternalVolumeStmt { name, options })
+        },
+    );
+    let drop_external_volume = map(
+        rule! {
+            DROP ~ EXTERNAL ~ VOLUME ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropExternalVolume(DropExternalVolumeStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let desc_external_volume = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ EXTERNAL ~ VOLUME ~ #ident
+        },
+        |(_, _, _, name)| Statement::DescribeExternalVolume(DescribeExternalVolumeStmt { name }),
+    );
+    let show_external_volumes = map(
+        rule! {
+            SHOW ~ EXTERNAL ~ VOLUMES
+        },
+        |(_, _, _)| Statement::ShowExternalVolumes(ShowExternalVolumesStmt {}),
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1425,13 +1606,28 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! {
             CREATE ~ FILE ~ FORMAT ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident ~ #format_options
+            ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
         },
-        |(_, _, _, opt_if_not_exists, name, options)| {
+        |(_, _, _, opt_if_not_exists, name, options, comment_opt)| {
             let file_format_options = FileFormatOptionsAst { options };
             Ok(Statement::CreateFileFormat {
                 if_not_exists: opt_if_not_exists.is_some(),
                 name: name.to_string(),
                 file_format_options,
+                comment: comment_opt.map(|v| v.2).unwrap_or_default(),
+            })
+        },
+    );
+
+    let alter_file_format = map(
+        rule! {
+            ALTER ~ FILE ~ FORMAT ~ ( IF ~ ^EXISTS )? ~ #ident ~ #alter_file_format_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            Statement::AlterFileFormat(AlterFileFormatStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
             })
         },
     );
@@ -1485,6 +1681,47 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+             ~ AS ~ "(" ~ ^#comma_separated_list1(row_access_policy_arg) ~ ")"
+             ~ RETURNS ~ ( BOOLEAN | BOOL ) ~ "->" ~ #expr
+             ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, _, _, opt_if_not_exists, name, _, _, args, _, _, _, _, body, opt_comment)| {
+            let stmt = CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                args,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            };
+            Statement::CreateRowAccessPolicy(stmt)
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            let stmt = DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropRowAccessPolicy(stmt)
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
@@ -1586,6 +1823,120 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_password_policy = map(
+        rule! {
+            CREATE ~ PASSWORD ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+             ~ #password_set_options
+        },
+        |(_, _, _, opt_if_not_exists, name, set_options)| {
+            let stmt = CreatePasswordPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            };
+            Statement::CreatePasswordPolicy(stmt)
+        },
+    );
+    let alter_password_policy = map(
+        rule! {
+            ALTER ~ PASSWORD ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+             ~ #alter_password_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            let stmt = AlterPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            };
+            Statement::AlterPasswordPolicy(stmt)
+        },
+    );
+    let drop_password_policy = map(
+        rule! {
+            DROP ~ PASSWORD ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropPasswordPolicy(stmt)
+        },
+    );
+    let describe_password_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PASSWORD ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescPasswordPolicy(DescPasswordPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_password_policies = value(
+        Statement::ShowPasswordPolicies,
+        rule! { SHOW ~ PASSWORD ~ POLICIES },
+    );
+
+    let create_settings_profile = map(
+        rule! {
+            CREATE ~ SETTINGS ~ PROFILE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+             ~ ( SETTINGS ~ ^"=" ~ ^"(" ~ ^#settings_profile_settings ~ ^")" )?
+             ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, opt_if_not_exists, name, opt_settings, opt_comment)| {
+            let stmt = CreateSettingsProfileStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                settings: opt_settings
+                    .map(|(_, _, _, settings, _)| settings)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            };
+            Statement::CreateSettingsProfile(stmt)
+        },
+    );
+    let alter_settings_profile = map(
+        rule! {
+            ALTER ~ SETTINGS ~ PROFILE ~ ( IF ~ ^EXISTS )? ~ #ident
+             ~ #alter_settings_profile_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            let stmt = AlterSettingsProfileStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            };
+            Statement::AlterSettingsProfile(stmt)
+        },
+    );
+    let drop_settings_profile = map(
+        rule! {
+            DROP ~ SETTINGS ~ PROFILE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropSettingsProfileStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropSettingsProfile(stmt)
+        },
+    );
+    let describe_settings_profile = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ SETTINGS ~ PROFILE ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescSettingsProfile(DescSettingsProfileStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_settings_profiles = value(
+        Statement::ShowSettingsProfiles,
+        rule! { SHOW ~ SETTINGS ~ PROFILES },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1653,9 +2004,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
-            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #explain_analyze : "`EXPLAIN ANALYZE [FRAGMENTS] <statement>`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #show_settings_profiles : "`SHOW SETTINGS PROFILES`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
@@ -1672,22 +2024,41 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy
+        // network policy / password policy / row access policy / external volume / function
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #drop_network_policy: "`DROP NETWORK POLICY [IF EXISTS] name`"
             | #describe_network_policy: "`DESC NETWORK POLICY name`"
             | #show_network_policies: "`SHOW NETWORK POLICIES`"
+            | #create_password_policy: "`CREATE PASSWORD POLICY [IF NOT EXISTS] name [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #alter_password_policy: "`ALTER PASSWORD POLICY [IF EXISTS] name SET [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>'] | UNSET [PASSWORD_MIN_LENGTH] ... [COMMENT]`"
+            | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
+            | #describe_password_policy: "`DESC PASSWORD POLICY name`"
+            | #show_password_policies: "`SHOW PASSWORD POLICIES`"
+            | #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type> [, ...]) RETURNS BOOLEAN -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <name>`"
+            | #create_external_volume: "`CREATE EXTERNAL VOLUME [IF NOT EXISTS] <name> (TYPE = '<type>' <storage_configs>) [COMMENT = '<string_literal>']`"
+            | #alter_external_volume: "`ALTER EXTERNAL VOLUME <name> SET (TYPE = '<type>' <storage_configs>)`"
+            | #drop_external_volume: "`DROP EXTERNAL VOLUME [IF EXISTS] <name>`"
+            | #desc_external_volume: "`DESC | DESCRIBE EXTERNAL VOLUME <name>`"
+            | #show_external_volumes: "`SHOW EXTERNAL VOLUMES`"
+            | #describe_function: "`DESC | DESCRIBE FUNCTION <name>`"
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #merge : "`MERGE INTO <target_table> USING <source> ON <join_expr> { matchedClause | notMatchedClause } [ ... ]`"
         ),
+        // settings / settings profile
         rule!(
             #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #create_settings_profile: "`CREATE SETTINGS PROFILE [IF NOT EXISTS] name [SETTINGS = (<setting> = '<value>', ...)] [COMMENT = '<string_literal>']`"
+            | #alter_settings_profile: "`ALTER SETTINGS PROFILE [IF EXISTS] name SET [SETTINGS = (<setting> = '<value>', ...)] [COMMENT = '<string_literal>'] | UNSET [SETTINGS (<setting>, ...)] [COMMENT]`"
+            | #drop_settings_profile: "`DROP SETTINGS PROFILE [IF EXISTS] name`"
+            | #describe_settings_profile: "`DESC SETTINGS PROFILE name`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
@@ -1721,6 +2092,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [TOKENIZER = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1736,6 +2110,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE <role_name> (SET PROFILE = '<profile>' | UNSET PROFILE)`"
             | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
@@ -1751,11 +2126,14 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
             | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
             | #drop_stage: "`DROP STAGE <stage_name>`"
+            | #alter_stage: "`ALTER STAGE [ IF EXISTS ] <stage_name> { RENAME TO <new_stage_name> | SET COMMENT = '<string_literal>' }`"
+            | #show_create_stage: "`SHOW CREATE STAGE <stage_name>`"
         ),
         rule!(
-            #create_file_format: "`CREATE FILE FORMAT [ IF NOT EXISTS ] <format_name> formatTypeOptions`"
+            #create_file_format: "`CREATE FILE FORMAT [ IF NOT EXISTS ] <format_name> formatTypeOptions [ COMMENT = '<string_literal>' ]`"
             | #show_file_formats: "`SHOW FILE FORMATS`"
             | #drop_file_format: "`DROP FILE FORMAT  [ IF EXISTS ] <format_name>`"
+            | #alter_file_format: "`ALTER FILE FORMAT [ IF EXISTS ] <format_name> { RENAME TO <new_format_name> | SET COMMENT = '<string_literal>' }`"
         ),
         rule!( #copy_into ),
         rule!(
@@ -1821,7 +2199,7 @@ AS
 
         ),
         rule!(
-            #create_connection: "`CREATE CONNECTION [IF NOT EXISTS] <connection_name> STORAGE_TYPE = <type> <storage_configs>`"
+            #create_connection: "`CREATE CONNECTION [IF NOT EXISTS] <connection_name> STORAGE_TYPE = <type> <storage_configs> [ COMMENT = '<string_literal>' ]`"
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
@@ -1839,6 +2217,16 @@ AS
     )(i)
 }
 
+// `IDEMPOTENT_TOKEN = '<token>'` of `INSERT` and `REPLACE` statements.
+pub fn idempotent_token(i: Input) -> IResult<String> {
+    map(
+        rule! {
+            IDEMPOTENT_TOKEN ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, token)| token,
+    )(i)
+}
+
 // `INSERT INTO ... FORMAT ...` and `INSERT INTO ... VALUES` statements will
 // stop the parser immediately and return the rest tokens by `InsertSource`.
 //
@@ -1902,12 +2290,13 @@ pub fn merge_source(i: Input) -> IResult<MergeSource> {
     });
 
     let source_table = map(
-        rule!(#dot_separated_idents_1_to_3 ~ #table_alias?),
-        |((catalog, database, table), alias)| MergeSource::Table {
+        rule!(#dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias?),
+        |((catalog, database, table), travel_point, alias)| MergeSource::Table {
             catalog,
             database,
             table,
             alias,
+            travel_point: travel_point.map(|p| p.1),
         },
     );
 
@@ -2129,6 +2518,17 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    // OWNERSHIP ON TABLE db.t, OWNERSHIP ON DATABASE db
+    let ownership = map(
+        rule! {
+            OWNERSHIP ~ ON ~ #ownership_level
+        },
+        |(_, _, level)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Ownership],
+            level,
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -2139,9 +2539,21 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let volume_privs = map(
+        rule! {
+            #comma_separated_list1(volume_priv_type) ~ ON ~ EXTERNAL ~ VOLUME ~ #ident
+        },
+        |(privileges, _, _, _, volume)| AccountMgrSource::Privs {
+            privileges,
+            level: AccountMgrLevel::Volume(volume.to_string()),
+        },
+    );
+
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "SELECT ON UDF <udf_name>"
+        | #volume_privs : "<volume_privileges> ON EXTERNAL VOLUME <volume_name>"
+        | #ownership : "OWNERSHIP ON { TABLE <table> | DATABASE <database> }"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
@@ -2168,9 +2580,22 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::Drop, rule! { DROP }),
         value(UserPrivilegeType::Create, rule! { CREATE }),
         value(UserPrivilegeType::Ownership, rule! { OWNERSHIP }),
+        value(
+            UserPrivilegeType::ApplyRowAccessPolicy,
+            rule! { APPLY ~ ROW ~ ACCESS ~ POLICY },
+        ),
     ))(i)
 }
 
+pub fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(rule! { #ident ~ #type_name }, |(arg_name, arg_type)| {
+        RowAccessPolicyArg {
+            arg_name: arg_name.name,
+            arg_type,
+        }
+    })(i)
+}
+
 pub fn stage_priv_type(i: Input) -> IResult<UserPrivilegeType> {
     alt((
         value(UserPrivilegeType::Read, rule! { READ }),
@@ -2178,6 +2603,13 @@ pub fn stage_priv_type(i: Input) -> IResult<UserPrivilegeType> {
     ))(i)
 }
 
+pub fn volume_priv_type(i: Input) -> IResult<UserPrivilegeType> {
+    alt((
+        value(UserPrivilegeType::Usage, rule! { USAGE }),
+        value(UserPrivilegeType::Read, rule! { READ }),
+    ))(i)
+}
+
 pub fn udf_priv_type(i: Input) -> IResult<UserPrivilegeType> {
     alt((value(UserPrivilegeType::Select, rule! { SELECT }),))(i)
 }
@@ -2224,6 +2656,13 @@ pub fn grant_share_object_name(i: Input) -> IResult<ShareGrantObjectName> {
 pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
     // *.*
     let global = map(rule! { "*" ~ "." ~ "*" }, |_| AccountMgrLevel::Global);
+    // FUTURE TABLES IN DATABASE db
+    let future_tables = map(
+        rule! {
+            FUTURE ~ TABLES ~ IN ~ DATABASE ~ #ident
+        },
+        |(_, _, _, _, database)| AccountMgrLevel::FutureTables(database.name),
+    );
     // db.*
     // "*": as current db or "table" with current db
     let db = map(
@@ -2245,14 +2684,65 @@ pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
 
     rule!(
         #global : "*.*"
+        | #future_tables : "FUTURE TABLES IN DATABASE <database>"
         | #db : "<database>.*"
         | #table : "<database>.<table>"
     )(i)
 }
 
+pub fn ownership_level(i: Input) -> IResult<AccountMgrLevel> {
+    // TABLE `db01`.`tb1` or TABLE tb1
+    let table = map(
+        rule! {
+            TABLE ~ ( #ident ~ "." )? ~ #ident
+        },
+        |(_, database, table)| {
+            AccountMgrLevel::Table(database.map(|(database, _)| database.name), table.name)
+        },
+    );
+    // DATABASE db01
+    let db = map(
+        rule! {
+            DATABASE ~ #ident
+        },
+        |(_, database)| AccountMgrLevel::Database(Some(database.name)),
+    );
+
+    rule!(
+        #table : "TABLE <database>.<table>"
+        | #db : "DATABASE <database>"
+    )(i)
+}
+
+pub fn show_grant_object_level(i: Input) -> IResult<AccountMgrLevel> {
+    let stage = map(rule! { STAGE ~ #ident }, |(_, stage_name)| {
+        AccountMgrLevel::Stage(stage_name.to_string())
+    });
+    let udf = map(rule! { UDF ~ #ident }, |(_, udf)| {
+        AccountMgrLevel::UDF(udf.to_string())
+    });
+    let volume = map(rule! { EXTERNAL ~ VOLUME ~ #ident }, |(_, _, volume)| {
+        AccountMgrLevel::Volume(volume.to_string())
+    });
+
+    rule!(
+        #ownership_level
+        | #stage : "STAGE <stage_name>"
+        | #udf : "UDF <udf_name>"
+        | #volume : "EXTERNAL VOLUME <volume_name>"
+    )(i)
+}
+
 pub fn grant_all_level(i: Input) -> IResult<AccountMgrLevel> {
     // *.*
     let global = map(rule! { "*" ~ "." ~ "*" }, |_| AccountMgrLevel::Global);
+    // FUTURE TABLES IN DATABASE db
+    let future_tables = map(
+        rule! {
+            FUTURE ~ TABLES ~ IN ~ DATABASE ~ #ident
+        },
+        |(_, _, _, _, database)| AccountMgrLevel::FutureTables(database.name),
+    );
     // db.*
     // "*": as current db or "table" with current db
     let db = map(
@@ -2275,8 +2765,13 @@ pub fn grant_all_level(i: Input) -> IResult<AccountMgrLevel> {
     let stage = map(rule! { STAGE ~ #ident}, |(_, stage_name)| {
         AccountMgrLevel::Stage(stage_name.to_string())
     });
+    let volume = map(rule! { EXTERNAL ~ VOLUME ~ #ident }, |(_, _, volume)| {
+        AccountMgrLevel::Volume(volume.to_string())
+    });
     rule!(
         #global : "*.*"
+        | #future_tables : "FUTURE TABLES IN DATABASE <database>"
+        | #volume : "EXTERNAL VOLUME <volume_name>"
         | #db : "<database>.*"
         | #table : "<database>.<table>"
         | #stage : "STAGE <stage_name>"
@@ -2291,11 +2786,11 @@ pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
         |(_, opt_principal)| ShowGrantOption::PrincipalIdentity(opt_principal),
     );
 
-    let share_object_name = map(
+    let object_name = map(
         rule! {
-            ON ~ #grant_share_object_name
+            ON ~ #show_grant_object_level
         },
-        |(_, object_name)| ShowGrantOption::ShareGrantObjectName(object_name),
+        |(_, object_name)| ShowGrantOption::GrantObjectName(object_name),
     );
 
     let share_name = map(
@@ -2307,7 +2802,7 @@ pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
 
     rule!(
         #grant_role: "FOR  { ROLE <role_name> | [USER] <user> }"
-        | #share_object_name: "ON {DATABASE <db_name> | TABLE <db_name>.<table_name>}"
+        | #object_name: "ON { DATABASE <db_name> | TABLE <db_name>.<table_name> | STAGE <stage_name> | UDF <udf_name> }"
         | #share_name: "OF SHARE <share_name>"
     )(i)
 }
@@ -2357,6 +2852,50 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
     )(i)
 }
 
+pub fn alter_stage_action(i: Input) -> IResult<AlterStageAction> {
+    let rename_stage = map(
+        rule! {
+            RENAME ~ TO ~ #stage_name
+        },
+        |(_, _, new_stage_name)| AlterStageAction::RenameStage {
+            new_stage_name: new_stage_name.to_string(),
+        },
+    );
+    let set_comment = map(
+        rule! {
+            SET ~ (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, comment)| AlterStageAction::SetComment { comment },
+    );
+
+    rule!(
+        #rename_stage
+        | #set_comment
+    )(i)
+}
+
+pub fn alter_file_format_action(i: Input) -> IResult<AlterFileFormatAction> {
+    let rename_file_format = map(
+        rule! {
+            RENAME ~ TO ~ #ident
+        },
+        |(_, _, new_name)| AlterFileFormatAction::RenameFileFormat {
+            new_name: new_name.to_string(),
+        },
+    );
+    let set_comment = map(
+        rule! {
+            SET ~ (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, comment)| AlterFileFormatAction::SetComment { comment },
+    );
+
+    rule!(
+        #rename_file_format
+        | #set_comment
+    )(i)
+}
+
 pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     let mut rename_database = map(
         rule! {
@@ -2554,6 +3093,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ ^#comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2565,6 +3121,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
     )(i)
 }
 
@@ -2677,9 +3235,16 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     alt((
         value(OptimizeTableAction::All, rule! { ALL }),
         map(
-            rule! { PURGE ~ (BEFORE ~ ^#travel_point)? },
-            |(_, opt_travel_point)| OptimizeTableAction::Purge {
-                before: opt_travel_point.map(|(_, p)| p),
+            rule! { PURGE ~ (BEFORE ~ ^#travel_point ~ FORCE?)? },
+            |(_, opt_travel_point)| match opt_travel_point {
+                Some((_, point, opt_force)) => OptimizeTableAction::Purge {
+                    before: Some(point),
+                    force: opt_force.is_some(),
+                },
+                None => OptimizeTableAction::Purge {
+                    before: None,
+                    force: false,
+                },
             },
         ),
         map(rule! { COMPACT ~ SEGMENT? }, |(_, opt_segment)| {
@@ -2951,6 +3516,19 @@ pub fn engine(i: Input) -> IResult<Engine> {
     )(i)
 }
 
+pub fn bucket_by(i: Input) -> IResult<BucketBy> {
+    map(
+        rule! {
+            BUCKET ~ BY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^INTO ~ ^#literal_u64 ~ ^BUCKETS
+        },
+        |(_, _, _, columns, _, _, num_buckets, _)| BucketBy {
+            columns,
+            num_buckets,
+        },
+    )(i)
+}
+
 pub fn database_engine(i: Input) -> IResult<DatabaseEngine> {
     value(DatabaseEngine::Default, rule! { DEFAULT })(i)
 }
@@ -3008,6 +3586,30 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetNetworkPolicy,
     );
+    let set_password_policy = map(
+        rule! {
+            SET ~ PASSWORD ~ ^POLICY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetPasswordPolicy(policy),
+    );
+    let unset_password_policy = map(
+        rule! {
+            UNSET ~ PASSWORD ~ ^POLICY
+        },
+        |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
+    );
+    let set_settings_profile = map(
+        rule! {
+            SET ~ PROFILE ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, profile)| UserOptionItem::SetSettingsProfile(profile),
+    );
+    let unset_settings_profile = map(
+        rule! {
+            UNSET ~ PROFILE
+        },
+        |(_, _)| UserOptionItem::UnsetSettingsProfile,
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -3015,11 +3617,199 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        set_password_policy,
+        unset_password_policy,
         set_network_policy,
         unset_network_policy,
+        set_settings_profile,
+        unset_settings_profile,
     ))(i)
 }
 
+pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
+    map(
+        rule! {
+             ( PASSWORD_MIN_LENGTH ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MAX_LENGTH ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MIN_UPPER_CASE_CHARS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MIN_LOWER_CASE_CHARS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MIN_NUMERIC_CHARS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MIN_SPECIAL_CHARS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MAX_AGE_DAYS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_MAX_RETRIES ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_LOCKOUT_TIME_MINS ~ Eq ~ ^#literal_u64 )?
+             ~ ( PASSWORD_HISTORY ~ Eq ~ ^#literal_u64 )?
+             ~ ( COMMENT ~ Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_min_length,
+            opt_max_length,
+            opt_min_upper_case_chars,
+            opt_min_lower_case_chars,
+            opt_min_numeric_chars,
+            opt_min_special_chars,
+            opt_max_age_days,
+            opt_max_retries,
+            opt_lockout_time_mins,
+            opt_history,
+            opt_comment,
+        )| PasswordSetOptions {
+            min_length: opt_min_length.map(|opt| opt.2),
+            max_length: opt_max_length.map(|opt| opt.2),
+            min_upper_case_chars: opt_min_upper_case_chars.map(|opt| opt.2),
+            min_lower_case_chars: opt_min_lower_case_chars.map(|opt| opt.2),
+            min_numeric_chars: opt_min_numeric_chars.map(|opt| opt.2),
+            min_special_chars: opt_min_special_chars.map(|opt| opt.2),
+            max_age_days: opt_max_age_days.map(|opt| opt.2),
+            max_retries: opt_max_retries.map(|opt| opt.2),
+            lockout_time_mins: opt_lockout_time_mins.map(|opt| opt.2),
+            history: opt_history.map(|opt| opt.2),
+            comment: opt_comment.map(|opt| opt.2),
+        },
+    )(i)
+}
+
+pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
+    map(
+        rule! {
+             PASSWORD_MIN_LENGTH?
+             ~ PASSWORD_MAX_LENGTH?
+             ~ PASSWORD_MIN_UPPER_CASE_CHARS?
+             ~ PASSWORD_MIN_LOWER_CASE_CHARS?
+             ~ PASSWORD_MIN_NUMERIC_CHARS?
+             ~ PASSWORD_MIN_SPECIAL_CHARS?
+             ~ PASSWORD_MAX_AGE_DAYS?
+             ~ PASSWORD_MAX_RETRIES?
+             ~ PASSWORD_LOCKOUT_TIME_MINS?
+             ~ PASSWORD_HISTORY?
+             ~ COMMENT?
+        },
+        |(
+            opt_min_length,
+            opt_max_length,
+            opt_min_upper_case_chars,
+            opt_min_lower_case_chars,
+            opt_min_numeric_chars,
+            opt_min_special_chars,
+            opt_max_age_days,
+            opt_max_retries,
+            opt_lockout_time_mins,
+            opt_history,
+            opt_comment,
+        )| PasswordUnSetOptions {
+            min_length: opt_min_length.is_some(),
+            max_length: opt_max_length.is_some(),
+            min_upper_case_chars: opt_min_upper_case_chars.is_some(),
+            min_lower_case_chars: opt_min_lower_case_chars.is_some(),
+            min_numeric_chars: opt_min_numeric_chars.is_some(),
+            min_special_chars: opt_min_special_chars.is_some(),
+            max_age_days: opt_max_age_days.is_some(),
+            max_retries: opt_max_retries.is_some(),
+            lockout_time_mins: opt_lockout_time_mins.is_some(),
+            history: opt_history.is_some(),
+            comment: opt_comment.is_some(),
+        },
+    )(i)
+}
+
+pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
+    let set_options = map(
+        rule! {
+            SET ~ #password_set_options
+        },
+        |(_, set_options)| AlterPasswordAction::SetOptions(set_options),
+    );
+    let unset_options = map(
+        rule! {
+            UNSET ~ #password_unset_options
+        },
+        |(_, unset_options)| AlterPasswordAction::UnSetOptions(unset_options),
+    );
+
+    rule!(
+        #set_options
+        | #unset_options
+    )(i)
+}
+
+pub fn settings_profile_settings(i: Input) -> IResult<BTreeMap<String, String>> {
+    let setting_value = |i| {
+        rule!(
+            #literal_string
+            | #map(literal_u64, |v| v.to_string())
+        )(i)
+    };
+    let setting = |i| rule!(#ident ~ ^"=" ~ ^#setting_value)(i);
+
+    map(
+        rule! {
+            #comma_separated_list1(setting)
+        },
+        |settings| {
+            BTreeMap::from_iter(
+                settings
+                    .into_iter()
+                    .map(|(k, _, v)| (k.name.to_lowercase(), v)),
+            )
+        },
+    )(i)
+}
+
+pub fn alter_settings_profile_action(i: Input) -> IResult<AlterSettingsProfileAction> {
+    let set = map(
+        rule! {
+            SET ~ ( SETTINGS ~ ^"=" ~ ^"(" ~ ^#settings_profile_settings ~ ^")" )?
+             ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, opt_settings, opt_comment)| AlterSettingsProfileAction::Set {
+            settings: opt_settings
+                .map(|(_, _, _, settings, _)| settings)
+                .unwrap_or_default(),
+            comment: opt_comment.map(|(_, _, comment)| comment),
+        },
+    );
+    let unset = map(
+        rule! {
+            UNSET ~ ( SETTINGS ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+             ~ COMMENT?
+        },
+        |(_, opt_settings, opt_comment)| AlterSettingsProfileAction::Unset {
+            settings: opt_settings
+                .map(|(_, _, settings, _)| {
+                    settings
+                        .into_iter()
+                        .map(|setting| setting.name.to_lowercase())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            comment: opt_comment.is_some(),
+        },
+    );
+
+    rule!(
+        #set
+        | #unset
+    )(i)
+}
+
+pub fn alter_role_action(i: Input) -> IResult<AlterRoleAction> {
+    let set_settings_profile = map(
+        rule! {
+            SET ~ PROFILE ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, profile)| AlterRoleAction::SetSettingsProfile(profile),
+    );
+    let unset_settings_profile = value(
+        AlterRoleAction::UnsetSettingsProfile,
+        rule! { UNSET ~ PROFILE },
+    );
+
+    rule!(
+        #set_settings_profile
+        | #unset_settings_profile
+    )(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
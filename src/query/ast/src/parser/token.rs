@@ -316,6 +316,8 @@ pub enum TokenKind {
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
     ALLOWED_IP_LIST,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ADD", ignore(ascii_case))]
     ADD,
     #[token("AFTER", ignore(ascii_case))]
@@ -324,6 +326,8 @@ pub enum TokenKind {
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
+    #[token("APPLY", ignore(ascii_case))]
+    APPLY,
     #[token("ARGS", ignore(ascii_case))]
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
@@ -372,10 +376,16 @@ pub enum TokenKind {
     BY,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
+    #[token("BUCKET", ignore(ascii_case))]
+    BUCKET,
+    #[token("BUCKETS", ignore(ascii_case))]
+    BUCKETS,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CASCADE", ignore(ascii_case))]
+    CASCADE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -488,6 +498,8 @@ pub enum TokenKind {
     DROP,
     #[token("DRY", ignore(ascii_case))]
     DRY,
+    #[token("DRYRUN", ignore(ascii_case))]
+    DRYRUN,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
     #[token("EXCLUDE", ignore(ascii_case))]
@@ -516,6 +528,8 @@ pub enum TokenKind {
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -530,6 +544,8 @@ pub enum TokenKind {
     NULL_DISPLAY,
     #[token("FILE_FORMAT", ignore(ascii_case))]
     FILE_FORMAT,
+    #[token("FILE_NAME_TEMPLATE", ignore(ascii_case))]
+    FILE_NAME_TEMPLATE,
     #[token("FILE", ignore(ascii_case))]
     FILE,
     #[token("FILES", ignore(ascii_case))]
@@ -572,6 +588,8 @@ pub enum TokenKind {
     SET_VAR,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
+    #[token("FUTURE", ignore(ascii_case))]
+    FUTURE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
     #[token("GLOBAL", ignore(ascii_case))]
@@ -596,6 +614,8 @@ pub enum TokenKind {
     ICEBERG,
     #[token("INTERSECT", ignore(ascii_case))]
     INTERSECT,
+    #[token("IDEMPOTENT_TOKEN", ignore(ascii_case))]
+    IDEMPOTENT_TOKEN,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
@@ -624,6 +644,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -741,6 +763,30 @@ pub enum TokenKind {
     PARTITION,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PASSWORD", ignore(ascii_case))]
+    PASSWORD,
+    #[token("PASSWORD_HISTORY", ignore(ascii_case))]
+    PASSWORD_HISTORY,
+    #[token("PASSWORD_LOCKOUT_TIME_MINS", ignore(ascii_case))]
+    PASSWORD_LOCKOUT_TIME_MINS,
+    #[token("PASSWORD_MAX_AGE_DAYS", ignore(ascii_case))]
+    PASSWORD_MAX_AGE_DAYS,
+    #[token("PASSWORD_MAX_LENGTH", ignore(ascii_case))]
+    PASSWORD_MAX_LENGTH,
+    #[token("PASSWORD_MAX_RETRIES", ignore(ascii_case))]
+    PASSWORD_MAX_RETRIES,
+    #[token("PASSWORD_MIN_LENGTH", ignore(ascii_case))]
+    PASSWORD_MIN_LENGTH,
+    #[token("PASSWORD_MIN_LOWER_CASE_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_LOWER_CASE_CHARS,
+    #[token("PASSWORD_MIN_NUMERIC_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_NUMERIC_CHARS,
+    #[token("PASSWORD_MIN_SPECIAL_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_SPECIAL_CHARS,
+    #[token("PASSWORD_MIN_UPPER_CASE_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_UPPER_CASE_CHARS,
+    #[token("PATH", ignore(ascii_case))]
+    PATH,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -755,6 +801,10 @@ pub enum TokenKind {
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
+    #[token("PROFILE", ignore(ascii_case))]
+    PROFILE,
+    #[token("PROFILES", ignore(ascii_case))]
+    PROFILES,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
     #[token("QUARTER", ignore(ascii_case))]
@@ -875,6 +925,8 @@ pub enum TokenKind {
     SIZE_LIMIT,
     #[token("MAX_FILES", ignore(ascii_case))]
     MAX_FILES,
+    #[token("MATCH_BY_COLUMN_NAME", ignore(ascii_case))]
+    MATCH_BY_COLUMN_NAME,
     #[token("SKIP_HEADER", ignore(ascii_case))]
     SKIP_HEADER,
     #[token("SMALLINT", ignore(ascii_case))]
@@ -951,6 +1003,8 @@ pub enum TokenKind {
     TO,
     #[token("TOKEN", ignore(ascii_case))]
     TOKEN,
+    #[token("TOKENIZER", ignore(ascii_case))]
+    TOKENIZER,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
     #[token("TRANSIENT", ignore(ascii_case))]
@@ -1011,6 +1065,10 @@ pub enum TokenKind {
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("VOLUME", ignore(ascii_case))]
+    VOLUME,
+    #[token("VOLUMES", ignore(ascii_case))]
+    VOLUMES,
     #[token("WHEN", ignore(ascii_case))]
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
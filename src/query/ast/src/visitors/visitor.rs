@@ -403,6 +403,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_functions(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_describe_function(&mut self, _name: &'ast Identifier) {}
+
     fn visit_show_table_functions(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_show_options(&mut self, _show_options: &'ast Option<ShowOptions>, _name: String) {}
@@ -568,6 +570,12 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
+    fn visit_refresh_inverted_index(&mut self, _stmt: &'ast RefreshInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -590,6 +598,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
 
+    fn visit_alter_role(&mut self, _stmt: &'ast AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &'ast GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &'ast Option<PrincipalIdentity>) {}
@@ -610,6 +620,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_describe_stage(&mut self, _stage_name: &'ast str) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &'ast AlterStageStmt) {}
+
+    fn visit_show_create_stage(&mut self, _stage_name: &'ast str) {}
+
     fn visit_remove_stage(&mut self, _location: &'ast str, _pattern: &'ast str) {}
 
     fn visit_list_stage(&mut self, _location: &'ast str, _pattern: &'ast Option<String>) {}
@@ -624,6 +638,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_file_format(&mut self, _if_exists: bool, _name: &'ast str) {}
 
+    fn visit_alter_file_format(&mut self, _stmt: &'ast AlterFileFormatStmt) {}
+
     fn visit_show_file_formats(&mut self) {}
 
     fn visit_presign(&mut self, _presign: &'ast PresignStmt) {}
@@ -658,6 +674,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -668,6 +690,26 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_password_policy(&mut self, _stmt: &'ast CreatePasswordPolicyStmt) {}
+
+    fn visit_alter_password_policy(&mut self, _stmt: &'ast AlterPasswordPolicyStmt) {}
+
+    fn visit_drop_password_policy(&mut self, _stmt: &'ast DropPasswordPolicyStmt) {}
+
+    fn visit_desc_password_policy(&mut self, _stmt: &'ast DescPasswordPolicyStmt) {}
+
+    fn visit_show_password_policies(&mut self) {}
+
+    fn visit_create_settings_profile(&mut self, _stmt: &'ast CreateSettingsProfileStmt) {}
+
+    fn visit_alter_settings_profile(&mut self, _stmt: &'ast AlterSettingsProfileStmt) {}
+
+    fn visit_drop_settings_profile(&mut self, _stmt: &'ast DropSettingsProfileStmt) {}
+
+    fn visit_desc_settings_profile(&mut self, _stmt: &'ast DescSettingsProfileStmt) {}
+
+    fn visit_show_settings_profiles(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
@@ -795,4 +837,10 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_connection(&mut self, _stmt: &'ast DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &'ast DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &'ast ShowConnectionsStmt) {}
+
+    fn visit_create_external_volume(&mut self, _stmt: &'ast CreateExternalVolumeStmt) {}
+    fn visit_alter_external_volume(&mut self, _stmt: &'ast AlterExternalVolumeStmt) {}
+    fn visit_drop_external_volume(&mut self, _stmt: &'ast DropExternalVolumeStmt) {}
+    fn visit_describe_external_volume(&mut self, _stmt: &'ast DescribeExternalVolumeStmt) {}
+    fn visit_show_external_volumes(&mut self, _stmt: &'ast ShowExternalVolumesStmt) {}
 }
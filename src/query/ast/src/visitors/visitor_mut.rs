@@ -419,6 +419,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_indexes(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_describe_function(&mut self, _name: &mut Identifier) {}
+
     fn visit_show_table_functions(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_show_limit(&mut self, _limit: &mut ShowLimit) {}
@@ -582,6 +584,12 @@ pub trait VisitorMut: Sized {
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
+    fn visit_refresh_inverted_index(&mut self, _stmt: &mut RefreshInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -604,6 +612,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
 
+    fn visit_alter_role(&mut self, _stmt: &mut AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &mut GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &mut Option<PrincipalIdentity>) {}
@@ -624,6 +634,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_describe_stage(&mut self, _stage_name: &mut String) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &mut AlterStageStmt) {}
+
+    fn visit_show_create_stage(&mut self, _stage_name: &mut String) {}
+
     fn visit_remove_stage(&mut self, _location: &mut String, _pattern: &mut String) {}
 
     fn visit_list_stage(&mut self, _location: &mut String, _pattern: &mut Option<String>) {}
@@ -638,6 +652,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_file_format(&mut self, _if_exists: bool, _name: &mut String) {}
 
+    fn visit_alter_file_format(&mut self, _stmt: &mut AlterFileFormatStmt) {}
+
     fn visit_show_file_formats(&mut self) {}
 
     fn visit_presign(&mut self, _presign: &mut PresignStmt) {}
@@ -672,6 +688,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
@@ -682,6 +704,26 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_password_policy(&mut self, _stmt: &mut CreatePasswordPolicyStmt) {}
+
+    fn visit_alter_password_policy(&mut self, _stmt: &mut AlterPasswordPolicyStmt) {}
+
+    fn visit_drop_password_policy(&mut self, _stmt: &mut DropPasswordPolicyStmt) {}
+
+    fn visit_desc_password_policy(&mut self, _stmt: &mut DescPasswordPolicyStmt) {}
+
+    fn visit_show_password_policies(&mut self) {}
+
+    fn visit_create_settings_profile(&mut self, _stmt: &mut CreateSettingsProfileStmt) {}
+
+    fn visit_alter_settings_profile(&mut self, _stmt: &mut AlterSettingsProfileStmt) {}
+
+    fn visit_drop_settings_profile(&mut self, _stmt: &mut DropSettingsProfileStmt) {}
+
+    fn visit_desc_settings_profile(&mut self, _stmt: &mut DescSettingsProfileStmt) {}
+
+    fn visit_show_settings_profiles(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
@@ -806,4 +848,10 @@ pub trait VisitorMut: Sized {
     fn visit_drop_connection(&mut self, _stmt: &mut DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &mut DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &mut ShowConnectionsStmt) {}
+
+    fn visit_create_external_volume(&mut self, _stmt: &mut CreateExternalVolumeStmt) {}
+    fn visit_alter_external_volume(&mut self, _stmt: &mut AlterExternalVolumeStmt) {}
+    fn visit_drop_external_volume(&mut self, _stmt: &mut DropExternalVolumeStmt) {}
+    fn visit_describe_external_volume(&mut self, _stmt: &mut DescribeExternalVolumeStmt) {}
+    fn visit_show_external_volumes(&mut self, _stmt: &mut ShowExternalVolumesStmt) {}
 }
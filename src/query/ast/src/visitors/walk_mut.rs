@@ -325,6 +325,7 @@ pub fn walk_time_travel_point_mut<V: VisitorMut>(visitor: &mut V, time: &mut Tim
     match time {
         TimeTravelPoint::Snapshot(_) => {}
         TimeTravelPoint::Timestamp(expr) => visitor.visit_expr(expr),
+        TimeTravelPoint::Stream { .. } => {}
     }
 }
 
@@ -379,6 +380,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::ShowMetrics { show_options } => visitor.visit_show_metrics(show_options),
         Statement::ShowEngines { show_options } => visitor.visit_show_engines(show_options),
         Statement::ShowFunctions { show_options } => visitor.visit_show_functions(show_options),
+        Statement::DescribeFunction { name } => visitor.visit_describe_function(name),
         Statement::ShowIndexes { show_options } => visitor.visit_show_indexes(show_options),
         Statement::ShowTableFunctions { show_options } => {
             visitor.visit_show_table_functions(show_options)
@@ -436,6 +438,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::RefreshInvertedIndex(stmt) => visitor.visit_refresh_inverted_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -453,6 +458,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             if_exists,
             role_name,
         } => visitor.visit_drop_role(*if_exists, role_name),
+        Statement::AlterRole(stmt) => visitor.visit_alter_role(stmt),
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants { principal } => visitor.visit_show_grant(principal),
         Statement::Revoke(stmt) => visitor.visit_revoke(stmt),
@@ -473,11 +479,15 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             visitor.visit_remove_stage(location, pattern)
         }
         Statement::DescribeStage { stage_name } => visitor.visit_describe_stage(stage_name),
+        Statement::AlterStage(stmt) => visitor.visit_alter_stage(stmt),
+        Statement::ShowCreateStage { stage_name } => visitor.visit_show_create_stage(stage_name),
         Statement::CreateFileFormat {
             if_not_exists,
             name,
             file_format_options,
+            ..
         } => visitor.visit_create_file_format(*if_not_exists, name, file_format_options),
+        Statement::AlterFileFormat(stmt) => visitor.visit_alter_file_format(stmt),
         Statement::DropFileFormat { if_exists, name } => {
             visitor.visit_drop_file_format(*if_exists, name)
         }
@@ -501,12 +511,25 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
         Statement::AttachTable(_) => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
         Statement::DropNetworkPolicy(stmt) => visitor.visit_drop_network_policy(stmt),
         Statement::DescNetworkPolicy(stmt) => visitor.visit_desc_network_policy(stmt),
         Statement::ShowNetworkPolicies => visitor.visit_show_network_policies(),
+        Statement::CreatePasswordPolicy(stmt) => visitor.visit_create_password_policy(stmt),
+        Statement::AlterPasswordPolicy(stmt) => visitor.visit_alter_password_policy(stmt),
+        Statement::DropPasswordPolicy(stmt) => visitor.visit_drop_password_policy(stmt),
+        Statement::DescPasswordPolicy(stmt) => visitor.visit_desc_password_policy(stmt),
+        Statement::ShowPasswordPolicies => visitor.visit_show_password_policies(),
+        Statement::CreateSettingsProfile(stmt) => visitor.visit_create_settings_profile(stmt),
+        Statement::AlterSettingsProfile(stmt) => visitor.visit_alter_settings_profile(stmt),
+        Statement::DropSettingsProfile(stmt) => visitor.visit_drop_settings_profile(stmt),
+        Statement::DescSettingsProfile(stmt) => visitor.visit_desc_settings_profile(stmt),
+        Statement::ShowSettingsProfiles => visitor.visit_show_settings_profiles(),
 
         Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
         Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
@@ -520,6 +543,12 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::DescribeConnection(stmt) => visitor.visit_describe_connection(stmt),
         Statement::ShowConnections(stmt) => visitor.visit_show_connections(stmt),
 
+        Statement::CreateExternalVolume(stmt) => visitor.visit_create_external_volume(stmt),
+        Statement::AlterExternalVolume(stmt) => visitor.visit_alter_external_volume(stmt),
+        Statement::DropExternalVolume(stmt) => visitor.visit_drop_external_volume(stmt),
+        Statement::DescribeExternalVolume(stmt) => visitor.visit_describe_external_volume(stmt),
+        Statement::ShowExternalVolumes(stmt) => visitor.visit_show_external_volumes(stmt),
+
         Statement::CreatePipe(_) => todo!(),
         Statement::AlterPipe(_) => todo!(),
         Statement::DropPipe(_) => todo!(),
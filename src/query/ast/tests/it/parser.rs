@@ -97,6 +97,7 @@ fn test_statement() {
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
+        r#"explain analyze fragments select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table a (c decimal(38, 0))"#,
@@ -124,6 +125,7 @@ fn test_statement() {
         r#"create database t FROM SHARE a.s;"#,
         r#"drop database ctl.t;"#,
         r#"drop database if exists t;"#,
+        r#"drop database if exists t cascade;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
@@ -149,6 +151,10 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET PASSWORD POLICY = 'policy1';"#,
+        r#"ALTER USER u1 WITH UNSET PASSWORD POLICY;"#,
+        r#"ALTER USER u1 WITH SET PROFILE = 'profile1';"#,
+        r#"ALTER USER u1 WITH UNSET PROFILE;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"DROP database if exists db1;"#,
@@ -184,6 +190,7 @@ fn test_statement() {
         r#"select '🦈'"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into t (c1, c2) values (1, 2);   "#,
+        r#"insert into t (c1) idempotent_token = 'abc' values (1);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
@@ -193,6 +200,9 @@ fn test_statement() {
         r#"CREATE STAGE IF NOT EXISTS test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
         r#"DROP STAGE abc"#,
         r#"DROP STAGE ~"#,
+        r#"ALTER STAGE IF EXISTS my_stage RENAME TO my_stage2"#,
+        r#"ALTER STAGE my_stage SET COMMENT = 'internal stage'"#,
+        r#"SHOW CREATE STAGE my_stage"#,
         r#"list @stage_a;"#,
         r#"list @~;"#,
         r#"create user 'test-e' identified by 'password';"#,
@@ -206,6 +216,7 @@ fn test_statement() {
         r#"OPTIMIZE TABLE t COMPACT LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t PURGE BEFORE (STREAM => db.s) FORCE;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
@@ -252,6 +263,10 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT SELECT ON tb1 TO ROLE role1;"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT, INSERT ON FUTURE TABLES IN DATABASE db01 TO ROLE role1;"#,
+        r#"GRANT ALL ON FUTURE TABLES IN DATABASE db01 TO 'u1';"#,
+        r#"GRANT OWNERSHIP ON TABLE db01.tb1 TO ROLE role1 REVOKE CURRENT GRANTS;"#,
+        r#"GRANT OWNERSHIP ON DATABASE db01 TO ROLE role1;"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant';"#,
         r#"SHOW GRANTS FOR USER 'test-grant';"#,
@@ -260,6 +275,7 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE role1;"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
+        r#"REVOKE SELECT ON FUTURE TABLES IN DATABASE db01 FROM ROLE role1;"#,
         r#"drop role 'role1';"#,
         r#"GRANT ROLE test TO ROLE 'test-user';"#,
         r#"GRANT ROLE test TO ROLE `test-user`;"#,
@@ -337,6 +353,11 @@ fn test_statement() {
                     error_on_column_count_mismatch = FALSE
                 )
                 size_limit=10;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                FILE_FORMAT = (type = PARQUET)
+                MATCH_BY_COLUMN_NAME = CASE_INSENSITIVE
+                ERROR_ON_COLUMN_COUNT_MISMATCH = true;"#,
         r#"COPY INTO 's3://mybucket/data.csv'
                 FROM mytable
                 FILE_FORMAT = (
@@ -355,6 +376,9 @@ fn test_statement() {
                     record_delimiter = '\n'
                     skip_header = 1
                 );"#,
+        r#"COPY INTO @my_stage
+                FROM mytable
+                FILE_NAME_TEMPLATE = 'export/{query_id}/{part:05d}.{ext}';"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -429,6 +453,7 @@ fn test_statement() {
         r#"show settings where name='max_memory_usage' limit 1"#,
         r#"show functions like 'today%' limit 1"#,
         r#"show functions where name='to_day_of_year' limit 1"#,
+        r#"describe function to_day_of_year"#,
         r#"show engines like 'FU%' limit 1"#,
         r#"show engines where engine='MEMORY' limit 1"#,
         r#"show metrics like '%parse%' limit 1"#,
@@ -463,6 +488,8 @@ fn test_statement() {
         r#"GRANT usage ON UDF a TO 'test-grant';"#,
         r#"REVOKE usage ON UDF a FROM 'test-grant';"#,
         r#"REVOKE all ON UDF a FROM 'test-grant';"#,
+        r#"GRANT usage, read ON EXTERNAL VOLUME vol TO 'test-grant';"#,
+        r#"REVOKE usage ON EXTERNAL VOLUME vol FROM 'test-grant';"#,
         r#"REVOKE USAGE ON DATABASE db1 FROM SHARE a;"#,
         r#"REVOKE SELECT ON TABLE db1.tb1 FROM SHARE a;"#,
         r#"ALTER SHARE a ADD TENANTS = b,c;"#,
@@ -473,6 +500,10 @@ fn test_statement() {
         r#"SHOW SHARES;"#,
         r#"SHOW GRANTS ON TABLE db1.tb1;"#,
         r#"SHOW GRANTS ON DATABASE db;"#,
+        r#"SHOW GRANTS ON TABLE tb1;"#,
+        r#"SHOW GRANTS ON STAGE s1;"#,
+        r#"SHOW GRANTS ON UDF f1;"#,
+        r#"SHOW GRANTS ON EXTERNAL VOLUME vol;"#,
         r#"SHOW GRANTS OF SHARE t;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
         r#"SET max_threads = 10;"#,
@@ -490,6 +521,9 @@ fn test_statement() {
             type = CSV field_delimiter = ',' record_delimiter = '\n' skip_header = 1;"#,
         r#"SHOW FILE FORMATS"#,
         r#"DROP FILE FORMAT my_csv"#,
+        r#"CREATE FILE FORMAT my_json type = NDJSON COMMENT = 'json format'"#,
+        r#"ALTER FILE FORMAT IF EXISTS my_csv RENAME TO my_csv2"#,
+        r#"ALTER FILE FORMAT my_csv SET COMMENT = 'csv format'"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, c, d)"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, (c, d))"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (c), (d, e))"#,
@@ -499,12 +533,29 @@ fn test_statement() {
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
         r#"DROP MASKING POLICY IF EXISTS email_mask"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS rap1 AS (region STRING) RETURNS BOOLEAN -> region = current_user() COMMENT = 'test'"#,
+        r#"DESC ROW ACCESS POLICY rap1"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap1"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap1 ON (region)"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY rap1"#,
+        r#"GRANT APPLY ROW ACCESS POLICY ON *.* TO ROLE role1"#,
         r#"CREATE VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
+        r#"CREATE INVERTED INDEX IF NOT EXISTS idx1 ON t(message, body) TOKENIZER = 'default'"#,
+        r#"DROP INVERTED INDEX idx1 ON db.t"#,
+        r#"REFRESH INVERTED INDEX idx1 ON t LIMIT 10"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE PASSWORD POLICY mypolicy PASSWORD_MIN_LENGTH=12 PASSWORD_MIN_SPECIAL_CHARS=1 PASSWORD_MAX_RETRIES=3 COMMENT='test'"#,
+        r#"ALTER PASSWORD POLICY IF EXISTS mypolicy SET PASSWORD_MAX_AGE_DAYS=30 PASSWORD_HISTORY=5"#,
+        r#"ALTER PASSWORD POLICY mypolicy UNSET PASSWORD_MIN_SPECIAL_CHARS COMMENT"#,
+        r#"CREATE SETTINGS PROFILE IF NOT EXISTS analyst SETTINGS = (max_threads = 8, timezone = 'Asia/Shanghai') COMMENT = 'test'"#,
+        r#"ALTER SETTINGS PROFILE analyst SET SETTINGS = (max_memory_usage = 1073741824)"#,
+        r#"ALTER SETTINGS PROFILE IF EXISTS analyst UNSET SETTINGS (timezone) COMMENT"#,
+        r#"SHOW SETTINGS PROFILES"#,
+        r#"ALTER ROLE role1 SET PROFILE = 'analyst'"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron' AS insert into t (c1, c2) values (1, 2), (3, 4)"#,
@@ -523,6 +574,15 @@ fn test_statement() {
         r#"DROP CONNECTION IF EXISTS my_conn;"#,
         r#"DESC CONNECTION my_conn;"#,
         r#"SHOW CONNECTIONS;"#,
+        r#"CREATE CONNECTION my_conn STORAGE_TYPE='s3' endpoint_url='http://127.0.0.1:9900' COMMENT='s3 connection'"#,
+        r#"CREATE EXTERNAL VOLUME IF NOT EXISTS vol (type = 's3' bucket = 'my-bucket' role_arn = 'arn:aws:iam::123:role/r') COMMENT = 'shared'"#,
+        r#"ALTER EXTERNAL VOLUME vol SET (type = 's3' bucket = 'my-bucket' role_arn = 'arn:aws:iam::123:role/r2')"#,
+        r#"DROP EXTERNAL VOLUME IF EXISTS vol;"#,
+        r#"DESC EXTERNAL VOLUME vol;"#,
+        r#"SHOW EXTERNAL VOLUMES;"#,
+        r#"CREATE STAGE s1 VOLUME = vol PATH = 'logs/'"#,
+        r#"CREATE TABLE t (a int) VOLUME = vol PATH = 'tables/t/'"#,
+        r#"CREATE TABLE t (a int, b string) BUCKET BY (a) INTO 64 BUCKETS"#,
         // pipes
         r#"CREATE PIPE IF NOT EXISTS MyPipe1 AUTO_INGEST = TRUE COMMENT = 'This is test pipe 1' AS COPY INTO MyTable1 FROM '@~/MyStage1' FILE_FORMAT = (TYPE = 'CSV')"#,
         r#"CREATE PIPE pipe1 AS COPY INTO db1.MyTable1 FROM @~/mybucket/data.csv"#,
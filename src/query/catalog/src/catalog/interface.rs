@@ -53,6 +53,7 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockMeta;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -195,6 +196,24 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     async fn list_tables_history(&self, tenant: &str, db_name: &str)
     -> Result<Vec<Arc<dyn Table>>>;
 
+    /// List the tables of a database by filters and pagination, returns the tables
+    /// and the token to list the next page.
+    ///
+    /// By default, all the tables are listed and filtered in memory.
+    async fn list_tables_with_options(
+        &self,
+        req: ListTableWithOptionsReq,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        let tables = if req.options.include_dropped {
+            self.list_tables_history(&req.tenant, &req.db_name).await?
+        } else {
+            self.list_tables(&req.tenant, &req.db_name).await?
+        };
+        Ok(req.options.apply(tables, |table| {
+            (table.name(), table.get_id(), table.engine())
+        }))
+    }
+
     async fn get_drop_table_infos(
         &self,
         _req: ListDroppedTableReq,
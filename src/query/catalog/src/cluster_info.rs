@@ -19,6 +19,10 @@ use common_meta_types::NodeInfo;
 pub struct Cluster {
     pub local_id: String,
     pub nodes: Vec<Arc<NodeInfo>>,
+    /// False if the local node only coordinates the query, the fragments are only
+    /// placed on the other nodes then, e.g. the local node is not in the warehouse
+    /// of the query.
+    pub local_executor: bool,
 }
 
 impl Cluster {
@@ -40,4 +44,14 @@ impl Cluster {
     pub fn is_empty(&self) -> bool {
         self.nodes.len() <= 1
     }
+
+    /// The nodes the fragments of the queries are placed on, the work of a query is split
+    /// among them.
+    pub fn get_executor_nodes(&self) -> Vec<Arc<NodeInfo>> {
+        self.nodes
+            .iter()
+            .filter(|node| self.local_executor || node.id != self.local_id)
+            .cloned()
+            .collect()
+    }
 }
@@ -24,6 +24,7 @@ use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ListTableOptions;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
@@ -107,6 +108,23 @@ pub trait Database: DynClone + Sync + Send {
         )))
     }
 
+    /// List the tables by filters and pagination, returns the tables and the token to
+    /// list the next page.
+    #[async_backtrace::framed]
+    async fn list_tables_with_options(
+        &self,
+        options: &ListTableOptions,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        let tables = if options.include_dropped {
+            self.list_tables_history().await?
+        } else {
+            self.list_tables().await?
+        };
+        Ok(options.apply(tables, |table| {
+            (table.name(), table.get_id(), table.engine())
+        }))
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
         Err(ErrorCode::Unimplemented(format!(
@@ -33,6 +33,12 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Keeps all the parse errors of each file, to validate the files to copy.
+    #[serde(default)]
+    pub collect_all_errors: bool,
+    /// Template of the names of the unloaded files, see `FILE_NAME_TEMPLATE` of `COPY INTO <location>`.
+    #[serde(default)]
+    pub file_name_template: Option<String>,
 }
 
 impl StageTableInfo {
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use parking_lot::RwLock;
 use rand::prelude::SliceRandom;
@@ -36,6 +37,11 @@ pub trait PartInfo: Send + Sync {
 
     /// Used for partition distributed.
     fn hash(&self) -> u64;
+
+    /// The bucket of the partition if it belongs to a bucketed table.
+    fn bucket_id(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl Debug for Box<dyn PartInfo> {
@@ -75,6 +81,9 @@ pub enum PartitionsShuffleKind {
     Rand,
     // Bind the Partition to executor by broadcast
     Broadcast,
+    // Bind the Partition to executor by partition.bucket_id()%executor_nums, so that
+    // the same bucket of different tables is always routed to the same executor.
+    Bucket,
 }
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Partitions {
@@ -135,6 +144,25 @@ impl Partitions {
                 parts.shuffle(&mut rng);
                 parts
             }
+            PartitionsShuffleKind::Bucket => {
+                let mut executor_parts = vec![vec![]; num_executors];
+                for part in self.partitions.iter() {
+                    let bucket_id = part.bucket_id().ok_or_else(|| {
+                        ErrorCode::Internal("Partition of bucketed table has no bucket id")
+                    })?;
+                    executor_parts[(bucket_id % num_executors as u64) as usize].push(part.clone());
+                }
+
+                let mut executor_part = HashMap::default();
+                for (executor, parts) in executors_sorted.into_iter().zip(executor_parts) {
+                    executor_part.insert(
+                        executor,
+                        Partitions::create(PartitionsShuffleKind::Seq, parts, self.is_lazy),
+                    );
+                }
+
+                return Ok(executor_part);
+            }
             PartitionsShuffleKind::Broadcast => {
                 let mut executor_part = HashMap::default();
                 for executor in executors_sorted.iter() {
@@ -25,6 +25,10 @@ pub struct PruningStatistics {
     /// Block bloom filter pruning stats.
     pub blocks_bloom_pruning_before: usize,
     pub blocks_bloom_pruning_after: usize,
+
+    /// Block inverted index pruning stats.
+    pub blocks_inverted_index_pruning_before: usize,
+    pub blocks_inverted_index_pruning_after: usize,
 }
 
 impl PruningStatistics {
@@ -35,5 +39,7 @@ impl PruningStatistics {
         self.blocks_range_pruning_after += other.blocks_range_pruning_after;
         self.blocks_bloom_pruning_before += other.blocks_bloom_pruning_before;
         self.blocks_bloom_pruning_after += other.blocks_bloom_pruning_after;
+        self.blocks_inverted_index_pruning_before += other.blocks_inverted_index_pruning_before;
+        self.blocks_inverted_index_pruning_after += other.blocks_inverted_index_pruning_after;
     }
 }
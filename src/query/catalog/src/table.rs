@@ -288,7 +288,7 @@ pub trait Table: Sync + Send {
         )))
     }
 
-    fn get_block_thresholds(&self) -> BlockThresholds {
+    fn get_block_thresholds(&self, _ctx: Arc<dyn TableContext>) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
             min_rows_per_block: DEFAULT_BLOCK_MIN_ROWS,
@@ -417,6 +417,8 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    /// The snapshot which the stream is created at.
+    StreamInfo(TableInfo),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
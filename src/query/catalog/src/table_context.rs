@@ -19,6 +19,7 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
@@ -71,9 +72,16 @@ pub struct ProcessInfo {
     /// storage metrics for persisted data reading.
     pub data_metrics: Option<StorageMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    pub write_progress_value: Option<ProgressValues>,
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    /// id of the query running in the session.
+    pub query_id: Option<String>,
+    /// sanitized `query_tag` setting of the session or the running query.
+    pub query_tag: String,
+    /// ids of the plan fragments of the query executed by this node.
+    pub fragment_ids: Vec<usize>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -93,6 +101,83 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// The process info reported by a node of the cluster, one row of `system.processes`.
+///
+/// Unlike `ProcessInfo`, it can be sent between the nodes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct NodeProcessInfo {
+    pub node_id: String,
+    pub id: String,
+    pub typ: String,
+    pub host: Option<String>,
+    pub user: String,
+    pub state: String,
+    pub database: String,
+    pub extra_info: String,
+    pub memory_usage: i64,
+    pub data_read_bytes: u64,
+    pub data_write_bytes: u64,
+    pub scan_progress_read_rows: u64,
+    pub scan_progress_read_bytes: u64,
+    pub write_progress_rows: u64,
+    pub write_progress_bytes: u64,
+    pub mysql_connection_id: Option<u32>,
+    pub time: u64,
+    pub status: String,
+    pub query_id: String,
+    pub query_tag: String,
+    pub fragment_ids: Vec<usize>,
+}
+
+impl NodeProcessInfo {
+    pub fn create(node_id: &str, process_info: &ProcessInfo) -> NodeProcessInfo {
+        let scan_progress = process_info.scan_progress_value.clone().unwrap_or_default();
+        let write_progress = process_info
+            .write_progress_value
+            .clone()
+            .unwrap_or_default();
+        let (data_read_bytes, data_write_bytes) = match &process_info.data_metrics {
+            Some(data_metrics) => (
+                data_metrics.get_read_bytes() as u64,
+                data_metrics.get_write_bytes() as u64,
+            ),
+            None => (0, 0),
+        };
+
+        NodeProcessInfo {
+            node_id: node_id.to_string(),
+            id: process_info.id.clone(),
+            typ: process_info.typ.clone(),
+            host: process_info.client_address.as_ref().map(|s| s.to_string()),
+            user: process_info
+                .user
+                .as_ref()
+                .map(|u| u.name.clone())
+                .unwrap_or_default(),
+            state: process_info.state.to_string(),
+            database: process_info.database.clone(),
+            extra_info: process_info.session_extra_info.clone().unwrap_or_default(),
+            memory_usage: process_info.memory_usage,
+            data_read_bytes,
+            data_write_bytes,
+            scan_progress_read_rows: scan_progress.rows as u64,
+            scan_progress_read_bytes: scan_progress.bytes as u64,
+            write_progress_rows: write_progress.rows as u64,
+            write_progress_bytes: write_progress.bytes as u64,
+            mysql_connection_id: process_info.mysql_connection_id,
+            time: process_info
+                .created_time
+                .elapsed()
+                .unwrap_or(Duration::from_secs(0))
+                .as_secs(),
+            status: process_info.status_info.clone().unwrap_or_default(),
+            query_id: process_info.query_id.clone().unwrap_or_default(),
+            query_tag: process_info.query_tag.clone(),
+            fragment_ids: process_info.fragment_ids.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -158,6 +243,8 @@ pub trait TableContext: Send + Sync {
         unimplemented!()
     }
     async fn get_available_roles(&self) -> Result<Vec<RoleInfo>>;
+    /// Get the roles in effect of the session, the current role and the secondary roles.
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>>;
     async fn get_visibility_checker(&self) -> Result<GrantObjectVisibilityChecker>;
     fn get_fuse_version(&self) -> String;
     fn get_format_settings(&self) -> Result<FormatSettings>;
@@ -170,6 +257,9 @@ pub trait TableContext: Send + Sync {
     fn get_shared_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// Get the processes info of all the nodes of the cluster, the nodes that fail to
+    /// respond in time are skipped.
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
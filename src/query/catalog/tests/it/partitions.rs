@@ -213,6 +213,65 @@ fn test_partition_reshuffle() {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+struct TestBucketPartInfo {
+    pub loc: String,
+    pub bucket_id: u64,
+}
+
+#[typetag::serde(name = "test_bucket")]
+impl PartInfo for TestBucketPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<TestBucketPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.loc.hash(&mut s);
+        s.finish()
+    }
+
+    fn bucket_id(&self) -> Option<u64> {
+        Some(self.bucket_id)
+    }
+}
+
+#[test]
+fn test_partition_reshuffle_bucket() {
+    let executors = vec!["node-2".to_string(), "node-1".to_string()];
+
+    let parts = (0..8)
+        .map(|i| -> PartInfoPtr {
+            Arc::new(Box::new(TestBucketPartInfo {
+                loc: format!("{}", i),
+                bucket_id: i % 4,
+            }))
+        })
+        .collect::<Vec<_>>();
+    let partitions = Partitions::create(PartitionsShuffleKind::Bucket, parts, false);
+    let shuffle = partitions.reshuffle(executors).unwrap();
+
+    // The executors are sorted, the bucket i is routed to the executor i % 2.
+    for (executor, remainder) in [("node-1", 0), ("node-2", 1)] {
+        let parts = shuffle.get(executor).unwrap();
+        assert_eq!(parts.kind, PartitionsShuffleKind::Seq);
+        assert_eq!(parts.len(), 4);
+        for part in parts.partitions.iter() {
+            assert_eq!(part.bucket_id().unwrap() % 2, remainder);
+        }
+    }
+
+    // Partitions without bucket id cannot be routed by bucket.
+    let partitions = gen_parts(PartitionsShuffleKind::Bucket, 3);
+    assert!(partitions.reshuffle(vec!["node-1".to_string()]).is_err());
+}
+
 #[test]
 fn test_split() {
     for seg in 0..1024 * 10 {
@@ -63,6 +63,36 @@ pub struct BackgroundCompactionConfig {
     #[clap(long, value_name = "VALUE")]
     pub block_limit: Option<u64>,
 
+    // Recluster unclustered blocks of tables with cluster key.
+    // `recluster_limit` is the maximum number of segments that would be reclustered in a batch
+    // None represent their is no limit
+    #[clap(long, value_name = "VALUE")]
+    pub recluster_limit: Option<u64>,
+
+    // Tables are ranked by their fragmentation score in each cycle,
+    // only the top `max_tables_per_cycle` tables would be compacted.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub max_tables_per_cycle: u64,
+
+    // The maximum uncompressed bytes of tables that would be compacted in a cycle.
+    // None represent their is no limit
+    #[clap(long, value_name = "VALUE")]
+    pub max_bytes_per_cycle: Option<u64>,
+
+    // The maximum seconds a cycle could take, tables not compacted in time are left to the next cycle.
+    // None represent their is no limit
+    #[clap(long, value_name = "VALUE")]
+    pub max_cycle_duration_secs: Option<u64>,
+
+    // Skip tables compacted within the last `min_compaction_interval_secs` seconds.
+    #[clap(long, value_name = "VALUE", default_value = "3600")]
+    pub min_compaction_interval_secs: u64,
+
+    // Compaction queries run with at most `compaction_max_threads` threads,
+    // so that they don't compete with user queries.
+    #[clap(long, value_name = "VALUE", default_value = "2")]
+    pub compaction_max_threads: u64,
+
     #[clap(flatten)]
     pub scheduled_config: BackgroundScheduledConfig,
 }
@@ -114,6 +144,12 @@ pub struct InnerBackgroundCompactionConfig {
     pub target_tables: Option<Vec<String>>,
     pub segment_limit: Option<u64>,
     pub block_limit: Option<u64>,
+    pub recluster_limit: Option<u64>,
+    pub max_tables_per_cycle: u64,
+    pub max_bytes_per_cycle: Option<u64>,
+    pub max_cycle_duration_secs: Option<u64>,
+    pub min_compaction_interval_secs: u64,
+    pub max_threads: u64,
     pub params: BackgroundJobParams,
 }
 
@@ -150,6 +186,12 @@ impl TryInto<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
         Ok(InnerBackgroundCompactionConfig {
             segment_limit: self.segment_limit,
             block_limit: self.block_limit,
+            recluster_limit: self.recluster_limit,
+            max_tables_per_cycle: self.max_tables_per_cycle,
+            max_bytes_per_cycle: self.max_bytes_per_cycle,
+            max_cycle_duration_secs: self.max_cycle_duration_secs,
+            min_compaction_interval_secs: self.min_compaction_interval_secs,
+            max_threads: self.compaction_max_threads,
             enable: self.enable_compaction,
             target_tables: self.target_tables,
             params: {
@@ -196,6 +238,12 @@ impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
             target_tables: inner.target_tables,
             segment_limit: inner.segment_limit,
             block_limit: inner.block_limit,
+            recluster_limit: inner.recluster_limit,
+            max_tables_per_cycle: inner.max_tables_per_cycle,
+            max_bytes_per_cycle: inner.max_bytes_per_cycle,
+            max_cycle_duration_secs: inner.max_cycle_duration_secs,
+            min_compaction_interval_secs: inner.min_compaction_interval_secs,
+            compaction_max_threads: inner.max_threads,
             scheduled_config: Default::default(), // it would be set later
         };
         match inner.params.job_type {
@@ -238,6 +286,12 @@ impl Default for BackgroundCompactionConfig {
             target_tables: None,
             segment_limit: None,
             block_limit: None,
+            recluster_limit: None,
+            max_tables_per_cycle: 10,
+            max_bytes_per_cycle: None,
+            max_cycle_duration_secs: None,
+            min_compaction_interval_secs: 3600,
+            compaction_max_threads: 2,
             scheduled_config: Default::default(),
         }
     }
@@ -249,6 +303,15 @@ impl Debug for BackgroundCompactionConfig {
             .field("mode", &self.compact_mode)
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field("recluster_limit", &self.recluster_limit)
+            .field("max_tables_per_cycle", &self.max_tables_per_cycle)
+            .field("max_bytes_per_cycle", &self.max_bytes_per_cycle)
+            .field("max_cycle_duration_secs", &self.max_cycle_duration_secs)
+            .field(
+                "min_compaction_interval_secs",
+                &self.min_compaction_interval_secs,
+            )
+            .field("compaction_max_threads", &self.compaction_max_threads)
             .field("fixed_config", &self.scheduled_config)
             .finish()
     }
@@ -281,6 +344,12 @@ impl Default for InnerBackgroundConfig {
                 target_tables: None,
                 segment_limit: None,
                 block_limit: None,
+                recluster_limit: None,
+                max_tables_per_cycle: 10,
+                max_bytes_per_cycle: None,
+                max_cycle_duration_secs: None,
+                min_compaction_interval_secs: 3600,
+                max_threads: 2,
                 params: Default::default(),
             },
         }
@@ -300,6 +369,15 @@ impl Debug for InnerBackgroundCompactionConfig {
         f.debug_struct("InnerBackgroundCompactionConfig")
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field("recluster_limit", &self.recluster_limit)
+            .field("max_tables_per_cycle", &self.max_tables_per_cycle)
+            .field("max_bytes_per_cycle", &self.max_bytes_per_cycle)
+            .field("max_cycle_duration_secs", &self.max_cycle_duration_secs)
+            .field(
+                "min_compaction_interval_secs",
+                &self.min_compaction_interval_secs,
+            )
+            .field("max_threads", &self.max_threads)
             .field("params", &self.params)
             .finish()
     }
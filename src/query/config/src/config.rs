@@ -1325,6 +1325,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub cluster_id: String,
 
+    /// Labels of the node registered to the cluster, e.g. the warehouse of the node.
+    /// The queries can be routed to the nodes by the `warehouse` setting.
+    #[clap(long, value_name = "VALUE")]
+    pub node_labels: Vec<String>,
+
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub num_cpus: u64,
 
@@ -1376,6 +1381,18 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "60")]
     pub http_handler_result_timeout_secs: u64,
 
+    /// The secret used to sign the session tokens issued by the http handler, at least 32 bytes.
+    /// Required to use session tokens if the nodes share a remote meta. If empty on a standalone
+    /// node, a random secret is generated on startup.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub http_session_token_secret: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "3600")]
+    pub http_session_token_ttl_secs: u64,
+
+    #[clap(long, value_name = "VALUE", default_value = "14400")]
+    pub http_refresh_token_ttl_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
     pub flight_sql_handler_host: String,
 
@@ -1442,6 +1459,24 @@ pub struct QueryConfig {
 
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
+
+    /// Max number of the latest audit events kept in `system.audit_log`.
+    #[clap(long, value_name = "VALUE", default_value = "10000")]
+    pub max_audit_log_size: usize,
+
+    /// The directory to write audit events as json lines, disabled if empty.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub audit_log_dir: String,
+
+    /// What to do if an audit event fails to be written: `warn` only logs the error,
+    /// `fail` returns the error to the client.
+    #[clap(long, value_name = "VALUE", default_value = "warn")]
+    pub audit_log_on_failure: String,
+
+    /// The local directory of the write-ahead log of the buffered inserts,
+    /// the buffered rows are only kept in memory if empty.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub write_buffer_wal_dir: String,
     /// Parquet file with smaller size will be read as a whole file, instead of column by column.
     /// For example:
     /// parquet_fast_read_bytes = 52428800
@@ -1605,6 +1640,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             tenant_id: self.tenant_id,
             cluster_id: self.cluster_id,
             node_id: "".to_string(),
+            node_labels: self.node_labels,
             num_cpus: self.num_cpus,
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
@@ -1619,6 +1655,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             http_handler_host: self.http_handler_host,
             http_handler_port: self.http_handler_port,
             http_handler_result_timeout_secs: self.http_handler_result_timeout_secs,
+            http_session_token_secret: self.http_session_token_secret,
+            http_session_token_ttl_secs: self.http_session_token_ttl_secs,
+            http_refresh_token_ttl_secs: self.http_refresh_token_ttl_secs,
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
@@ -1640,6 +1679,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            max_audit_log_size: self.max_audit_log_size,
+            audit_log_dir: self.audit_log_dir,
+            audit_log_on_failure: self.audit_log_on_failure,
+            write_buffer_wal_dir: self.write_buffer_wal_dir,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1676,6 +1719,7 @@ impl From<InnerQueryConfig> for QueryConfig {
         Self {
             tenant_id: inner.tenant_id,
             cluster_id: inner.cluster_id,
+            node_labels: inner.node_labels,
             num_cpus: inner.num_cpus,
             mysql_handler_host: inner.mysql_handler_host,
             mysql_handler_port: inner.mysql_handler_port,
@@ -1696,6 +1740,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             http_handler_host: inner.http_handler_host,
             http_handler_port: inner.http_handler_port,
             http_handler_result_timeout_secs: inner.http_handler_result_timeout_secs,
+            http_session_token_secret: inner.http_session_token_secret,
+            http_session_token_ttl_secs: inner.http_session_token_ttl_secs,
+            http_refresh_token_ttl_secs: inner.http_refresh_token_ttl_secs,
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
@@ -1717,6 +1764,10 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            max_audit_log_size: inner.max_audit_log_size,
+            audit_log_dir: inner.audit_log_dir,
+            audit_log_on_failure: inner.audit_log_on_failure,
+            write_buffer_wal_dir: inner.write_buffer_wal_dir,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -152,6 +152,9 @@ pub struct QueryConfig {
     // ID for the query node.
     // This only initialized when InnerConfig::load().
     pub node_id: String,
+    /// Labels of the node registered to the cluster, e.g. the warehouse of the node.
+    /// The queries can be routed to the nodes by the `warehouse` setting.
+    pub node_labels: Vec<String>,
     pub num_cpus: u64,
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
@@ -166,6 +169,9 @@ pub struct QueryConfig {
     pub http_handler_host: String,
     pub http_handler_port: u16,
     pub http_handler_result_timeout_secs: u64,
+    pub http_session_token_secret: String,
+    pub http_session_token_ttl_secs: u64,
+    pub http_refresh_token_ttl_secs: u64,
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
@@ -191,6 +197,10 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    pub max_audit_log_size: usize,
+    pub audit_log_dir: String,
+    pub audit_log_on_failure: String,
+    pub write_buffer_wal_dir: String,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -231,6 +241,7 @@ impl Default for QueryConfig {
             tenant_id: "admin".to_string(),
             cluster_id: "".to_string(),
             node_id: "".to_string(),
+            node_labels: Vec::new(),
             num_cpus: 0,
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
@@ -245,6 +256,9 @@ impl Default for QueryConfig {
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_secs: 60,
+            http_session_token_secret: "".to_string(),
+            http_session_token_ttl_secs: 3600,
+            http_refresh_token_ttl_secs: 14400,
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
@@ -265,6 +279,10 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10_000,
+            max_audit_log_size: 10_000,
+            audit_log_dir: "".to_string(),
+            audit_log_on_failure: "warn".to_string(),
+            write_buffer_wal_dir: "".to_string(),
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -309,6 +327,7 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.http_session_token_secret = mask_string(&self.http_session_token_secret, 3);
         sanitized
     }
 }
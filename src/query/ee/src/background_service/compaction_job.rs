@@ -20,14 +20,20 @@ use arrow_array::BooleanArray;
 use arrow_array::LargeBinaryArray;
 use arrow_array::RecordBatch;
 use arrow_array::UInt64Array;
+use chrono::DateTime;
 use chrono::Utc;
 use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
 use common_base::base::tokio::time::Instant;
 use common_base::base::uuid::Uuid;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::lock::LockExt;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_api::BackgroundApi;
+use common_meta_app::background::AcquireBackgroundJobLeaseReq;
 use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobParams;
@@ -36,13 +42,19 @@ use common_meta_app::background::BackgroundJobType::ONESHOT;
 use common_meta_app::background::BackgroundTaskIdent;
 use common_meta_app::background::BackgroundTaskInfo;
 use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
 use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ListBackgroundTasksReq;
 use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobMessageReq;
 use common_meta_app::background::UpdateBackgroundJobParamsReq;
 use common_meta_app::background::UpdateBackgroundJobStatusReq;
 use common_meta_app::background::UpdateBackgroundTaskReq;
 use common_meta_app::schema::TableStatistics;
 use common_meta_store::MetaStore;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::DEFAULT_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
@@ -51,15 +63,25 @@ use log::as_debug;
 use log::debug;
 use log::error;
 use log::info;
+use storages_common_locks::LockManager;
+use storages_common_table_meta::table::OPT_KEY_BACKGROUND_COMPACTION;
 
 use crate::background_service::job::Job;
 use crate::background_service::session::create_session;
+use crate::background_service::table_fragmentation::rank_candidates;
+use crate::background_service::table_fragmentation::select_candidates;
+use crate::background_service::table_fragmentation::CompactionCandidate;
+use crate::background_service::table_fragmentation::CompactionCycleReport;
+use crate::background_service::table_fragmentation::CompactionDecision;
+use crate::background_service::table_fragmentation::TableFragmentation;
+use crate::background_service::table_fragmentation::MIN_FRAGMENTATION_SCORE;
 
 const BLOCK_COUNT: u64 = 500;
 const PER_SEGMENT_BLOCK: u64 = 500;
 const PER_BLOCK_SIZE: u64 = 50; // MB
 
 const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+const LEASE_SEC: u64 = 60 * 30; // 30 minutes
 
 #[derive(Clone)]
 pub struct CompactionJob {
@@ -158,6 +180,12 @@ impl CompactionJob {
     }
     async fn do_compaction_job(&mut self) -> Result<()> {
         let session = create_session(&self.conf).await?;
+        // compaction runs at background priority, with a limited number of threads
+        if self.conf.background.compaction.max_threads > 0 {
+            session
+                .get_settings()
+                .set_max_threads(self.conf.background.compaction.max_threads)?;
+        }
         let ctx = session.create_query_context().await?;
         let job_info = self.get_info().await?;
 
@@ -165,6 +193,75 @@ impl CompactionJob {
         // guarantee at least once for maunal job
         self.update_job_params(params).await?;
 
+        if self.acquire_lease().await? {
+            let report = self
+                .do_compaction_cycle(session.clone(), ctx.clone(), manual)
+                .await?;
+            info!(job = "compaction", background = true, report = report.to_string().as_str(); "compaction cycle report");
+            self.update_job_message(report.to_string()).await?;
+        }
+
+        info!(
+            job = "compaction",
+            background = true;
+            "compaction task is done"
+        );
+        if self.conf.background.compaction.params.job_type == ONESHOT {
+            let finish_tx = self.finish_tx.clone();
+            let _ = finish_tx.lock().await.send(1).await;
+        }
+
+        Ok(())
+    }
+
+    // only the holder of the job lease runs the cycle, others in the cluster skip it.
+    async fn acquire_lease(&self) -> Result<bool> {
+        let lease = self
+            .conf
+            .background
+            .compaction
+            .max_cycle_duration_secs
+            .unwrap_or(LEASE_SEC)
+            + LEASE_SEC;
+        let reply = self
+            .meta_api
+            .acquire_background_job_lease(AcquireBackgroundJobLeaseReq {
+                job_name: self.creator.clone(),
+                holder: self.conf.query.node_id.clone(),
+                lease: Duration::from_secs(lease),
+            })
+            .await?;
+        if !reply.acquired {
+            info!(job = "compaction", background = true, holder = reply.holder.as_str(); "skip compaction cycle, lease is held by another node");
+        }
+        Ok(reply.acquired)
+    }
+
+    async fn update_job_message(&self, message: String) -> Result<()> {
+        self.meta_api
+            .update_background_job_message(UpdateBackgroundJobMessageReq {
+                job_name: self.creator.clone(),
+                message,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn do_compaction_cycle(
+        &mut self,
+        session: Arc<Session>,
+        ctx: Arc<QueryContext>,
+        manual: Option<ManualTriggerParams>,
+    ) -> Result<CompactionCycleReport> {
+        let config = self.conf.background.compaction.clone();
+        let start = Instant::now();
+        let deadline = config
+            .max_cycle_duration_secs
+            .map(|secs| start + Duration::from_secs(secs));
+        let mut report = CompactionCycleReport::default();
+
+        let recently_compacted = self.get_recently_compacted_tables().await?;
+        let mut candidates = vec![];
         for records in Self::do_get_target_tables_from_config(&self.conf, ctx.clone()).await? {
             debug!(records = as_debug!(&records); "target_tables");
             let db_names = records
@@ -188,47 +285,182 @@ impl CompactionJob {
                 .downcast_ref::<UInt64Array>()
                 .unwrap();
             for i in 0..records.num_rows() {
-                let db_name = String::from_utf8_lossy(db_names.value(i)).to_string();
-                let db_id = db_ids.value(i);
-                let tb_name = String::from_utf8_lossy(tb_names.value(i)).to_string();
-                let tb_id = tb_ids.value(i);
-                match self
-                    .compact_table(
-                        session.clone(),
-                        db_name.clone(),
-                        tb_name.clone(),
-                        db_id,
-                        tb_id,
-                        manual.clone(),
-                    )
+                let mut candidate = CompactionCandidate {
+                    db_name: String::from_utf8_lossy(db_names.value(i)).to_string(),
+                    db_id: db_ids.value(i),
+                    table_name: String::from_utf8_lossy(tb_names.value(i)).to_string(),
+                    table_id: tb_ids.value(i),
+                    fragmentation: TableFragmentation::default(),
+                };
+                let table = match ctx
+                    .get_table(CATALOG_DEFAULT, &candidate.db_name, &candidate.table_name)
                     .await
                 {
-                    Ok(_) => {
-                        info!(
-                            "compaction job success, db: {}, table: {}",
-                            db_name, tb_name
-                        );
-                    }
+                    Ok(table) => table,
                     Err(e) => {
-                        error!(
-                            "compaction job failed, db: {}, table: {}, err: {}",
-                            db_name, tb_name, e
-                        );
+                        report.record(&candidate, CompactionDecision::Failed(e.to_string()));
+                        continue;
                     }
+                };
+
+                if let Some(decision) = Self::check_skip(
+                    ctx.clone(),
+                    table.clone(),
+                    &recently_compacted,
+                    config.min_compaction_interval_secs,
+                )
+                .await?
+                {
+                    info!(job = "compaction", background = true, database = candidate.db_name.as_str(), table = candidate.table_name.as_str(), decision = decision.to_string().as_str(); "skip compact");
+                    report.record(&candidate, decision);
+                    continue;
+                }
+
+                candidate.fragmentation =
+                    match Self::get_table_fragmentation(ctx.clone(), table.clone(), &candidate)
+                        .await
+                    {
+                        Ok(fragmentation) => fragmentation,
+                        Err(e) => {
+                            report.record(&candidate, CompactionDecision::Failed(e.to_string()));
+                            continue;
+                        }
+                    };
+                // tables listed in config are always compacted
+                if !config.has_target_tables() && candidate.score() < MIN_FRAGMENTATION_SCORE {
+                    report.record(&candidate, CompactionDecision::SkipHealthy);
+                    continue;
                 }
+                candidates.push(candidate);
             }
         }
-        info!(
-            job = "compaction",
-            background = true;
-            "compaction task is done"
+
+        rank_candidates(&mut candidates);
+        let selected = select_candidates(
+            candidates,
+            config.max_tables_per_cycle,
+            config.max_bytes_per_cycle,
+            &mut report,
         );
-        if self.conf.background.compaction.params.job_type == ONESHOT {
-            let finish_tx = self.finish_tx.clone();
-            let _ = finish_tx.lock().await.send(1).await;
+
+        for candidate in selected {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                report.record(&candidate, CompactionDecision::DeferredByTimeBudget);
+                continue;
+            }
+            match self
+                .compact_table(session.clone(), &candidate, manual.clone(), deadline)
+                .await
+            {
+                Ok(_) => {
+                    info!(
+                        "compaction job success, db: {}, table: {}",
+                        candidate.db_name, candidate.table_name
+                    );
+                    report.record(&candidate, CompactionDecision::Compacted);
+                }
+                Err(e) => {
+                    error!(
+                        "compaction job failed, db: {}, table: {}, err: {}",
+                        candidate.db_name, candidate.table_name, e
+                    );
+                    report.record(&candidate, CompactionDecision::Failed(e.to_string()));
+                }
+            }
         }
+        Ok(report)
+    }
 
-        Ok(())
+    // returns the reason if the table should not be compacted in this cycle.
+    async fn check_skip(
+        ctx: Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        recently_compacted: &HashMap<u64, DateTime<Utc>>,
+        min_interval_secs: u64,
+    ) -> Result<Option<CompactionDecision>> {
+        let opt_out = table
+            .options()
+            .get(OPT_KEY_BACKGROUND_COMPACTION)
+            .map(|v| v.to_lowercase() == "false")
+            .unwrap_or(false);
+        if opt_out {
+            return Ok(Some(CompactionDecision::SkipOptOut));
+        }
+
+        let table_info = table.get_table_info();
+        if let Some(last) = recently_compacted.get(&table_info.ident.table_id) {
+            if Utc::now().signed_duration_since(*last).num_seconds() < min_interval_secs as i64 {
+                return Ok(Some(CompactionDecision::SkipRecentlyCompacted));
+            }
+        }
+
+        let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        let table_lock = LockManager::create_table_lock(table_info.clone())?;
+        if table_lock.check_lock(catalog).await? {
+            return Ok(Some(CompactionDecision::SkipLocked));
+        }
+        Ok(None)
+    }
+
+    // the last time each table is compacted by the background service.
+    async fn get_recently_compacted_tables(&self) -> Result<HashMap<u64, DateTime<Utc>>> {
+        let tasks = self
+            .meta_api
+            .list_background_tasks(ListBackgroundTasksReq::new(self.creator.tenant.clone()))
+            .await?;
+        let mut res: HashMap<u64, DateTime<Utc>> = HashMap::new();
+        for (_, _, task) in tasks {
+            if task.task_type != BackgroundTaskType::COMPACTION
+                || task.task_state != BackgroundTaskState::DONE
+            {
+                continue;
+            }
+            if let (Some(stats), Some(updated)) = (task.compaction_task_stats, task.last_updated) {
+                let last = res.entry(stats.table_id).or_insert(updated);
+                if *last < updated {
+                    *last = updated;
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    pub async fn get_table_fragmentation(
+        ctx: Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        candidate: &CompactionCandidate,
+    ) -> Result<TableFragmentation> {
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+            return Ok(TableFragmentation::default());
+        };
+        let unclustered_block_count = if fuse_table.cluster_key_meta().is_some() {
+            let sql = Self::get_unclustered_blocks_sql(
+                candidate.db_name.clone(),
+                candidate.table_name.clone(),
+            );
+            let res = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+            res.map(|r| {
+                r.column(0)
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .value(0)
+            })
+            .unwrap_or_default()
+        } else {
+            0
+        };
+        Ok(TableFragmentation {
+            block_count: snapshot.summary.block_count,
+            perfect_block_count: snapshot.summary.perfect_block_count,
+            segment_count: snapshot.segments.len() as u64,
+            block_per_segment: fuse_table
+                .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
+                as u64,
+            unclustered_block_count,
+            bytes_uncompressed: snapshot.summary.uncompressed_byte_size,
+        })
     }
 
     fn set_task_status(info: &mut BackgroundTaskInfo, state: BackgroundTaskState) {
@@ -272,13 +504,13 @@ impl CompactionJob {
     async fn compact_table(
         &mut self,
         session: Arc<Session>,
-        database: String,
-        table: String,
-        db_id: u64,
-        tb_id: u64,
+        candidate: &CompactionCandidate,
         manual: Option<ManualTriggerParams>,
+        deadline: Option<Instant>,
     ) -> Result<()> {
-        let (seg, blk, stats) = Self::do_check_table(
+        let database = candidate.db_name.clone();
+        let table = candidate.table_name.clone();
+        let (_, _, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
             table.clone(),
@@ -287,18 +519,19 @@ impl CompactionJob {
             PER_BLOCK_SIZE,
         )
         .await?;
-        let (seg, blk, stats) = if !self.conf.background.compaction.has_target_tables() {
-            if !seg && !blk {
-                info!(job = "compaction", background = true, database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, table_stats = as_debug!(&stats); "skip compact");
-                return Ok(());
-            }
-            (seg, blk, stats)
+        let fragmentation = &candidate.fragmentation;
+        let (seg, blk) = if !self.conf.background.compaction.has_target_tables() {
+            (
+                fragmentation.need_segment_compact(),
+                fragmentation.need_block_compact(),
+            )
         } else {
-            (true, true, stats)
+            (true, true)
         };
+        let recluster = fragmentation.need_recluster();
 
-        if !seg && !blk {
-            info!(job = "compaction", background = true, database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, table_stats = as_debug!(&stats); "skip compact");
+        if !seg && !blk && !recluster {
+            info!(job = "compaction", background = true, database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, should_recluster = recluster, table_stats = as_debug!(&stats); "skip compact");
             return Ok(());
         }
         let job_info = self.get_info().await?;
@@ -310,20 +543,23 @@ impl CompactionJob {
         }
         self.update_job_status(status.clone().unwrap()).await?;
 
-        info!(job = "compaction", background = true, id=id.clone(), database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, table_stats = as_debug!(&stats); "start compact");
+        info!(job = "compaction", background = true, id=id.clone(), database = database.clone(), table = table.clone(), score = candidate.score(), should_compact_segment = seg, should_compact_blk = blk, should_recluster = recluster, table_stats = as_debug!(&stats); "start compact");
         let task_name = BackgroundTaskIdent {
             tenant: self.creator.tenant.clone(),
             task_id: status.unwrap().last_task_id.unwrap(),
         };
         let mut info = BackgroundTaskInfo::new_compaction_task(
             self.creator.clone(),
-            db_id,
-            tb_id,
+            candidate.db_id,
+            candidate.table_id,
             stats,
             manual,
             format!(
-                "need segment compaction: {}, need block compaction: {}",
-                seg, blk
+                "fragmentation score: {:.2}, need segment compaction: {}, need block compaction: {}, need recluster: {}",
+                candidate.score(),
+                seg,
+                blk,
+                recluster
             ),
         );
         self.meta_api
@@ -337,7 +573,13 @@ impl CompactionJob {
         let start = Instant::now();
 
         match self
-            .do_compact_table(session.clone(), database.clone(), table.clone())
+            .do_compact_table(
+                session.clone(),
+                database.clone(),
+                table.clone(),
+                (seg, blk, recluster),
+                deadline,
+            )
             .await
         {
             Ok(_) => {
@@ -371,20 +613,28 @@ impl CompactionJob {
                         expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
                     })
                     .await?;
+                return Err(e);
             }
         }
         Ok(())
     }
 
-    // continuous compact on table until it's not needed
+    // continuous compact on table until it's not needed or the deadline is reached
     // return true if actually compacted
     async fn do_compact_table(
         &self,
         session: Arc<Session>,
         database: String,
         table: String,
+        (seg, blk, recluster): (bool, bool, bool),
+        deadline: Option<Instant>,
     ) -> Result<bool> {
-        let (seg, blk, stats) = Self::do_check_table(
+        if !seg && !blk && !recluster {
+            return Ok(false);
+        }
+        let timeout = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+        let (_, _, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
             table.clone(),
@@ -393,10 +643,6 @@ impl CompactionJob {
             PER_BLOCK_SIZE,
         )
         .await?;
-        if !seg && !blk {
-            return Ok(false);
-        }
-
         let mut old = stats;
         if seg {
             loop {
@@ -416,7 +662,7 @@ impl CompactionJob {
                     PER_BLOCK_SIZE,
                 )
                 .await?;
-                if !should_continue_compaction(&old, &new).0 {
+                if !should_continue_compaction(&old, &new).0 || timeout() {
                     old = new;
                     break;
                 }
@@ -424,7 +670,7 @@ impl CompactionJob {
             }
         }
 
-        if blk {
+        if blk && !timeout() {
             loop {
                 self.do_block_compaction(
                     session.clone(),
@@ -442,13 +688,23 @@ impl CompactionJob {
                     PER_BLOCK_SIZE,
                 )
                 .await?;
-                if !should_continue_compaction(&old, &new).1 {
+                if !should_continue_compaction(&old, &new).1 || timeout() {
                     break;
                 }
                 old = new;
             }
         }
 
+        if recluster && !timeout() {
+            self.do_recluster(
+                session.clone(),
+                database.clone(),
+                table.clone(),
+                self.conf.background.compaction.recluster_limit,
+            )
+            .await?;
+        }
+
         Ok(true)
     }
 
@@ -664,6 +920,25 @@ impl CompactionJob {
         Ok(())
     }
 
+    async fn do_recluster(
+        &self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+        limit: Option<u64>,
+    ) -> Result<()> {
+        let sql = Self::get_recluster_sql(database, table, limit);
+        debug!(
+            job = "compaction",
+            background = true,
+            sql = sql.as_str();
+            "recluster"
+        );
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
     pub fn get_compaction_advice_sql(
         database: String,
         table: String,
@@ -708,4 +983,20 @@ impl CompactionJob {
         };
         format!("OPTIMIZE TABLE {}.{} COMPACT{};", database, table, limit)
     }
+
+    pub fn get_recluster_sql(database: String, table: String, limit: Option<u64>) -> String {
+        let limit = if let Some(s) = limit {
+            format!(" LIMIT {}", s)
+        } else {
+            "".to_string()
+        };
+        format!("ALTER TABLE {}.{} RECLUSTER{};", database, table, limit)
+    }
+
+    pub fn get_unclustered_blocks_sql(database: String, table: String) -> String {
+        format!(
+            "SELECT unclustered_block_count FROM clustering_information('{}', '{}');",
+            database, table
+        )
+    }
 }
@@ -17,9 +17,16 @@ mod compaction_job;
 mod job;
 mod job_scheduler;
 mod session;
+mod table_fragmentation;
 
 pub use background_service_handler::RealBackgroundService;
 pub use compaction_job::should_continue_compaction;
 pub use compaction_job::CompactionJob;
 pub use job::Job;
 pub use job_scheduler::JobScheduler;
+pub use table_fragmentation::rank_candidates;
+pub use table_fragmentation::select_candidates;
+pub use table_fragmentation::CompactionCandidate;
+pub use table_fragmentation::CompactionCycleReport;
+pub use table_fragmentation::CompactionDecision;
+pub use table_fragmentation::TableFragmentation;
@@ -0,0 +1,229 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+const SMALL_BLOCK_WEIGHT: f64 = 0.4;
+const SEGMENT_WEIGHT: f64 = 0.3;
+const UNCLUSTERED_WEIGHT: f64 = 0.3;
+
+// tables scored below this are considered healthy.
+pub const MIN_FRAGMENTATION_SCORE: f64 = 0.1;
+// the ratios above which the corresponding compaction is worth running.
+const SMALL_BLOCK_RATIO_THRESHOLD: f64 = 0.2;
+const SEGMENT_RATIO_THRESHOLD: f64 = 0.2;
+const UNCLUSTERED_RATIO_THRESHOLD: f64 = 0.2;
+
+/// Fragmentation of a fuse table, computed from its snapshot summary.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableFragmentation {
+    pub block_count: u64,
+    pub perfect_block_count: u64,
+    pub segment_count: u64,
+    // `block_per_segment` of the table, used to compute the ideal segment count.
+    pub block_per_segment: u64,
+    // Always 0 for tables without cluster key.
+    pub unclustered_block_count: u64,
+    pub bytes_uncompressed: u64,
+}
+
+impl TableFragmentation {
+    pub fn small_block_ratio(&self) -> f64 {
+        if self.block_count == 0 {
+            return 0.0;
+        }
+        let small = self.block_count.saturating_sub(self.perfect_block_count);
+        small as f64 / self.block_count as f64
+    }
+
+    pub fn ideal_segment_count(&self) -> u64 {
+        if self.block_per_segment == 0 {
+            return self.segment_count;
+        }
+        self.block_count.div_ceil(self.block_per_segment)
+    }
+
+    // the share of segments that would be merged away by segment compaction.
+    pub fn segment_ratio(&self) -> f64 {
+        if self.segment_count == 0 {
+            return 0.0;
+        }
+        let excess = self
+            .segment_count
+            .saturating_sub(self.ideal_segment_count().max(1));
+        excess as f64 / self.segment_count as f64
+    }
+
+    pub fn unclustered_ratio(&self) -> f64 {
+        if self.block_count == 0 {
+            return 0.0;
+        }
+        (self.unclustered_block_count.min(self.block_count)) as f64 / self.block_count as f64
+    }
+
+    /// The weighted fragmentation score in [0, 1], higher means more fragmented.
+    pub fn score(&self) -> f64 {
+        SMALL_BLOCK_WEIGHT * self.small_block_ratio()
+            + SEGMENT_WEIGHT * self.segment_ratio()
+            + UNCLUSTERED_WEIGHT * self.unclustered_ratio()
+    }
+
+    pub fn need_segment_compact(&self) -> bool {
+        self.segment_ratio() > SEGMENT_RATIO_THRESHOLD
+    }
+
+    pub fn need_block_compact(&self) -> bool {
+        self.small_block_ratio() > SMALL_BLOCK_RATIO_THRESHOLD
+    }
+
+    pub fn need_recluster(&self) -> bool {
+        self.unclustered_ratio() > UNCLUSTERED_RATIO_THRESHOLD
+    }
+}
+
+/// What the compaction job decided to do with a table in a cycle.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompactionDecision {
+    Compacted,
+    Failed(String),
+    SkipOptOut,
+    SkipLocked,
+    SkipRecentlyCompacted,
+    SkipHealthy,
+    // Not in the top-K tables of the cycle.
+    DeferredByRank,
+    DeferredByByteBudget,
+    DeferredByTimeBudget,
+}
+
+impl Display for CompactionDecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompactionDecision::Compacted => write!(f, "compacted"),
+            CompactionDecision::Failed(e) => write!(f, "failed: {}", e),
+            CompactionDecision::SkipOptOut => write!(f, "skipped: background compaction disabled"),
+            CompactionDecision::SkipLocked => write!(f, "skipped: locked"),
+            CompactionDecision::SkipRecentlyCompacted => {
+                write!(f, "skipped: recently compacted")
+            }
+            CompactionDecision::SkipHealthy => write!(f, "skipped: healthy"),
+            CompactionDecision::DeferredByRank => write!(f, "deferred: not in top tables"),
+            CompactionDecision::DeferredByByteBudget => write!(f, "deferred: byte budget"),
+            CompactionDecision::DeferredByTimeBudget => write!(f, "deferred: time budget"),
+        }
+    }
+}
+
+/// A table considered by a compaction cycle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompactionCandidate {
+    pub db_name: String,
+    pub db_id: u64,
+    pub table_name: String,
+    pub table_id: u64,
+    pub fragmentation: TableFragmentation,
+}
+
+impl CompactionCandidate {
+    pub fn score(&self) -> f64 {
+        self.fragmentation.score()
+    }
+}
+
+/// Sort candidates by fragmentation score, the most fragmented first.
+pub fn rank_candidates(candidates: &mut [CompactionCandidate]) {
+    candidates.sort_by(|a, b| {
+        b.score()
+            .total_cmp(&a.score())
+            .then_with(|| a.table_id.cmp(&b.table_id))
+    });
+}
+
+/// Pick the tables to compact in a cycle from the ranked candidates: at most `max_tables`
+/// tables whose uncompressed bytes add up within `max_bytes`, the others are deferred.
+/// The first table is always picked so that a large table can't block the cycle forever.
+pub fn select_candidates(
+    ranked: Vec<CompactionCandidate>,
+    max_tables: u64,
+    max_bytes: Option<u64>,
+    report: &mut CompactionCycleReport,
+) -> Vec<CompactionCandidate> {
+    let mut selected = vec![];
+    let mut total_bytes = 0;
+    for candidate in ranked {
+        if selected.len() as u64 >= max_tables {
+            report.record(&candidate, CompactionDecision::DeferredByRank);
+            continue;
+        }
+        let bytes = candidate.fragmentation.bytes_uncompressed;
+        if let Some(max_bytes) = max_bytes {
+            if !selected.is_empty() && total_bytes + bytes > max_bytes {
+                report.record(&candidate, CompactionDecision::DeferredByByteBudget);
+                continue;
+            }
+        }
+        total_bytes += bytes;
+        selected.push(candidate);
+    }
+    selected
+}
+
+/// The activity of a compaction cycle, recorded as the message of the job.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CompactionCycleReport {
+    pub decisions: Vec<(String, f64, CompactionDecision)>,
+}
+
+impl CompactionCycleReport {
+    pub fn record(&mut self, candidate: &CompactionCandidate, decision: CompactionDecision) {
+        self.decisions.push((
+            format!("{}.{}", candidate.db_name, candidate.table_name),
+            candidate.score(),
+            decision,
+        ));
+    }
+
+    pub fn count(&self, f: impl Fn(&CompactionDecision) -> bool) -> usize {
+        self.decisions.iter().filter(|(_, _, d)| f(d)).count()
+    }
+}
+
+impl Display for CompactionCycleReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "compacted: {}, failed: {}, skipped: {}, deferred: {}",
+            self.count(|d| matches!(d, CompactionDecision::Compacted)),
+            self.count(|d| matches!(d, CompactionDecision::Failed(_))),
+            self.count(|d| matches!(
+                d,
+                CompactionDecision::SkipOptOut
+                    | CompactionDecision::SkipLocked
+                    | CompactionDecision::SkipRecentlyCompacted
+                    | CompactionDecision::SkipHealthy
+            )),
+            self.count(|d| matches!(
+                d,
+                CompactionDecision::DeferredByRank
+                    | CompactionDecision::DeferredByByteBudget
+                    | CompactionDecision::DeferredByTimeBudget
+            )),
+        )?;
+        for (table, score, decision) in &self.decisions {
+            write!(f, "; {} (score {:.2}) {}", table, score, decision)?;
+        }
+        Ok(())
+    }
+}
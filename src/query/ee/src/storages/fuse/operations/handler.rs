@@ -23,6 +23,7 @@ use common_exception::Result;
 use common_storages_fuse::FuseTable;
 use vacuum_handler::VacuumHandler;
 use vacuum_handler::VacuumHandlerWrapper;
+use vacuum_handler::VacuumTableResult;
 
 use crate::storages::fuse::do_vacuum;
 use crate::storages::fuse::do_vacuum_drop_tables;
@@ -37,7 +38,7 @@ impl VacuumHandler for RealVacuumHandler {
         ctx: Arc<dyn TableContext>,
         retention_time: DateTime<Utc>,
         dry_run: bool,
-    ) -> Result<Option<Vec<String>>> {
+    ) -> Result<VacuumTableResult> {
         do_vacuum(fuse_table, ctx, retention_time, dry_run).await
     }
 
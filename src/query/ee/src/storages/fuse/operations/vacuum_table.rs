@@ -24,13 +24,16 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_storages_fuse::io::DeletionReport;
 use common_storages_fuse::io::MetaReaders;
 use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::FuseTable;
+use log::warn;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
+use vacuum_handler::VacuumTableResult;
 
 use crate::storages::fuse::get_snapshot_referenced_segments;
 
@@ -99,6 +102,7 @@ pub async fn get_snapshot_referenced_files(
         timestamp: root_snapshot.timestamp,
         segments: HashSet::from_iter(root_snapshot.segments.clone()),
         table_statistics_location: root_snapshot.table_statistics_location.clone(),
+        segment_stats_index_location: root_snapshot.segment_stats_index_location.clone(),
     });
     drop(root_snapshot);
 
@@ -136,20 +140,28 @@ pub async fn get_snapshot_referenced_files(
     }))
 }
 
-// return orphan files to be purged
+// return orphan files to be purged, and the number of orphan files not expired yet
 #[async_backtrace::framed]
 async fn get_orphan_files_to_be_purged(
     fuse_table: &FuseTable,
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
-) -> Result<Vec<String>> {
+) -> Result<(Vec<String>, usize)> {
+    let mut skipped = 0;
     let files_to_be_purged = match referenced_files.iter().next().cloned() {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
                 fuse_table
                     .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
+                        if referenced_files.contains(&location) {
+                            return false;
+                        }
+                        if modified > retention_time {
+                            skipped += 1;
+                            return false;
+                        }
+                        true
                     })
                     .await?
             } else {
@@ -161,7 +173,7 @@ async fn get_orphan_files_to_be_purged(
         }
     };
 
-    Ok(files_to_be_purged)
+    Ok((files_to_be_purged, skipped))
 }
 
 #[async_backtrace::framed]
@@ -170,12 +182,14 @@ pub async fn do_gc_orphan_files(
     ctx: &Arc<dyn TableContext>,
     retention_time: DateTime<Utc>,
     start: Instant,
-) -> Result<()> {
+    report: &mut DeletionReport,
+) -> Result<usize> {
     // 1. Get all the files referenced by the current snapshot
     let referenced_files = match get_snapshot_referenced_files(fuse_table, ctx).await? {
         Some(referenced_files) => referenced_files,
-        None => return Ok(()),
+        None => return Ok(0),
     };
+    let mut skipped = 0;
     let status = format!(
         "gc orphan: read referenced files:{},{},{}, cost:{} sec",
         referenced_files.segments.len(),
@@ -187,9 +201,10 @@ pub async fn do_gc_orphan_files(
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
-    let segment_locations_to_be_purged =
+    let (segment_locations_to_be_purged, segments_skipped) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
             .await?;
+    skipped += segments_skipped;
     let status = format!(
         "gc orphan: read segment_locations_to_be_purged:{}, cost:{} sec, retention_time: {}",
         segment_locations_to_be_purged.len(),
@@ -200,12 +215,14 @@ pub async fn do_gc_orphan_files(
 
     // 2.2 Delete all the orphan segment files to be purged
     let purged_file_num = segment_locations_to_be_purged.len();
-    fuse_table
+    let deletion = fuse_table
         .try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
             ctx.clone(),
             HashSet::from_iter(segment_locations_to_be_purged.into_iter()),
+            true,
         )
         .await?;
+    report.merge(deletion);
     let status = format!(
         "gc orphan: purged segment files:{}, cost:{} sec",
         purged_file_num,
@@ -215,8 +232,9 @@ pub async fn do_gc_orphan_files(
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
-    let block_locations_to_be_purged =
+    let (block_locations_to_be_purged, blocks_skipped) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    skipped += blocks_skipped;
     let status = format!(
         "gc orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -226,12 +244,14 @@ pub async fn do_gc_orphan_files(
 
     // 3.2 Delete all the orphan block files to be purged
     let purged_file_num = block_locations_to_be_purged.len();
-    fuse_table
+    let deletion = fuse_table
         .try_purge_location_files(
             ctx.clone(),
             HashSet::from_iter(block_locations_to_be_purged.into_iter()),
+            true,
         )
         .await?;
+    report.merge(deletion);
     let status = format!(
         "gc orphan: purged block files:{}, cost:{} sec",
         purged_file_num,
@@ -241,9 +261,10 @@ pub async fn do_gc_orphan_files(
 
     // 4. Purge orphan block index files.
     // 4.1 Get orphan block index files to be purged
-    let index_locations_to_be_purged =
+    let (index_locations_to_be_purged, indexes_skipped) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
             .await?;
+    skipped += indexes_skipped;
     let status = format!(
         "gc orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -253,12 +274,14 @@ pub async fn do_gc_orphan_files(
 
     // 4.2 Delete all the orphan block index files to be purged
     let purged_file_num = index_locations_to_be_purged.len();
-    fuse_table
+    let deletion = fuse_table
         .try_purge_location_files(
             ctx.clone(),
             HashSet::from_iter(index_locations_to_be_purged.into_iter()),
+            true,
         )
         .await?;
+    report.merge(deletion);
     let status = format!(
         "gc orphan: purged block index files:{}, cost:{} sec",
         purged_file_num,
@@ -266,7 +289,7 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
-    Ok(())
+    Ok(skipped)
 }
 
 #[async_backtrace::framed]
@@ -293,7 +316,7 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     // 2. Get purge orphan segment files.
-    let segment_locations_to_be_purged =
+    let (segment_locations_to_be_purged, _) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
             .await?;
     let status = format!(
@@ -309,7 +332,7 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 3. Get purge orphan block files.
-    let block_locations_to_be_purged =
+    let (block_locations_to_be_purged, _) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
     let status = format!(
         "dry_run orphan: read block_locations_to_be_purged:{}, cost:{} sec",
@@ -323,7 +346,7 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 4. Get purge orphan block index files.
-    let index_locations_to_be_purged =
+    let (index_locations_to_be_purged, _) =
         get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
             .await?;
     let status = format!(
@@ -344,14 +367,28 @@ pub async fn do_vacuum(
     ctx: Arc<dyn TableContext>,
     retention_time: DateTime<Utc>,
     dry_run: bool,
-) -> Result<Option<Vec<String>>> {
+) -> Result<VacuumTableResult> {
     let start = Instant::now();
     // First, do purge
     let instant = Some(NavigationPoint::TimePoint(retention_time));
-    let dry_run_limit = if dry_run { Some(DRY_RUN_LIMIT) } else { None };
-    let purge_files_opt = fuse_table
-        .purge(ctx.clone(), instant, dry_run_limit, true, dry_run)
-        .await?;
+    let mut purge_files = vec![];
+    let mut deletion = DeletionReport::default();
+    if dry_run {
+        purge_files = fuse_table
+            .purge(ctx.clone(), instant, Some(DRY_RUN_LIMIT), true, true)
+            .await?
+            .unwrap_or_default();
+    } else {
+        match fuse_table.navigate_for_purge(&ctx, instant).await {
+            Ok((table, files)) => {
+                deletion = table.do_purge_with_report(&ctx, files, true).await?;
+            }
+            Err(e) if e.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                warn!("navigate failed: {:?}", e);
+            }
+            Err(e) => return Err(e),
+        }
+    }
     let status = format!(
         "do_vacuum: purged table, cost:{} sec",
         start.elapsed().as_secs()
@@ -361,27 +398,26 @@ pub async fn do_vacuum(
     // use min(now - get_retention_period(), retention_time) as gc orphan files retention time
     // to protect files that generated by txn which has not been committed being gc.
     let retention_time = std::cmp::min(chrono::Utc::now() - retention, retention_time);
-    if let Some(mut purge_files) = purge_files_opt {
-        let dry_run_limit = dry_run_limit.unwrap();
-        if purge_files.len() < dry_run_limit {
+    if dry_run {
+        if purge_files.len() < DRY_RUN_LIMIT {
             do_dry_run_orphan_files(
                 fuse_table,
                 &ctx,
                 retention_time,
                 start,
                 &mut purge_files,
-                dry_run_limit,
+                DRY_RUN_LIMIT,
             )
             .await?;
         }
 
-        if purge_files.len() > dry_run_limit {
-            purge_files = purge_files.into_iter().take(dry_run_limit).collect();
+        if purge_files.len() > DRY_RUN_LIMIT {
+            purge_files = purge_files.into_iter().take(DRY_RUN_LIMIT).collect();
         }
-        Ok(Some(purge_files))
+        Ok(VacuumTableResult::DryRun(purge_files))
     } else {
-        debug_assert!(dry_run_limit.is_none());
-        do_gc_orphan_files(fuse_table, &ctx, retention_time, start).await?;
-        Ok(None)
+        let skipped =
+            do_gc_orphan_files(fuse_table, &ctx, retention_time, start, &mut deletion).await?;
+        Ok(VacuumTableResult::Purged { deletion, skipped })
     }
 }
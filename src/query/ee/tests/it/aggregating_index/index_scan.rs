@@ -965,6 +965,17 @@ fn get_test_suites() -> Vec<TestSuite> {
             index: "select sum(a), to_string(b) as bs from t group by bs",
             is_index_scan: true,
         },
+        // query: eval-agg-scan, index: eval-agg-scan with bitmap agg funcs
+        TestSuite {
+            query: "select b, bitmap_count(bitmap_union(to_bitmap(to_uint64(abs(a))))) from t group by b",
+            index: "select b, bitmap_union(to_bitmap(to_uint64(abs(a)))) from t group by b",
+            is_index_scan: true,
+        },
+        TestSuite {
+            query: "select bitmap_or_count(to_bitmap(to_uint64(abs(a)))) from t where c > 1",
+            index: "select bitmap_or_count(to_bitmap(to_uint64(abs(a)))) from t where c > 1",
+            is_index_scan: true,
+        },
     ]
 }
 
@@ -17,8 +17,14 @@ use core::default::Default;
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
+use enterprise_query::background_service::rank_candidates;
+use enterprise_query::background_service::select_candidates;
 use enterprise_query::background_service::should_continue_compaction;
+use enterprise_query::background_service::CompactionCandidate;
+use enterprise_query::background_service::CompactionCycleReport;
+use enterprise_query::background_service::CompactionDecision;
 use enterprise_query::background_service::CompactionJob;
+use enterprise_query::background_service::TableFragmentation;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_get_compaction_advice_sql() -> Result<()> {
@@ -175,3 +181,120 @@ async fn test_should_continue_compaction() -> Result<()> {
     assert_eq!(should_continue_compaction(&old, &new), (false, false));
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_recluster_sql() -> Result<()> {
+    let sql = CompactionJob::get_recluster_sql("db1".to_string(), "tbl1".to_string(), None);
+    assert_eq!(sql.trim(), "ALTER TABLE db1.tbl1 RECLUSTER;");
+    let sql =
+        CompactionJob::get_recluster_sql("db1".to_string(), "tbl1".to_string(), Option::Some(100));
+    assert_eq!(sql.trim(), "ALTER TABLE db1.tbl1 RECLUSTER LIMIT 100;");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_fragmentation_score() -> Result<()> {
+    // compacted table
+    let healthy = TableFragmentation {
+        block_count: 1000,
+        perfect_block_count: 1000,
+        segment_count: 1,
+        block_per_segment: 1000,
+        unclustered_block_count: 0,
+        bytes_uncompressed: 1000,
+    };
+    assert_eq!(healthy.score(), 0.0);
+    assert!(!healthy.need_segment_compact());
+    assert!(!healthy.need_block_compact());
+    assert!(!healthy.need_recluster());
+
+    // every block is small and lives in its own segment
+    let fragmented = TableFragmentation {
+        block_count: 1000,
+        perfect_block_count: 0,
+        segment_count: 1000,
+        block_per_segment: 1000,
+        unclustered_block_count: 1000,
+        bytes_uncompressed: 1000,
+    };
+    assert_eq!(fragmented.ideal_segment_count(), 1);
+    assert_eq!(fragmented.small_block_ratio(), 1.0);
+    assert_eq!(fragmented.segment_ratio(), 0.999);
+    assert_eq!(fragmented.unclustered_ratio(), 1.0);
+    assert!(fragmented.score() > 0.99);
+    assert!(fragmented.need_segment_compact());
+    assert!(fragmented.need_block_compact());
+    assert!(fragmented.need_recluster());
+
+    // empty table
+    assert_eq!(TableFragmentation::default().score(), 0.0);
+    Ok(())
+}
+
+fn new_candidate(table_id: u64, perfect_block_count: u64, bytes: u64) -> CompactionCandidate {
+    CompactionCandidate {
+        db_name: "db1".to_string(),
+        db_id: 1,
+        table_name: format!("tbl{}", table_id),
+        table_id,
+        fragmentation: TableFragmentation {
+            block_count: 100,
+            perfect_block_count,
+            segment_count: 1,
+            block_per_segment: 1000,
+            unclustered_block_count: 0,
+            bytes_uncompressed: bytes,
+        },
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rank_and_select_candidates() -> Result<()> {
+    let mut candidates = vec![
+        new_candidate(1, 90, 100),
+        new_candidate(2, 0, 100),
+        new_candidate(3, 50, 100),
+        new_candidate(4, 10, 100),
+    ];
+    rank_candidates(&mut candidates);
+    let ids = candidates.iter().map(|c| c.table_id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![2, 4, 3, 1]);
+
+    // top-k
+    let mut report = CompactionCycleReport::default();
+    let selected = select_candidates(candidates.clone(), 2, None, &mut report);
+    let ids = selected.iter().map(|c| c.table_id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![2, 4]);
+    assert_eq!(
+        report.count(|d| *d == CompactionDecision::DeferredByRank),
+        2
+    );
+
+    // byte budget, the first table is always selected
+    let mut report = CompactionCycleReport::default();
+    let selected = select_candidates(candidates, 10, Some(50), &mut report);
+    let ids = selected.iter().map(|c| c.table_id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![2]);
+    assert_eq!(
+        report.count(|d| *d == CompactionDecision::DeferredByByteBudget),
+        3
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_compaction_cycle_report() -> Result<()> {
+    let mut report = CompactionCycleReport::default();
+    report.record(&new_candidate(1, 0, 100), CompactionDecision::Compacted);
+    report.record(&new_candidate(2, 100, 100), CompactionDecision::SkipLocked);
+    report.record(&new_candidate(3, 100, 100), CompactionDecision::SkipOptOut);
+    report.record(
+        &new_candidate(4, 100, 100),
+        CompactionDecision::SkipRecentlyCompacted,
+    );
+    assert_eq!(
+        report.to_string(),
+        "compacted: 1, failed: 0, skipped: 3, deferred: 0; db1.tbl1 (score 0.40) compacted; db1.tbl2 (score 0.00) skipped: locked; db1.tbl3 (score 0.00) skipped: background compaction disabled; db1.tbl4 (score 0.00) skipped: recently compacted"
+    );
+    Ok(())
+}
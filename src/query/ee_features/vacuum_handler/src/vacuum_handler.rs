@@ -20,8 +20,21 @@ use common_base::base::GlobalInstance;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_storages_fuse::io::DeletionReport;
 use common_storages_fuse::FuseTable;
 
+/// The result of vacuuming a table.
+pub enum VacuumTableResult {
+    /// The files to be removed, returned by a dry run.
+    DryRun(Vec<String>),
+    /// The outcome of the deletions, and the number of the orphan files which are
+    /// skipped because they are not expired yet.
+    Purged {
+        deletion: DeletionReport,
+        skipped: usize,
+    },
+}
+
 #[async_trait::async_trait]
 pub trait VacuumHandler: Sync + Send {
     async fn do_vacuum(
@@ -30,7 +43,7 @@ pub trait VacuumHandler: Sync + Send {
         ctx: Arc<dyn TableContext>,
         retention_time: DateTime<Utc>,
         dry_run: bool,
-    ) -> Result<Option<Vec<String>>>;
+    ) -> Result<VacuumTableResult>;
 
     async fn do_vacuum_drop_tables(
         &self,
@@ -55,7 +68,7 @@ impl VacuumHandlerWrapper {
         ctx: Arc<dyn TableContext>,
         retention_time: DateTime<Utc>,
         dry_run: bool,
-    ) -> Result<Option<Vec<String>>> {
+    ) -> Result<VacuumTableResult> {
         self.handler
             .do_vacuum(fuse_table, ctx, retention_time, dry_run)
             .await
@@ -137,7 +137,7 @@ impl<'a> Evaluator<'a> {
                 function,
                 args,
                 generics,
-                ..
+                return_type,
             } => {
                 let args = args
                     .iter()
@@ -151,18 +151,44 @@ impl<'a> Evaluator<'a> {
                         })
                         .all_equal()
                 );
-                let cols_ref = args.iter().map(Value::as_ref).collect::<Vec<_>>();
-                let mut ctx = EvalContext {
-                    generics,
-                    num_rows: self.input_columns.num_rows(),
-                    validity,
-                    errors: None,
-                    func_ctx: self.func_ctx,
+                let eval_function = |args: &[Value<AnyType>], num_rows: usize| -> Result<_> {
+                    let cols_ref = args.iter().map(Value::as_ref).collect::<Vec<_>>();
+                    let mut ctx = EvalContext {
+                        generics,
+                        num_rows,
+                        validity: validity.clone(),
+                        errors: None,
+                        func_ctx: self.func_ctx,
+                    };
+                    let (_, eval) = function.eval.as_scalar().unwrap();
+                    let result = (eval)(cols_ref.as_slice(), &mut ctx);
+                    ctx.render_error(*span, id.params(), args, &function.signature.name)?;
+                    Ok(result)
                 };
-                let (_, eval) = function.eval.as_scalar().unwrap();
-                let result = (eval)(cols_ref.as_slice(), &mut ctx);
-                ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
-                Ok(result)
+
+                // Partially evaluated rows are not cached, their results are not real.
+                match &self.func_ctx.function_result_cache {
+                    Some(cache)
+                        if validity.is_none()
+                            && self
+                                .fn_registry
+                                .properties
+                                .get(&function.signature.name)
+                                .is_some_and(|property| {
+                                    property.expensive && !property.non_deterministic
+                                }) =>
+                    {
+                        cache.eval(
+                            id,
+                            generics,
+                            return_type,
+                            &args,
+                            self.input_columns.num_rows(),
+                            eval_function,
+                        )
+                    }
+                    _ => eval_function(&args, self.input_columns.num_rows()),
+                }
             }
             Expr::LambdaFunctionCall {
                 name,
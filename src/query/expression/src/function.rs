@@ -30,6 +30,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::date_helper::TzLUT;
+use crate::function_cache::FunctionResultCache;
 use crate::property::Domain;
 use crate::property::FunctionProperty;
 use crate::type_check::try_unify_signature;
@@ -105,6 +106,9 @@ pub struct FunctionContext {
 
     pub external_server_connect_timeout_secs: u64,
     pub external_server_request_timeout_secs: u64,
+
+    /// Caches the results of the expensive deterministic functions of the query.
+    pub function_result_cache: Option<Arc<FunctionResultCache>>,
 }
 
 #[derive(Clone)]
@@ -153,6 +157,9 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+
+    /// Usage examples of the functions, shown in `system.functions`.
+    pub examples: HashMap<String, String>,
 }
 
 impl Function {
@@ -414,6 +421,69 @@ impl FunctionRegistry {
             .push((Box::new(factory), id));
     }
 
+    pub fn register_example(&mut self, fn_name: &str, example: &str) {
+        self.examples
+            .insert(fn_name.to_string(), example.to_string());
+    }
+
+    /// The usage example of the function or the function aliased by the name.
+    pub fn get_example(&self, func_name: &str) -> Option<&str> {
+        let func_name = self
+            .aliases
+            .get(func_name)
+            .map_or(func_name, String::as_str);
+        self.examples.get(func_name).map(String::as_str)
+    }
+
+    /// The signatures of the overloads of the function or the function aliased by the name.
+    ///
+    /// The nullable overloads generated from the non-nullable ones are not listed, and
+    /// neither are the overloads of the function factories, which are built by the
+    /// arguments, see `is_variadic`.
+    pub fn registered_signatures(&self, func_name: &str) -> Vec<FunctionSignature> {
+        let func_name = self
+            .aliases
+            .get(func_name)
+            .map_or(func_name, String::as_str);
+        let Some(funcs) = self.funcs.get(func_name) else {
+            return vec![];
+        };
+        let signatures = funcs
+            .iter()
+            .sorted_by_key(|(_, id)| *id)
+            .map(|(func, _)| func.signature.clone())
+            .collect::<Vec<_>>();
+
+        let is_generated_nullable = |sig: &FunctionSignature| {
+            sig.args_type.iter().any(|ty| ty.is_nullable())
+                && signatures.iter().any(|other| {
+                    other.args_type.len() == sig.args_type.len()
+                        && other
+                            .args_type
+                            .iter()
+                            .zip(&sig.args_type)
+                            .all(|(other_ty, ty)| {
+                                !other_ty.is_nullable() && *other_ty == ty.remove_nullable()
+                            })
+                })
+        };
+        signatures
+            .iter()
+            .filter(|sig| !is_generated_nullable(sig))
+            .cloned()
+            .collect()
+    }
+
+    /// Whether the function or the function aliased by the name is built by a factory, which
+    /// accepts a variable number or type of arguments.
+    pub fn is_variadic(&self, func_name: &str) -> bool {
+        let func_name = self
+            .aliases
+            .get(func_name)
+            .map_or(func_name, String::as_str);
+        self.factories.contains_key(func_name)
+    }
+
     pub fn register_aliases(&mut self, fn_name: &str, aliases: &[&str]) {
         for alias in aliases {
             self.aliases.insert(alias.to_string(), fn_name.to_string());
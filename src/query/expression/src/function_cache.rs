@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_exception::Result;
+use common_metrics::function::metrics_inc_function_result_cache_hit_count;
+use common_metrics::function::metrics_inc_function_result_cache_miss_count;
+
+use crate::types::AnyType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::ColumnBuilder;
+use crate::FunctionID;
+use crate::Scalar;
+use crate::Value;
+
+/// Number of blocks sampled before deciding whether to cache the results of a function.
+const SAMPLE_BLOCKS: usize = 4;
+
+/// The results are cached only if the ratio of distinct inputs to rows of the
+/// sampled blocks is below this threshold.
+const MAX_DISTINCT_RATIO: f64 = 0.1;
+
+/// Per-query memoization of the results of the expensive deterministic functions,
+/// keyed by the argument values.
+///
+/// Each function call starts in the sampling state, in which the distinct inputs of
+/// the first `SAMPLE_BLOCKS` blocks are counted. The cache is then enabled only if the
+/// inputs turn out to be of low cardinality. The memory of the cached results of all
+/// the functions is bounded by `memory_budget`, once exceeded, new results are not
+/// cached anymore.
+pub struct FunctionResultCache {
+    memory_budget: usize,
+    memory_usage: AtomicUsize,
+    entries: Mutex<HashMap<(FunctionID, Vec<DataType>), Arc<Mutex<CacheEntry>>>>,
+}
+
+enum CacheState {
+    Sampling {
+        blocks: usize,
+        rows: usize,
+        distinct_rows: usize,
+    },
+    Enabled,
+    Disabled,
+}
+
+struct CacheEntry {
+    state: CacheState,
+    results: HashMap<Vec<Scalar>, Scalar>,
+}
+
+impl FunctionResultCache {
+    pub fn create(memory_budget: usize) -> Arc<FunctionResultCache> {
+        Arc::new(FunctionResultCache {
+            memory_budget,
+            memory_usage: AtomicUsize::new(0),
+            entries: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn memory_usage(&self) -> usize {
+        self.memory_usage.load(Ordering::Relaxed)
+    }
+
+    /// Evaluate the function by `eval` on the rows whose results are not cached yet,
+    /// and fill the rest from the cache.
+    pub fn eval(
+        &self,
+        id: &FunctionID,
+        generics: &GenericMap,
+        return_type: &DataType,
+        args: &[Value<AnyType>],
+        num_rows: usize,
+        eval: impl Fn(&[Value<AnyType>], usize) -> Result<Value<AnyType>>,
+    ) -> Result<Value<AnyType>> {
+        // Constant arguments are evaluated only once anyway.
+        if num_rows == 0 || args.iter().all(|arg| matches!(arg, Value::Scalar(_))) {
+            return eval(args, num_rows);
+        }
+
+        let entry = self.entry(id, generics);
+        if matches!(entry.lock().unwrap().state, CacheState::Disabled) {
+            return eval(args, num_rows);
+        }
+
+        let keys = (0..num_rows)
+            .map(|row| {
+                args.iter()
+                    .map(|arg| arg.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // The rows of the first occurrences of the inputs missing from the cache.
+        let mut miss_rows = Vec::new();
+        let mut miss_index = HashMap::new();
+        let mut hits = Vec::with_capacity(num_rows);
+        let sampling = {
+            let mut entry = entry.lock().unwrap();
+            match &mut entry.state {
+                CacheState::Sampling {
+                    blocks,
+                    rows,
+                    distinct_rows,
+                } => {
+                    *blocks += 1;
+                    *rows += num_rows;
+                    *distinct_rows += keys.iter().collect::<HashSet<_>>().len();
+                    if *blocks >= SAMPLE_BLOCKS {
+                        let ratio = *distinct_rows as f64 / *rows as f64;
+                        entry.state = if ratio < MAX_DISTINCT_RATIO {
+                            CacheState::Enabled
+                        } else {
+                            CacheState::Disabled
+                        };
+                    }
+                    true
+                }
+                CacheState::Disabled => true,
+                CacheState::Enabled => {
+                    for (row, key) in keys.iter().enumerate() {
+                        match entry.results.get(key) {
+                            Some(result) => hits.push(Some(result.clone())),
+                            None => {
+                                hits.push(None);
+                                miss_index.entry(key).or_insert_with(|| {
+                                    miss_rows.push(row);
+                                    miss_rows.len() - 1
+                                });
+                            }
+                        }
+                    }
+                    false
+                }
+            }
+        };
+        if sampling {
+            return eval(args, num_rows);
+        }
+
+        let name = id.name();
+        let hit_count = hits.iter().filter(|hit| hit.is_some()).count();
+        metrics_inc_function_result_cache_hit_count(hit_count as u64, &name);
+        metrics_inc_function_result_cache_miss_count((num_rows - hit_count) as u64, &name);
+
+        let miss_results = if miss_rows.is_empty() {
+            vec![]
+        } else {
+            let miss_args = args
+                .iter()
+                .map(|arg| match arg {
+                    Value::Scalar(scalar) => Value::Scalar(scalar.clone()),
+                    Value::Column(column) => {
+                        let mut builder =
+                            ColumnBuilder::with_capacity(&column.data_type(), miss_rows.len());
+                        for row in &miss_rows {
+                            builder.push(column.index(*row).unwrap());
+                        }
+                        Value::Column(builder.build())
+                    }
+                })
+                .collect::<Vec<_>>();
+            let miss_value = eval(&miss_args, miss_rows.len())?;
+            let miss_results = (0..miss_rows.len())
+                .map(|i| miss_value.index(i).unwrap().to_owned())
+                .collect::<Vec<_>>();
+
+            let mut entry = entry.lock().unwrap();
+            for (row, result) in miss_rows.iter().zip(&miss_results) {
+                let key = &keys[*row];
+                let size = key
+                    .iter()
+                    .chain(std::iter::once(result))
+                    .map(|scalar| scalar.as_ref().memory_size())
+                    .sum::<usize>();
+                if self.memory_usage.fetch_add(size, Ordering::Relaxed) + size > self.memory_budget
+                {
+                    self.memory_usage.fetch_sub(size, Ordering::Relaxed);
+                    break;
+                }
+                entry.results.insert(key.clone(), result.clone());
+            }
+            miss_results
+        };
+
+        let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+        for (key, hit) in keys.iter().zip(&hits) {
+            match hit {
+                Some(result) => builder.push(result.as_ref()),
+                None => builder.push(miss_results[miss_index[key]].as_ref()),
+            }
+        }
+        Ok(Value::Column(builder.build()))
+    }
+
+    fn entry(&self, id: &FunctionID, generics: &GenericMap) -> Arc<Mutex<CacheEntry>> {
+        let mut entries = self.entries.lock().unwrap();
+        entries
+            .entry((id.clone(), generics.to_vec()))
+            .or_insert_with(|| {
+                Arc::new(Mutex::new(CacheEntry {
+                    state: CacheState::Sampling {
+                        blocks: 0,
+                        rows: 0,
+                        distinct_rows: 0,
+                    },
+                    results: HashMap::new(),
+                }))
+            })
+            .clone()
+    }
+}
@@ -46,6 +46,7 @@ pub mod converts;
 mod evaluator;
 mod expression;
 mod function;
+mod function_cache;
 mod kernels;
 mod property;
 mod register;
@@ -63,6 +64,7 @@ pub use crate::block::*;
 pub use crate::evaluator::*;
 pub use crate::expression::*;
 pub use crate::function::*;
+pub use crate::function_cache::*;
 pub use crate::kernels::*;
 pub use crate::property::*;
 pub use crate::register::*;
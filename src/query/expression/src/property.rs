@@ -44,6 +44,9 @@ use crate::Scalar;
 #[derive(Debug, Clone, Copy)]
 pub struct FunctionProperty {
     pub non_deterministic: bool,
+    /// The function is costly to evaluate, its results may be cached within a query
+    /// if it is deterministic, see `FunctionResultCache`.
+    pub expensive: bool,
     pub kind: FunctionKind,
 }
 
@@ -53,6 +56,11 @@ impl FunctionProperty {
         self
     }
 
+    pub fn expensive(mut self) -> Self {
+        self.expensive = true;
+        self
+    }
+
     pub fn kind(mut self, kind: FunctionKind) -> Self {
         self.kind = kind;
         self
@@ -63,6 +71,7 @@ impl Default for FunctionProperty {
     fn default() -> Self {
         FunctionProperty {
             non_deterministic: false,
+            expensive: false,
             kind: FunctionKind::Scalar,
         }
     }
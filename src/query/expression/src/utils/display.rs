@@ -886,6 +886,9 @@ impl Display for FunctionProperty {
         if self.non_deterministic {
             properties.push("non_deterministic");
         }
+        if self.expensive {
+            properties.push("expensive");
+        }
         if !properties.is_empty() {
             write!(f, "{{{}}}", properties.join(", "))?;
         }
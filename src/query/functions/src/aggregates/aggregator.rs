@@ -130,6 +130,10 @@ impl Aggregators {
             "bitmap_intersect",
             aggregate_bitmap_intersect_function_desc(),
         );
+        factory.register(
+            "bitmap_intersect_count",
+            aggregate_bitmap_and_count_function_desc(),
+        );
         factory.register(
             "intersect_count",
             aggregate_bitmap_intersect_count_function_desc(),
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::FunctionRegistry;
+
+/// Usage examples of the common functions, including the aggregate and window functions,
+/// shown in `system.functions` and `DESCRIBE FUNCTION`.
+const EXAMPLES: &[(&str, &str)] = &[
+    // Scalar functions.
+    ("abs", "abs(-1.5) = 1.5"),
+    ("round", "round(3.1415, 2) = 3.14"),
+    ("floor", "floor(1.7) = 1"),
+    ("ceil", "ceil(1.2) = 2"),
+    ("length", "length('databend') = 8"),
+    ("lower", "lower('Databend') = 'databend'"),
+    ("upper", "upper('Databend') = 'DATABEND'"),
+    ("concat", "concat('data', 'bend') = 'databend'"),
+    ("substr", "substr('databend', 1, 4) = 'data'"),
+    (
+        "replace",
+        "replace('databend', 'bend', 'base') = 'database'",
+    ),
+    ("split", "split('a,b,c', ',') = ['a','b','c']"),
+    ("trim", "trim('  databend  ') = 'databend'"),
+    ("coalesce", "coalesce(NULL, 1, 2) = 1"),
+    ("if", "if(1 > 2, 'yes', 'no') = 'no'"),
+    ("to_date", "to_date('2023-01-01') = 2023-01-01"),
+    (
+        "to_yyyymmdd",
+        "to_yyyymmdd(to_date('2023-01-01')) = 20230101",
+    ),
+    (
+        "date_add",
+        "date_add(day, 1, to_date('2023-01-01')) = 2023-01-02",
+    ),
+    ("now", "now() = 2023-01-01 00:00:00.000000"),
+    ("get", "get(parse_json('{\"a\":1}'), 'a') = 1"),
+    ("parse_json", "parse_json('[1,2]') = [1,2]"),
+    ("contains", "contains([1, 2, 3], 2) = true"),
+    ("md5", "md5('1') = 'c4ca4238a0b923820dcc509a6f75849b'"),
+    // Aggregate functions.
+    ("count", "SELECT count(*) FROM numbers(3) = 3"),
+    ("sum", "SELECT sum(number) FROM numbers(3) = 3"),
+    ("avg", "SELECT avg(number) FROM numbers(3) = 1.0"),
+    ("min", "SELECT min(number) FROM numbers(3) = 0"),
+    ("max", "SELECT max(number) FROM numbers(3) = 2"),
+    // Window functions.
+    (
+        "row_number",
+        "SELECT number, row_number() OVER (ORDER BY number DESC) FROM numbers(3)",
+    ),
+    (
+        "rank",
+        "SELECT number, rank() OVER (ORDER BY number) FROM numbers(3)",
+    ),
+    (
+        "lag",
+        "SELECT number, lag(number, 1) OVER (ORDER BY number) FROM numbers(3)",
+    ),
+];
+
+pub fn register(registry: &mut FunctionRegistry) {
+    for (name, example) in EXAMPLES {
+        registry.register_example(name, example);
+    }
+}
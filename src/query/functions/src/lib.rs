@@ -26,6 +26,7 @@ use ctor::ctor;
 
 pub mod aggregates;
 mod cast_rules;
+mod examples;
 pub mod scalars;
 pub mod srfs;
 
@@ -68,6 +69,7 @@ fn builtin_functions() -> FunctionRegistry {
     cast_rules::register(&mut registry);
     scalars::register(&mut registry);
     srfs::register(&mut registry);
+    examples::register(&mut registry);
 
     registry
 }
@@ -156,6 +156,24 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<BitmapType, ArrayType<UInt64Type>, _, _>(
+        "bitmap_to_array",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, ArrayType<UInt64Type>>(|b, builder, ctx| {
+            match RoaringTreemap::deserialize_from(b) {
+                Ok(rb) => {
+                    for item in rb.iter() {
+                        builder.put_item(item);
+                    }
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                }
+            }
+            builder.commit_row();
+        }),
+    );
+
     registry.register_passthrough_nullable_2_arg::<BitmapType, UInt64Type, BooleanType, _, _>(
         "bitmap_contains",
         |_, _, _| FunctionDomain::Full,
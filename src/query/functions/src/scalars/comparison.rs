@@ -41,6 +41,7 @@ use common_expression::EvalContext;
 use common_expression::Function;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
+use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::ScalarRef;
@@ -561,6 +562,10 @@ fn register_like(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.properties.insert(
+        "regexp".to_string(),
+        FunctionProperty::default().expensive(),
+    );
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
         "regexp",
         |_, _, _| FunctionDomain::Full,
@@ -39,6 +39,8 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use string::tokenize;
+pub use string::DEFAULT_TOKENIZER;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::io::Write;
 
 use base64::engine::general_purpose;
@@ -23,6 +24,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -291,6 +293,18 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        |text, query, _| {
+            let text = String::from_utf8_lossy(text);
+            let query = String::from_utf8_lossy(query);
+            let terms = tokenize(&text).collect::<HashSet<_>>();
+            let mut query_terms = tokenize(&query).peekable();
+            query_terms.peek().is_some() && query_terms.all(|term| terms.contains(&term))
+        },
+    );
+
     let find_at = |str: &[u8], substr: &[u8], pos: u64| {
         if substr.is_empty() {
             // the same behavior as MySQL, Postgres and Clickhouse
@@ -937,6 +951,17 @@ pub(crate) mod soundex {
 }
 
 #[inline]
+/// The name of the tokenizer used by `match` and inverted indexes.
+pub const DEFAULT_TOKENIZER: &str = "default";
+
+/// Splits the text into lowercase terms on every non-alphanumeric character,
+/// this is the `default` tokenizer of inverted indexes.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+}
+
 fn substr(str: &[u8], pos: i64, len: u64) -> &[u8] {
     if pos > 0 && pos <= str.len() as i64 {
         let l = str.len();
@@ -31,6 +31,7 @@ use common_expression::EvalContext;
 use common_expression::Function;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
+use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
@@ -294,6 +295,17 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    for name in [
+        "regexp_instr",
+        "regexp_like",
+        "regexp_replace",
+        "regexp_substr",
+    ] {
+        registry
+            .properties
+            .insert(name.to_string(), FunctionProperty::default().expensive());
+    }
+
     // Notes: https://dev.mysql.com/doc/refman/8.0/en/regexp.html#function_regexp-instr
     registry.register_function_factory("regexp_instr", |_, args_type| {
         let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
@@ -23,6 +23,7 @@ fn test_bitmap() {
     test_bitmap_subset_limit(file);
     test_bitmap_subset_in_range(file);
     test_bitmap_op(file);
+    test_bitmap_to_array(file);
 }
 
 fn test_build_bitmap(file: &mut impl Write) {
@@ -126,3 +127,7 @@ fn test_bitmap_op(file: &mut impl Write) {
         &[],
     );
 }
+
+fn test_bitmap_to_array(file: &mut impl Write) {
+    run_ast(file, "bitmap_to_array(build_bitmap([1,3,5]))", &[]);
+}
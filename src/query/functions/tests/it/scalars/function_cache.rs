@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::type_check;
+use common_expression::types::*;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::FunctionResultCache;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use super::parser;
+
+const EXPRS: [&str; 3] = [
+    "regexp_replace(s, 'b', 'X')",
+    // Returns NULL for the strings not matched.
+    "regexp_substr(s, 'b.')",
+    "regexp_like(s, '^a')",
+];
+
+#[test]
+fn test_function_result_cache() {
+    // Low cardinality inputs with NULLs, the cache is enabled after sampling.
+    let blocks = (0..8)
+        .map(|_| {
+            StringType::from_opt_data(
+                (0..100)
+                    .map(|i| match i % 4 {
+                        0 => Some("abc"),
+                        1 => None,
+                        2 => Some("bcd"),
+                        _ => Some("xyz"),
+                    })
+                    .collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+    for text in EXPRS {
+        let cache = FunctionResultCache::create(1024 * 1024);
+        assert_eq!(
+            eval_blocks(text, &blocks, Some(cache.clone())),
+            eval_blocks(text, &blocks, None),
+            "{text}"
+        );
+        assert!(cache.memory_usage() > 0, "{text}");
+
+        // Nothing is cached without memory budget, but the results are still correct.
+        let cache = FunctionResultCache::create(0);
+        assert_eq!(
+            eval_blocks(text, &blocks, Some(cache.clone())),
+            eval_blocks(text, &blocks, None),
+            "{text}"
+        );
+        assert_eq!(cache.memory_usage(), 0, "{text}");
+    }
+
+    // High cardinality inputs, the cache is disabled after sampling.
+    let blocks = (0..8)
+        .map(|block| {
+            let strings = (0..100)
+                .map(|i| format!("b{}", block * 100 + i))
+                .collect::<Vec<_>>();
+            StringType::from_data(strings.iter().map(String::as_str).collect())
+        })
+        .collect::<Vec<_>>();
+    for text in EXPRS {
+        let cache = FunctionResultCache::create(1024 * 1024);
+        assert_eq!(
+            eval_blocks(text, &blocks, Some(cache.clone())),
+            eval_blocks(text, &blocks, None),
+            "{text}"
+        );
+        assert_eq!(cache.memory_usage(), 0, "{text}");
+    }
+}
+
+fn eval_blocks(
+    text: &str,
+    blocks: &[Column],
+    function_result_cache: Option<std::sync::Arc<FunctionResultCache>>,
+) -> Vec<Scalar> {
+    let func_ctx = FunctionContext {
+        function_result_cache,
+        ..Default::default()
+    };
+    let mut results = vec![];
+    for column in blocks {
+        let raw_expr = parser::parse_raw_expr(text, &[("s", column.data_type())]);
+        let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+        let block = DataBlock::new(
+            vec![BlockEntry::new(
+                column.data_type(),
+                Value::Column(column.clone()),
+            )],
+            column.len(),
+        );
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let result: Value<AnyType> = evaluator.run(&expr).unwrap();
+        results.extend((0..column.len()).map(|row| result.index(row).unwrap().to_owned()));
+    }
+    results
+}
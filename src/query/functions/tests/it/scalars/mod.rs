@@ -38,6 +38,7 @@ mod cast;
 mod comparison;
 mod control;
 mod datetime;
+mod function_cache;
 mod geo;
 // NOTE:(everpcpc) result different on macos
 #[cfg(not(target_os = "macos"))]
@@ -52,6 +52,7 @@ fn test_string() {
     test_replace(file);
     test_translate(file);
     test_strcmp(file);
+    test_match(file);
     test_locate(file);
     test_char(file);
     test_soundex(file);
@@ -541,6 +542,12 @@ fn test_strcmp(file: &mut impl Write) {
     run_ast(file, "strcmp(a, b)", &table);
 }
 
+fn test_match(file: &mut impl Write) {
+    run_ast(file, "match('Hello, World!', 'world')", &[]);
+    run_ast(file, "match('Hello, World!', 'hello moon')", &[]);
+    run_ast(file, "match('Hello, World!', '')", &[]);
+}
+
 fn test_locate(file: &mut impl Write) {
     run_ast(file, "locate('bar', 'foobarbar')", &[]);
     run_ast(file, "locate('', 'foobarbar')", &[]);
@@ -0,0 +1,35 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait ExternalVolumeApi: Sync + Send {
+    // Add an external volume to /tenant/volume-name.
+    async fn add_external_volume(&self, volume: ExternalVolume) -> Result<u64>;
+
+    async fn get_external_volume(&self, name: &str, seq: MatchSeq) -> Result<SeqV<ExternalVolume>>;
+
+    // Get all the external volumes for a tenant.
+    async fn get_external_volumes(&self) -> Result<Vec<ExternalVolume>>;
+
+    // Update the external volume if the seq matches.
+    async fn update_external_volume(&self, volume: ExternalVolume, seq: MatchSeq) -> Result<u64>;
+
+    // Drop the tenant's external volume by name.
+    async fn drop_external_volume(&self, name: &str, seq: MatchSeq) -> Result<()>;
+}
@@ -0,0 +1,170 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::ExternalVolumeApi;
+
+static EXTERNAL_VOLUME_API_KEY_PREFIX: &str = "__fd_external_volume";
+
+pub struct ExternalVolumeMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    external_volume_prefix: String,
+}
+
+impl ExternalVolumeMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while external volume mgr create)",
+            ));
+        }
+
+        Ok(Self {
+            kv_api,
+            external_volume_prefix: format!(
+                "{}/{}",
+                EXTERNAL_VOLUME_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ExternalVolumeApi for ExternalVolumeMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_external_volume(&self, info: ExternalVolume) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalExternalVolume,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.external_volume_prefix,
+            escape_for_key(&info.name)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res_seq = upsert_info.await?.added_seq_or_else(|v| {
+            ErrorCode::ExternalVolumeAlreadyExists(format!(
+                "external volume already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_external_volume(&self, name: &str, seq: MatchSeq) -> Result<SeqV<ExternalVolume>> {
+        let key = format!("{}/{}", self.external_volume_prefix, escape_for_key(name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownExternalVolume(format!("Unknown external volume {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalExternalVolume, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownExternalVolume(format!(
+                "Unknown external volume {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_external_volumes(&self) -> Result<Vec<ExternalVolume>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.external_volume_prefix)
+            .await?;
+
+        let mut volumes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let volume = deserialize_struct(&value.data, ErrorCode::IllegalExternalVolume, || "")?;
+            volumes.push(volume);
+        }
+        Ok(volumes)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_external_volume(&self, info: ExternalVolume, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalExternalVolume,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.external_volume_prefix,
+            escape_for_key(&info.name)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownExternalVolume(format!(
+                "Unknown external volume, or seq not match {}",
+                info.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_external_volume(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.external_volume_prefix, escape_for_key(name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownExternalVolume(format!(
+                "Unknown external volume {}",
+                name
+            )))
+        }
+    }
+}
@@ -33,4 +33,14 @@ pub trait FileFormatApi: Sync + Send {
 
     // Drop the tenant's file_format by name.
     async fn drop_file_format(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Update the file_format info if the seq matches.
+    async fn update_file_format(
+        &self,
+        file_format: UserDefinedFileFormat,
+        seq: MatchSeq,
+    ) -> Result<u64>;
+
+    // Rename the tenant's file_format.
+    async fn rename_file_format(&self, name: &str, new_name: &str) -> Result<()>;
 }
@@ -17,20 +17,28 @@ use std::sync::Arc;
 use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_api::reply::txn_reply_to_api_result;
+use common_meta_api::txn_cond_seq;
+use common_meta_api::txn_op_del;
+use common_meta_api::txn_op_put;
+use common_meta_app::app_error::TxnRetryMaxTimes;
 use common_meta_app::principal::UserDefinedFileFormat;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::ConditionResult::Eq;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TxnRequest;
 
 use crate::serde::deserialize_struct;
 use crate::serde::serialize_struct;
 use crate::FileFormatApi;
 
 static USER_FILE_FORMAT_API_KEY_PREFIX: &str = "__fd_file_formats";
+const TXN_MAX_RETRY_TIMES: u32 = 10;
 
 pub struct FileFormatMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
@@ -146,4 +154,85 @@ impl FileFormatApi for FileFormatMgr {
             )))
         }
     }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_file_format(&self, info: UserDefinedFileFormat, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalFileFormat,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.file_format_prefix,
+            escape_for_key(&info.name)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownFileFormat(format!(
+                "Unknown file_format, or seq not match {}",
+                info.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn rename_file_format(&self, name: &str, new_name: &str) -> Result<()> {
+        let key = format!("{}/{}", self.file_format_prefix, escape_for_key(name)?);
+        let new_key = format!("{}/{}", self.file_format_prefix, escape_for_key(new_name)?);
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (seq, mut file_format): (_, UserDefinedFileFormat) =
+                if let Some(seq_v) = self.kv_api.get_kv(&key).await? {
+                    (
+                        seq_v.seq,
+                        deserialize_struct(&seq_v.data, ErrorCode::IllegalFileFormat, || "")?,
+                    )
+                } else {
+                    return Err(ErrorCode::UnknownFileFormat(format!(
+                        "Unknown file_format {}",
+                        name
+                    )));
+                };
+            if let Some(seq_v) = self.kv_api.get_kv(&new_key).await? {
+                return Err(ErrorCode::FileFormatAlreadyExists(format!(
+                    "file_format already exists, seq [{}]",
+                    seq_v.seq
+                )));
+            }
+            file_format.name = new_name.to_string();
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&key, Eq, seq), txn_cond_seq(&new_key, Eq, 0)],
+                if_then: vec![
+                    txn_op_del(&key),
+                    txn_op_put(
+                        &new_key,
+                        serialize_struct(&file_format, ErrorCode::IllegalFileFormat, || "")?,
+                    ),
+                ],
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("rename_file_format", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
 }
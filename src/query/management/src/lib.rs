@@ -16,12 +16,17 @@
 
 mod cluster;
 mod connection;
+mod external_volume;
 mod file_format;
 mod network_policy;
+mod password_policy;
 mod quota;
 mod role;
+mod row_access_policy;
 mod serde;
+mod session_token;
 mod setting;
+mod settings_profile;
 mod stage;
 mod udf;
 mod user;
@@ -30,18 +35,28 @@ pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
 pub use connection::ConnectionApi;
 pub use connection::ConnectionMgr;
+pub use external_volume::ExternalVolumeApi;
+pub use external_volume::ExternalVolumeMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
+pub use password_policy::PasswordPolicyApi;
+pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
+pub use session_token::SessionTokenApi;
+pub use session_token::SessionTokenMgr;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
+pub use settings_profile::SettingsProfileApi;
+pub use settings_profile::SettingsProfileMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
 pub use udf::UdfApi;
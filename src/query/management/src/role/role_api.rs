@@ -53,5 +53,8 @@ pub trait RoleApi: Sync + Send {
     /// Get the ownership info by object. If it's not granted to any role, return PUBLIC
     async fn get_ownership(&self, object: &GrantObjectByID) -> Result<Option<OwnershipInfo>>;
 
+    /// List the ownership info of all the objects which are owned by a role.
+    async fn get_ownerships(&self) -> Result<Vec<SeqV<OwnershipInfo>>>;
+
     async fn drop_role(&self, role: String, seq: MatchSeq) -> Result<()>;
 }
@@ -235,6 +235,24 @@ impl RoleApi for RoleMgr {
         Ok(Some(ownership.data))
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_ownerships(&self) -> common_exception::Result<Vec<SeqV<OwnershipInfo>>> {
+        let object_owner_prefix = format!("{}/", self.object_owner_prefix);
+        let kv_api = self.kv_api.clone();
+        let values = kv_api.prefix_list_kv(object_owner_prefix.as_str()).await?;
+
+        let mut r = vec![];
+        for (_key, val) in values {
+            let ownership = serde_json::from_slice::<OwnershipInfo>(&val.data)
+                .map_err_to_code(ErrorCode::IllegalUserInfoFormat, || "")?;
+
+            r.push(SeqV::new(val.seq, ownership));
+        }
+
+        Ok(r)
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn drop_ownership(&self, object: &GrantObjectByID) -> common_exception::Result<()> {
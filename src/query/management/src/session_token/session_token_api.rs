@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+#[async_trait::async_trait]
+pub trait SessionTokenApi: Sync + Send {
+    /// Add the session to the revocation list of the tenant.
+    /// The record is removed by meta after `expire_at`(unix timestamp in seconds),
+    /// when all the tokens of the session have expired anyway.
+    async fn revoke_session(&self, session_id: &str, expire_at: u64) -> Result<()>;
+
+    async fn is_session_revoked(&self, session_id: &str) -> Result<bool>;
+
+    /// Get the ids of all the revoked sessions of the tenant.
+    async fn get_revoked_sessions(&self) -> Result<Vec<String>>;
+}
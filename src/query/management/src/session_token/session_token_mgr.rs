@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+
+use crate::session_token::SessionTokenApi;
+
+static REVOKED_SESSION_TOKEN_API_KEY_PREFIX: &str = "__fd_revoked_session_tokens";
+
+pub struct SessionTokenMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    revoked_prefix: String,
+}
+
+impl SessionTokenMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        Ok(SessionTokenMgr {
+            kv_api,
+            revoked_prefix: format!("{}/{}", REVOKED_SESSION_TOKEN_API_KEY_PREFIX, tenant),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionTokenApi for SessionTokenMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn revoke_session(&self, session_id: &str, expire_at: u64) -> Result<()> {
+        let key = format!("{}/{}", self.revoked_prefix, session_id);
+        let val = Operation::Update(serde_json::to_vec(&expire_at)?);
+        let meta = Some(KVMeta {
+            expire_at: Some(expire_at),
+        });
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, meta))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn is_session_revoked(&self, session_id: &str) -> Result<bool> {
+        let key = format!("{}/{}", self.revoked_prefix, session_id);
+        let res = self.kv_api.get_kv(&key).await?;
+        Ok(res.is_some())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_revoked_sessions(&self) -> Result<Vec<String>> {
+        let values = self.kv_api.prefix_list_kv(&self.revoked_prefix).await?;
+        Ok(values
+            .into_iter()
+            .map(|(key, _)| key[self.revoked_prefix.len() + 1..].to_string())
+            .collect())
+    }
+}
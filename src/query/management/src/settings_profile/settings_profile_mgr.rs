@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SettingsProfile;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::settings_profile::settings_profile_api::SettingsProfileApi;
+
+static SETTINGS_PROFILE_API_KEY_PREFIX: &str = "__fd_settings_profiles";
+
+pub struct SettingsProfileMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    settings_profile_prefix: String,
+}
+
+impl SettingsProfileMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create settings profile)",
+            ));
+        }
+
+        Ok(SettingsProfileMgr {
+            kv_api,
+            settings_profile_prefix: format!("{}/{}", SETTINGS_PROFILE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_settings_profile_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.settings_profile_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingsProfileApi for SettingsProfileMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_settings_profile(&self, settings_profile: SettingsProfile) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_settings_profile_key(settings_profile.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &settings_profile,
+            ErrorCode::IllegalSettingsProfile,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res_seq = upsert_kv.await?.added_seq_or_else(|v| {
+            ErrorCode::SettingsProfileAlreadyExists(format!(
+                "SettingsProfile already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_settings_profile(
+        &self,
+        settings_profile: SettingsProfile,
+        match_seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = self.make_settings_profile_key(settings_profile.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &settings_profile,
+            ErrorCode::IllegalSettingsProfile,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownSettingsProfile(format!(
+                "Unknown SettingsProfile, or seq not match {}",
+                settings_profile.name.clone()
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_settings_profile(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_settings_profile_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSettingsProfile(format!(
+                "Unknown SettingsProfile {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_settings_profile(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<SettingsProfile>> {
+        let key = self.make_settings_profile_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownSettingsProfile(format!("Unknown SettingsProfile {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalSettingsProfile, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownSettingsProfile(format!(
+                "Unknown SettingsProfile {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_settings_profiles(&self) -> Result<Vec<SettingsProfile>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.settings_profile_prefix)
+            .await?;
+
+        let mut settings_profiles = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let settings_profile =
+                deserialize_struct(&value.data, ErrorCode::IllegalSettingsProfile, || "")?;
+            settings_profiles.push(settings_profile);
+        }
+        Ok(settings_profiles)
+    }
+}
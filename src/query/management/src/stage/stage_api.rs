@@ -31,6 +31,12 @@ pub trait StageApi: Sync + Send {
     // Drop the tenant's stage by name.
     async fn drop_stage(&self, name: &str) -> Result<()>;
 
+    // Update the stage info if the seq matches.
+    async fn update_stage(&self, stage: StageInfo, seq: MatchSeq) -> Result<u64>;
+
+    // Rename the tenant's stage, the files of the stage are moved along with it.
+    async fn rename_stage(&self, name: &str, new_name: &str) -> Result<()>;
+
     async fn add_file(&self, name: &str, file: StageFile) -> Result<u64>;
 
     async fn list_files(&self, name: &str) -> Result<Vec<StageFile>>;
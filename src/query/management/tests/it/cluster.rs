@@ -153,6 +153,7 @@ fn create_test_node_info() -> NodeInfo {
         version: 0,
         flight_address: String::from("ip:port"),
         binary_version: "binary_version".to_string(),
+        labels: vec![],
     }
 }
 
@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod session_token;
 mod setting;
 mod stage;
 mod udf;
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_revoke_session() -> Result<()> {
+    let (kv_api, mgr) = new_session_token_api().await?;
+
+    let expire_at = (SystemTime::now() + Duration::from_secs(3600))
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    assert!(!mgr.is_session_revoked("session1").await?);
+    assert!(mgr.get_revoked_sessions().await?.is_empty());
+
+    mgr.revoke_session("session1", expire_at).await?;
+    let value = kv_api
+        .get_kv("__fd_revoked_session_tokens/databend_query/session1")
+        .await?;
+    assert_eq!(value.unwrap().meta.unwrap().expire_at, Some(expire_at));
+
+    // revoke twice is ok
+    mgr.revoke_session("session1", expire_at).await?;
+    mgr.revoke_session("session2", expire_at).await?;
+
+    assert!(mgr.is_session_revoked("session1").await?);
+    assert!(!mgr.is_session_revoked("session3").await?);
+
+    let mut revoked = mgr.get_revoked_sessions().await?;
+    revoked.sort();
+    assert_eq!(revoked, vec![
+        "session1".to_string(),
+        "session2".to_string()
+    ]);
+
+    Ok(())
+}
+
+async fn new_session_token_api() -> Result<(Arc<MetaEmbedded>, SessionTokenMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SessionTokenMgr::create(test_api.clone(), "databend_query")?;
+    Ok((test_api, mgr))
+}
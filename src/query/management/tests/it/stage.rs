@@ -112,6 +112,49 @@ async fn test_unknown_stage_drop_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_rename_stage() -> Result<()> {
+    let (kv_api, stage_api) = new_stage_api().await?;
+
+    let stage_info = create_test_stage_info();
+    stage_api.add_stage(stage_info.clone()).await?;
+    stage_api
+        .add_file("mystage", StageFile {
+            path: "books.csv".to_string(),
+            size: 100,
+            ..Default::default()
+        })
+        .await?;
+
+    stage_api.rename_stage("mystage", "newstage").await?;
+
+    let stages = stage_api.get_stages().await?;
+    assert_eq!(stages.len(), 1);
+    assert_eq!(stages[0].stage_name, "newstage");
+    assert_eq!(stages[0].number_of_files, 1);
+    assert_eq!(stage_api.list_files("newstage").await?.len(), 1);
+    assert!(
+        kv_api
+            .get_kv("__fd_stage_files/admin/mystage/books%2ecsv")
+            .await?
+            .is_none()
+    );
+
+    // the new name is taken.
+    stage_api.add_stage(stage_info).await?;
+    match stage_api.rename_stage("mystage", "newstage").await {
+        Ok(_) => panic!("Rename stage to an existing name must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2502),
+    }
+
+    // the stage does not exist.
+    match stage_api.rename_stage("UNKNOWN_ID", "stage").await {
+        Ok(_) => panic!("Rename unknown stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+    Ok(())
+}
+
 fn create_test_stage_info() -> StageInfo {
     StageInfo {
         stage_name: "mystage".to_string(),
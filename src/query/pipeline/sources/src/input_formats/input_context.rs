@@ -29,6 +29,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
 use common_expression::DataSchema;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
@@ -111,6 +112,26 @@ impl InputSource {
     }
 }
 
+/// With ON_ERROR=SKIP_FILE_n, the rows of a file are held back until the whole file is parsed,
+/// up to the size of this many blocks.
+const SKIP_FILE_BUFFER_BLOCKS: usize = 4;
+
+/// The rows of a file loaded with ON_ERROR=SKIP_FILE_n, which are not output yet.
+///
+/// The row batches of a file are deserialized by different builders, the rows are output by
+/// the builder deserializing the last batch.
+#[derive(Default)]
+pub struct SkipFileBuffer {
+    // set by the aligner once all the batches of the file are sent.
+    num_batches: Option<usize>,
+    num_batches_done: usize,
+    blocks: Vec<DataBlock>,
+    memory_size: usize,
+    status: FileStatus,
+    // some rows are output before the whole file is parsed, since the buffer is full.
+    flushed: bool,
+}
+
 pub struct InputContext {
     pub table_context: Arc<dyn TableContext>,
     pub plan: InputPlan,
@@ -133,6 +154,10 @@ pub struct InputContext {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
+    pub num_errors_per_file: DashMap<String, u64>,
+    /// The rows held back for ON_ERROR=SKIP_FILE_n, key is file path.
+    pub skip_file_buffers: DashMap<String, SkipFileBuffer>,
+    pub collect_all_errors: bool,
     pub projection: Option<Vec<usize>>,
 }
 
@@ -178,6 +203,7 @@ impl InputContext {
         is_select: bool,
         projection: Option<Vec<usize>>,
         default_values: Option<Vec<Scalar>>,
+        collect_all_errors: bool,
     ) -> Result<Self> {
         let mut file_format_options_ext =
             FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
@@ -205,6 +231,9 @@ impl InputContext {
             file_format_options_ext,
             on_error_mode,
             on_error_count: AtomicU64::new(0),
+            num_errors_per_file: DashMap::new(),
+            skip_file_buffers: DashMap::new(),
+            collect_all_errors,
             on_error_map: Some(on_error_map),
             projection,
             default_values,
@@ -262,6 +291,9 @@ impl InputContext {
             file_format_options_ext,
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
+            num_errors_per_file: DashMap::new(),
+            skip_file_buffers: DashMap::new(),
+            collect_all_errors: false,
             on_error_map: None,
             projection: None,
             default_values: None,
@@ -305,6 +337,9 @@ impl InputContext {
             file_format_params,
             on_error_mode,
             on_error_count: AtomicU64::new(0),
+            num_errors_per_file: DashMap::new(),
+            skip_file_buffers: DashMap::new(),
+            collect_all_errors: false,
             on_error_map: None,
             projection: None,
             default_values: None,
@@ -403,6 +438,99 @@ impl InputContext {
         None
     }
 
+    /// With ON_ERROR=SKIP_FILE_n, the rows of a file can only be output after the whole file is
+    /// parsed, because they are dropped if the file is skipped.
+    pub fn is_skip_file_mode(&self) -> bool {
+        matches!(self.on_error_mode, OnErrorMode::SkipFileNum(_))
+    }
+
+    pub fn should_skip_file(&self, file_path: &str) -> bool {
+        match &self.on_error_mode {
+            OnErrorMode::SkipFileNum(n) => self
+                .num_errors_per_file
+                .get(file_path)
+                .map_or(false, |num_errors| *num_errors >= *n),
+            _ => false,
+        }
+    }
+
+    /// Called by the aligner when all the row batches of a file are sent.
+    pub fn finish_skip_file_split(&self, file_path: &str, num_batches: usize) {
+        if num_batches == 0 {
+            self.skip_file_buffers.remove(file_path);
+            return;
+        }
+        let mut buffer = self
+            .skip_file_buffers
+            .entry(file_path.to_string())
+            .or_default();
+        buffer.num_batches = Some(num_batches);
+    }
+
+    /// Buffers the rows of a row batch of a file, and returns the rows of the file once all its
+    /// batches are deserialized, or once the buffer is full.
+    ///
+    /// The rows output because the buffer is full can't be dropped anymore, so the load fails
+    /// if errors are found in the rest of the file and the file should be skipped.
+    pub fn buffer_skip_file_rows(
+        &self,
+        file_path: &str,
+        block: Option<DataBlock>,
+        file_status: FileStatus,
+    ) -> Result<Vec<DataBlock>> {
+        let mut buffer = self
+            .skip_file_buffers
+            .entry(file_path.to_string())
+            .or_default();
+        buffer.num_batches_done += 1;
+        buffer.status.merge(file_status);
+
+        // the errors are counted before the rows are buffered, so either the rows are dropped
+        // here, or the builder finding the errors sees that some rows are output.
+        let skipped = self.should_skip_file(file_path);
+        if skipped {
+            if buffer.flushed {
+                return Err(ErrorCode::BadBytes(format!(
+                    "fail to skip file '{}', the rows more than the buffer of ON_ERROR=SKIP_FILE ({} bytes) have been loaded before the error is found",
+                    file_path,
+                    self.max_skip_file_buffer_size()
+                )));
+            }
+            buffer.blocks.clear();
+            buffer.memory_size = 0;
+        } else if let Some(block) = block {
+            buffer.memory_size += block.memory_size();
+            buffer.blocks.push(block);
+        }
+
+        let finished = buffer.num_batches == Some(buffer.num_batches_done);
+        if !finished && buffer.memory_size <= self.max_skip_file_buffer_size() {
+            return Ok(vec![]);
+        }
+        let blocks = mem::take(&mut buffer.blocks);
+        buffer.memory_size = 0;
+        if finished {
+            let mut file_status = mem::take(&mut buffer.status);
+            drop(buffer);
+            self.skip_file_buffers.remove(file_path);
+            if skipped {
+                file_status.num_rows_skipped += mem::take(&mut file_status.num_rows_loaded);
+            }
+            self.table_context.add_file_status(file_path, file_status)?;
+        } else {
+            buffer.flushed = true;
+        }
+        if blocks.is_empty() {
+            Ok(vec![])
+        } else {
+            Ok(vec![DataBlock::concat(&blocks)?])
+        }
+    }
+
+    fn max_skip_file_buffer_size(&self) -> usize {
+        self.block_compact_thresholds.max_bytes_per_block * SKIP_FILE_BUFFER_BLOCKS
+    }
+
     /// the line start from 0, it will be increased by 1 right before output
     pub fn on_error(
         &self,
@@ -425,6 +553,19 @@ impl InputContext {
 
         match &self.on_error_mode {
             OnErrorMode::Continue => {
+                if self.collect_all_errors {
+                    file_status.add_and_keep_error(e, line);
+                } else {
+                    file_status.add_error(e, line);
+                }
+                Ok(())
+            }
+            OnErrorMode::SkipFileNum(_) => {
+                // the rows of a skipped file are dropped by the block builder.
+                *self
+                    .num_errors_per_file
+                    .entry(file_path.to_string())
+                    .or_insert(0) += 1;
                 file_status.add_error(e, line);
                 Ok(())
             }
@@ -635,6 +635,20 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         }
     }
 
+    /// With ON_ERROR=SKIP_FILE_n, the rows of each batch are handed over to the buffer of its
+    /// file in the context, which outputs them once the whole file is parsed, since the batches
+    /// of a file may be deserialized by different builders.
+    fn deserialize_skip_file(&mut self, batch: RowBatch) -> Result<Vec<DataBlock>> {
+        let file_name = batch.split_info.file.path.clone();
+        if !self.ctx.should_skip_file(&file_name) {
+            T::deserialize(self, batch)?;
+        }
+        let file_status = mem::take(&mut self.file_status);
+        let block = self.flush()?.pop();
+        self.ctx
+            .buffer_skip_file_rows(&file_name, block, file_status)
+    }
+
     fn memory_size(&self) -> usize {
         self.mutable_columns.iter().map(|x| x.memory_size()).sum()
     }
@@ -645,6 +659,9 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
 
     fn deserialize(&mut self, batch: Option<RowBatch>) -> Result<Vec<DataBlock>> {
         if let Some(b) = batch {
+            if self.ctx.is_skip_file_mode() {
+                return self.deserialize_skip_file(b);
+            }
             let file_name = b.split_info.file.path.clone();
             T::deserialize(self, b)?;
             let file_status = mem::take(&mut self.file_status);
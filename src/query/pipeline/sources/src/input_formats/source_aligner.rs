@@ -48,6 +48,9 @@ pub struct Aligner<I: InputFormatPipe> {
 
     is_flushing_split: bool,
     no_more_split: bool,
+    // the file of the current split, and the row batches of it, for ON_ERROR=SKIP_FILE_n.
+    file_path: String,
+    num_row_batches: usize,
 
     // output
     row_batches: VecDeque<I::RowBatch>,
@@ -68,11 +71,22 @@ impl<I: InputFormatPipe> Aligner<I> {
             batch_rx: None,
             is_flushing_split: false,
             no_more_split: false,
+            file_path: String::new(),
+            num_row_batches: 0,
             row_batches: Default::default(),
         })))
     }
 }
 
+impl<I: InputFormatPipe> Aligner<I> {
+    /// With ON_ERROR=SKIP_FILE_n, the last row batch of a split is held back until the split is
+    /// finished, so that the batches of the file are all counted before the last one is
+    /// deserialized.
+    fn hold_last_row_batch(&self) -> bool {
+        self.state.is_some() && self.ctx.is_skip_file_mode()
+    }
+}
+
 #[async_trait::async_trait]
 impl<I: InputFormatPipe> Processor for Aligner<I> {
     fn name(&self) -> String {
@@ -91,7 +105,8 @@ impl<I: InputFormatPipe> Processor for Aligner<I> {
             Ok(Event::Finished)
         } else if !self.output.can_push() {
             Ok(Event::NeedConsume)
-        } else if let Some(rb) = self.row_batches.pop_front() {
+        } else if self.row_batches.len() > usize::from(self.hold_last_row_batch()) {
+            let rb = self.row_batches.pop_front().unwrap();
             let block = DataBlock::empty_with_meta(Box::new(rb));
             self.output.push_data(Ok(block));
             Ok(Event::NeedConsume)
@@ -113,6 +128,7 @@ impl<I: InputFormatPipe> Processor for Aligner<I> {
                 for b in row_batches.into_iter() {
                     if b.size() > 0 {
                         process_values.rows += b.rows();
+                        self.num_row_batches += 1;
                         self.row_batches.push_back(b);
                     }
                 }
@@ -124,9 +140,16 @@ impl<I: InputFormatPipe> Processor for Aligner<I> {
                         // just aligned data beyond end
                         let row_batches = state.align(None)?;
                         for b in row_batches.into_iter() {
+                            self.num_row_batches += 1;
                             self.row_batches.push_back(b);
                         }
                     }
+                    if self.ctx.is_skip_file_mode() {
+                        self.ctx.finish_skip_file_split(
+                            &self.file_path,
+                            mem::take(&mut self.num_row_batches),
+                        );
+                    }
                     self.is_flushing_split = false;
                     self.state = None;
                     self.batch_rx = None;
@@ -145,6 +168,8 @@ impl<I: InputFormatPipe> Processor for Aligner<I> {
                 None => match self.split_rx.recv().await {
                     Ok(Ok(split)) => {
                         self.state = Some(I::try_create_align_state(&self.ctx, &split.info)?);
+                        self.file_path = split.info.file.path.clone();
+                        self.num_row_batches = 0;
                         self.batch_rx = Some(split.rx);
                         debug!("aligner recv new split {}", &split.info);
                     }
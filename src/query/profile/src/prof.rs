@@ -23,14 +23,22 @@ pub struct QueryProfile {
     /// Query ID of the query profile
     pub query_id: String,
 
+    /// Sanitized `query_tag` setting of the query
+    pub query_tag: String,
+
     /// Flattened plan node profiles
     pub operator_profiles: Vec<OperatorProfile>,
 }
 
 impl QueryProfile {
-    pub fn new(query_id: String, operator_profiles: Vec<OperatorProfile>) -> Self {
+    pub fn new(
+        query_id: String,
+        query_tag: String,
+        operator_profiles: Vec<OperatorProfile>,
+    ) -> Self {
         QueryProfile {
             query_id,
+            query_tag,
             operator_profiles,
         }
     }
@@ -75,6 +83,7 @@ pub enum OperatorType {
     Insert,
     ConstantTableScan,
     Udf,
+    Unnest,
 }
 
 impl Display for OperatorType {
@@ -99,6 +108,7 @@ impl Display for OperatorType {
             OperatorType::CteScan => write!(f, "CteScan"),
             OperatorType::ConstantTableScan => write!(f, "ConstantTableScan"),
             OperatorType::Udf => write!(f, "Udf"),
+            OperatorType::Unnest => write!(f, "Unnest"),
         }
     }
 }
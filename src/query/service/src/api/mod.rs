@@ -25,7 +25,10 @@ pub use rpc::DataPacket;
 pub use rpc::DatabendQueryFlightService;
 pub use rpc::DefaultExchangeInjector;
 pub use rpc::ExchangeDeserializeMeta;
+pub use rpc::ExchangeEdge;
+pub use rpc::ExchangeEdgeMetrics;
 pub use rpc::ExchangeInjector;
+pub use rpc::ExchangeMetrics;
 pub use rpc::ExchangeSerializeMeta;
 pub use rpc::ExchangeShuffleMeta;
 pub use rpc::ExchangeSorting;
@@ -37,13 +40,20 @@ pub use rpc::FragmentData;
 pub use rpc::FragmentPlanPacket;
 pub use rpc::HashFlightScatter;
 pub use rpc::InitNodesChannelPacket;
+pub use rpc::JoinSkewKeys;
+pub use rpc::JoinSkewKeysPacket;
+pub use rpc::JoinSkewState;
+pub use rpc::JoinSkews;
 pub use rpc::KillQueryPacket;
 pub use rpc::MergeExchange;
 pub use rpc::MergeExchangeParams;
 pub use rpc::Packet;
+pub use rpc::ProcessesInfoPacket;
 pub use rpc::QueryFragmentsPlanPacket;
 pub use rpc::ShuffleDataExchange;
 pub use rpc::ShuffleExchangeParams;
+pub use rpc::ShuffleSkew;
+pub use rpc::SkewFlightScatter;
 pub use rpc::TransformExchangeDeserializer;
 pub use rpc::TruncateTablePacket;
 pub use rpc_service::RpcService;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_expression::RemoteExpr;
+use common_sql::executor::physical_plans::SkewJoinSide;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
@@ -43,17 +44,32 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    pub skew: Option<ShuffleSkew>,
 }
 
 impl ShuffleDataExchange {
-    pub fn create(destination_ids: Vec<String>, shuffle_keys: Vec<RemoteExpr>) -> DataExchange {
+    pub fn create(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<RemoteExpr>,
+        skew: Option<ShuffleSkew>,
+    ) -> DataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            skew,
         })
     }
 }
 
+/// The shuffle of one side of a skew-tolerant join.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ShuffleSkew {
+    pub join_id: u32,
+    pub side: SkewJoinSide,
+    // The node that collects the hot keys detected by all nodes, it's the coordinator of the query.
+    pub coordinator_id: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
@@ -28,6 +28,7 @@ use crate::api::DataExchange;
 use crate::api::ExchangeSorting;
 use crate::api::HashFlightScatter;
 use crate::api::ShuffleExchangeParams;
+use crate::api::SkewFlightScatter;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -83,11 +84,20 @@ impl ExchangeInjector for DefaultExchangeInjector {
             DataExchange::Broadcast(exchange) => Box::new(BroadcastFlightScatter::try_create(
                 exchange.destination_ids.len(),
             )?),
-            DataExchange::ShuffleDataExchange(exchange) => HashFlightScatter::try_create(
-                ctx.get_function_context()?,
-                exchange.shuffle_keys.clone(),
-                exchange.destination_ids.len(),
-            )?,
+            DataExchange::ShuffleDataExchange(exchange) => match &exchange.skew {
+                None => HashFlightScatter::try_create(
+                    ctx.get_function_context()?,
+                    exchange.shuffle_keys.clone(),
+                    exchange.destination_ids.len(),
+                )?,
+                Some(skew) => SkewFlightScatter::try_create(
+                    ctx.get_function_context()?,
+                    exchange.shuffle_keys.clone(),
+                    exchange.destination_ids.len(),
+                    skew.side,
+                    ctx.get_join_skew_state(skew.join_id, exchange.destination_ids.len())?,
+                )?,
+            },
         }))
     }
 
@@ -116,6 +116,23 @@ impl DataExchangeManager {
         queries_profiles
     }
 
+    /// Returns the ids of the fragments of the query that are executed on this node.
+    pub fn get_query_fragment_ids(&self, query_id: &str) -> Vec<usize> {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        let mut fragment_ids = match queries_coordinator.get(query_id) {
+            None => vec![],
+            Some(coordinator) => coordinator
+                .fragments_coordinator
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>(),
+        };
+        fragment_ids.sort();
+        fragment_ids
+    }
+
     // Create connections for cluster all nodes. We will push data through this connection.
     #[async_backtrace::framed]
     #[minitrace::trace]
@@ -829,6 +846,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        skew: None,
                     }))
                 }
                 DataExchange::ShuffleDataExchange(exchange) => {
@@ -841,6 +859,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        skew: exchange.skew.clone(),
                     }))
                 }
             };
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+/// An exchange edge of the query: the data of `fragment_id` moved from `source` node to `destination` node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExchangeEdge {
+    pub fragment_id: usize,
+    pub source: String,
+    pub destination: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExchangeEdgeMetrics {
+    pub sent_rows: u64,
+    pub sent_bytes: u64,
+    pub received_rows: u64,
+    pub received_bytes: u64,
+    pub serialize_nanos: u64,
+}
+
+impl ExchangeEdgeMetrics {
+    pub fn merge(&mut self, other: &ExchangeEdgeMetrics) {
+        self.sent_rows += other.sent_rows;
+        self.sent_bytes += other.sent_bytes;
+        self.received_rows += other.received_rows;
+        self.received_bytes += other.received_bytes;
+        self.serialize_nanos += other.serialize_nanos;
+    }
+
+    pub fn serialize_time(&self) -> Duration {
+        Duration::from_nanos(self.serialize_nanos)
+    }
+}
+
+/// Data movement of the exchanges of a query, collected by the exchange writers and readers.
+///
+/// Each node only records the edges it takes part in, the workers send their metrics to the
+/// coordinator when the query finishes, where they are merged.
+#[derive(Default)]
+pub struct ExchangeMetrics {
+    edges: Mutex<BTreeMap<ExchangeEdge, ExchangeEdgeMetrics>>,
+}
+
+impl ExchangeMetrics {
+    pub fn record_sent(&self, edge: &ExchangeEdge, rows: usize, bytes: usize, time: Duration) {
+        let mut edges = self.edges.lock();
+        let metrics = edges.entry(edge.clone()).or_default();
+        metrics.sent_rows += rows as u64;
+        metrics.sent_bytes += bytes as u64;
+        metrics.serialize_nanos += time.as_nanos() as u64;
+    }
+
+    pub fn record_received(&self, edge: &ExchangeEdge, rows: usize, bytes: usize) {
+        let mut edges = self.edges.lock();
+        let metrics = edges.entry(edge.clone()).or_default();
+        metrics.received_rows += rows as u64;
+        metrics.received_bytes += bytes as u64;
+    }
+
+    pub fn merge(&self, other: Vec<(ExchangeEdge, ExchangeEdgeMetrics)>) {
+        let mut edges = self.edges.lock();
+        for (edge, metrics) in other {
+            edges.entry(edge).or_default().merge(&metrics);
+        }
+    }
+
+    /// Returns the metrics of all the edges, ordered by (fragment, source, destination).
+    pub fn snapshot(&self) -> Vec<(ExchangeEdge, ExchangeEdgeMetrics)> {
+        let edges = self.edges.lock();
+        edges
+            .iter()
+            .map(|(edge, metrics)| (edge.clone(), metrics.clone()))
+            .collect()
+    }
+
+    /// Formats the metrics of the edges for `EXPLAIN ANALYZE FRAGMENTS`, one edge per block of lines.
+    pub fn format_edges(&self) -> Vec<String> {
+        let mut lines = vec![];
+        for (edge, metrics) in self.snapshot() {
+            lines.push(format!(
+                "Fragment {}: {} -> {}",
+                edge.fragment_id, edge.source, edge.destination
+            ));
+            lines.push(format!(
+                "  sent: {} rows, {} bytes, serialize time: {:?}",
+                metrics.sent_rows,
+                metrics.sent_bytes,
+                metrics.serialize_time()
+            ));
+            lines.push(format!(
+                "  received: {} rows, {} bytes",
+                metrics.received_rows, metrics.received_bytes
+            ));
+        }
+        lines
+    }
+}
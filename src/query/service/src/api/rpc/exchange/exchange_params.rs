@@ -23,6 +23,7 @@ use common_expression::DataSchemaRef;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::ExchangeInjector;
+use crate::api::ShuffleSkew;
 
 #[derive(Clone)]
 pub struct SerializeParams {
@@ -40,6 +41,7 @@ pub struct ShuffleExchangeParams {
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    pub skew: Option<ShuffleSkew>,
 }
 
 #[derive(Clone)]
@@ -30,14 +30,16 @@ use common_pipeline_sinks::Sink;
 use common_pipeline_sinks::Sinker;
 
 use crate::api::rpc::flight_client::FlightSender;
+use crate::api::DataPacket;
+use crate::api::ExchangeEdge;
+use crate::api::ExchangeMetrics;
 use crate::api::ExchangeSerializeMeta;
 use crate::sessions::QueryContext;
 
 pub struct ExchangeWriterSink {
     flight_sender: FlightSender,
-    source: String,
-    destination: String,
-    fragment: usize,
+    edge: ExchangeEdge,
+    metrics: Arc<ExchangeMetrics>,
 }
 
 impl ExchangeWriterSink {
@@ -45,15 +47,19 @@ impl ExchangeWriterSink {
         ctx: Arc<dyn TableContext>,
         input: Arc<InputPort>,
         flight_sender: FlightSender,
+        metrics: Arc<ExchangeMetrics>,
         source_id: &str,
         destination_id: &str,
         fragment_id: usize,
     ) -> Box<dyn Processor> {
         AsyncSinker::create(input, ctx, ExchangeWriterSink {
             flight_sender,
-            source: source_id.to_string(),
-            destination: destination_id.to_string(),
-            fragment: fragment_id,
+            edge: ExchangeEdge {
+                fragment_id,
+                source: source_id.to_string(),
+                destination: destination_id.to_string(),
+            },
+            metrics,
         })
     }
 }
@@ -80,10 +86,16 @@ impl AsyncSink for ExchangeWriterSink {
             }),
         }?;
 
+        let mut rows = 0;
         let mut bytes = 0;
         let count = serialize_meta.packet.len();
+        let serialize_time = serialize_meta.serialize_time;
         for packet in serialize_meta.packet {
             bytes += packet.bytes_size();
+            if let DataPacket::FragmentData(fragment_data) = &packet {
+                rows += fragment_data.num_rows();
+            }
+
             if let Err(error) = self.flight_sender.send(packet).await {
                 if error.code() == ErrorCode::ABORTED_QUERY {
                     return Ok(true);
@@ -98,6 +110,9 @@ impl AsyncSink for ExchangeWriterSink {
             metrics_inc_exchange_write_bytes(bytes);
         }
 
+        self.metrics
+            .record_sent(&self.edge, rows, bytes, serialize_time);
+
         Ok(false)
     }
 
@@ -111,9 +126,9 @@ impl AsyncSink for ExchangeWriterSink {
         }
 
         Some(format!("{:?}", Display {
-            source: self.source.clone(),
-            destination: self.destination.clone(),
-            fragment: self.fragment
+            source: self.edge.source.clone(),
+            destination: self.edge.destination.clone(),
+            fragment: self.edge.fragment_id
         }))
     }
 }
@@ -150,6 +165,7 @@ pub fn create_writer_item(
     source_id: &str,
 ) -> PipeItem {
     let input = InputPort::create();
+    let metrics = ctx.get_exchange_metrics();
     PipeItem::create(
         match ignore {
             true => ProcessorPtr::create(IgnoreExchangeSink::create(input.clone(), exchange)),
@@ -157,6 +173,7 @@ pub fn create_writer_item(
                 ctx,
                 input.clone(),
                 exchange,
+                metrics,
                 source_id,
                 destination_id,
                 fragment_id,
@@ -0,0 +1,405 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::sleep;
+use common_catalog::table_context::TableContext;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_metrics::transform::*;
+use common_pipeline_transforms::processors::AsyncTransform;
+use common_sql::executor::physical_plans::SkewJoinSide;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::api::rpc::packets::JoinSkewKeysPacket;
+use crate::api::ShuffleSkew;
+use crate::sessions::QueryContext;
+
+// The max number of hot keys taken from a skewed bucket.
+const MAX_HOT_KEYS_PER_BUCKET: usize = 16;
+const SYNC_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The hot keys of a skew-tolerant join, decided by the coordinator of the query.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct JoinSkewKeys {
+    // The hot keys will not change once all the nodes have reported, or the waiting timed out.
+    pub sealed: bool,
+    // Hashes of the join keys.
+    pub hot_keys: Vec<u64>,
+}
+
+struct SkewKeysRegistration {
+    reported: HashSet<String>,
+    hot_keys: BTreeSet<u64>,
+    sealed: bool,
+    created: Instant,
+}
+
+/// Counts the probe rows sent to each destination, until enough rows are sampled.
+struct SkewDetector {
+    threshold: u64,
+    detection_rows: u64,
+    sampled_rows: u64,
+    bucket_rows: Vec<u64>,
+    key_rows: HashMap<u64, u64>,
+    reported: bool,
+}
+
+impl SkewDetector {
+    // A bucket receiving more than `threshold` percent of the rows is skewed, its biggest keys
+    // are hot until the rest of the bucket is no more than a fair share of the rows.
+    fn hot_keys(&self) -> Vec<u64> {
+        let scatter_size = self.bucket_rows.len() as u64;
+        let fair_rows = self.sampled_rows / scatter_size;
+
+        let mut hot_keys = vec![];
+        for (bucket, rows) in self.bucket_rows.iter().enumerate() {
+            if *rows * 100 <= self.sampled_rows * self.threshold {
+                continue;
+            }
+
+            let mut keys = self
+                .key_rows
+                .iter()
+                .filter(|(key, _)| **key % scatter_size == bucket as u64)
+                .collect::<Vec<_>>();
+            keys.sort_by(|a, b| b.1.cmp(a.1));
+
+            let mut remaining_rows = *rows;
+            for (key, key_rows) in keys.into_iter().take(MAX_HOT_KEYS_PER_BUCKET) {
+                if remaining_rows <= fair_rows {
+                    break;
+                }
+                hot_keys.push(*key);
+                remaining_rows -= key_rows;
+            }
+        }
+
+        hot_keys
+    }
+}
+
+/// Skew state of a join on a node, shared by the exchanges of both sides of the join.
+pub struct JoinSkewState {
+    // None until the hot keys are sealed by the coordinator.
+    hot_keys: RwLock<Option<Arc<HashSet<u64>>>>,
+    detecting: AtomicBool,
+    detector: Mutex<SkewDetector>,
+    running_probes: AtomicUsize,
+}
+
+impl JoinSkewState {
+    pub fn create(scatter_size: usize, threshold: u64, detection_rows: u64) -> JoinSkewState {
+        JoinSkewState {
+            hot_keys: RwLock::new(None),
+            // Nothing is skewed if there is only one node.
+            detecting: AtomicBool::new(scatter_size > 1),
+            detector: Mutex::new(SkewDetector {
+                threshold,
+                detection_rows,
+                sampled_rows: 0,
+                bucket_rows: vec![0; scatter_size],
+                key_rows: HashMap::new(),
+                reported: false,
+            }),
+            running_probes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn hot_keys(&self) -> Option<Arc<HashSet<u64>>> {
+        self.hot_keys.read().clone()
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.hot_keys.read().is_some()
+    }
+
+    pub fn seal(&self, hot_keys: Vec<u64>) {
+        let mut guard = self.hot_keys.write();
+        if guard.is_none() {
+            *guard = Some(Arc::new(hot_keys.into_iter().collect()));
+        }
+    }
+
+    /// Samples the hashes of the join keys of the probe rows.
+    pub fn record(&self, hashes: &[u64]) {
+        if !self.detecting.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut detector = self.detector.lock();
+        let scatter_size = detector.bucket_rows.len() as u64;
+        for hash in hashes {
+            detector.bucket_rows[(*hash % scatter_size) as usize] += 1;
+            *detector.key_rows.entry(*hash).or_default() += 1;
+        }
+        detector.sampled_rows += hashes.len() as u64;
+
+        if detector.sampled_rows >= detector.detection_rows {
+            self.detecting.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Takes the locally detected hot keys once, after enough rows are sampled or if `force`.
+    pub fn take_report(&self, force: bool) -> Option<Vec<u64>> {
+        let mut detector = self.detector.lock();
+        if detector.reported || (!force && detector.sampled_rows < detector.detection_rows) {
+            return None;
+        }
+
+        self.detecting.store(false, Ordering::Relaxed);
+        detector.reported = true;
+        let hot_keys = match detector.sampled_rows {
+            0 => vec![],
+            _ => detector.hot_keys(),
+        };
+        detector.key_rows.clear();
+        Some(hot_keys)
+    }
+
+    pub fn is_reported(&self) -> bool {
+        self.detector.lock().reported
+    }
+
+    fn add_probe(&self) {
+        self.running_probes.fetch_add(1, Ordering::SeqCst);
+    }
+
+    // Returns true if it's the last running probe exchange on this node.
+    fn finish_probe(&self) -> bool {
+        self.running_probes.fetch_sub(1, Ordering::SeqCst) == 1
+    }
+}
+
+/// Skew states of the joins of a query.
+///
+/// The coordinator of the query also collects the hot keys reported by all nodes, and seals
+/// them when all nodes have reported or the waiting timed out.
+#[derive(Default)]
+pub struct JoinSkews {
+    states: Mutex<HashMap<u32, Arc<JoinSkewState>>>,
+    registrations: Mutex<HashMap<u32, SkewKeysRegistration>>,
+}
+
+impl JoinSkews {
+    pub fn get_or_create_state(
+        &self,
+        join_id: u32,
+        create: impl FnOnce() -> JoinSkewState,
+    ) -> Arc<JoinSkewState> {
+        self.states
+            .lock()
+            .entry(join_id)
+            .or_insert_with(|| Arc::new(create()))
+            .clone()
+    }
+
+    pub fn sync(
+        &self,
+        query_id: &str,
+        packet: &JoinSkewKeysPacket,
+        expected_nodes: usize,
+        timeout: Duration,
+    ) -> JoinSkewKeys {
+        let mut registrations = self.registrations.lock();
+        let registration =
+            registrations
+                .entry(packet.join_id)
+                .or_insert_with(|| SkewKeysRegistration {
+                    reported: HashSet::new(),
+                    hot_keys: BTreeSet::new(),
+                    sealed: false,
+                    created: Instant::now(),
+                });
+
+        if !registration.sealed {
+            if let Some(hot_keys) = &packet.hot_keys {
+                registration.reported.insert(packet.executor.clone());
+                registration.hot_keys.extend(hot_keys.iter().cloned());
+            }
+
+            if registration.reported.len() >= expected_nodes
+                || registration.created.elapsed() >= timeout
+            {
+                registration.sealed = true;
+
+                if !registration.hot_keys.is_empty() {
+                    metrics_inc_exchange_skewed_join_count();
+                    metrics_inc_exchange_hot_key_count(registration.hot_keys.len());
+                    info!(
+                        "Detected {} hot keys of join {} in query {}, reported by {} nodes",
+                        registration.hot_keys.len(),
+                        packet.join_id,
+                        query_id,
+                        registration.reported.len()
+                    );
+                }
+            }
+        }
+
+        JoinSkewKeys {
+            sealed: registration.sealed,
+            hot_keys: match registration.sealed {
+                true => registration.hot_keys.iter().cloned().collect(),
+                false => vec![],
+            },
+        }
+    }
+}
+
+/// Handles the hot keys reported by a node, on the coordinator of the query.
+pub fn sync_join_skew_keys(
+    ctx: &Arc<QueryContext>,
+    packet: &JoinSkewKeysPacket,
+) -> Result<JoinSkewKeys> {
+    let expected_nodes = ctx.get_cluster().get_executor_nodes().len();
+    let timeout = ctx.get_settings().get_join_skew_wait_timeout_ms()?;
+    Ok(ctx.get_join_skews().sync(
+        &ctx.get_id(),
+        packet,
+        expected_nodes,
+        Duration::from_millis(timeout),
+    ))
+}
+
+/// Synchronizes the hot keys of a skew-tolerant join with the coordinator before the rows
+/// are scattered.
+///
+/// The build side waits until the hot keys are sealed, so that its rows of the hot keys are
+/// replicated to all nodes from the start. The probe side never waits, its rows are sent to
+/// the owner of their keys until the hot keys are sealed, which is always correct.
+pub struct TransformJoinSkewSync {
+    ctx: Arc<QueryContext>,
+    skew: ShuffleSkew,
+    state: Arc<JoinSkewState>,
+    last_sync: Instant,
+}
+
+impl TransformJoinSkewSync {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        skew: ShuffleSkew,
+        state: Arc<JoinSkewState>,
+    ) -> TransformJoinSkewSync {
+        if skew.side == SkewJoinSide::Probe {
+            state.add_probe();
+        }
+
+        TransformJoinSkewSync {
+            ctx,
+            skew,
+            state,
+            last_sync: Instant::now(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn sync(&mut self, hot_keys: Option<Vec<u64>>) -> Result<()> {
+        let cluster = self.ctx.get_cluster();
+        let packet = JoinSkewKeysPacket {
+            query_id: self.ctx.get_id(),
+            join_id: self.skew.join_id,
+            executor: cluster.local_id.clone(),
+            hot_keys,
+        };
+
+        let keys = match self.skew.coordinator_id == cluster.local_id {
+            true => sync_join_skew_keys(&self.ctx, &packet)?,
+            false => {
+                let coordinator = cluster
+                    .nodes
+                    .iter()
+                    .find(|node| node.id == self.skew.coordinator_id)
+                    .ok_or_else(|| {
+                        ErrorCode::ClusterUnknownNode(format!(
+                            "Not found the coordinator node {} in cluster",
+                            self.skew.coordinator_id
+                        ))
+                    })?;
+
+                let config = GlobalConfig::instance();
+                let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+                packet
+                    .sync(&config, &coordinator.flight_address, timeout)
+                    .await?
+            }
+        };
+
+        self.last_sync = Instant::now();
+        if keys.sealed {
+            self.state.seal(keys.hot_keys);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformJoinSkewSync {
+    const NAME: &'static str = "TransformJoinSkewSync";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        if self.skew.side == SkewJoinSide::Build {
+            while !self.state.is_sealed() {
+                self.ctx.check_aborting()?;
+                self.sync(None).await?;
+
+                if !self.state.is_sealed() {
+                    sleep(SYNC_INTERVAL).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        if self.skew.side == SkewJoinSide::Probe && !self.state.is_sealed() {
+            if let Some(hot_keys) = self.state.take_report(false) {
+                self.sync(Some(hot_keys)).await?;
+            } else if self.state.is_reported() && self.last_sync.elapsed() >= SYNC_INTERVAL {
+                self.sync(None).await?;
+            }
+        }
+
+        Ok(data)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.skew.side == SkewJoinSide::Probe && self.state.finish_probe() {
+            // Report whatever is sampled, so that the other nodes don't wait for this node.
+            if let Some(hot_keys) = self.state.take_report(true) {
+                self.sync(Some(hot_keys)).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
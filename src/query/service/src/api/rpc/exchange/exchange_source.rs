@@ -78,6 +78,7 @@ pub fn via_exchange_source(
             ExchangeSourceReader::create(
                 output.clone(),
                 flight_exchange,
+                ctx.get_exchange_metrics(),
                 &destination_id,
                 &ctx.get_cluster().local_id(),
                 params.fragment_id,
@@ -31,21 +31,23 @@ use log::info;
 use crate::api::rpc::flight_client::FlightReceiver;
 use crate::api::DataPacket;
 use crate::api::ExchangeDeserializeMeta;
+use crate::api::ExchangeEdge;
+use crate::api::ExchangeMetrics;
 
 pub struct ExchangeSourceReader {
     finished: AtomicBool,
     output: Arc<OutputPort>,
     output_data: Vec<DataPacket>,
     flight_receiver: FlightReceiver,
-    source: String,
-    destination: String,
-    fragment: usize,
+    edge: ExchangeEdge,
+    metrics: Arc<ExchangeMetrics>,
 }
 
 impl ExchangeSourceReader {
     pub fn create(
         output: Arc<OutputPort>,
         flight_receiver: FlightReceiver,
+        metrics: Arc<ExchangeMetrics>,
         source: &str,
         destination: &str,
         fragment: usize,
@@ -53,11 +55,14 @@ impl ExchangeSourceReader {
         ProcessorPtr::create(Box::new(ExchangeSourceReader {
             output,
             flight_receiver,
-            source: source.to_string(),
-            destination: destination.to_string(),
+            edge: ExchangeEdge {
+                fragment_id: fragment,
+                source: source.to_string(),
+                destination: destination.to_string(),
+            },
+            metrics,
             finished: AtomicBool::new(false),
             output_data: vec![],
-            fragment,
         }))
     }
 }
@@ -126,6 +131,12 @@ impl Processor for ExchangeSourceReader {
                         metrics_inc_exchange_read_bytes(bytes);
                     }
 
+                    let rows = match &output_data {
+                        DataPacket::FragmentData(fragment_data) => fragment_data.num_rows(),
+                        _ => 0,
+                    };
+                    self.metrics.record_received(&self.edge, rows, bytes);
+
                     dictionaries.push(output_data);
                     self.output_data = dictionaries;
                     return Ok(());
@@ -155,9 +166,9 @@ impl Processor for ExchangeSourceReader {
         }
 
         Some(format!("{:?}", Display {
-            source: self.source.clone(),
-            destination: self.destination.clone(),
-            fragment: self.fragment,
+            source: self.edge.source.clone(),
+            destination: self.edge.destination.clone(),
+            fragment: self.edge.fragment_id,
             can_push: self.output.can_push()
         }))
     }
@@ -165,6 +176,7 @@ impl Processor for ExchangeSourceReader {
 
 pub fn create_reader_item(
     flight_receiver: FlightReceiver,
+    metrics: Arc<ExchangeMetrics>,
     source: &str,
     destination: &str,
     fragment: usize,
@@ -174,6 +186,7 @@ pub fn create_reader_item(
         ExchangeSourceReader::create(
             output.clone(),
             flight_receiver,
+            metrics,
             source,
             destination,
             fragment,
@@ -44,7 +44,7 @@ impl ExchangeTransform {
                 via_exchange_source(ctx.clone(), params, injector, pipeline)
             }
             ExchangeParams::ShuffleExchange(params) => {
-                exchange_shuffle(params, pipeline)?;
+                exchange_shuffle(ctx, params, pipeline)?;
 
                 // exchange writer sink and resize and exchange reader
                 let len = params.destination_ids.len();
@@ -78,6 +78,7 @@ impl ExchangeTransform {
                         nodes_source += 1;
                         items.push(create_reader_item(
                             receiver,
+                            ctx.get_exchange_metrics(),
                             &destination_id,
                             &params.executor_id,
                             params.fragment_id,
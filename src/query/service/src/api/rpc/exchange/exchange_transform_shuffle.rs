@@ -33,12 +33,15 @@ use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipe;
 use common_pipeline_core::PipeItem;
 use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::AsyncTransformer;
 
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
+use crate::api::rpc::exchange::exchange_skew::TransformJoinSkewSync;
 use crate::api::rpc::exchange::exchange_sorting::ExchangeSorting;
 use crate::api::rpc::exchange::exchange_sorting::TransformExchangeSorting;
 use crate::api::rpc::exchange::exchange_transform_scatter::ScatterTransform;
 use crate::api::rpc::exchange::serde::exchange_serializer::ExchangeSerializeMeta;
+use crate::sessions::QueryContext;
 
 pub struct ExchangeShuffleMeta {
     pub blocks: Vec<DataBlock>,
@@ -394,7 +397,23 @@ impl ExchangeShuffleTransform {
 }
 
 // Scatter the data block and push it to the corresponding output port
-pub fn exchange_shuffle(params: &ShuffleExchangeParams, pipeline: &mut Pipeline) -> Result<()> {
+pub fn exchange_shuffle(
+    ctx: &Arc<QueryContext>,
+    params: &ShuffleExchangeParams,
+    pipeline: &mut Pipeline,
+) -> Result<()> {
+    // sync the hot keys of skew-tolerant join before scattering
+    if let Some(skew) = &params.skew {
+        let state = ctx.get_join_skew_state(skew.join_id, params.destination_ids.len())?;
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncTransformer::create(
+                input,
+                output,
+                TransformJoinSkewSync::create(ctx.clone(), skew.clone(), state.clone()),
+            )))
+        })?;
+    }
+
     // append scatter transform
     pipeline.add_transform(|input, output| {
         Ok(ScatterTransform::create(
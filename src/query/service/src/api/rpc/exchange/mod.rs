@@ -15,9 +15,11 @@
 mod data_exchange;
 mod exchange_injector;
 mod exchange_manager;
+mod exchange_metrics;
 mod exchange_params;
 mod exchange_sink;
 mod exchange_sink_writer;
+mod exchange_skew;
 mod exchange_sorting;
 mod exchange_source;
 mod exchange_source_reader;
@@ -32,11 +34,19 @@ pub use data_exchange::BroadcastExchange;
 pub use data_exchange::DataExchange;
 pub use data_exchange::MergeExchange;
 pub use data_exchange::ShuffleDataExchange;
+pub use data_exchange::ShuffleSkew;
 pub use exchange_injector::DefaultExchangeInjector;
 pub use exchange_injector::ExchangeInjector;
 pub use exchange_manager::DataExchangeManager;
+pub use exchange_metrics::ExchangeEdge;
+pub use exchange_metrics::ExchangeEdgeMetrics;
+pub use exchange_metrics::ExchangeMetrics;
 pub use exchange_params::MergeExchangeParams;
 pub use exchange_params::ShuffleExchangeParams;
+pub use exchange_skew::sync_join_skew_keys;
+pub use exchange_skew::JoinSkewKeys;
+pub use exchange_skew::JoinSkewState;
+pub use exchange_skew::JoinSkews;
 pub use exchange_sorting::ExchangeSorting;
 pub use exchange_transform_shuffle::ExchangeShuffleMeta;
 
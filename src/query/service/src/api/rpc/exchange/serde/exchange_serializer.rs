@@ -15,6 +15,8 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::io::flight::default_ipc_fields;
@@ -47,13 +49,20 @@ use crate::api::FragmentData;
 pub struct ExchangeSerializeMeta {
     pub block_number: isize,
     pub packet: Vec<DataPacket>,
+    /// Time spent on serializing the block into the packets.
+    pub serialize_time: Duration,
 }
 
 impl ExchangeSerializeMeta {
-    pub fn create(block_number: isize, packet: Vec<DataPacket>) -> BlockMetaInfoPtr {
+    pub fn create(
+        block_number: isize,
+        packet: Vec<DataPacket>,
+        serialize_time: Duration,
+    ) -> BlockMetaInfoPtr {
         Box::new(ExchangeSerializeMeta {
             packet,
             block_number,
+            serialize_time,
         })
     }
 }
@@ -186,9 +195,11 @@ pub fn serialize_block(
         return Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
             block_num,
             vec![],
+            Duration::ZERO,
         )));
     }
 
+    let start = Instant::now();
     let mut meta = vec![];
     meta.write_scalar_own(data_block.num_rows() as u32)?;
     bincode::serialize_into(&mut meta, &data_block.get_meta())
@@ -210,6 +221,8 @@ pub fn serialize_block(
 
     packet.push(DataPacket::FragmentData(FragmentData::create(meta, values)));
     Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
-        block_num, packet,
+        block_num,
+        packet,
+        start.elapsed(),
     )))
 }
@@ -139,6 +139,10 @@ impl StatisticsReceiver {
                 ctx.get_merge_status().write().merge_status(status);
                 Ok(false)
             }
+            Ok(Some(DataPacket::ExchangeMetrics(metrics))) => {
+                ctx.get_exchange_metrics().merge(metrics);
+                Ok(false)
+            }
         }
     }
 
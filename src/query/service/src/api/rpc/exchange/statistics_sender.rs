@@ -91,6 +91,10 @@ impl StatisticsSender {
                     warn!("MergeStatus send has error, cause: {:?}.", error);
                 }
 
+                if let Err(error) = Self::send_exchange_metrics(&ctx, &tx).await {
+                    warn!("ExchangeMetrics send has error, cause: {:?}.", error);
+                }
+
                 if let Err(error) = Self::send_statistics(&ctx, &tx).await {
                     warn!("Statistics send has error, cause: {:?}.", error);
                 }
@@ -161,6 +165,19 @@ impl StatisticsSender {
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn send_exchange_metrics(
+        ctx: &Arc<QueryContext>,
+        flight_sender: &FlightSender,
+    ) -> Result<()> {
+        let metrics = ctx.get_exchange_metrics().snapshot();
+        if !metrics.is_empty() {
+            let data_packet = DataPacket::ExchangeMetrics(metrics);
+            flight_sender.send(data_packet).await?;
+        }
+        Ok(())
+    }
+
     fn fetch_progress(ctx: &Arc<QueryContext>) -> Result<Vec<ProgressInfo>> {
         let mut progress_info = vec![];
 
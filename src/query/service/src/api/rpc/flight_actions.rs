@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::ToErrorCode;
 use tonic::Status;
 
+use crate::api::rpc::packets::JoinSkewKeysPacket;
 use crate::api::rpc::packets::KillQueryPacket;
 use crate::api::rpc::packets::TruncateTablePacket;
 use crate::api::InitNodesChannelPacket;
@@ -132,6 +133,33 @@ impl TryInto<Vec<u8>> for KillQuery {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct SyncJoinSkewKeys {
+    pub packet: JoinSkewKeysPacket,
+}
+
+impl TryInto<SyncJoinSkewKeys> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<SyncJoinSkewKeys, Self::Error> {
+        match serde_json::from_slice::<SyncJoinSkewKeys>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for SyncJoinSkewKeys {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(
+            ErrorCode::Internal,
+            || "Logical error: cannot serialize JoinSkewKeysPacket.",
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
@@ -139,6 +167,8 @@ pub enum FlightAction {
     ExecutePartialQuery(String),
     TruncateTable(TruncateTable),
     KillQuery(KillQuery),
+    GetProcessesInfo,
+    SyncJoinSkewKeys(SyncJoinSkewKeys),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -158,6 +188,8 @@ impl TryInto<FlightAction> for Action {
             },
             "TruncateTable" => Ok(FlightAction::TruncateTable(self.body.try_into()?)),
             "KillQuery" => Ok(FlightAction::KillQuery(self.body.try_into()?)),
+            "GetProcessesInfo" => Ok(FlightAction::GetProcessesInfo),
+            "SyncJoinSkewKeys" => Ok(FlightAction::SyncJoinSkewKeys(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -191,6 +223,14 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("KillQuery"),
                 body: kill_query.try_into()?,
             }),
+            FlightAction::GetProcessesInfo => Ok(Action {
+                r#type: String::from("GetProcessesInfo"),
+                body: vec![],
+            }),
+            FlightAction::SyncJoinSkewKeys(sync_join_skew_keys) => Ok(Action {
+                r#type: String::from("SyncJoinSkewKeys"),
+                body: sync_join_skew_keys.try_into()?,
+            }),
         }
     }
 }
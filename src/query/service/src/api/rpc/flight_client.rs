@@ -62,6 +62,14 @@ impl FlightClient {
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    pub async fn request_action(&mut self, action: FlightAction, timeout: u64) -> Result<Vec<u8>> {
+        match self.do_action(action, timeout).await {
+            Ok(body) => Ok(body),
+            Err(cause) => Err(cause.add_message_back("(while in query flight)")),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn request_server_exchange(
         &mut self,
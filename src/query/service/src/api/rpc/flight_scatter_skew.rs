@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::physical_plans::SkewJoinSide;
+
+use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::rpc::flight_scatter_hash::get_hash_values;
+use crate::api::JoinSkewState;
+
+/// Hash scatter of one side of a skew-tolerant join.
+///
+/// Rows are sent to the owner of their keys, except the rows of the hot keys: build rows are
+/// replicated to all nodes, and probe rows are spread over all nodes in round robin.
+pub struct SkewFlightScatter {
+    func_ctx: FunctionContext,
+    hash_keys: Vec<Expr>,
+    scatter_size: usize,
+    side: SkewJoinSide,
+    state: Arc<JoinSkewState>,
+    next_destination: AtomicUsize,
+}
+
+impl SkewFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        hash_keys: Vec<RemoteExpr>,
+        scatter_size: usize,
+        side: SkewJoinSide,
+        state: Arc<JoinSkewState>,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let hash_keys = hash_keys
+            .iter()
+            .map(|key| {
+                check_function(
+                    None,
+                    "siphash",
+                    &[],
+                    &[key.as_expr(&BUILTIN_FUNCTIONS)],
+                    &BUILTIN_FUNCTIONS,
+                )
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Box::new(SkewFlightScatter {
+            func_ctx,
+            hash_keys,
+            scatter_size,
+            side,
+            state,
+            next_destination: AtomicUsize::new(0),
+        }))
+    }
+
+    fn hash_rows(&self, data_block: &DataBlock) -> Result<Vec<u64>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = data_block.num_rows();
+
+        let mut hash_values = Vec::with_capacity(self.hash_keys.len());
+        for expr in &self.hash_keys {
+            let column = evaluator.run(expr)?;
+            hash_values.push(get_hash_values(column, num_rows)?);
+        }
+
+        if hash_values.len() == 1 {
+            return Ok(hash_values[0].to_vec());
+        }
+
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        for values in hash_values.iter() {
+            for (hash, value) in hashes.iter_mut().zip(values.iter()) {
+                hash.write_u64(*value);
+            }
+        }
+        Ok(hashes.into_iter().map(|hash| hash.finish()).collect())
+    }
+}
+
+impl FlightScatter for SkewFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let hashes = self.hash_rows(&data_block)?;
+        let scatter_size = self.scatter_size as u64;
+
+        let hot_keys = self.state.hot_keys();
+        if self.side == SkewJoinSide::Probe && hot_keys.is_none() {
+            self.state.record(&hashes);
+        }
+
+        let mut indices = vec![Vec::new(); self.scatter_size];
+        match hot_keys {
+            Some(hot_keys) if !hot_keys.is_empty() => {
+                let mut next_destination = self.next_destination.load(Ordering::Relaxed);
+                for (row, hash) in hashes.iter().enumerate() {
+                    if !hot_keys.contains(hash) {
+                        indices[(*hash % scatter_size) as usize].push(row as u32);
+                        continue;
+                    }
+
+                    match self.side {
+                        SkewJoinSide::Build => {
+                            for destination in indices.iter_mut() {
+                                destination.push(row as u32);
+                            }
+                        }
+                        SkewJoinSide::Probe => {
+                            indices[next_destination].push(row as u32);
+                            next_destination = (next_destination + 1) % self.scatter_size;
+                        }
+                    }
+                }
+                self.next_destination
+                    .store(next_destination, Ordering::Relaxed);
+            }
+            _ => {
+                for (row, hash) in hashes.iter().enumerate() {
+                    indices[(*hash % scatter_size) as usize].push(row as u32);
+                }
+            }
+        }
+
+        let block_meta = data_block.get_meta();
+        let mut res = Vec::with_capacity(self.scatter_size);
+        for indices in indices {
+            let block = data_block.take(&indices, &mut None)?;
+            res.push(block.add_meta(block_meta.cloned())?);
+        }
+
+        Ok(res)
+    }
+}
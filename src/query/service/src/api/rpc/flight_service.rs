@@ -31,8 +31,11 @@ use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
 use common_base::match_join_handle;
 use common_base::runtime::TrySpawn;
+use common_catalog::table_context::NodeProcessInfo;
 use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::ToErrorCode;
 use common_settings::Settings;
 use minitrace::full_name;
 use minitrace::prelude::*;
@@ -42,6 +45,7 @@ use tonic::Response as RawResponse;
 use tonic::Status;
 use tonic::Streaming;
 
+use crate::api::rpc::exchange::sync_join_skew_keys;
 use crate::api::rpc::flight_actions::FlightAction;
 use crate::api::rpc::request_builder::RequestGetter;
 use crate::api::DataExchangeManager;
@@ -250,6 +254,33 @@ impl FlightService for DatabendQueryFlightService {
                     interpreter.execute2().await?;
                     FlightResult { body: vec![] }
                 }
+                FlightAction::GetProcessesInfo => {
+                    let config = GlobalConfig::instance();
+                    let processes_info = SessionManager::instance()
+                        .processes_info()
+                        .iter()
+                        .map(|process_info| {
+                            NodeProcessInfo::create(&config.query.node_id, process_info)
+                        })
+                        .collect::<Vec<_>>();
+
+                    let body = serde_json::to_vec(&processes_info).map_err_to_code(
+                        ErrorCode::Internal,
+                        || "Logical error: cannot serialize processes info.",
+                    )?;
+                    FlightResult { body }
+                }
+                FlightAction::SyncJoinSkewKeys(sync_keys) => {
+                    let packet = &sync_keys.packet;
+                    let ctx = DataExchangeManager::instance().get_query_ctx(&packet.query_id)?;
+                    let join_skew_keys = sync_join_skew_keys(&ctx, packet)?;
+
+                    let body = serde_json::to_vec(&join_skew_keys).map_err_to_code(
+                        ErrorCode::Internal,
+                        || "Logical error: cannot serialize join skew keys.",
+                    )?;
+                    FlightResult { body }
+                }
             };
 
             Ok(RawResponse::new(
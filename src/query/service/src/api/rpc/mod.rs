@@ -22,6 +22,7 @@ mod flight_client;
 mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
+mod flight_scatter_skew;
 mod flight_service;
 mod packets;
 mod request_builder;
@@ -32,25 +33,35 @@ pub use exchange::DataExchange;
 pub use exchange::DataExchangeManager;
 pub use exchange::DefaultExchangeInjector;
 pub use exchange::ExchangeDeserializeMeta;
+pub use exchange::ExchangeEdge;
+pub use exchange::ExchangeEdgeMetrics;
 pub use exchange::ExchangeInjector;
+pub use exchange::ExchangeMetrics;
 pub use exchange::ExchangeSerializeMeta;
 pub use exchange::ExchangeShuffleMeta;
 pub use exchange::ExchangeSorting;
+pub use exchange::JoinSkewKeys;
+pub use exchange::JoinSkewState;
+pub use exchange::JoinSkews;
 pub use exchange::MergeExchange;
 pub use exchange::MergeExchangeParams;
 pub use exchange::ShuffleDataExchange;
 pub use exchange::ShuffleExchangeParams;
+pub use exchange::ShuffleSkew;
 pub use exchange::TransformExchangeDeserializer;
 pub use flight_scatter::FlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
 pub use flight_scatter_hash::HashFlightScatter;
+pub use flight_scatter_skew::SkewFlightScatter;
 pub use packets::ConnectionInfo;
 pub use packets::DataPacket;
 pub use packets::ExecutePartialQueryPacket;
 pub use packets::FragmentData;
 pub use packets::FragmentPlanPacket;
 pub use packets::InitNodesChannelPacket;
+pub use packets::JoinSkewKeysPacket;
 pub use packets::KillQueryPacket;
 pub use packets::Packet;
+pub use packets::ProcessesInfoPacket;
 pub use packets::QueryFragmentsPlanPacket;
 pub use packets::TruncateTablePacket;
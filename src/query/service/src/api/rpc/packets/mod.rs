@@ -18,7 +18,9 @@ mod packet_data_progressinfo;
 mod packet_execute;
 mod packet_executor;
 mod packet_fragment;
+mod packet_join_skew_keys;
 mod packet_kill_query;
+mod packet_processes_info;
 mod packet_publisher;
 mod packet_truncate_table;
 
@@ -29,7 +31,9 @@ pub use packet_data_progressinfo::ProgressInfo;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
 pub use packet_fragment::FragmentPlanPacket;
+pub use packet_join_skew_keys::JoinSkewKeysPacket;
 pub use packet_kill_query::KillQueryPacket;
+pub use packet_processes_info::ProcessesInfoPacket;
 pub use packet_publisher::ConnectionInfo;
 pub use packet_publisher::InitNodesChannelPacket;
 pub use packet_truncate_table::TruncateTablePacket;
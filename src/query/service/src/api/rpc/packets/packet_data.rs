@@ -22,11 +22,14 @@ use byteorder::WriteBytesExt;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::BinaryRead;
 use common_storage::CopyStatus;
 use common_storage::MergeStatus;
 use log::error;
 
 use crate::api::rpc::packets::ProgressInfo;
+use crate::api::ExchangeEdge;
+use crate::api::ExchangeEdgeMetrics;
 
 pub struct FragmentData {
     meta: Vec<u8>,
@@ -41,6 +44,12 @@ impl FragmentData {
     pub fn create(meta: Vec<u8>, data: FlightData) -> FragmentData {
         FragmentData { meta, data }
     }
+
+    /// The number of rows of the block, written at the head of the meta by `serialize_block`.
+    pub fn num_rows(&self) -> usize {
+        let mut meta = self.get_meta();
+        meta.read_scalar::<u32>().unwrap_or_default() as usize
+    }
 }
 
 impl Debug for FragmentData {
@@ -57,6 +66,7 @@ pub enum DataPacket {
     SerializeProgress(Vec<ProgressInfo>),
     CopyStatus(CopyStatus),
     MergeStatus(MergeStatus),
+    ExchangeMetrics(Vec<(ExchangeEdge, ExchangeEdgeMetrics)>),
 }
 
 fn calc_size(flight_data: &FlightData) -> usize {
@@ -70,6 +80,7 @@ impl DataPacket {
             DataPacket::FetchProgress => 0,
             DataPacket::CopyStatus(_) => 0,
             DataPacket::MergeStatus(_) => 0,
+            DataPacket::ExchangeMetrics(_) => 0,
             DataPacket::SerializeProgress(_) => 0,
             DataPacket::Dictionary(v) => calc_size(v),
             DataPacket::FragmentData(v) => calc_size(&v.data) + v.meta.len(),
@@ -125,6 +136,12 @@ impl TryFrom<DataPacket> for FlightData {
                 data_header: vec![],
                 flight_descriptor: None,
             },
+            DataPacket::ExchangeMetrics(metrics) => FlightData {
+                app_metadata: vec![0x08],
+                data_body: serde_json::to_vec(&metrics)?,
+                data_header: vec![],
+                flight_descriptor: None,
+            },
         })
     }
 }
@@ -176,6 +193,10 @@ impl TryFrom<FlightData> for DataPacket {
                 let status = serde_json::from_slice::<MergeStatus>(&flight_data.data_body)?;
                 Ok(DataPacket::MergeStatus(status))
             }
+            0x08 => {
+                let metrics = serde_json::from_slice(&flight_data.data_body)?;
+                Ok(DataPacket::ExchangeMetrics(metrics))
+            }
             _ => Err(ErrorCode::BadBytes("Unknown flight data packet type.")),
         }
     }
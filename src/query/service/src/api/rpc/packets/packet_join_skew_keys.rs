@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+
+use crate::api::rpc::flight_actions::SyncJoinSkewKeys;
+use crate::api::rpc::packets::packet::create_client;
+use crate::api::FlightAction;
+use crate::api::JoinSkewKeys;
+
+/// Reports the hot keys of a join detected by a node to the coordinator, or polls the sealed
+/// hot keys if `hot_keys` is none.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct JoinSkewKeysPacket {
+    pub query_id: String,
+    pub join_id: u32,
+    pub executor: String,
+    pub hot_keys: Option<Vec<u64>>,
+}
+
+impl JoinSkewKeysPacket {
+    #[async_backtrace::framed]
+    pub async fn sync(
+        &self,
+        config: &InnerConfig,
+        flight_address: &str,
+        timeout: u64,
+    ) -> Result<JoinSkewKeys> {
+        let mut conn = create_client(config, flight_address).await?;
+        let action = FlightAction::SyncJoinSkewKeys(SyncJoinSkewKeys {
+            packet: self.clone(),
+        });
+        let body = conn.request_action(action, timeout).await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::Internal, || {
+            format!(
+                "Logical error: cannot deserialize hot keys of join {} in query {}.",
+                self.join_id, self.query_id
+            )
+        })
+    }
+}
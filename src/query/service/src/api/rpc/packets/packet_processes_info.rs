@@ -0,0 +1,52 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::NodeProcessInfo;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_types::NodeInfo;
+
+use crate::api::rpc::packets::packet::create_client;
+use crate::api::FlightAction;
+
+/// Fetches the processes info of a node of the cluster, for `system.processes`.
+#[derive(Clone, Debug)]
+pub struct ProcessesInfoPacket {
+    pub executor: Arc<NodeInfo>,
+}
+
+impl ProcessesInfoPacket {
+    pub fn create(executor: Arc<NodeInfo>) -> ProcessesInfoPacket {
+        ProcessesInfoPacket { executor }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn fetch(&self, config: &InnerConfig, timeout: u64) -> Result<Vec<NodeProcessInfo>> {
+        let executor_info = &self.executor;
+        let mut conn = create_client(config, &executor_info.flight_address).await?;
+        let body = conn
+            .request_action(FlightAction::GetProcessesInfo, timeout)
+            .await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::Internal, || {
+            format!(
+                "Logical error: cannot deserialize processes info of node {}.",
+                executor_info.id
+            )
+        })
+    }
+}
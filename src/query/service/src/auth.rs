@@ -24,6 +24,7 @@ use common_meta_app::principal::UserInfo;
 use common_users::JwtAuthenticator;
 use common_users::UserApiProvider;
 
+use crate::servers::http::v1::SessionTokenManager;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
@@ -40,6 +41,11 @@ pub enum Credential {
         password: Option<Vec<u8>>,
         client_ip: Option<String>,
     },
+    /// Session token issued by the `/v1/session/login` of the http handler.
+    DatabendToken {
+        token: String,
+        client_ip: Option<String>,
+    },
 }
 
 impl AuthMgr {
@@ -141,17 +147,28 @@ impl AuthMgr {
                     } => match p {
                         None => return Err(ErrorCode::AuthenticateFailure("password required")),
                         Some(p) => {
-                            if *h == t.hash(p) {
-                                user
-                            } else {
+                            let authed = *h == t.hash(p);
+                            let must_change_password = user_api
+                                .check_login_password(&tenant, &user, authed)
+                                .await?;
+                            if !authed {
                                 return Err(ErrorCode::AuthenticateFailure("wrong password"));
                             }
+                            session.set_must_change_password(must_change_password);
+                            user
                         }
                     },
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
                 session.set_authed_user(user, None).await?;
             }
+            Credential::DatabendToken { token, client_ip } => {
+                let (tenant, user) = SessionTokenManager::instance()
+                    .authenticate(token, client_ip.as_deref())
+                    .await?;
+                session.set_current_tenant(tenant);
+                session.set_authed_user(user, None).await?;
+            }
         };
         Ok(())
     }
@@ -60,6 +60,7 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockMeta;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -364,6 +365,33 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn list_tables_with_options(
+        &self,
+        req: ListTableWithOptionsReq,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        if req.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while list tables)",
+            ));
+        }
+
+        let r = self
+            .immutable_catalog
+            .list_tables_with_options(req.clone())
+            .await;
+        match r {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_DATABASE {
+                    self.mutable_catalog.list_tables_with_options(req).await
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
         if req.tenant().is_empty() {
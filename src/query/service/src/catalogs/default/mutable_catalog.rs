@@ -63,6 +63,7 @@ use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::ListIndexesByIdReq;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_app::schema::ListLockRevReq;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::LockMeta;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -378,6 +379,15 @@ impl Catalog for MutableCatalog {
         db.list_tables_history().await
     }
 
+    #[async_backtrace::framed]
+    async fn list_tables_with_options(
+        &self,
+        req: ListTableWithOptionsReq,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        let db = self.get_database(&req.tenant, &req.db_name).await?;
+        db.list_tables_with_options(&req.options).await
+    }
+
     async fn get_drop_table_infos(
         &self,
         req: ListDroppedTableReq,
@@ -74,18 +74,27 @@ pub trait ClusterHelper {
     fn local_id(&self) -> String;
     async fn create_node_conn(&self, name: &str, config: &InnerConfig) -> Result<FlightClient>;
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>>;
+    /// The nodes labeled with the warehouse, with the local node which coordinates the queries.
+    /// The local node only executes the fragments if it is labeled with the warehouse too.
+    /// Returns None if no node is labeled with the warehouse.
+    fn route_to_warehouse(&self, warehouse: &str) -> Option<Arc<Cluster>>;
 }
 
 #[async_trait::async_trait]
 impl ClusterHelper for Cluster {
     fn create(nodes: Vec<Arc<NodeInfo>>, local_id: String) -> Arc<Cluster> {
-        Arc::new(Cluster { local_id, nodes })
+        Arc::new(Cluster {
+            local_id,
+            nodes,
+            local_executor: true,
+        })
     }
 
     fn empty() -> Arc<Cluster> {
         Arc::new(Cluster {
             local_id: String::from(""),
             nodes: Vec::new(),
+            local_executor: true,
         })
     }
 
@@ -135,6 +144,30 @@ impl ClusterHelper for Cluster {
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>> {
         self.nodes.to_vec()
     }
+
+    fn route_to_warehouse(&self, warehouse: &str) -> Option<Arc<Cluster>> {
+        if !self.nodes.iter().any(|node| node.has_label(warehouse)) {
+            return None;
+        }
+
+        // The local node coordinates the query even if it is not in the warehouse,
+        // but the fragments are only placed on the nodes of the warehouse.
+        let nodes = self
+            .nodes
+            .iter()
+            .filter(|node| node.has_label(warehouse) || self.is_local(node))
+            .cloned()
+            .collect();
+        let local_executor = self
+            .nodes
+            .iter()
+            .any(|node| self.is_local(node) && node.has_label(warehouse));
+        Some(Arc::new(Cluster {
+            local_id: self.local_id.clone(),
+            nodes,
+            local_executor,
+        }))
+    }
 }
 
 impl ClusterDiscovery {
@@ -335,6 +368,7 @@ impl ClusterDiscovery {
             cpus,
             address,
             DATABEND_COMMIT_VERSION.to_string(),
+            cfg.query.node_labels.clone(),
         );
 
         self.drop_invalid_nodes(&node_info).await?;
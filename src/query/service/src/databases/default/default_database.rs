@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::table::Table;
 use common_exception::Result;
 use common_meta_api::SchemaApi;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_app::principal::VolumeLocation;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DatabaseInfo;
@@ -25,7 +28,9 @@ use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
+use common_meta_app::schema::ListTableOptions;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
@@ -39,6 +44,9 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME_PATH;
 
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
@@ -64,6 +72,42 @@ impl DefaultDatabase {
             Ok(acc)
         })
     }
+
+    /// Resolves the storage params of the tables on external volumes through the volumes,
+    /// so that the tables always use the current configuration of their volumes.
+    #[async_backtrace::framed]
+    async fn resolve_external_volumes(
+        &self,
+        table_infos: Vec<Arc<TableInfo>>,
+    ) -> Result<Vec<Arc<TableInfo>>> {
+        let mut volumes: HashMap<String, ExternalVolume> = HashMap::new();
+        let mut resolved = Vec::with_capacity(table_infos.len());
+        for table_info in table_infos {
+            let Some(volume_name) = table_info.options().get(OPT_KEY_EXTERNAL_VOLUME).cloned()
+            else {
+                resolved.push(table_info);
+                continue;
+            };
+            if !volumes.contains_key(&volume_name) {
+                let volume = UserApiProvider::instance()
+                    .get_external_volume(self.get_tenant(), &volume_name)
+                    .await?;
+                volumes.insert(volume_name.clone(), volume);
+            }
+            let location = VolumeLocation {
+                path: table_info
+                    .options()
+                    .get(OPT_KEY_EXTERNAL_VOLUME_PATH)
+                    .cloned()
+                    .unwrap_or_default(),
+                volume: volume_name,
+            };
+            let mut table_info = table_info.as_ref().clone();
+            table_info.meta.storage_params = Some(volumes[&location.volume].resolve(&location));
+            resolved.push(Arc::new(table_info));
+        }
+        Ok(resolved)
+    }
 }
 
 #[async_trait::async_trait]
@@ -93,7 +137,8 @@ impl Database for DefaultDatabase {
                 table_name,
             ))
             .await?;
-        self.get_table_by_info(table_info.as_ref())
+        let table_info = self.resolve_external_volumes(vec![table_info]).await?;
+        self.get_table_by_info(table_info[0].as_ref())
     }
 
     #[async_backtrace::framed]
@@ -104,6 +149,7 @@ impl Database for DefaultDatabase {
             .list_tables(ListTableReq::new(self.get_tenant(), self.get_db_name()))
             .await?;
 
+        let table_infos = self.resolve_external_volumes(table_infos).await?;
         self.load_tables(table_infos)
     }
 
@@ -129,9 +175,29 @@ impl Database for DefaultDatabase {
 
         table_infos.append(&mut dropped);
 
+        let table_infos = self.resolve_external_volumes(table_infos).await?;
         self.load_tables(table_infos)
     }
 
+    #[async_backtrace::framed]
+    async fn list_tables_with_options(
+        &self,
+        options: &ListTableOptions,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        let reply = self
+            .ctx
+            .meta
+            .list_tables_with_options(ListTableWithOptionsReq::new(
+                self.get_tenant(),
+                self.get_db_name(),
+                options.clone(),
+            ))
+            .await?;
+
+        let table_infos = self.resolve_external_volumes(reply.table_infos).await?;
+        Ok((self.load_tables(table_infos)?, reply.next_token))
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
         let res = self.ctx.meta.create_table(req).await?;
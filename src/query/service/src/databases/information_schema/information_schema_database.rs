@@ -21,6 +21,7 @@ use common_meta_app::schema::DatabaseNameIdent;
 use common_storages_information_schema::ColumnsTable;
 use common_storages_information_schema::KeyColumnUsageTable;
 use common_storages_information_schema::KeywordsTable;
+use common_storages_information_schema::ReferentialConstraintsTable;
 use common_storages_information_schema::SchemataTable;
 use common_storages_information_schema::StatisticsTable;
 use common_storages_information_schema::TablesTable;
@@ -45,6 +46,7 @@ impl InformationSchemaDatabase {
             SchemataTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id()),
             KeyColumnUsageTable::create(sys_db_meta.next_table_id()),
+            ReferentialConstraintsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = "information_schema";
@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_storages_system::AuditLogTable;
 use common_storages_system::BackgroundJobTable;
 use common_storages_system::BackgroundTaskTable;
 use common_storages_system::BacktraceTable;
@@ -56,6 +57,7 @@ use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TaskHistoryTable;
 use common_storages_system::TasksTable;
 use common_storages_system::TempFilesTable;
+use common_storages_system::TenantQuotasTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -105,6 +107,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_audit_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -123,6 +129,7 @@ impl SystemDatabase {
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
             ProcessorProfileTable::create(sys_db_meta.next_table_id()),
+            TenantQuotasTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -29,6 +29,7 @@ use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
+use common_storages_fuse::operations::TenantStorageUsage;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
 use common_tracing::GlobalLogger;
@@ -41,8 +42,11 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AuditLogger;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::http::v1::SessionTokenManager;
 use crate::sessions::SessionManager;
+use crate::write_buffer::WriteBufferManager;
 
 pub struct GlobalServices;
 
@@ -108,7 +112,10 @@ impl GlobalServices {
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        TenantStorageUsage::init()?;
         AuthMgr::init(config)?;
+        SessionTokenManager::init(config)?;
+        AuditLogger::init(config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
             config.query.idm.clone(),
@@ -132,6 +139,8 @@ impl GlobalServices {
             CloudControlApiProvider::init(addr).await?;
         }
 
+        WriteBufferManager::init(config)?;
+
         Ok(())
     }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 
+use crate::interpreters::access::PasswordAccess;
 use crate::interpreters::access::PrivilegeAccess;
 use crate::interpreters::ManagementModeAccess;
 use crate::sessions::QueryContext;
@@ -37,6 +38,7 @@ impl Accessor {
     pub fn create(ctx: Arc<QueryContext>) -> Self {
         let mut accessors: HashMap<String, Box<dyn AccessChecker>> = Default::default();
         accessors.insert("management".to_string(), ManagementModeAccess::create());
+        accessors.insert("password".to_string(), PasswordAccess::create());
         accessors.insert(
             "privilege".to_string(),
             PrivilegeAccess::create(ctx.clone()),
@@ -90,6 +90,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
                 | Plan::DropRole(_)
+                | Plan::AlterRole(_)
 
                 // Privilege.
                 | Plan::GrantPriv(_)
@@ -98,11 +99,27 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::RevokeRole(_)
                 // Stage.
                 | Plan::CreateStage(_)
+                | Plan::AlterStage(_)
                 | Plan::DropStage(_)
                 // Network policy.
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Password policy.
+                | Plan::CreatePasswordPolicy(_)
+                | Plan::AlterPasswordPolicy(_)
+                | Plan::DropPasswordPolicy(_)
+                // Settings profile.
+                | Plan::CreateSettingsProfile(_)
+                | Plan::AlterSettingsProfile(_)
+                | Plan::DropSettingsProfile(_)
+                // Row access policy.
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+                // External volume.
+                | Plan::CreateExternalVolume(_)
+                | Plan::AlterExternalVolume(_)
+                | Plan::DropExternalVolume(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
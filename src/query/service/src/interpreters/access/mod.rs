@@ -14,9 +14,11 @@
 
 mod accessor;
 mod management_mode_access;
+mod password_access;
 mod privilege_access;
 
 pub use accessor::AccessChecker;
 pub use accessor::Accessor;
 pub use management_mode_access::ManagementModeAccess;
+pub use password_access::PasswordAccess;
 pub use privilege_access::PrivilegeAccess;
@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+
+pub struct PasswordAccess {}
+
+impl PasswordAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(PasswordAccess {})
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for PasswordAccess {
+    // Check what we can do if the password of the current user is expired.
+    #[async_backtrace::framed]
+    async fn check(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        if !ctx.get_current_session().get_must_change_password() {
+            return Ok(());
+        }
+
+        // Only changing the password of the current user is allowed.
+        if let Plan::AlterUser(plan) = plan {
+            if plan.auth_info.is_some() && plan.user == ctx.get_current_user()?.identity() {
+                return Ok(());
+            }
+        }
+        Err(ErrorCode::PasswordMustBeChanged(
+            "the password is expired, it must be changed with `ALTER USER ... IDENTIFIED BY ...` before executing other statements",
+        ))
+    }
+}
@@ -30,6 +30,8 @@ use common_sql::plans::InsertInputSource;
 use common_sql::plans::PresignAction;
 use common_sql::plans::RewriteKind;
 use common_users::RoleCacheManager;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
@@ -126,6 +128,24 @@ impl PrivilegeAccess {
         session.validate_privilege(object, privileges).await
     }
 
+    // The audit log is only visible to the admin role, whatever privileges are granted
+    // on the system database.
+    async fn validate_audit_log_access(&self) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        let roles = session.get_all_available_roles().await?;
+        if roles
+            .iter()
+            .any(|role| role.name == BUILTIN_ROLE_ACCOUNT_ADMIN)
+        {
+            return Ok(());
+        }
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, user {} must have role {} to access system.audit_log",
+            self.ctx.get_current_user()?.identity(),
+            BUILTIN_ROLE_ACCOUNT_ADMIN
+        )))
+    }
+
     async fn validate_access_stage(
         &self,
         stage_info: &StageInfo,
@@ -273,6 +293,11 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
+                    if table.database().eq_ignore_ascii_case("system")
+                        && table.name() == "audit_log"
+                    {
+                        self.validate_audit_log_access().await?;
+                    }
                     self.validate_access(
                         &GrantObject::Table(
                             table.catalog().to_string(),
@@ -375,6 +400,44 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
 
+            // Inverted index.
+            Plan::CreateInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Create],
+                    false,
+                )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Drop],
+                    false,
+                )
+                    .await?;
+            }
+            Plan::RefreshInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    false,
+                )
+                    .await?;
+            }
+
             // Table.
             Plan::ShowCreateTable(plan) => {
                 self.validate_access(
@@ -407,6 +470,14 @@ impl AccessChecker for PrivilegeAccess {
                     true,
                 )
                     .await?;
+                if let Some(volume) = plan.options.get(OPT_KEY_EXTERNAL_VOLUME) {
+                    self.validate_access(
+                        &GrantObject::Volume(volume.clone()),
+                        vec![UserPrivilegeType::Usage],
+                        false,
+                    )
+                        .await?;
+                }
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
@@ -513,6 +584,42 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Global,
+                    vec![UserPrivilegeType::ApplyRowAccessPolicy],
+                    false,
+                )
+                    .await?;
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Global,
+                    vec![UserPrivilegeType::ApplyRowAccessPolicy],
+                    false,
+                )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -865,21 +972,35 @@ impl AccessChecker for PrivilegeAccess {
             Plan::GrantShareObject(_)
             | Plan::RevokeShareObject(_)
             | Plan::AlterShareTenants(_)
-            | Plan::ShowObjectGrantPrivileges(_)
             | Plan::ShowGrantTenantsOfShare(_)
             | Plan::ShowGrants(_)
             | Plan::GrantRole(_)
-            | Plan::GrantPriv(_)
-            | Plan::RevokePriv(_)
             | Plan::AlterUDF(_)
             | Plan::RevokeRole(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Grant], false)
                     .await?;
             }
+            // The owner of an object could grant the privileges on it, or hand it over.
+            Plan::GrantPriv(plan) => {
+                self.validate_access(&plan.on, vec![UserPrivilegeType::Grant], true)
+                    .await?;
+            }
+            Plan::RevokePriv(plan) => {
+                self.validate_access(&plan.on, vec![UserPrivilegeType::Grant], true)
+                    .await?;
+            }
+            Plan::ShowObjectGrantPrivileges(plan) => {
+                self.validate_access(&plan.object, vec![UserPrivilegeType::Grant], true)
+                    .await?;
+            }
             Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
             }
+            // Users can always change their own passwords.
+            Plan::AlterUser(plan)
+                if plan.user_option.is_none()
+                    && plan.user == self.ctx.get_current_user()?.identity() => {}
             Plan::AlterUser(_)
             | Plan::RenameDatabase(_)
             | Plan::RevertTable(_)
@@ -909,6 +1030,18 @@ impl AccessChecker for PrivilegeAccess {
                 let from = plan.from.clone();
                 return self.check(ctx, &from).await;
             }
+            Plan::CreateStage(plan) => {
+                if let Some(location) = &plan.stage_info.volume_location {
+                    self.validate_access(
+                        &GrantObject::Volume(location.volume.clone()),
+                        vec![UserPrivilegeType::Usage],
+                        false,
+                    )
+                        .await?;
+                }
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
+                    .await?;
+            }
             Plan::RemoveStage(plan) => {
                 self.validate_access_stage(&plan.stage, UserPrivilegeType::Write).await?;
             }
@@ -922,9 +1055,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowCreateCatalog(_)
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
-            | Plan::CreateStage(_)
+            | Plan::AlterStage(_)
             | Plan::DropStage(_)
+            | Plan::ShowCreateStage(_)
             | Plan::CreateFileFormat(_)
+            | Plan::AlterFileFormat(_)
             | Plan::DropFileFormat(_)
             | Plan::ShowFileFormats(_)
             | Plan::CreateNetworkPolicy(_)
@@ -932,6 +1067,17 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
             | Plan::ShowNetworkPolicies(_)
+            | Plan::CreatePasswordPolicy(_)
+            | Plan::AlterPasswordPolicy(_)
+            | Plan::DropPasswordPolicy(_)
+            | Plan::DescPasswordPolicy(_)
+            | Plan::ShowPasswordPolicies(_)
+            | Plan::CreateSettingsProfile(_)
+            | Plan::AlterSettingsProfile(_)
+            | Plan::DropSettingsProfile(_)
+            | Plan::DescSettingsProfile(_)
+            | Plan::ShowSettingsProfiles(_)
+            | Plan::AlterRole(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -945,6 +1091,22 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
             }
+            Plan::CreateExternalVolume(_)
+            | Plan::AlterExternalVolume(_)
+            | Plan::DropExternalVolume(_) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
+                    .await?;
+            }
+            Plan::DescExternalVolume(plan) => {
+                self.validate_access(
+                    &GrantObject::Volume(plan.name.clone()),
+                    vec![UserPrivilegeType::Read],
+                    false,
+                )
+                    .await?;
+            }
+            // The volumes are filtered by the privileges of the current user
+            Plan::ShowExternalVolumes(_) => {}
             Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -953,6 +1115,16 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DescRowAccessPolicy(_) => {
+                self.validate_access(
+                    &GrantObject::Global,
+                    vec![UserPrivilegeType::ApplyRowAccessPolicy],
+                    false,
+                )
+                    .await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
@@ -1003,6 +1175,7 @@ async fn has_priv(
                         ldb == database
                     }
                 }
+                GrantObject::FutureTables(_, ldb) => ldb == database,
                 _ => false,
             }
         }))
@@ -0,0 +1,402 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use common_base::base::GlobalInstance;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::Plan;
+use common_storages_system::AuditLogElement;
+use common_storages_system::AuditLogQueue;
+use log::error;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+
+/// The object changed by a DDL or grant/revoke statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditObject {
+    pub action: &'static str,
+    pub object_type: &'static str,
+    pub object_name: String,
+}
+
+impl AuditObject {
+    fn new(action: &'static str, object_type: &'static str, object_name: String) -> Self {
+        AuditObject {
+            action,
+            object_type,
+            object_name,
+        }
+    }
+
+    /// Returns the object to audit if the plan is a DDL or grant/revoke statement.
+    pub fn from_plan(plan: &Plan) -> Option<AuditObject> {
+        let object = match plan {
+            // Catalog.
+            Plan::CreateCatalog(p) => Self::new("CREATE", "CATALOG", p.catalog.clone()),
+            Plan::DropCatalog(p) => Self::new("DROP", "CATALOG", p.catalog.clone()),
+
+            // Database.
+            Plan::CreateDatabase(p) => Self::new("CREATE", "DATABASE", p.database.clone()),
+            Plan::DropDatabase(p) => Self::new("DROP", "DATABASE", p.database.clone()),
+            Plan::UndropDatabase(p) => Self::new("UNDROP", "DATABASE", p.database.clone()),
+            Plan::RenameDatabase(p) => {
+                let names = p
+                    .entities
+                    .iter()
+                    .map(|e| format!("{} TO {}", e.database, e.new_database))
+                    .collect::<Vec<_>>();
+                Self::new("RENAME", "DATABASE", names.join(", "))
+            }
+
+            // Table.
+            Plan::CreateTable(p) => Self::new("CREATE", "TABLE", table_name(&p.database, &p.table)),
+            Plan::DropTable(p) => Self::new("DROP", "TABLE", table_name(&p.database, &p.table)),
+            Plan::UndropTable(p) => Self::new("UNDROP", "TABLE", table_name(&p.database, &p.table)),
+            Plan::RenameTable(p) => Self::new(
+                "RENAME",
+                "TABLE",
+                format!(
+                    "{} TO {}",
+                    table_name(&p.database, &p.table),
+                    table_name(&p.new_database, &p.new_table)
+                ),
+            ),
+            Plan::TruncateTable(p) => {
+                Self::new("TRUNCATE", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::RenameTableColumn(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::AddTableColumn(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::DropTableColumn(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::ModifyTableColumn(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::AlterTableClusterKey(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::DropTableClusterKey(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::RevertTable(p) => Self::new("ALTER", "TABLE", table_name(&p.database, &p.table)),
+            Plan::SetOptions(p) => Self::new("ALTER", "TABLE", table_name(&p.database, &p.table)),
+            Plan::AddTableRowAccessPolicy(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+            Plan::DropTableRowAccessPolicy(p) => {
+                Self::new("ALTER", "TABLE", table_name(&p.database, &p.table))
+            }
+
+            // View.
+            Plan::CreateView(p) => {
+                Self::new("CREATE", "VIEW", table_name(&p.database, &p.view_name))
+            }
+            Plan::AlterView(p) => Self::new("ALTER", "VIEW", table_name(&p.database, &p.view_name)),
+            Plan::DropView(p) => Self::new("DROP", "VIEW", table_name(&p.database, &p.view_name)),
+
+            // Stream.
+            Plan::CreateStream(p) => {
+                Self::new("CREATE", "STREAM", table_name(&p.database, &p.stream_name))
+            }
+            Plan::DropStream(p) => {
+                Self::new("DROP", "STREAM", table_name(&p.database, &p.stream_name))
+            }
+
+            // Index.
+            Plan::CreateIndex(p) => Self::new("CREATE", "INDEX", p.index_name.clone()),
+            Plan::DropIndex(p) => Self::new("DROP", "INDEX", p.index.clone()),
+            Plan::CreateInvertedIndex(p) => Self::new(
+                "CREATE",
+                "INVERTED INDEX",
+                format!("{} ON {}", p.index_name, table_name(&p.database, &p.table)),
+            ),
+            Plan::DropInvertedIndex(p) => Self::new(
+                "DROP",
+                "INVERTED INDEX",
+                format!("{} ON {}", p.index_name, table_name(&p.database, &p.table)),
+            ),
+
+            // Virtual column.
+            Plan::CreateVirtualColumn(p) => Self::new(
+                "CREATE",
+                "VIRTUAL COLUMN",
+                table_name(&p.database, &p.table),
+            ),
+            Plan::AlterVirtualColumn(p) => {
+                Self::new("ALTER", "VIRTUAL COLUMN", table_name(&p.database, &p.table))
+            }
+            Plan::DropVirtualColumn(p) => {
+                Self::new("DROP", "VIRTUAL COLUMN", table_name(&p.database, &p.table))
+            }
+
+            // Account.
+            Plan::CreateUser(p) => Self::new("CREATE", "USER", p.user.to_string()),
+            Plan::AlterUser(p) => Self::new("ALTER", "USER", p.user.to_string()),
+            Plan::DropUser(p) => Self::new("DROP", "USER", p.user.to_string()),
+            Plan::CreateRole(p) => Self::new("CREATE", "ROLE", p.role_name.clone()),
+            Plan::DropRole(p) => Self::new("DROP", "ROLE", p.role_name.clone()),
+            Plan::AlterRole(p) => Self::new("ALTER", "ROLE", p.role_name.clone()),
+            Plan::GrantRole(p) => {
+                Self::new("GRANT", "ROLE", format!("{} TO{}", p.role, p.principal))
+            }
+            Plan::RevokeRole(p) => {
+                Self::new("REVOKE", "ROLE", format!("{} FROM{}", p.role, p.principal))
+            }
+            Plan::GrantPriv(p) => Self::new(
+                "GRANT",
+                "PRIVILEGE",
+                format!("{} ON {} TO{}", p.priv_types, p.on, p.principal),
+            ),
+            Plan::RevokePriv(p) => Self::new(
+                "REVOKE",
+                "PRIVILEGE",
+                format!("{} ON {} FROM{}", p.priv_types, p.on, p.principal),
+            ),
+
+            // UDF.
+            Plan::CreateUDF(p) => Self::new("CREATE", "FUNCTION", p.udf.name.clone()),
+            Plan::AlterUDF(p) => Self::new("ALTER", "FUNCTION", p.udf.name.clone()),
+            Plan::DropUDF(p) => Self::new("DROP", "FUNCTION", p.udf.clone()),
+
+            // Stage, file format and connection.
+            Plan::CreateStage(p) => Self::new("CREATE", "STAGE", p.stage_info.stage_name.clone()),
+            Plan::AlterStage(p) => Self::new("ALTER", "STAGE", p.name.clone()),
+            Plan::DropStage(p) => Self::new("DROP", "STAGE", p.name.clone()),
+            Plan::CreateFileFormat(p) => Self::new("CREATE", "FILE FORMAT", p.name.clone()),
+            Plan::AlterFileFormat(p) => Self::new("ALTER", "FILE FORMAT", p.name.clone()),
+            Plan::DropFileFormat(p) => Self::new("DROP", "FILE FORMAT", p.name.clone()),
+            Plan::CreateConnection(p) => Self::new("CREATE", "CONNECTION", p.name.clone()),
+            Plan::DropConnection(p) => Self::new("DROP", "CONNECTION", p.name.clone()),
+
+            // Share.
+            Plan::CreateShareEndpoint(p) => {
+                Self::new("CREATE", "SHARE ENDPOINT", p.endpoint.endpoint.clone())
+            }
+            Plan::DropShareEndpoint(p) => Self::new("DROP", "SHARE ENDPOINT", p.endpoint.clone()),
+            Plan::CreateShare(p) => Self::new("CREATE", "SHARE", p.share.clone()),
+            Plan::DropShare(p) => Self::new("DROP", "SHARE", p.share.clone()),
+            Plan::GrantShareObject(p) => Self::new(
+                "GRANT",
+                "SHARE",
+                format!("{} ON {} TO SHARE {}", p.privilege, p.object, p.share),
+            ),
+            Plan::RevokeShareObject(p) => Self::new(
+                "REVOKE",
+                "SHARE",
+                format!("{} ON {} FROM SHARE {}", p.privilege, p.object, p.share),
+            ),
+            Plan::AlterShareTenants(p) => Self::new("ALTER", "SHARE", p.share.clone()),
+
+            // Policy.
+            Plan::CreateDatamaskPolicy(p) => Self::new("CREATE", "MASKING POLICY", p.name.clone()),
+            Plan::DropDatamaskPolicy(p) => Self::new("DROP", "MASKING POLICY", p.name.clone()),
+            Plan::CreateNetworkPolicy(p) => Self::new("CREATE", "NETWORK POLICY", p.name.clone()),
+            Plan::AlterNetworkPolicy(p) => Self::new("ALTER", "NETWORK POLICY", p.name.clone()),
+            Plan::DropNetworkPolicy(p) => Self::new("DROP", "NETWORK POLICY", p.name.clone()),
+            Plan::CreatePasswordPolicy(p) => Self::new("CREATE", "PASSWORD POLICY", p.name.clone()),
+            Plan::AlterPasswordPolicy(p) => Self::new("ALTER", "PASSWORD POLICY", p.name.clone()),
+            Plan::DropPasswordPolicy(p) => Self::new("DROP", "PASSWORD POLICY", p.name.clone()),
+            Plan::CreateSettingsProfile(p) => {
+                Self::new("CREATE", "SETTINGS PROFILE", p.name.clone())
+            }
+            Plan::AlterSettingsProfile(p) => Self::new("ALTER", "SETTINGS PROFILE", p.name.clone()),
+            Plan::DropSettingsProfile(p) => Self::new("DROP", "SETTINGS PROFILE", p.name.clone()),
+            Plan::CreateRowAccessPolicy(p) => {
+                Self::new("CREATE", "ROW ACCESS POLICY", p.name.clone())
+            }
+            Plan::DropRowAccessPolicy(p) => Self::new("DROP", "ROW ACCESS POLICY", p.name.clone()),
+            Plan::CreateExternalVolume(p) => Self::new("CREATE", "EXTERNAL VOLUME", p.name.clone()),
+            Plan::AlterExternalVolume(p) => Self::new("ALTER", "EXTERNAL VOLUME", p.name.clone()),
+            Plan::DropExternalVolume(p) => Self::new("DROP", "EXTERNAL VOLUME", p.name.clone()),
+
+            // Task.
+            Plan::CreateTask(p) => Self::new("CREATE", "TASK", p.task_name.clone()),
+            Plan::AlterTask(p) => Self::new("ALTER", "TASK", p.task_name.clone()),
+            Plan::DropTask(p) => Self::new("DROP", "TASK", p.task_name.clone()),
+
+            _ => return None,
+        };
+        Some(object)
+    }
+}
+
+fn table_name(database: &str, table: &str) -> String {
+    format!("{}.{}", database, table)
+}
+
+/// Destination of the audit events.
+pub trait AuditSink: Send + Sync {
+    fn write(&self, event: &AuditLogElement) -> Result<()>;
+}
+
+/// Keeps the latest events in memory for `system.audit_log`.
+pub struct SystemTableAuditSink;
+
+impl AuditSink for SystemTableAuditSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        AuditLogQueue::instance()?.append_data(event.clone())
+    }
+}
+
+/// Appends the events as json lines to `databend-audit.log` under the directory.
+pub struct FileAuditSink {
+    file: Mutex<File>,
+}
+
+impl FileAuditSink {
+    pub fn create(dir: &str) -> Result<FileAuditSink> {
+        std::fs::create_dir_all(dir)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(Path::new(dir).join("databend-audit.log"))?;
+        Ok(FileAuditSink {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl AuditSink for FileAuditSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+        let mut file = self.file.lock();
+        file.write_all(&line)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditFailurePolicy {
+    /// Only log the error.
+    Warn,
+    /// Report the error to the client.
+    Fail,
+}
+
+impl TryFrom<&str> for AuditFailurePolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "warn" => Ok(AuditFailurePolicy::Warn),
+            "fail" => Ok(AuditFailurePolicy::Fail),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "invalid audit_log_on_failure: {}, must be one of warn, fail",
+                value
+            ))),
+        }
+    }
+}
+
+/// Writes the audit events of the DDL and grant/revoke statements to the sinks.
+///
+/// The object of the statement is attached to the query context by the interpreter factory,
+/// and the event is written when the query finishes.
+pub struct AuditLogger {
+    sinks: Vec<Arc<dyn AuditSink>>,
+    on_failure: AuditFailurePolicy,
+}
+
+impl AuditLogger {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let mut sinks: Vec<Arc<dyn AuditSink>> = vec![Arc::new(SystemTableAuditSink)];
+        if !cfg.query.audit_log_dir.is_empty() {
+            sinks.push(Arc::new(FileAuditSink::create(&cfg.query.audit_log_dir)?));
+        }
+        let on_failure = AuditFailurePolicy::try_from(cfg.query.audit_log_on_failure.as_str())?;
+        GlobalInstance::set(Arc::new(AuditLogger::create(sinks, on_failure)));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AuditLogger> {
+        GlobalInstance::get()
+    }
+
+    pub fn create(sinks: Vec<Arc<dyn AuditSink>>, on_failure: AuditFailurePolicy) -> AuditLogger {
+        AuditLogger { sinks, on_failure }
+    }
+
+    /// Writes the audit event if an object is attached to the query.
+    ///
+    /// Failing to write the event of a successful statement is returned as error with the
+    /// `Fail` policy. The error of a failed statement is always reported instead.
+    pub fn log_query_finished(&self, ctx: &QueryContext, err: Option<&ErrorCode>) -> Result<()> {
+        let object = match ctx.take_audit_object() {
+            Some(object) => object,
+            None => return Ok(()),
+        };
+
+        let res = Self::build_event(ctx, object, err)
+            .and_then(|event| self.sinks.iter().try_for_each(|sink| sink.write(&event)));
+        match res {
+            Ok(_) => Ok(()),
+            Err(e) if self.on_failure == AuditFailurePolicy::Fail && err.is_none() => {
+                error!("fail to write audit log of query {}: {:?}", ctx.get_id(), e);
+                Err(e.add_message_back(" (fail to write audit log)"))
+            }
+            Err(e) => {
+                warn!("fail to write audit log of query {}: {:?}", ctx.get_id(), e);
+                Ok(())
+            }
+        }
+    }
+
+    fn build_event(
+        ctx: &QueryContext,
+        object: AuditObject,
+        err: Option<&ErrorCode>,
+    ) -> Result<AuditLogElement> {
+        let client_address = match ctx.get_client_address() {
+            Some(addr) => format!("{:?}", addr),
+            None => "".to_string(),
+        };
+        let (exception_code, exception_text) = match err {
+            None => (0, "".to_string()),
+            Some(e) => (e.code().into(), e.message()),
+        };
+        Ok(AuditLogElement {
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            tenant_id: ctx.get_tenant(),
+            node_id: ctx.get_cluster().local_id.clone(),
+            query_id: ctx.get_id(),
+            query_tag: ctx.get_query_tag(),
+            sql_user: ctx.get_current_user()?.identity().to_string(),
+            client_address,
+            action: object.action.to_string(),
+            object_type: object.object_type.to_string(),
+            object_name: object.object_name,
+            success: err.is_none(),
+            exception_code,
+            exception_text,
+        })
+    }
+}
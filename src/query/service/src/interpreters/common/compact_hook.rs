@@ -101,6 +101,12 @@ async fn compact_table(
     compact_target: CompactTargetTableDescription,
     need_lock: bool,
 ) -> Result<()> {
+    // the deduplicate label belongs to the statement committed before the compaction,
+    // which must not be committed with it.
+    unsafe {
+        ctx.get_settings().set_deduplicate_label("".to_string())?;
+    }
+
     // build the compact pipeline
     ctx.evict_table_from_cache(
         &compact_target.catalog,
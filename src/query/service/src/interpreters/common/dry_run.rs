@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use common_catalog::plan::Partitions;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_storages_fuse::operations::Mutation;
+use common_storages_fuse::FusePartInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::pipelines::PipelineBuildResult;
+
+/// The report of `EXPLAIN DRYRUN`: what a mutating statement would change, one
+/// `name: value` line per item.
+pub struct DryRunReport {
+    lines: Vec<String>,
+}
+
+impl DryRunReport {
+    pub fn create(statement: &str, table: &str) -> Self {
+        let mut report = DryRunReport { lines: vec![] };
+        report.add("statement", statement);
+        report.add("table", table);
+        report
+    }
+
+    pub fn add(&mut self, name: &str, value: impl Display) {
+        self.lines.push(format!("{name}: {value}"));
+    }
+
+    pub fn add_base_snapshot(&mut self, snapshot_location: Option<&str>) {
+        self.add("base snapshot", snapshot_location.unwrap_or("NULL"));
+    }
+
+    /// Reports a mutation matching nothing, e.g. on an empty table.
+    pub fn add_no_match(&mut self) {
+        self.add_matched(0, 0, 0, 0);
+    }
+
+    /// Reports the blocks of the table matched by a mutation without a filter, or with a
+    /// filter that is always true.
+    pub fn add_whole_table(&mut self, snapshot: &TableSnapshot) {
+        let summary = &snapshot.summary;
+        self.add_matched(
+            summary.block_count,
+            summary.block_count,
+            summary.row_count,
+            0,
+        );
+    }
+
+    /// Reports the partitions left after the mutation pruning.
+    ///
+    /// The rows of the blocks matched as a whole are exact, the other blocks are only
+    /// known to contain some of the affected rows.
+    pub fn add_partitions(&mut self, partitions: &Partitions) -> Result<()> {
+        let mut blocks = 0;
+        let mut whole_blocks = 0;
+        let mut exact_rows = 0;
+        let mut partial_rows = 0;
+        for part in &partitions.partitions {
+            match Mutation::from_part(part)? {
+                Mutation::MutationDeletedSegment(segment) => {
+                    blocks += segment.summary.block_count;
+                    whole_blocks += segment.summary.block_count;
+                    exact_rows += segment.summary.row_count;
+                }
+                Mutation::MutationPartInfo(part) => {
+                    let rows = FusePartInfo::from_part(&part.inner_part)?.nums_rows as u64;
+                    blocks += 1;
+                    if part.whole_block_mutation {
+                        whole_blocks += 1;
+                        exact_rows += rows;
+                    } else {
+                        partial_rows += rows;
+                    }
+                }
+            }
+        }
+        self.add_matched(blocks, whole_blocks, exact_rows, partial_rows);
+        Ok(())
+    }
+
+    fn add_matched(&mut self, blocks: u64, whole_blocks: u64, exact_rows: u64, partial_rows: u64) {
+        self.add("partitions matched", blocks);
+        self.add("partitions matched as a whole", whole_blocks);
+        if partial_rows == 0 {
+            self.add("estimated rows affected", exact_rows);
+        } else {
+            self.add(
+                "estimated rows affected",
+                format!("{} to {}", exact_rows, exact_rows + partial_rows),
+            );
+        }
+    }
+
+    pub fn into_build_result(self) -> Result<PipelineBuildResult> {
+        let lines = self.lines.iter().map(String::as_str).collect::<Vec<_>>();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(lines),
+        ])])
+    }
+}
@@ -45,7 +45,8 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
-        GrantObject::Database(catalog_name, database_name) => {
+        GrantObject::Database(catalog_name, database_name)
+        | GrantObject::FutureTables(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name).await?;
             if !catalog
                 .exists_database(tenant.as_str(), database_name)
@@ -77,6 +78,11 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Volume(volume) => {
+            UserApiProvider::instance()
+                .get_external_volume(tenant.as_str(), volume)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
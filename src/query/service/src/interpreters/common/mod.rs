@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log;
 mod compact_hook;
+mod dry_run;
 mod grant;
 mod metrics;
 mod query_log;
@@ -22,17 +24,25 @@ mod table;
 mod task;
 mod util;
 
+pub use audit_log::AuditLogger;
+pub use audit_log::AuditObject;
 pub use compact_hook::*;
+pub use dry_run::DryRunReport;
 pub use grant::validate_grant_object_exists;
 pub use query_log::InterpreterQueryLog;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
 pub use stream::build_update_stream_meta_seq;
+pub use stream::get_streams_of_database;
+pub use stream::get_streams_of_table;
 pub use table::check_referenced_computed_columns;
 pub use task::get_client_config;
+pub use task::get_tasks_referencing_file_format;
+pub use task::get_tasks_referencing_stage;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
+pub use util::gen_session_result_cache_key;
 
 pub use self::metrics::*;
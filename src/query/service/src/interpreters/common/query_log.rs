@@ -146,6 +146,7 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag: ctx.get_query_tag(),
             event_date,
             event_time,
             query_start_time,
@@ -277,6 +278,7 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag: ctx.get_query_tag(),
             event_date,
             event_time,
             query_start_time,
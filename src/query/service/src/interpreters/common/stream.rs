@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::catalog::Catalog;
 use common_exception::Result;
 use common_license::license::Feature;
 use common_license::license_manager::get_license_manager;
@@ -67,6 +69,63 @@ pub async fn build_update_stream_meta_seq(
     Ok(reqs)
 }
 
+/// Lists the streams created on the table `table_id` in the catalog.
+pub async fn get_streams_of_table(
+    ctx: &Arc<QueryContext>,
+    catalog: &Arc<dyn Catalog>,
+    table_id: u64,
+) -> Result<Vec<Arc<dyn Table>>> {
+    let tenant = ctx.get_tenant();
+    let mut streams = vec![];
+    for database in catalog.list_databases(&tenant).await? {
+        for table in catalog.list_tables(&tenant, database.name()).await? {
+            if table.engine() == STREAM_ENGINE
+                && StreamTable::try_from_table(table.as_ref())?.source_table_id() == table_id
+            {
+                streams.push(table);
+            }
+        }
+    }
+    Ok(streams)
+}
+
+/// Lists the streams in the other databases of the catalog, which are created on the tables of
+/// the database `db_name`.
+pub async fn get_streams_of_database(
+    ctx: &Arc<QueryContext>,
+    catalog: &Arc<dyn Catalog>,
+    db_name: &str,
+) -> Result<Vec<Arc<dyn Table>>> {
+    let tenant = ctx.get_tenant();
+    let table_ids = catalog
+        .list_tables(&tenant, db_name)
+        .await?
+        .iter()
+        .filter(|table| table.engine() != STREAM_ENGINE)
+        .map(|table| table.get_id())
+        .collect::<HashSet<_>>();
+    if table_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut streams = vec![];
+    for database in catalog.list_databases(&tenant).await? {
+        // a database created from share can not contain streams.
+        if database.name() == db_name || database.get_db_info().meta.from_share.is_some() {
+            continue;
+        }
+        for table in catalog.list_tables(&tenant, database.name()).await? {
+            if table.engine() == STREAM_ENGINE
+                && table_ids
+                    .contains(&StreamTable::try_from_table(table.as_ref())?.source_table_id())
+            {
+                streams.push(table);
+            }
+        }
+    }
+    Ok(streams)
+}
+
 fn get_stream_table(metadata: &MetadataRef) -> Result<Vec<Arc<dyn Table>>> {
     let r_lock = metadata.read();
     let tables = r_lock.tables();
@@ -12,15 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::CopyIntoLocationSource;
+use common_ast::ast::CopyIntoLocationStmt;
+use common_ast::ast::CopyIntoTableSource;
+use common_ast::ast::CopyIntoTableStmt;
+use common_ast::ast::FileLocation;
 use common_ast::ast::ScheduleOptions;
+use common_ast::ast::TableReference;
 use common_ast::ast::WarehouseOptions;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_statement;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
 use common_catalog::table_context::TableContext;
 use common_cloud_control::client_config::build_client_config;
 use common_cloud_control::client_config::ClientConfig;
+use common_cloud_control::cloud_api::CloudControlApiProvider;
 use common_cloud_control::pb::schedule_options::ScheduleType;
+use common_cloud_control::pb::ShowTasksRequest;
+use common_cloud_control::task_client::make_request;
+use common_config::GlobalConfig;
 use common_exception::Result;
+use common_users::UserApiProvider;
+use log::warn;
 
 use crate::sessions::QueryContext;
 
@@ -61,3 +80,123 @@ pub fn get_client_config(ctx: Arc<QueryContext>) -> Result<ClientConfig> {
 
     Ok(build_client_config(tenant, user, query_id))
 }
+
+/// The stages and named file formats referenced by the SQL of a task.
+#[derive(Default)]
+struct TaskReferences {
+    stages: HashSet<String>,
+    file_formats: HashSet<String>,
+}
+
+impl TaskReferences {
+    fn add_location(&mut self, location: &FileLocation) {
+        if let FileLocation::Stage(path) = location {
+            let name = path.split('/').next().unwrap_or_default();
+            self.stages.insert(name.to_string());
+        }
+    }
+
+    fn add_file_format(&mut self, file_format: &BTreeMap<String, String>) {
+        if let Some(name) = file_format.get("format_name") {
+            self.file_formats.insert(name.clone());
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for TaskReferences {
+    fn visit_copy_into_table(&mut self, copy: &'ast CopyIntoTableStmt) {
+        self.add_file_format(&copy.file_format);
+        match &copy.src {
+            CopyIntoTableSource::Location(location) => self.add_location(location),
+            CopyIntoTableSource::Query(query) => self.visit_query(query),
+        }
+    }
+
+    fn visit_copy_into_location(&mut self, copy: &'ast CopyIntoLocationStmt) {
+        self.add_file_format(&copy.file_format);
+        self.add_location(&copy.dst);
+        if let CopyIntoLocationSource::Query(query) = &copy.src {
+            self.visit_query(query)
+        }
+    }
+
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Location {
+            location, options, ..
+        } = table
+        {
+            self.add_location(location);
+            if let Some(file_format) = &options.file_format {
+                self.file_formats.insert(file_format.clone());
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
+
+/// Returns the names of the tasks referencing the stage or the named file format in their SQL,
+/// the tasks are only available with cloud control enabled.
+async fn get_referencing_tasks(
+    ctx: Arc<QueryContext>,
+    is_referenced: impl Fn(&TaskReferences) -> bool,
+) -> Result<Vec<String>> {
+    let config = GlobalConfig::instance();
+    if config.query.cloud_control_grpc_server_address.is_none() {
+        return Ok(vec![]);
+    }
+
+    // List the tasks owned by all the roles of the tenant, not only the ones available to the
+    // current user.
+    let tenant = ctx.get_tenant();
+    let roles = UserApiProvider::instance().get_roles(&tenant).await?;
+    let req = ShowTasksRequest {
+        tenant_id: tenant.clone(),
+        name_like: "".to_string(),
+        result_limit: 10000,
+        owners: roles
+            .into_iter()
+            .map(|x| x.identity().to_string())
+            .collect(),
+        task_ids: vec![],
+    };
+    let task_client = CloudControlApiProvider::instance().get_task_client();
+    let resp = task_client
+        .show_tasks(make_request(req, get_client_config(ctx.clone())?))
+        .await?;
+
+    let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+    let mut tasks = vec![];
+    for task in resp.tasks {
+        let tokens = tokenize_sql(&task.query_text)?;
+        let stmt = match parse_sql(&tokens, sql_dialect) {
+            Ok((stmt, _)) => stmt,
+            Err(e) => {
+                warn!("failed to parse the sql of task {}: {}", task.task_name, e);
+                continue;
+            }
+        };
+        let mut references = TaskReferences::default();
+        walk_statement(&mut references, &stmt);
+        if is_referenced(&references) {
+            tasks.push(task.task_name);
+        }
+    }
+    Ok(tasks)
+}
+
+pub async fn get_tasks_referencing_stage(
+    ctx: Arc<QueryContext>,
+    stage_name: &str,
+) -> Result<Vec<String>> {
+    get_referencing_tasks(ctx, |references| references.stages.contains(stage_name)).await
+}
+
+pub async fn get_tasks_referencing_file_format(
+    ctx: Arc<QueryContext>,
+    format_name: &str,
+) -> Result<Vec<String>> {
+    get_referencing_tasks(ctx, |references| {
+        references.file_formats.contains(format_name)
+    })
+    .await
+}
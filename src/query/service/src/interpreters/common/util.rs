@@ -20,6 +20,8 @@ use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_kvapi::kvapi::KVApi;
+use common_storages_fuse::operations::DeduplicatedResult;
+use common_storages_result_cache::gen_result_cache_key;
 use common_users::UserApiProvider;
 
 use crate::sql::executor::cast_expr_to_non_null_boolean;
@@ -34,6 +36,8 @@ use crate::sql::ScalarExpr;
 /// # Returns
 ///
 /// Returns a `Result` containing a `bool` indicating whether specific duplicate label exists (`true`) or not (`false`).
+///
+/// If the label exists, the result of the statement committed with it is reported in `ctx`.
 pub async fn check_deduplicate_label(ctx: Arc<dyn TableContext>) -> Result<bool> {
     match unsafe { ctx.get_settings().get_deduplicate_label()? } {
         None => Ok(false),
@@ -42,7 +46,12 @@ pub async fn check_deduplicate_label(ctx: Arc<dyn TableContext>) -> Result<bool>
             let raw = kv_store.get_kv(&deduplicate_label).await?;
             match raw {
                 None => Ok(false),
-                Some(_) => Ok(true),
+                Some(seq_v) => {
+                    if let Some(result) = DeduplicatedResult::from_bytes(&seq_v.data) {
+                        result.apply_to(ctx.as_ref());
+                    }
+                    Ok(true)
+                }
             }
         }
     }
@@ -66,3 +75,33 @@ pub fn create_push_down_filters(scalar: &ScalarExpr) -> Result<Filters> {
         inverted_filter: remote_inverted_filter,
     })
 }
+
+/// Generates the result cache key of a query in the current session.
+///
+/// Besides the query text, the key takes the user and the roles in effect of the session,
+/// which the result may depend on through the role functions(`current_role()`,
+/// `is_role_in_session()`, ...) and the row access policies. So the cached results are
+/// never shared across users or roles, and changing the roles of the session misses the cache.
+pub async fn gen_session_result_cache_key(
+    ctx: &dyn TableContext,
+    formatted_ast: &str,
+) -> Result<String> {
+    let user = ctx.get_current_user()?;
+    let current_role = ctx.get_current_role().map(|r| r.name).unwrap_or_default();
+    let mut roles = ctx
+        .get_all_effective_roles()
+        .await?
+        .into_iter()
+        .map(|r| r.name)
+        .collect::<Vec<_>>();
+    roles.sort();
+
+    let raw = format!(
+        "{}\n{}\n{}\n{}",
+        formatted_ast,
+        user.identity(),
+        current_role,
+        roles.join(",")
+    );
+    Ok(gen_result_cache_key(&raw))
+}
@@ -21,7 +21,9 @@ use common_exception::Result;
 use common_expression::SendableDataBlockStream;
 use common_pipeline_core::SourcePipeBuilder;
 use log::error;
+use minitrace::prelude::*;
 
+use crate::interpreters::AuditLogger;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -46,25 +48,32 @@ pub trait Interpreter: Sync + Send {
     #[minitrace::trace]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
         ctx.set_status_info("building pipeline");
+        // The tag may be changed by the SET_VAR hints of the query.
+        let query_tag = ctx.get_settings().get_query_tag()?;
+        if !query_tag.is_empty() {
+            let tag = query_tag.clone();
+            LocalSpan::add_property(|| ("query_tag", tag));
+        }
+        ctx.set_query_tag(query_tag);
         InterpreterMetrics::record_query_start(&ctx);
         log_query_start(&ctx);
 
         if let Err(err) = ctx.check_aborting() {
-            log_query_finished(&ctx, Some(err.clone()));
+            log_query_finished(&ctx, Some(err.clone()))?;
             return Err(err);
         }
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
                 InterpreterMetrics::record_query_error(&ctx);
-                log_query_finished(&ctx, Some(build_error.clone()));
+                log_query_finished(&ctx, Some(build_error.clone()))?;
                 return Err(build_error);
             }
         };
 
         if build_res.main_pipeline.is_empty() {
             InterpreterMetrics::record_query_finished(&ctx, None);
-            log_query_finished(&ctx, None);
+            log_query_finished(&ctx, None)?;
 
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
@@ -72,7 +81,7 @@ pub trait Interpreter: Sync + Send {
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
-            log_query_finished(&query_ctx, may_error.clone());
+            log_query_finished(&query_ctx, may_error.clone())?;
 
             match may_error {
                 None => Ok(()),
@@ -137,7 +146,10 @@ fn log_query_start(ctx: &QueryContext) {
     }
 }
 
-fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
+/// Writes the query log and the audit log of the finished query.
+///
+/// Only failing to write the audit log is returned, see [`AuditLogger::log_query_finished`].
+fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) -> Result<()> {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
@@ -146,7 +158,9 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
         SessionManager::instance().status.write().query_finish(now)
     }
 
-    if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
+    if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error.clone()) {
         error!("interpreter.finish.error: {:?}", error)
     }
+
+    AuditLogger::instance().log_query_finished(ctx, error.as_ref())
 }
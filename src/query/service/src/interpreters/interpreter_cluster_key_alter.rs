@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_sql::plans::AlterTableClusterKeyPlan;
+use common_storages_fuse::FuseTable;
+use log::warn;
 
 use super::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -51,6 +54,25 @@ impl Interpreter for AlterTableClusterKeyInterpreter {
 
         let cluster_key_str = format!("({})", plan.cluster_keys.join(", "));
 
+        // Evaluate the keys over a sample of the blocks, the keys destroying the order of
+        // their input cluster the blocks poorly.
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            let analysis = fuse_table
+                .analyze_cluster_key(self.ctx.clone(), &cluster_key_str)
+                .await?;
+            let strict = self
+                .ctx
+                .get_settings()
+                .get_enable_strict_cluster_key_check()?;
+            for warning in analysis.warnings {
+                if strict {
+                    return Err(ErrorCode::InvalidClusterKeys(warning));
+                }
+                warn!("alter cluster key of table {}: {}", plan.table, warning);
+                self.ctx.push_warning(warning);
+            }
+        }
+
         table
             .alter_table_cluster_keys(self.ctx.clone(), cluster_key_str)
             .await?;
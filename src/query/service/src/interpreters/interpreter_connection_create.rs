@@ -54,7 +54,8 @@ impl Interpreter for CreateConnectionInterpreter {
             &plan.name,
             plan.storage_type.clone(),
             plan.storage_params.clone(),
-        );
+        )
+        .with_comment(plan.comment.clone());
 
         let tenant = self.ctx.get_tenant();
         let _create_file_format = user_mgr
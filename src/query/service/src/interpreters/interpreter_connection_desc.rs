@@ -56,6 +56,7 @@ impl Interpreter for DescConnectionInterpreter {
 
         let names = vec![connection.name.as_bytes().to_vec()];
         let types = vec![connection.storage_type.as_bytes().to_vec()];
+        let comments = vec![connection.comment.as_bytes().to_vec()];
         let conn = Connection::new(connection.storage_params).mask();
         connection.storage_params = conn.conns;
         let params = vec![connection.storage_params_display().as_bytes().to_vec()];
@@ -64,6 +65,7 @@ impl Interpreter for DescConnectionInterpreter {
             StringType::from_data(names),
             StringType::from_data(types),
             StringType::from_data(params),
+            StringType::from_data(comments),
         ])])
     }
 }
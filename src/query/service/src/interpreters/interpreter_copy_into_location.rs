@@ -18,9 +18,14 @@ use common_catalog::plan::StageTableInfo;
 use common_catalog::table::AppendMode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::SendableDataBlockStream;
 use common_meta_app::principal::StageInfo;
 use common_storage::StageFilesInfo;
 use common_storages_stage::StageTable;
@@ -35,6 +40,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyIntoLocationPlan;
 use crate::sql::plans::Plan;
+use crate::stream::DataBlockStream;
 
 pub struct CopyIntoLocationInterpreter {
     ctx: Arc<QueryContext>,
@@ -108,6 +114,8 @@ impl CopyIntoLocationInterpreter {
             },
             files_to_copy: None,
             is_select: false,
+            collect_all_errors: false,
+            file_name_template: self.plan.file_name_template.clone(),
             default_values: None,
         };
         let to_table = StageTable::try_create(stage_table_info)?;
@@ -146,4 +154,34 @@ impl Interpreter for CopyIntoLocationInterpreter {
         )
         .await
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        if self.plan.file_name_template.is_none() {
+            return Ok(Box::pin(DataBlockStream::create(None, vec![])));
+        }
+
+        let mut unloaded_files = self
+            .ctx
+            .get_copy_status()
+            .unloaded_files
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        unloaded_files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut files = Vec::with_capacity(unloaded_files.len());
+        let mut rows = Vec::with_capacity(unloaded_files.len());
+        let mut sizes = Vec::with_capacity(unloaded_files.len());
+        for (path, info) in unloaded_files {
+            files.push(path.as_bytes().to_vec());
+            rows.push(info.num_rows as u64);
+            sizes.push(info.size as u64);
+        }
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(files),
+            UInt64Type::from_data(rows),
+            UInt64Type::from_data(sizes),
+        ])];
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
 }
@@ -19,6 +19,7 @@ use common_catalog::plan::StageTableInfo;
 use common_exception::Result;
 use common_expression::types::Int32Type;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
 use common_expression::DataField;
@@ -26,6 +27,7 @@ use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
 use common_expression::SendableDataBlockStream;
+use common_meta_app::principal::OnErrorMode;
 use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::Pipeline;
 use common_sql::executor::physical_plans::CopyIntoTable;
@@ -36,6 +38,7 @@ use common_sql::executor::physical_plans::QuerySource;
 use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use common_sql::executor::PhysicalPlan;
 use common_storage::StageFileInfo;
+use common_storages_fuse::FuseTable;
 use common_storages_stage::StageTable;
 use log::debug;
 use log::info;
@@ -46,6 +49,7 @@ use crate::interpreters::common::hook_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::DryRunReport;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
@@ -56,17 +60,70 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyIntoTablePlan;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 use crate::stream::DataBlockStream;
 
 pub struct CopyIntoTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: CopyIntoTablePlan,
+    dry_run: bool,
 }
 
 impl CopyIntoTableInterpreter {
     /// Create a CopyInterpreter with context and [`CopyIntoTablePlan`].
     pub fn try_create(ctx: Arc<QueryContext>, plan: CopyIntoTablePlan) -> Result<Self> {
-        Ok(CopyIntoTableInterpreter { ctx, plan })
+        Ok(CopyIntoTableInterpreter {
+            ctx,
+            plan,
+            dry_run: false,
+        })
+    }
+
+    /// Only reports the files that would be loaded or skipped, see `EXPLAIN DRYRUN`.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Resolves the files to copy like `build_physical_plan` does, without building
+    /// the loading pipeline.
+    #[async_backtrace::framed]
+    async fn dry_run(&self) -> Result<DryRunReport> {
+        let plan = &self.plan;
+        let mut report = DryRunReport::create(
+            "COPY",
+            &format!("{}.{}", plan.database_name, plan.table_name),
+        );
+
+        let to_table = self
+            .ctx
+            .get_table(
+                plan.catalog_info.catalog_name(),
+                &plan.database_name,
+                &plan.table_name,
+            )
+            .await?;
+        let snapshot_location = match FuseTable::try_from_table(to_table.as_ref()) {
+            Ok(fuse_table) => fuse_table.snapshot_loc().await?,
+            Err(_) => None,
+        };
+        report.add_base_snapshot(snapshot_location.as_deref());
+
+        let (files, duplicated_files) =
+            plan.collect_files_and_duplicated(self.ctx.as_ref()).await?;
+        report.add("files to load", files.len());
+        report.add(
+            "bytes to load",
+            files.iter().map(|file| file.size).sum::<u64>(),
+        );
+        report.add("files skipped", duplicated_files.len());
+        for file in &files {
+            report.add("load", &file.path);
+        }
+        for file in &duplicated_files {
+            report.add("skip", &file.path);
+        }
+        Ok(report)
     }
 
     #[async_backtrace::framed]
@@ -131,7 +188,13 @@ impl CopyIntoTableInterpreter {
                 &plan.table_name,
             )
             .await?;
-        let files = plan.collect_files(self.ctx.as_ref()).await?;
+        let (files, duplicated_files) =
+            plan.collect_files_and_duplicated(self.ctx.as_ref()).await?;
+        if plan.detailed_result {
+            let copy_status = self.ctx.get_copy_status();
+            copy_status.add_files_to_copy(&files);
+            copy_status.add_duplicated_files(&duplicated_files);
+        }
         let mut seq = vec![];
         let source = if let Some(ref query) = plan.query {
             let (select_interpreter, query_source_schema, update_stream_meta) =
@@ -187,6 +250,7 @@ impl CopyIntoTableInterpreter {
                 keys: Vec::new(),
                 allow_adjust_parallelism: true,
                 ignore_exchange: false,
+                skew: None,
             });
         }
         Ok((root, files, seq))
@@ -225,6 +289,10 @@ impl CopyIntoTableInterpreter {
     }
 
     fn get_copy_into_table_result(&self) -> Result<Vec<DataBlock>> {
+        if self.plan.detailed_result {
+            return self.get_copy_into_table_detailed_result();
+        }
+
         let return_all = !self
             .plan
             .stage_table_info
@@ -269,6 +337,128 @@ impl CopyIntoTableInterpreter {
         Ok(blocks)
     }
 
+    fn get_copy_into_table_detailed_result(&self) -> Result<Vec<DataBlock>> {
+        let copy_options = &self.plan.stage_table_info.stage_info.copy_options;
+        let return_all = !copy_options.return_failed_only;
+        let cs = self.ctx.get_copy_status();
+
+        let mut paths = cs
+            .files_to_copy
+            .iter()
+            .map(|entry| entry.key().clone())
+            .chain(cs.files.iter().map(|entry| entry.key().clone()))
+            .chain(cs.duplicated_files.iter().map(|entry| entry.key().clone()))
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+
+        let n = paths.len();
+        let mut files = Vec::with_capacity(n);
+        let mut file_sizes = Vec::with_capacity(n);
+        let mut rows_parsed = Vec::with_capacity(n);
+        let mut rows_loaded = Vec::with_capacity(n);
+        let mut errors_seen = Vec::with_capacity(n);
+        let mut first_error = Vec::with_capacity(n);
+        let mut first_error_line = Vec::with_capacity(n);
+        let mut statuses = Vec::with_capacity(n);
+
+        for path in paths {
+            let size = cs
+                .files_to_copy
+                .get(&path)
+                .or_else(|| cs.duplicated_files.get(&path))
+                .map_or(0, |size| *size);
+            let status = cs
+                .files
+                .get(&path)
+                .map(|status| status.value().clone())
+                .unwrap_or_default();
+            let status_name = if cs.duplicated_files.contains_key(&path)
+                && !cs.files_to_copy.contains_key(&path)
+            {
+                "skipped_dedup"
+            } else {
+                match &status.error {
+                    None => "loaded",
+                    Some(error) => match copy_options.on_error {
+                        OnErrorMode::SkipFileNum(n) if error.num_errors as u64 >= n => "skipped",
+                        _ if status.num_rows_loaded > 0 => "partially_loaded",
+                        _ => "failed",
+                    },
+                }
+            };
+            if !return_all && status.error.is_none() {
+                continue;
+            }
+
+            files.push(path.as_bytes().to_vec());
+            file_sizes.push(size);
+            rows_parsed.push(status.num_rows_parsed() as i32);
+            rows_loaded.push(status.num_rows_loaded as i32);
+            match &status.error {
+                Some(err) => {
+                    errors_seen.push(err.num_errors as i32);
+                    first_error.push(Some(err.first_error.error.to_string().as_bytes().to_vec()));
+                    first_error_line.push(Some(err.first_error.line as i32 + 1));
+                }
+                None => {
+                    errors_seen.push(0);
+                    first_error.push(None);
+                    first_error_line.push(None);
+                }
+            }
+            statuses.push(status_name.as_bytes().to_vec());
+        }
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(files),
+            UInt64Type::from_data(file_sizes),
+            Int32Type::from_data(rows_parsed),
+            Int32Type::from_data(rows_loaded),
+            Int32Type::from_data(errors_seen),
+            StringType::from_opt_data(first_error),
+            Int32Type::from_opt_data(first_error_line),
+            StringType::from_data(statuses),
+        ])];
+        Ok(blocks)
+    }
+
+    fn get_copy_into_table_validation_errors(&self) -> Result<Vec<DataBlock>> {
+        let cs = self.ctx.get_copy_status();
+
+        let mut file_statuses = cs
+            .files
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        file_statuses.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut files = vec![];
+        let mut lines = vec![];
+        let mut columns = vec![];
+        let mut errors = vec![];
+        for (path, mut status) in file_statuses {
+            status.errors.sort_by_key(|error| error.line);
+            for error in status.errors {
+                files.push(path.as_bytes().to_vec());
+                lines.push(error.line as i32 + 1);
+                columns.push(
+                    error
+                        .error
+                        .column_name()
+                        .map(|name| name.as_bytes().to_vec()),
+                );
+                errors.push(error.error.to_string().as_bytes().to_vec());
+            }
+        }
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(files),
+            Int32Type::from_data(lines),
+            StringType::from_opt_data(columns),
+            StringType::from_data(errors),
+        ])];
+        Ok(blocks)
+    }
+
     /// Build commit insertion pipeline.
     async fn commit_insertion(
         &self,
@@ -334,6 +524,10 @@ impl Interpreter for CopyIntoTableInterpreter {
 
         let start = Instant::now();
 
+        if self.dry_run {
+            return self.dry_run().await?.into_build_result();
+        }
+
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
@@ -347,6 +541,11 @@ impl Interpreter for CopyIntoTableInterpreter {
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan, false)
                 .await?;
 
+        // Validation is a dry run, nothing is committed and the files are not marked as copied.
+        if self.plan.validation_mode != ValidationMode::None {
+            return Ok(build_res);
+        }
+
         // Build commit insertion pipeline.
         {
             self.commit_insertion(
@@ -403,6 +602,8 @@ impl Interpreter for CopyIntoTableInterpreter {
     fn inject_result(&self) -> Result<SendableDataBlockStream> {
         let blocks = if self.plan.no_file_to_copy {
             vec![DataBlock::empty_with_schema(self.plan.schema())]
+        } else if self.plan.validation_mode != ValidationMode::None {
+            self.get_copy_into_table_validation_errors()?
         } else {
             self.get_copy_into_table_result()?
         };
@@ -14,13 +14,23 @@
 
 use std::sync::Arc;
 
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::database::Database;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_management::RoleApi;
+use common_meta_api::ShareApi;
 use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::share::ShowSharesReq;
 use common_sql::plans::DropDatabasePlan;
+use common_storages_fuse::operations::TenantStorageUsage;
 use common_storages_share::save_share_spec;
 use common_users::UserApiProvider;
 
+use crate::interpreters::common::get_streams_of_database;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -35,6 +45,39 @@ impl DropDatabaseInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: DropDatabasePlan) -> Result<Self> {
         Ok(DropDatabaseInterpreter { ctx, plan })
     }
+
+    /// Returns the objects which would be left dangling by dropping the database: the shares
+    /// granted the database, and the streams in the other databases on its tables.
+    #[async_backtrace::framed]
+    async fn get_dependents(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        db: &dyn Database,
+    ) -> Result<(Vec<String>, Vec<Arc<dyn Table>>)> {
+        // shares and streams only live in the default catalog, and a database created from
+        // share can not be shared or streamed again.
+        if self.plan.catalog != CATALOG_DEFAULT || db.get_db_info().meta.from_share.is_some() {
+            return Ok((vec![], vec![]));
+        }
+
+        let mut shares = vec![];
+        if !db.get_db_info().meta.shared_by.is_empty() {
+            let meta_api = UserApiProvider::instance().get_meta_store_client();
+            let resp = meta_api
+                .show_shares(ShowSharesReq {
+                    tenant: self.ctx.get_tenant(),
+                })
+                .await?;
+            for entry in resp.outbound_accounts {
+                if entry.database_name.as_deref() == Some(db.name()) {
+                    shares.push(entry.share_name.share_name);
+                }
+            }
+        }
+
+        let streams = get_streams_of_database(&self.ctx, catalog, db.name()).await?;
+        Ok((shares, streams))
+    }
 }
 
 #[async_trait::async_trait]
@@ -52,16 +95,47 @@ impl Interpreter for DropDatabaseInterpreter {
         // unset the ownership of the database, the database may not exists.
         let db = catalog.get_database(&tenant, &self.plan.database).await;
         if let Ok(db) = db {
+            let (shares, streams) = self.get_dependents(&catalog, db.as_ref()).await?;
+            if !self.plan.cascade && (!shares.is_empty() || !streams.is_empty()) {
+                let mut dependents = shares
+                    .iter()
+                    .map(|share| format!("share '{share}'"))
+                    .collect::<Vec<_>>();
+                dependents.extend(
+                    streams
+                        .iter()
+                        .map(|stream| format!("stream {}", stream.get_table_info().desc)),
+                );
+                return Err(ErrorCode::DatabaseHasDependents(format!(
+                    "Can not drop database '{}', it is depended on by {}, use CASCADE to drop them together",
+                    self.plan.database,
+                    dependents.join(", ")
+                )));
+            }
+
             role_api
                 .drop_ownership(&GrantObjectByID::Database {
                     catalog_name: self.plan.catalog.clone(),
                     db_id: db.get_db_info().ident.db_id,
                 })
                 .await?;
+
+            // With CASCADE, the streams are dropped before the database, and the database is
+            // revoked from the shares on dropping it.
+            for stream in streams {
+                catalog
+                    .drop_table_by_id(DropTableByIdReq {
+                        if_exists: true,
+                        tenant: tenant.clone(),
+                        tb_id: stream.get_id(),
+                    })
+                    .await?;
+            }
         }
 
         // actual drop database
         let resp = catalog.drop_database(self.plan.clone().into()).await?;
+        TenantStorageUsage::instance().invalidate(&tenant);
 
         // handle share cleanups with the DropDatabaseReply
         if let Some(spec_vec) = resp.spec_vec {
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::UndropDatabasePlan;
+use common_storages_fuse::operations::TenantStorageUsage;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -44,6 +45,7 @@ impl Interpreter for UndropDatabaseInterpreter {
         let catalog_name = self.plan.catalog.as_str();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         catalog.undrop_database(self.plan.clone().into()).await?;
+        TenantStorageUsage::instance().invalidate(&self.plan.tenant);
         Ok(PipelineBuildResult::create())
     }
 }
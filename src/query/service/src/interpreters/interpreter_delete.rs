@@ -62,6 +62,7 @@ use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::common::create_push_down_filters;
+use crate::interpreters::common::DryRunReport;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -78,12 +79,23 @@ use crate::stream::PullingExecutorStream;
 pub struct DeleteInterpreter {
     ctx: Arc<QueryContext>,
     plan: DeletePlan,
+    dry_run: bool,
 }
 
 impl DeleteInterpreter {
     /// Create the DeleteInterpreter from DeletePlan
     pub fn try_create(ctx: Arc<QueryContext>, plan: DeletePlan) -> Result<Self> {
-        Ok(DeleteInterpreter { ctx, plan })
+        Ok(DeleteInterpreter {
+            ctx,
+            plan,
+            dry_run: false,
+        })
+    }
+
+    /// Only reports the blocks and rows that would be deleted, see `EXPLAIN DRYRUN`.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 }
 
@@ -108,17 +120,20 @@ impl Interpreter for DeleteInterpreter {
         let db_name = self.plan.database_name.as_str();
         let tbl_name = self.plan.table_name.as_str();
 
-        // refresh table.
-        let tbl = catalog
-            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
-            .await?;
+        // use the table of the query, the subqueries read the same snapshot of it, the
+        // changes committed since then are reconciled with the mutation on commit.
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
 
         // check mutability
         tbl.check_mutable()?;
 
-        // Add table lock.
-        let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
-        let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
+        // Add table lock, a dry run does not need it as nothing is written.
+        let lock_guard = if self.dry_run {
+            None
+        } else {
+            let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
+            table_lock.try_lock(self.ctx.clone()).await?
+        };
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.support_row_id_column();
@@ -194,8 +209,15 @@ impl Interpreter for DeleteInterpreter {
             ))
         })?;
 
-        let mut build_res = PipelineBuildResult::create();
         let query_row_id_col = !self.plan.subquery_desc.is_empty();
+        if self.dry_run {
+            return self
+                .dry_run(fuse_table, filters, col_indices, query_row_id_col)
+                .await?
+                .into_build_result();
+        }
+
+        let mut build_res = PipelineBuildResult::create();
         if let Some(snapshot) = fuse_table
             .fast_delete(
                 self.ctx.clone(),
@@ -206,7 +228,8 @@ impl Interpreter for DeleteInterpreter {
             .await?
         {
             let cluster = self.ctx.get_cluster();
-            let is_lazy = !cluster.is_empty() && snapshot.segments.len() >= cluster.nodes.len();
+            let is_lazy = !cluster.is_empty()
+                && snapshot.segments.len() >= cluster.get_executor_nodes().len();
             let partitions = fuse_table
                 .mutation_read_partitions(
                     self.ctx.clone(),
@@ -243,6 +266,59 @@ impl Interpreter for DeleteInterpreter {
 }
 
 impl DeleteInterpreter {
+    /// Prunes the blocks like `fast_delete` and `mutation_read_partitions` do, without
+    /// truncating the table or building the deletion pipeline.
+    async fn dry_run(
+        &self,
+        fuse_table: &FuseTable,
+        filters: Option<Filters>,
+        col_indices: Vec<usize>,
+        query_row_id_col: bool,
+    ) -> Result<DryRunReport> {
+        let mut report = DryRunReport::create(
+            "DELETE",
+            &format!("{}.{}", self.plan.database_name, self.plan.table_name),
+        );
+        report.add_base_snapshot(fuse_table.snapshot_loc().await?.as_deref());
+
+        let snapshot = match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) if snapshot.summary.row_count > 0 => snapshot,
+            _ => {
+                report.add_no_match();
+                return Ok(report);
+            }
+        };
+
+        let whole_table = match &filters {
+            None => true,
+            Some(filters) => {
+                col_indices.is_empty()
+                    && !query_row_id_col
+                    && fuse_table.try_eval_const(
+                        self.ctx.clone(),
+                        &fuse_table.schema(),
+                        &filters.filter,
+                    )?
+            }
+        };
+        if whole_table {
+            report.add_whole_table(&snapshot);
+        } else {
+            let partitions = fuse_table
+                .mutation_read_partitions(
+                    self.ctx.clone(),
+                    snapshot,
+                    col_indices,
+                    filters,
+                    false,
+                    true,
+                )
+                .await?;
+            report.add_partitions(&partitions)?;
+        }
+        Ok(report)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn build_physical_plan(
         filters: Filters,
@@ -273,6 +349,7 @@ impl DeleteInterpreter {
                 keys: vec![],
                 allow_adjust_parallelism: true,
                 ignore_exchange: false,
+                skew: None,
             });
         }
 
@@ -29,16 +29,20 @@ use common_sql::optimizer::ColumnSet;
 use common_sql::BindContext;
 use common_sql::InsertInputSource;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_users::UserApiProvider;
 
+use super::DeleteInterpreter;
 use super::InterpreterFactory;
+use super::UpdateInterpreter;
+use crate::interpreters::common::gen_session_result_cache_key;
+use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
@@ -156,6 +160,27 @@ impl Interpreter for ExplainInterpreter {
                 ))?,
             },
 
+            ExplainKind::AnalyzeFragments => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ignore_result,
+                    ..
+                } => {
+                    self.explain_analyze_fragments(
+                        s_expr,
+                        metadata,
+                        bind_context.column_set(),
+                        *ignore_result,
+                    )
+                    .await?
+                }
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN ANALYZE FRAGMENTS statement",
+                ))?,
+            },
+
             ExplainKind::Pipeline => {
                 // todo:(JackTan25), we need to make all execute2() just do `build pipeline` work,
                 // don't take real actions. for now we fix #13657 like below.
@@ -196,6 +221,8 @@ impl Interpreter for ExplainInterpreter {
                 ));
             }
 
+            ExplainKind::DryRun => return self.explain_dry_run().await,
+
             ExplainKind::Ast(display_string)
             | ExplainKind::Syntax(display_string)
             | ExplainKind::Memo(display_string) => {
@@ -214,6 +241,30 @@ impl ExplainInterpreter {
         Ok(ExplainInterpreter { ctx, plan, kind })
     }
 
+    /// The mutation interpreters plan and prune in dry run mode, and return the report
+    /// instead of the mutation pipeline.
+    #[async_backtrace::framed]
+    async fn explain_dry_run(&self) -> Result<PipelineBuildResult> {
+        let ctx = self.ctx.clone();
+        let interpreter: Arc<dyn Interpreter> = match &self.plan {
+            Plan::Delete(plan) => {
+                Arc::new(DeleteInterpreter::try_create(ctx, *plan.clone())?.with_dry_run(true))
+            }
+            Plan::Update(plan) => {
+                Arc::new(UpdateInterpreter::try_create(ctx, *plan.clone())?.with_dry_run(true))
+            }
+            Plan::CopyIntoTable(plan) => Arc::new(
+                CopyIntoTableInterpreter::try_create(ctx, *plan.clone())?.with_dry_run(true),
+            ),
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "EXPLAIN DRYRUN only supports UPDATE, DELETE and COPY INTO <table>",
+                ));
+            }
+        };
+        interpreter.execute2().await
+    }
+
     pub fn explain_plan(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
         let result = plan.format_indent()?;
         let line_split_result: Vec<&str> = result.lines().collect();
@@ -228,7 +279,9 @@ impl ExplainInterpreter {
         formatted_ast: &Option<String>,
     ) -> Result<Vec<DataBlock>> {
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(formatted_ast.as_ref().unwrap());
+            let key =
+                gen_session_result_cache_key(self.ctx.as_ref(), formatted_ast.as_ref().unwrap())
+                    .await?;
             let kv_store = UserApiProvider::instance().get_meta_store_client();
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
@@ -332,31 +385,15 @@ impl ExplainInterpreter {
     ) -> Result<Vec<DataBlock>> {
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
         let plan = builder.build(s_expr, required).await?;
-        let mut build_res = build_query_pipeline(&self.ctx, &[], &plan, ignore_result).await?;
+        let build_res = build_query_pipeline(&self.ctx, &[], &plan, ignore_result).await?;
 
         let prof_span_set = build_res.prof_span_set.clone();
-
-        let settings = self.ctx.get_settings();
         let query_id = self.ctx.get_id();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id.clone())?;
-
-        // Drain the data
-        if build_res.main_pipeline.is_complete_pipeline()? {
-            let mut pipelines = build_res.sources_pipelines;
-            pipelines.push(build_res.main_pipeline);
-
-            let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-            complete_executor.execute()?;
-        } else {
-            let mut pulling_executor =
-                PipelinePullingExecutor::from_pipelines(build_res, settings)?;
-            pulling_executor.start();
-            while (pulling_executor.pull_data()?).is_some() {}
-        }
+        self.drain_pipeline(build_res)?;
 
         let profile = ProfileHelper::build_query_profile(
             &query_id,
+            &self.ctx.get_query_tag(),
             metadata,
             &plan,
             &prof_span_set.lock().unwrap(),
@@ -374,6 +411,84 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    #[async_backtrace::framed]
+    async fn explain_analyze_fragments(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        required: ColumnSet,
+        ignore_result: bool,
+    ) -> Result<Vec<DataBlock>> {
+        let ctx = self.ctx.clone();
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone(), true);
+        let plan = builder.build(s_expr, required).await?;
+
+        if !plan.is_distributed_plan() {
+            let build_res = build_query_pipeline(&ctx, &[], &plan, ignore_result).await?;
+            self.drain_pipeline(build_res)?;
+
+            let result = plan
+                .format(metadata.clone(), SharedProcessorProfiles::default())?
+                .format_pretty()?;
+            let mut lines: Vec<&str> = result.lines().collect();
+            lines.extend(["", "Exchange edges: none, the query is executed locally"]);
+            let formatted_plan = StringType::from_data(lines);
+            return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+        }
+
+        // Build the fragments here instead of `build_query_pipeline`, the fragment ids are
+        // allocated from the context, so the displayed ones must be the executed ones.
+        let root_fragment = Fragmenter::try_create(ctx.clone())?.build_fragment(&plan)?;
+        let mut fragments_actions = QueryFragmentsActions::create(ctx.clone(), false);
+        root_fragment.get_actions(ctx.clone(), &mut fragments_actions)?;
+        let display_string = fragments_actions.display_indent(metadata).to_string();
+
+        let mut build_res = ctx
+            .get_exchange_manager()
+            .commit_actions(ctx.clone(), false, fragments_actions)
+            .await?;
+        PipelineBuilder::build_result_projection(
+            &ctx.get_function_context()?,
+            plan.output_schema()?,
+            &[],
+            &mut build_res.main_pipeline,
+            ignore_result,
+        )?;
+        self.drain_pipeline(build_res)?;
+
+        let mut lines: Vec<String> = display_string.lines().map(str::to_string).collect();
+        lines.push("Exchange edges:".to_string());
+        lines.extend(
+            ctx.get_exchange_metrics()
+                .format_edges()
+                .into_iter()
+                .map(|line| format!("  {}", line)),
+        );
+        let formatted_plan = StringType::from_data(lines);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
+    /// Executes the pipeline and discards the result.
+    fn drain_pipeline(&self, mut build_res: PipelineBuildResult) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+
+        if build_res.main_pipeline.is_complete_pipeline()? {
+            let mut pipelines = build_res.sources_pipelines;
+            pipelines.push(build_res.main_pipeline);
+
+            let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+            complete_executor.execute()?;
+        } else {
+            let mut pulling_executor =
+                PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+            pulling_executor.start();
+            while (pulling_executor.pull_data()?).is_some() {}
+        }
+        Ok(())
+    }
+
     async fn explain_query(
         &self,
         s_expr: &SExpr,
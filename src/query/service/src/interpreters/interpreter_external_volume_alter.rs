@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::AlterExternalVolumePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterExternalVolumeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterExternalVolumePlan,
+}
+
+impl AlterExternalVolumeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterExternalVolumePlan) -> Result<Self> {
+        Ok(AlterExternalVolumeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterExternalVolumeInterpreter {
+    fn name(&self) -> &str {
+        "AlterExternalVolumeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_external_volume_execute");
+
+        // The stages and tables on the volume pick up the new storage params the next time
+        // they are loaded.
+        let plan = self.plan.clone();
+        UserApiProvider::instance()
+            .update_external_volume_with(&plan.tenant, &plan.name, |volume| {
+                volume.storage_params = plan.storage_params;
+                Ok(())
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::DescExternalVolumePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescExternalVolumeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescExternalVolumePlan,
+}
+
+impl DescExternalVolumeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescExternalVolumePlan) -> Result<Self> {
+        Ok(DescExternalVolumeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescExternalVolumeInterpreter {
+    fn name(&self) -> &str {
+        "DescExternalVolumeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "desc_external_volume_execute");
+
+        let volume = UserApiProvider::instance()
+            .get_external_volume(&self.plan.tenant, &self.plan.name)
+            .await?;
+
+        // The display of storage params never contains the credentials.
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![volume.name.into_bytes()]),
+            StringType::from_data(vec![volume.storage_params.to_string().into_bytes()]),
+            StringType::from_data(vec![volume.comment.into_bytes()]),
+            TimestampType::from_data(vec![volume.created_on.timestamp_micros()]),
+        ])])
+    }
+}
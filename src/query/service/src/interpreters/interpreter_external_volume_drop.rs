@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DropExternalVolumePlan;
+use common_users::UserApiProvider;
+use log::debug;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropExternalVolumeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropExternalVolumePlan,
+}
+
+impl DropExternalVolumeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropExternalVolumePlan) -> Result<Self> {
+        Ok(DropExternalVolumeInterpreter { ctx, plan })
+    }
+
+    /// Returns the stages and tables on the volume, which would be left without storage by
+    /// dropping it.
+    #[async_backtrace::framed]
+    async fn get_dependents(&self) -> Result<Vec<String>> {
+        let tenant = &self.plan.tenant;
+        let mut dependents = vec![];
+        for stage in UserApiProvider::instance().get_stages(tenant).await? {
+            if let Some(location) = &stage.volume_location {
+                if location.volume == self.plan.name {
+                    dependents.push(format!("stage '{}'", stage.stage_name));
+                }
+            }
+        }
+
+        // tables on volumes only live in the default catalog.
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        for database in catalog.list_databases(tenant).await? {
+            for table in database.list_tables().await? {
+                if table.options().get(OPT_KEY_EXTERNAL_VOLUME) == Some(&self.plan.name) {
+                    dependents.push(format!("table {}", table.get_table_info().desc));
+                }
+            }
+        }
+        Ok(dependents)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropExternalVolumeInterpreter {
+    fn name(&self) -> &str {
+        "DropExternalVolumeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_external_volume_execute");
+
+        let plan = self.plan.clone();
+        let dependents = self.get_dependents().await?;
+        if !dependents.is_empty() {
+            return Err(ErrorCode::ExternalVolumeHasDependents(format!(
+                "Can not drop external volume '{}', it is used by {}",
+                plan.name,
+                dependents.join(", ")
+            )));
+        }
+
+        UserApiProvider::instance()
+            .drop_external_volume(&plan.tenant, &plan.name, plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
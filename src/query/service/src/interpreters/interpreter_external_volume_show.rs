@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::ShowExternalVolumesPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowExternalVolumesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowExternalVolumesPlan,
+}
+
+impl ShowExternalVolumesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowExternalVolumesPlan) -> Result<Self> {
+        Ok(ShowExternalVolumesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowExternalVolumesInterpreter {
+    fn name(&self) -> &str {
+        "ShowExternalVolumesInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "show_external_volumes_execute");
+
+        let mut volumes = UserApiProvider::instance()
+            .get_external_volumes(&self.plan.tenant)
+            .await?;
+
+        // Only the volumes on which the current user has any privilege are listed.
+        let visibility_checker = self.ctx.get_visibility_checker().await?;
+        volumes.retain(|volume| visibility_checker.check_volume_visibility(&volume.name));
+        volumes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names = volumes
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let storage_params = volumes
+            .iter()
+            .map(|x| x.storage_params.to_string().into_bytes())
+            .collect::<Vec<_>>();
+        let comments = volumes
+            .iter()
+            .map(|x| x.comment.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let created_ons = volumes
+            .iter()
+            .map(|x| x.created_on.timestamp_micros())
+            .collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(storage_params),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+        ])])
+    }
+}
@@ -35,6 +35,12 @@ use crate::interpreters::interpreter_connection_drop::DropConnectionInterpreter;
 use crate::interpreters::interpreter_connection_show::ShowConnectionsInterpreter;
 use crate::interpreters::interpreter_copy_into_location::CopyIntoLocationInterpreter;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::interpreter_external_volume_alter::AlterExternalVolumeInterpreter;
+use crate::interpreters::interpreter_external_volume_create::CreateExternalVolumeInterpreter;
+use crate::interpreters::interpreter_external_volume_desc::DescExternalVolumeInterpreter;
+use crate::interpreters::interpreter_external_volume_drop::DropExternalVolumeInterpreter;
+use crate::interpreters::interpreter_external_volume_show::ShowExternalVolumesInterpreter;
+use crate::interpreters::interpreter_file_format_alter::AlterFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
@@ -49,6 +55,8 @@ use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
 use crate::interpreters::interpreter_task_execute::ExecuteTaskInterpreter;
 use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
 use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::AuditLogger;
+use crate::interpreters::AuditObject;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
 use crate::interpreters::CreateStreamInterpreter;
@@ -68,12 +76,18 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // DDL and grant/revoke statements are audited when they finish.
+        if let Some(object) = AuditObject::from_plan(plan) {
+            ctx.set_audit_object(object);
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
-        access_checker.check(plan).await.map_err(|e| {
+        if let Err(e) = access_checker.check(plan).await {
             error!("Access.denied(v2): {:?}", e);
-            e
-        })?;
+            AuditLogger::instance().log_query_finished(&ctx, Some(&e))?;
+            return Err(e);
+        }
         Self::get_inner(ctx, plan)
     }
 
@@ -193,6 +207,12 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -262,6 +282,15 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::RefreshInvertedIndex(index) => Ok(Arc::new(
+                RefreshInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -314,6 +343,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -336,6 +369,14 @@ impl InterpreterFactory {
                 ctx,
                 *s.clone(),
             )?)),
+            Plan::AlterStage(s) => Ok(Arc::new(AlterUserStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
+            Plan::ShowCreateStage(s) => Ok(Arc::new(ShowCreateStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
 
             // FileFormats
             Plan::CreateFileFormat(create_file_format) => Ok(Arc::new(
@@ -344,6 +385,9 @@ impl InterpreterFactory {
             Plan::DropFileFormat(drop_file_format) => Ok(Arc::new(
                 DropFileFormatInterpreter::try_create(ctx, *drop_file_format.clone())?,
             )),
+            Plan::AlterFileFormat(alter_file_format) => Ok(Arc::new(
+                AlterFileFormatInterpreter::try_create(ctx, *alter_file_format.clone())?,
+            )),
             Plan::ShowFileFormats(_) => Ok(Arc::new(ShowFileFormatsInterpreter::try_create(ctx)?)),
 
             // Grant
@@ -472,6 +516,68 @@ impl InterpreterFactory {
                 Ok(Arc::new(ShowNetworkPoliciesInterpreter::try_create(ctx)?))
             }
 
+            Plan::CreatePasswordPolicy(p) => Ok(Arc::new(
+                CreatePasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterPasswordPolicy(p) => Ok(Arc::new(
+                AlterPasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropPasswordPolicy(p) => Ok(Arc::new(DropPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescPasswordPolicy(p) => Ok(Arc::new(DescPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowPasswordPolicies(_) => {
+                Ok(Arc::new(ShowPasswordPoliciesInterpreter::try_create(ctx)?))
+            }
+
+            Plan::CreateSettingsProfile(p) => Ok(Arc::new(
+                CreateSettingsProfileInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterSettingsProfile(p) => Ok(Arc::new(
+                AlterSettingsProfileInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropSettingsProfile(p) => Ok(Arc::new(
+                DropSettingsProfileInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescSettingsProfile(p) => Ok(Arc::new(
+                DescSettingsProfileInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::ShowSettingsProfiles(_) => {
+                Ok(Arc::new(ShowSettingsProfilesInterpreter::try_create(ctx)?))
+            }
+
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
+            Plan::CreateExternalVolume(p) => Ok(Arc::new(
+                CreateExternalVolumeInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterExternalVolume(p) => Ok(Arc::new(
+                AlterExternalVolumeInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropExternalVolume(p) => Ok(Arc::new(DropExternalVolumeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescExternalVolume(p) => Ok(Arc::new(DescExternalVolumeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowExternalVolumes(p) => Ok(Arc::new(
+                ShowExternalVolumesInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::AlterFileFormatAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::AlterFileFormatPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::get_tasks_referencing_file_format;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterFileFormatInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterFileFormatPlan,
+}
+
+impl AlterFileFormatInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterFileFormatPlan) -> Result<Self> {
+        Ok(AlterFileFormatInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_file_format(&self, tenant: &str, new_name: &str) -> Result<()> {
+        let plan = &self.plan;
+
+        // The stages copy the options of a named file format when they are created, so only
+        // the tasks may still refer to the file format by its name.
+        let tasks = get_tasks_referencing_file_format(self.ctx.clone(), &plan.name).await?;
+        if !tasks.is_empty() {
+            return Err(ErrorCode::FileFormatHasDependents(format!(
+                "file format {} is referenced by tasks: {}",
+                plan.name,
+                tasks.join(", ")
+            )));
+        }
+
+        UserApiProvider::instance()
+            .rename_file_format(tenant, &plan.name, new_name)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterFileFormatInterpreter {
+    fn name(&self) -> &str {
+        "AlterFileFormatInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_file_format_execute");
+
+        let plan = &self.plan;
+        let tenant = self.ctx.get_tenant();
+        let res = match &plan.action {
+            AlterFileFormatAction::RenameFileFormat { new_name } => {
+                self.rename_file_format(&tenant, new_name).await
+            }
+            AlterFileFormatAction::SetComment { comment } => UserApiProvider::instance()
+                .update_file_format_with(&tenant, &plan.name, |file_format| {
+                    file_format.comment = comment.clone()
+                })
+                .await
+                .map(|_| ()),
+        };
+
+        match res {
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_FILE_FORMAT => {}
+            res => res?,
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
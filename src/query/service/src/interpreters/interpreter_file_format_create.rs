@@ -54,7 +54,8 @@ impl Interpreter for CreateFileFormatInterpreter {
             &plan.name,
             plan.file_format_params.clone(),
             self.ctx.get_current_user()?.identity(),
-        );
+        )
+        .with_comment(plan.comment.clone());
 
         let tenant = self.ctx.get_tenant();
         let _create_file_format = user_mgr
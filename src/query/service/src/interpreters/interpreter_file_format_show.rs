@@ -64,9 +64,15 @@ impl Interpreter for ShowFileFormatsInterpreter {
             .map(|x| x.file_format_params.to_string().as_bytes().to_vec())
             .collect::<Vec<_>>();
 
+        let comments = formats
+            .iter()
+            .map(|x| x.comment.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
             StringType::from_data(names),
             StringType::from_data(options),
+            StringType::from_data(comments),
         ])])
     }
 }
@@ -14,13 +14,18 @@
 
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
 use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::executor::physical_plans::DistributedInsertSelect;
 use common_sql::executor::PhysicalPlan;
@@ -37,12 +42,16 @@ use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
+use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::pipelines::ValueSource;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::write_buffer::AppendOptions;
+use crate::write_buffer::TableKey;
+use crate::write_buffer::WriteBufferSink;
 
 pub struct InsertInterpreter {
     ctx: Arc<QueryContext>,
@@ -71,6 +80,64 @@ impl InsertInterpreter {
         let cast_needed = select_schema != DataSchema::from(output_schema.as_ref()).into();
         Ok(cast_needed)
     }
+
+    // The rows of the statements with a deduplicate label are not buffered, the label
+    // must be committed with the rows.
+    fn use_write_buffer(&self, table: &dyn Table) -> Result<bool> {
+        let settings = self.ctx.get_settings();
+        Ok(settings.get_enable_write_buffer()?
+            && !self.plan.overwrite
+            && table.engine() == "FUSE"
+            && unsafe { settings.get_deduplicate_label()? }.is_none())
+    }
+
+    fn build_write_buffer_sink(
+        &self,
+        table: Arc<dyn Table>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        let source_schema = self.plan.schema();
+        let table_default_schema = table.schema().remove_computed_fields();
+        let default_schema: DataSchemaRef = Arc::new((&table_default_schema).into());
+
+        // The rows are buffered with all the columns, so that the rows of the statements
+        // inserting different columns are committed together.
+        if source_schema != default_schema {
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformResortAddOn::try_create(
+                    self.ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    source_schema.clone(),
+                    default_schema.clone(),
+                    table.clone(),
+                )
+            })?;
+        }
+
+        let key = TableKey {
+            tenant: self.ctx.get_tenant(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+        };
+        let options = AppendOptions {
+            durability: settings.get_write_buffer_durability()?,
+            flush_interval: Duration::from_millis(settings.get_write_buffer_flush_interval_ms()?),
+            thresholds: table.get_block_thresholds(self.ctx.clone()),
+        };
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(WriteBufferSink::create(
+                self.ctx.clone(),
+                input,
+                key.clone(),
+                table.get_id(),
+                table_default_schema.clone(),
+                options,
+            )))
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -116,6 +183,11 @@ impl Interpreter for InsertInterpreter {
                     },
                     1,
                 )?;
+
+                if self.use_write_buffer(table.as_ref())? {
+                    self.build_write_buffer_sink(table, &mut build_res.main_pipeline)?;
+                    return Ok(build_res);
+                }
             }
             InsertInputSource::StreamingWithFormat(format, _, input_context) => {
                 let input_context = input_context.as_ref().expect("must success").clone();
@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::RefreshInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshInvertedIndexPlan,
+}
+
+impl RefreshInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshInvertedIndexPlan) -> Result<Self> {
+        Ok(RefreshInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let Some(index) = table
+            .get_table_info()
+            .meta
+            .indexes
+            .get(&self.plan.index_name)
+        else {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "inverted index `{}` does not exist on table {}.{}",
+                self.plan.index_name, self.plan.database, self.plan.table
+            )));
+        };
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table
+            .refresh_inverted_index(self.ctx.clone(), index, self.plan.limit)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -88,6 +88,37 @@ impl KillInterpreter {
         )))
     }
 
+    // The process listed by `system.processes` can be killed by the combined id
+    // `<node>:<id>`, which is routed to the node directly.
+    #[async_backtrace::framed]
+    async fn kill_node_query(
+        &self,
+        node_id: &str,
+        session_id: &str,
+    ) -> Result<PipelineBuildResult> {
+        let cluster = self.ctx.get_cluster();
+        if node_id == cluster.local_id {
+            return self.execute_kill(&session_id.to_string()).await;
+        }
+
+        let node_info = cluster
+            .nodes
+            .iter()
+            .find(|node| node.id == node_id)
+            .ok_or_else(|| ErrorCode::UnknownSession(format!("Not found node id {}", node_id)))?;
+
+        let settings = self.ctx.get_settings();
+        let timeout = settings.get_flight_client_timeout()?;
+        let conf = GlobalConfig::instance();
+        let kill_query_packet = KillQueryPacket::create(
+            session_id.to_string(),
+            self.plan.kill_connection,
+            node_info.clone(),
+        );
+        kill_query_packet.commit(conf.as_ref(), timeout).await?;
+        Ok(PipelineBuildResult::create())
+    }
+
     #[async_backtrace::framed]
     async fn execute_kill(&self, session_id: &String) -> Result<PipelineBuildResult> {
         match self.ctx.get_session_by_id(session_id) {
@@ -135,7 +166,12 @@ impl Interpreter for KillInterpreter {
                     mysql_conn_id
                 ))),
             },
-            Err(_) => self.execute_kill(id).await,
+            Err(_) => match id.rsplit_once(':') {
+                Some((node_id, session_id)) if self.proxy_to_cluster => {
+                    self.kill_node_query(node_id, session_id).await
+                }
+                _ => self.execute_kill(id).await,
+            },
         }
     }
 }
@@ -412,6 +412,7 @@ impl MergeIntoInterpreter {
                     keys: vec![],
                     allow_adjust_parallelism: true,
                     ignore_exchange: false,
+                    skew: None,
                 })),
                 table_info: table_info.clone(),
                 catalog_info: catalog_.info(),
@@ -298,6 +298,7 @@ impl MergeIntoInterpreter {
             RelOperator::Pattern(_) => {}
             RelOperator::AddRowNumber(_) => {}
             RelOperator::Udf(_) => {}
+            RelOperator::Unnest(_) => {}
         }
         Ok(())
     }
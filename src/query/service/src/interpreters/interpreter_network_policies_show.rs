@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_expression::types::StringType;
 use common_expression::DataBlock;
 use common_expression::FromData;
+use common_users::display_network_policy_ip_list;
 use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
@@ -54,8 +55,10 @@ impl Interpreter for ShowNetworkPoliciesInterpreter {
         let mut comments = Vec::with_capacity(network_policies.len());
         for network_policy in network_policies {
             names.push(network_policy.name.as_bytes().to_vec());
-            allowed_ip_lists.push(network_policy.allowed_ip_list.join(",").as_bytes().to_vec());
-            blocked_ip_lists.push(network_policy.blocked_ip_list.join(",").as_bytes().to_vec());
+            allowed_ip_lists
+                .push(display_network_policy_ip_list(&network_policy.allowed_ip_list).into_bytes());
+            blocked_ip_lists
+                .push(display_network_policy_ip_list(&network_policy.blocked_ip_list).into_bytes());
             comments.push(network_policy.comment.as_bytes().to_vec());
         }
 
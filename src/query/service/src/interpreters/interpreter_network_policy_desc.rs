@@ -19,6 +19,7 @@ use common_expression::types::StringType;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_sql::plans::DescNetworkPolicyPlan;
+use common_users::display_network_policy_ip_list;
 use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
@@ -54,8 +55,10 @@ impl Interpreter for DescNetworkPolicyInterpreter {
             .await?;
 
         let names = vec![network_policy.name.as_bytes().to_vec()];
-        let allowed_ip_lists = vec![network_policy.allowed_ip_list.join(",").as_bytes().to_vec()];
-        let blocked_ip_lists = vec![network_policy.blocked_ip_list.join(",").as_bytes().to_vec()];
+        let allowed_ip_lists =
+            vec![display_network_policy_ip_list(&network_policy.allowed_ip_list).into_bytes()];
+        let blocked_ip_lists =
+            vec![display_network_policy_ip_list(&network_policy.blocked_ip_list).into_bytes()];
         let comments = vec![network_policy.comment.as_bytes().to_vec()];
 
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
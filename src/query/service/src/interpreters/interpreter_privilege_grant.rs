@@ -133,17 +133,56 @@ impl GrantPrivilegeInterpreter {
             }
         }
 
-        // the new owner must be one of the available roles
-        if !available_roles.iter().any(|r| &r.name == role) {
-            return Err(ErrorCode::IllegalGrant(
-                "Illegal GRANT/REVOKE command; invalid new owner",
-            ));
-        }
-
+        // the ownership can be handed over to any existing role, the current session does not
+        // have to own the new role.
         user_mgr
             .grant_ownership_to_role(tenant, &object_by_id, role)
             .await?;
 
+        if self.plan.revoke_current_grants {
+            self.revoke_current_grants(tenant, object).await?;
+        }
+
+        Ok(())
+    }
+
+    // Revoke the privileges granted on the object from all the users and roles, the new owner
+    // is left as the only one who could access it.
+    #[async_backtrace::framed]
+    async fn revoke_current_grants(&self, tenant: &str, object: &GrantObject) -> Result<()> {
+        let user_mgr = UserApiProvider::instance();
+        let privileges = object.available_privileges();
+
+        for role in user_mgr.get_roles(tenant).await? {
+            if role
+                .grants
+                .entries()
+                .iter()
+                .any(|e| e.matches_entry(object))
+            {
+                user_mgr
+                    .revoke_privileges_from_role(tenant, &role.name, object.clone(), privileges)
+                    .await?;
+            }
+        }
+        for user in user_mgr.get_users(tenant).await? {
+            if user
+                .grants
+                .entries()
+                .iter()
+                .any(|e| e.matches_entry(object))
+            {
+                user_mgr
+                    .revoke_privileges_from_user(
+                        tenant,
+                        user.identity(),
+                        object.clone(),
+                        privileges,
+                    )
+                    .await?;
+            }
+        }
+        RoleCacheManager::instance().invalidate_cache(tenant);
         Ok(())
     }
 }
@@ -278,6 +278,7 @@ impl ReplaceInterpreter {
                 keys: vec![],
                 allow_adjust_parallelism: true,
                 ignore_exchange: false,
+                skew: None,
             }));
         }
 
@@ -310,7 +311,7 @@ impl ReplaceInterpreter {
         )));
         root = Box::new(PhysicalPlan::ReplaceInto(Box::new(ReplaceInto {
             input: root,
-            block_thresholds: fuse_table.get_block_thresholds(),
+            block_thresholds: fuse_table.get_block_thresholds(self.ctx.clone()),
             table_info: table_info.clone(),
             catalog_info: catalog.info(),
             on_conflicts,
@@ -332,6 +333,7 @@ impl ReplaceInterpreter {
                 keys: vec![],
                 allow_adjust_parallelism: true,
                 ignore_exchange: false,
+                skew: None,
             }));
         }
         root = Box::new(PhysicalPlan::CommitSink(Box::new(CommitSink {
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_ast::ast::AlterSettingsProfileAction;
+use common_exception::Result;
+use common_sql::plans::AlterSettingsProfilePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterSettingsProfileInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterSettingsProfilePlan,
+}
+
+impl AlterSettingsProfileInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterSettingsProfilePlan) -> Result<Self> {
+        Ok(AlterSettingsProfileInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterSettingsProfileInterpreter {
+    fn name(&self) -> &str {
+        "AlterSettingsProfileInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_settings_profile_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .update_settings_profile(
+                &tenant,
+                &plan.name,
+                |settings_profile| match &plan.action {
+                    AlterSettingsProfileAction::Set { settings, comment } => {
+                        settings_profile.settings.extend(settings.clone());
+                        if let Some(comment) = comment {
+                            settings_profile.comment = comment.clone();
+                        }
+                    }
+                    AlterSettingsProfileAction::Unset { settings, comment } => {
+                        for setting in settings {
+                            settings_profile.settings.remove(setting);
+                        }
+                        if *comment {
+                            settings_profile.comment = "".to_string();
+                        }
+                    }
+                },
+                plan.if_exists,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::DescSettingsProfilePlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescSettingsProfileInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescSettingsProfilePlan,
+}
+
+impl DescSettingsProfileInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescSettingsProfilePlan) -> Result<Self> {
+        Ok(DescSettingsProfileInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescSettingsProfileInterpreter {
+    fn name(&self) -> &str {
+        "DescSettingsProfileInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let settings_profile = user_mgr
+            .get_settings_profile(&tenant, self.plan.name.as_str())
+            .await?;
+
+        let settings = self.ctx.get_settings();
+        let mut names = Vec::with_capacity(settings_profile.settings.len());
+        let mut values = Vec::with_capacity(settings_profile.settings.len());
+        let mut defaults = Vec::with_capacity(settings_profile.settings.len());
+        for (name, value) in settings_profile.settings {
+            // The setting may be removed since the profile is stored.
+            let default = settings
+                .check_and_get_default_value(&name)
+                .map(|v| v.to_string())
+                .unwrap_or_default();
+            names.push(name.into_bytes());
+            values.push(value.into_bytes());
+            defaults.push(default.into_bytes());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+            StringType::from_data(defaults),
+        ])])
+    }
+}
@@ -14,14 +14,26 @@
 
 use std::sync::Arc;
 
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_exception::Result;
+use common_expression::types::BooleanType;
 use common_expression::types::StringType;
+use common_expression::types::TimestampType;
 use common_expression::DataBlock;
 use common_expression::FromData;
+use common_expression::FromOptData;
 use common_meta_api::ShareApi;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::share::GetObjectGrantPrivilegesReq;
+use common_meta_app::share::ShareGrantObjectName;
+use common_users::GrantObjectIndex;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
+use crate::interpreters::common::validate_grant_object_exists;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -37,6 +49,43 @@ impl ShowObjectGrantPrivilegesInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: ShowObjectGrantPrivilegesPlan) -> Result<Self> {
         Ok(ShowObjectGrantPrivilegesInterpreter { ctx, plan })
     }
+
+    #[async_backtrace::framed]
+    async fn get_owner(&self, tenant: &str) -> Result<Option<String>> {
+        let (catalog_name, db_name, table_name) = match &self.plan.object {
+            GrantObject::Database(catalog_name, db_name) => (catalog_name, db_name, None),
+            GrantObject::Table(catalog_name, db_name, table_name) => {
+                (catalog_name, db_name, Some(table_name))
+            }
+            _ => return Ok(None),
+        };
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let db_id = catalog
+            .get_database(tenant, db_name)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let object = match table_name {
+            None => GrantObjectByID::Database {
+                catalog_name: catalog_name.clone(),
+                db_id,
+            },
+            Some(table_name) => GrantObjectByID::Table {
+                catalog_name: catalog_name.clone(),
+                db_id,
+                table_id: catalog
+                    .get_table(tenant, db_name, table_name)
+                    .await?
+                    .get_id(),
+            },
+        };
+        let ownership = UserApiProvider::instance()
+            .get_ownership(tenant, &object)
+            .await?;
+        Ok(ownership.map(|ownership| ownership.role))
+    }
 }
 
 #[async_trait::async_trait]
@@ -47,29 +96,93 @@ impl Interpreter for ShowObjectGrantPrivilegesInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let meta_api = UserApiProvider::instance().get_meta_store_client();
-        let req = GetObjectGrantPrivilegesReq {
-            tenant: self.ctx.get_tenant(),
-            object: self.plan.object.clone(),
-        };
-        let resp = meta_api.get_grant_privileges_of_object(req).await?;
-        if resp.privileges.is_empty() {
-            return Ok(PipelineBuildResult::create());
-        }
-        let mut share_names: Vec<Vec<u8>> = vec![];
+        let tenant = self.ctx.get_tenant();
+        let object = &self.plan.object;
+        validate_grant_object_exists(&self.ctx, object).await?;
+
         let mut privileges: Vec<Vec<u8>> = vec![];
-        let mut created_owns: Vec<Vec<u8>> = vec![];
+        let mut grantee_types: Vec<Vec<u8>> = vec![];
+        let mut grantees: Vec<Vec<u8>> = vec![];
+        let mut grant_options: Vec<bool> = vec![];
+        let mut granted_ats: Vec<Option<i64>> = vec![];
 
-        for privilege in resp.privileges {
-            share_names.push(privilege.share_name.as_bytes().to_vec());
-            privileges.push(privilege.privileges.to_string().as_bytes().to_vec());
-            created_owns.push(privilege.grant_on.to_string().as_bytes().to_vec());
+        if let Some(owner) = self.get_owner(&tenant).await? {
+            privileges.push(UserPrivilegeType::Ownership.to_string().into_bytes());
+            grantee_types.push("ROLE".as_bytes().to_vec());
+            grantees.push(owner.into_bytes());
+            grant_options.push(true);
+            granted_ats.push(None);
         }
 
+        // The role grants are looked up in the index kept by the role cache, while the user
+        // grants are indexed on each call as the users are not cached.
+        let mut object_grants = RoleCacheManager::instance()
+            .find_object_grants(&tenant, object)
+            .await?;
+        let mut user_index = GrantObjectIndex::default();
+        for user in UserApiProvider::instance().get_users(&tenant).await? {
+            user_index.insert(PrincipalIdentity::User(user.identity()), &user.grants);
+        }
+        object_grants.extend(user_index.find(object));
+
+        for grant in object_grants {
+            let (grantee_type, grantee) = match &grant.principal {
+                PrincipalIdentity::Role(role) => ("ROLE", role.clone()),
+                PrincipalIdentity::User(user) => ("USER", user.to_string()),
+            };
+            let grant_option = grant.privileges.contains(UserPrivilegeType::Grant);
+            for privilege in grant.privileges.iter() {
+                privileges.push(privilege.to_string().into_bytes());
+                grantee_types.push(grantee_type.as_bytes().to_vec());
+                grantees.push(grantee.clone().into_bytes());
+                grant_options.push(grant_option);
+                granted_ats.push(None);
+            }
+        }
+
+        let share_object = match object {
+            GrantObject::Database(catalog_name, db_name) if catalog_name == CATALOG_DEFAULT => {
+                Some(ShareGrantObjectName::Database(db_name.clone()))
+            }
+            GrantObject::Table(catalog_name, db_name, table_name)
+                if catalog_name == CATALOG_DEFAULT =>
+            {
+                Some(ShareGrantObjectName::Table(
+                    db_name.clone(),
+                    table_name.clone(),
+                ))
+            }
+            _ => None,
+        };
+        if let Some(share_object) = share_object {
+            let meta_api = UserApiProvider::instance().get_meta_store_client();
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant.clone(),
+                object: share_object,
+            };
+            let resp = meta_api.get_grant_privileges_of_object(req).await?;
+            for share_privilege in resp.privileges {
+                for privilege in share_privilege.privileges.iter() {
+                    privileges.push(privilege.to_string().into_bytes());
+                    grantee_types.push("SHARE".as_bytes().to_vec());
+                    grantees.push(share_privilege.share_name.as_bytes().to_vec());
+                    grant_options.push(false);
+                    granted_ats.push(Some(share_privilege.grant_on.timestamp_micros()));
+                }
+            }
+        }
+
+        if privileges.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+        let granted_bys: Vec<Option<Vec<u8>>> = vec![None; privileges.len()];
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-            StringType::from_data(created_owns),
             StringType::from_data(privileges),
-            StringType::from_data(share_names),
+            StringType::from_data(grantee_types),
+            StringType::from_data(grantees),
+            BooleanType::from_data(grant_options),
+            StringType::from_opt_data(granted_bys),
+            TimestampType::from_opt_data(granted_ats),
         ])])
     }
 }
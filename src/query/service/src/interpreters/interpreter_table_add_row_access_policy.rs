@@ -0,0 +1,142 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableRowAccessPolicy;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_sql::resolve_type_name_by_str;
+use common_storages_share::save_share_table_info;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let table = catalog
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let engine = table_info.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support alter",
+                &self.plan.database, &self.plan.table, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if let Some(row_access_policy) = &table_info.meta.row_access_policy {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "table {}.{} already has row access policy `{}`, drop it first",
+                &self.plan.database, &self.plan.table, row_access_policy.policy
+            )));
+        }
+
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&self.plan.tenant, &self.plan.policy)
+            .await?;
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy `{}` expects {} arguments, but got {} columns",
+                policy.name,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+
+        // The arguments are bound to the columns by position, and must be of the same type.
+        let schema = table.schema();
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(self.plan.columns.iter()) {
+            let field = schema.field_with_name(column)?;
+            let arg_type = resolve_type_name_by_str(arg_type, true)?;
+            if field.data_type().remove_nullable() != arg_type {
+                return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                    "column {} of type {} does not match the argument {} of type {} in row access policy `{}`",
+                    column,
+                    field.data_type(),
+                    arg_name,
+                    arg_type,
+                    policy.name
+                )));
+            }
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.row_access_policy = Some(TableRowAccessPolicy {
+            policy: self.plan.policy.clone(),
+            columns: self.plan.columns.clone(),
+        });
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -25,6 +25,7 @@ use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
+use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_license::license::Feature::ComputedColumn;
 use common_license::license_manager::get_license_manager;
@@ -55,12 +56,15 @@ use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::OPT_KEY_BACKGROUND_COMPACTION;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME_PATH;
 use storages_common_table_meta::table::OPT_KEY_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -317,14 +321,17 @@ impl CreateTableInterpreter {
             } else {
                 Default::default()
             },
+            bucket_spec: self.plan.bucket_spec.clone(),
             ..Default::default()
         };
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_block_size_threshold(&table_meta.options)?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_background_compaction(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -435,6 +442,7 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_BACKGROUND_COMPACTION);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -442,6 +450,8 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
 
     r.insert(OPT_KEY_LOCATION);
     r.insert(OPT_KEY_CONNECTION_NAME);
+    r.insert(OPT_KEY_EXTERNAL_VOLUME);
+    r.insert(OPT_KEY_EXTERNAL_VOLUME_PATH);
 
     r.insert("transient");
     r
@@ -491,12 +501,26 @@ pub fn is_valid_block_per_segment(options: &BTreeMap<String, String>) -> Result<
 }
 
 pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()> {
-    // check row_per_block can not be over 1000000.
+    // check row_per_block is in [1, 1000000].
     if let Some(value) = options.get(FUSE_OPT_KEY_ROW_PER_BLOCK) {
         let row_per_block = value.parse::<u64>()?;
-        let error_str = "invalid row_per_block option, can't be over 1000000";
+        let error_str = "invalid row_per_block option, must be between 1 and 1000000";
 
-        if row_per_block > DEFAULT_BLOCK_MAX_ROWS as u64 {
+        if row_per_block == 0 || row_per_block > DEFAULT_BLOCK_MAX_ROWS as u64 {
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_block_size_threshold(options: &BTreeMap<String, String>) -> Result<()> {
+    // check block_size_threshold is in [1, 100MB].
+    if let Some(value) = options.get(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD) {
+        let block_size_threshold = value.parse::<u64>()?;
+        let error_str = "invalid block_size_threshold option, must be between 1 and 104857600";
+
+        if block_size_threshold == 0 || block_size_threshold > DEFAULT_BLOCK_BUFFER_SIZE as u64 {
             error!("{}", error_str);
             return Err(ErrorCode::TableOptionInvalid(error_str));
         }
@@ -520,3 +544,10 @@ pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()
     }
     Ok(())
 }
+
+pub fn is_valid_background_compaction(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_BACKGROUND_COMPACTION) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
@@ -21,6 +21,7 @@ use common_management::RoleApi;
 use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
+use common_storages_fuse::operations::TenantStorageUsage;
 use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_spec;
 use common_storages_stream::stream_table::STREAM_ENGINE;
@@ -111,6 +112,8 @@ impl Interpreter for DropTableInterpreter {
                     tb_id: tbl.get_table_info().ident.table_id,
                 })
                 .await?;
+            // the dropped table is no longer counted in the storage usage of the tenant
+            TenantStorageUsage::instance().invalidate(&tenant);
 
             // if `plan.all`, truncate, then purge the historical data
             if self.plan.all {
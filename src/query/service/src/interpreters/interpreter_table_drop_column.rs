@@ -93,6 +93,40 @@ impl Interpreter for DropTableColumnInterpreter {
             )?;
         }
 
+        if let Some(row_access_policy) = &table_info.meta.row_access_policy {
+            if row_access_policy.columns.contains(&self.plan.column) {
+                return Err(ErrorCode::ColumnIsUsedByRowAccessPolicy(format!(
+                    "column {} is used by row access policy `{}`, drop the policy first",
+                    self.plan.column, row_access_policy.policy
+                )));
+            }
+        }
+
+        let column_id = table_info.schema().column_id_of(&self.plan.column)?;
+        if let Some(index) = table_info
+            .meta
+            .indexes
+            .values()
+            .find(|index| index.column_ids.contains(&column_id))
+        {
+            return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
+                "column {} is used by inverted index `{}`, drop the index first",
+                self.plan.column, index.name
+            )));
+        }
+
+        if table_info
+            .meta
+            .bucket_spec
+            .as_ref()
+            .is_some_and(|spec| spec.column_ids.contains(&column_id))
+        {
+            return Err(ErrorCode::InvalidBucketSpec(format!(
+                "column {} is a bucket key of table {}, it can not be dropped",
+                self.plan.column, table_info.desc
+            )));
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
@@ -256,6 +256,16 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
+
+                    // Changing the type of a bucket key changes the bucket of its rows.
+                    if table_info.meta.bucket_spec.as_ref().is_some_and(|spec| {
+                        spec.column_ids.contains(&new_schema.fields[i].column_id())
+                    }) {
+                        return Err(ErrorCode::InvalidBucketSpec(format!(
+                            "Can not modify the data type of bucket key {}",
+                            column
+                        )));
+                    }
                     new_schema.fields[i].data_type = data_type.clone();
                     table_info.meta.field_comments[i] = comment.to_string();
                 }
@@ -24,6 +24,9 @@ use common_catalog::table::Table;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
 use common_meta_app::schema::CatalogInfo;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::Pipeline;
@@ -36,10 +39,14 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::plans::OptimizeTableAction;
 use common_sql::plans::OptimizeTablePlan;
 use common_storages_factory::NavigationPoint;
+use common_storages_fuse::io::SnapshotsIO;
+use common_storages_fuse::operations::PurgeStats;
 use common_storages_fuse::FuseTable;
+use common_storages_stream::stream_table::StreamTable;
 use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::get_streams_of_table;
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -89,10 +96,24 @@ impl Interpreter for OptimizeTableInterpreter {
                 self.build_pipeline(catalog, table, CompactTarget::Segments, false)
                     .await
             }
-            OptimizeTableAction::Purge(point) => {
-                purge(ctx, catalog, plan, point).await?;
+            OptimizeTableAction::Purge {
+                before: None,
+                force: _,
+            } => {
+                purge(ctx, catalog, plan, None).await?;
                 Ok(PipelineBuildResult::create())
             }
+            OptimizeTableAction::Purge {
+                before: Some(point),
+                force,
+            } => {
+                let stats = purge_before(ctx, catalog, table, point, force, plan.limit).await?;
+                PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                    UInt64Type::from_data(vec![stats.snapshots as u64]),
+                    UInt64Type::from_data(vec![stats.segments as u64]),
+                    UInt64Type::from_data(vec![stats.blocks as u64]),
+                ])])
+            }
             OptimizeTableAction::All => {
                 self.build_pipeline(catalog, table, CompactTarget::Blocks, true)
                     .await
@@ -126,6 +147,7 @@ impl OptimizeTableInterpreter {
                 keys: vec![],
                 allow_adjust_parallelism: true,
                 ignore_exchange: false,
+                skew: None,
             });
         }
 
@@ -300,3 +322,51 @@ async fn purge(
     assert!(res.is_none());
     Ok(())
 }
+
+/// Purges the history of the table before `point`, regardless of the retention period.
+///
+/// The history still needed by the streams of the table is kept, unless `force` is given.
+async fn purge_before(
+    ctx: Arc<QueryContext>,
+    catalog: Arc<dyn Catalog>,
+    table: Arc<dyn Table>,
+    point: NavigationPoint,
+    force: bool,
+    limit: Option<usize>,
+) -> Result<PurgeStats> {
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let (boundary, snapshot_files) = fuse_table.navigate_for_purge_before(&point).await?;
+    let boundary_snapshot = boundary.read_table_snapshot().await?.ok_or_else(|| {
+        ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+    })?;
+
+    if !force {
+        let table_info = table.get_table_info();
+        for stream in get_streams_of_table(&ctx, &catalog, table_info.ident.table_id).await? {
+            let stream = StreamTable::try_from_table(stream.as_ref())?;
+            let Some(location) = stream.snapshot_loc() else {
+                continue;
+            };
+            let stream_snapshot =
+                match SnapshotsIO::read_snapshot(location, fuse_table.get_operator()).await {
+                    Ok((snapshot, _)) => snapshot,
+                    // the offset of the stream has already been purged.
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                    Err(e) => return Err(e),
+                };
+            if stream_snapshot.timestamp < boundary_snapshot.timestamp {
+                return Err(ErrorCode::HistoryProtectedByStream(format!(
+                    "Can not purge the history of table '{}' before the offset of stream '{}', use FORCE to purge it anyway",
+                    table_info.desc,
+                    stream.get_table_info().desc
+                )));
+            }
+        }
+    }
+
+    let ctx: Arc<dyn TableContext> = ctx;
+    let keep_latest = true;
+    boundary
+        .do_purge_with_stats(&ctx, snapshot_files, limit, keep_latest)
+        .await
+}
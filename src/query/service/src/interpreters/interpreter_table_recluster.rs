@@ -253,6 +253,7 @@ pub fn build_recluster_physical_plan(
             keys: vec![],
             allow_adjust_parallelism: true,
             ignore_exchange: false,
+            skew: None,
         });
     }
 
@@ -103,6 +103,13 @@ impl Interpreter for RenameTableColumnInterpreter {
             }
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
+            if let Some(row_access_policy) = &mut new_table_meta.row_access_policy {
+                for column in row_access_policy.columns.iter_mut() {
+                    if *column == self.plan.old_column {
+                        *column = self.plan.new_column.clone();
+                    }
+                }
+            }
 
             // update table options
             let opts = &mut new_table_meta.options;
@@ -24,9 +24,13 @@ use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME_PATH;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
+use super::interpreter_table_create::is_valid_background_compaction;
 use super::interpreter_table_create::is_valid_block_per_segment;
+use super::interpreter_table_create::is_valid_block_size_threshold;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
@@ -60,7 +64,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check block_size_threshold
+        is_valid_block_size_threshold(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
+        is_valid_background_compaction(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -77,6 +84,15 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        for key in [OPT_KEY_EXTERNAL_VOLUME, OPT_KEY_EXTERNAL_VOLUME_PATH] {
+            if self.plan.set_options.get(key).is_some() {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {} for alter table statement",
+                    key
+                )));
+            }
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -153,6 +153,22 @@ impl ShowCreateTableInterpreter {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
 
+        if let Some(bucket_spec) = &table_info.meta.bucket_spec {
+            let columns = bucket_spec
+                .key_indices(&schema)?
+                .into_iter()
+                .map(|index| format!("`{}`", schema.field(index).name()))
+                .collect::<Vec<_>>();
+            table_create_sql.push_str(
+                format!(
+                    " BUCKET BY ({}) INTO {} BUCKETS",
+                    columns.join(", "),
+                    bucket_spec.num_buckets
+                )
+                .as_str(),
+            );
+        }
+
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
             .get_hide_options_in_show_create_table()
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::table::TableExt;
 use common_exception::Result;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_license::license::Feature::Vacuum;
@@ -24,6 +25,7 @@ use common_license::license_manager::get_license_manager;
 use common_sql::plans::VacuumTablePlan;
 use common_storages_fuse::FuseTable;
 use vacuum_handler::get_vacuum_handler;
+use vacuum_handler::VacuumTableResult;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -75,7 +77,7 @@ impl Interpreter for VacuumTableInterpreter {
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let handler = get_vacuum_handler();
-        let purge_files_opt = handler
+        let result = handler
             .do_vacuum(
                 fuse_table,
                 ctx,
@@ -84,9 +86,8 @@ impl Interpreter for VacuumTableInterpreter {
             )
             .await?;
 
-        match purge_files_opt {
-            None => return Ok(PipelineBuildResult::create()),
-            Some(purge_files) => {
+        match result {
+            VacuumTableResult::DryRun(purge_files) => {
                 let mut files: Vec<Vec<u8>> = Vec::with_capacity(purge_files.len());
                 for file in purge_files.into_iter() {
                     files.push(file.as_bytes().to_vec());
@@ -96,6 +97,28 @@ impl Interpreter for VacuumTableInterpreter {
                     StringType::from_data(files),
                 ])])
             }
+            VacuumTableResult::Purged { deletion, skipped } => {
+                if self
+                    .ctx
+                    .get_settings()
+                    .get_vacuum_fail_on_deletion_error()?
+                {
+                    deletion.check()?;
+                }
+                let failed_samples = deletion
+                    .failed_samples
+                    .iter()
+                    .map(|(path, error)| format!("{path}: {error}"))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+
+                PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                    UInt64Type::from_data(vec![deletion.deleted as u64]),
+                    UInt64Type::from_data(vec![deletion.failed as u64]),
+                    UInt64Type::from_data(vec![skipped as u64]),
+                    StringType::from_data(vec![failed_samples.into_bytes()]),
+                ])])
+            }
         }
     }
 }
@@ -48,6 +48,7 @@ use storages_common_table_meta::meta::TableSnapshot;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::create_push_down_filters;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::DryRunReport;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::interpreter_delete::replace_subquery;
 use crate::interpreters::interpreter_delete::subquery_filter;
@@ -62,12 +63,23 @@ use crate::sql::plans::UpdatePlan;
 pub struct UpdateInterpreter {
     ctx: Arc<QueryContext>,
     plan: UpdatePlan,
+    dry_run: bool,
 }
 
 impl UpdateInterpreter {
     /// Create the UpdateInterpreter from UpdatePlan
     pub fn try_create(ctx: Arc<QueryContext>, plan: UpdatePlan) -> Result<Self> {
-        Ok(UpdateInterpreter { ctx, plan })
+        Ok(UpdateInterpreter {
+            ctx,
+            plan,
+            dry_run: false,
+        })
+    }
+
+    /// Only reports the blocks and rows that would be updated, see `EXPLAIN DRYRUN`.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 }
 
@@ -83,7 +95,7 @@ impl Interpreter for UpdateInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "update_interpreter_execute");
 
-        if check_deduplicate_label(self.ctx.clone()).await? {
+        if !self.dry_run && check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
 
@@ -92,17 +104,20 @@ impl Interpreter for UpdateInterpreter {
         let tbl_name = self.plan.table.as_str();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let catalog_info = catalog.info();
-        // refresh table.
-        let tbl = catalog
-            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
-            .await?;
+        // use the table of the query, the subqueries read the same snapshot of it, the
+        // changes committed since then are reconciled with the mutation on commit.
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
 
         // check mutability
         tbl.check_mutable()?;
 
-        // Add table lock.
-        let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
-        let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
+        // Add table lock, a dry run does not need it as nothing is written.
+        let lock_guard = if self.dry_run {
+            None
+        } else {
+            let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
+            table_lock.try_lock(self.ctx.clone()).await?
+        };
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.support_row_id_column();
@@ -198,8 +213,15 @@ impl Interpreter for UpdateInterpreter {
             ))
         })?;
 
-        let mut build_res = PipelineBuildResult::create();
         let query_row_id_col = !self.plan.subquery_desc.is_empty();
+        if self.dry_run {
+            return self
+                .dry_run(fuse_table, filters, col_indices, query_row_id_col)
+                .await?
+                .into_build_result();
+        }
+
+        let mut build_res = PipelineBuildResult::create();
         if let Some(snapshot) = fuse_table
             .fast_update(
                 self.ctx.clone(),
@@ -259,6 +281,49 @@ impl Interpreter for UpdateInterpreter {
 }
 
 impl UpdateInterpreter {
+    /// Prunes the blocks like `fast_update` and `mutation_read_partitions` do, without
+    /// building the update pipeline.
+    async fn dry_run(
+        &self,
+        fuse_table: &FuseTable,
+        mut filters: Option<Filters>,
+        col_indices: Vec<usize>,
+        query_row_id_col: bool,
+    ) -> Result<DryRunReport> {
+        let mut report = DryRunReport::create(
+            "UPDATE",
+            &format!("{}.{}", self.plan.database, self.plan.table),
+        );
+        report.add_base_snapshot(fuse_table.snapshot_loc().await?.as_deref());
+
+        match fuse_table
+            .fast_update(
+                self.ctx.clone(),
+                &mut filters,
+                col_indices.clone(),
+                query_row_id_col,
+            )
+            .await?
+        {
+            None => report.add_no_match(),
+            Some(snapshot) if filters.is_none() => report.add_whole_table(&snapshot),
+            Some(snapshot) => {
+                let partitions = fuse_table
+                    .mutation_read_partitions(
+                        self.ctx.clone(),
+                        snapshot,
+                        col_indices,
+                        filters,
+                        false,
+                        false,
+                    )
+                    .await?;
+                report.add_partitions(&partitions)?;
+            }
+        }
+        Ok(report)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn build_physical_plan(
         filters: Option<Filters>,
@@ -50,9 +50,15 @@ impl Interpreter for AlterUserInterpreter {
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
         if plan.auth_info.is_some() || plan.user_option.is_some() {
+            let session = self.ctx.get_current_session();
+            let change_own_password =
+                plan.auth_info.is_some() && plan.user == self.ctx.get_current_user()?.identity();
             UserApiProvider::instance()
                 .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
                 .await?;
+            if change_own_password {
+                session.set_must_change_password(false);
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::AlterStageAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::StageType;
+use common_sql::plans::AlterStagePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::get_tasks_referencing_stage;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterUserStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterStagePlan,
+}
+
+impl AlterUserStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterStagePlan) -> Result<Self> {
+        Ok(AlterUserStageInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_stage(&self, new_stage_name: &str) -> Result<()> {
+        let plan = &self.plan;
+        let user_mgr = UserApiProvider::instance();
+
+        // The files of an internal stage are stored under a path derived from its name.
+        let stage = user_mgr.get_stage(&plan.tenant, &plan.name).await?;
+        if !matches!(&stage.stage_type, StageType::External) {
+            return Err(ErrorCode::StagePermissionDenied(format!(
+                "internal stage {} is not allowed to be renamed",
+                plan.name
+            )));
+        }
+
+        let tasks = get_tasks_referencing_stage(self.ctx.clone(), &plan.name).await?;
+        if !tasks.is_empty() {
+            return Err(ErrorCode::StageHasDependents(format!(
+                "stage {} is referenced by tasks: {}",
+                plan.name,
+                tasks.join(", ")
+            )));
+        }
+
+        user_mgr
+            .rename_stage(&plan.tenant, &plan.name, new_stage_name)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterUserStageInterpreter {
+    fn name(&self) -> &str {
+        "AlterUserStageInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_user_stage_execute");
+
+        let plan = &self.plan;
+        let res = match &plan.action {
+            AlterStageAction::RenameStage { new_stage_name } => {
+                self.rename_stage(new_stage_name).await
+            }
+            AlterStageAction::SetComment { comment } => UserApiProvider::instance()
+                .update_stage_with(&plan.tenant, &plan.name, |stage| {
+                    stage.comment = comment.clone()
+                })
+                .await
+                .map(|_| ()),
+        };
+
+        match res {
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_STAGE => {}
+            res => res?,
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
+use common_meta_app::storage::StorageParams;
+use common_sql::plans::ShowCreateStagePlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowCreateStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowCreateStagePlan,
+}
+
+impl ShowCreateStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowCreateStagePlan) -> Result<Self> {
+        Ok(ShowCreateStageInterpreter { ctx, plan })
+    }
+
+    /// The CREATE STAGE statement of the stage, the connection options of an external stage
+    /// are left out as they may carry credentials.
+    fn show_create_stage(stage: &StageInfo) -> String {
+        let mut create_sql = format!("CREATE STAGE {}", stage.stage_name);
+
+        if let StageType::External = stage.stage_type {
+            if let Some(url) = Self::storage_url(&stage.stage_params.storage) {
+                create_sql.push_str(&format!(" URL = '{}'", url));
+            }
+        }
+
+        let file_format_options = stage
+            .file_format_params
+            .to_ast()
+            .options
+            .iter()
+            .map(|(k, v)| format!("{} = '{}'", k, escape_quote(v)))
+            .collect::<Vec<_>>();
+        create_sql.push_str(&format!(
+            " FILE_FORMAT = ({})",
+            file_format_options.join(", ")
+        ));
+
+        let copy_options = &stage.copy_options;
+        if copy_options.on_error != OnErrorMode::default() {
+            create_sql.push_str(&format!(" ON_ERROR = '{}'", copy_options.on_error));
+        }
+        if copy_options.size_limit != 0 {
+            create_sql.push_str(&format!(" SIZE_LIMIT = {}", copy_options.size_limit));
+        }
+
+        if !stage.comment.is_empty() {
+            create_sql.push_str(&format!(" COMMENT = '{}'", escape_quote(&stage.comment)));
+        }
+        create_sql
+    }
+
+    fn storage_url(storage: &StorageParams) -> Option<String> {
+        let url = match storage {
+            StorageParams::S3(v) => format!("s3://{}{}", v.bucket, v.root),
+            StorageParams::Gcs(v) => format!("gcs://{}{}", v.bucket, v.root),
+            StorageParams::Azblob(v) => format!("azblob://{}{}", v.container, v.root),
+            StorageParams::Oss(v) => format!("oss://{}{}", v.bucket, v.root),
+            StorageParams::Obs(v) => format!("obs://{}{}", v.bucket, v.root),
+            StorageParams::Cos(v) => format!("cos://{}{}", v.bucket, v.root),
+            StorageParams::Fs(v) => format!("fs://{}", v.root),
+            StorageParams::Hdfs(v) => format!("hdfs://{}{}", v.name_node, v.root),
+            StorageParams::Ipfs(v) => format!("ipfs://{}", v.root.trim_start_matches('/')),
+            StorageParams::Webhdfs(v) => format!(
+                "webhdfs://{}{}",
+                v.endpoint_url
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://"),
+                v.root
+            ),
+            StorageParams::Http(v) => v.endpoint_url.clone(),
+            _ => return None,
+        };
+        Some(url)
+    }
+}
+
+fn escape_quote(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowCreateStageInterpreter {
+    fn name(&self) -> &str {
+        "ShowCreateStageInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.plan.name == "~" {
+            return Err(ErrorCode::StagePermissionDenied(
+                "user stage is not created by CREATE STAGE",
+            ));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let stage = UserApiProvider::instance()
+            .get_stage(&tenant, &self.plan.name)
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![stage.stage_name.as_bytes().to_vec()]),
+            StringType::from_data(vec![Self::show_create_stage(&stage).into_bytes()]),
+        ])])
+    }
+}
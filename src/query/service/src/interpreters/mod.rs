@@ -37,7 +37,13 @@ mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
 mod interpreter_explain;
+mod interpreter_external_volume_alter;
+mod interpreter_external_volume_create;
+mod interpreter_external_volume_desc;
+mod interpreter_external_volume_drop;
+mod interpreter_external_volume_show;
 mod interpreter_factory;
+mod interpreter_file_format_alter;
 mod interpreter_file_format_create;
 mod interpreter_file_format_drop;
 mod interpreter_file_format_show;
@@ -45,6 +51,9 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
+mod interpreter_inverted_index_refresh;
 mod interpreter_kill;
 mod interpreter_merge_into;
 mod interpreter_merge_into_static_filter;
@@ -54,10 +63,16 @@ mod interpreter_network_policy_alter;
 mod interpreter_network_policy_create;
 mod interpreter_network_policy_desc;
 mod interpreter_network_policy_drop;
+mod interpreter_password_policies_show;
+mod interpreter_password_policy_alter;
+mod interpreter_password_policy_create;
+mod interpreter_password_policy_desc;
+mod interpreter_password_policy_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -65,8 +80,16 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
+mod interpreter_settings_profile_alter;
+mod interpreter_settings_profile_create;
+mod interpreter_settings_profile_desc;
+mod interpreter_settings_profile_drop;
+mod interpreter_settings_profiles_show;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
 mod interpreter_share_desc;
@@ -83,11 +106,13 @@ mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
@@ -112,9 +137,11 @@ mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_user_stage_alter;
 mod interpreter_user_stage_create;
 mod interpreter_user_stage_drop;
 mod interpreter_user_stage_remove;
+mod interpreter_user_stage_show_create;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
@@ -128,6 +155,9 @@ mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
 
 pub use access::ManagementModeAccess;
+pub use common::check_deduplicate_label;
+pub use common::AuditLogger;
+pub use common::AuditObject;
 pub use common::InterpreterQueryLog;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
@@ -147,6 +177,9 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
+pub use interpreter_inverted_index_refresh::RefreshInvertedIndexInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
@@ -154,17 +187,31 @@ pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
 pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
 pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_password_policies_show::ShowPasswordPoliciesInterpreter;
+pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
+pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
+pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
+pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
+pub use interpreter_settings_profile_alter::AlterSettingsProfileInterpreter;
+pub use interpreter_settings_profile_create::CreateSettingsProfileInterpreter;
+pub use interpreter_settings_profile_desc::DescSettingsProfileInterpreter;
+pub use interpreter_settings_profile_drop::DropSettingsProfileInterpreter;
+pub use interpreter_settings_profiles_show::ShowSettingsProfilesInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
 pub use interpreter_share_drop::DropShareInterpreter;
@@ -180,11 +227,13 @@ pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInter
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
@@ -201,9 +250,11 @@ pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
+pub use interpreter_user_stage_alter::AlterUserStageInterpreter;
 pub use interpreter_user_stage_create::CreateUserStageInterpreter;
 pub use interpreter_user_stage_drop::DropUserStageInterpreter;
 pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
+pub use interpreter_user_stage_show_create::ShowCreateStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFInterpreter;
 pub use interpreter_user_udf_create::CreateUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
@@ -55,6 +55,7 @@ pub mod spillers;
 pub mod stream;
 pub mod table_functions;
 pub mod test_kits;
+pub mod write_buffer;
 
 mod global_services;
 
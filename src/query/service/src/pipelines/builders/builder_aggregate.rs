@@ -148,7 +148,8 @@ impl PipelineBuilder {
                         method,
                         input,
                         output,
-                        params.clone()
+                        params.clone(),
+                        aggregate.time_rollup,
                     ),
                 }),
             }?;
@@ -166,43 +167,7 @@ impl PipelineBuilder {
 
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty() {
-            let operator = DataOperator::instance().operator();
-            let location_prefix = query_spill_prefix(&self.ctx.get_tenant());
-            self.main_pipeline.add_transform(|input, output| {
-                let transform = match params.aggregate_functions.is_empty() {
-                    true => with_mappedhash_method!(|T| match method.clone() {
-                        HashMethodKind::T(method) => TransformGroupBySpillWriter::create(
-                            self.ctx.clone(),
-                            input,
-                            output,
-                            method,
-                            operator.clone(),
-                            location_prefix.clone()
-                        ),
-                    }),
-                    false => with_mappedhash_method!(|T| match method.clone() {
-                        HashMethodKind::T(method) => TransformAggregateSpillWriter::create(
-                            self.ctx.clone(),
-                            input,
-                            output,
-                            method,
-                            operator.clone(),
-                            params.clone(),
-                            location_prefix.clone()
-                        ),
-                    }),
-                };
-
-                if self.enable_profiling {
-                    Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
-                        transform,
-                        aggregate.plan_id,
-                        self.proc_profs.clone(),
-                    )))
-                } else {
-                    Ok(ProcessorPtr::create(transform))
-                }
-            })?;
+            self.build_aggregate_spill_writer(&params, method.clone(), aggregate.plan_id)?;
         }
 
         self.exchange_injector = match params.aggregate_functions.is_empty() {
@@ -219,6 +184,51 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_aggregate_spill_writer(
+        &mut self,
+        params: &Arc<AggregatorParams>,
+        method: HashMethodKind,
+        plan_id: u32,
+    ) -> Result<()> {
+        let operator = DataOperator::instance().operator();
+        let location_prefix = query_spill_prefix(&self.ctx.get_tenant());
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = match params.aggregate_functions.is_empty() {
+                true => with_mappedhash_method!(|T| match method.clone() {
+                    HashMethodKind::T(method) => TransformGroupBySpillWriter::create(
+                        self.ctx.clone(),
+                        input,
+                        output,
+                        method,
+                        operator.clone(),
+                        location_prefix.clone()
+                    ),
+                }),
+                false => with_mappedhash_method!(|T| match method.clone() {
+                    HashMethodKind::T(method) => TransformAggregateSpillWriter::create(
+                        self.ctx.clone(),
+                        input,
+                        output,
+                        method,
+                        operator.clone(),
+                        params.clone(),
+                        location_prefix.clone()
+                    ),
+                }),
+            };
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     pub(crate) fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
         let params = Self::build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
@@ -283,6 +293,17 @@ impl PipelineBuilder {
 
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
+                    if let PhysicalPlan::AggregatePartial(partial) = input {
+                        if !self.ctx.get_cluster().is_empty() {
+                            // The partial aggregate is not followed by an exchange, e.g. the input is bucketed by
+                            // the group keys, so the spilled data must be written here.
+                            self.build_aggregate_spill_writer(
+                                &params,
+                                HashMethodKind::T(v.clone()),
+                                partial.plan_id,
+                            )?;
+                        }
+                    }
                     build_partition_bucket::<_, ()>(
                         v,
                         &mut self.main_pipeline,
@@ -305,6 +326,17 @@ impl PipelineBuilder {
                     }
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
+                    if let PhysicalPlan::AggregatePartial(partial) = input {
+                        if !self.ctx.get_cluster().is_empty() {
+                            // The partial aggregate is not followed by an exchange, e.g. the input is bucketed by
+                            // the group keys, so the spilled data must be written here.
+                            self.build_aggregate_spill_writer(
+                                &params,
+                                HashMethodKind::T(v.clone()),
+                                partial.plan_id,
+                            )?;
+                        }
+                    }
                     build_partition_bucket::<_, usize>(
                         v,
                         &mut self.main_pipeline,
@@ -26,10 +26,13 @@ use common_expression::Scalar;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_pipeline_sinks::EmptySink;
 use common_sql::executor::physical_plans::CopyIntoTable;
 use common_sql::executor::physical_plans::CopyIntoTableSource;
 use common_sql::plans::CopyIntoTableMode;
+use common_sql::plans::ValidationMode;
 use common_storage::StageFileInfo;
 use common_storages_stage::StageTable;
 use log::debug;
@@ -37,6 +40,7 @@ use log::info;
 
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
@@ -60,7 +64,7 @@ impl PipelineBuilder {
             }
             CopyIntoTableSource::Stage(source) => {
                 let stage_table = StageTable::try_create(copy.stage_table_info.clone())?;
-                stage_table.set_block_thresholds(to_table.get_block_thresholds());
+                stage_table.set_block_thresholds(to_table.get_block_thresholds(self.ctx.clone()));
                 stage_table.read_data(self.ctx.clone(), source, &mut self.main_pipeline, false)?;
                 copy.required_source_schema.clone()
             }
@@ -101,15 +105,33 @@ impl PipelineBuilder {
         }
 
         if !plan_values_consts.is_empty() {
+            // the input has been casted to the required source schema.
             Self::fill_const_columns(
                 ctx.clone(),
                 main_pipeline,
-                source_schema,
+                plan_required_source_schema.clone(),
                 plan_required_values_schema.clone(),
                 plan_values_consts,
             )?;
         }
 
+        // validation only parses the files, the data is neither written nor committed.
+        match &plan.validation_mode {
+            ValidationMode::None => {}
+            ValidationMode::ReturnNRows(n) => {
+                main_pipeline.try_resize(1)?;
+                return main_pipeline.add_transform(|input, output| {
+                    let transform =
+                        TransformLimit::try_create(Some(*n as usize), 0, input, output)?;
+                    Ok(ProcessorPtr::create(transform))
+                });
+            }
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                return main_pipeline
+                    .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))));
+            }
+        }
+
         // append data without commit.
         match plan_write_mode {
             CopyIntoTableMode::Insert { overwrite: _ } => {
@@ -124,8 +124,12 @@ impl PipelineBuilder {
                     )
                 })?;
         }
-        let cluster_stats_gen =
-            table.get_cluster_stats_gen(self.ctx.clone(), 0, table.get_block_thresholds(), None)?;
+        let cluster_stats_gen = table.get_cluster_stats_gen(
+            self.ctx.clone(),
+            0,
+            table.get_block_thresholds(self.ctx.clone()),
+            None,
+        )?;
         self.main_pipeline.add_transform(|input, output| {
             let proc = TransformSerializeBlock::try_create(
                 self.ctx.clone(),
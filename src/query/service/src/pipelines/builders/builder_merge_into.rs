@@ -196,7 +196,7 @@ impl PipelineBuilder {
 
         // 3. cluster sort
         let table = FuseTable::try_from_table(tbl.as_ref())?;
-        let block_thresholds = table.get_block_thresholds();
+        let block_thresholds = table.get_block_thresholds(self.ctx.clone());
         table.cluster_gen_for_append_with_specified_len(
             self.ctx.clone(),
             &mut self.main_pipeline,
@@ -322,7 +322,7 @@ impl PipelineBuilder {
             .build_table_by_table_info(catalog_info, table_info, None)?;
 
         let table = FuseTable::try_from_table(tbl.as_ref())?;
-        let block_thresholds = table.get_block_thresholds();
+        let block_thresholds = table.get_block_thresholds(self.ctx.clone());
 
         let cluster_stats_gen =
             table.get_cluster_stats_gen(self.ctx.clone(), 0, block_thresholds, None)?;
@@ -59,7 +59,7 @@ impl PipelineBuilder {
                 let catalog_info = recluster_source.catalog_info.clone();
                 let task = &recluster_source.tasks[0];
                 let recluster_block_nums = task.parts.len();
-                let block_thresholds = table.get_block_thresholds();
+                let block_thresholds = table.get_block_thresholds(self.ctx.clone());
                 let table_info = table.get_table_info();
                 let schema = table.schema_with_stream();
                 let description = task.stats.get_description(&table_info.desc);
@@ -32,10 +32,13 @@ use common_formats::FastValuesDecoder;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipe;
+use common_pipeline_core::PipeItem;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_pipeline_transforms::processors::create_dummy_item;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
 use common_sql::executor::physical_plans::MutationKind;
 use common_sql::executor::physical_plans::ReplaceAsyncSourcer;
 use common_sql::executor::physical_plans::ReplaceDeduplicate;
@@ -45,6 +48,7 @@ use common_sql::BindContext;
 use common_sql::Metadata;
 use common_sql::MetadataRef;
 use common_sql::NameResolutionContext;
+use common_storages_fuse::operations::common::TransformBucketScatter;
 use common_storages_fuse::operations::common::TransformSerializeSegment;
 use common_storages_fuse::operations::processors::BroadcastProcessor;
 use common_storages_fuse::operations::processors::ReplaceIntoProcessor;
@@ -158,6 +162,21 @@ impl PipelineBuilder {
             return Ok(());
         }
 
+        if let Some(hasher) = table.bucket_hasher(self.ctx.clone())? {
+            // split the rows to append by bucket before serializing them.
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor = AccumulatingTransformer::create(
+                input.clone(),
+                output.clone(),
+                TransformBucketScatter::new(hasher),
+            );
+            self.main_pipeline.add_pipe(Pipe::create(2, 2, vec![
+                PipeItem::create(ProcessorPtr::create(processor), vec![input], vec![output]),
+                create_dummy_item(),
+            ]));
+        }
+
         if segment_partition_num == 0 {
             let dummy_item = create_dummy_item();
             //                      ┌──────────────────────┐            ┌──────────────────┐
@@ -323,7 +342,7 @@ impl PipelineBuilder {
         let _ = table.cluster_gen_for_append(
             self.ctx.clone(),
             &mut self.main_pipeline,
-            table.get_block_thresholds(),
+            table.get_block_thresholds(self.ctx.clone()),
             opt_modified_schema,
         )?;
         // 1. resize input to 1, since the UpsertTransform need to de-duplicate inputs "globally"
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::ProcessorProfileWrapper;
+use common_sql::executor::physical_plans::Unnest;
+
+use crate::pipelines::processors::transforms::TransformUnnest;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_unnest(&mut self, unnest: &Unnest) -> Result<()> {
+        self.build_pipeline(&unnest.input)?;
+
+        let output_schema = unnest.output_schema()?;
+        let element_type = output_schema
+            .field_with_name(&unnest.element.to_string())?
+            .data_type()
+            .clone();
+        let max_block_size = self.settings.get_max_block_size()? as usize;
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformUnnest::try_create(
+                input,
+                output,
+                unnest.projections.clone(),
+                unnest.array_offset,
+                element_type.clone(),
+                unnest.ordinality.is_some(),
+                unnest.outer,
+                max_block_size,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    unnest.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+}
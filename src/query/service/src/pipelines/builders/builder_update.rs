@@ -68,7 +68,7 @@ impl PipelineBuilder {
                 })?;
         }
 
-        let block_thresholds = table.get_block_thresholds();
+        let block_thresholds = table.get_block_thresholds(self.ctx.clone());
         // sort
         let cluster_stats_gen = table.cluster_gen_for_append(
             self.ctx.clone(),
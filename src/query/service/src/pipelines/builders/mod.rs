@@ -35,6 +35,7 @@ mod builder_scan;
 mod builder_sort;
 mod builder_udf;
 mod builder_union_all;
+mod builder_unnest;
 mod builder_update;
 mod builder_window;
 
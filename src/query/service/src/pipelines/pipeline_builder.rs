@@ -127,6 +127,7 @@ impl PipelineBuilder {
             }
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::Udf(udf) => self.build_udf(udf),
+            PhysicalPlan::Unnest(unnest) => self.build_unnest(unnest),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
@@ -43,6 +43,7 @@ use crate::pipelines::processors::transforms::aggregator::aggregate_meta::Aggreg
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::HashTableCell;
 use crate::pipelines::processors::transforms::aggregator::PartitionedHashTableDropper;
+use crate::pipelines::processors::transforms::group_by::Area;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
@@ -108,6 +109,8 @@ pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     hash_table: HashTable<Method>,
 
     params: Arc<AggregatorParams>,
+    // Group the sorted rows of each block by ranges instead of hashing every row.
+    time_rollup: bool,
 }
 
 impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
@@ -117,6 +120,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         params: Arc<AggregatorParams>,
+        time_rollup: bool,
     ) -> Result<Box<dyn Processor>> {
         let arena = Arc::new(Bump::new());
         let hashtable = method.create_hash_table(arena)?;
@@ -138,6 +142,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                 params,
                 hash_table,
                 settings: AggregateSettings::try_from(ctx)?,
+                time_rollup,
             },
         ))
     }
@@ -215,6 +220,36 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
         Ok(())
     }
 
+    // Only the first key of every range of equal keys is looked up in the hash table.
+    unsafe fn build_range_places<'a, T: HashtableLike<Value = usize>>(
+        params: &AggregatorParams,
+        hashtable: &mut T,
+        arena: &mut Area,
+        mut keys: impl Iterator<Item = &'a T::Key>,
+        ranges: Vec<(usize, usize)>,
+        rows_num: usize,
+    ) -> StateAddrs
+    where
+        T::Key: 'a,
+    {
+        let mut places = Vec::with_capacity(rows_num);
+        let mut consumed = 0;
+        for (start, end) in ranges {
+            let key = keys.nth(start - consumed).unwrap();
+            consumed = start + 1;
+            let place = match hashtable.insert_and_entry(key) {
+                Err(entry) => Into::<StateAddr>::into(*entry.get()),
+                Ok(mut entry) => {
+                    let place = params.alloc_layout(arena);
+                    *entry.get_mut() = place.addr();
+                    place
+                }
+            };
+            places.extend(std::iter::repeat(place).take(end - start));
+        }
+        places
+    }
+
     fn execute_one_block(&mut self, block: DataBlock) -> Result<()> {
         let is_agg_index_block = block
             .get_meta()
@@ -232,12 +267,33 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             .map(|c| (c.value.as_column().unwrap().clone(), c.data_type.clone()))
             .collect::<Vec<_>>();
 
+        let ranges = match self.time_rollup {
+            true => sorted_time_ranges(&group_columns[0].0),
+            false => None,
+        };
+
         unsafe {
             let rows_num = block.num_rows();
             let state = self.method.build_keys_state(&group_columns, rows_num)?;
 
             match &mut self.hash_table {
                 HashTable::MovedOut => unreachable!(),
+                HashTable::HashTable(hashtable) if ranges.is_some() => {
+                    let places = Self::build_range_places(
+                        &self.params,
+                        &mut hashtable.hashtable,
+                        &mut hashtable.arena,
+                        self.method.build_keys_iter(&state)?,
+                        ranges.unwrap(),
+                        rows_num,
+                    );
+
+                    if is_agg_index_block {
+                        self.execute_agg_index_block(&block, &places)
+                    } else {
+                        Self::execute(&self.params, &block, &places)
+                    }
+                }
                 HashTable::HashTable(hashtable) => {
                     let mut places = Vec::with_capacity(rows_num);
 
@@ -258,6 +314,22 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                         Self::execute(&self.params, &block, &places)
                     }
                 }
+                HashTable::PartitionedHashTable(hashtable) if ranges.is_some() => {
+                    let places = Self::build_range_places(
+                        &self.params,
+                        &mut hashtable.hashtable,
+                        &mut hashtable.arena,
+                        self.method.build_keys_iter(&state)?,
+                        ranges.unwrap(),
+                        rows_num,
+                    );
+
+                    if is_agg_index_block {
+                        self.execute_agg_index_block(&block, &places)
+                    } else {
+                        Self::execute(&self.params, &block, &places)
+                    }
+                }
                 HashTable::PartitionedHashTable(hashtable) => {
                     let mut places = Vec::with_capacity(rows_num);
 
@@ -283,6 +355,46 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
     }
 }
 
+/// Splits the group column into ranges of equal values, returns `None` if the rows
+/// are not sorted.
+fn sorted_time_ranges(column: &Column) -> Option<Vec<(usize, usize)>> {
+    match column {
+        Column::Timestamp(values) => sorted_ranges(values.as_slice()),
+        Column::Date(values) => sorted_ranges(values.as_slice()),
+        Column::Nullable(box column) if column.validity.unset_bits() == 0 => {
+            sorted_time_ranges(&column.column)
+        }
+        _ => None,
+    }
+}
+
+fn sorted_ranges<T: Ord>(values: &[T]) -> Option<Vec<(usize, usize)>> {
+    // Bail out with the block min/max before checking every row.
+    if values.is_empty()
+        || values[0] > values[values.len() - 1]
+        || values.windows(2).any(|w| w[0] > w[1])
+    {
+        return None;
+    }
+
+    let mut ranges = vec![];
+    let mut start = 0;
+    while start < values.len() {
+        // Gallop to the upper bound of the range, then binary search inside it.
+        let value = &values[start];
+        let mut step = 1;
+        while start + step < values.len() && &values[start + step] <= value {
+            step *= 2;
+        }
+        let lower = start + step / 2;
+        let upper = values.len().min(start + step);
+        let end = lower + values[lower..upper].partition_point(|v| v <= value);
+        ranges.push((start, end));
+        start = end;
+    }
+    Some(ranges)
+}
+
 impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggregate<Method> {
     const NAME: &'static str = "TransformPartialAggregate";
 
@@ -33,6 +33,7 @@ mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
 mod transform_srf;
 mod transform_udf;
+mod transform_unnest;
 mod window;
 
 pub use hash_join::*;
@@ -57,6 +58,7 @@ pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithou
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_srf::TransformSRF;
 pub use transform_udf::TransformUdf;
+pub use transform_unnest::TransformUnnest;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
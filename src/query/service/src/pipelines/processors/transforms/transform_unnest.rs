@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::DataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Value;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::BlockingTransform;
+use common_pipeline_transforms::processors::BlockingTransformer;
+use common_sql::ColumnSet;
+
+/// Expand each row of the input [`DataBlock`] into one row per element of the array column.
+///
+/// The elements are read directly from the values and offsets of the array column,
+/// the input rows are repeated with a single `take` per output block.
+pub struct TransformUnnest {
+    projections: ColumnSet,
+    array_offset: usize,
+    element_type: DataType,
+    with_ordinality: bool,
+    outer: bool,
+    max_block_size: usize,
+
+    /// The projected input rows.
+    input: Option<DataBlock>,
+    /// The flattened elements of the array column, `None` if all the arrays are empty.
+    values: Option<Column>,
+    offsets: Buffer<u64>,
+    /// The number of elements of each input row, 0 for NULL arrays.
+    lengths: Vec<usize>,
+    /// The next input row to expand.
+    cursor: usize,
+}
+
+impl TransformUnnest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        projections: ColumnSet,
+        array_offset: usize,
+        element_type: DataType,
+        with_ordinality: bool,
+        outer: bool,
+        max_block_size: usize,
+    ) -> Box<dyn Processor> {
+        BlockingTransformer::create(input, output, TransformUnnest {
+            projections,
+            array_offset,
+            element_type,
+            with_ordinality,
+            outer,
+            max_block_size,
+            input: None,
+            values: None,
+            offsets: Buffer::default(),
+            lengths: vec![],
+            cursor: 0,
+        })
+    }
+
+    /// The number of output rows of the input row `row`.
+    #[inline]
+    fn output_rows(&self, row: usize) -> usize {
+        if self.outer {
+            self.lengths[row].max(1)
+        } else {
+            self.lengths[row]
+        }
+    }
+
+    fn build_elements(&self, start: usize, end: usize, num_rows: usize) -> Column {
+        let first = self.offsets.get(start).copied().unwrap_or_default() as usize;
+        let last = self.offsets.get(end).copied().unwrap_or_default() as usize;
+        let contiguous = (start..end).all(|row| {
+            self.output_rows(row) == (self.offsets[row + 1] - self.offsets[row]) as usize
+        });
+
+        // Fast path: the elements of the rows are exactly a slice of the values.
+        if let (true, Some(values)) = (contiguous, &self.values) {
+            let column = values.slice(first..last);
+            if self.element_type.is_nullable() && !column.data_type().is_nullable_or_null() {
+                return Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity: Bitmap::new_constant(true, num_rows),
+                }));
+            }
+            return column;
+        }
+
+        let mut builder = ColumnBuilder::with_capacity(&self.element_type, num_rows);
+        for row in start..end {
+            let length = self.lengths[row];
+            if length == 0 {
+                if self.outer {
+                    builder.push_default();
+                }
+                continue;
+            }
+            let values = self.values.as_ref().unwrap();
+            let offset = self.offsets[row] as usize;
+            for index in offset..offset + length {
+                builder.push(unsafe { values.index_unchecked(index) });
+            }
+        }
+        builder.build()
+    }
+
+    fn build_ordinality(&self, start: usize, end: usize, num_rows: usize) -> Column {
+        let mut ordinality = Vec::with_capacity(num_rows);
+        let mut validity = Vec::with_capacity(if self.outer { num_rows } else { 0 });
+        for row in start..end {
+            let length = self.lengths[row];
+            ordinality.extend(1..=length as u64);
+            if self.outer {
+                validity.extend(std::iter::repeat(true).take(length));
+                if length == 0 {
+                    ordinality.push(0);
+                    validity.push(false);
+                }
+            }
+        }
+        if self.outer {
+            UInt64Type::from_data_with_validity(ordinality, validity)
+        } else {
+            UInt64Type::from_data(ordinality)
+        }
+    }
+}
+
+impl BlockingTransform for TransformUnnest {
+    const NAME: &'static str = "TransformUnnest";
+
+    fn consume(&mut self, input: DataBlock) -> Result<()> {
+        debug_assert!(self.input.is_none());
+
+        let num_rows = input.num_rows();
+        let entry = input.get_by_offset(self.array_offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let (column, validity) = match column {
+            Column::Nullable(box NullableColumn { column, validity }) => (column, Some(validity)),
+            column => (column, None),
+        };
+
+        self.lengths.clear();
+        match column {
+            Column::Array(box array) => {
+                self.lengths
+                    .extend(array.offsets.windows(2).map(|w| (w[1] - w[0]) as usize));
+                self.values = Some(array.values);
+                self.offsets = array.offsets;
+            }
+            // All the arrays are empty or NULL.
+            _ => {
+                self.lengths.resize(num_rows, 0);
+                self.values = None;
+                self.offsets = vec![0; num_rows + 1].into();
+            }
+        }
+        if let Some(validity) = validity {
+            for (length, valid) in self.lengths.iter_mut().zip(validity.iter()) {
+                if !valid {
+                    *length = 0;
+                }
+            }
+        }
+
+        self.cursor = 0;
+        self.input = Some(input.project(&self.projections));
+        Ok(())
+    }
+
+    fn transform(&mut self) -> Result<Option<DataBlock>> {
+        let input = match &self.input {
+            Some(input) => input,
+            None => return Ok(None),
+        };
+
+        // Expand the input rows until `max_block_size` output rows are reached.
+        let start = self.cursor;
+        let mut end = start;
+        let mut num_rows = 0;
+        while end < self.lengths.len() && (num_rows < self.max_block_size || end == start) {
+            num_rows += self.output_rows(end);
+            end += 1;
+        }
+
+        let mut indices = Vec::with_capacity(num_rows);
+        for row in start..end {
+            indices.extend(std::iter::repeat(row as u32).take(self.output_rows(row)));
+        }
+        let mut result = input.take(&indices, &mut None)?;
+
+        let elements = self.build_elements(start, end, num_rows);
+        result.add_column(BlockEntry::new(
+            self.element_type.clone(),
+            Value::Column(elements),
+        ));
+        if self.with_ordinality {
+            let ordinality = self.build_ordinality(start, end, num_rows);
+            result.add_column(BlockEntry::new(
+                ordinality.data_type(),
+                Value::Column(ordinality),
+            ));
+        }
+
+        self.cursor = end;
+        if self.cursor == self.lengths.len() {
+            self.input = None;
+            self.values = None;
+        }
+        Ok(Some(result))
+    }
+}
@@ -36,6 +36,7 @@ use crate::api::BroadcastExchange;
 use crate::api::DataExchange;
 use crate::api::MergeExchange;
 use crate::api::ShuffleDataExchange;
+use crate::api::ShuffleSkew;
 use crate::clusters::ClusterHelper;
 use crate::schedulers::fragments::plan_fragment::FragmentType;
 use crate::schedulers::PlanFragment;
@@ -82,7 +83,7 @@ impl Fragmenter {
     /// This method is basically copied from `QueryFragmentActions::get_executors()`.
     pub fn get_executors(ctx: Arc<QueryContext>) -> Vec<String> {
         let cluster = ctx.get_cluster();
-        let cluster_nodes = cluster.get_nodes();
+        let cluster_nodes = cluster.get_executor_nodes();
 
         cluster_nodes.iter().map(|node| &node.id).cloned().collect()
     }
@@ -99,8 +100,13 @@ impl Fragmenter {
         match plan {
             PhysicalPlan::ExchangeSink(plan) => match plan.kind {
                 FragmentKind::Normal => Ok(Some(ShuffleDataExchange::create(
-                    Self::get_executors(ctx),
+                    Self::get_executors(ctx.clone()),
                     plan.keys.clone(),
+                    plan.skew.map(|skew| ShuffleSkew {
+                        join_id: skew.join_id,
+                        side: skew.side,
+                        coordinator_id: Self::get_local_executor(ctx),
+                    }),
                 ))),
                 FragmentKind::Merge => Ok(Some(MergeExchange::create(
                     Self::get_local_executor(ctx),
@@ -266,6 +272,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             destination_fragment_id: usize::MAX,
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            skew: plan.skew,
         });
         let fragment_type = match self.state {
             State::SelectLeaf => FragmentType::Source,
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_settings::ReplaceIntoShuffleStrategy;
@@ -155,6 +156,15 @@ impl PlanFragment {
             ));
         }
 
+        if let Some(read_sources) = self.get_bucketed_read_sources() {
+            return Self::redistribute_bucketed_sources(
+                &self.plan,
+                ctx,
+                fragment_actions,
+                read_sources,
+            );
+        }
+
         let read_source = self.get_read_source()?;
 
         let executors = Fragmenter::get_executors(ctx);
@@ -180,6 +190,40 @@ impl PlanFragment {
         Ok(())
     }
 
+    /// Redistribute the partitions of the bucketed tables scanned by the fragment, the same
+    /// bucket of every table is routed to the same executor.
+    fn redistribute_bucketed_sources(
+        plan: &PhysicalPlan,
+        ctx: Arc<QueryContext>,
+        fragment_actions: &mut QueryFragmentActions,
+        read_sources: HashMap<u32, DataSourcePlan>,
+    ) -> Result<()> {
+        let executors = Fragmenter::get_executors(ctx);
+        let mut executor_sources: HashMap<String, HashMap<u32, DataSourcePlan>> = executors
+            .iter()
+            .map(|executor| (executor.clone(), HashMap::new()))
+            .collect();
+        for (plan_id, read_source) in read_sources.iter() {
+            let partition_reshuffle = read_source.parts.reshuffle(executors.clone())?;
+            for (executor, parts) in partition_reshuffle.into_iter() {
+                let mut new_read_source = read_source.clone();
+                new_read_source.parts = parts;
+                executor_sources
+                    .entry(executor)
+                    .or_default()
+                    .insert(*plan_id, new_read_source);
+            }
+        }
+
+        for (executor, sources) in executor_sources.into_iter() {
+            let mut replace_read_sources = ReplaceReadSources { sources };
+            let plan = replace_read_sources.replace(plan)?;
+            fragment_actions.add_action(QueryFragmentAction::create(executor, plan));
+        }
+
+        Ok(())
+    }
+
     fn redistribute_delete_leaf(
         &self,
         ctx: Arc<QueryContext>,
@@ -226,13 +270,19 @@ impl PlanFragment {
         };
         let partitions = &plan.segments;
         let executors = Fragmenter::get_executors(ctx.clone());
+        // The local node may only coordinate the query, the rows are then inserted
+        // by one of the executors.
         let local_id = ctx.get_cluster().local_id.clone();
+        let insert_executor = match executors.contains(&local_id) {
+            true => local_id,
+            false => executors.first().cloned().unwrap_or(local_id),
+        };
         match ctx.get_settings().get_replace_into_shuffle_strategy()? {
             ReplaceIntoShuffleStrategy::SegmentLevelShuffling => {
                 let partition_reshuffle = Self::reshuffle(executors, partitions.clone())?;
                 for (executor, parts) in partition_reshuffle.into_iter() {
                     let mut plan = self.plan.clone();
-                    let need_insert = executor == local_id;
+                    let need_insert = executor == insert_executor;
 
                     let mut replace_replace_into = ReplaceReplaceInto {
                         partitions: parts,
@@ -250,7 +300,7 @@ impl PlanFragment {
                 // but for each segment, one executor only need to take part of the blocks
                 for (executor_idx, executor) in executors.into_iter().enumerate() {
                     let mut plan = self.plan.clone();
-                    let need_insert = executor == local_id;
+                    let need_insert = executor == insert_executor;
                     let mut replace_replace_into = ReplaceReplaceInto {
                         partitions: partitions.clone(),
                         slot: Some(BlockSlotDescription {
@@ -374,6 +424,32 @@ impl PlanFragment {
         Ok(executor_part)
     }
 
+    /// Returns the read sources of the table scans if the fragment scans multiple bucketed
+    /// tables, e.g. the bucket-aligned join.
+    fn get_bucketed_read_sources(&self) -> Option<HashMap<u32, DataSourcePlan>> {
+        let mut sources = HashMap::new();
+        let mut bucketed = true;
+        PhysicalPlan::traverse(
+            &self.plan,
+            &mut |_| true,
+            &mut |plan| match plan {
+                PhysicalPlan::TableScan(scan) => {
+                    bucketed &= scan.source.parts.kind == PartitionsShuffleKind::Bucket;
+                    sources.insert(scan.plan_id, *scan.source.clone());
+                }
+                PhysicalPlan::CopyIntoTable(_) => bucketed = false,
+                _ => {}
+            },
+            &mut |_| {},
+        );
+
+        if bucketed && sources.len() > 1 {
+            Some(sources)
+        } else {
+            None
+        }
+    }
+
     fn get_read_source(&self) -> Result<DataSourcePlan> {
         if self.fragment_type != FragmentType::Source {
             return Err(ErrorCode::Internal(
@@ -448,6 +524,25 @@ impl PhysicalPlanReplacer for ReplaceReadSource {
     }
 }
 
+struct ReplaceReadSources {
+    sources: HashMap<u32, DataSourcePlan>,
+}
+
+impl PhysicalPlanReplacer for ReplaceReadSources {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let source = self.sources.get(&plan.plan_id).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Missing read source of table scan {}",
+                plan.plan_id
+            ))
+        })?;
+        Ok(PhysicalPlan::TableScan(TableScan {
+            source: Box::new(source.clone()),
+            ..plan.clone()
+        }))
+    }
+}
+
 struct ReplaceReclusterSource {
     pub tasks: Vec<ReclusterTask>,
 }
@@ -120,7 +120,7 @@ impl QueryFragmentsActions {
 
     pub fn get_executors(&self) -> Vec<String> {
         let cluster = self.ctx.get_cluster();
-        let cluster_nodes = cluster.get_nodes();
+        let cluster_nodes = cluster.get_executor_nodes();
 
         cluster_nodes.iter().map(|node| &node.id).cloned().collect()
     }
@@ -111,11 +111,16 @@ impl FlightSqlServiceImpl {
             } => match password {
                 None => return Err(Status::unauthenticated("password required")),
                 Some(p) => {
-                    if *h == t.hash(&p) {
-                        user
-                    } else {
+                    let authed = *h == t.hash(&p);
+                    let must_change_password = UserApiProvider::instance()
+                        .check_login_password(&tenant, &user, authed)
+                        .await
+                        .map_err(|e| Status::unauthenticated(e.message()))?;
+                    if !authed {
                         return Err(Status::unauthenticated("wrong password"));
                     }
+                    session.set_must_change_password(must_change_password);
+                    user
                 }
             },
             _ => return Err(Status::unauthenticated("wrong auth type")),
@@ -347,7 +347,7 @@ pub async fn clickhouse_handler_post(
                         ctx.get_settings(),
                         table_schema,
                         ctx.get_scan_progress(),
-                        to_table.get_block_thresholds(),
+                        to_table.get_block_thresholds(ctx.clone()),
                     )
                     .await
                     .map_err(InternalServerError)?,
@@ -399,7 +399,7 @@ pub async fn clickhouse_handler_post(
                         table_schema,
                         ctx.get_scan_progress(),
                         false,
-                        to_table.get_block_thresholds(),
+                        to_table.get_block_thresholds(ctx.clone()),
                         on_error_mode.clone(),
                     )
                     .await
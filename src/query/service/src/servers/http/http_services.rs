@@ -41,6 +41,7 @@ use crate::servers::http::middleware::PanicHandler;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::list_suggestions;
 use crate::servers::http::v1::query_route;
+use crate::servers::http::v1::session_route;
 use crate::servers::http::v1::streaming_load;
 use crate::servers::Server;
 
@@ -100,6 +101,10 @@ impl HttpHandler {
             .at("/upload_to_stage", put(upload_to_stage))
             .at("/suggested_background_tasks", get(list_suggestions));
         let ep_v1 = self.wrap_auth(ep_v1);
+        // the session token endpoints authenticate the requests by themselves.
+        let ep_v1 = Route::new()
+            .nest("/session", session_route())
+            .nest("/", ep_v1);
 
         let ep_clickhouse = Route::new().nest("/", clickhouse_router());
         let ep_clickhouse = self.wrap_auth(ep_clickhouse);
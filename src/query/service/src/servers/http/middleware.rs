@@ -44,6 +44,7 @@ use poem::Response;
 use uuid::Uuid;
 
 use super::v1::HttpQueryContext;
+use super::v1::SESSION_TOKEN_PREFIX;
 use crate::auth::AuthMgr;
 use crate::auth::Credential;
 use crate::servers::HttpHandlerKind;
@@ -52,6 +53,7 @@ use crate::sessions::SessionType;
 
 const DEDUPLICATE_LABEL: &str = "X-DATABEND-DEDUPLICATE-LABEL";
 const USER_AGENT: &str = "User-Agent";
+const QUERY_TAG: &str = "X-DATABEND-QUERY-TAG";
 const QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 
 pub struct HTTPSessionMiddleware {
@@ -65,7 +67,7 @@ impl HTTPSessionMiddleware {
     }
 }
 
-fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
+pub(crate) fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
     let std_auth_headers: Vec<_> = req.headers().get_all(AUTHORIZATION).iter().collect();
     if std_auth_headers.len() > 1 {
         let msg = &format!("Multiple {} headers detected", AUTHORIZATION);
@@ -111,6 +113,12 @@ fn auth_by_header(
         }
     } else if value.as_bytes().starts_with(b"Bearer ") {
         match Bearer::decode(value) {
+            Some(bearer) if bearer.token().starts_with(SESSION_TOKEN_PREFIX) => {
+                Ok(Credential::DatabendToken {
+                    token: bearer.token().to_string(),
+                    client_ip,
+                })
+            }
             Some(bearer) => Ok(Credential::Jwt {
                 token: bearer.token().to_string(),
                 client_ip,
@@ -197,6 +205,11 @@ impl<E> HTTPSessionEndpoint<E> {
             .get(USER_AGENT)
             .map(|id| id.to_str().unwrap().to_string());
 
+        let query_tag = req
+            .headers()
+            .get(QUERY_TAG)
+            .map(|tag| String::from_utf8_lossy(tag.as_bytes()).to_string());
+
         let query_id = req
             .headers()
             .get(QUERY_ID)
@@ -209,6 +222,7 @@ impl<E> HTTPSessionEndpoint<E> {
             node_id,
             deduplicate_label,
             user_agent,
+            query_tag,
         ))
     }
 }
@@ -228,6 +242,14 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
                 req.extensions_mut().insert(ctx);
                 self.ep.call(req).await
             }
+            Err(err) if is_session_token_error(&err) => {
+                warn!(
+                    "http auth failure: {method} {uri}, headers={:?}, error={}",
+                    sanitize_request_headers(&headers),
+                    err
+                );
+                return Ok(error_response(&err));
+            }
             Err(err) => match err.code() {
                 ErrorCode::AUTHENTICATE_FAILURE => {
                     warn!(
@@ -269,6 +291,42 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
     }
 }
 
+// The errors of the session tokens, the clients tell by the `reason` of the response
+// when to refresh the session token or login again.
+fn is_session_token_error(err: &ErrorCode) -> bool {
+    matches!(
+        err.code(),
+        ErrorCode::SESSION_TOKEN_EXPIRED
+            | ErrorCode::REFRESH_TOKEN_EXPIRED
+            | ErrorCode::SESSION_TOKEN_REVOKED
+            | ErrorCode::INVALID_SESSION_TOKEN
+    )
+}
+
+/// Converts the error of the session token endpoints and of the authentication with
+/// a session token to a json response.
+///
+/// Besides the http status code, the body contains the name of the error code in `reason`,
+/// e.g. `SessionTokenExpired`, so that clients can tell when to refresh the session token.
+pub(crate) fn error_response(err: &ErrorCode) -> Response {
+    let status = if is_session_token_error(err) || err.code() == ErrorCode::AUTHENTICATE_FAILURE {
+        StatusCode::UNAUTHORIZED
+    } else if err.code() == ErrorCode::PASSWORD_MUST_BE_CHANGED {
+        StatusCode::FORBIDDEN
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    let body = Body::from_json(serde_json::json!({
+        "error": {
+            "code": status.as_str(),
+            "message": err.message(),
+            "reason": err.name(),
+        }
+    }))
+    .unwrap();
+    Response::builder().status(status).body(body)
+}
+
 pub fn sanitize_request_headers(headers: &HeaderMap) -> HashMap<String, String> {
     let sensitive_headers = ["authorization", "x-clickhouse-key", "cookie"];
     headers
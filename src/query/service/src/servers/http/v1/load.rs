@@ -169,7 +169,7 @@ pub async fn streaming_load(
                         table_schema,
                         context.get_scan_progress(),
                         false,
-                        to_table.get_block_thresholds(),
+                        to_table.get_block_thresholds(context.clone()),
                         on_error_mode.clone(),
                     )
                     .await
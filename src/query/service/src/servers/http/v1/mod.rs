@@ -16,6 +16,7 @@ mod http_query_handlers;
 pub mod json_block;
 mod load;
 mod query;
+mod session;
 mod stage;
 mod suggestions;
 
@@ -35,6 +36,12 @@ pub use query::ExpiringState;
 pub use query::HttpQueryContext;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
+pub use session::session_route;
+pub use session::SessionTokenClaims;
+pub use session::SessionTokenManager;
+pub use session::SessionTokenPair;
+pub use session::TokenType;
+pub use session::SESSION_TOKEN_PREFIX;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
 pub use suggestions::list_suggestions;
@@ -288,6 +288,7 @@ impl HttpQuery {
         let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
+        let query_tag = &ctx.query_tag;
         let query_id = ctx.query_id.clone();
         let ctx = session.create_query_context().await?;
 
@@ -301,6 +302,10 @@ impl HttpQuery {
         if let Some(ua) = user_agent {
             ctx.set_ua(ua.clone());
         }
+        // The tag in the header only applies to this query, the SET_VAR hints may still override it.
+        if let Some(tag) = query_tag {
+            ctx.get_settings().set_query_tag(tag.clone())?;
+        }
 
         // TODO: validate the query_id to be uuid format
         ctx.set_id(query_id.clone());
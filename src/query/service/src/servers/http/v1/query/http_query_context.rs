@@ -30,6 +30,7 @@ pub struct HttpQueryContext {
     pub node_id: String,
     pub deduplicate_label: Option<String>,
     pub user_agent: Option<String>,
+    pub query_tag: Option<String>,
 }
 
 impl HttpQueryContext {
@@ -39,6 +40,7 @@ impl HttpQueryContext {
         node_id: String,
         deduplicate_label: Option<String>,
         user_agent: Option<String>,
+        query_tag: Option<String>,
     ) -> Self {
         HttpQueryContext {
             session,
@@ -46,6 +48,7 @@ impl HttpQueryContext {
             node_id,
             deduplicate_label,
             user_agent,
+            query_tag,
         }
     }
 
@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use headers::authorization::Bearer;
+use headers::authorization::Credentials;
+use http::header::AUTHORIZATION;
+use poem::error::Result as PoemResult;
+use poem::post;
+use poem::web::Json;
+use poem::EndpointExt;
+use poem::IntoResponse;
+use poem::Request;
+use poem::Response;
+use poem::Route;
+
+use super::token_manager::SessionTokenManager;
+use super::token_manager::SessionTokenPair;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::servers::http::middleware::error_response;
+use crate::servers::http::middleware::get_credential;
+use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::HttpHandlerKind;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Validates the credentials once, and issues a session token and a refresh token.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn login_handler(req: &Request) -> PoemResult<Response> {
+    Ok(to_response(login(req).await))
+}
+
+/// Issues a new session token with the refresh token in the `Authorization: Bearer` header.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn refresh_handler(req: &Request) -> PoemResult<Response> {
+    let res = match get_bearer_token(req) {
+        Ok(token) => SessionTokenManager::instance().refresh(&token).await,
+        Err(e) => Err(e),
+    };
+    Ok(to_response(res))
+}
+
+/// Revokes the session of the session token or refresh token in the `Authorization: Bearer` header.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn revoke_handler(req: &Request) -> PoemResult<Response> {
+    let res = match get_bearer_token(req) {
+        Ok(token) => SessionTokenManager::instance().revoke(&token).await,
+        Err(e) => Err(e),
+    };
+    match res {
+        Ok(_) => Ok(Json(serde_json::json!({})).into_response()),
+        Err(err) => Ok(error_response(&err)),
+    }
+}
+
+pub fn session_route() -> Route {
+    let rules = [
+        ("/login", post(login_handler)),
+        ("/refresh", post(refresh_handler)),
+        ("/revoke", post(revoke_handler)),
+    ];
+
+    let mut route = Route::new();
+    for (path, endpoint) in rules.into_iter() {
+        route = route.at(path, endpoint.with(MetricsMiddleware::new(path)));
+    }
+    route
+}
+
+#[async_backtrace::framed]
+async fn login(req: &Request) -> Result<SessionTokenPair> {
+    let credential = get_credential(req, HttpHandlerKind::Query)?;
+    if let Credential::DatabendToken { .. } = credential {
+        return Err(ErrorCode::AuthenticateFailure(
+            "can not login with a session token, use /v1/session/refresh instead",
+        ));
+    }
+
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    if let Some(tenant_id) = req.headers().get("X-DATABEND-TENANT") {
+        let tenant_id = tenant_id.to_str().unwrap().to_string();
+        session.set_current_tenant(tenant_id);
+    }
+    AuthMgr::instance()
+        .auth(session.clone(), &credential)
+        .await?;
+    let user = session.get_current_user()?;
+    SessionTokenManager::instance()
+        .issue_tokens(&session.get_current_tenant(), &user)
+        .await
+}
+
+fn get_bearer_token(req: &Request) -> Result<String> {
+    let value = req
+        .headers()
+        .get(AUTHORIZATION)
+        .ok_or_else(|| ErrorCode::AuthenticateFailure("No authorization header detected"))?;
+    match Bearer::decode(value) {
+        Some(bearer) => Ok(bearer.token().to_string()),
+        None => Err(ErrorCode::AuthenticateFailure("bad Bearer auth header")),
+    }
+}
+
+fn to_response(res: Result<SessionTokenPair>) -> Response {
+    match res {
+        Ok(pair) => Json(pair).into_response(),
+        Err(err) => error_response(&err),
+    }
+}
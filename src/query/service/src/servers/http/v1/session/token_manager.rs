@@ -0,0 +1,415 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserInfo;
+use common_users::UserApiProvider;
+use jwt_simple::algorithms::HS256Key;
+use jwt_simple::algorithms::MACLike;
+use jwt_simple::claims::JWTClaims;
+use jwt_simple::prelude::Clock;
+use log::warn;
+use parking_lot::RwLock;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Prefix of the tokens issued by databend, to tell them from the JWTs of the external providers.
+pub const SESSION_TOKEN_PREFIX: &str = "bend-v1-";
+
+// The revoked sessions and the users of the tokens are cached on each node, and reloaded
+// from meta after this interval, so a token revoked on another node is rejected at last
+// after this interval.
+const CACHE_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+// The secret is used as the HS256 key as is, it should be no shorter than the output of SHA-256.
+const MIN_SECRET_LENGTH: usize = 32;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    Session,
+    Refresh,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionTokenClaims {
+    pub tenant: String,
+    pub token_type: TokenType,
+    /// Unix timestamp in seconds.
+    pub expire_at: u64,
+    /// Expire time of the refresh token of the session, in unix timestamp seconds.
+    pub refresh_expire_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionTokenPair {
+    pub session_token: String,
+    pub refresh_token: String,
+    pub session_token_ttl_in_secs: u64,
+    pub refresh_token_ttl_in_secs: u64,
+}
+
+struct CachedRevokedSessions {
+    sessions: HashSet<String>,
+    refreshed_at: Instant,
+}
+
+struct CachedUser {
+    user: UserInfo,
+    refreshed_at: Instant,
+}
+
+/// Issues and verifies the session tokens of the http handler.
+///
+/// A token is a HS256 JWT whose subject is the user name and id is the session id.
+/// Verifying a session token only needs the signing key and the cached revoked sessions,
+/// the meta is only accessed when the caches expire.
+pub struct SessionTokenManager {
+    // None if the node shares the meta with other nodes but has no secret.
+    key: Option<HS256Key>,
+    session_token_ttl: Duration,
+    refresh_token_ttl: Duration,
+    // tenant -> revoked session ids
+    revoked_sessions: RwLock<HashMap<String, CachedRevokedSessions>>,
+    // (tenant, user, client ip) -> user info
+    users: RwLock<HashMap<(String, String, Option<String>), CachedUser>>,
+}
+
+impl SessionTokenManager {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(SessionTokenManager::create(cfg)?));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SessionTokenManager> {
+        GlobalInstance::get()
+    }
+
+    fn create(cfg: &InnerConfig) -> Result<SessionTokenManager> {
+        let secret = &cfg.query.http_session_token_secret;
+        let key = if !secret.is_empty() {
+            if secret.len() < MIN_SECRET_LENGTH {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "http_session_token_secret must be at least {} bytes, got {} bytes",
+                    MIN_SECRET_LENGTH,
+                    secret.len()
+                )));
+            }
+            Some(HS256Key::from_bytes(secret.as_bytes()))
+        } else if cfg.meta.endpoints.is_empty() {
+            // a standalone node, the tokens only need to be valid on this node.
+            Some(HS256Key::generate())
+        } else {
+            warn!(
+                "http_session_token_secret is not set, the session tokens are disabled on the nodes of cluster {}",
+                cfg.query.cluster_id
+            );
+            None
+        };
+        Ok(SessionTokenManager {
+            key,
+            session_token_ttl: Duration::from_secs(cfg.query.http_session_token_ttl_secs),
+            refresh_token_ttl: Duration::from_secs(cfg.query.http_refresh_token_ttl_secs),
+            revoked_sessions: RwLock::new(HashMap::new()),
+            users: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Issues the tokens of a new session for an authenticated user.
+    #[async_backtrace::framed]
+    pub async fn issue_tokens(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+    ) -> Result<SessionTokenPair> {
+        check_password_policy(tenant, user_info).await?;
+        let user = &user_info.name;
+        let session_id = Uuid::new_v4().to_string();
+        let refresh_expire_at = unix_now() + self.refresh_token_ttl.as_secs();
+        let refresh_token = self.sign(
+            tenant,
+            user,
+            &session_id,
+            TokenType::Refresh,
+            refresh_expire_at,
+            refresh_expire_at,
+        )?;
+        self.issue_session_token(tenant, user, &session_id, refresh_token, refresh_expire_at)
+    }
+
+    /// Issues a new session token with a valid refresh token.
+    ///
+    /// Unlike the session tokens, the revocation of the refresh token is always checked against meta.
+    #[async_backtrace::framed]
+    pub async fn refresh(&self, refresh_token: &str) -> Result<SessionTokenPair> {
+        let claims = self.verify(refresh_token, TokenType::Refresh)?;
+        let (tenant, user, session_id) = claims_identity(&claims)?;
+        let session_token_api =
+            UserApiProvider::instance().get_session_token_api_client(&tenant)?;
+        if session_token_api.is_session_revoked(&session_id).await? {
+            return Err(ErrorCode::SessionTokenRevoked("refresh token is revoked"));
+        }
+        // the user may have been dropped, locked or its password expired after login.
+        let user_info = UserApiProvider::instance()
+            .get_user(&tenant, UserIdentity::new(&user, "%"))
+            .await
+            .map_err(|e| ErrorCode::AuthenticateFailure(e.message()))?;
+        check_password_policy(&tenant, &user_info).await?;
+
+        self.issue_session_token(
+            &tenant,
+            &user,
+            &session_id,
+            refresh_token.to_string(),
+            claims.custom.refresh_expire_at,
+        )
+    }
+
+    /// Revokes the session of the token, both its session token and refresh token are rejected after that.
+    #[async_backtrace::framed]
+    pub async fn revoke(&self, token: &str) -> Result<()> {
+        let claims = self.decode(token)?;
+        let (tenant, _, session_id) = claims_identity(&claims)?;
+        if claims.custom.refresh_expire_at <= unix_now() {
+            // all the tokens of the session have expired.
+            return Ok(());
+        }
+        let session_token_api =
+            UserApiProvider::instance().get_session_token_api_client(&tenant)?;
+        session_token_api
+            .revoke_session(&session_id, claims.custom.refresh_expire_at)
+            .await?;
+        if let Some(cached) = self.revoked_sessions.write().get_mut(&tenant) {
+            cached.sessions.insert(session_id);
+        }
+        Ok(())
+    }
+
+    /// Authenticates a request by its session token, returns the tenant and the user of the session.
+    #[async_backtrace::framed]
+    pub async fn authenticate(
+        &self,
+        token: &str,
+        client_ip: Option<&str>,
+    ) -> Result<(String, UserInfo)> {
+        let claims = self.verify(token, TokenType::Session)?;
+        let (tenant, user, session_id) = claims_identity(&claims)?;
+        if self.is_session_revoked(&tenant, &session_id).await? {
+            return Err(ErrorCode::SessionTokenRevoked("session token is revoked"));
+        }
+        let user = self.get_user(&tenant, &user, client_ip).await?;
+        Ok((tenant, user))
+    }
+
+    /// Checks the signature, type and expiry of the token.
+    pub fn verify(
+        &self,
+        token: &str,
+        token_type: TokenType,
+    ) -> Result<JWTClaims<SessionTokenClaims>> {
+        let claims = self.decode(token)?;
+        if claims.custom.token_type != token_type {
+            return Err(ErrorCode::InvalidSessionToken(format!(
+                "expect {:?} token, but got {:?} token",
+                token_type, claims.custom.token_type
+            )));
+        }
+        if claims.custom.expire_at <= unix_now() {
+            return Err(match token_type {
+                TokenType::Session => ErrorCode::SessionTokenExpired("session token expired"),
+                TokenType::Refresh => ErrorCode::RefreshTokenExpired("refresh token expired"),
+            });
+        }
+        Ok(claims)
+    }
+
+    fn decode(&self, token: &str) -> Result<JWTClaims<SessionTokenClaims>> {
+        let jwt = token
+            .strip_prefix(SESSION_TOKEN_PREFIX)
+            .ok_or_else(|| ErrorCode::InvalidSessionToken("not a databend session token"))?;
+        self.key()?
+            .verify_token::<SessionTokenClaims>(jwt, None)
+            .map_err(|e| ErrorCode::InvalidSessionToken(format!("invalid session token: {}", e)))
+    }
+
+    // A token signed with a random key of one node would be rejected by the other nodes.
+    fn key(&self) -> Result<&HS256Key> {
+        self.key.as_ref().ok_or_else(|| {
+            ErrorCode::InvalidConfig(
+                "http_session_token_secret must be set to use session tokens on a cluster",
+            )
+        })
+    }
+
+    fn issue_session_token(
+        &self,
+        tenant: &str,
+        user: &str,
+        session_id: &str,
+        refresh_token: String,
+        refresh_expire_at: u64,
+    ) -> Result<SessionTokenPair> {
+        let now = unix_now();
+        // a session token never outlives its refresh token.
+        let expire_at = (now + self.session_token_ttl.as_secs()).min(refresh_expire_at);
+        let session_token = self.sign(
+            tenant,
+            user,
+            session_id,
+            TokenType::Session,
+            expire_at,
+            refresh_expire_at,
+        )?;
+        Ok(SessionTokenPair {
+            session_token,
+            refresh_token,
+            session_token_ttl_in_secs: expire_at.saturating_sub(now),
+            refresh_token_ttl_in_secs: refresh_expire_at.saturating_sub(now),
+        })
+    }
+
+    fn sign(
+        &self,
+        tenant: &str,
+        user: &str,
+        session_id: &str,
+        token_type: TokenType,
+        expire_at: u64,
+        refresh_expire_at: u64,
+    ) -> Result<String> {
+        let claims = JWTClaims {
+            issued_at: Some(Clock::now_since_epoch()),
+            // expiry is checked by `verify` to report it with a dedicated error code.
+            expires_at: None,
+            invalid_before: None,
+            audiences: None,
+            issuer: None,
+            jwt_id: Some(session_id.to_string()),
+            subject: Some(user.to_string()),
+            nonce: None,
+            custom: SessionTokenClaims {
+                tenant: tenant.to_string(),
+                token_type,
+                expire_at,
+                refresh_expire_at,
+            },
+        };
+        let jwt = self
+            .key()?
+            .authenticate(claims)
+            .map_err(|e| ErrorCode::Internal(format!("fail to sign session token: {}", e)))?;
+        Ok(format!("{}{}", SESSION_TOKEN_PREFIX, jwt))
+    }
+
+    #[async_backtrace::framed]
+    async fn is_session_revoked(&self, tenant: &str, session_id: &str) -> Result<bool> {
+        if let Some(cached) = self.revoked_sessions.read().get(tenant) {
+            if cached.refreshed_at.elapsed() < CACHE_REFRESH_INTERVAL {
+                return Ok(cached.sessions.contains(session_id));
+            }
+        }
+
+        let session_token_api = UserApiProvider::instance().get_session_token_api_client(tenant)?;
+        let sessions: HashSet<String> = session_token_api
+            .get_revoked_sessions()
+            .await?
+            .into_iter()
+            .collect();
+        let revoked = sessions.contains(session_id);
+        self.revoked_sessions
+            .write()
+            .insert(tenant.to_string(), CachedRevokedSessions {
+                sessions,
+                refreshed_at: Instant::now(),
+            });
+        Ok(revoked)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_user(
+        &self,
+        tenant: &str,
+        user: &str,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
+        let key = (
+            tenant.to_string(),
+            user.to_string(),
+            client_ip.map(|s| s.to_string()),
+        );
+        if let Some(cached) = self.users.read().get(&key) {
+            if cached.refreshed_at.elapsed() < CACHE_REFRESH_INTERVAL {
+                return Ok(cached.user.clone());
+            }
+        }
+
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(tenant, UserIdentity::new(user, "%"), client_ip)
+            .await
+            .map_err(|e| match e.code() {
+                ErrorCode::META_SERVICE_ERROR => e,
+                _ => ErrorCode::AuthenticateFailure(e.message()),
+            })?;
+        self.users.write().insert(key, CachedUser {
+            user: user_info.clone(),
+            refreshed_at: Instant::now(),
+        });
+        Ok(user_info)
+    }
+}
+
+// The password authentication only allows the users whose passwords are expired to change
+// the password, which can't be enforced on the sessions of the tokens, so the tokens are
+// not issued or refreshed for them.
+#[async_backtrace::framed]
+async fn check_password_policy(tenant: &str, user_info: &UserInfo) -> Result<()> {
+    let must_change_password = UserApiProvider::instance()
+        .check_login_without_password(tenant, user_info)
+        .await?;
+    if must_change_password {
+        return Err(ErrorCode::PasswordMustBeChanged(format!(
+            "password of user `{}` must be changed before using session tokens",
+            user_info.name
+        )));
+    }
+    Ok(())
+}
+
+fn claims_identity(claims: &JWTClaims<SessionTokenClaims>) -> Result<(String, String, String)> {
+    match (&claims.subject, &claims.jwt_id) {
+        (Some(user), Some(session_id)) => Ok((
+            claims.custom.tenant.clone(),
+            user.clone(),
+            session_id.clone(),
+        )),
+        _ => Err(ErrorCode::InvalidSessionToken(
+            "missing user or session id in session token",
+        )),
+    }
+}
+
+fn unix_now() -> u64 {
+    Clock::now_since_epoch().as_secs()
+}
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
 use common_base::base::tokio::io::AsyncWrite;
@@ -29,6 +31,7 @@ use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::UserIdentity;
 use common_metrics::mysql::*;
+use common_sql::plans::Plan;
 use common_sql::Planner;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
@@ -48,10 +51,13 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::writers::encode_session_state_info;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
 use crate::servers::mysql::writers::QueryResult;
+use crate::servers::mysql::writers::ResultSetColumns;
+use crate::servers::mysql::writers::SessionStateChange;
 use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
 use crate::sessions::QueryContext;
@@ -59,6 +65,9 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// https://dev.mysql.com/doc/dev/mysql-server/latest/group__group__cs__capabilities__flags.html
+const CLIENT_MULTI_STATEMENTS: u32 = 1 << 16;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
 }
@@ -68,6 +77,8 @@ pub struct InteractiveWorker {
     version: String,
     salt: [u8; 20],
     client_addr: String,
+    // Whether the client negotiated CLIENT_MULTI_STATEMENTS.
+    multi_statements: bool,
 }
 
 #[async_trait::async_trait]
@@ -203,26 +214,42 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
                 ));
             }
 
-            let mut writer = DFQueryResultWriter::create(writer);
-
-            let instant = Instant::now();
-            let query_result = self
-                .base
-                .do_query(query)
-                .await
-                .map_err(|err| err.display_with_sql(query));
-
-            let format = self.base.session.get_format_settings();
-
-            let mut write_result = writer.write(query_result, &format).await;
+            // Each statement of a multi-statement request is replied with its own result set.
+            let queries = match self.multi_statements {
+                true => split_statements(query),
+                false => vec![query],
+            };
+            let columns = queries
+                .iter()
+                .map(|_| ResultSetColumns::new())
+                .collect::<Vec<_>>();
+            let mut writer = DFQueryResultWriter::create(writer, &columns);
+
+            for query in queries {
+                let instant = Instant::now();
+                let query_result = self
+                    .base
+                    .do_query(query)
+                    .await
+                    .map_err(|err| err.display_with_sql(query));
+
+                let format = self.base.session.get_format_settings();
+
+                let write_result = writer.write(query_result, &format).await;
+                observe_mysql_process_request_duration(instant.elapsed());
+
+                if let Err(cause) = write_result {
+                    let suffix = format!("(while in query {})", query);
+                    return Err(cause.add_message_back(suffix));
+                }
 
-            if let Err(cause) = write_result {
-                let suffix = format!("(while in query {})", query);
-                write_result = Err(cause.add_message_back(suffix));
+                // Like MySQL, the rest statements are not executed after an error.
+                if !writer.is_open() {
+                    break;
+                }
             }
-            observe_mysql_process_request_duration(instant.elapsed());
 
-            write_result
+            Ok(())
         }
         .in_span(root)
         .await
@@ -258,13 +285,19 @@ impl InteractiveWorkerBase {
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        // the client address is `ip:port`, which is parsed as a socket address, so that
+        // the colons of IPv6 addresses are not mistaken for the port separator.
+        let client_ip = info.user_client_address.as_str();
         let user_info = UserApiProvider::instance()
             .get_user_with_client_ip(&ctx.get_tenant(), identity, Some(client_ip))
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
+        let must_change_password = UserApiProvider::instance()
+            .check_login_password(&ctx.get_tenant(), &user_info, authed)
+            .await?;
         if authed {
+            self.session.set_must_change_password(must_change_password);
             self.session.set_authed_user(user_info, None).await?;
         }
         Ok(authed)
@@ -352,6 +385,8 @@ impl InteractiveWorkerBase {
                 let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
 
                 let has_result_set = plan.has_result_set();
+                let session_state_info =
+                    encode_session_state_info(&Self::session_state_changes(&plan));
 
                 match interpreter {
                     Ok(interpreter) => {
@@ -366,7 +401,8 @@ impl InteractiveWorkerBase {
                                 has_result_set,
                                 schema,
                                 query.to_string(),
-                            ),
+                            )
+                            .with_session_state_info(session_state_info),
                             Some(format),
                         ))
                     }
@@ -379,6 +415,23 @@ impl InteractiveWorkerBase {
         }
     }
 
+    // The session state changed by the plan.
+    fn session_state_changes(plan: &Plan) -> Vec<SessionStateChange> {
+        match plan {
+            Plan::UseDatabase(plan) => vec![SessionStateChange::Schema(plan.database.clone())],
+            Plan::SetVariable(plan) => plan
+                .vars
+                .iter()
+                .filter(|var| !var.is_global)
+                .map(|var| SessionStateChange::SystemVariable {
+                    name: var.variable.clone(),
+                    value: var.value.clone(),
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn exec_query(
@@ -433,6 +486,35 @@ impl InteractiveWorkerBase {
     }
 }
 
+/// Splits the statements of a multi-statement request by the semicolons out of the
+/// literals and comments. The request is not split if it can't be tokenized, the error
+/// will be reported by the parser.
+fn split_statements(query: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut start = 0;
+    for token in Tokenizer::new(query) {
+        let Ok(token) = token else {
+            return vec![query];
+        };
+        if token.kind == TokenKind::SemiColon {
+            let statement = query[start..token.span.start()].trim();
+            if !statement.is_empty() {
+                statements.push(statement);
+            }
+            start = token.span.end();
+        }
+    }
+    let statement = query[start..].trim();
+    if !statement.is_empty() {
+        statements.push(statement);
+    }
+
+    match statements.len() {
+        0 | 1 => vec![query],
+        _ => statements,
+    }
+}
+
 impl InteractiveWorker {
     pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
         let mut bs = vec![0u8; 20];
@@ -452,8 +534,14 @@ impl InteractiveWorker {
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
+            multi_statements: false,
         }
     }
+
+    /// Sets the capabilities of the client from the handshake response.
+    pub fn set_client_capabilities(&mut self, capabilities: u32) {
+        self.multi_statements = capabilities & CLIENT_MULTI_STATEMENTS != 0;
+    }
 }
 
 struct ContextProgressReporter {
@@ -80,6 +80,8 @@ impl MySQLConnection {
                     &tls,
                 )
                 .await?;
+                // The capabilities of the handshake response, the third of the init params.
+                interactive_worker.set_client_capabilities(init_params.2.bits());
 
                 match tls {
                     Some(config) if use_ssl => {
@@ -16,6 +16,9 @@ mod init_result_writer;
 mod query_result_writer;
 
 pub use self::init_result_writer::DFInitResultWriter;
+pub use self::query_result_writer::encode_session_state_info;
 pub use self::query_result_writer::DFQueryResultWriter;
 pub use self::query_result_writer::ProgressReporter;
 pub use self::query_result_writer::QueryResult;
+pub use self::query_result_writer::ResultSetColumns;
+pub use self::query_result_writer::SessionStateChange;
@@ -27,6 +27,8 @@ use common_formats::field_encoder::FieldEncoderValues;
 use common_io::prelude::FormatSettings;
 use futures_util::StreamExt;
 use log::error;
+use log::warn;
+use once_cell::sync::OnceCell;
 use opensrv_mysql::*;
 
 /// Reports progress information as string, intend to be put into the mysql Ok packet.
@@ -39,12 +41,91 @@ pub trait ProgressReporter {
     fn affected_rows(&self) -> u64;
 }
 
+/// A change of the session state, reported to the client with the session tracking of the
+/// OK packet, so that the proxies and the connection pools can follow the state of the session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionStateChange {
+    /// `SET` of a session variable.
+    SystemVariable { name: String, value: String },
+    /// `USE` of a database.
+    Schema(String),
+}
+
+impl SessionStateChange {
+    // https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_ok_packet.html
+    const SESSION_TRACK_SYSTEM_VARIABLES: u8 = 0x00;
+    const SESSION_TRACK_SCHEMA: u8 = 0x01;
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let mut data = vec![];
+        let typ = match self {
+            SessionStateChange::SystemVariable { name, value } => {
+                write_lenenc_str(&mut data, name.as_bytes());
+                write_lenenc_str(&mut data, value.as_bytes());
+                Self::SESSION_TRACK_SYSTEM_VARIABLES
+            }
+            SessionStateChange::Schema(schema) => {
+                write_lenenc_str(&mut data, schema.as_bytes());
+                Self::SESSION_TRACK_SCHEMA
+            }
+        };
+        buf.push(typ);
+        write_lenenc_str(buf, &data);
+    }
+}
+
+fn write_lenenc_int(buf: &mut Vec<u8>, n: u64) {
+    if n < 251 {
+        buf.push(n as u8);
+    } else if n < 1 << 16 {
+        buf.push(0xfc);
+        buf.extend_from_slice(&(n as u16).to_le_bytes());
+    } else if n < 1 << 24 {
+        buf.push(0xfd);
+        buf.extend_from_slice(&(n as u32).to_le_bytes()[..3]);
+    } else {
+        buf.push(0xfe);
+        buf.extend_from_slice(&n.to_le_bytes());
+    }
+}
+
+fn write_lenenc_str(buf: &mut Vec<u8>, s: &[u8]) {
+    write_lenenc_int(buf, s.len() as u64);
+    buf.extend_from_slice(s);
+}
+
+/// Encodes the session state info of the OK packet.
+///
+/// The OK packet of opensrv takes the info as a `String`, so an entry whose length-encoded
+/// bytes are not valid UTF-8 can't be reported. This happens when the entry of a change is
+/// at least 128 bytes long, such a change is skipped and logged.
+pub fn encode_session_state_info(changes: &[SessionStateChange]) -> Option<String> {
+    let mut info = String::new();
+    for change in changes {
+        let mut buf = vec![];
+        change.encode(&mut buf);
+        match String::from_utf8(buf) {
+            Ok(entry) => info.push_str(&entry),
+            Err(cause) => warn!(
+                "Skip the session state change {:?}, its entry of {} bytes can't be reported by the MySQL handler",
+                change,
+                cause.as_bytes().len()
+            ),
+        }
+    }
+    match info.is_empty() {
+        true => None,
+        false => Some(info),
+    }
+}
+
 pub struct QueryResult {
     blocks: SendableDataBlockStream,
     extra_info: Option<Box<dyn ProgressReporter + Send>>,
     has_result_set: bool,
     schema: DataSchemaRef,
     sql: String,
+    session_state_info: Option<String>,
 }
 
 impl QueryResult {
@@ -61,12 +142,24 @@ impl QueryResult {
             has_result_set,
             schema,
             sql,
+            session_state_info: None,
         }
     }
+
+    /// Sets the session state info encoded by [`encode_session_state_info`].
+    pub fn with_session_state_info(mut self, info: Option<String>) -> Self {
+        self.session_state_info = info;
+        self
+    }
 }
 
+/// The columns of a result set, which are borrowed by the rows writer until the result set
+/// is finished, so they are kept by the caller of the writer for the whole multi-results.
+pub type ResultSetColumns = OnceCell<Vec<Column>>;
+
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
+    columns: std::slice::Iter<'a, ResultSetColumns>,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -83,8 +176,21 @@ fn write_field<W: AsyncWrite + Unpin>(
 }
 
 impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
-    pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+    /// Creates a writer of `columns.len()` result sets.
+    pub fn create(
+        inner: QueryResultWriter<'a, W>,
+        columns: &'a [ResultSetColumns],
+    ) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            columns: columns.iter(),
+        }
+    }
+
+    /// Whether the writer can write more result sets, it is closed after the last
+    /// result set or an error is written.
+    pub fn is_open(&self) -> bool {
+        self.inner.is_some()
     }
 
     #[async_backtrace::framed]
@@ -94,13 +200,17 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         format: &FormatSettings,
     ) -> Result<()> {
         if let Some(writer) = self.inner.take() {
+            let Some(columns) = self.columns.next() else {
+                return Err(ErrorCode::Internal(
+                    "No more result sets are expected by the MySQL writer",
+                ));
+            };
+            let more_results = !self.columns.as_slice().is_empty();
             match query_result {
                 Ok((query_result, query_format)) => {
-                    if let Some(format) = query_format {
-                        Self::ok(query_result, writer, &format).await?
-                    } else {
-                        Self::ok(query_result, writer, format).await?
-                    }
+                    let format = query_format.as_ref().unwrap_or(format);
+                    self.inner =
+                        Self::ok(query_result, writer, columns, more_results, format).await?;
                 }
                 Err(error) => Self::err(&error, writer).await?,
             }
@@ -108,12 +218,15 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         Ok(())
     }
 
+    /// Returns the writer of the next result set if `more_results` and no error is written.
     #[async_backtrace::framed]
     async fn ok(
         mut query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
+        result_set_columns: &'a ResultSetColumns,
+        more_results: bool,
         format: &FormatSettings,
-    ) -> Result<()> {
+    ) -> Result<Option<QueryResultWriter<'a, W>>> {
         // XXX: num_columns == 0 may is error?
         if !query_result.has_result_set {
             // For statements without result sets, we still need to pull the stream because errors may occur in the stream.
@@ -132,7 +245,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                         )
                         .await?;
 
-                    return Ok(());
+                    return Ok(None);
                 }
             }
 
@@ -140,13 +253,19 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 .extra_info
                 .map(|r| r.affected_rows())
                 .unwrap_or_default();
-            dataset_writer
-                .completed(OkResponse {
-                    affected_rows,
-                    ..Default::default()
-                })
-                .await?;
-            return Ok(());
+            let mut ok_response = OkResponse {
+                affected_rows,
+                ..Default::default()
+            };
+            if let Some(info) = query_result.session_state_info {
+                ok_response.status_flags |= StatusFlags::SERVER_SESSION_STATE_CHANGED;
+                ok_response.session_state_info = info;
+            }
+            if more_results {
+                return Ok(Some(dataset_writer.complete_one(ok_response).await?));
+            }
+            dataset_writer.completed(ok_response).await?;
+            return Ok(None);
         }
 
         fn convert_field_type(field: &DataField) -> Result<ColumnType> {
@@ -198,9 +317,13 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
 
         let _tz = format.timezone;
         match convert_schema(&query_result.schema) {
-            Err(error) => Self::err(&error, dataset_writer).await,
+            Err(error) => {
+                Self::err(&error, dataset_writer).await?;
+                Ok(None)
+            }
             Ok(columns) => {
-                let mut row_writer = dataset_writer.start(&columns).await?;
+                let columns = result_set_columns.get_or_init(|| columns);
+                let mut row_writer = dataset_writer.start(columns).await?;
                 let blocks = &mut query_result.blocks;
 
                 while let Some(block) = blocks.next().await {
@@ -213,7 +336,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     &e.display_with_sql(&query_result.sql).to_string().as_bytes(),
                                 )
                                 .await?;
-                            return Ok(());
+                            return Ok(None);
                         }
                         Ok(block) => block,
                     };
@@ -291,13 +414,17 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     }
                 }
 
+                if more_results {
+                    return Ok(Some(row_writer.finish_one().await?));
+                }
+
                 let info = query_result
                     .extra_info
                     .map(|r| r.progress_info())
                     .unwrap_or_default();
                 row_writer.finish_with_info(&info).await?;
 
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -30,6 +30,7 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use chrono_tz::Tz;
+use common_base::base::tokio;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
@@ -42,6 +43,7 @@ use common_catalog::plan::StageTableInfo;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::MaterializedCtesBlocks;
+use common_catalog::table_context::NodeProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
@@ -85,12 +87,18 @@ use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
 use log::debug;
 use log::info;
+use log::warn;
 use parking_lot::RwLock;
 use storages_common_table_meta::meta::Location;
 
 use crate::api::DataExchangeManager;
+use crate::api::ExchangeMetrics;
+use crate::api::JoinSkewState;
+use crate::api::JoinSkews;
+use crate::api::ProcessesInfoPacket;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::interpreters::AuditObject;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -100,10 +108,13 @@ use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
+use crate::write_buffer::TableKey;
+use crate::write_buffer::WriteBufferManager;
 
 const MYSQL_VERSION: &str = "8.0.26";
 const CLICKHOUSE_VERSION: &str = "8.12.14";
 const COPIED_FILES_FILTER_BATCH_SIZE: usize = 1000;
+const CLUSTER_PROCESSES_INFO_TIMEOUT_SECS: u64 = 5;
 
 #[derive(Clone)]
 pub struct QueryContext {
@@ -277,6 +288,14 @@ impl QueryContext {
         ua.clone()
     }
 
+    pub fn set_query_tag(&self, query_tag: String) {
+        *self.shared.query_tag.write() = query_tag;
+    }
+
+    pub fn get_query_tag(&self) -> String {
+        self.shared.get_query_tag()
+    }
+
     pub fn get_query_duration_ms(&self) -> i64 {
         let query_start_time = convert_query_log_timestamp(self.shared.created_time);
         let finish_time = *self.shared.finish_time.read();
@@ -296,6 +315,37 @@ impl QueryContext {
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    /// Attach the object changed by the query, it will be audited when the query finishes.
+    pub fn set_audit_object(&self, object: AuditObject) {
+        *self.shared.audit_object.write() = Some(object);
+    }
+
+    pub fn take_audit_object(&self) -> Option<AuditObject> {
+        self.shared.audit_object.write().take()
+    }
+
+    pub fn get_exchange_metrics(&self) -> Arc<ExchangeMetrics> {
+        self.shared.exchange_metrics.clone()
+    }
+
+    pub fn get_join_skews(&self) -> Arc<JoinSkews> {
+        self.shared.join_skews.clone()
+    }
+
+    /// Get the skew state of the join on this node, shared by the exchanges of both sides.
+    pub fn get_join_skew_state(
+        &self,
+        join_id: u32,
+        scatter_size: usize,
+    ) -> Result<Arc<JoinSkewState>> {
+        let settings = self.get_settings();
+        let threshold = settings.get_join_skew_threshold()?;
+        let detection_rows = settings.get_join_skew_detection_rows()?;
+        Ok(self.shared.join_skews.get_or_create_state(join_id, || {
+            JoinSkewState::create(scatter_size, threshold, detection_rows)
+        }))
+    }
 }
 
 #[async_trait::async_trait]
@@ -521,6 +571,10 @@ impl TableContext for QueryContext {
         self.get_current_session().get_all_available_roles().await
     }
 
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>> {
+        self.get_current_session().get_all_effective_roles().await
+    }
+
     fn get_current_session_id(&self) -> String {
         self.get_current_session().get_id()
     }
@@ -570,6 +624,13 @@ impl TableContext for QueryContext {
 
         let query_config = &GlobalConfig::instance().query;
 
+        let function_result_cache = if self.get_settings().get_enable_function_result_cache()? {
+            let max_bytes = self.get_settings().get_function_result_cache_max_bytes()?;
+            Some(self.shared.get_function_result_cache(max_bytes))
+        } else {
+            None
+        };
+
         Ok(FunctionContext {
             tz,
             rounding_mode,
@@ -583,6 +644,8 @@ impl TableContext for QueryContext {
 
             external_server_connect_timeout_secs,
             external_server_request_timeout_secs,
+
+            function_result_cache,
         })
     }
 
@@ -614,6 +677,60 @@ impl TableContext for QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    #[async_backtrace::framed]
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>> {
+        let cluster = self.get_cluster();
+        let mut processes_info = SessionManager::instance()
+            .processes_info()
+            .iter()
+            .map(|process_info| NodeProcessInfo::create(&cluster.local_id, process_info))
+            .collect::<Vec<_>>();
+
+        // A slow node should not hang the query, its processes are just missing in the result.
+        let config = GlobalConfig::instance();
+        let timeout = min(
+            self.get_settings().get_flight_client_timeout()?,
+            CLUSTER_PROCESSES_INFO_TIMEOUT_SECS,
+        );
+        let requests = cluster
+            .nodes
+            .iter()
+            .filter(|node| node.id != cluster.local_id)
+            .map(|node| {
+                let packet = ProcessesInfoPacket::create(node.clone());
+                let config = config.clone();
+                async move {
+                    let res = tokio::time::timeout(
+                        Duration::from_secs(timeout),
+                        packet.fetch(&config, timeout),
+                    )
+                    .await;
+                    (packet.executor.id.clone(), res)
+                }
+            });
+
+        for (node_id, res) in futures::future::join_all(requests).await {
+            let cause = match res {
+                Ok(Ok(node_processes_info)) => {
+                    processes_info.extend(node_processes_info);
+                    continue;
+                }
+                Ok(Err(cause)) => cause.to_string(),
+                Err(_) => format!("timeout after {} seconds", timeout),
+            };
+            warn!(
+                "Failed to get processes info of node {}: {}",
+                node_id, cause
+            );
+            self.push_warning(format!(
+                "processes of node {} are missing: {}",
+                node_id, cause
+            ));
+        }
+
+        Ok(processes_info)
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -711,6 +828,17 @@ impl TableContext for QueryContext {
         database: &str,
         table: &str,
     ) -> Result<Arc<dyn Table>> {
+        // The scans read the committed snapshots, the buffered rows are committed to be seen.
+        if self.get_settings().get_enable_write_buffer_read()? {
+            let key = TableKey {
+                tenant: self.get_tenant(),
+                catalog: catalog.to_string(),
+                database: database.to_string(),
+                table: table.to_string(),
+            };
+            WriteBufferManager::instance().flush_table(&key).await?;
+        }
+
         let table = self.shared.get_table(catalog, database, table).await?;
         // the better place to do this is in the QueryContextShared::get_table_to_cache() method,
         // but there is no way to access dyn TableContext.
@@ -28,6 +28,7 @@ use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::FunctionResultCache;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserDefinedConnection;
@@ -38,13 +39,17 @@ use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::MergeStatus;
 use common_storage::StorageMetrics;
+use common_storages_fuse::FuseTable;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
+use crate::api::ExchangeMetrics;
+use crate::api::JoinSkews;
 use crate::clusters::Cluster;
+use crate::interpreters::AuditObject;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
@@ -103,8 +108,18 @@ pub struct QueryContextShared {
 
     // Client User-Agent
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
+    /// The sanitized `query_tag` setting of the query.
+    pub(in crate::sessions) query_tag: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// The object changed by the DDL or grant/revoke query, for audit.
+    pub(in crate::sessions) audit_object: Arc<RwLock<Option<AuditObject>>>,
+    /// Rows/bytes moved by the exchanges of the query, merged from all nodes on the coordinator.
+    pub(in crate::sessions) exchange_metrics: Arc<ExchangeMetrics>,
+    /// Hot keys of the skew-tolerant joins of the query.
+    pub(in crate::sessions) join_skews: Arc<JoinSkews>,
+    /// Results of the expensive deterministic functions of the query, created on first use.
+    pub(in crate::sessions) function_result_cache: Arc<RwLock<Option<Arc<FunctionResultCache>>>>,
 }
 
 impl QueryContextShared {
@@ -143,10 +158,15 @@ impl QueryContextShared {
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
+            query_tag: Arc::new(RwLock::new(String::new())),
+            function_result_cache: Arc::new(RwLock::new(None)),
             materialized_cte_tables: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            audit_object: Arc::new(RwLock::new(None)),
+            exchange_metrics: Arc::new(ExchangeMetrics::default()),
+            join_skews: Arc::new(JoinSkews::default()),
         }))
     }
 
@@ -309,6 +329,15 @@ impl QueryContextShared {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // Pin the snapshot of the table, so that all the scans of the table in the same query,
+        // e.g. self-join, subqueries of DML and the source of MERGE, read the same snapshot.
+        let cache_table = match FuseTable::try_from_table(cache_table.as_ref()) {
+            Ok(fuse_table) => match fuse_table.pin_snapshot().await? {
+                Some(pinned) => pinned as Arc<dyn Table>,
+                None => cache_table,
+            },
+            Err(_) => cache_table,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -419,6 +448,18 @@ impl QueryContextShared {
         status.clone()
     }
 
+    pub fn get_function_result_cache(&self, memory_budget: usize) -> Arc<FunctionResultCache> {
+        let mut function_result_cache = self.function_result_cache.write();
+        function_result_cache
+            .get_or_insert_with(|| FunctionResultCache::create(memory_budget))
+            .clone()
+    }
+
+    pub fn get_query_tag(&self) -> String {
+        let query_tag = self.query_tag.read();
+        query_tag.clone()
+    }
+
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
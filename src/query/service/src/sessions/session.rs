@@ -26,10 +26,13 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::Settings;
 use common_users::GrantObjectVisibilityChecker;
+use common_users::UserApiProvider;
 use log::debug;
+use log::warn;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterHelper;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManagerImpl;
@@ -39,6 +42,7 @@ use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
 use crate::sessions::SessionType;
+use crate::sessions::TableContext;
 
 pub struct Session {
     pub(in crate::sessions) id: String,
@@ -132,11 +136,41 @@ impl Session {
         let config = GlobalConfig::instance();
         let session = self.clone();
         let cluster = ClusterDiscovery::instance().discover(&config).await?;
+
+        // The fragments received from the coordinator are already placed on the warehouse.
+        let mut warning = None;
+        let settings = self.get_settings();
+        let warehouse = settings.get_warehouse()?;
+        let cluster = match self.get_type() {
+            SessionType::FlightRPC => cluster,
+            _ if warehouse.is_empty() => cluster,
+            _ => match cluster.route_to_warehouse(&warehouse) {
+                Some(warehouse_cluster) => warehouse_cluster,
+                None if settings.get_enable_strict_warehouse_routing()? => {
+                    return Err(ErrorCode::NotFoundClusterNode(format!(
+                        "No node of the cluster is labeled with the warehouse \"{}\"",
+                        warehouse
+                    )));
+                }
+                None => {
+                    warning = Some(format!(
+                        "No node of the cluster is labeled with the warehouse \"{}\", the query runs on all the nodes",
+                        warehouse
+                    ));
+                    cluster
+                }
+            },
+        };
         let shared = QueryContextShared::try_create(session, cluster)?;
 
         self.session_ctx
             .set_query_context_shared(Arc::downgrade(&shared));
-        Ok(QueryContext::create_from_shared(shared))
+        let ctx = QueryContext::create_from_shared(shared);
+        if let Some(warning) = warning {
+            warn!("{}", warning);
+            ctx.push_warning(warning);
+        }
+        Ok(ctx)
     }
 
     // only used for values and mysql output
@@ -182,6 +216,14 @@ impl Session {
         self.privilege_mgr.get_current_user()
     }
 
+    pub fn get_must_change_password(self: &Arc<Self>) -> bool {
+        self.session_ctx.get_must_change_password()
+    }
+
+    pub fn set_must_change_password(self: &Arc<Self>, v: bool) {
+        self.session_ctx.set_must_change_password(v);
+    }
+
     // set_authed_user() is called after authentication is passed in various protocol handlers, like
     // HTTP handler, clickhouse query handler, mysql query handler. restricted_role represents the role
     // granted by external authenticator, it will over write the current user's granted roles, and
@@ -194,7 +236,28 @@ impl Session {
     ) -> Result<()> {
         self.privilege_mgr
             .set_authed_user(user, restricted_role)
-            .await
+            .await?;
+        self.apply_settings_profiles().await
+    }
+
+    // The settings profiles are applied as the defaults of the session, the profile of the
+    // current role is applied before the profile of the user, so the user's one wins.
+    #[async_backtrace::framed]
+    async fn apply_settings_profiles(self: &Arc<Self>) -> Result<()> {
+        let role_profile = self
+            .get_current_role()
+            .and_then(|role| role.settings_profile);
+        let user_profile = self.get_current_user()?.option.settings_profile().cloned();
+
+        let tenant = self.get_current_tenant();
+        let settings = self.get_settings();
+        for name in [role_profile, user_profile].into_iter().flatten() {
+            let settings_profile = UserApiProvider::instance()
+                .get_settings_profile(&tenant, &name)
+                .await?;
+            settings.apply_settings_profile(&settings_profile);
+        }
+        Ok(())
     }
 
     #[async_backtrace::framed]
@@ -240,6 +303,13 @@ impl Session {
         self.privilege_mgr.get_all_available_roles().await
     }
 
+    // Returns the roles that take effect on the privilege checks of the current session, which
+    // are the current role and the secondary roles, with all their related roles.
+    #[async_backtrace::framed]
+    pub async fn get_all_effective_roles(self: &Arc<Self>) -> Result<Vec<RoleInfo>> {
+        self.privilege_mgr.get_all_effective_roles().await
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_privilege(
         self: &Arc<Self>,
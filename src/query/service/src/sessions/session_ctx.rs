@@ -57,6 +57,9 @@ pub struct SessionContext {
     secondary_roles: RwLock<Option<Vec<String>>>,
     // The client IP from the client.
     client_host: RwLock<Option<SocketAddr>>,
+    // The password of the current user is expired, only changing the password is allowed
+    // until it is changed.
+    must_change_password: AtomicBool,
     io_shutdown_tx: RwLock<Option<Box<dyn FnOnce() + Send + Sync + 'static>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
@@ -76,6 +79,7 @@ impl SessionContext {
             secondary_roles: Default::default(),
             current_tenant: Default::default(),
             client_host: Default::default(),
+            must_change_password: Default::default(),
             current_catalog: RwLock::new("default".to_string()),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
@@ -204,6 +208,14 @@ impl SessionContext {
         *lock = sock
     }
 
+    pub fn get_must_change_password(&self) -> bool {
+        self.must_change_password.load(Ordering::Relaxed)
+    }
+
+    pub fn set_must_change_password(&self, v: bool) {
+        self.must_change_password.store(v, Ordering::Relaxed);
+    }
+
     pub fn set_io_shutdown_tx<F: FnOnce() + Send + Sync + 'static>(&self, f: F) {
         let mut lock = self.io_shutdown_tx.write();
 
@@ -20,6 +20,7 @@ pub use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::ProcessInfoState;
 use common_storage::StorageMetrics;
 
+use crate::api::DataExchangeManager;
 use crate::sessions::Session;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionType;
@@ -41,6 +42,10 @@ impl Session {
             }
         }
 
+        let query_id = shared_query_context
+            .as_ref()
+            .map(|shared| shared.init_query_id.read().clone());
+
         ProcessInfo {
             id: self.id.clone(),
             typ: self.get_type().to_string(),
@@ -53,11 +58,20 @@ impl Session {
             memory_usage,
             data_metrics: Self::query_data_metrics(session_ctx),
             scan_progress_value: Self::query_scan_progress_value(session_ctx),
+            write_progress_value: Self::query_write_progress_value(session_ctx),
             mysql_connection_id: self.mysql_connection_id,
             created_time: Self::query_created_time(session_ctx),
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
+            query_id: query_id.clone(),
+            query_tag: match shared_query_context {
+                Some(shared) => shared.get_query_tag(),
+                None => self.get_settings().get_query_tag().unwrap_or_default(),
+            },
+            fragment_ids: query_id
+                .map(|query_id| DataExchangeManager::instance().get_query_fragment_ids(&query_id))
+                .unwrap_or_default(),
         }
     }
 
@@ -103,6 +117,13 @@ impl Session {
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
 
+    fn query_write_progress_value(status: &SessionContext) -> Option<ProgressValues> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.write_progress.get_values())
+    }
+
     fn query_created_time(status: &SessionContext) -> SystemTime {
         match status.get_query_context_shared() {
             None => SystemTime::now(),
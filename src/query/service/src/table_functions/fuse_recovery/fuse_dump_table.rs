@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::table_functions::string_literal;
+use common_storages_fuse::table_functions::string_value;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+
+use super::manifest::check_admin;
+use super::manifest::resolve_manifest_location;
+use super::manifest::FuseTableManifest;
+
+const FUSE_DUMP: &str = "fuse_dump";
+
+/// `FUSE_DUMP('db', 't', '@stage/path')` exports the metadata of a fuse table to a
+/// manifest on the stage, see [`FuseTableManifest`].
+pub struct FuseDumpTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+    arg_location: String,
+}
+
+impl FuseDumpTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(FUSE_DUMP, Some(3))?;
+        let arg_database_name = string_value(&args[0])?;
+        let arg_table_name = string_value(&args[1])?;
+        let arg_location = string_value(&args[2])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: FUSE_DUMP.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+            arg_location,
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("manifest", TableDataType::String),
+            TableField::new("storage_prefix", TableDataType::String),
+            TableField::new("snapshot_location", TableDataType::String.wrap_nullable()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseDumpTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+            string_literal(self.arg_location.as_str()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                FuseDumpSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_database_name.clone(),
+                    self.arg_table_name.clone(),
+                    self.arg_location.clone(),
+                )
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for FuseDumpTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct FuseDumpSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_database_name: String,
+    arg_table_name: String,
+    arg_location: String,
+}
+
+impl FuseDumpSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_database_name: String,
+        arg_table_name: String,
+        arg_location: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, FuseDumpSource {
+            finish: false,
+            ctx,
+            arg_database_name,
+            arg_table_name,
+            arg_location,
+        })
+    }
+
+    async fn dump(&self) -> Result<FuseTableManifest> {
+        let table = self
+            .ctx
+            .get_table(
+                CATALOG_DEFAULT,
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = table.get_table_info();
+        if table_info.meta.storage_params.is_some() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{} only supports tables in the default storage, table {} is attached or external",
+                FUSE_DUMP, table_info.desc
+            )));
+        }
+
+        let database_id = match table_info.options().get(OPT_KEY_DATABASE_ID) {
+            Some(id) => id.parse::<u64>()?,
+            None => 0,
+        };
+        Ok(FuseTableManifest {
+            version: FuseTableManifest::VERSION,
+            database: self.arg_database_name.clone(),
+            database_id,
+            table: self.arg_table_name.clone(),
+            table_id: table_info.ident.table_id,
+            storage_prefix: FuseTable::parse_storage_prefix(table_info)?,
+            snapshot_location: fuse_table.snapshot_loc().await?,
+            table_meta: table_info.meta.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for FuseDumpSource {
+    const NAME: &'static str = FUSE_DUMP;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+        self.finish = true;
+
+        check_admin(&self.ctx, FUSE_DUMP).await?;
+        let (operator, path) = resolve_manifest_location(&self.ctx, &self.arg_location).await?;
+        let manifest = self.dump().await?;
+        operator.write(&path, manifest.to_bytes()?).await?;
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.arg_location.clone().into_bytes()]),
+            StringType::from_data(vec![manifest.storage_prefix.into_bytes()]),
+            StringType::from_opt_data(vec![manifest.snapshot_location.map(String::into_bytes)]),
+        ])))
+    }
+}
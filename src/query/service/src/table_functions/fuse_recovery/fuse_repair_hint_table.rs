@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::table_functions::string_literal;
+use common_storages_fuse::table_functions::string_value;
+use common_storages_fuse::FuseTable;
+
+use super::manifest::check_admin;
+
+const FUSE_REPAIR_HINT: &str = "fuse_repair_hint";
+
+/// `FUSE_REPAIR_HINT('db', 't')` rewrites the hint of the last snapshot of a fuse table
+/// from the snapshot location of the table meta, the hint might be stale if writing it
+/// failed after a commit.
+pub struct FuseRepairHintTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseRepairHintTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(FUSE_REPAIR_HINT, Some(2))?;
+        let arg_database_name = string_value(&args[0])?;
+        let arg_table_name = string_value(&args[1])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: FUSE_REPAIR_HINT.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("hint", TableDataType::String),
+            TableField::new("snapshot_location", TableDataType::String.wrap_nullable()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseRepairHintTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                FuseRepairHintSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_database_name.clone(),
+                    self.arg_table_name.clone(),
+                )
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for FuseRepairHintTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct FuseRepairHintSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseRepairHintSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, FuseRepairHintSource {
+            finish: false,
+            ctx,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+
+    /// Returns the location of the hint and the snapshot it points to, the hint is
+    /// left untouched if the table has no snapshot.
+    async fn repair(&self) -> Result<(String, Option<String>)> {
+        let table = self
+            .ctx
+            .get_table(
+                CATALOG_DEFAULT,
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if fuse_table.is_snapshot_loc_from_hint() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{} does not support table {}, its hint is maintained by the source table",
+                FUSE_REPAIR_HINT,
+                table.get_table_info().desc
+            )));
+        }
+
+        let location_generator = fuse_table.meta_location_generator();
+        let hint = location_generator.gen_last_snapshot_hint_location();
+        let snapshot_location = fuse_table.snapshot_loc().await?;
+        if let Some(location) = &snapshot_location {
+            FuseTable::try_write_last_snapshot_hint(
+                fuse_table.get_operator_ref(),
+                location_generator,
+                location,
+            )
+            .await?;
+        }
+        Ok((hint, snapshot_location))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for FuseRepairHintSource {
+    const NAME: &'static str = FUSE_REPAIR_HINT;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+        self.finish = true;
+
+        check_admin(&self.ctx, FUSE_REPAIR_HINT).await?;
+        let (hint, snapshot_location) = self.repair().await?;
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![hint.into_bytes()]),
+            StringType::from_opt_data(vec![snapshot_location.map(String::into_bytes)]),
+        ])))
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_management::RoleApi;
+use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::Ownership;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TableStatistics;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::io::SnapshotsIO;
+use common_storages_fuse::table_functions::string_literal;
+use common_storages_fuse::table_functions::string_value;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use common_users::UserApiProvider;
+use opendal::Operator;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+
+use super::manifest::check_admin;
+use super::manifest::resolve_manifest_location;
+use super::manifest::FuseTableManifest;
+
+const FUSE_RESTORE: &str = "fuse_restore";
+
+/// `FUSE_RESTORE('@stage/path', 'db', 't')` re-creates a fuse table from a manifest
+/// exported by `FUSE_DUMP`, the table reuses the data in the storage prefix of the
+/// dumped table.
+pub struct FuseRestoreTable {
+    table_info: TableInfo,
+    arg_location: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseRestoreTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(FUSE_RESTORE, Some(3))?;
+        let arg_location = string_value(&args[0])?;
+        let arg_database_name = string_value(&args[1])?;
+        let arg_table_name = string_value(&args[2])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: FUSE_RESTORE.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            arg_location,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("snapshot_location", TableDataType::String.wrap_nullable()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseRestoreTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            string_literal(self.arg_location.as_str()),
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                FuseRestoreSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_location.clone(),
+                    self.arg_database_name.clone(),
+                    self.arg_table_name.clone(),
+                )
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for FuseRestoreTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct FuseRestoreSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_location: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseRestoreSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_location: String,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, FuseRestoreSource {
+            finish: false,
+            ctx,
+            arg_location,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+
+    /// Checks the snapshot of the manifest exists and is consistent with the schema of
+    /// the manifest, and no newer snapshot has been committed since the dump.
+    ///
+    /// Returns the statistics of the snapshot.
+    async fn validate(
+        &self,
+        manifest: &FuseTableManifest,
+        operator: &Operator,
+    ) -> Result<TableStatistics> {
+        let latest_snapshot_location = Self::read_last_snapshot_hint(manifest, operator).await?;
+        let Some(location) = &manifest.snapshot_location else {
+            if let Some(latest) = latest_snapshot_location {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "the table has been written after the dump, latest snapshot {latest} is not in the manifest"
+                )));
+            }
+            return Ok(TableStatistics::default());
+        };
+
+        if !location.starts_with(&format!("{}/", manifest.storage_prefix)) {
+            return Err(ErrorCode::BadBytes(format!(
+                "snapshot {location} is not under the storage prefix {} of the manifest",
+                manifest.storage_prefix
+            )));
+        }
+        let (snapshot, _) = SnapshotsIO::read_snapshot(location.clone(), operator.clone())
+            .await
+            .map_err(|e| {
+                e.add_message(format!("read snapshot {location} of the manifest failed"))
+            })?;
+
+        // The schema of the table meta evolves ahead of the snapshots, as altering
+        // columns does not always commit a new snapshot. But it can not be older.
+        if snapshot.schema.next_column_id() > manifest.table_meta.schema.next_column_id() {
+            return Err(ErrorCode::BadBytes(format!(
+                "the schema of the manifest is older than snapshot {location}"
+            )));
+        }
+
+        if let Some(latest) = latest_snapshot_location {
+            if &latest != location {
+                let (latest_snapshot, _) =
+                    SnapshotsIO::read_snapshot(latest.clone(), operator.clone()).await?;
+                if latest_snapshot.timestamp > snapshot.timestamp {
+                    return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                        "the table has been written after the dump, snapshot {latest} is newer than snapshot {location} of the manifest"
+                    )));
+                }
+            }
+        }
+
+        Ok(TableStatistics {
+            number_of_rows: snapshot.summary.row_count,
+            data_bytes: snapshot.summary.uncompressed_byte_size,
+            compressed_data_bytes: snapshot.summary.compressed_byte_size,
+            index_data_bytes: snapshot.summary.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(snapshot.summary.block_count),
+        })
+    }
+
+    // Reads the hint of the last snapshot left by the commits of the table.
+    async fn read_last_snapshot_hint(
+        manifest: &FuseTableManifest,
+        operator: &Operator,
+    ) -> Result<Option<String>> {
+        let hint = format!(
+            "{}/{}",
+            manifest.storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT
+        );
+        match operator.read(&hint).await {
+            Ok(content) => {
+                let snapshot_full_path = String::from_utf8(content)?;
+                let root = operator.info().root().to_string();
+                Ok(Some(
+                    snapshot_full_path
+                        .strip_prefix(&root)
+                        .unwrap_or(&snapshot_full_path)
+                        .to_string(),
+                ))
+            }
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Two writable tables on the same storage prefix would corrupt each other,
+    /// including the dropped tables which can still be undropped.
+    async fn check_storage_prefix_unused(&self, storage_prefix: &str) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        for database in catalog.list_databases(&tenant).await? {
+            let tables = catalog
+                .list_tables_history(&tenant, database.name())
+                .await?;
+            for table in tables {
+                let table_info = table.get_table_info();
+                if !table.engine().eq_ignore_ascii_case("FUSE")
+                    || table_info.meta.storage_params.is_some()
+                    || table_info
+                        .options()
+                        .contains_key(OPT_KEY_TABLE_ATTACHED_READ_ONLY)
+                {
+                    continue;
+                }
+                if FuseTable::parse_storage_prefix(table_info).ok().as_deref()
+                    == Some(storage_prefix)
+                {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "storage prefix {storage_prefix} is already used by table {}.{} (id {}), can not restore it twice",
+                        database.name(),
+                        table_info.name,
+                        table_info.ident.table_id
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn restore(&self) -> Result<(u64, Option<String>)> {
+        let (stage_operator, path) =
+            resolve_manifest_location(&self.ctx, &self.arg_location).await?;
+        let manifest = FuseTableManifest::from_bytes(&stage_operator.read(&path).await?)?;
+
+        let operator = self.ctx.get_data_operator()?.operator();
+        let statistics = self.validate(&manifest, &operator).await?;
+        self.check_storage_prefix_unused(&manifest.storage_prefix)
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let database = catalog
+            .get_database(&tenant, &self.arg_database_name)
+            .await?;
+        let db_id = database.get_db_info().ident.db_id;
+
+        let mut table_meta = manifest.table_meta.clone();
+        let options = &mut table_meta.options;
+        options.insert(OPT_KEY_DATABASE_ID.to_string(), db_id.to_string());
+        options.insert(
+            OPT_KEY_STORAGE_PREFIX.to_string(),
+            manifest.storage_prefix.clone(),
+        );
+        options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+        match &manifest.snapshot_location {
+            Some(location) => {
+                options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), location.clone());
+            }
+            None => {
+                options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            }
+        }
+        table_meta.statistics = statistics;
+        table_meta.drop_on = None;
+        table_meta.shared_by = BTreeSet::new();
+        table_meta.updated_on = Utc::now();
+        table_meta.owner = self
+            .ctx
+            .get_current_role()
+            .map(|role| Ownership::new(role.name));
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: self.arg_database_name.clone(),
+                table_name: self.arg_table_name.clone(),
+            },
+            table_meta,
+        };
+        let reply = catalog.create_table(req).await?;
+
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
+            role_api
+                .grant_ownership(
+                    &GrantObjectByID::Table {
+                        catalog_name: CATALOG_DEFAULT.to_string(),
+                        db_id,
+                        table_id: reply.table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+        }
+
+        Ok((reply.table_id, manifest.snapshot_location))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for FuseRestoreSource {
+    const NAME: &'static str = FUSE_RESTORE;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+        self.finish = true;
+
+        check_admin(&self.ctx, FUSE_RESTORE).await?;
+        let (table_id, snapshot_location) = self.restore().await?;
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.arg_database_name.clone().into_bytes()]),
+            StringType::from_data(vec![self.arg_table_name.clone().into_bytes()]),
+            UInt64Type::from_data(vec![table_id]),
+            StringType::from_opt_data(vec![snapshot_location.map(String::into_bytes)]),
+        ])))
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::FileLocation;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableMeta;
+use common_sql::binder::resolve_file_location;
+use common_storage::init_stage_operator;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use opendal::Operator;
+
+/// The table metadata exported by `FUSE_DUMP`, from which `FUSE_RESTORE` re-creates
+/// the table on top of its existing data.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct FuseTableManifest {
+    pub version: u64,
+    pub database: String,
+    pub database_id: u64,
+    pub table: String,
+    pub table_id: u64,
+    /// The prefix of all the files of the table in the data storage.
+    pub storage_prefix: String,
+    /// The latest snapshot of the table, `None` if the table is empty.
+    pub snapshot_location: Option<String>,
+    pub table_meta: TableMeta,
+}
+
+impl FuseTableManifest {
+    pub const VERSION: u64 = 1;
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let manifest: FuseTableManifest = serde_json::from_slice(bytes)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid fuse table manifest: {e}")))?;
+        if manifest.version != Self::VERSION {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported fuse table manifest version {}, expecting {}",
+                manifest.version,
+                Self::VERSION
+            )));
+        }
+        Ok(manifest)
+    }
+}
+
+/// Dumping and restoring table metadata bypasses the privileges of the tables,
+/// so they are only allowed to the admin role.
+pub(crate) async fn check_admin(ctx: &Arc<dyn TableContext>, func_name: &str) -> Result<()> {
+    let roles = ctx.get_all_effective_roles().await?;
+    if roles
+        .iter()
+        .any(|role| role.name == BUILTIN_ROLE_ACCOUNT_ADMIN)
+    {
+        return Ok(());
+    }
+    Err(ErrorCode::PermissionDenied(format!(
+        "Permission denied, user {} must have role {} to call {}",
+        ctx.get_current_user()?.identity(),
+        BUILTIN_ROLE_ACCOUNT_ADMIN,
+        func_name
+    )))
+}
+
+/// Resolves the stage location of a manifest, like `@stage/path/manifest.json`.
+pub(crate) async fn resolve_manifest_location(
+    ctx: &Arc<dyn TableContext>,
+    location: &str,
+) -> Result<(Operator, String)> {
+    let Some(location) = location.strip_prefix('@') else {
+        return Err(ErrorCode::BadArguments(format!(
+            "the manifest location must be a stage location like '@stage/path', but got '{location}'"
+        )));
+    };
+    let (stage_info, path) =
+        resolve_file_location(ctx.as_ref(), &FileLocation::Stage(location.to_string())).await?;
+    if path.is_empty() || path.ends_with('/') {
+        return Err(ErrorCode::BadArguments(format!(
+            "the manifest location must be a file, but got '@{location}'"
+        )));
+    }
+    Ok((init_stage_operator(&stage_info)?, path))
+}
@@ -165,7 +165,7 @@ impl Table for NumbersTable {
 
         worker_num = match worker_num > fake_partitions {
             true => fake_partitions,
-            false => worker_num * cluster.nodes.len() as u64,
+            false => worker_num * cluster.get_executor_nodes().len() as u64,
         };
 
         let parts = generate_numbers_parts(0, worker_num, total);
@@ -30,6 +30,7 @@ use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::DataBlock;
@@ -51,6 +52,7 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_storages_factory::Table;
+use common_storages_fuse::operations::TenantStorageUsage;
 use common_users::UserApiProvider;
 
 pub struct TenantQuotaTable {
@@ -74,6 +76,11 @@ impl TenantQuotaTable {
                 "max_files_per_stage",
                 TableDataType::Number(NumberDataType::UInt32),
             ),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 
@@ -198,6 +205,14 @@ impl TenantQuotaSource {
                     DataType::Number(NumberDataType::UInt32),
                     Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt32),
+                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_users)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_storage_bytes)),
+                ),
             ],
             1,
         ))
@@ -210,6 +225,8 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_users: u32
+/// max_storage_bytes: u64
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -253,10 +270,17 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_users) = args.get(5) {
+            quota.max_users = max_users.parse::<u32>()?;
+        };
+        if let Some(max_storage_bytes) = args.get(6) {
+            quota.max_storage_bytes = max_storage_bytes.parse::<u64>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
             .await?;
+        TenantStorageUsage::instance().invalidate_quota(&tenant);
 
         Ok(Some(self.to_block(&quota)?))
     }
@@ -36,7 +36,11 @@ use crate::storages::fuse::table_functions::FuseBlockTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
+use crate::storages::fuse::table_functions::FuseTimeTravelBoundaryTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
+use crate::table_functions::fuse_recovery::FuseDumpTable;
+use crate::table_functions::fuse_recovery::FuseRepairHintTable;
+use crate::table_functions::fuse_recovery::FuseRestoreTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
 use crate::table_functions::list_stage::ListStageTable;
@@ -132,6 +136,26 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
 
+        creators.insert(
+            "fuse_time_travel_boundaries".to_string(),
+            (next_id(), Arc::new(FuseTimeTravelBoundaryTable::create)),
+        );
+
+        creators.insert(
+            "fuse_dump".to_string(),
+            (next_id(), Arc::new(FuseDumpTable::create)),
+        );
+
+        creators.insert(
+            "fuse_restore".to_string(),
+            (next_id(), Arc::new(FuseRestoreTable::create)),
+        );
+
+        creators.insert(
+            "fuse_repair_hint".to_string(),
+            (next_id(), Arc::new(FuseRepairHintTable::create)),
+        );
+
         creators.insert(
             "clustering_information".to_string(),
             (next_id(), Arc::new(ClusteringInformationTable::create)),
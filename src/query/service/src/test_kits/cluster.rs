@@ -39,6 +39,7 @@ impl ClusterDescriptor {
             0,
             addr.into(),
             DATABEND_COMMIT_VERSION.to_string(),
+            vec![],
         )));
         ClusterDescriptor {
             cluster_nodes_list: new_nodes,
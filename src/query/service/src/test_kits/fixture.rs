@@ -312,6 +312,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            bucket_spec: None,
         }
     }
 
@@ -336,6 +337,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            bucket_spec: None,
         }
     }
 
@@ -371,6 +373,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            bucket_spec: None,
         }
     }
 
@@ -415,6 +418,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            bucket_spec: None,
         }
     }
 
@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_expression::TableSchema;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+
+const HEADER_FILE: &str = "header.json";
+const SEGMENT_SUFFIX: &str = ".wal";
+
+/// The table whose buffered rows are logged in a [`WriteAheadLog`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalHeader {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+    /// The schema of the buffered rows.
+    pub schema: TableSchema,
+    /// The buffer whose rows are logged, the labels of its batches are made of it.
+    pub buffer_id: String,
+}
+
+/// The write-ahead log of the buffered rows of a table on the node.
+///
+/// The log is a directory of numbered segments, the rows are appended to the last one.
+/// A flush closes the last segment and removes the segments it covers once committed.
+/// Each record of a segment is a length-prefixed block, a torn record at the end of
+/// a segment is the write interrupted by a crash and is ignored on recovery.
+pub struct WriteAheadLog {
+    dir: PathBuf,
+    seq: u64,
+    file: File,
+}
+
+/// The log of a table found on startup, with the rows of its segments.
+pub struct RecoveredWal {
+    pub header: WalHeader,
+    pub segments: Vec<(u64, Vec<DataBlock>)>,
+    pub wal: WriteAheadLog,
+}
+
+impl WriteAheadLog {
+    /// Creates the log of a table in `dir`, replacing the one left by a dropped table.
+    pub fn create(dir: &Path, header: &WalHeader) -> Result<WriteAheadLog> {
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        fs::create_dir_all(dir)?;
+        let mut file = File::create(dir.join(HEADER_FILE))?;
+        file.write_all(&serde_json::to_vec(header)?)?;
+        file.sync_all()?;
+
+        Ok(WriteAheadLog {
+            dir: dir.to_path_buf(),
+            seq: 1,
+            file: Self::open_segment(dir, 1)?,
+        })
+    }
+
+    /// Reads the log left in `dir`, new rows are appended to a new segment.
+    pub fn recover(dir: &Path) -> Result<RecoveredWal> {
+        let header: WalHeader = serde_json::from_slice(&fs::read(dir.join(HEADER_FILE))?)?;
+
+        let mut segments = vec![];
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            if let Some(seq) = name
+                .strip_suffix(SEGMENT_SUFFIX)
+                .and_then(|seq| seq.parse::<u64>().ok())
+            {
+                segments.push(seq);
+            }
+        }
+        segments.sort();
+
+        let mut recovered = Vec::with_capacity(segments.len());
+        for seq in segments.iter() {
+            let path = Self::segment_path(dir, *seq);
+            recovered.push((*seq, decode_blocks(&path, &fs::read(&path)?)?));
+        }
+
+        let seq = segments.last().copied().unwrap_or_default() + 1;
+        Ok(RecoveredWal {
+            header,
+            segments: recovered,
+            wal: WriteAheadLog {
+                dir: dir.to_path_buf(),
+                seq,
+                file: Self::open_segment(dir, seq)?,
+            },
+        })
+    }
+
+    /// Appends the blocks to the last segment, they are on disk when it returns.
+    pub fn append(&mut self, blocks: &[DataBlock]) -> Result<()> {
+        let mut buf = vec![];
+        for block in blocks {
+            encode_block(block, &mut buf);
+        }
+        self.file.write_all(&buf)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    /// Closes the last segment and returns its number, new rows go to the next one.
+    pub fn rotate(&mut self) -> Result<u64> {
+        let seq = self.seq;
+        self.file = Self::open_segment(&self.dir, seq + 1)?;
+        self.seq = seq + 1;
+        Ok(seq)
+    }
+
+    /// Removes the segments up to `seq`, their rows are committed.
+    pub fn remove_segments(&self, seq: u64) -> Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let segment = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(SEGMENT_SUFFIX))
+                .and_then(|seq| seq.parse::<u64>().ok());
+            if matches!(segment, Some(segment) if segment <= seq) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the log, the table has no buffered rows left.
+    pub fn remove(self) -> Result<()> {
+        fs::remove_dir_all(&self.dir)?;
+        Ok(())
+    }
+
+    fn segment_path(dir: &Path, seq: u64) -> PathBuf {
+        dir.join(format!("{:020}{}", seq, SEGMENT_SUFFIX))
+    }
+
+    fn open_segment(dir: &Path, seq: u64) -> Result<File> {
+        Ok(OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(Self::segment_path(dir, seq))?)
+    }
+}
+
+// A record is the length of the payload followed by the payload, which is the number
+// of columns followed by the length and the arrow ipc bytes of each column.
+fn encode_block(block: &DataBlock, buf: &mut Vec<u8>) {
+    let mut payload = vec![];
+    payload.extend_from_slice(&(block.num_columns() as u32).to_le_bytes());
+    for entry in block.columns() {
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let data = serialize_column(&column);
+        payload.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        payload.extend_from_slice(&data);
+    }
+    buf.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&payload);
+}
+
+fn decode_blocks(path: &Path, mut data: &[u8]) -> Result<Vec<DataBlock>> {
+    let mut blocks = vec![];
+    while !data.is_empty() {
+        let payload = match read_u64(&mut data).and_then(|len| split(&mut data, len as usize)) {
+            Some(payload) => payload,
+            None => {
+                warn!(
+                    "ignore the torn record at the end of write buffer log {:?}",
+                    path
+                );
+                break;
+            }
+        };
+        blocks.push(decode_block(payload).ok_or_else(|| {
+            ErrorCode::StorageOther(format!("corrupted write buffer log {:?}", path))
+        })?);
+    }
+    Ok(blocks)
+}
+
+fn decode_block(mut payload: &[u8]) -> Option<DataBlock> {
+    let num_columns = u32::from_le_bytes(split(&mut payload, 4)?.try_into().ok()?);
+    let mut columns = Vec::with_capacity(num_columns as usize);
+    for _ in 0..num_columns {
+        let len = read_u64(&mut payload)?;
+        columns.push(deserialize_column(split(&mut payload, len as usize)?)?);
+    }
+    Some(DataBlock::new_from_columns(columns))
+}
+
+fn read_u64(data: &mut &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(split(data, 8)?.try_into().ok()?))
+}
+
+fn split<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Some(head)
+}
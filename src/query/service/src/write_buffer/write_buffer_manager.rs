@@ -0,0 +1,590 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::oneshot;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table::AppendMode;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockThresholds;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_pipeline_sources::BlocksSource;
+use common_settings::WriteBufferDurability;
+use common_users::UserApiProvider;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::check_deduplicate_label;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::write_buffer::wal::WalHeader;
+use crate::write_buffer::wal::WriteAheadLog;
+
+/// The table of a write buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TableKey {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl fmt::Display for TableKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.catalog, self.database, self.table)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct AppendOptions {
+    pub durability: WriteBufferDurability,
+    pub flush_interval: Duration,
+    pub thresholds: BlockThresholds,
+}
+
+/// Buffers the rows of small inserts on the node and commits them in batches.
+///
+/// The rows of a table are committed as one batch when the flush interval of the first
+/// buffered rows elapses or the buffered rows reach the block thresholds of the table.
+///
+/// Each batch is committed with a deduplicate label made of the buffer and the batch number,
+/// so a batch retried after a failure or replayed from the write-ahead log after a crash
+/// is committed at most once. The id of a buffer is unique, a new buffer of the table after
+/// a schema change or a restart never reuses the labels of the committed batches. The id is
+/// kept in the header of the write-ahead log, the batches replayed after a crash have the
+/// labels they were committed with. The statements with their own deduplicate label are not
+/// buffered, since the label is recorded in the same transaction as the commit.
+///
+/// A scan reads the committed snapshots of the table, the buffered rows are not visible to
+/// the queries. With `enable_write_buffer_read`, the buffered rows of the table on the node
+/// are committed before a query reads the table, which trades the batching of the inserts
+/// for reading them. The rows buffered on the other nodes are not committed.
+pub struct WriteBufferManager {
+    wal_dir: Option<PathBuf>,
+    buffers: Mutex<HashMap<TableKey, Arc<TableWriteBuffer>>>,
+}
+
+impl WriteBufferManager {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let manager = Arc::new(WriteBufferManager::create(&cfg.query.write_buffer_wal_dir)?);
+        GlobalInstance::set(manager.clone());
+
+        if manager.wal_dir.is_some() {
+            GlobalIORuntime::instance().spawn("write_buffer_recovery", async move {
+                if let Err(e) = manager.recover().await {
+                    error!("fail to recover the write buffer log: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WriteBufferManager> {
+        GlobalInstance::get()
+    }
+
+    /// The rows are only kept in memory if `wal_dir` is empty.
+    pub fn create(wal_dir: &str) -> Result<WriteBufferManager> {
+        if wal_dir.is_empty() {
+            return Ok(WriteBufferManager {
+                wal_dir: None,
+                buffers: Mutex::new(HashMap::new()),
+            });
+        }
+
+        let wal_dir = PathBuf::from(wal_dir);
+        fs::create_dir_all(&wal_dir)?;
+        Ok(WriteBufferManager {
+            wal_dir: Some(wal_dir),
+            buffers: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Buffers the rows of a statement, returns when they are buffered or committed
+    /// according to the durability.
+    ///
+    /// `schema` is the schema of the table without the computed columns, the rows are
+    /// filled with the default values of the missing columns.
+    pub async fn append(
+        &self,
+        key: &TableKey,
+        table_id: u64,
+        schema: &TableSchema,
+        blocks: Vec<DataBlock>,
+        options: &AppendOptions,
+    ) -> Result<()> {
+        loop {
+            let buffer = self.get_buffer(key, table_id, schema)?;
+            match buffer.append(&blocks, options)? {
+                Appended::Buffered => return Ok(()),
+                Appended::Waiting(rx) => {
+                    return rx.await.map_err(|_| {
+                        ErrorCode::Internal(format!("the write buffer of table {} is closed", key))
+                    })?;
+                }
+                // The table was recreated or altered meanwhile.
+                Appended::Closed => continue,
+            }
+        }
+    }
+
+    /// Commits the buffered rows of the table, if any.
+    pub async fn flush_table(&self, key: &TableKey) -> Result<()> {
+        let buffer = self.buffers.lock().get(key).cloned();
+        match buffer {
+            Some(buffer) => buffer.flush().await,
+            None => Ok(()),
+        }
+    }
+
+    fn get_buffer(
+        &self,
+        key: &TableKey,
+        table_id: u64,
+        schema: &TableSchema,
+    ) -> Result<Arc<TableWriteBuffer>> {
+        let mut buffers = self.buffers.lock();
+        if let Some(buffer) = buffers.get(key) {
+            if buffer.table_id == table_id && &buffer.schema == schema {
+                return Ok(buffer.clone());
+            }
+            // The rows buffered before the table was recreated or altered are
+            // committed on their own.
+            if let Some(buffer) = buffers.remove(key) {
+                buffer.close();
+            }
+        }
+
+        let buffer_id = uuid::Uuid::new_v4().simple().to_string();
+        let wal = match &self.wal_dir {
+            None => None,
+            Some(wal_dir) => Some(WriteAheadLog::create(
+                &wal_dir.join(&buffer_id),
+                &WalHeader {
+                    tenant: key.tenant.clone(),
+                    catalog: key.catalog.clone(),
+                    database: key.database.clone(),
+                    table: key.table.clone(),
+                    table_id,
+                    schema: schema.clone(),
+                    buffer_id: buffer_id.clone(),
+                },
+            )?),
+        };
+        let buffer = Arc::new(TableWriteBuffer::create(
+            key.clone(),
+            table_id,
+            schema.clone(),
+            buffer_id,
+            wal,
+        ));
+        buffers.insert(key.clone(), buffer.clone());
+        Ok(buffer)
+    }
+
+    /// Replays the logs left by the last run of the node and commits their rows.
+    pub async fn recover(&self) -> Result<()> {
+        let Some(wal_dir) = &self.wal_dir else {
+            return Ok(());
+        };
+
+        for entry in fs::read_dir(wal_dir)? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let recovered = match WriteAheadLog::recover(&path) {
+                Ok(recovered) => recovered,
+                Err(e) => {
+                    error!("fail to recover write buffer log {:?}: {}", path, e);
+                    continue;
+                }
+            };
+            let header = recovered.header;
+            let key = TableKey {
+                tenant: header.tenant,
+                catalog: header.catalog,
+                database: header.database,
+                table: header.table,
+            };
+
+            // The segments up to a committed batch are committed, even if the crash
+            // happened before they were removed.
+            let mut committed = 0;
+            for (seq, _) in recovered.segments.iter() {
+                let label = batch_label(&key.tenant, &header.buffer_id, header.table_id, *seq);
+                if label_exists(&label).await? {
+                    committed = *seq;
+                }
+            }
+            recovered.wal.remove_segments(committed)?;
+
+            let blocks = recovered
+                .segments
+                .into_iter()
+                .filter(|(seq, _)| *seq > committed)
+                .flat_map(|(_, blocks)| blocks)
+                .collect::<Vec<_>>();
+            info!(
+                "recovered {} buffered rows of table {} from write buffer log {:?}",
+                blocks.iter().map(|b| b.num_rows()).sum::<usize>(),
+                key,
+                path
+            );
+
+            let buffer = Arc::new(TableWriteBuffer::create(
+                key,
+                header.table_id,
+                header.schema,
+                header.buffer_id,
+                Some(recovered.wal),
+            ));
+            buffer.state.lock().push_recovered(blocks);
+            // The rows are committed on their own, the new rows go to a new buffer.
+            buffer.close();
+        }
+        Ok(())
+    }
+}
+
+enum Appended {
+    Buffered,
+    Waiting(oneshot::Receiver<Result<()>>),
+    Closed,
+}
+
+/// The buffered rows of a table.
+struct TableWriteBuffer {
+    key: TableKey,
+    table_id: u64,
+    schema: TableSchema,
+    // Unique among the buffers of all the nodes, it's the directory of the log if any.
+    buffer_id: String,
+    state: Mutex<BufferState>,
+    // Serializes the flushes of the table, with the batch to retry.
+    flush_lock: tokio::sync::Mutex<Option<FailedBatch>>,
+}
+
+struct BufferState {
+    // The rows acknowledged once buffered, they are logged in the write-ahead log if any.
+    acked: Vec<DataBlock>,
+    // The rows whose statements wait for the commit.
+    pending: Vec<DataBlock>,
+    waiters: Vec<oneshot::Sender<Result<()>>>,
+    num_rows: usize,
+    num_bytes: usize,
+    flush_scheduled: bool,
+    flush_interval: Duration,
+    // The number of the last batch, it's the number of the segment of the log if any.
+    seq: u64,
+    wal: Option<WriteAheadLog>,
+    closed: bool,
+}
+
+// A batch of acknowledged rows failed to commit, it's retried with the same label.
+struct FailedBatch {
+    label: String,
+    seq: u64,
+    blocks: Vec<DataBlock>,
+}
+
+impl BufferState {
+    fn push_recovered(&mut self, blocks: Vec<DataBlock>) {
+        self.num_rows += blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        self.num_bytes += blocks.iter().map(|b| b.memory_size()).sum::<usize>();
+        self.acked.extend(blocks);
+    }
+
+    fn next_seq(&mut self) -> Result<u64> {
+        match self.wal.as_mut() {
+            Some(wal) => wal.rotate(),
+            None => {
+                self.seq += 1;
+                Ok(self.seq)
+            }
+        }
+    }
+}
+
+impl TableWriteBuffer {
+    fn create(
+        key: TableKey,
+        table_id: u64,
+        schema: TableSchema,
+        buffer_id: String,
+        wal: Option<WriteAheadLog>,
+    ) -> TableWriteBuffer {
+        TableWriteBuffer {
+            key,
+            table_id,
+            schema,
+            buffer_id,
+            state: Mutex::new(BufferState {
+                acked: vec![],
+                pending: vec![],
+                waiters: vec![],
+                num_rows: 0,
+                num_bytes: 0,
+                flush_scheduled: false,
+                flush_interval: Duration::from_secs(1),
+                seq: 0,
+                wal,
+                closed: false,
+            }),
+            flush_lock: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    fn append(self: &Arc<Self>, blocks: &[DataBlock], options: &AppendOptions) -> Result<Appended> {
+        let mut state = self.state.lock();
+        if state.closed {
+            return Ok(Appended::Closed);
+        }
+
+        let appended = match options.durability {
+            WriteBufferDurability::Buffer => {
+                if let Some(wal) = state.wal.as_mut() {
+                    wal.append(blocks)?;
+                }
+                state.acked.extend_from_slice(blocks);
+                Appended::Buffered
+            }
+            WriteBufferDurability::Commit => {
+                let (tx, rx) = oneshot::channel();
+                state.pending.extend_from_slice(blocks);
+                state.waiters.push(tx);
+                Appended::Waiting(rx)
+            }
+        };
+        state.num_rows += blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        state.num_bytes += blocks.iter().map(|b| b.memory_size()).sum::<usize>();
+        state.flush_interval = options.flush_interval;
+
+        let thresholds = &options.thresholds;
+        if state.num_rows >= thresholds.max_rows_per_block
+            || state.num_bytes >= thresholds.max_bytes_per_block
+        {
+            state.flush_scheduled = true;
+            self.spawn_flush(Duration::ZERO);
+        } else if !state.flush_scheduled {
+            state.flush_scheduled = true;
+            self.spawn_flush(options.flush_interval);
+        }
+        Ok(appended)
+    }
+
+    // The buffered rows are committed on their own, the log is removed after that.
+    fn close(self: &Arc<Self>) {
+        self.state.lock().closed = true;
+        self.spawn_flush(Duration::ZERO);
+    }
+
+    fn spawn_flush(self: &Arc<Self>, delay: Duration) {
+        let buffer = self.clone();
+        GlobalIORuntime::instance().spawn("write_buffer_flush", async move {
+            tokio::time::sleep(delay).await;
+            if let Err(e) = buffer.flush().await {
+                error!(
+                    "fail to flush the write buffer of table {}: {}",
+                    buffer.key, e
+                );
+            }
+        });
+    }
+
+    async fn flush(self: &Arc<Self>) -> Result<()> {
+        let mut failed = self.flush_lock.lock().await;
+
+        if let Some(batch) = failed.take() {
+            if let Err(e) = self.commit_batch(&batch.label, batch.blocks.clone()).await {
+                if !Self::is_table_dropped(&e) {
+                    *failed = Some(batch);
+                    self.spawn_flush(self.state.lock().flush_interval);
+                    return Err(e);
+                }
+            }
+            self.remove_segments(batch.seq)?;
+        }
+
+        let (acked, pending, waiters, seq) = {
+            let mut state = self.state.lock();
+            state.flush_scheduled = false;
+            if state.num_rows == 0 {
+                if state.closed {
+                    if let Some(wal) = state.wal.take() {
+                        wal.remove()?;
+                    }
+                }
+                return Ok(());
+            }
+
+            let seq = state.next_seq()?;
+            state.num_rows = 0;
+            state.num_bytes = 0;
+            (
+                std::mem::take(&mut state.acked),
+                std::mem::take(&mut state.pending),
+                std::mem::take(&mut state.waiters),
+                seq,
+            )
+        };
+
+        let label = batch_label(&self.key.tenant, &self.buffer_id, self.table_id, seq);
+        let mut blocks = acked.clone();
+        blocks.extend(pending);
+        let res = self.commit_batch(&label, blocks).await;
+        for waiter in waiters {
+            let _ = waiter.send(res.clone());
+        }
+
+        match res {
+            Err(e) if !acked.is_empty() && !Self::is_table_dropped(&e) => {
+                // The statements of the acknowledged rows have returned, the rows are
+                // committed by the next flush.
+                *failed = Some(FailedBatch {
+                    label,
+                    seq,
+                    blocks: acked,
+                });
+                self.spawn_flush(self.state.lock().flush_interval);
+                Err(e)
+            }
+            res => {
+                if let Err(e) = &res {
+                    warn!(
+                        "discard {} buffered rows of table {}: {}",
+                        acked.iter().map(|b| b.num_rows()).sum::<usize>(),
+                        self.key,
+                        e
+                    );
+                }
+                self.remove_segments(seq)?;
+
+                let mut state = self.state.lock();
+                if state.closed && state.num_rows == 0 {
+                    if let Some(wal) = state.wal.take() {
+                        wal.remove()?;
+                    }
+                }
+                res
+            }
+        }
+    }
+
+    fn is_table_dropped(e: &ErrorCode) -> bool {
+        e.code() == ErrorCode::UNKNOWN_TABLE || e.code() == ErrorCode::UNKNOWN_DATABASE
+    }
+
+    fn remove_segments(&self, seq: u64) -> Result<()> {
+        match self.state.lock().wal.as_ref() {
+            Some(wal) => wal.remove_segments(seq),
+            None => Ok(()),
+        }
+    }
+
+    // Appends the rows to the table in one commit, which records the label of the batch.
+    async fn commit_batch(&self, label: &str, blocks: Vec<DataBlock>) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_current_tenant(self.key.tenant.clone());
+        let ctx = session.create_query_context().await?;
+
+        unsafe {
+            ctx.get_settings()
+                .set_deduplicate_label(label.to_string())?;
+        }
+        // The batch was committed before a failure or a crash.
+        if check_deduplicate_label(ctx.clone()).await? {
+            return Ok(());
+        }
+
+        // Not `ctx.get_table`, which flushes the buffer if `enable_write_buffer_read` is set.
+        let table = ctx
+            .get_catalog(&self.key.catalog)
+            .await?
+            .get_table(&self.key.tenant, &self.key.database, &self.key.table)
+            .await?;
+        if table.get_id() != self.table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table {} was dropped after the rows were buffered",
+                self.key
+            )));
+        }
+
+        let mut build_res = PipelineBuildResult::create();
+        let blocks = Arc::new(parking_lot::Mutex::new(VecDeque::from(blocks)));
+        build_res.main_pipeline.add_source(
+            |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+            1,
+        )?;
+        // The rows are filled with the default values of the columns added since.
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            Arc::new(DataSchema::from(&self.schema)),
+            None,
+            vec![],
+            false,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let complete_executor =
+            PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()
+    }
+}
+
+// The label is a key of the meta store, keep the labels in their own namespace.
+fn batch_label(tenant: &str, buffer_id: &str, table_id: u64, seq: u64) -> String {
+    format!(
+        "__fd_write_buffer/{}/{}/{}/{}",
+        tenant, buffer_id, table_id, seq
+    )
+}
+
+async fn label_exists(label: &str) -> Result<bool> {
+    let kv_store = UserApiProvider::instance().get_meta_store_client();
+    Ok(kv_store.get_kv(label).await?.is_some())
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchema;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+
+use crate::sessions::QueryContext;
+use crate::write_buffer::AppendOptions;
+use crate::write_buffer::TableKey;
+use crate::write_buffer::WriteBufferManager;
+
+/// Hands the rows of an insert statement to the [`WriteBufferManager`] instead of
+/// appending them to the table.
+pub struct WriteBufferSink {
+    ctx: Arc<QueryContext>,
+    key: TableKey,
+    table_id: u64,
+    schema: TableSchema,
+    options: AppendOptions,
+    blocks: Vec<DataBlock>,
+}
+
+impl WriteBufferSink {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        key: TableKey,
+        table_id: u64,
+        schema: TableSchema,
+        options: AppendOptions,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, ctx.clone(), WriteBufferSink {
+            ctx,
+            key,
+            table_id,
+            schema,
+            options,
+            blocks: vec![],
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for WriteBufferSink {
+    const NAME: &'static str = "WriteBufferSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.blocks);
+        let progress_values = ProgressValues {
+            rows: blocks.iter().map(|b| b.num_rows()).sum(),
+            bytes: blocks.iter().map(|b| b.memory_size()).sum(),
+        };
+        if progress_values.rows == 0 {
+            return Ok(());
+        }
+
+        WriteBufferManager::instance()
+            .append(
+                &self.key,
+                self.table_id,
+                &self.schema,
+                blocks,
+                &self.options,
+            )
+            .await?;
+        self.ctx.get_write_progress().incr(&progress_values);
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if !data_block.is_empty() {
+            self.blocks.push(data_block);
+        }
+        Ok(false)
+    }
+}
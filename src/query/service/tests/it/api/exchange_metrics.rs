@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_exception::Result;
+use databend_query::api::DataPacket;
+use databend_query::api::ExchangeEdge;
+use databend_query::api::ExchangeMetrics;
+
+fn edge(fragment_id: usize, source: &str, destination: &str) -> ExchangeEdge {
+    ExchangeEdge {
+        fragment_id,
+        source: source.to_string(),
+        destination: destination.to_string(),
+    }
+}
+
+#[test]
+fn test_exchange_metrics_merge_from_worker() -> Result<()> {
+    // node1 is the coordinator, node2 is the worker.
+    let coordinator = ExchangeMetrics::default();
+    let worker = ExchangeMetrics::default();
+
+    // fragment 0 is shuffled between the two nodes.
+    coordinator.record_sent(
+        &edge(0, "node1", "node2"),
+        50,
+        400,
+        Duration::from_millis(2),
+    );
+    worker.record_received(&edge(0, "node1", "node2"), 50, 400);
+    worker.record_sent(
+        &edge(0, "node2", "node1"),
+        30,
+        240,
+        Duration::from_millis(1),
+    );
+    coordinator.record_received(&edge(0, "node2", "node1"), 30, 240);
+
+    // fragment 1 is merged into the coordinator, in two blocks.
+    worker.record_sent(&edge(1, "node2", "node1"), 1, 8, Duration::from_millis(1));
+    worker.record_sent(&edge(1, "node2", "node1"), 2, 16, Duration::from_millis(1));
+    coordinator.record_received(&edge(1, "node2", "node1"), 3, 24);
+
+    // the worker sends its metrics to the coordinator via the statistics exchange.
+    let flight_data = FlightData::try_from(DataPacket::ExchangeMetrics(worker.snapshot()))?;
+    match DataPacket::try_from(flight_data)? {
+        DataPacket::ExchangeMetrics(metrics) => coordinator.merge(metrics),
+        _ => unreachable!(),
+    }
+
+    let expected = vec![
+        "Fragment 0: node1 -> node2",
+        "  sent: 50 rows, 400 bytes, serialize time: 2ms",
+        "  received: 50 rows, 400 bytes",
+        "Fragment 0: node2 -> node1",
+        "  sent: 30 rows, 240 bytes, serialize time: 1ms",
+        "  received: 30 rows, 240 bytes",
+        "Fragment 1: node2 -> node1",
+        "  sent: 3 rows, 24 bytes, serialize time: 2ms",
+        "  received: 3 rows, 24 bytes",
+    ];
+    assert_eq!(coordinator.format_edges(), expected);
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_sql::executor::physical_plans::SkewJoinSide;
+use databend_query::api::FlightScatter;
+use databend_query::api::JoinSkewKeysPacket;
+use databend_query::api::JoinSkewState;
+use databend_query::api::JoinSkews;
+use databend_query::api::SkewFlightScatter;
+
+fn report(executor: &str, hot_keys: Option<Vec<u64>>) -> JoinSkewKeysPacket {
+    JoinSkewKeysPacket {
+        query_id: "query".to_string(),
+        join_id: 1,
+        executor: executor.to_string(),
+        hot_keys,
+    }
+}
+
+fn scatter(side: SkewJoinSide, state: &Arc<JoinSkewState>) -> Result<Box<dyn FlightScatter>> {
+    SkewFlightScatter::try_create(
+        FunctionContext::default(),
+        vec![RemoteExpr::ColumnRef {
+            span: None,
+            id: 0,
+            data_type: DataType::Number(NumberDataType::Int32),
+            display_name: "k".to_string(),
+        }],
+        2,
+        side,
+        state.clone(),
+    )
+}
+
+fn block(keys: Vec<i32>) -> DataBlock {
+    DataBlock::new_from_columns(vec![Int32Type::from_data(keys)])
+}
+
+fn keys_of(blocks: Vec<DataBlock>) -> Vec<Vec<i32>> {
+    blocks
+        .iter()
+        .map(|block| {
+            let column = block.get_by_offset(0).value.as_column().unwrap();
+            Int32Type::try_downcast_column(column).unwrap().to_vec()
+        })
+        .collect()
+}
+
+#[test]
+fn test_join_skew_detect_hot_keys() -> Result<()> {
+    let state = JoinSkewState::create(3, 60, 100);
+
+    // 90% of the rows are of the key 3, which are all sent to the bucket 0.
+    state.record(&[3; 45]);
+    state.record(&[1, 2, 4, 5, 7]);
+    assert_eq!(state.take_report(false), None);
+
+    state.record(&[3; 45]);
+    state.record(&[1, 2, 4, 5, 8]);
+    assert_eq!(state.take_report(false), Some(vec![3]));
+    assert_eq!(state.take_report(true), None);
+
+    // no bucket exceeds the threshold.
+    let state = JoinSkewState::create(3, 60, 100);
+    state.record(&(0..100).collect::<Vec<_>>());
+    assert_eq!(state.take_report(false), Some(vec![]));
+
+    // a finished node reports what it sampled.
+    let state = JoinSkewState::create(3, 60, 100);
+    state.record(&[6; 10]);
+    assert_eq!(state.take_report(true), Some(vec![6]));
+    Ok(())
+}
+
+#[test]
+fn test_join_skew_keys_sealed() -> Result<()> {
+    let skews = JoinSkews::default();
+    let timeout = Duration::from_secs(3600);
+
+    let keys = skews.sync("query", &report("node1", Some(vec![3])), 2, timeout);
+    assert!(!keys.sealed);
+    assert!(keys.hot_keys.is_empty());
+
+    // polling does not count as a report.
+    let keys = skews.sync("query", &report("node2", None), 2, timeout);
+    assert!(!keys.sealed);
+
+    let keys = skews.sync("query", &report("node2", Some(vec![7, 3])), 2, timeout);
+    assert!(keys.sealed);
+    assert_eq!(keys.hot_keys, vec![3, 7]);
+
+    // sealed hot keys do not change anymore.
+    let keys = skews.sync("query", &report("node3", Some(vec![9])), 2, timeout);
+    assert_eq!(keys.hot_keys, vec![3, 7]);
+
+    // the hot keys are sealed without waiting for the missing nodes after timeout.
+    let skews = JoinSkews::default();
+    let keys = skews.sync("query", &report("node1", None), 2, Duration::ZERO);
+    assert!(keys.sealed);
+    assert!(keys.hot_keys.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_join_skew_scatter() -> Result<()> {
+    let state = Arc::new(JoinSkewState::create(2, 60, 8));
+    let probe = scatter(SkewJoinSide::Probe, &state)?;
+    let build = scatter(SkewJoinSide::Build, &state)?;
+
+    // before the hot keys are sealed, all the rows of a key are sent to its owner.
+    let blocks = keys_of(probe.execute(block(vec![1, 1, 1, 1, 1, 1, 1, 2]))?);
+    let owner = blocks.iter().position(|keys| keys.contains(&1)).unwrap();
+    assert_eq!(blocks[owner].iter().filter(|key| **key == 1).count(), 7);
+
+    let hot_keys = state.take_report(false).unwrap();
+    assert_eq!(hot_keys.len(), 1);
+    state.seal(hot_keys);
+
+    // build rows of the hot key are replicated to all nodes.
+    let build_blocks = keys_of(build.execute(block(vec![1, 2, 3]))?);
+    assert_eq!(build_blocks.iter().map(|keys| keys.len()).sum::<usize>(), 4);
+    assert!(build_blocks.iter().all(|keys| keys.contains(&1)));
+
+    // probe rows of the hot key are spread over all nodes.
+    let probe_blocks = keys_of(probe.execute(block(vec![1, 1, 1, 1, 2, 3]))?);
+    for keys in probe_blocks.iter() {
+        assert_eq!(keys.iter().filter(|key| **key == 1).count(), 2);
+    }
+
+    // the other keys are still sent to their owners on both sides.
+    for key in [2, 3] {
+        let build_owner = build_blocks.iter().position(|keys| keys.contains(&key));
+        let probe_owner = probe_blocks.iter().position(|keys| keys.contains(&key));
+        assert_eq!(build_owner, probe_owner);
+    }
+    Ok(())
+}
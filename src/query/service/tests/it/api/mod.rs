@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod exchange_metrics;
 mod http;
 mod http_service;
+mod join_skew;
 mod rpc_service;
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::base::tokio;
+use common_catalog::cluster_info::Cluster;
 use common_exception::Result;
+use common_meta_types::NodeInfo;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
 use databend_query::test_kits::*;
@@ -65,6 +69,66 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_route_to_warehouse() -> Result<()> {
+    let node = |id: &str, labels: &[&str]| {
+        Arc::new(NodeInfo::create(
+            id.to_string(),
+            0,
+            format!("{}:9090", id),
+            "".to_string(),
+            labels.iter().map(|label| label.to_string()).collect(),
+        ))
+    };
+    let cluster = Cluster::create(
+        vec![
+            node("coordinator", &[]),
+            node("etl_1", &["etl"]),
+            node("etl_2", &["etl", "bi"]),
+            node("bi_1", &["bi"]),
+        ],
+        "coordinator".to_string(),
+    );
+
+    let ids =
+        |nodes: Vec<Arc<NodeInfo>>| nodes.iter().map(|node| node.id.clone()).collect::<Vec<_>>();
+
+    // The coordinator is kept even if it is not labeled with the warehouse,
+    // but no fragment is placed on it.
+    let etl_cluster = cluster.route_to_warehouse("etl").unwrap();
+    assert_eq!(ids(etl_cluster.get_nodes()), vec![
+        "coordinator",
+        "etl_1",
+        "etl_2"
+    ]);
+    assert_eq!(ids(etl_cluster.get_executor_nodes()), vec![
+        "etl_1", "etl_2"
+    ]);
+    assert_eq!(etl_cluster.local_id(), "coordinator");
+
+    let bi_cluster = cluster.route_to_warehouse("bi").unwrap();
+    assert_eq!(bi_cluster.get_nodes().len(), 3);
+    assert_eq!(ids(bi_cluster.get_executor_nodes()), vec!["etl_2", "bi_1"]);
+
+    // The coordinator labeled with the warehouse executes the fragments too.
+    let cluster = Cluster::create(
+        vec![node("coordinator", &["bi"]), node("etl_1", &["etl"])],
+        "coordinator".to_string(),
+    );
+    let bi_cluster = cluster.route_to_warehouse("bi").unwrap();
+    assert_eq!(ids(bi_cluster.get_executor_nodes()), vec!["coordinator"]);
+    let etl_cluster = cluster.route_to_warehouse("etl").unwrap();
+    assert_eq!(ids(etl_cluster.get_executor_nodes()), vec!["etl_1"]);
+    assert_eq!(ids(cluster.get_executor_nodes()), vec![
+        "coordinator",
+        "etl_1"
+    ]);
+
+    assert!(cluster.route_to_warehouse("adhoc").is_none());
+
+    Ok(())
+}
+
 // TODO:(Winter) need kvapi::KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
@@ -38,3 +38,4 @@ mod sql;
 mod storages;
 mod table_functions;
 mod tests;
+mod write_buffer;
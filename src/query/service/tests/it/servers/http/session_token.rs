@@ -0,0 +1,247 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::UserApi;
+use common_meta_app::principal::UserIdentity;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+use databend_query::auth::AuthMgr;
+use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::session_route;
+use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::SessionTokenPair;
+use databend_query::servers::HttpHandlerKind;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use headers::Header;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::IntoResponse;
+use poem::Request;
+use poem::Route;
+use serde_json::Value as JsonValue;
+
+fn create_endpoint() -> impl Endpoint {
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::instance());
+    Route::new()
+        .nest("/v1/session", session_route())
+        .nest("/v1/query", query_route().with(session_middleware))
+}
+
+async fn call(
+    ep: &impl Endpoint,
+    method: Method,
+    uri: &str,
+    auth: impl Header,
+    body: Vec<u8>,
+) -> (StatusCode, String) {
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(uri.parse().unwrap())
+                .method(method)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(auth)
+                .body(body),
+        )
+        .await
+        .unwrap()
+        .into_response();
+    let status = response.status();
+    let body = response.into_body().into_string().await.unwrap();
+    (status, body)
+}
+
+async fn login(ep: &impl Endpoint) -> Result<SessionTokenPair> {
+    let (status, body) = login_as(ep, "root", "").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    Ok(serde_json::from_str(&body)?)
+}
+
+async fn login_as(ep: &impl Endpoint, user: &str, password: &str) -> (StatusCode, String) {
+    let basic = headers::Authorization::basic(user, password);
+    call(ep, Method::POST, "/v1/session/login", basic, vec![]).await
+}
+
+async fn refresh(ep: &impl Endpoint, token: &str) -> (StatusCode, String) {
+    let bearer = headers::Authorization::bearer(token).unwrap();
+    call(ep, Method::POST, "/v1/session/refresh", bearer, vec![]).await
+}
+
+async fn query_current_user(ep: &impl Endpoint, token: &str) -> Result<(StatusCode, String)> {
+    let bearer = headers::Authorization::bearer(token).unwrap();
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let body = serde_json::to_vec(&json)?;
+    Ok(call(ep, Method::POST, "/v1/query", bearer, body).await)
+}
+
+fn error_reason(body: &str) -> String {
+    let v: JsonValue = serde_json::from_str(body).unwrap();
+    v["error"]["reason"].as_str().unwrap().to_string()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_session_token() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint();
+
+    let tokens = login(&ep).await?;
+    assert!(tokens.session_token.starts_with("bend-v1-"));
+    assert_eq!(tokens.session_token_ttl_in_secs, 3600);
+    assert_eq!(tokens.refresh_token_ttl_in_secs, 14400);
+
+    // query with session token
+    let (status, body) = query_current_user(&ep, &tokens.session_token).await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let resp: QueryResponse = serde_json::from_str(&body)?;
+    assert!(resp.error.is_none(), "{:?}", resp.error);
+    assert_eq!(resp.data[0][0].as_str().unwrap(), "'root'@'%'");
+
+    // refresh token can not be used to query
+    let (status, body) = query_current_user(&ep, &tokens.refresh_token).await?;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    assert_eq!(error_reason(&body), "InvalidSessionToken");
+
+    // tampered token
+    let tampered = format!("{}x", tokens.session_token);
+    let (status, body) = query_current_user(&ep, &tampered).await?;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    assert_eq!(error_reason(&body), "InvalidSessionToken");
+
+    // refresh
+    let (status, body) = refresh(&ep, &tokens.refresh_token).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let refreshed: SessionTokenPair = serde_json::from_str(&body)?;
+    assert_eq!(refreshed.refresh_token, tokens.refresh_token);
+    let (status, body) = query_current_user(&ep, &refreshed.session_token).await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    // session token can not be used to refresh
+    let (status, body) = refresh(&ep, &tokens.session_token).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    assert_eq!(error_reason(&body), "InvalidSessionToken");
+
+    // revoke the session, all its tokens are rejected
+    let bearer = headers::Authorization::bearer(&tokens.session_token).unwrap();
+    let (status, body) = call(&ep, Method::POST, "/v1/session/revoke", bearer, vec![]).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    for token in [&tokens.session_token, &refreshed.session_token] {
+        let (status, body) = query_current_user(&ep, token).await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+        assert_eq!(error_reason(&body), "SessionTokenRevoked");
+    }
+    let (status, body) = refresh(&ep, &tokens.refresh_token).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    assert_eq!(error_reason(&body), "SessionTokenRevoked");
+
+    // other sessions are not affected
+    let tokens = login(&ep).await?;
+    let (status, body) = query_current_user(&ep, &tokens.session_token).await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    // the errors of the other authentications are unchanged
+    let basic = headers::Authorization::basic("root", "wrong");
+    let json = serde_json::json!({"sql": "select 1"});
+    let (status, body) = call(
+        &ep,
+        Method::POST,
+        "/v1/query",
+        basic,
+        serde_json::to_vec(&json)?,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    let v: JsonValue = serde_json::from_str(&body)?;
+    assert_eq!(v["error"]["code"].as_str(), Some("401"));
+    assert!(v["error"].get("reason").is_none(), "{}", body);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_session_token_expired() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.http_session_token_ttl_secs = 0;
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+    let ep = create_endpoint();
+
+    let tokens = login(&ep).await?;
+    let (status, body) = query_current_user(&ep, &tokens.session_token).await?;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", body);
+    assert_eq!(error_reason(&body), "SessionTokenExpired");
+
+    // the refresh token is still valid
+    let (status, body) = refresh(&ep, &tokens.refresh_token).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_session_token_password_expired() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ep = create_endpoint();
+    fixture
+        .execute_command("create password policy p1 password_max_age_days = 1")
+        .await?;
+    fixture
+        .execute_command(
+            "create user u1 identified by 'Abc123456!' with set password policy = 'p1'",
+        )
+        .await?;
+
+    let (status, body) = login_as(&ep, "u1", "Abc123456!").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let tokens: SessionTokenPair = serde_json::from_str(&body)?;
+
+    // the password expires after login
+    UserApiProvider::instance()
+        .get_user_api_client(&fixture.default_tenant())?
+        .update_user_with(UserIdentity::new("u1", "%"), MatchSeq::GE(1), |ui| {
+            ui.password_update_on = Some(Utc::now() - Duration::days(2));
+        })
+        .await?;
+
+    // the password must be changed with the password authentication first
+    let (status, body) = refresh(&ep, &tokens.refresh_token).await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+    assert_eq!(error_reason(&body), "PasswordMustBeChanged");
+    let (status, body) = login_as(&ep, "u1", "Abc123456!").await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+    assert_eq!(error_reason(&body), "PasswordMustBeChanged");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_session_token_short_secret() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.http_session_token_secret = "short".to_string();
+    match TestFixture::setup_with_config(&config).await {
+        Ok(_) => panic!("a short http_session_token_secret should be rejected"),
+        Err(e) => assert_eq!(e.code(), ErrorCode::INVALID_CONFIG),
+    }
+    Ok(())
+}
@@ -28,6 +28,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::SessionStateType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -54,6 +55,123 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_multi_statements_with_on_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    // One result set for each statement.
+    let mut result = connection
+        .query_iter("SELECT 1; SELECT 2, 3;")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let first = result
+        .collect::<u64>()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Read result set failed")?;
+    assert_eq!(first, vec![1]);
+    let second = result
+        .collect::<(u64, u64)>()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Read result set failed")?;
+    assert_eq!(second, vec![(2, 3)]);
+    assert!(result.is_empty());
+    drop(result);
+
+    // The session state changed by a statement is seen by the next ones.
+    connection
+        .query_drop("CREATE DATABASE IF NOT EXISTS db_mysql_multi; USE db_mysql_multi")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let database = connection
+        .query_first::<String, _>("SELECT database()")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(database, Some("db_mysql_multi".to_string()));
+
+    // The rest statements are not executed after an error.
+    let result = connection
+        .query_drop("SELECT * FROM t_not_exists; CREATE TABLE t_not_created(a int)")
+        .await;
+    assert!(result.is_err());
+    let tables = connection
+        .query::<String, _>("SHOW TABLES")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert!(tables.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_session_state_changes() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    connection
+        .query_drop("CREATE DATABASE IF NOT EXISTS db_mysql_state")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    connection
+        .query_drop("USE db_mysql_state")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let changes = connection
+        .session_state_changes()
+        .map_err_to_code(ErrorCode::UnknownException, || "Read session state failed")?;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(
+        changes[0].data_type(),
+        SessionStateType::SESSION_TRACK_SCHEMA
+    );
+    assert_eq!(changes[0].data_ref(), b"\x0edb_mysql_state");
+
+    connection
+        .query_drop("SET query_tag = 'tag'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let changes = connection
+        .session_state_changes()
+        .map_err_to_code(ErrorCode::UnknownException, || "Read session state failed")?;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(
+        changes[0].data_type(),
+        SessionStateType::SESSION_TRACK_SYSTEM_VARIABLES
+    );
+    assert_eq!(changes[0].data_ref(), b"\x09query_tag\x03tag");
+
+    // The entry of a value of 200 bytes can't be reported in the OK packet of opensrv,
+    // the change is not reported and the statement succeeds.
+    let long_tag = "x".repeat(200);
+    connection
+        .query_drop(format!("SET query_tag = '{}'", long_tag))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let changes = connection
+        .session_state_changes()
+        .map_err_to_code(ErrorCode::UnknownException, || "Read session state failed")?;
+    assert!(changes.is_empty());
+    let tag = connection
+        .query_first::<String, _>("SELECT value FROM system.settings WHERE name = 'query_tag'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(tag, Some(long_tag));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_app::principal::SettingsProfile;
 use databend_query::sessions::SessionType;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
@@ -62,3 +65,29 @@ async fn test_session_setting_override() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_profile() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+
+    let settings = session.get_settings();
+    settings.set_setting("max_storage_io_requests".to_string(), "3000".to_string())?;
+
+    let settings_profile = SettingsProfile {
+        name: "test_profile".to_string(),
+        settings: BTreeMap::from([
+            ("max_threads".to_string(), "5".to_string()),
+            ("max_storage_io_requests".to_string(), "100".to_string()),
+            ("no_such_setting".to_string(), "1".to_string()),
+        ]),
+        ..Default::default()
+    };
+    settings.apply_settings_profile(&settings_profile);
+
+    assert_eq!(settings.get_max_threads()?, 5);
+    // The settings set in the session win over the profile.
+    assert_eq!(settings.get_max_storage_io_requests()?, 3000);
+
+    Ok(())
+}
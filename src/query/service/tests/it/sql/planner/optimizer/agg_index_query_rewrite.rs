@@ -82,6 +82,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        bucket_spec: None,
     }
 }
 
@@ -56,6 +56,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        bucket_spec: None,
     };
 
     // create test table
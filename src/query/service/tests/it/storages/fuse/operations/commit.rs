@@ -29,6 +29,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table::Table;
 use common_catalog::table_context::MaterializedCtesBlocks;
+use common_catalog::table_context::NodeProcessInfo;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
@@ -514,6 +515,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>> {
+        todo!()
+    }
+
     async fn get_visibility_checker(&self) -> Result<GrantObjectVisibilityChecker> {
         todo!()
     }
@@ -558,6 +563,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>> {
+        todo!()
+    }
+
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         todo!()
     }
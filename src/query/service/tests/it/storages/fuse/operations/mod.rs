@@ -25,5 +25,8 @@ mod optimize;
 mod purge_drop;
 mod read_plan;
 mod replace_into;
+mod segment_stats_index;
+mod snapshot_pin;
+mod storage_quota;
 mod table_analyze;
 mod truncate;
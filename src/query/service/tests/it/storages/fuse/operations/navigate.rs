@@ -25,6 +25,9 @@ use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::FuseTable;
 use databend_query::test_kits::*;
 use futures::TryStreamExt;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::TableSnapshot;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_navigate() -> Result<()> {
@@ -117,12 +120,43 @@ async fn test_fuse_navigate() -> Result<()> {
         .unwrap()
         .sub(chrono::Duration::milliseconds(1));
     // navigate from the instant that is just one ms before the timestamp of the last insertion
-    let res = fuse_table.navigate_to_time_point(loc, instant).await;
+    let res = fuse_table
+        .navigate_to_time_point(loc.clone(), instant)
+        .await;
     match res {
         Ok(_) => panic!("historical data should not exist"),
-        Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND),
+        Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_TIME_TRAVEL_BEFORE_CREATION),
+    };
+
+    // 5. navigate to the purged history
+    fuse_table.get_operator().delete(&first_snapshot).await?;
+    if let Some(cache) = TableSnapshot::cache() {
+        cache.evict(&first_snapshot);
+    }
+    let res = fuse_table
+        .navigate_to_time_point(loc.clone(), instant)
+        .await;
+    match res {
+        Ok(_) => panic!("historical data should be purged"),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_PURGED);
+            let earliest = latest.timestamp.unwrap().to_string();
+            assert!(e.message().contains(&earliest));
+        }
     };
 
+    let first_snapshot_id = first_insertion.snapshot_id.simple().to_string();
+    let res = fuse_table
+        .navigate_to_snapshot(loc, first_snapshot_id.as_str())
+        .await;
+    match res {
+        Ok(_) => panic!("snapshot should be purged"),
+        Err(e) => assert_eq!(e.code(), ErrorCode::UNKNOWN_TABLE_SNAPSHOT),
+    };
+
+    let (earliest, latest) = fuse_table.time_travel_boundaries().await?.unwrap();
+    assert_eq!(earliest.snapshot_id, latest.snapshot_id);
+
     Ok(())
 }
 
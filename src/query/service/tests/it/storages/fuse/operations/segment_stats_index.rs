@@ -0,0 +1,91 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storages_factory::Table;
+use common_storages_fuse::io::SegmentsIO;
+use common_storages_fuse::FuseTable;
+use databend_query::test_kits::*;
+use storages_common_table_meta::meta::SegmentInfo;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_segment_stats_index() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // the index of each snapshot covers all of its segments
+    let mut previous_index_location = None;
+    for num_segments in 1..=3 {
+        append_sample_data(1, &fixture).await?;
+
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+        assert_eq!(snapshot.segments.len(), num_segments);
+        assert_ne!(
+            snapshot.segment_stats_index_location,
+            previous_index_location
+        );
+        previous_index_location = snapshot.segment_stats_index_location.clone();
+
+        let index = fuse_table
+            .read_segment_stats_index(snapshot.as_ref())
+            .await?
+            .unwrap();
+        assert_eq!(index.snapshot_id, snapshot.snapshot_id);
+        assert_eq!(index.segments.len(), num_segments);
+
+        let segments_io =
+            SegmentsIO::create(ctx.clone(), fuse_table.get_operator(), fuse_table.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?;
+        for (location, segment) in snapshot.segments.iter().zip(segments.into_iter()) {
+            let segment = segment?;
+            let stats = index.get(&location.0).unwrap();
+            assert_eq!(stats.col_stats, segment.summary.col_stats);
+            assert_eq!(
+                stats.uncompressed_byte_size,
+                segment.summary.uncompressed_byte_size
+            );
+        }
+    }
+
+    // the pruning results are the same with the index
+    let qry = format!(
+        "select count(*) from {}.{} where id > 0",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let stream = fixture.execute_query(&qry).await?;
+    assert_eq!(query_count(stream).await?, 9);
+
+    // a missing index is ignored
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let mut snapshot = snapshot.as_ref().clone();
+    snapshot.segment_stats_index_location = Some("not_exist".to_string());
+    assert!(
+        fuse_table
+            .read_segment_stats_index(&snapshot)
+            .await?
+            .is_none()
+    );
+
+    Ok(())
+}
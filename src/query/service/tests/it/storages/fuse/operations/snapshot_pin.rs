@@ -0,0 +1,112 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot_pinned_across_scans() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.base(c int)")
+        .await?;
+    fixture
+        .execute_command("insert into default.base values(1),(2)")
+        .await?;
+
+    // The snapshot location of a READ_ONLY attached table is read from the hint file of the
+    // base table, each scan would read the latest snapshot without the pin.
+    let ctx = fixture.new_query_ctx().await?;
+    let base = ctx.get_table("default", "default", "base").await?;
+    let prefix = FuseTable::try_from_table(base.as_ref())?
+        .meta_location_generator()
+        .prefix()
+        .to_string();
+    fixture
+        .execute_command(&format!(
+            "attach table default.t 'fs://{}/{}/' READ_ONLY",
+            fixture.storage_root(),
+            prefix
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table("default", "default", "t").await?;
+    let pinned = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+
+    // concurrent append from another context
+    let other_ctx = fixture.new_query_ctx().await?;
+    execute_command(other_ctx, "insert into default.base values(3)").await?;
+
+    // all the scans of the table in the query read the pinned snapshot
+    let table = ctx.get_table("default", "default", "t").await?;
+    let snapshot = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+    assert_eq!(pinned.snapshot_id, snapshot.snapshot_id);
+
+    let qry = "select count(*) from default.t a join default.t b on a.c = b.c \
+               where a.c in (select c from default.t)";
+    let stream = execute_query(ctx.clone(), qry).await?;
+    assert_eq!(2, query_count(stream).await?);
+
+    // a new query reads the latest snapshot
+    let stream = fixture.execute_query(qry).await?;
+    assert_eq!(3, query_count(stream).await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_mutation_reconciled_on_commit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t(c int)")
+        .await?;
+    fixture
+        .execute_command("insert into default.t values(1),(2)")
+        .await?;
+
+    // the table of the query is resolved before the concurrent append
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_table("default", "default", "t").await?;
+
+    let other_ctx = fixture.new_query_ctx().await?;
+    execute_command(other_ctx, "insert into default.t values(1),(3)").await?;
+
+    // the delete works on the snapshot of the query, the appended rows are kept on commit
+    execute_command(
+        ctx,
+        "delete from default.t where c in (select c from default.t where c < 2)",
+    )
+    .await?;
+
+    let stream = fixture
+        .execute_query("select count(*) from default.t")
+        .await?;
+    assert_eq!(3, query_count(stream).await?);
+    let stream = fixture
+        .execute_query("select count(*) from default.t where c = 1")
+        .await?;
+    assert_eq!(1, query_count(stream).await?);
+
+    Ok(())
+}
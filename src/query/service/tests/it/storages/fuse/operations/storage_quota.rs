@@ -0,0 +1,102 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_storages_fuse::operations::TenantStorageUsage;
+use common_users::UserApiProvider;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_storage_quota() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    // 1. without quota, appends are not limited
+    let table = fixture.latest_default_table().await?;
+    let blocks = TestFixture::gen_sample_blocks_stream_ex(2, 3, 1)
+        .try_collect()
+        .await?;
+    fixture
+        .append_commit_blocks(table.clone(), blocks, false, true)
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let table_bytes = table.get_table_info().meta.statistics.data_bytes;
+    assert!(table_bytes > 0);
+    let usage = TenantStorageUsage::instance()
+        .get_usage(ctx.as_ref())
+        .await?;
+    assert_eq!(usage, table_bytes);
+
+    // 2. limit the storage to what is already used
+    let quota_api =
+        UserApiProvider::instance().get_tenant_quota_api_client(&fixture.default_tenant())?;
+    let res = quota_api.get_quota(MatchSeq::GE(0)).await?;
+    let mut quota = res.data;
+    quota.max_storage_bytes = table_bytes;
+    quota_api
+        .set_quota(&quota, MatchSeq::Exact(res.seq))
+        .await?;
+    // the quota is cached, it's invalidated by admin$tenant_quota on the node
+    TenantStorageUsage::instance().invalidate_quota(&fixture.default_tenant());
+
+    // 3. size-increasing mutations are rejected, and nothing is committed
+    let blocks = TestFixture::gen_sample_blocks_stream_ex(1, 3, 1)
+        .try_collect()
+        .await?;
+    let r = fixture
+        .append_commit_blocks(table.clone(), blocks, false, true)
+        .await;
+    assert_eq!(r.unwrap_err().code(), ErrorCode::TENANT_QUOTA_EXCEEDED);
+
+    let latest = fixture.latest_default_table().await?;
+    assert_eq!(
+        latest.get_table_info().ident.seq,
+        table.get_table_info().ident.seq
+    );
+
+    // 4. truncate releases the usage
+    latest.truncate(ctx.clone()).await?;
+    let usage = TenantStorageUsage::instance()
+        .get_usage(ctx.as_ref())
+        .await?;
+    assert_eq!(usage, 0);
+
+    // 5. the limit is soft, the commits are not checked until the usage is computed
+    let res = quota_api.get_quota(MatchSeq::GE(0)).await?;
+    let mut quota = res.data;
+    quota.max_storage_bytes = 1;
+    quota_api
+        .set_quota(&quota, MatchSeq::Exact(res.seq))
+        .await?;
+    TenantStorageUsage::instance().invalidate_quota(&fixture.default_tenant());
+    TenantStorageUsage::instance().invalidate(&fixture.default_tenant());
+
+    let latest = fixture.latest_default_table().await?;
+    let blocks = TestFixture::gen_sample_blocks_stream_ex(1, 3, 1)
+        .try_collect()
+        .await?;
+    fixture
+        .append_commit_blocks(latest, blocks, false, true)
+        .await?;
+
+    Ok(())
+}
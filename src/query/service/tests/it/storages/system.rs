@@ -410,6 +410,10 @@ async fn test_users_table() -> Result<()> {
                 grants: UserGrantSet::empty(),
                 quota: UserQuota::no_limit(),
                 option: UserOption::default(),
+                history_auth_infos: vec![],
+                password_fails: vec![],
+                password_update_on: None,
+                lockout_time: None,
             },
             false,
         )
@@ -426,6 +430,10 @@ async fn test_users_table() -> Result<()> {
                 grants: UserGrantSet::empty(),
                 quota: UserQuota::no_limit(),
                 option: UserOption::default().with_default_role(Some("role1".to_string())),
+                history_auth_infos: vec![],
+                password_fails: vec![],
+                password_update_on: None,
+                lockout_time: None,
             },
             false,
         )
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableSchema;
+use databend_query::write_buffer::WalHeader;
+use databend_query::write_buffer::WriteAheadLog;
+
+fn header() -> WalHeader {
+    WalHeader {
+        tenant: "test".to_string(),
+        catalog: "default".to_string(),
+        database: "db".to_string(),
+        table: "t".to_string(),
+        table_id: 1,
+        schema: TableSchema::default(),
+        buffer_id: "b1".to_string(),
+    }
+}
+
+fn block(values: Vec<i32>, names: Vec<&str>) -> DataBlock {
+    DataBlock::new_from_columns(vec![
+        Int32Type::from_data(values),
+        StringType::from_data(names),
+    ])
+}
+
+fn assert_blocks_eq(actual: &[DataBlock], expected: &[DataBlock]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected.iter()) {
+        assert_eq!(actual.num_rows(), expected.num_rows());
+        assert_eq!(actual.columns(), expected.columns());
+    }
+}
+
+#[test]
+fn test_wal_recover() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path().join("t");
+
+    let b1 = block(vec![1, 2], vec!["a", "b"]);
+    let b2 = block(vec![3], vec!["c"]);
+    let b3 = block(vec![4, 5, 6], vec!["d", "e", "f"]);
+
+    let mut wal = WriteAheadLog::create(&dir, &header())?;
+    wal.append(&[b1.clone()])?;
+    wal.append(&[b2.clone()])?;
+    assert_eq!(wal.rotate()?, 1);
+    wal.append(&[b3.clone()])?;
+    drop(wal);
+
+    let recovered = WriteAheadLog::recover(&dir)?;
+    assert_eq!(recovered.header, header());
+    assert_eq!(recovered.segments.len(), 2);
+    assert_eq!(recovered.segments[0].0, 1);
+    assert_blocks_eq(&recovered.segments[0].1, &[b1, b2]);
+    assert_eq!(recovered.segments[1].0, 2);
+    assert_blocks_eq(&recovered.segments[1].1, &[b3.clone()]);
+
+    // The committed segments are not recovered again.
+    recovered.wal.remove_segments(1)?;
+    drop(recovered);
+    let recovered = WriteAheadLog::recover(&dir)?;
+    assert_eq!(recovered.segments.len(), 2);
+    assert_blocks_eq(&recovered.segments[0].1, &[b3]);
+    assert!(recovered.segments[1].1.is_empty());
+
+    recovered.wal.remove()?;
+    assert!(!dir.exists());
+    Ok(())
+}
+
+#[test]
+fn test_wal_torn_record() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path().join("t");
+
+    let b1 = block(vec![1, 2], vec!["a", "b"]);
+    let mut wal = WriteAheadLog::create(&dir, &header())?;
+    wal.append(&[b1.clone()])?;
+    drop(wal);
+
+    // A crash in the middle of an append.
+    let segment = fs::read_dir(&dir)?
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "wal"))
+        .unwrap();
+    let mut file = OpenOptions::new().append(true).open(segment)?;
+    file.write_all(&100u64.to_le_bytes())?;
+    file.write_all(&[0; 10])?;
+    drop(file);
+
+    let recovered = WriteAheadLog::recover(&dir)?;
+    assert_eq!(recovered.segments.len(), 1);
+    assert_blocks_eq(&recovered.segments[0].1, &[b1]);
+    Ok(())
+}
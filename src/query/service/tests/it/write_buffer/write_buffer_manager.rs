@@ -0,0 +1,256 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::BlockThresholds;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableSchema;
+use common_settings::WriteBufferDurability;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use databend_query::write_buffer::AppendOptions;
+use databend_query::write_buffer::TableKey;
+use databend_query::write_buffer::WriteBufferManager;
+
+// The rows are acknowledged once buffered and are not flushed during the test.
+fn options() -> AppendOptions {
+    AppendOptions {
+        durability: WriteBufferDurability::Buffer,
+        flush_interval: Duration::from_secs(3600),
+        thresholds: BlockThresholds::default(),
+    }
+}
+
+fn block(columns: Vec<Vec<i32>>) -> DataBlock {
+    DataBlock::new_from_columns(columns.into_iter().map(Int32Type::from_data).collect())
+}
+
+async fn table(fixture: &TestFixture) -> Result<(TableKey, u64, TableSchema)> {
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table("default", "default", "t").await?;
+    let key = TableKey {
+        tenant: fixture.default_tenant(),
+        catalog: "default".to_string(),
+        database: "default".to_string(),
+        table: "t".to_string(),
+    };
+    Ok((key, table.get_id(), table.schema().as_ref().clone()))
+}
+
+async fn count(fixture: &TestFixture) -> Result<u64> {
+    query_count(
+        fixture
+            .execute_query("select count(*) from default.t")
+            .await?,
+    )
+    .await
+}
+
+// The write-ahead log of the only buffer of the table.
+fn wal_of_table(wal_dir: &Path) -> Result<PathBuf> {
+    let logs = fs::read_dir(wal_dir)?
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    assert_eq!(logs.len(), 1);
+    Ok(logs[0].clone())
+}
+
+// The recovered rows are committed in the background, the log is removed after that.
+async fn wait_removed(path: &Path) {
+    for _ in 0..100 {
+        if !path.exists() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("write buffer log {:?} is not removed", path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_buffer_labels_after_restart() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t(a int)")
+        .await?;
+    let (key, table_id, schema) = table(&fixture).await?;
+    let dir = tempfile::tempdir()?;
+    let wal_dir = dir.path().to_str().unwrap();
+
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![1, 2]])],
+            &options(),
+        )
+        .await?;
+    manager.flush_table(&key).await?;
+    assert_eq!(count(&fixture).await?, 2);
+    let log = wal_of_table(dir.path())?;
+    drop(manager);
+
+    // The first batch of the buffer after the restart is not taken for the committed one.
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager.recover().await?;
+    wait_removed(&log).await;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![3]])],
+            &options(),
+        )
+        .await?;
+    manager.flush_table(&key).await?;
+    assert_eq!(count(&fixture).await?, 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_buffer_labels_after_schema_change() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t(a int)")
+        .await?;
+    let manager = WriteBufferManager::create("")?;
+
+    let (key, table_id, schema) = table(&fixture).await?;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![1, 2]])],
+            &options(),
+        )
+        .await?;
+    manager.flush_table(&key).await?;
+    assert_eq!(count(&fixture).await?, 2);
+
+    // The table keeps its id, the rows go to a new buffer of the table.
+    fixture
+        .execute_command("alter table default.t add column b int")
+        .await?;
+    let (key, new_table_id, schema) = table(&fixture).await?;
+    assert_eq!(table_id, new_table_id);
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![3], vec![4]])],
+            &options(),
+        )
+        .await?;
+    manager.flush_table(&key).await?;
+    assert_eq!(count(&fixture).await?, 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_buffer_recover_uncommitted() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t(a int)")
+        .await?;
+    let (key, table_id, schema) = table(&fixture).await?;
+    let dir = tempfile::tempdir()?;
+    let wal_dir = dir.path().to_str().unwrap();
+
+    // The node crashes after the rows are acknowledged, before they are committed.
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![1, 2]])],
+            &options(),
+        )
+        .await?;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![3]])],
+            &options(),
+        )
+        .await?;
+    let log = wal_of_table(dir.path())?;
+    drop(manager);
+    assert_eq!(count(&fixture).await?, 0);
+
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager.recover().await?;
+    wait_removed(&log).await;
+    assert_eq!(count(&fixture).await?, 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_buffer_recover_committed() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t(a int)")
+        .await?;
+    let (key, table_id, schema) = table(&fixture).await?;
+    let dir = tempfile::tempdir()?;
+    let wal_dir = dir.path().to_str().unwrap();
+    let backup = tempfile::tempdir()?;
+
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager
+        .append(
+            &key,
+            table_id,
+            &schema,
+            vec![block(vec![vec![1, 2]])],
+            &options(),
+        )
+        .await?;
+    let log = wal_of_table(dir.path())?;
+    for entry in fs::read_dir(&log)? {
+        let path = entry?.path();
+        fs::copy(&path, backup.path().join(path.file_name().unwrap()))?;
+    }
+    manager.flush_table(&key).await?;
+    assert_eq!(count(&fixture).await?, 2);
+    drop(manager);
+
+    // The node crashes after the batch is committed, before its segment is removed.
+    for entry in fs::read_dir(backup.path())? {
+        let path = entry?.path();
+        fs::copy(&path, log.join(path.file_name().unwrap()))?;
+    }
+
+    // The label of the batch is found, the rows are not committed twice.
+    let manager = WriteBufferManager::create(wal_dir)?;
+    manager.recover().await?;
+    wait_removed(&log).await;
+    assert_eq!(count(&fixture).await?, 2);
+    Ok(())
+}
@@ -16,9 +16,12 @@ mod settings;
 mod settings_default;
 mod settings_getter_setter;
 mod settings_global;
+mod settings_profile;
 
 pub use settings::ChangeValue;
 pub use settings::ScopeLevel;
 pub use settings::Settings;
 pub use settings_default::ReplaceIntoShuffleStrategy;
 pub use settings_default::SettingMode;
+pub use settings_default::WriteBufferDurability;
+pub use settings_getter_setter::MAX_QUERY_TAG_LENGTH;
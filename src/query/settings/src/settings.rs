@@ -31,6 +31,8 @@ use crate::SettingMode;
 pub enum ScopeLevel {
     Global,
     Session,
+    // Applied from the settings profile of the user or the role at login.
+    Profile,
 }
 
 impl Debug for ScopeLevel {
@@ -42,6 +44,9 @@ impl Debug for ScopeLevel {
             ScopeLevel::Session => {
                 write!(f, "SESSION")
             }
+            ScopeLevel::Profile => {
+                write!(f, "PROFILE")
+            }
         }
     }
 }
@@ -21,6 +21,8 @@ use common_exception::Result;
 use common_meta_app::principal::UserSettingValue;
 use once_cell::sync::OnceCell;
 
+use crate::Settings;
+
 static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
 
 // Settings for readability and writability of tags.
@@ -71,6 +73,18 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("max_rows_per_block", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000 * 1000),
+                    desc: "Sets the maximum rows of a block written to fuse tables without the row_per_block option.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("max_bytes_per_block", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100 * 1024 * 1024),
+                    desc: "Sets the maximum in-memory byte size of a block written to fuse tables without the block_size_threshold option.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("max_threads", DefaultSettingValue {
                     value: UserSettingValue::UInt64(num_cpus),
                     desc: "Sets the maximum number of threads to execute a request.",
@@ -90,6 +104,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("vacuum_fail_on_deletion_error", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Fails VACUUM TABLE if any of the files failed to be removed.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("max_storage_io_requests", DefaultSettingValue {
                     value: UserSettingValue::UInt64(default_max_storage_io_requests),
                     desc: "Sets the maximum number of concurrent I/O requests.",
@@ -227,12 +247,48 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_join_skew_mitigation", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables detecting hot join keys at runtime in the hash shuffle of distributed joins, and replicating the build rows of the hot keys to all nodes so that their probe rows are not sent to a single node.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("join_skew_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(60),
+                    desc: "Sets the percentage of the probe rows that a single node must receive for the join to be considered skewed.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("join_skew_detection_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the number of probe rows sampled on each node before the hot join keys are decided.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("join_skew_wait_timeout_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3000),
+                    desc: "Sets the maximum time in milliseconds to wait for all nodes to report their hot join keys.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("storage_prefetch_part_num", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of partitions that each async parquet block reader prefetches ahead of decoding. Setting it to 0 disables prefetching.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("storage_prefetch_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of block data that each async parquet block reader prefetches ahead of decoding.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("load_file_metadata_expire_hours", DefaultSettingValue {
                     value: UserSettingValue::UInt64(24 * 7),
                     desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
@@ -275,6 +331,18 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_function_result_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables caching the results of expensive deterministic functions on low-cardinality inputs within a query.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("function_result_cache_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of the cached function results of a query.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("query_result_cache_ttl_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(300), // seconds
                     desc: "Sets the time-to-live (TTL) in seconds for cached query results. \
@@ -324,6 +392,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_streaming_time_rollup", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables grouping by ranges of sorted rows for GROUP BY a truncation of the leading cluster key (enabled by default).",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
@@ -369,12 +443,48 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Write,
                 }),
+                ("deduplicate_label_expire_hours", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(24),
+                    desc: "Sets the hours a deduplicate label is kept after the commit, a retry with the label within the period is not committed again.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("enable_write_buffer", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Buffers the rows of INSERT VALUES on the node and commits them in batches, instead of one commit per statement.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("write_buffer_flush_interval_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum time in milliseconds the buffered rows of a table wait before they are committed.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("write_buffer_durability", DefaultSettingValue {
+                    value: UserSettingValue::String("commit".to_owned()),
+                    desc: "Sets when a buffered INSERT returns. Available values include \"commit\" (after its rows are committed) and \"buffer\" (after its rows are buffered).",
+                    possible_values: Some(vec!["commit", "buffer"]),
+                    mode: SettingMode::Both,
+                }),
+                ("enable_write_buffer_read", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Commits the buffered rows of a table on the node before reading it, so the query sees them. Each read of the table commits the rows buffered so far instead of waiting for the flush interval.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable distributed execution of copy into.",
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_copy_into_detailed_result", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Returns one row per file with its size, rows parsed, rows loaded, errors and status for COPY INTO table.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("enable_experimental_merge_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable experimental merge into.",
@@ -483,6 +593,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_strict_cluster_key_check", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Rejects a cluster key which does not preserve the order of its input instead of warning.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("enable_parquet_page_index", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables parquet page index",
@@ -531,6 +647,24 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("query_tag", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets a tag of the queries, recorded in the process list, query log, audit log and query profile to attribute the load.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("warehouse", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the label of the cluster nodes to run the queries on, the coordinator node always takes part in the queries.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("enable_strict_warehouse_routing", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables failing the queries if no cluster node matches the warehouse setting, instead of running on all the nodes.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -634,6 +768,10 @@ impl DefaultSettings {
                         let u64_val = val.parse::<u64>()?;
                         Ok((k, Some(UserSettingValue::UInt64(u64_val))))
                     }
+                    UserSettingValue::String(_) if k == "query_tag" => {
+                        let tag = Settings::sanitize_query_tag(&v)?;
+                        Ok((k, Some(UserSettingValue::String(tag))))
+                    }
                     UserSettingValue::String(_) => Ok((k, Some(UserSettingValue::String(v)))),
                 }
             }
@@ -702,3 +840,11 @@ impl TryFrom<u64> for ReplaceIntoShuffleStrategy {
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteBufferDurability {
+    /// The statement returns after its rows are committed.
+    Commit,
+    /// The statement returns after its rows are buffered.
+    Buffer,
+}
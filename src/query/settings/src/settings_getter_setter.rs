@@ -23,6 +23,10 @@ use crate::ChangeValue;
 use crate::ReplaceIntoShuffleStrategy;
 use crate::ScopeLevel;
 use crate::SettingMode;
+use crate::WriteBufferDurability;
+
+/// The max length in chars of the `query_tag` setting.
+pub const MAX_QUERY_TAG_LENGTH: usize = 256;
 
 impl Settings {
     // Get u64 value, we don't get from the metasrv.
@@ -114,6 +118,14 @@ impl Settings {
         self.try_get_u64("parquet_max_block_size")
     }
 
+    pub fn get_max_rows_per_block(&self) -> Result<u64> {
+        self.try_get_u64("max_rows_per_block")
+    }
+
+    pub fn get_max_bytes_per_block(&self) -> Result<u64> {
+        self.try_get_u64("max_bytes_per_block")
+    }
+
     // Get max_threads.
     pub fn get_max_threads(&self) -> Result<u64> {
         match self.try_get_u64("max_threads")? {
@@ -135,6 +147,16 @@ impl Settings {
         }
     }
 
+    // Get storage_prefetch_part_num.
+    pub fn get_storage_prefetch_part_num(&self) -> Result<u64> {
+        self.try_get_u64("storage_prefetch_part_num")
+    }
+
+    // Get storage_prefetch_max_bytes.
+    pub fn get_storage_prefetch_max_bytes(&self) -> Result<u64> {
+        self.try_get_u64("storage_prefetch_max_bytes")
+    }
+
     // Get parquet_uncompressed_buffer_size.
     pub fn get_parquet_uncompressed_buffer_size(&self) -> Result<u64> {
         self.try_get_u64("parquet_uncompressed_buffer_size")
@@ -156,6 +178,10 @@ impl Settings {
         self.try_get_u64("retention_period")
     }
 
+    pub fn get_vacuum_fail_on_deletion_error(&self) -> Result<bool> {
+        Ok(self.try_get_u64("vacuum_fail_on_deletion_error")? != 0)
+    }
+
     pub fn get_max_storage_io_requests(&self) -> Result<u64> {
         self.try_get_u64("max_storage_io_requests")
     }
@@ -251,6 +277,22 @@ impl Settings {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
 
+    pub fn get_enable_join_skew_mitigation(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_join_skew_mitigation")? != 0)
+    }
+
+    pub fn get_join_skew_threshold(&self) -> Result<u64> {
+        self.try_get_u64("join_skew_threshold")
+    }
+
+    pub fn get_join_skew_detection_rows(&self) -> Result<u64> {
+        self.try_get_u64("join_skew_detection_rows")
+    }
+
+    pub fn get_join_skew_wait_timeout_ms(&self) -> Result<u64> {
+        self.try_get_u64("join_skew_wait_timeout_ms")
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -295,6 +337,14 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
 
+    pub fn get_enable_function_result_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_function_result_cache")? != 0)
+    }
+
+    pub fn get_function_result_cache_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("function_result_cache_max_bytes")? as usize)
+    }
+
     pub fn get_http_handler_result_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
@@ -323,6 +373,10 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_enable_streaming_time_rollup(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_streaming_time_rollup")? != 0)
+    }
+
     pub fn get_lazy_read_threshold(&self) -> Result<u64> {
         self.try_get_u64("lazy_read_threshold")
     }
@@ -376,10 +430,37 @@ impl Settings {
         self.unchecked_set_setting("deduplicate_label".to_string(), val)
     }
 
+    pub fn get_deduplicate_label_expire_hours(&self) -> Result<u64> {
+        self.try_get_u64("deduplicate_label_expire_hours")
+    }
+
+    pub fn get_enable_write_buffer(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_write_buffer")? != 0)
+    }
+
+    pub fn get_write_buffer_flush_interval_ms(&self) -> Result<u64> {
+        self.try_get_u64("write_buffer_flush_interval_ms")
+    }
+
+    pub fn get_write_buffer_durability(&self) -> Result<WriteBufferDurability> {
+        match self.try_get_string("write_buffer_durability")?.as_str() {
+            "buffer" => Ok(WriteBufferDurability::Buffer),
+            _ => Ok(WriteBufferDurability::Commit),
+        }
+    }
+
+    pub fn get_enable_write_buffer_read(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_write_buffer_read")? != 0)
+    }
+
     pub fn get_enable_distributed_copy(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_distributed_copy_into")? != 0)
     }
 
+    pub fn get_enable_copy_into_detailed_result(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_copy_into_detailed_result")? != 0)
+    }
+
     pub fn get_enable_experimental_merge_into(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_merge_into")? != 0)
     }
@@ -449,6 +530,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_recluster")? != 0)
     }
 
+    pub fn get_enable_strict_cluster_key_check(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_strict_cluster_key_check")? != 0)
+    }
+
     pub fn get_enable_refresh_aggregating_index_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_refresh_aggregating_index_after_write")? != 0)
     }
@@ -495,4 +580,39 @@ impl Settings {
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
+
+    pub fn get_warehouse(&self) -> Result<String> {
+        self.try_get_string("warehouse")
+    }
+
+    pub fn get_enable_strict_warehouse_routing(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_strict_warehouse_routing")? != 0)
+    }
+
+    pub fn get_query_tag(&self) -> Result<String> {
+        self.try_get_string("query_tag")
+    }
+
+    pub fn set_query_tag(&self, val: String) -> Result<()> {
+        self.set_setting("query_tag".to_string(), val)
+    }
+
+    /// Removes the control characters and the surrounding whitespaces of a query tag,
+    /// the tag is rejected if it is still longer than `MAX_QUERY_TAG_LENGTH` chars.
+    pub fn sanitize_query_tag(tag: &str) -> Result<String> {
+        let tag = tag
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect::<String>()
+            .trim()
+            .to_string();
+        if tag.chars().count() > MAX_QUERY_TAG_LENGTH {
+            return Err(ErrorCode::WrongValueForVariable(format!(
+                "Invalid setting value for variable \"query_tag\", the tag must be at most {} chars, but got {}",
+                MAX_QUERY_TAG_LENGTH,
+                tag.chars().count()
+            )));
+        }
+        Ok(tag)
+    }
 }
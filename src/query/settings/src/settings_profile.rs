@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SettingsProfile;
+use common_meta_app::principal::UserSettingValue;
+use log::warn;
+
+use crate::settings::ChangeValue;
+use crate::settings::Settings;
+use crate::settings_default::DefaultSettings;
+use crate::ScopeLevel;
+use crate::SettingMode;
+
+impl Settings {
+    /// Checks a setting of a settings profile the same way as `SET` does, the setting
+    /// must be writable and the value must match its type and possible values.
+    pub fn check_profile_setting(k: &str, v: &str) -> Result<UserSettingValue> {
+        DefaultSettings::check_setting_mode(k, SettingMode::Write)?;
+
+        match DefaultSettings::convert_value(k.to_string(), v.to_string())? {
+            (_, Some(value)) => Ok(value),
+            (_, None) => Err(ErrorCode::UnknownVariable(format!(
+                "Unknown variable: {:?}",
+                k
+            ))),
+        }
+    }
+
+    /// Applies the settings of a profile as the defaults of the session.
+    ///
+    /// The settings already set in the session are kept. The settings no longer valid,
+    /// e.g. removed since the profile is stored, are ignored.
+    pub fn apply_settings_profile(&self, settings_profile: &SettingsProfile) {
+        for (name, value) in &settings_profile.settings {
+            let set_in_session = self
+                .changes
+                .get(name)
+                .map(|change| matches!(change.level, ScopeLevel::Session))
+                .unwrap_or(false);
+            if set_in_session {
+                continue;
+            }
+
+            match Self::check_profile_setting(name, value) {
+                Ok(value) => {
+                    self.changes.insert(name.clone(), ChangeValue {
+                        level: ScopeLevel::Profile,
+                        value,
+                    });
+                }
+                Err(e) => warn!(
+                    "Ignore invalid setting {} = {} of settings profile {}: {}",
+                    name, value, settings_profile.name, e
+                ),
+            }
+        }
+    }
+}
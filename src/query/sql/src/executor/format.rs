@@ -49,6 +49,7 @@ use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Unnest;
 use crate::executor::physical_plans::Window;
 use crate::executor::physical_plans::WindowFunction;
 use crate::executor::PhysicalPlan;
@@ -202,6 +203,7 @@ fn to_format_tree(
         PhysicalPlan::CommitSink(plan) => commit_sink_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Unnest(plan) => unnest_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
@@ -314,6 +316,10 @@ fn table_scan_to_format_tree(
         )),
     ];
 
+    if let Some(travel_point) = metadata.travel_point_by_table_index(plan.table_index) {
+        children.push(FormatTreeNode::new(format!("travel point: {travel_point}")));
+    }
+
     // Part stats.
     children.extend(part_stats_info_to_format_tree(&plan.source.statistics));
     // Push downs.
@@ -575,6 +581,10 @@ fn aggregate_partial_to_format_tree(
         FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
     ];
 
+    if plan.time_rollup {
+        children.push(FormatTreeNode::new("streaming time rollup".to_string()));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -928,7 +938,7 @@ fn exchange_to_format_tree(
     metadata: &Metadata,
     prof_span_set: &SharedProcessorProfiles,
 ) -> Result<FormatTreeNode<String>> {
-    Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
+    let mut children = vec![
         FormatTreeNode::new(format!(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
@@ -946,8 +956,21 @@ fn exchange_to_format_tree(
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
         })),
-        to_format_tree(&plan.input, metadata, prof_span_set)?,
-    ]))
+    ];
+
+    if let Some(skew) = &plan.skew {
+        children.push(FormatTreeNode::new(format!(
+            "skew mitigation: {} side",
+            skew.side
+        )));
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Exchange".to_string(),
+        children,
+    ))
 }
 
 fn union_all_to_format_tree(
@@ -987,14 +1010,25 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
     ];
 
     if info.pruning_stats.segments_range_pruning_before > 0 {
+        let inverted_index_pruning = if info.pruning_stats.blocks_inverted_index_pruning_before > 0
+        {
+            format!(
+                ", inverted index pruning: {} to {}",
+                info.pruning_stats.blocks_inverted_index_pruning_before,
+                info.pruning_stats.blocks_inverted_index_pruning_after,
+            )
+        } else {
+            "".to_string()
+        };
         items.push(FormatTreeNode::new(format!(
-            "pruning stats: [segments: <range pruning: {} to {}>, blocks: <range pruning: {} to {}, bloom pruning: {} to {}>]",
+            "pruning stats: [segments: <range pruning: {} to {}>, blocks: <range pruning: {} to {}, bloom pruning: {} to {}{}>]",
             info.pruning_stats.segments_range_pruning_before,
             info.pruning_stats.segments_range_pruning_after,
             info.pruning_stats.blocks_range_pruning_before,
             info.pruning_stats.blocks_range_pruning_after,
             info.pruning_stats.blocks_bloom_pruning_before,
             info.pruning_stats.blocks_bloom_pruning_after,
+            inverted_index_pruning,
         )))
     }
 
@@ -1156,6 +1190,37 @@ fn udf_to_format_tree(
     Ok(FormatTreeNode::with_children("Udf".to_string(), children))
 }
 
+fn unnest_to_format_tree(
+    plan: &Unnest,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![
+        FormatTreeNode::new(format!("array: {}", metadata.column(plan.array).name())),
+        FormatTreeNode::new(format!("outer: {}", plan.outer)),
+        FormatTreeNode::new(format!("with ordinality: {}", plan.ordinality.is_some())),
+    ]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children(
+        "Unnest".to_string(),
+        children,
+    ))
+}
+
 fn materialized_cte_to_format_tree(
     plan: &MaterializedCte,
     metadata: &Metadata,
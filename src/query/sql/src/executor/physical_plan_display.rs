@@ -54,6 +54,7 @@ use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Unnest;
 use crate::executor::physical_plans::UpdateSource;
 use crate::executor::physical_plans::Window;
 use crate::plans::JoinType;
@@ -113,6 +114,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ReclusterSink(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::UpdateSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::Udf(udf) => write!(f, "{}", udf)?,
+            PhysicalPlan::Unnest(unnest) => write!(f, "{}", unnest)?,
         }
 
         for node in self.node.children() {
@@ -523,3 +525,9 @@ impl Display for Udf {
         write!(f, "Udf functions: {}", scalars.join(", "))
     }
 }
+
+impl Display for Unnest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unnest: array: {}, outer: {}", self.array, self.outer)
+    }
+}
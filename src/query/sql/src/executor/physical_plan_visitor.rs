@@ -52,6 +52,7 @@ use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Unnest;
 use crate::executor::physical_plans::UpdateSource;
 use crate::executor::physical_plans::Window;
 
@@ -97,6 +98,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ReclusterSink(plan) => self.replace_recluster_sink(plan),
             PhysicalPlan::UpdateSource(plan) => self.replace_update_source(plan),
             PhysicalPlan::Udf(plan) => self.replace_udf(plan),
+            PhysicalPlan::Unnest(plan) => self.replace_unnest(plan),
         }
     }
 
@@ -180,6 +182,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             group_by: plan.group_by.clone(),
             agg_funcs: plan.agg_funcs.clone(),
+            time_rollup: plan.time_rollup,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -315,6 +318,7 @@ pub trait PhysicalPlanReplacer {
             keys: plan.keys.clone(),
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            skew: plan.skew,
         }))
     }
 
@@ -338,6 +342,7 @@ pub trait PhysicalPlanReplacer {
             query_id: plan.query_id.clone(),
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            skew: plan.skew,
         }))
     }
 
@@ -490,6 +495,21 @@ pub trait PhysicalPlanReplacer {
             stat_info: plan.stat_info.clone(),
         }))
     }
+
+    fn replace_unnest(&mut self, plan: &Unnest) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Unnest(Unnest {
+            plan_id: plan.plan_id,
+            projections: plan.projections.clone(),
+            input: Box::new(input),
+            array: plan.array,
+            array_offset: plan.array_offset,
+            element: plan.element,
+            ordinality: plan.ordinality,
+            outer: plan.outer,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
 }
 
 impl PhysicalPlan {
@@ -602,6 +622,9 @@ impl PhysicalPlan {
                 PhysicalPlan::Udf(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Unnest(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
@@ -69,6 +69,32 @@ pub enum FragmentKind {
     Merge,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkewJoinSide {
+    Probe,
+    Build,
+}
+
+/// Marks the hash exchange of one side of a skew-tolerant join.
+///
+/// The rows of the hot join keys detected at runtime are replicated to all nodes on the
+/// build side, and kept on the local node on the probe side.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExchangeSkew {
+    // The plan id of the hash join, both sides of a join share it.
+    pub join_id: u32,
+    pub side: SkewJoinSide,
+}
+
+impl Display for SkewJoinSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkewJoinSide::Probe => write!(f, "probe"),
+            SkewJoinSide::Build => write!(f, "build"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Copy)]
 pub enum MutationKind {
     Delete,
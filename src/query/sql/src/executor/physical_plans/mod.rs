@@ -90,3 +90,5 @@ pub use physical_window::*;
 mod physical_udf;
 pub use physical_udf::Udf;
 pub use physical_udf::UdfFunctionDesc;
+mod physical_unnest;
+pub use physical_unnest::Unnest;
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -150,6 +151,8 @@ impl PhysicalPlanBuilder {
 
                 let settings = self.ctx.get_settings();
                 let group_by_shuffle_mode = settings.get_group_by_shuffle_mode()?;
+                let time_rollup = settings.get_enable_streaming_time_rollup()?
+                    && self.is_time_rollup(s_expr.child(0)?, &agg);
 
                 if let Some(grouping_sets) = agg.grouping_sets.as_ref() {
                     assert_eq!(grouping_sets.dup_group_items.len(), group_items.len() - 1); // ignore `_grouping_id`.
@@ -185,6 +188,7 @@ impl PhysicalPlanBuilder {
                                 input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                 agg_funcs,
                                 group_by: group_items,
+                                time_rollup,
                                 stat_info: Some(stat_info),
                             }
                         } else {
@@ -193,6 +197,7 @@ impl PhysicalPlanBuilder {
                                 input,
                                 agg_funcs,
                                 group_by: group_items,
+                                time_rollup,
                                 stat_info: Some(stat_info),
                             }
                         };
@@ -216,6 +221,7 @@ impl PhysicalPlanBuilder {
                             kind,
                             allow_adjust_parallelism: true,
                             ignore_exchange: false,
+                            skew: None,
                             input: Box::new(PhysicalPlan::AggregatePartial(aggregate_partial)),
                             keys: vec![RemoteExpr::ColumnRef {
                                 span: None,
@@ -238,6 +244,7 @@ impl PhysicalPlanBuilder {
                                 plan_id: self.next_plan_id(),
                                 agg_funcs,
                                 group_by: group_items,
+                                time_rollup,
                                 input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                 stat_info: Some(stat_info),
                             })
@@ -246,6 +253,7 @@ impl PhysicalPlanBuilder {
                                 plan_id: self.next_plan_id(),
                                 agg_funcs,
                                 group_by: group_items,
+                                time_rollup,
                                 input: Box::new(input),
                                 stat_info: Some(stat_info),
                             })
@@ -367,4 +375,76 @@ impl PhysicalPlanBuilder {
 
         Ok(result)
     }
+
+    /// Checks whether the partial aggregation can group the sorted rows of each block by
+    /// ranges instead of hashing every row. That's the case when the only group item is a
+    /// monotonic truncation of a timestamp or date column, which is the leading cluster key
+    /// of the table scanned below: the blocks of a clustered table are sorted by the cluster
+    /// key, so the rows of a group are adjacent. The executor still checks that every block
+    /// is sorted and falls back to hashing otherwise.
+    fn is_time_rollup(&self, s_expr: &SExpr, agg: &crate::plans::Aggregate) -> bool {
+        if agg.group_items.len() != 1 || agg.grouping_sets.is_some() {
+            return false;
+        }
+        let column = match &agg.group_items[0].scalar {
+            ScalarExpr::FunctionCall(func)
+                if TIME_ROLLUP_FUNCTIONS.contains(&func.func_name.as_str())
+                    && func.arguments.len() == 1 =>
+            {
+                match &func.arguments[0] {
+                    ScalarExpr::BoundColumnRef(col)
+                        if matches!(
+                            col.column.data_type.remove_nullable(),
+                            DataType::Timestamp | DataType::Date
+                        ) =>
+                    {
+                        &col.column
+                    }
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        let table_index = match column.table_index {
+            Some(table_index) => table_index,
+            None => return false,
+        };
+
+        // The rows must come from the table scan without being reordered.
+        let mut s_expr = s_expr;
+        loop {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Exchange(_) => {
+                    match s_expr.child(0) {
+                        Ok(child) => s_expr = child,
+                        Err(_) => return false,
+                    }
+                }
+                RelOperator::Scan(scan) if scan.table_index == table_index => break,
+                _ => return false,
+            }
+        }
+
+        let table = self.metadata.read().table(table_index).table();
+        matches!(
+            table.cluster_keys(self.ctx.clone()).first(),
+            Some(RemoteExpr::ColumnRef { id, .. }) if id == &column.column_name
+        )
+    }
 }
+
+/// Monotonic truncations of a timestamp or date, `date_trunc` is resolved to one of them.
+const TIME_ROLLUP_FUNCTIONS: &[&str] = &[
+    "to_start_of_second",
+    "to_start_of_minute",
+    "to_start_of_five_minutes",
+    "to_start_of_ten_minutes",
+    "to_start_of_fifteen_minutes",
+    "to_start_of_hour",
+    "to_start_of_day",
+    "to_start_of_week",
+    "to_start_of_month",
+    "to_start_of_quarter",
+    "to_start_of_year",
+    "to_start_of_iso_year",
+];
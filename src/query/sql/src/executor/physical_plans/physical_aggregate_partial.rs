@@ -31,6 +31,9 @@ pub struct AggregatePartial {
     pub input: Box<PhysicalPlan>,
     pub group_by: Vec<IndexType>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
+    /// Group the sorted rows of each block by ranges instead of hashing every row,
+    /// see `PhysicalPlanBuilder::is_time_rollup`.
+    pub time_rollup: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
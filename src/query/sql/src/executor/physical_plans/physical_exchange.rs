@@ -18,6 +18,7 @@ use common_expression::DataSchemaRef;
 use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
+use crate::executor::physical_plans::common::ExchangeSkew;
 use crate::executor::physical_plans::common::FragmentKind;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
@@ -35,6 +36,8 @@ pub struct Exchange {
     pub keys: Vec<RemoteExpr>,
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
+    // Set if the exchange is one side of a skew-tolerant join.
+    pub skew: Option<ExchangeSkew>,
 }
 
 impl Exchange {
@@ -90,6 +93,7 @@ impl PhysicalPlanBuilder {
             keys,
             allow_adjust_parallelism,
             ignore_exchange: false,
+            skew: None,
         }))
     }
 }
@@ -16,6 +16,7 @@ use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_expression::RemoteExpr;
 
+use crate::executor::physical_plans::common::ExchangeSkew;
 use crate::executor::physical_plans::common::FragmentKind;
 use crate::executor::PhysicalPlan;
 
@@ -36,6 +37,7 @@ pub struct ExchangeSink {
     pub query_id: String,
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
+    pub skew: Option<ExchangeSkew>,
 }
 
 impl ExchangeSink {
@@ -27,6 +27,9 @@ use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::ExchangeSkew;
+use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::SkewJoinSide;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
@@ -87,6 +90,7 @@ impl PhysicalPlanBuilder {
     ) -> Result<PhysicalPlan> {
         let mut probe_side = Box::new(self.build(s_expr.child(0)?, required.0).await?);
         let mut build_side = Box::new(self.build(s_expr.child(1)?, required.1).await?);
+        let plan_id = self.next_plan_id();
         // Unify the data types of the left and right exchange keys.
         if let (
             PhysicalPlan::Exchange(Exchange {
@@ -130,6 +134,35 @@ impl PhysicalPlanBuilder {
             }
         }
 
+        if self.ctx.get_settings().get_enable_join_skew_mitigation()?
+            && Self::is_skew_tolerant(join)
+        {
+            if let (
+                PhysicalPlan::Exchange(
+                    probe_exchange @ Exchange {
+                        kind: FragmentKind::Normal,
+                        ..
+                    },
+                ),
+                PhysicalPlan::Exchange(
+                    build_exchange @ Exchange {
+                        kind: FragmentKind::Normal,
+                        ..
+                    },
+                ),
+            ) = (probe_side.as_mut(), build_side.as_mut())
+            {
+                probe_exchange.skew = Some(ExchangeSkew {
+                    join_id: plan_id,
+                    side: SkewJoinSide::Probe,
+                });
+                build_exchange.skew = Some(ExchangeSkew {
+                    join_id: plan_id,
+                    side: SkewJoinSide::Build,
+                });
+            }
+        }
+
         let build_schema = match join.join_type {
             JoinType::Left | JoinType::LeftSingle | JoinType::Full => {
                 let build_schema = build_side.output_schema()?;
@@ -370,7 +403,7 @@ impl PhysicalPlanBuilder {
         let output_schema = DataSchemaRefExt::create(output_fields);
 
         Ok(PhysicalPlan::HashJoin(HashJoin {
-            plan_id: self.next_plan_id(),
+            plan_id,
             projections,
             build_projections,
             probe_projections,
@@ -400,4 +433,13 @@ impl PhysicalPlanBuilder {
             stat_info: Some(stat_info),
         }))
     }
+
+    // The build rows of the hot keys are replicated to all nodes, so only the joins
+    // whose result is driven by the probe rows can tolerate a skewed shuffle.
+    fn is_skew_tolerant(join: &Join) -> bool {
+        matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        ) && !join.need_hold_hash_table
+    }
 }
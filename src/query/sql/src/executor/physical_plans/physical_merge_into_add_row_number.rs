@@ -56,7 +56,9 @@ impl PhysicalPlanBuilder {
             ));
         }
         let mut cluster_index = BTreeMap::new();
-        for (id, node) in self.ctx.get_cluster().nodes.iter().enumerate() {
+        // The row numbers are generated by the nodes executing the query.
+        let executor_nodes = self.ctx.get_cluster().get_executor_nodes();
+        for (id, node) in executor_nodes.iter().enumerate() {
             cluster_index.insert(node.id.clone(), id);
         }
         let input_schema = input_plan.output_schema()?;
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Unnest {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub projections: ColumnSet,
+    pub input: Box<PhysicalPlan>,
+    pub array: IndexType,
+    // The offset of the array column in the input schema.
+    pub array_offset: usize,
+    pub element: IndexType,
+    pub ordinality: Option<IndexType>,
+    pub outer: bool,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Unnest {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(input_schema.num_fields() + 2);
+        for (i, field) in input_schema.fields().iter().enumerate() {
+            if self.projections.contains(&i) {
+                fields.push(field.clone());
+            }
+        }
+        let array_type = input_schema.field(self.array_offset).data_type();
+        fields.push(DataField::new(
+            &self.element.to_string(),
+            crate::plans::Unnest::element_type(array_type, self.outer)?,
+        ));
+        if let Some(ordinality) = self.ordinality {
+            fields.push(DataField::new(
+                &ordinality.to_string(),
+                crate::plans::Unnest::ordinality_type(self.outer),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_unnest(
+        &mut self,
+        s_expr: &SExpr,
+        unnest: &crate::plans::Unnest,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        let column_projections = required.clone().into_iter().collect::<Vec<_>>();
+        required.insert(unnest.array);
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        let input_schema = input.output_schema()?;
+        let array_offset = input_schema
+            .index_of(&unnest.array.to_string())
+            .map_err(|_| ErrorCode::Internal("Unable to get the array column of unnest"))?;
+
+        let mut projections = ColumnSet::new();
+        for column in column_projections.iter() {
+            if let Some((index, _)) = input_schema.column_with_name(&column.to_string()) {
+                projections.insert(index);
+            }
+        }
+
+        Ok(PhysicalPlan::Unnest(Unnest {
+            plan_id: self.next_plan_id(),
+            projections,
+            input: Box::new(input),
+            array: unnest.array,
+            array_offset,
+            element: unnest.element,
+            ordinality: unnest.ordinality,
+            outer: unnest.outer,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
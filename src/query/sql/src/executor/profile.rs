@@ -46,6 +46,7 @@ pub struct ProfileHelper;
 impl ProfileHelper {
     pub fn build_query_profile(
         query_id: &str,
+        query_tag: &str,
         metadata: &MetadataRef,
         plan: &PhysicalPlan,
         profs: &ProcessorProfiles,
@@ -54,7 +55,11 @@ impl ProfileHelper {
         let metadata = metadata.read().clone();
         flatten_plan_node_profile(&metadata, plan, profs, &mut plan_node_profs)?;
 
-        Ok(QueryProfile::new(query_id.to_string(), plan_node_profs))
+        Ok(QueryProfile::new(
+            query_id.to_string(),
+            query_tag.to_string(),
+            plan_node_profs,
+        ))
     }
 }
 
@@ -487,6 +492,18 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::Unnest(unnest) => {
+            flatten_plan_node_profile(metadata, &unnest.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&unnest.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: unnest.plan_id,
+                operator_type: OperatorType::Unnest,
+                execution_info: proc_prof.into(),
+                children: vec![unnest.input.get_id()],
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::MaterializedCte(_) => todo!(),
         PhysicalPlan::DeleteSource(_)
         | PhysicalPlan::CommitSink(_)
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use chrono_tz::Tz;
 use common_ast::ast::format_statement;
+use common_ast::ast::AlterFileFormatAction;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Hint;
 use common_ast::ast::Identifier;
@@ -43,6 +44,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::plans::AlterFileFormatPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -57,6 +59,7 @@ use crate::plans::Plan;
 use crate::plans::RelOperator;
 use crate::plans::RewriteKind;
 use crate::plans::ShowConnectionsPlan;
+use crate::plans::ShowCreateStagePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
@@ -94,6 +97,10 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    // Whether the statement is bound for EXPLAIN or EXPLAIN ANALYZE, which decides
+    // how the row access policies are applied, see `bind_row_access_policy`.
+    pub in_explain: bool,
+    pub in_explain_analyze: bool,
 }
 
 impl<'a> Binder {
@@ -114,6 +121,8 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            in_explain: false,
+            in_explain_analyze: false,
         }
     }
 
@@ -180,6 +189,28 @@ impl<'a> Binder {
         self.ctx.get_settings().set_batch_settings(&hint_settings)
     }
 
+    /// Uses the `IDEMPOTENT_TOKEN` of a statement writing the table as its deduplicate label,
+    /// so that the statement is committed at most once for the token.
+    pub(crate) fn set_idempotent_token(
+        &self,
+        table_id: u64,
+        idempotent_token: &Option<String>,
+    ) -> Result<()> {
+        if let Some(token) = idempotent_token {
+            // the label is a key of the meta store, keep the tokens in their own namespace
+            let label = format!(
+                "__fd_idempotent_tokens/{}/{}/{}",
+                self.ctx.get_tenant(),
+                table_id,
+                token
+            );
+            unsafe {
+                self.ctx.get_settings().set_deduplicate_label(label)?;
+            }
+        }
+        Ok(())
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub(crate) async fn bind_statement(
@@ -188,6 +219,9 @@ impl<'a> Binder {
         stmt: &Statement,
     ) -> Result<Plan> {
         let plan = match stmt {
+            Statement::Query(query) if query.into.is_some() => {
+                self.bind_query_into(bind_context, query).await?
+            }
             Statement::Query(query) => {
                 let (mut s_expr, bind_context) = self.bind_query(bind_context, query).await?;
                 // Wrap `LogicalMaterializedCte` to `s_expr`
@@ -222,11 +256,24 @@ impl<'a> Binder {
                 match kind {
                     ExplainKind::Ast(formatted_stmt) => Plan::ExplainAst { formatted_string: formatted_stmt.clone() },
                     ExplainKind::Syntax(formatted_sql) => Plan::ExplainSyntax { formatted_sql: formatted_sql.clone() },
-                    _ => Plan::Explain { kind: kind.clone(), plan: Box::new(self.bind_statement(bind_context, query).await?) },
+                    ExplainKind::AnalyzeFragments => {
+                        // the query is executed, like EXPLAIN ANALYZE
+                        self.in_explain_analyze = true;
+                        Plan::Explain { kind: kind.clone(), plan: Box::new(self.bind_statement(bind_context, query).await?) }
+                    }
+                    ExplainKind::DryRun => {
+                        // the statement is planned as if it were executed
+                        Plan::Explain { kind: kind.clone(), plan: Box::new(self.bind_statement(bind_context, query).await?) }
+                    }
+                    _ => {
+                        self.in_explain = true;
+                        Plan::Explain { kind: kind.clone(), plan: Box::new(self.bind_statement(bind_context, query).await?) }
+                    }
                 }
             }
 
             Statement::ExplainAnalyze { query } => {
+                self.in_explain_analyze = true;
                 let plan = self.bind_statement(bind_context, query).await?;
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
@@ -235,6 +282,10 @@ impl<'a> Binder {
                 self.bind_show_functions(bind_context, show_options).await?
             }
 
+            Statement::DescribeFunction { name } => {
+                self.bind_describe_function(bind_context, name).await?
+            }
+
             Statement::ShowTableFunctions { show_options } => {
                 self.bind_show_table_functions(bind_context, show_options).await?
             }
@@ -315,6 +366,9 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+            Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -328,7 +382,7 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 user: user.clone(),
             })),
-            Statement::ShowUsers => self.bind_rewrite_to_query(bind_context, "SELECT name, hostname, auth_type, is_configured FROM system.users ORDER BY name", RewriteKind::ShowUsers).await?,
+            Statement::ShowUsers => self.bind_rewrite_to_query(bind_context, "SELECT name, hostname, auth_type, is_configured, password_policy, password_expired FROM system.users ORDER BY name", RewriteKind::ShowUsers).await?,
             Statement::AlterUser(stmt) => self.bind_alter_user(stmt).await?,
 
             // Roles
@@ -347,6 +401,7 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => self.bind_alter_role(stmt).await?,
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -360,6 +415,10 @@ impl<'a> Binder {
             }
             Statement::DescribeStage { stage_name } => self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM system.stages WHERE name = '{stage_name}'").as_str(), RewriteKind::DescribeStage).await?,
             Statement::CreateStage(stmt) => self.bind_create_stage(stmt).await?,
+            Statement::AlterStage(stmt) => self.bind_alter_stage(stmt).await?,
+            Statement::ShowCreateStage { stage_name } => Plan::ShowCreateStage(Box::new(ShowCreateStagePlan {
+                name: stage_name.clone(),
+            })),
             Statement::DropStage {
                 stage_name,
                 if_exists,
@@ -420,7 +479,7 @@ impl<'a> Binder {
             Statement::Revoke(stmt) => self.bind_revoke(stmt).await?,
 
             // File Formats
-            Statement::CreateFileFormat { if_not_exists, name, file_format_options } => {
+            Statement::CreateFileFormat { if_not_exists, name, file_format_options, comment } => {
                 if StageFileFormatType::from_str(name).is_ok() {
                     return Err(ErrorCode::SyntaxException(format!(
                         "File format {name} is reserved"
@@ -430,6 +489,21 @@ impl<'a> Binder {
                     if_not_exists: *if_not_exists,
                     name: name.clone(),
                     file_format_params: file_format_options.clone().try_into()?,
+                    comment: comment.clone(),
+                }))
+            }
+            Statement::AlterFileFormat(stmt) => {
+                if let AlterFileFormatAction::RenameFileFormat { new_name } = &stmt.action {
+                    if StageFileFormatType::from_str(new_name).is_ok() {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "File format {new_name} is reserved"
+                        )));
+                    }
+                }
+                Plan::AlterFileFormat(Box::new(AlterFileFormatPlan {
+                    if_exists: stmt.if_exists,
+                    name: stmt.name.clone(),
+                    action: stmt.action.clone(),
                 }))
             }
             Statement::DropFileFormat {
@@ -556,6 +630,60 @@ impl<'a> Binder {
             Statement::ShowNetworkPolicies => {
                 self.bind_show_network_policies().await?
             }
+            Statement::CreatePasswordPolicy(stmt) => {
+                self.bind_create_password_policy(stmt).await?
+            }
+            Statement::AlterPasswordPolicy(stmt) => {
+                self.bind_alter_password_policy(stmt).await?
+            }
+            Statement::DropPasswordPolicy(stmt) => {
+                self.bind_drop_password_policy(stmt).await?
+            }
+            Statement::DescPasswordPolicy(stmt) => {
+                self.bind_desc_password_policy(stmt).await?
+            }
+            Statement::ShowPasswordPolicies => {
+                self.bind_show_password_policies().await?
+            }
+            Statement::CreateSettingsProfile(stmt) => {
+                self.bind_create_settings_profile(stmt).await?
+            }
+            Statement::AlterSettingsProfile(stmt) => {
+                self.bind_alter_settings_profile(stmt).await?
+            }
+            Statement::DropSettingsProfile(stmt) => {
+                self.bind_drop_settings_profile(stmt).await?
+            }
+            Statement::DescSettingsProfile(stmt) => {
+                self.bind_desc_settings_profile(stmt).await?
+            }
+            Statement::ShowSettingsProfiles => {
+                self.bind_show_settings_profiles().await?
+            }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
+            Statement::CreateExternalVolume(stmt) => {
+                self.bind_create_external_volume(stmt).await?
+            }
+            Statement::AlterExternalVolume(stmt) => {
+                self.bind_alter_external_volume(stmt).await?
+            }
+            Statement::DropExternalVolume(stmt) => {
+                self.bind_drop_external_volume(stmt).await?
+            }
+            Statement::DescribeExternalVolume(stmt) => {
+                self.bind_desc_external_volume(stmt).await?
+            }
+            Statement::ShowExternalVolumes(_) => {
+                self.bind_show_external_volumes().await?
+            }
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -14,12 +14,14 @@
 
 use common_ast::ast::CopyIntoLocationSource;
 use common_ast::ast::CopyIntoLocationStmt;
+use common_ast::ast::Query;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageInfo;
+use common_storages_stage::FileNameTemplate;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
@@ -77,13 +79,41 @@ impl<'a> Binder {
         self.apply_copy_into_location_options(stmt, &mut stage_info)
             .await?;
 
+        if let Some(template) = &stmt.file_name_template {
+            FileNameTemplate::parse(template)?.check(stage_info.copy_options.single)?;
+        }
+
         Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
+            file_name_template: stmt.file_name_template.clone(),
         }))
     }
 
+    /// Binds `SELECT ... INTO <location>` as `COPY INTO <location> FROM (SELECT ...)`,
+    /// so both statements share the same plan and return the same file manifest.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_query_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        query: &Query,
+    ) -> Result<Plan> {
+        let into = query.into.clone().unwrap();
+        let mut src = query.clone();
+        src.into = None;
+        let stmt = CopyIntoLocationStmt {
+            hints: None,
+            src: CopyIntoLocationSource::Query(Box::new(src)),
+            dst: into.dst,
+            file_format: into.file_format,
+            single: into.single,
+            max_file_size: into.max_file_size,
+            file_name_template: into.file_name_template,
+        };
+        self.bind_copy_into_location(bind_context, &stmt).await
+    }
+
     #[async_backtrace::framed]
     pub async fn apply_copy_into_location_options(
         &mut self,
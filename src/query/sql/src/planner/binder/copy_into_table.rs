@@ -47,6 +47,7 @@ use common_expression::Scalar;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
 use common_storage::StageFilesInfo;
 use common_users::UserApiProvider;
@@ -57,8 +58,11 @@ use parking_lot::RwLock;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
+use crate::plans::MatchByColumnName;
 use crate::plans::Plan;
 use crate::plans::ValidationMode;
 use crate::BindContext;
@@ -73,15 +77,27 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyIntoTableStmt,
     ) -> Result<Plan> {
+        let match_by_column_name = MatchByColumnName::from_str(&stmt.match_by_column_name)
+            .map_err(ErrorCode::SyntaxException)?;
         match &stmt.src {
             CopyIntoTableSource::Location(location) => {
                 let plan = self
                     .bind_copy_into_table_common(bind_context, stmt, location)
                     .await?;
-                self.bind_copy_into_table_from_location(bind_context, plan)
-                    .await
+                self.bind_copy_into_table_from_location(
+                    bind_context,
+                    plan,
+                    match_by_column_name,
+                    stmt.error_on_column_count_mismatch,
+                )
+                .await
             }
             CopyIntoTableSource::Query(query) => {
+                if match_by_column_name != MatchByColumnName::None {
+                    return Err(ErrorCode::BadArguments(
+                        "MATCH_BY_COLUMN_NAME can not be used with a query as source of copy",
+                    ));
+                }
                 let mut max_column_position = MaxColumnPosition::new();
                 max_column_position.visit_query(query.as_ref());
                 self.metadata
@@ -122,6 +138,20 @@ impl<'a> Binder {
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
         self.apply_copy_into_table_options(stmt, &mut stage_info)
             .await?;
+        // validation reports the errors instead of skipping the rows or files.
+        let collect_all_errors = matches!(
+            validation_mode,
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors
+        );
+        match validation_mode {
+            ValidationMode::None => {}
+            ValidationMode::ReturnNRows(_) => {
+                stage_info.copy_options.on_error = OnErrorMode::AbortNum(1);
+            }
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                stage_info.copy_options.on_error = OnErrorMode::Continue;
+            }
+        }
         let files_info = StageFilesInfo {
             path,
             files: stmt.files.clone(),
@@ -154,6 +184,8 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                collect_all_errors,
+                file_name_template: None,
                 default_values: Some(default_values),
             },
             values_consts: vec![],
@@ -163,6 +195,10 @@ impl<'a> Binder {
             query: None,
 
             enable_distributed: false,
+            detailed_result: self
+                .ctx
+                .get_settings()
+                .get_enable_copy_into_detailed_result()?,
         })
     }
 
@@ -172,11 +208,34 @@ impl<'a> Binder {
         &mut self,
         bind_ctx: &BindContext,
         plan: CopyIntoTablePlan,
+        match_by_column_name: MatchByColumnName,
+        error_on_column_count_mismatch: bool,
     ) -> Result<Plan> {
-        if matches!(
+        let is_parquet = matches!(
             plan.stage_table_info.stage_info.file_format_params,
             FileFormatParams::Parquet(_)
-        ) {
+        );
+        if match_by_column_name != MatchByColumnName::None {
+            if !is_parquet {
+                return Err(ErrorCode::BadArguments(format!(
+                    "MATCH_BY_COLUMN_NAME is only supported for PARQUET, but got {:?}",
+                    plan.stage_table_info
+                        .stage_info
+                        .file_format_params
+                        .get_type()
+                )));
+            }
+            return self
+                .bind_copy_from_parquet_by_column_name(
+                    bind_ctx,
+                    plan,
+                    match_by_column_name,
+                    error_on_column_count_mismatch,
+                )
+                .await;
+        }
+
+        if is_parquet {
             let select_list = plan
                 .required_source_schema
                 .fields()
@@ -287,6 +346,8 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                collect_all_errors: false,
+                file_name_template: None,
                 default_values: Some(default_values),
             },
             write_mode,
@@ -294,9 +355,10 @@ impl<'a> Binder {
             validation_mode: ValidationMode::None,
 
             enable_distributed: false,
+            detailed_result: false,
         };
 
-        self.bind_copy_into_table_from_location(bind_context, plan)
+        self.bind_copy_into_table_from_location(bind_context, plan, MatchByColumnName::None, false)
             .await
     }
 
@@ -309,16 +371,164 @@ impl<'a> Binder {
         select_list: &'a [SelectTarget],
         alias: &Option<TableAlias>,
     ) -> Result<Plan> {
+        let Some((s_expr, from_context)) = self
+            .bind_copy_source_stage_table(bind_context, &mut plan, alias)
+            .await?
+        else {
+            return Ok(Plan::CopyIntoTable(Box::new(plan)));
+        };
+
+        self.bind_copy_projection(plan, s_expr, from_context, select_list)
+            .await
+    }
+
+    /// Bind COPY INTO <table> FROM <parquet_location> MATCH_BY_COLUMN_NAME = ...
+    ///
+    /// The columns of the files are matched to the columns of the table by name, the extra
+    /// columns of the files are ignored (unless `error_on_column_count_mismatch`), and the table
+    /// columns missing from the files are filled with their default values. The matched columns
+    /// are casted to the types of the table columns when appending data.
+    #[async_backtrace::framed]
+    async fn bind_copy_from_parquet_by_column_name(
+        &mut self,
+        bind_context: &BindContext,
+        mut plan: CopyIntoTablePlan,
+        match_by_column_name: MatchByColumnName,
+        error_on_column_count_mismatch: bool,
+    ) -> Result<Plan> {
+        let Some((s_expr, from_context)) = self
+            .bind_copy_source_stage_table(bind_context, &mut plan, &None)
+            .await?
+        else {
+            return Ok(Plan::CopyIntoTable(Box::new(plan)));
+        };
+
+        let file_columns = from_context
+            .columns
+            .iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .map(|column| column.column_name.clone())
+            .collect::<Vec<_>>();
+        let default_values = plan
+            .stage_table_info
+            .default_values
+            .clone()
+            .unwrap_or_default();
+
+        let mut select_list = vec![];
+        let mut source_fields = vec![];
+        let mut values_consts = vec![];
+        let mut missing_columns = vec![];
+        let mut matched_file_columns = vec![];
+        for (i, field) in plan.required_source_schema.fields().iter().enumerate() {
+            // prefer the exact match if there are multiple columns matched case-insensitively.
+            let file_column = file_columns
+                .iter()
+                .find(|name| *name == field.name())
+                .or_else(|| {
+                    file_columns
+                        .iter()
+                        .find(|name| match_by_column_name.matches(name, field.name()))
+                });
+            match file_column {
+                Some(name) => {
+                    matched_file_columns.push(name.as_str());
+                    let column = Expr::ColumnRef {
+                        span: None,
+                        database: None,
+                        table: None,
+                        column: AstColumnID::Name(Identifier {
+                            name: name.clone(),
+                            quote: Some('"'),
+                            span: None,
+                        }),
+                    };
+                    let expr = if field.data_type().remove_nullable() == DataType::Variant {
+                        Expr::Cast {
+                            span: None,
+                            expr: Box::new(column),
+                            target_type: TypeName::Variant,
+                            pg_style: false,
+                        }
+                    } else {
+                        column
+                    };
+                    select_list.push(SelectTarget::AliasedExpr {
+                        expr: Box::new(expr),
+                        alias: Some(Identifier {
+                            name: field.name().clone(),
+                            quote: Some('"'),
+                            span: None,
+                        }),
+                    });
+                    source_fields.push(field.clone());
+                }
+                None if !field.is_nullable() && field.default_expr().is_none() => {
+                    missing_columns.push(field.name().as_str());
+                }
+                None => {
+                    let value = default_values.get(i).cloned().ok_or_else(|| {
+                        ErrorCode::Internal(format!("no default value of column {}", field.name()))
+                    })?;
+                    values_consts.push(value);
+                }
+            }
+        }
+
+        if !missing_columns.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Required columns {} of table {} are not found in the files",
+                missing_columns.join(", "),
+                plan.table_name,
+            )));
+        }
+        if error_on_column_count_mismatch {
+            let extra_columns = file_columns
+                .iter()
+                .filter(|name| !matched_file_columns.contains(&name.as_str()))
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            if !extra_columns.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Columns {} in the files are not found in table {}",
+                    extra_columns.join(", "),
+                    plan.table_name,
+                )));
+            }
+        }
+        if select_list.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "None of the columns in the files matches the columns of table {}",
+                plan.table_name,
+            )));
+        }
+
+        plan.required_source_schema = Arc::new(DataSchema::new(source_fields));
+        plan.values_consts = values_consts;
+        self.bind_copy_projection(plan, s_expr, from_context, &select_list)
+            .await
+    }
+
+    /// Collects the files to copy and binds the stage table reading them.
+    ///
+    /// Returns None if there is no file to copy.
+    #[async_backtrace::framed]
+    async fn bind_copy_source_stage_table(
+        &mut self,
+        bind_context: &BindContext,
+        plan: &mut CopyIntoTablePlan,
+        alias: &Option<TableAlias>,
+    ) -> Result<Option<(SExpr, BindContext)>> {
         let need_copy_file_infos = plan.collect_files(self.ctx.as_ref()).await?;
 
         if need_copy_file_infos.is_empty() {
             plan.no_file_to_copy = true;
-            return Ok(Plan::CopyIntoTable(Box::new(plan)));
+            return Ok(None);
         }
         plan.stage_table_info.files_to_copy = Some(need_copy_file_infos.clone());
 
         let table_ctx = self.ctx.clone();
-        let (s_expr, mut from_context) = self
+        let (s_expr, from_context) = self
             .bind_stage_table(
                 table_ctx,
                 bind_context,
@@ -328,7 +538,17 @@ impl<'a> Binder {
                 Some(need_copy_file_infos.clone()),
             )
             .await?;
+        Ok(Some((s_expr, from_context)))
+    }
 
+    #[async_backtrace::framed]
+    async fn bind_copy_projection(
+        &mut self,
+        mut plan: CopyIntoTablePlan,
+        s_expr: SExpr,
+        mut from_context: BindContext,
+        select_list: &[SelectTarget],
+    ) -> Result<Plan> {
         // Generate a analyzed select list with from context
         let select_list = self
             .normalize_select_list(&mut from_context, select_list)
@@ -483,6 +703,7 @@ fn check_transform_query(
                     location,
                     options,
                     alias,
+                    travel_point: None,
                 } = &select.from[0]
                 {
                     if options.is_empty() {
@@ -18,11 +18,13 @@ use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
 use common_ast::ast::RevokeStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserPrivilegeType;
 use common_users::UserApiProvider;
 
 use crate::plans::AlterUserPlan;
@@ -40,7 +42,25 @@ impl Binder {
         &mut self,
         stmt: &GrantStmt,
     ) -> Result<Plan> {
-        let GrantStmt { source, principal } = stmt;
+        let GrantStmt {
+            source,
+            principal,
+            revoke_current_grants,
+        } = stmt;
+
+        if *revoke_current_grants {
+            let grant_ownership = match source {
+                AccountMgrSource::Privs { privileges, .. } => {
+                    privileges.as_slice() == [UserPrivilegeType::Ownership]
+                }
+                _ => false,
+            };
+            if !grant_ownership {
+                return Err(ErrorCode::SemanticError(
+                    "REVOKE CURRENT GRANTS can only be used with GRANT OWNERSHIP",
+                ));
+            }
+        }
 
         match source {
             AccountMgrSource::Role { role } => {
@@ -59,6 +79,7 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    revoke_current_grants: *revoke_current_grants,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
@@ -72,6 +93,7 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    revoke_current_grants: *revoke_current_grants,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
@@ -141,8 +163,12 @@ impl Binder {
                     .unwrap_or_else(|| self.ctx.get_current_database());
                 GrantObject::Database(catalog_name, database_name)
             }
+            AccountMgrLevel::FutureTables(database_name) => {
+                GrantObject::FutureTables(catalog_name, database_name.clone())
+            }
             AccountMgrLevel::UDF(udf) => GrantObject::UDF(udf.clone()),
             AccountMgrLevel::Stage(stage) => GrantObject::Stage(stage.clone()),
+            AccountMgrLevel::Volume(volume) => GrantObject::Volume(volume.clone()),
         }
     }
 
@@ -161,9 +187,15 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        let auth_info = AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?;
+        if let (AuthInfo::Password { .. }, Some(password)) = (&auth_info, &auth_option.password) {
+            UserApiProvider::instance()
+                .verify_password(&self.ctx.get_tenant(), &user_option, password, None)
+                .await?;
+        }
         let plan = CreateUserPlan {
             user: user.clone(),
-            auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
+            auth_info,
             user_option,
             if_not_exists: *if_not_exists,
         };
@@ -207,6 +239,19 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        if let (Some(AuthInfo::Password { .. }), Some(password)) = (
+            &new_auth_info,
+            auth_option.as_ref().and_then(|a| a.password.as_ref()),
+        ) {
+            UserApiProvider::instance()
+                .verify_password(
+                    &self.ctx.get_tenant(),
+                    &user_option,
+                    password,
+                    Some(&user_info),
+                )
+                .await?;
+        }
         let new_user_option = if user_option == user_info.option {
             None
         } else {
@@ -40,6 +40,7 @@ impl Binder {
             name: stmt.name.to_string(),
             storage_type: stmt.storage_type.clone(),
             storage_params: stmt.storage_params.clone(),
+            comment: stmt.comment.clone(),
         })))
     }
 }
@@ -161,6 +161,7 @@ impl Binder {
             if_exists,
             catalog,
             database,
+            cascade,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -175,6 +176,7 @@ impl Binder {
             tenant,
             catalog,
             database,
+            cascade: *cascade,
         })))
     }
 
@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+use common_storage::init_operator;
+
+use crate::binder::parse_uri_location;
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::AlterExternalVolumePlan;
+use crate::plans::CreateExternalVolumePlan;
+use crate::plans::DescExternalVolumePlan;
+use crate::plans::DropExternalVolumePlan;
+use crate::plans::Plan;
+use crate::plans::ShowExternalVolumesPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_external_volume(
+        &mut self,
+        stmt: &CreateExternalVolumeStmt,
+    ) -> Result<Plan> {
+        let CreateExternalVolumeStmt {
+            if_not_exists,
+            name,
+            options,
+            comment,
+        } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let storage_params = parse_volume_options(&name, options).await?;
+        Ok(Plan::CreateExternalVolume(Box::new(
+            CreateExternalVolumePlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name,
+                storage_params,
+                comment: comment.clone(),
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_external_volume(
+        &mut self,
+        stmt: &AlterExternalVolumeStmt,
+    ) -> Result<Plan> {
+        let AlterExternalVolumeStmt { name, options } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let storage_params = parse_volume_options(&name, options).await?;
+        Ok(Plan::AlterExternalVolume(Box::new(
+            AlterExternalVolumePlan {
+                tenant: self.ctx.get_tenant(),
+                name,
+                storage_params,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_external_volume(
+        &mut self,
+        stmt: &DropExternalVolumeStmt,
+    ) -> Result<Plan> {
+        let DropExternalVolumeStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropExternalVolume(Box::new(DropExternalVolumePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_external_volume(
+        &mut self,
+        stmt: &DescribeExternalVolumeStmt,
+    ) -> Result<Plan> {
+        let DescribeExternalVolumeStmt { name } = stmt;
+
+        Ok(Plan::DescExternalVolume(Box::new(DescExternalVolumePlan {
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_external_volumes(&mut self) -> Result<Plan> {
+        Ok(Plan::ShowExternalVolumes(Box::new(
+            ShowExternalVolumesPlan {
+                tenant: self.ctx.get_tenant(),
+            },
+        )))
+    }
+}
+
+// Builds the storage params of a volume from its options, e.g.
+// `(type = 's3' bucket = 'b' root = 'path/' role_arn = '...')`.
+//
+// `type` is the storage protocol, `bucket` is the bucket or container and `root` is the
+// root directory of the volume, all the other options are passed as connection options.
+async fn parse_volume_options(
+    name: &str,
+    options: &BTreeMap<String, String>,
+) -> Result<StorageParams> {
+    let mut options = options.clone();
+    let protocol = options.remove("type").ok_or_else(|| {
+        ErrorCode::IllegalExternalVolume(format!(
+            "Missing option 'type' for external volume {name}"
+        ))
+    })?;
+    let bucket = options.remove("bucket").unwrap_or_default();
+    let mut root = options.remove("root").unwrap_or_else(|| "/".to_string());
+    if !root.ends_with('/') {
+        root.push('/');
+    }
+
+    let mut location = UriLocation::new(
+        protocol.to_lowercase(),
+        bucket,
+        root,
+        "".to_string(),
+        options,
+    );
+    // Volumes can not refer to a connection, the storage config is kept in the volume.
+    let (storage_params, _) = parse_uri_location(&mut location, None).await.map_err(|e| {
+        ErrorCode::IllegalExternalVolume(format!("Invalid options for external volume {name}: {e}"))
+    })?;
+
+    // Check the storage params via init operator.
+    let _ = init_operator(&storage_params).map_err(|err| {
+        ErrorCode::IllegalExternalVolume(format!(
+            "Storage config of external volume {name} is invalid: {err:?}"
+        ))
+    })?;
+    Ok(storage_params)
+}
@@ -15,11 +15,14 @@
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::RefreshIndexStmt;
+use common_ast::ast::RefreshInvertedIndexStmt;
 use common_ast::ast::SetExpr;
 use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
@@ -29,6 +32,8 @@ use common_ast::walk_statement_mut;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
+use common_functions::scalars::DEFAULT_TOKENIZER;
 use common_license::license::Feature::AggregateIndex;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::GetIndexReq;
@@ -37,13 +42,17 @@ use common_meta_app::schema::IndexNameIdent;
 use storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -261,6 +270,142 @@ impl Binder {
         Ok(Plan::RefreshIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            tokenizer,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        let tokenizer = tokenizer
+            .clone()
+            .unwrap_or_else(|| DEFAULT_TOKENIZER.to_string());
+        if tokenizer != DEFAULT_TOKENIZER {
+            return Err(ErrorCode::SemanticError(format!(
+                "Unsupported tokenizer '{tokenizer}', only '{DEFAULT_TOKENIZER}' is supported"
+            )));
+        }
+
+        let schema = table_info.schema();
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Inverted index only support String column, but column '{}' is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if column_ids.contains(&field.column_id()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Column '{column}' is duplicated in inverted index"
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        Ok(Plan::CreateInvertedIndex(Box::new(
+            CreateInvertedIndexPlan {
+                if_not_exists: *if_not_exists,
+                catalog,
+                database,
+                table,
+                index_name,
+                column_ids,
+                tokenizer,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        Ok(Plan::DropInvertedIndex(Box::new(DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+        &mut self,
+        stmt: &RefreshInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshInvertedIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        if limit.is_some() && limit.unwrap() < 1 {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Invalid 'limit' value: {}. 'limit' must be greater than or equal to 1.",
+                limit.unwrap()
+            )));
+        }
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        Ok(Plan::RefreshInvertedIndex(Box::new(
+            RefreshInvertedIndexPlan {
+                catalog,
+                database,
+                table,
+                index_name,
+                limit: *limit,
+            },
+        )))
+    }
+
     pub async fn build_refresh_index_plan(
         &mut self,
         bind_context: &mut BindContext,
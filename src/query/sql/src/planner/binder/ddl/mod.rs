@@ -18,9 +18,13 @@ mod column;
 mod connection;
 mod data_mask;
 mod database;
+mod external_volume;
 mod index;
 mod network_policy;
+mod password_policy;
 mod role;
+mod row_access_policy;
+mod settings_profile;
 mod share;
 mod stage;
 mod stream;
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use cidr::Ipv4Cidr;
 use common_ast::ast::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_users::normalize_network_policy_ip_list;
 
 use crate::binder::Binder;
 use crate::plans::AlterNetworkPolicyPlan;
@@ -39,32 +39,19 @@ impl Binder {
             comment,
         } = stmt;
 
-        for ip in allowed_ip_list {
-            if ip.parse::<Ipv4Cidr>().is_err() {
-                return Err(ErrorCode::SemanticError(format!(
-                    "invalid ip address {}",
-                    ip
-                )));
-            }
-        }
-        if let Some(blocked_ip_list) = blocked_ip_list {
-            for ip in blocked_ip_list {
-                if ip.parse::<Ipv4Cidr>().is_err() {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "invalid ip address {}",
-                        ip
-                    )));
-                }
-            }
-        }
+        let allowed_ip_list = normalize_ip_list(allowed_ip_list)?;
+        let blocked_ip_list = match blocked_ip_list {
+            Some(blocked_ip_list) => normalize_ip_list(blocked_ip_list)?,
+            None => vec![],
+        };
 
         let tenant = self.ctx.get_tenant();
         let plan = CreateNetworkPolicyPlan {
             if_not_exists: *if_not_exists,
             tenant,
             name: name.to_string(),
-            allowed_ip_list: allowed_ip_list.clone(),
-            blocked_ip_list: blocked_ip_list.clone().unwrap_or_default(),
+            allowed_ip_list,
+            blocked_ip_list,
             comment: comment.clone().unwrap_or_default(),
         };
         Ok(Plan::CreateNetworkPolicy(Box::new(plan)))
@@ -83,34 +70,22 @@ impl Binder {
             comment,
         } = stmt;
 
-        if let Some(allowed_ip_list) = allowed_ip_list {
-            for ip in allowed_ip_list {
-                if ip.parse::<Ipv4Cidr>().is_err() {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "invalid ip address {}",
-                        ip
-                    )));
-                }
-            }
-        }
-        if let Some(blocked_ip_list) = blocked_ip_list {
-            for ip in blocked_ip_list {
-                if ip.parse::<Ipv4Cidr>().is_err() {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "invalid ip address {}",
-                        ip
-                    )));
-                }
-            }
-        }
+        let allowed_ip_list = allowed_ip_list
+            .as_ref()
+            .map(|ip_list| normalize_ip_list(ip_list))
+            .transpose()?;
+        let blocked_ip_list = blocked_ip_list
+            .as_ref()
+            .map(|ip_list| normalize_ip_list(ip_list))
+            .transpose()?;
 
         let tenant = self.ctx.get_tenant();
         let plan = AlterNetworkPolicyPlan {
             if_exists: *if_exists,
             tenant,
             name: name.to_string(),
-            allowed_ip_list: allowed_ip_list.clone(),
-            blocked_ip_list: blocked_ip_list.clone(),
+            allowed_ip_list,
+            blocked_ip_list,
             comment: comment.clone(),
         };
         Ok(Plan::AlterNetworkPolicy(Box::new(plan)))
@@ -151,3 +126,8 @@ impl Binder {
         Ok(Plan::ShowNetworkPolicies(Box::new(plan)))
     }
 }
+
+// Validates every IPv4/IPv6 entry, the error names the first invalid one.
+fn normalize_ip_list(ip_list: &[String]) -> Result<Vec<String>> {
+    normalize_network_policy_ip_list(ip_list).map_err(|e| ErrorCode::SemanticError(e.message()))
+}
@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::MAX_PASSWORD_AGE_DAYS;
+use common_meta_app::principal::MAX_PASSWORD_HISTORY;
+use common_meta_app::principal::MAX_PASSWORD_LENGTH;
+use common_meta_app::principal::MAX_PASSWORD_LOCKOUT_TIME_MINS;
+use common_meta_app::principal::MAX_PASSWORD_RETRIES;
+
+use crate::binder::Binder;
+use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ShowPasswordPoliciesPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_password_policy(
+        &mut self,
+        stmt: &CreatePasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let CreatePasswordPolicyStmt {
+            if_not_exists,
+            name,
+            set_options,
+        } = stmt;
+
+        check_password_set_options(set_options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreatePasswordPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            set_options: set_options.clone(),
+        };
+        Ok(Plan::CreatePasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_password_policy(
+        &mut self,
+        stmt: &AlterPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let AlterPasswordPolicyStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        if let AlterPasswordAction::SetOptions(set_options) = action {
+            check_password_set_options(set_options)?;
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            action: action.clone(),
+        };
+        Ok(Plan::AlterPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_password_policy(
+        &mut self,
+        stmt: &DropPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DropPasswordPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_password_policy(
+        &mut self,
+        stmt: &DescPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DescPasswordPolicyStmt { name } = stmt;
+
+        let plan = DescPasswordPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_password_policies(&mut self) -> Result<Plan> {
+        let plan = ShowPasswordPoliciesPlan {};
+        Ok(Plan::ShowPasswordPolicies(Box::new(plan)))
+    }
+}
+
+// Checks that each option is in its allowed range, the consistency between the options
+// is checked when the policy is stored.
+fn check_password_set_options(set_options: &PasswordSetOptions) -> Result<()> {
+    let ranges = [
+        (
+            "PASSWORD_MIN_LENGTH",
+            set_options.min_length,
+            1,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MAX_LENGTH",
+            set_options.max_length,
+            1,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MIN_UPPER_CASE_CHARS",
+            set_options.min_upper_case_chars,
+            0,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MIN_LOWER_CASE_CHARS",
+            set_options.min_lower_case_chars,
+            0,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MIN_NUMERIC_CHARS",
+            set_options.min_numeric_chars,
+            0,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MIN_SPECIAL_CHARS",
+            set_options.min_special_chars,
+            0,
+            MAX_PASSWORD_LENGTH,
+        ),
+        (
+            "PASSWORD_MAX_AGE_DAYS",
+            set_options.max_age_days,
+            0,
+            MAX_PASSWORD_AGE_DAYS,
+        ),
+        (
+            "PASSWORD_MAX_RETRIES",
+            set_options.max_retries,
+            0,
+            MAX_PASSWORD_RETRIES,
+        ),
+        (
+            "PASSWORD_LOCKOUT_TIME_MINS",
+            set_options.lockout_time_mins,
+            1,
+            MAX_PASSWORD_LOCKOUT_TIME_MINS,
+        ),
+        (
+            "PASSWORD_HISTORY",
+            set_options.history,
+            0,
+            MAX_PASSWORD_HISTORY,
+        ),
+    ];
+    for (name, value, min, max) in ranges {
+        if let Some(value) = value {
+            if value < min || value > max {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "invalid {}, must be in the range {} to {}, but got {}",
+                    name, min, max, value
+                )));
+            }
+        }
+    }
+    Ok(())
+}
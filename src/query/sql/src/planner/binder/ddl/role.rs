@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::AlterRoleAction;
+use common_ast::ast::AlterRoleStmt;
 use common_ast::ast::SecondaryRolesOption;
 use common_exception::Result;
 
+use crate::plans::AlterRolePlan;
 use crate::plans::Plan;
 use crate::plans::SetRolePlan;
 use crate::plans::SetSecondaryRolesPlan;
@@ -47,4 +50,19 @@ impl Binder {
         };
         Ok(Plan::SetSecondaryRoles(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_role(
+        &mut self,
+        stmt: &AlterRoleStmt,
+    ) -> Result<Plan> {
+        let settings_profile = match &stmt.action {
+            AlterRoleAction::SetSettingsProfile(profile) => Some(profile.clone()),
+            AlterRoleAction::UnsetSettingsProfile => None,
+        };
+        Ok(Plan::AlterRole(Box::new(AlterRolePlan {
+            role_name: stmt.role_name.clone(),
+            settings_profile,
+        })))
+    }
 }
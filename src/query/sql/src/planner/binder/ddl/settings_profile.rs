@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::*;
+use common_exception::Result;
+use common_settings::Settings;
+
+use crate::binder::Binder;
+use crate::plans::AlterSettingsProfilePlan;
+use crate::plans::CreateSettingsProfilePlan;
+use crate::plans::DescSettingsProfilePlan;
+use crate::plans::DropSettingsProfilePlan;
+use crate::plans::Plan;
+use crate::plans::ShowSettingsProfilesPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_settings_profile(
+        &mut self,
+        stmt: &CreateSettingsProfileStmt,
+    ) -> Result<Plan> {
+        let CreateSettingsProfileStmt {
+            if_not_exists,
+            name,
+            settings,
+            comment,
+        } = stmt;
+
+        check_profile_settings(settings)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateSettingsProfilePlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            settings: settings.clone(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateSettingsProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_settings_profile(
+        &mut self,
+        stmt: &AlterSettingsProfileStmt,
+    ) -> Result<Plan> {
+        let AlterSettingsProfileStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        if let AlterSettingsProfileAction::Set { settings, .. } = action {
+            check_profile_settings(settings)?;
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterSettingsProfilePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            action: action.clone(),
+        };
+        Ok(Plan::AlterSettingsProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_settings_profile(
+        &mut self,
+        stmt: &DropSettingsProfileStmt,
+    ) -> Result<Plan> {
+        let DropSettingsProfileStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropSettingsProfilePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropSettingsProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_settings_profile(
+        &mut self,
+        stmt: &DescSettingsProfileStmt,
+    ) -> Result<Plan> {
+        let DescSettingsProfileStmt { name } = stmt;
+
+        let plan = DescSettingsProfilePlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescSettingsProfile(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_settings_profiles(&mut self) -> Result<Plan> {
+        let plan = ShowSettingsProfilesPlan {};
+        Ok(Plan::ShowSettingsProfiles(Box::new(plan)))
+    }
+}
+
+// Rejects the settings which could not be set by `SET`, so that a profile never holds
+// a setting failing when it is applied to a session.
+fn check_profile_settings(settings: &BTreeMap<String, String>) -> Result<()> {
+    for (name, value) in settings {
+        Settings::check_profile_setting(name, value)?;
+    }
+    Ok(())
+}
@@ -225,7 +225,7 @@ impl Binder {
         let ShowObjectGrantPrivilegesStmt { object } = stmt;
 
         let plan = ShowObjectGrantPrivilegesPlan {
-            object: object.clone(),
+            object: self.convert_to_grant_object(object),
         };
         Ok(Plan::ShowObjectGrantPrivileges(Box::new(plan)))
     }
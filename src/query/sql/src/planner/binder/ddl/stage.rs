@@ -15,6 +15,8 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use common_ast::ast::AlterStageAction;
+use common_ast::ast::AlterStageStmt;
 use common_ast::ast::CreateStageStmt;
 use common_ast::ast::UriLocation;
 use common_exception::ErrorCode;
@@ -23,11 +25,15 @@ use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::VolumeLocation;
 use common_storage::init_operator;
+use common_users::UserApiProvider;
 
 use super::super::copy_into_table::resolve_stage_location;
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::AlterStagePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::Plan;
 use crate::plans::RemoveStagePlan;
@@ -58,22 +64,57 @@ impl Binder {
             if_not_exists,
             stage_name,
             location,
+            volume_location,
             file_format_options,
             on_error,
             size_limit,
             validation_mode: _,
-            comments: _,
+            comments,
         } = stmt;
 
-        let mut stage_info = match location {
-            None => {
+        let mut stage_info = match (location, volume_location) {
+            (Some(_), Some(_)) => {
+                return Err(ErrorCode::SyntaxException(
+                    "CREATE STAGE can not specify both URL and external volume",
+                ));
+            }
+            (None, Some(volume_location)) => {
+                let location = VolumeLocation {
+                    volume: normalize_identifier(
+                        &volume_location.volume,
+                        &self.name_resolution_ctx,
+                    )
+                    .name,
+                    path: volume_location.path.clone(),
+                };
+                let volume = UserApiProvider::instance()
+                    .get_external_volume(&self.ctx.get_tenant(), &location.volume)
+                    .await?;
+                let stage_storage = volume.resolve(&location);
+
+                // Check the storage params via init operator.
+                let _ = init_operator(&stage_storage).map_err(|err| {
+                    ErrorCode::InvalidConfig(format!(
+                        "Storage config of external volume {} is invalid: {err:?}",
+                        location.volume
+                    ))
+                })?;
+
+                // Only the location is persisted, the storage params are resolved through
+                // the volume whenever the stage is loaded.
+                let mut stage_info = StageInfo::new_external_stage(stage_storage, "/", true)
+                    .with_stage_name(stage_name);
+                stage_info.volume_location = Some(location);
+                stage_info
+            }
+            (None, None) => {
                 if stage_name == "~" {
                     StageInfo::new_user_stage(&self.ctx.get_current_user()?.name)
                 } else {
                     StageInfo::new_internal_stage(stage_name)
                 }
             }
-            Some(uri) => {
+            (Some(uri), None) => {
                 let mut uri = UriLocation {
                     protocol: uri.protocol.clone(),
                     name: uri.name.clone(),
@@ -117,6 +158,7 @@ impl Binder {
 
             stage_info.copy_options.size_limit = *size_limit;
         }
+        stage_info.comment = comments.clone();
 
         Ok(Plan::CreateStage(Box::new(CreateStagePlan {
             if_not_exists: *if_not_exists,
@@ -125,6 +167,35 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_stage(
+        &mut self,
+        stmt: &AlterStageStmt,
+    ) -> Result<Plan> {
+        let AlterStageStmt {
+            if_exists,
+            stage_name,
+            action,
+        } = stmt;
+
+        // The user stage `~` is bound to the current user, it can not be renamed, nor can
+        // a named stage take its place.
+        if let AlterStageAction::RenameStage { new_stage_name } = action {
+            if stage_name == "~" || new_stage_name == "~" {
+                return Err(ErrorCode::SyntaxException(
+                    "The user stage `~` can not be renamed",
+                ));
+            }
+        }
+
+        Ok(Plan::AlterStage(Box::new(AlterStagePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: stage_name.clone(),
+            action: action.clone(),
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn try_resolve_file_format(
         &self,
@@ -21,6 +21,7 @@ use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
 use common_ast::ast::AttachTableStmt;
+use common_ast::ast::BucketBy;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
@@ -67,15 +68,20 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::VolumeLocation;
+use common_meta_app::schema::BucketSpec;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_iceberg::IcebergTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_VOLUME_PATH;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -97,6 +103,7 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
@@ -104,6 +111,7 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -127,6 +135,8 @@ use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
 
+const MAX_NUM_BUCKETS: u64 = 4096;
+
 impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_tables(
@@ -400,10 +410,12 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            bucket_by,
             as_query,
             transient,
             engine,
             uri_location,
+            volume_location,
         } = stmt;
 
         let (catalog, database, table) =
@@ -420,6 +432,12 @@ impl Binder {
             )?;
         }
 
+        if uri_location.is_some() && volume_location.is_some() {
+            return Err(ErrorCode::BadArguments(
+                "Incorrect CREATE query: CREATE TABLE can not specify both external location and external volume",
+            ));
+        }
+
         let (mut storage_params, part_prefix) = match (uri_location, engine) {
             (Some(uri), Engine::Fuse) => {
                 let mut uri = UriLocation {
@@ -450,6 +468,32 @@ impl Binder {
             _ => (None, "".to_string()),
         };
 
+        if let Some(volume_location) = volume_location {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect CREATE query: CREATE TABLE on external volume is only supported for FUSE engine, but got {:?}",
+                    engine
+                )));
+            }
+            let location = VolumeLocation {
+                volume: normalize_identifier(&volume_location.volume, &self.name_resolution_ctx)
+                    .name,
+                path: volume_location.path.clone(),
+            };
+            let volume = UserApiProvider::instance()
+                .get_external_volume(&self.ctx.get_tenant(), &location.volume)
+                .await?;
+            let sp = volume.resolve(&location);
+
+            // create a temporary op to check if params is correct
+            DataOperator::try_create(&sp).await?;
+
+            // The storage params are resolved through the volume whenever the table is loaded.
+            options.insert(OPT_KEY_EXTERNAL_VOLUME.to_string(), location.volume);
+            options.insert(OPT_KEY_EXTERNAL_VOLUME_PATH.to_string(), location.path);
+            storage_params = Some(sp);
+        }
+
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
@@ -599,6 +643,24 @@ impl Binder {
             }
         };
 
+        let bucket_spec = match bucket_by {
+            Some(bucket_by) => {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::InvalidBucketSpec(format!(
+                        "BUCKET BY is only supported for FUSE engine, but got {:?}",
+                        engine
+                    )));
+                }
+                if cluster_key.is_some() {
+                    return Err(ErrorCode::InvalidBucketSpec(
+                        "BUCKET BY can not be used together with CLUSTER BY",
+                    ));
+                }
+                Some(self.analyze_bucket_spec(bucket_by, &schema)?)
+            }
+            None => None,
+        };
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -613,6 +675,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            bucket_spec,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -692,6 +755,7 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            bucket_spec: None,
             as_select: None,
         })))
     }
@@ -880,11 +944,19 @@ impl Binder {
                 })))
             }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
-                let schema = self
+                let table_info = self
                     .ctx
                     .get_table(&catalog, &database, &table)
                     .await?
-                    .schema();
+                    .get_table_info()
+                    .clone();
+                if table_info.meta.bucket_spec.is_some() {
+                    return Err(ErrorCode::InvalidBucketSpec(format!(
+                        "Can not set cluster key for bucketed table {}",
+                        table_info.desc
+                    )));
+                }
+                let schema = table_info.schema();
                 let cluster_keys = self.analyze_cluster_keys(cluster_by, schema).await?;
 
                 Ok(Plan::AlterTableClusterKey(Box::new(
@@ -960,6 +1032,30 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -1041,14 +1137,17 @@ impl Binder {
             self.normalize_object_identifier_triple(catalog, database, table);
         let action = match ast_action {
             AstOptimizeTableAction::All => OptimizeTableAction::All,
-            AstOptimizeTableAction::Purge { before } => {
+            AstOptimizeTableAction::Purge { before, force } => {
                 let p = if let Some(point) = before {
                     let point = self.resolve_data_travel_point(bind_context, point).await?;
                     Some(point)
                 } else {
                     None
                 };
-                OptimizeTableAction::Purge(p)
+                OptimizeTableAction::Purge {
+                    before: p,
+                    force: *force,
+                }
             }
             AstOptimizeTableAction::Compact { target } => match target {
                 CompactTarget::Block => OptimizeTableAction::CompactBlocks,
@@ -1490,6 +1589,50 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    fn analyze_bucket_spec(
+        &self,
+        bucket_by: &BucketBy,
+        schema: &TableSchema,
+    ) -> Result<BucketSpec> {
+        if bucket_by.num_buckets == 0 || bucket_by.num_buckets > MAX_NUM_BUCKETS {
+            return Err(ErrorCode::InvalidBucketSpec(format!(
+                "The number of buckets must be between 1 and {}, but got {}",
+                MAX_NUM_BUCKETS, bucket_by.num_buckets
+            )));
+        }
+
+        let mut column_ids = Vec::with_capacity(bucket_by.columns.len());
+        for column in bucket_by.columns.iter() {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&name)?;
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::InvalidBucketSpec(format!(
+                    "Virtual computed column `{}` can not be a bucket key",
+                    name
+                )));
+            }
+            if !Self::valid_cluster_key_type(&DataType::from(field.data_type())) {
+                return Err(ErrorCode::InvalidBucketSpec(format!(
+                    "Unsupported data type '{}' for bucket key `{}`",
+                    field.data_type(),
+                    name
+                )));
+            }
+            if column_ids.contains(&field.column_id()) {
+                return Err(ErrorCode::InvalidBucketSpec(format!(
+                    "Duplicated bucket key `{}`",
+                    name
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        Ok(BucketSpec {
+            column_ids,
+            num_buckets: bucket_by.num_buckets,
+        })
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
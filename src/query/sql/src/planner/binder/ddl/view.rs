@@ -15,8 +15,16 @@
 use common_ast::ast::AlterViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::Query;
+use common_ast::ast::SubqueryModifier;
+use common_ast::walk_expr;
+use common_ast::walk_query;
+use common_ast::Visitor;
 use common_ast::VisitorMut;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
@@ -48,6 +56,7 @@ impl Binder {
             .iter()
             .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
             .collect::<Vec<_>>();
+        check_query_into(&query)?;
         let mut visitor = ViewRewriter {
             current_database: database.clone(),
         };
@@ -87,6 +96,7 @@ impl Binder {
             .iter()
             .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
             .collect::<Vec<_>>();
+        check_query_into(&query)?;
         let mut visitor = ViewRewriter {
             current_database: database.clone(),
         };
@@ -129,3 +139,56 @@ impl Binder {
         Ok(Plan::DropView(plan.into()))
     }
 }
+
+/// Finds the first `INTO <location>` clause in a query, which is not allowed in views.
+#[derive(Default)]
+struct QueryIntoFinder {
+    span: Option<Span>,
+}
+
+impl<'ast> Visitor<'ast> for QueryIntoFinder {
+    fn visit_query(&mut self, query: &'ast Query) {
+        if let Some(into) = &query.into {
+            if self.span.is_none() {
+                self.span = Some(into.span);
+            }
+        }
+        walk_query(self, query);
+    }
+
+    fn visit_in_subquery(
+        &mut self,
+        _span: Span,
+        expr: &'ast Expr,
+        subquery: &'ast Query,
+        _not: bool,
+    ) {
+        walk_expr(self, expr);
+        self.visit_query(subquery);
+    }
+
+    fn visit_exists(&mut self, _span: Span, _not: bool, subquery: &'ast Query) {
+        self.visit_query(subquery);
+    }
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'ast Option<SubqueryModifier>,
+        subquery: &'ast Query,
+    ) {
+        self.visit_query(subquery);
+    }
+}
+
+fn check_query_into(query: &Query) -> Result<()> {
+    let mut finder = QueryIntoFinder::default();
+    finder.visit_query(query);
+    match finder.span {
+        Some(span) => Err(ErrorCode::SemanticError(
+            "INTO <location> is not allowed in view definition".to_string(),
+        )
+        .set_span(span)),
+        None => Ok(()),
+    }
+}
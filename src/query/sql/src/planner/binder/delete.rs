@@ -83,6 +83,7 @@ impl<'a> Binder {
         };
 
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, policy_predicates) = Self::split_row_access_policy(table_expr);
 
         context.allow_internal_columns(false);
         let mut scalar_binder = ScalarBinder::new(
@@ -95,7 +96,7 @@ impl<'a> Binder {
             self.ctes_map.clone(),
         );
 
-        let (selection, subquery_desc) = self
+        let (selection, mut subquery_desc) = self
             .process_selection(selection, table_expr, &mut scalar_binder)
             .await?;
 
@@ -108,6 +109,8 @@ impl<'a> Binder {
                 .set_span(selection.span()));
             }
         }
+        let selection =
+            Self::conjoin_row_access_policy(selection, &mut subquery_desc, policy_predicates);
 
         let plan = DeletePlan {
             catalog_name,
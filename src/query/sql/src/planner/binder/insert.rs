@@ -83,6 +83,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            idempotent_token,
             ..
         } = stmt;
         let (catalog_name, database_name, table_name) =
@@ -92,6 +93,7 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_id = table.get_id();
+        self.set_idempotent_token(table_id, idempotent_token)?;
         let schema = self.schema_project(&table.schema(), columns)?;
 
         let input_source: Result<InsertInputSource> = match source.clone() {
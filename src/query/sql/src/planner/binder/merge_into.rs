@@ -272,6 +272,21 @@ impl Binder {
             .bind_single_table(&mut source_context, &target_table)
             .await?;
 
+        // The target table is joined without the filter of the row access policy, so
+        // that the rows hidden by the policy are still matched instead of being taken
+        // as not matched and inserted again, the policy is applied to the matched
+        // clauses below and leaves the hidden rows unchanged.
+        let (expr, policy_predicates) = Self::split_row_access_policy(target_expr);
+        target_expr = expr;
+        for predicate in policy_predicates.iter() {
+            if !self.check_allowed_scalar_expr(predicate)? {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "the row access policy of table {} can't be used as a matched condition of MERGE INTO",
+                    table_name
+                )));
+            }
+        }
+
         // add internal_column (_row_id)
         let table_index = self
             .metadata
@@ -372,6 +387,48 @@ impl Binder {
             );
         }
 
+        if !policy_predicates.is_empty() {
+            for predicate in policy_predicates.iter() {
+                columns_set.extend(predicate.used_columns());
+            }
+            for evaluator in matched_evaluators.iter_mut() {
+                evaluator.condition = Self::conjoin_row_access_policy(
+                    evaluator.condition.take(),
+                    &mut [],
+                    policy_predicates.clone(),
+                );
+            }
+        }
+
+        // Blocks are updated in place, rows can not be moved to another bucket.
+        if let Some(bucket_spec) = &table.get_table_info().meta.bucket_spec {
+            for clause in &matched_clauses {
+                let MatchOperation::Update {
+                    update_list,
+                    is_star,
+                } = &clause.operation
+                else {
+                    continue;
+                };
+                if *is_star {
+                    return Err(ErrorCode::InvalidBucketSpec(
+                        "Can not update bucket keys with UPDATE *",
+                    ));
+                }
+                for update_expr in update_list {
+                    let col_name =
+                        normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                    let field = table_schema.field_with_name(&col_name)?;
+                    if bucket_spec.column_ids.contains(&field.column_id()) {
+                        return Err(ErrorCode::InvalidBucketSpec(format!(
+                            "Can not update bucket key '{}'",
+                            field.name()
+                        )));
+                    }
+                }
+            }
+        }
+
         // bind not matched clause columns and add insert exprs
         for clause in &unmatched_clauses {
             unmatched_evaluators.push(
@@ -49,6 +49,7 @@ impl Binder {
             source,
             delete_when,
             hints: _,
+            idempotent_token,
         } = stmt;
 
         let (catalog_name, database_name, table_name) =
@@ -59,6 +60,7 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_id = table.get_id();
+        self.set_idempotent_token(table_id, idempotent_token)?;
 
         let schema = if columns.is_empty() {
             table.schema()
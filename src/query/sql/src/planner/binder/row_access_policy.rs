@@ -0,0 +1,222 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use common_users::UserApiProvider;
+
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
+use crate::plans::SubqueryDesc;
+use crate::BindContext;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+impl Binder {
+    /// Filters the scan of a base table with the predicate of the row access policy
+    /// attached to the table, if any.
+    ///
+    /// The filter is added on top of the scan before optimization, so that it
+    /// is pushed down and used for pruning like the predicates of the query.
+    #[async_backtrace::framed]
+    pub(crate) async fn apply_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        match self
+            .bind_row_access_policy(bind_context, table_index)
+            .await?
+        {
+            Some(predicate) => {
+                let filter = Filter {
+                    predicates: vec![predicate],
+                };
+                Ok(SExpr::create_unary(
+                    Arc::new(filter.into()),
+                    Arc::new(s_expr),
+                ))
+            }
+            None => Ok(s_expr),
+        }
+    }
+
+    /// Binds the predicate of the row access policy attached to the table, with the
+    /// arguments of the policy bound to the columns of the table.
+    ///
+    /// Users with the `APPLY ROW ACCESS POLICY` privilege are exempted from the policies.
+    /// EXPLAIN does the opposite, the predicate is only shown to the users who are
+    /// allowed to manage the policies, and EXPLAIN ANALYZE is denied to the others.
+    #[async_backtrace::framed]
+    async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+    ) -> Result<Option<ScalarExpr>> {
+        let table = self.metadata.read().table(table_index).table();
+        let binding = match &table.get_table_info().meta.row_access_policy {
+            Some(binding) => binding.clone(),
+            None => return Ok(None),
+        };
+
+        let exempted = self.has_row_access_policy_privilege().await?;
+        if self.in_explain_analyze && !exempted {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, privilege APPLY ROW ACCESS POLICY is required to EXPLAIN ANALYZE table {} protected by row access policy",
+                table.name()
+            )));
+        }
+        let apply = if self.in_explain { exempted } else { !exempted };
+        if !apply {
+            return Ok(None);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&tenant, &binding.policy)
+            .await
+            .map_err(|e| {
+                e.add_message_back(format!(
+                    " (while apply row access policy of table {})",
+                    table.name()
+                ))
+            })?;
+        if policy.args.len() != binding.columns.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy `{}` expects {} arguments, but {} columns of table {} are bound to it",
+                policy.name,
+                policy.args.len(),
+                binding.columns.len(),
+                table.name()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column_name) in policy.args.iter().zip(binding.columns.iter()) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|column| {
+                    column.table_index == Some(table_index) && &column.column_name == column_name
+                })
+                .ok_or_else(|| {
+                    ErrorCode::IllegalRowAccessPolicy(format!(
+                        "column {} bound to row access policy `{}` does not exist in table {}",
+                        column_name,
+                        policy.name,
+                        table.name()
+                    ))
+                })?;
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column.clone(),
+                }),
+            ));
+        }
+
+        // Only the arguments are visible to the body of the policy, the context
+        // functions like `current_user()` are resolved with the invoking session.
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        let mut policy_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            false,
+            true,
+        )?;
+        let (predicate, data_type) = *type_checker.resolve(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy `{}` must return BOOLEAN, but got {}",
+                policy.name, data_type
+            )));
+        }
+
+        Ok(Some(predicate))
+    }
+
+    async fn has_row_access_policy_privilege(&self) -> Result<bool> {
+        let object = GrantObject::Global;
+        let privileges = vec![UserPrivilegeType::ApplyRowAccessPolicy];
+        let user = self.ctx.get_current_user()?;
+        if user.grants.verify_privilege(&object, privileges.clone()) {
+            return Ok(true);
+        }
+        let roles = self.ctx.get_all_effective_roles().await?;
+        Ok(roles
+            .iter()
+            .any(|role| role.grants.verify_privilege(&object, privileges.clone())))
+    }
+
+    /// Takes the filter of the row access policy off the bound target table of
+    /// UPDATE, DELETE and MERGE INTO, which work on the scan of the table, the predicates
+    /// are conjoined into the selection or the matched conditions by
+    /// `conjoin_row_access_policy` instead.
+    pub(crate) fn split_row_access_policy(table_expr: SExpr) -> (SExpr, Vec<ScalarExpr>) {
+        if let (RelOperator::Filter(filter), Some(child)) =
+            (&*table_expr.plan, table_expr.children.first())
+        {
+            if matches!(&*child.plan, RelOperator::Scan(_)) {
+                return (child.as_ref().clone(), filter.predicates.clone());
+            }
+        }
+        (table_expr, vec![])
+    }
+
+    /// Conjoins the predicates of the row access policy into the selection of UPDATE and DELETE,
+    /// or into the condition of a matched clause of MERGE INTO.
+    ///
+    /// The columns used by the predicates are also added to the outer columns of the
+    /// subqueries in the selection, which decide the columns read by the mutation then.
+    pub(crate) fn conjoin_row_access_policy(
+        selection: Option<ScalarExpr>,
+        subquery_desc: &mut [SubqueryDesc],
+        predicates: Vec<ScalarExpr>,
+    ) -> Option<ScalarExpr> {
+        for desc in subquery_desc.iter_mut() {
+            for predicate in predicates.iter() {
+                desc.outer_columns.extend(predicate.used_columns());
+            }
+        }
+        predicates
+            .into_iter()
+            .chain(selection)
+            .reduce(|left, right| {
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "and".to_string(),
+                    params: vec![],
+                    arguments: vec![left, right],
+                })
+            })
+    }
+}
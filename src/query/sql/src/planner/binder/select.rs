@@ -345,6 +345,12 @@ impl Binder {
         bind_context: &mut BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(into) = &query.into {
+            return Err(ErrorCode::SemanticError(
+                "INTO <location> is only allowed in the top level query".to_string(),
+            )
+            .set_span(into.span));
+        }
         if let Some(with) = &query.with {
             for (idx, cte) in with.ctes.iter().enumerate() {
                 let table_name =
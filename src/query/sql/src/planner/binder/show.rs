@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::Identifier;
 use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowOptions;
 use common_exception::Result;
@@ -31,13 +32,31 @@ impl Binder {
         let (show_limit, limit_str) = get_show_options(show_options, None);
         // rewrite show functions to select * from system.functions ...
         let query = format!(
-            "SELECT name, is_builtin, is_aggregate, definition, description FROM system.functions {} ORDER BY name {}",
+            "SELECT DISTINCT name, is_builtin, is_aggregate, definition, description FROM system.functions {} ORDER BY name {}",
             show_limit, limit_str,
         );
         self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowFunctions)
             .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_describe_function(
+        &mut self,
+        bind_context: &mut BindContext,
+        name: &Identifier,
+    ) -> Result<Plan> {
+        // Function names are case-insensitive, rewrite describe function to
+        // select the overloads of the function from system.functions.
+        let name = name.name.to_lowercase().replace('\'', "\\'");
+        let query = format!(
+            "SELECT name, kind, arguments, return_type, is_variadic, is_deterministic, description, example \
+            FROM system.functions WHERE name = '{}'",
+            name,
+        );
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowFunctions)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_table_functions(
         &mut self,
@@ -28,6 +28,8 @@ use common_ast::ast::FileLocation;
 use common_ast::ast::Identifier;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
 use common_ast::ast::Literal;
 use common_ast::ast::Query;
 use common_ast::ast::SelectStageOptions;
@@ -102,6 +104,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::BoundColumnRef;
+use crate::plans::BucketInfo;
 use crate::plans::ConstantExpr;
 use crate::plans::CteScan;
 use crate::plans::EvalScalar;
@@ -111,6 +114,7 @@ use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
+use crate::plans::Unnest;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
@@ -223,11 +227,6 @@ impl Binder {
 
         let tenant = self.ctx.get_tenant();
 
-        let navigation_point = match travel_point {
-            Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp).await?),
-            None => None,
-        };
-
         // Resolve table with catalog
         let table_meta = match self
             .resolve_data_source(
@@ -235,7 +234,7 @@ impl Binder {
                 catalog.as_str(),
                 database.as_str(),
                 table_name.as_str(),
-                &navigation_point,
+                &None,
             )
             .await
         {
@@ -266,6 +265,25 @@ impl Binder {
             }
         };
 
+        // The time travel point is resolved after the table, so that the errors of
+        // navigation are not taken as an unknown table.
+        let table_meta = match travel_point {
+            Some(tp) => {
+                if table_meta.engine() == "VIEW" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "AT clause is not supported on view `{database}`.`{table_name}`"
+                    ))
+                    .set_span(*span));
+                }
+                let navigation_point = self.resolve_data_travel_point(bind_context, tp).await?;
+                table_meta
+                    .navigate_to(&navigation_point)
+                    .await
+                    .map_err(|e| e.set_span(*span))?
+            }
+            None => table_meta,
+        };
+
         match table_meta.engine() {
             "VIEW" => {
                 // TODO(leiysky): this check is error-prone,
@@ -322,10 +340,18 @@ impl Binder {
                     bind_context.planning_agg_index,
                     false,
                 );
+                if let Some(tp) = travel_point {
+                    self.metadata
+                        .write()
+                        .set_table_travel_point(table_index, format!("AT{tp}"));
+                }
 
                 let (s_expr, mut bind_context) = self
                     .bind_base_table(bind_context, database.as_str(), table_index)
                     .await?;
+                let s_expr = self
+                    .apply_row_access_policy(&bind_context, table_index, s_expr)
+                    .await?;
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -521,6 +547,126 @@ impl Binder {
         }
     }
 
+    /// Bind `UNNEST(<array>)` on the right side of a join, the array can reference
+    /// the columns of the left side. Each row of the left side is expanded into one
+    /// row per element, the rows of NULL or empty arrays are kept only by `LEFT JOIN`.
+    #[async_backtrace::framed]
+    async fn bind_unnest(
+        &mut self,
+        parent_context: &mut BindContext,
+        child: SExpr,
+        join: &Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let TableReference::TableFunction {
+            span,
+            params,
+            named_params,
+            alias,
+            ..
+        } = join.right.as_ref()
+        else {
+            unreachable!()
+        };
+
+        let is_true = |expr: &Expr| {
+            matches!(expr, Expr::Literal {
+                lit: Literal::Boolean(true),
+                ..
+            })
+        };
+        let outer = match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin | JoinOperator::Inner, JoinCondition::None) => false,
+            (JoinOperator::Inner, JoinCondition::On(expr)) if is_true(expr) => false,
+            (JoinOperator::LeftOuter, JoinCondition::On(expr)) if is_true(expr) => true,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "UNNEST can only be joined by CROSS JOIN, or by INNER/LEFT JOIN with ON TRUE",
+                )
+                .set_span(*span));
+            }
+        };
+        if params.len() != 1 || !named_params.is_empty() {
+            return Err(
+                ErrorCode::SemanticError("UNNEST expects exactly one array argument")
+                    .set_span(*span),
+            );
+        }
+
+        let mut scalar_binder = ScalarBinder::new(
+            parent_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, data_type) = scalar_binder.bind(&params[0]).await?;
+        let element_type =
+            Unnest::element_type(&data_type, outer).map_err(|err| err.set_span(*span))?;
+
+        // Evaluate the array first if it's not a column.
+        let (array, child) = match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => (column_ref.column.index, child),
+            scalar => {
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(params[0].to_string(), data_type);
+                let eval_scalar = EvalScalar {
+                    items: vec![ScalarItem { scalar, index }],
+                };
+                let child = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(child));
+                (index, child)
+            }
+        };
+
+        // The ordinality column is added only if it's named in the alias.
+        let with_ordinality = alias
+            .as_ref()
+            .map_or(false, |alias| alias.columns.len() > 1);
+        let mut columns = vec![("value", element_type)];
+        if with_ordinality {
+            columns.push(("ordinality", Unnest::ordinality_type(outer)));
+        }
+        let mut bind_context = BindContext::with_parent(Box::new(parent_context.clone()));
+        let mut indexes = Vec::with_capacity(columns.len());
+        for (name, data_type) in columns {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.to_string(), data_type.clone());
+            let column_binding = ColumnBindingBuilder::new(
+                name.to_string(),
+                index,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            bind_context.add_column_binding(column_binding);
+            indexes.push(index);
+        }
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        // add left table columns.
+        let mut new_columns = parent_context.columns.clone();
+        new_columns.extend_from_slice(&bind_context.columns);
+        bind_context.columns = new_columns;
+
+        let unnest = Unnest {
+            array,
+            element: indexes[0],
+            ordinality: indexes.get(1).copied(),
+            outer,
+        };
+        Ok((
+            SExpr::create_unary(Arc::new(unnest.into()), Arc::new(child)),
+            bind_context,
+        ))
+    }
+
     /// Bind a table function.
     #[async_backtrace::framed]
     async fn bind_table_function(
@@ -804,11 +950,18 @@ impl Binder {
                     .await
             }
             TableReference::Location {
-                span: _,
+                span,
                 location,
                 options,
                 alias,
+                travel_point,
             } => {
+                if travel_point.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "AT clause is not supported on stage table {location}"
+                    ))
+                    .set_span(*span));
+                }
                 self.bind_location(bind_context, location, options, alias)
                     .await
             }
@@ -893,6 +1046,8 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    collect_all_errors: false,
+                    file_name_template: None,
                     default_values: None,
                 };
                 StageTable::try_create(info)?
@@ -920,6 +1075,8 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    collect_all_errors: false,
+                    file_name_template: None,
                     default_values: None,
                 };
                 StageTable::try_create(info)?
@@ -1011,7 +1168,13 @@ impl Binder {
                     result_ctx = ctx;
                 }
                 _ => {
-                    if join.right.is_lateral_table_function() {
+                    if is_unnest_table_function(&join.right) {
+                        let (expr, ctx) = self
+                            .bind_unnest(&mut result_ctx, result_expr.clone(), join)
+                            .await?;
+                        result_expr = expr;
+                        result_ctx = ctx;
+                    } else if join.right.is_lateral_table_function() {
                         let (expr, ctx) = self
                             .bind_lateral_table_function(
                                 &mut result_ctx,
@@ -1298,6 +1461,37 @@ impl Binder {
             }
         }
 
+        // The blocks of a bucketed table are distributed to the nodes by bucket.
+        let bucket_info = match &table.get_table_info().meta.bucket_spec {
+            Some(bucket_spec) => {
+                let keys = bucket_spec
+                    .key_indices(&table.schema())?
+                    .into_iter()
+                    .map(|field_index| {
+                        columns
+                            .iter()
+                            .find_map(|column| match column {
+                                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                                    column_index,
+                                    column_position,
+                                    path_indices: None,
+                                    ..
+                                }) if *column_position == Some(field_index + 1) => {
+                                    Some(*column_index)
+                                }
+                                _ => None,
+                            })
+                            .ok_or_else(|| ErrorCode::Internal("Bucket key column is not bound"))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(BucketInfo {
+                    keys,
+                    num_buckets: bucket_spec.num_buckets,
+                })
+            }
+            None => None,
+        };
+
         let mut columns = columns
             .into_iter()
             .map(|col| col.index())
@@ -1350,6 +1544,7 @@ impl Binder {
                     statistics: stat,
                     col_stats,
                 },
+                bucket_info,
                 ..Default::default()
             }
             .into(),
@@ -1457,6 +1652,21 @@ impl Binder {
                     )),
                 }
             }
+            TimeTravelPoint::Stream {
+                catalog,
+                database,
+                name,
+            } => {
+                let (catalog, database, name) =
+                    self.normalize_object_identifier_triple(catalog, database, name);
+                let stream = self.ctx.get_table(&catalog, &database, &name).await?;
+                if stream.engine() != "STREAM" {
+                    return Err(ErrorCode::IllegalStream(format!(
+                        "{database}.{name} is not a stream"
+                    )));
+                }
+                Ok(NavigationPoint::StreamInfo(stream.get_table_info().clone()))
+            }
         }
     }
 
@@ -1492,6 +1702,16 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
 }
 
 // parse flatten named params to arguments
+// `UNNEST` without `LATERAL` on the right side of a join is bound to the `Unnest` operator,
+// while `LATERAL UNNEST` is kept as a set-returning function.
+fn is_unnest_table_function(table_ref: &TableReference) -> bool {
+    matches!(table_ref, TableReference::TableFunction {
+        lateral: false,
+        name,
+        ..
+    } if name.name.eq_ignore_ascii_case("unnest"))
+}
+
 fn parse_table_function_args(
     span: &Span,
     func_name: &Identifier,
@@ -64,6 +64,7 @@ impl Binder {
         };
 
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, policy_predicates) = Self::split_row_access_policy(table_expr);
 
         let table = self
             .ctx
@@ -98,6 +99,19 @@ impl Binder {
                     field.name()
                 )));
             }
+            // Blocks are updated in place, rows can not be moved to another bucket.
+            if table
+                .get_table_info()
+                .meta
+                .bucket_spec
+                .as_ref()
+                .is_some_and(|spec| spec.column_ids.contains(&field.column_id()))
+            {
+                return Err(ErrorCode::InvalidBucketSpec(format!(
+                    "Can not update bucket key '{}'",
+                    field.name()
+                )));
+            }
 
             // TODO(zhyass): update_list support subquery.
             let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
@@ -111,7 +125,7 @@ impl Binder {
             update_columns.insert(index, scalar);
         }
 
-        let (selection, subquery_desc) = self
+        let (selection, mut subquery_desc) = self
             .process_selection(selection, table_expr, &mut scalar_binder)
             .await?;
 
@@ -124,6 +138,8 @@ impl Binder {
                 .set_span(selection.span()));
             }
         }
+        let selection =
+            Self::conjoin_row_access_policy(selection, &mut subquery_desc, policy_predicates);
 
         let plan = UpdatePlan {
             catalog: catalog_name,
@@ -77,6 +77,8 @@ impl Plan {
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
+            Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
+            Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable(_) => Ok("ReclusterTable".to_string()),
@@ -100,6 +102,9 @@ impl Plan {
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::CreateInvertedIndex(_) => Ok("CreateInvertedIndex".to_string()),
+            Plan::DropInvertedIndex(_) => Ok("DropInvertedIndex".to_string()),
+            Plan::RefreshInvertedIndex(_) => Ok("RefreshInvertedIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -116,11 +121,14 @@ impl Plan {
 
             // Stages
             Plan::CreateStage(_) => Ok("CreateStage".to_string()),
+            Plan::AlterStage(_) => Ok("AlterStage".to_string()),
             Plan::DropStage(_) => Ok("DropStage".to_string()),
+            Plan::ShowCreateStage(_) => Ok("ShowCreateStage".to_string()),
             Plan::RemoveStage(_) => Ok("RemoveStage".to_string()),
 
             // FileFormat
             Plan::CreateFileFormat(_) => Ok("CreateFileFormat".to_string()),
+            Plan::AlterFileFormat(_) => Ok("AlterFileFormat".to_string()),
             Plan::DropFileFormat(_) => Ok("DropFileFormat".to_string()),
             Plan::ShowFileFormats(_) => Ok("ShowFileFormats".to_string()),
 
@@ -138,6 +146,7 @@ impl Plan {
             Plan::AlterUser(_) => Ok("AlterUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::SetVariable(_) => Ok("SetVariable".to_string()),
@@ -173,6 +182,26 @@ impl Plan {
             Plan::DropNetworkPolicy(_) => Ok("DropNetworkPolicy".to_string()),
             Plan::DescNetworkPolicy(_) => Ok("DescNetworkPolicy".to_string()),
             Plan::ShowNetworkPolicies(_) => Ok("ShowNetworkPolicies".to_string()),
+            Plan::CreatePasswordPolicy(_) => Ok("CreatePasswordPolicy".to_string()),
+            Plan::AlterPasswordPolicy(_) => Ok("AlterPasswordPolicy".to_string()),
+            Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
+            Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
+            Plan::ShowPasswordPolicies(_) => Ok("ShowPasswordPolicies".to_string()),
+            Plan::CreateSettingsProfile(_) => Ok("CreateSettingsProfile".to_string()),
+            Plan::AlterSettingsProfile(_) => Ok("AlterSettingsProfile".to_string()),
+            Plan::DropSettingsProfile(_) => Ok("DropSettingsProfile".to_string()),
+            Plan::DescSettingsProfile(_) => Ok("DescSettingsProfile".to_string()),
+            Plan::ShowSettingsProfiles(_) => Ok("ShowSettingsProfiles".to_string()),
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+
+            // external volume
+            Plan::CreateExternalVolume(_) => Ok("CreateExternalVolume".to_string()),
+            Plan::AlterExternalVolume(_) => Ok("AlterExternalVolume".to_string()),
+            Plan::DropExternalVolume(_) => Ok("DropExternalVolume".to_string()),
+            Plan::DescExternalVolume(_) => Ok("DescExternalVolume".to_string()),
+            Plan::ShowExternalVolumes(_) => Ok("ShowExternalVolumes".to_string()),
 
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
@@ -232,6 +261,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            bucket_info: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
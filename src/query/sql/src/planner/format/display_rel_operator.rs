@@ -80,6 +80,7 @@ impl Display for FormatContext {
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
                 RelOperator::Udf(_) => write!(f, "Udf"),
+                RelOperator::Unnest(_) => write!(f, "Unnest"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
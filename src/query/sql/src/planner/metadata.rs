@@ -60,6 +60,8 @@ pub struct Metadata {
     lazy_columns: HashSet<IndexType>,
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
+    /// Mappings from table index to the time travel point the table is read at.
+    table_travel_points: HashMap<IndexType, String>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
 }
@@ -145,6 +147,16 @@ impl Metadata {
         self.table_row_id_index.values().copied().collect()
     }
 
+    pub fn set_table_travel_point(&mut self, table_index: IndexType, travel_point: String) {
+        self.table_travel_points.insert(table_index, travel_point);
+    }
+
+    pub fn travel_point_by_table_index(&self, table_index: IndexType) -> Option<&str> {
+        self.table_travel_points
+            .get(&table_index)
+            .map(|point| point.as_str())
+    }
+
     pub fn columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
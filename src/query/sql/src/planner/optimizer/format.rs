@@ -53,6 +53,7 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
         RelOperator::Udf(_) => "Udf".to_string(),
+        RelOperator::Unnest(_) => "Unnest".to_string(),
     }
 }
 
@@ -164,6 +164,7 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::Unnest(_)
                 ) {
                     left_is_subquery = true;
                 }
@@ -176,6 +177,7 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::Unnest(_)
                 ) {
                     right_is_subquery = true;
                 }
@@ -220,6 +222,7 @@ impl DPhpy {
             | RelOperator::EvalScalar(_)
             | RelOperator::Window(_)
             | RelOperator::Udf(_)
+            | RelOperator::Unnest(_)
             | RelOperator::Filter(_) => {
                 if join_child {
                     // If plan is filter, save it
@@ -38,6 +38,7 @@ use crate::plans::CopyIntoLocationPlan;
 use crate::plans::MergeInto;
 use crate::plans::Plan;
 use crate::plans::RelOperator;
+use crate::plans::ValidationMode;
 use crate::IndexType;
 use crate::MetadataRef;
 
@@ -110,16 +111,22 @@ pub fn optimize(
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(ctx, opt_ctx, *plan)?),
         }),
-        Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
-            Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
-                stage,
-                path,
-                from: Box::new(optimize(ctx, opt_ctx, *from)?),
-            }))
-        }
+        Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from,
+            file_name_template,
+        }) => Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from: Box::new(optimize(ctx, opt_ctx, *from)?),
+            file_name_template,
+        })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
+            // the validation of the files is not distributed, the rows are returned directly.
             plan.enable_distributed = opt_ctx.config.enable_distributed_optimization
-                && ctx.get_settings().get_enable_distributed_copy()?;
+                && ctx.get_settings().get_enable_distributed_copy()?
+                && plan.validation_mode == ValidationMode::None;
             info!(
                 "after optimization enable_distributed_copy? : {}",
                 plan.enable_distributed
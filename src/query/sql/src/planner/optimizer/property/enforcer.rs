@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::optimizer::property::Distribution;
@@ -139,6 +140,10 @@ pub fn enforce_distribution(distribution: &Distribution, s_expr: &SExpr) -> Resu
             Arc::new(Exchange::Hash(hash_keys.clone()).into()),
             Arc::new(s_expr.clone()),
         )),
+
+        Distribution::Bucket { .. } => Err(ErrorCode::Internal(
+            "Bucket distribution can only be required from a bucketed input",
+        )),
     }
 }
 
@@ -73,6 +73,12 @@ pub enum Distribution {
     Serial,
     Broadcast,
     Hash(Vec<ScalarExpr>),
+    /// The data of a bucketed table, the same bucket of the tables bucketed into the
+    /// same number of buckets is always on the same node.
+    Bucket {
+        keys: Vec<IndexType>,
+        num_buckets: u64,
+    },
 }
 
 impl Default for Distribution {
@@ -92,6 +98,8 @@ impl Distribution {
             | (Distribution::Serial, Distribution::Serial)
             | (Distribution::Broadcast, Distribution::Broadcast) => true,
 
+            (Distribution::Bucket { .. }, Distribution::Bucket { .. }) => self == distribution,
+
             // TODO(leiysky): this is actually broken by https://github.com/datafuselabs/databend/pull/7451
             // , would be fixed later.
             // (Distribution::Hash(ref keys), Distribution::Hash(ref other_keys)) => keys
@@ -364,7 +364,8 @@ impl SExpr {
             | RelOperator::AddRowNumber(_)
             | RelOperator::Pattern(_)
             | RelOperator::MaterializedCte(_)
-            | RelOperator::ConstantTableScan(_) => {}
+            | RelOperator::ConstantTableScan(_)
+            | RelOperator::Unnest(_) => {}
         };
         for child in &self.children {
             let udf = child.get_udfs()?;
@@ -430,7 +431,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::AddRowNumber(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
-        | RelOperator::ConstantTableScan(_) => false,
+        | RelOperator::ConstantTableScan(_)
+        | RelOperator::Unnest(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
                 || op.right_conditions.iter().any(find_subquery_in_expr)
@@ -29,6 +29,7 @@ use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::IndexType;
 
@@ -81,6 +82,20 @@ impl Aggregate {
         Ok(used_columns)
     }
 
+    /// Check if the input is bucketed by a subset of the group items.
+    fn is_bucket_aligned(&self, distribution: &Distribution) -> bool {
+        let Distribution::Bucket { keys, .. } = distribution else {
+            return false;
+        };
+        // The grouping sets generate groups without some of the group items.
+        self.grouping_sets.is_none()
+            && keys.iter().all(|key| {
+            self.group_items.iter().any(|item| {
+                matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == *key)
+            })
+        })
+    }
+
     pub fn group_columns(&self) -> Result<ColumnSet> {
         let mut col_set = ColumnSet::new();
         for group_item in self.group_items.iter() {
@@ -119,6 +134,9 @@ impl Operator for Aggregate {
                 if self.group_items.is_empty() {
                     // Scalar aggregation
                     required.distribution = Distribution::Any;
+                } else if self.is_bucket_aligned(&child_physical_prop.distribution) {
+                    // The rows of the same group are already on the same node.
+                    required.distribution = child_physical_prop.distribution;
                 } else {
                     let settings = ctx.get_settings();
 
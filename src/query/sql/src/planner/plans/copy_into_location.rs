@@ -14,7 +14,14 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::sync::Arc;
 
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_meta_app::principal::StageInfo;
 
 use crate::plans::Plan;
@@ -24,6 +31,21 @@ pub struct CopyIntoLocationPlan {
     pub stage: Box<StageInfo>,
     pub path: String,
     pub from: Box<Plan>,
+    /// The unloaded files are returned if their names are given by the template.
+    pub file_name_template: Option<String>,
+}
+
+impl CopyIntoLocationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.file_name_template.is_none() {
+            return Arc::new(DataSchema::empty());
+        }
+        DataSchemaRefExt::create(vec![
+            DataField::new("File", DataType::String),
+            DataField::new("Rows_unloaded", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("File_size", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
 }
 
 impl Debug for CopyIntoLocationPlan {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
@@ -68,6 +69,38 @@ impl FromStr for ValidationMode {
     }
 }
 
+/// How the columns of the files are matched to the columns of the table.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MatchByColumnName {
+    /// The columns are selected by the table column names, as identifiers.
+    None,
+    CaseSensitive,
+    CaseInsensitive,
+}
+
+impl FromStr for MatchByColumnName {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "" | "NONE" => Ok(MatchByColumnName::None),
+            "CASE_SENSITIVE" => Ok(MatchByColumnName::CaseSensitive),
+            "CASE_INSENSITIVE" => Ok(MatchByColumnName::CaseInsensitive),
+            v => Err(format!(
+                "Unknown match_by_column_name:{v:?}, must one of {{ CASE_SENSITIVE | CASE_INSENSITIVE | NONE }}"
+            )),
+        }
+    }
+}
+
+impl MatchByColumnName {
+    pub fn matches(&self, file_column: &str, table_column: &str) -> bool {
+        match self {
+            MatchByColumnName::CaseInsensitive => file_column.eq_ignore_ascii_case(table_column),
+            _ => file_column == table_column,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum CopyIntoTableMode {
     Insert { overwrite: bool },
@@ -106,10 +139,21 @@ pub struct CopyIntoTablePlan {
     pub query: Option<Box<Plan>>,
 
     pub enable_distributed: bool,
+    /// Returns the detailed result of each file, see [`CopyIntoTablePlan::schema`].
+    pub detailed_result: bool,
 }
 
 impl CopyIntoTablePlan {
     pub async fn collect_files(&self, ctx: &dyn TableContext) -> Result<Vec<StageFileInfo>> {
+        let (files, _) = self.collect_files_and_duplicated(ctx).await?;
+        Ok(files)
+    }
+
+    /// Collects the files to copy, and the files filtered out since they have been copied before.
+    pub async fn collect_files_and_duplicated(
+        &self,
+        ctx: &dyn TableContext,
+    ) -> Result<(Vec<StageFileInfo>, Vec<StageFileInfo>)> {
         ctx.set_status_info("begin to list files");
         let start = Instant::now();
 
@@ -152,6 +196,7 @@ impl CopyIntoTablePlan {
 
         ctx.set_status_info(&format!("end list files: got {} files", num_all_files));
 
+        let mut duplicated_file_infos = vec![];
         let need_copy_file_infos = if self.force {
             if !self.stage_table_info.stage_info.copy_options.purge
                 && all_source_file_infos.len() > COPY_MAX_FILES_PER_COMMIT
@@ -187,6 +232,12 @@ impl CopyIntoTablePlan {
                 .as_millis();
             metrics_inc_copy_filter_out_copied_files_entire_milliseconds(cost_filter_out as u64);
 
+            let need_copy: HashSet<&str> = files.iter().map(|f| f.path.as_str()).collect();
+            duplicated_file_infos = all_source_file_infos
+                .iter()
+                .filter(|f| !need_copy.contains(f.path.as_str()))
+                .cloned()
+                .collect();
             files
         };
 
@@ -198,7 +249,7 @@ impl CopyIntoTablePlan {
             start.elapsed().as_secs()
         );
 
-        Ok(need_copy_file_infos)
+        Ok((need_copy_file_infos, duplicated_file_infos))
     }
 }
 
@@ -249,9 +300,43 @@ impl CopyIntoTablePlan {
         ])
     }
 
+    fn copy_into_table_detailed_schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("File", DataType::String),
+            DataField::new("File_size", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Rows_parsed", DataType::Number(NumberDataType::Int32)),
+            DataField::new("Rows_loaded", DataType::Number(NumberDataType::Int32)),
+            DataField::new("Errors_seen", DataType::Number(NumberDataType::Int32)),
+            DataField::new(
+                "First_error",
+                DataType::Nullable(Box::new(DataType::String)),
+            ),
+            DataField::new(
+                "First_error_line",
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32))),
+            ),
+            DataField::new("Status", DataType::String),
+        ])
+    }
+
+    fn copy_into_table_validation_errors_schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("File", DataType::String),
+            DataField::new("Line", DataType::Number(NumberDataType::Int32)),
+            DataField::new("Column", DataType::Nullable(Box::new(DataType::String))),
+            DataField::new("Error", DataType::String),
+        ])
+    }
+
     pub fn schema(&self) -> DataSchemaRef {
         if self.from_attachment {
             Arc::new(DataSchema::empty())
+        } else if let ValidationMode::ReturnNRows(_) = self.validation_mode {
+            self.required_values_schema.clone()
+        } else if self.validation_mode != ValidationMode::None {
+            Self::copy_into_table_validation_errors_schema()
+        } else if self.detailed_result {
+            Self::copy_into_table_detailed_schema()
         } else {
             Self::copy_into_table_schema()
         }
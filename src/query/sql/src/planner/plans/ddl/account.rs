@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use common_ast::ast::AlterPasswordAction;
+use common_ast::ast::AlterSettingsProfileAction;
+use common_ast::ast::PasswordSetOptions;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::DataField;
@@ -58,6 +63,13 @@ pub struct DropRolePlan {
     pub role_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterRolePlan {
+    pub role_name: String,
+    // None means unset the settings profile of the role
+    pub settings_profile: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GrantRolePlan {
     pub principal: PrincipalIdentity,
@@ -112,6 +124,8 @@ pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
     pub on: GrantObject,
+    /// Revoke all the privileges granted on the object while transferring its ownership.
+    pub revoke_current_grants: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -195,3 +209,143 @@ impl ShowNetworkPoliciesPlan {
         ])
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatePasswordPolicyPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub set_options: PasswordSetOptions,
+}
+
+impl CreatePasswordPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterPasswordPolicyPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub action: AlterPasswordAction,
+}
+
+impl AlterPasswordPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropPasswordPolicyPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropPasswordPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescPasswordPolicyPlan {
+    pub name: String,
+}
+
+impl DescPasswordPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Property", DataType::String),
+            DataField::new("Value", DataType::String),
+            DataField::new("Default", DataType::String),
+            DataField::new("Description", DataType::String),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowPasswordPoliciesPlan {}
+
+impl ShowPasswordPoliciesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Comment", DataType::String),
+            DataField::new("Options", DataType::String),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateSettingsProfilePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub settings: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl CreateSettingsProfilePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterSettingsProfilePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub action: AlterSettingsProfileAction,
+}
+
+impl AlterSettingsProfilePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropSettingsProfilePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropSettingsProfilePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescSettingsProfilePlan {
+    pub name: String,
+}
+
+impl DescSettingsProfilePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Setting", DataType::String),
+            DataField::new("Value", DataType::String),
+            DataField::new("Default", DataType::String),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowSettingsProfilesPlan {}
+
+impl ShowSettingsProfilesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Comment", DataType::String),
+            DataField::new("Settings", DataType::String),
+        ])
+    }
+}
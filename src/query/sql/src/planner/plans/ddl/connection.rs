@@ -26,6 +26,7 @@ pub struct CreateConnectionPlan {
     pub name: String,
     pub storage_type: String,
     pub storage_params: BTreeMap<String, String>,
+    pub comment: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -48,6 +49,7 @@ impl ShowConnectionsPlan {
             DataField::new("name", DataType::String),
             DataField::new("storage_type", DataType::String),
             DataField::new("storage_params", DataType::String),
+            DataField::new("comment", DataType::String),
         ])
     }
 }
@@ -58,6 +60,7 @@ impl DescConnectionPlan {
             DataField::new("name", DataType::String),
             DataField::new("storage_type", DataType::String),
             DataField::new("storage_params", DataType::String),
+            DataField::new("comment", DataType::String),
         ])
     }
 }
@@ -62,6 +62,8 @@ pub struct DropDatabasePlan {
     pub tenant: String,
     pub catalog: String,
     pub database: String,
+    /// Drop the objects depending on the database together, like the streams on its tables.
+    pub cascade: bool,
 }
 
 impl From<DropDatabasePlan> for DropDatabaseReq {
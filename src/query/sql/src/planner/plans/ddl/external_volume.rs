@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::storage::StorageParams;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateExternalVolumePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub storage_params: StorageParams,
+    pub comment: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterExternalVolumePlan {
+    pub tenant: String,
+    pub name: String,
+    pub storage_params: StorageParams,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropExternalVolumePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescExternalVolumePlan {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DescExternalVolumePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        external_volume_schema()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowExternalVolumesPlan {
+    pub tenant: String,
+}
+
+impl ShowExternalVolumesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        external_volume_schema()
+    }
+}
+
+fn external_volume_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("name", DataType::String),
+        DataField::new("storage_params", DataType::String),
+        DataField::new("comment", DataType::String),
+        DataField::new("created_on", DataType::Timestamp),
+    ])
+}
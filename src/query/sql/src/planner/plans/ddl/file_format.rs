@@ -14,6 +14,7 @@
 
 use std::fmt::Debug;
 
+use common_ast::ast::AlterFileFormatAction;
 use common_expression::types::DataType;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -25,6 +26,15 @@ pub struct CreateFileFormatPlan {
     pub if_not_exists: bool,
     pub name: String,
     pub file_format_params: FileFormatParams,
+    pub comment: String,
+}
+
+/// Alter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterFileFormatPlan {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterFileFormatAction,
 }
 
 /// Drop.
@@ -43,6 +53,7 @@ impl ShowFileFormatsPlan {
         DataSchemaRefExt::create(vec![
             DataField::new("name", DataType::String),
             DataField::new("format_options", DataType::String),
+            DataField::new("comment", DataType::String),
         ])
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::TableIndexType;
+use common_expression::ColumnId;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
@@ -48,3 +49,32 @@ pub struct RefreshIndexPlan {
     pub user_defined_block_name: bool,
     pub segment_locs: Option<Vec<Location>>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub column_ids: Vec<ColumnId>,
+    pub tokenizer: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshInvertedIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub limit: Option<u64>,
+}
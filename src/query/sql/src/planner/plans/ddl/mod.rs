@@ -16,6 +16,7 @@ mod account;
 mod catalog;
 mod connection;
 mod database;
+mod external_volume;
 mod file_format;
 mod index;
 mod stage;
@@ -30,6 +31,7 @@ pub use account::*;
 pub use catalog::*;
 pub use connection::*;
 pub use database::*;
+pub use external_volume::*;
 pub use file_format::*;
 pub use index::*;
 pub use stage::*;
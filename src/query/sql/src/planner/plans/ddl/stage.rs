@@ -14,6 +14,11 @@
 
 use std::fmt::Debug;
 
+use common_ast::ast::AlterStageAction;
+use common_expression::types::DataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_meta_app::principal::StageInfo;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -30,6 +35,30 @@ pub struct DropStagePlan {
     pub name: String,
 }
 
+/// Alter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterStagePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub action: AlterStageAction,
+}
+
+/// Show create.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowCreateStagePlan {
+    pub name: String,
+}
+
+impl ShowCreateStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Stage", DataType::String),
+            DataField::new("Create Stage", DataType::String),
+        ])
+    }
+}
+
 /// Remove.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RemoveStagePlan {
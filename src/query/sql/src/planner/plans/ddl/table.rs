@@ -26,6 +26,7 @@ use common_expression::DataSchemaRefExt;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
+use common_meta_app::schema::BucketSpec;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
@@ -50,6 +51,7 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub bucket_spec: Option<BucketSpec>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -111,7 +113,12 @@ impl VacuumTablePlan {
                 DataType::String,
             )]))
         } else {
-            Arc::new(DataSchema::empty())
+            Arc::new(DataSchema::new(vec![
+                DataField::new("files_removed", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("files_failed", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("files_skipped", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("failed_samples", DataType::String),
+            ]))
         }
     }
 }
@@ -163,14 +170,33 @@ pub struct OptimizeTablePlan {
 
 impl OptimizeTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
-        Arc::new(DataSchema::empty())
+        if let OptimizeTableAction::Purge {
+            before: Some(_), ..
+        } = self.action
+        {
+            Arc::new(DataSchema::new(vec![
+                DataField::new(
+                    "snapshots_removed",
+                    DataType::Number(NumberDataType::UInt64),
+                ),
+                DataField::new("segments_removed", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("blocks_removed", DataType::Number(NumberDataType::UInt64)),
+            ]))
+        } else {
+            Arc::new(DataSchema::empty())
+        }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OptimizeTableAction {
     All,
-    Purge(Option<NavigationPoint>),
+    /// Purges the history before the given point regardless of the retention period, the
+    /// points protected by the streams of the table can only be purged with `force`.
+    Purge {
+        before: Option<NavigationPoint>,
+        force: bool,
+    },
     CompactBlocks,
     CompactSegments,
 }
@@ -279,6 +305,38 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table add row access policy
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop row access policy
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
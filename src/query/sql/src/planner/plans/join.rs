@@ -181,6 +181,45 @@ impl Join {
         Ok(used_columns)
     }
 
+    /// Check if both sides are bucketed into the same number of buckets and each pair of the
+    /// bucket keys is equated by the join conditions, then the join can be done without shuffle.
+    fn is_bucket_aligned(&self, probe: &Distribution, build: &Distribution) -> bool {
+        let (
+            Distribution::Bucket {
+                keys: probe_keys,
+                num_buckets: probe_num_buckets,
+            },
+            Distribution::Bucket {
+                keys: build_keys,
+                num_buckets: build_num_buckets,
+            },
+        ) = (probe, build)
+        else {
+            return false;
+        };
+        if probe_num_buckets != build_num_buckets || probe_keys.len() != build_keys.len() {
+            return false;
+        }
+        probe_keys
+            .iter()
+            .zip(build_keys.iter())
+            .all(|(probe_key, build_key)| {
+                self.left_conditions
+                    .iter()
+                    .zip(self.right_conditions.iter())
+                    .any(|(left, right)| match (left, right) {
+                        (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right)) => {
+                            // The same value of different types has different hashes.
+                            left.column.index == *probe_key
+                                && right.column.index == *build_key
+                                && left.column.data_type.remove_nullable()
+                                    == right.column.data_type.remove_nullable()
+                        }
+                        _ => false,
+                    })
+            })
+    }
+
     fn inner_join_cardinality(
         &self,
         left_cardinality: &mut f64,
@@ -399,6 +438,21 @@ impl Operator for Join {
         let build_prop = rel_expr.derive_physical_prop_child(1)?;
 
         match (&probe_prop.distribution, &build_prop.distribution) {
+            // The rows of the build side can be emitted on any node, so only the joins which
+            // output the rows of the probe side keep the bucket distribution.
+            (Distribution::Bucket { .. }, _)
+                if !matches!(
+                    self.join_type,
+                    JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+                ) =>
+            {
+                Ok(PhysicalProperty {
+                    distribution: Distribution::Random,
+                })
+            }
+            (Distribution::Random, Distribution::Bucket { .. }) => Ok(PhysicalProperty {
+                distribution: Distribution::Random,
+            }),
             // If the distribution of probe side is Random, we will pass through
             // the distribution of build side.
             (Distribution::Random, _) => Ok(PhysicalProperty {
@@ -486,6 +540,17 @@ impl Operator for Join {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
             return Ok(required);
+        } else if self.is_bucket_aligned(
+            &probe_physical_prop.distribution,
+            &build_physical_prop.distribution,
+        ) {
+            // The matching rows of both sides are already on the same node.
+            required.distribution = if child_index == 0 {
+                probe_physical_prop.distribution
+            } else {
+                build_physical_prop.distribution
+            };
+            return Ok(required);
         } else if ctx.get_settings().get_prefer_broadcast_join()?
             && !matches!(
                 self.join_type,
@@ -499,8 +564,9 @@ impl Operator for Join {
             let left_stat_info = rel_expr.derive_cardinality_child(0)?;
             let right_stat_info = rel_expr.derive_cardinality_child(1)?;
             // The broadcast join is cheaper than the hash join when one input is at least (n − 1)× larger than the other
-            // where n is the number of servers in the cluster.
-            let broadcast_join_threshold = (ctx.get_cluster().nodes.len() - 1) as f64;
+            // where n is the number of servers executing the query in the cluster.
+            let broadcast_join_threshold =
+                (ctx.get_cluster().get_executor_nodes().len() - 1) as f64;
             if right_stat_info.cardinality * broadcast_join_threshold < left_stat_info.cardinality {
                 required.distribution = Distribution::Broadcast;
                 return Ok(required);
@@ -32,6 +32,7 @@ mod limit;
 mod materialized_cte;
 mod merge_into;
 mod udf;
+mod unnest;
 
 mod copy_into_location;
 pub mod operator;
@@ -42,6 +43,7 @@ mod project_set;
 mod recluster_table;
 mod replace;
 mod revert_table;
+mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod setting;
@@ -86,6 +88,7 @@ pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use setting::*;
@@ -93,5 +96,6 @@ pub use share::*;
 pub use sort::*;
 pub use udf::*;
 pub use union_all::UnionAll;
+pub use unnest::*;
 pub use update::*;
 pub use window::*;
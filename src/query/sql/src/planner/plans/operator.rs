@@ -40,6 +40,7 @@ use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
 use crate::plans::Udf;
+use crate::plans::Unnest;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -84,6 +85,7 @@ pub enum RelOp {
     ConstantTableScan,
     AddRowNumber,
     Udf,
+    Unnest,
 
     // Pattern
     Pattern,
@@ -109,6 +111,7 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    Unnest(Unnest),
     Pattern(PatternPlan),
 }
 
@@ -133,6 +136,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::Unnest(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -156,6 +160,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Unnest(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -179,6 +184,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Unnest(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -202,6 +208,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::Unnest(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -267,6 +274,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Unnest(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -589,3 +599,21 @@ impl TryFrom<RelOperator> for Udf {
         }
     }
 }
+
+impl From<Unnest> for RelOperator {
+    fn from(value: Unnest) -> Self {
+        Self::Unnest(value)
+    }
+}
+
+impl TryFrom<RelOperator> for Unnest {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::Unnest(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast RelOperator to Unnest"))
+        }
+    }
+}
@@ -28,8 +28,15 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
+use crate::plans::AlterExternalVolumePlan;
+use crate::plans::AlterFileFormatPlan;
 use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterRolePlan;
+use crate::plans::AlterSettingsProfilePlan;
 use crate::plans::AlterShareTenantsPlan;
+use crate::plans::AlterStagePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
@@ -43,10 +50,15 @@ use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateConnectionPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateDatamaskPolicyPlan;
+use crate::plans::CreateExternalVolumePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::CreateSettingsProfilePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -60,7 +72,11 @@ use crate::plans::CreateVirtualColumnPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
+use crate::plans::DescExternalVolumePlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DescSettingsProfilePlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -68,10 +84,15 @@ use crate::plans::DropCatalogPlan;
 use crate::plans::DropConnectionPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
+use crate::plans::DropExternalVolumePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSettingsProfilePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
@@ -79,6 +100,7 @@ use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -93,10 +115,12 @@ use crate::plans::Insert;
 use crate::plans::KillPlan;
 use crate::plans::MergeInto;
 use crate::plans::ModifyTableColumnPlan;
+use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -113,13 +137,17 @@ use crate::plans::SettingPlan;
 use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
+use crate::plans::ShowCreateStagePlan;
 use crate::plans::ShowCreateTablePlan;
+use crate::plans::ShowExternalVolumesPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantTenantsOfSharePlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
 use crate::plans::ShowObjectGrantPrivilegesPlan;
+use crate::plans::ShowPasswordPoliciesPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSettingsProfilesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
@@ -190,6 +218,8 @@ pub enum Plan {
     RenameTableColumn(Box<RenameTableColumnPlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
@@ -222,6 +252,9 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
+    RefreshInvertedIndex(Box<RefreshInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -243,6 +276,7 @@ pub enum Plan {
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     ShowGrants(Box<ShowGrantsPlan>),
@@ -253,12 +287,15 @@ pub enum Plan {
 
     // FileFormat
     CreateFileFormat(Box<CreateFileFormatPlan>),
+    AlterFileFormat(Box<AlterFileFormatPlan>),
     DropFileFormat(Box<DropFileFormatPlan>),
     ShowFileFormats(Box<ShowFileFormatsPlan>),
 
     // Stages
     CreateStage(Box<CreateStagePlan>),
+    AlterStage(Box<AlterStagePlan>),
     DropStage(Box<DropStagePlan>),
+    ShowCreateStage(Box<ShowCreateStagePlan>),
     RemoveStage(Box<RemoveStagePlan>),
 
     // Connection
@@ -301,6 +338,32 @@ pub enum Plan {
     DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
     ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
 
+    // Password policy
+    CreatePasswordPolicy(Box<CreatePasswordPolicyPlan>),
+    AlterPasswordPolicy(Box<AlterPasswordPolicyPlan>),
+    DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
+    DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
+    ShowPasswordPolicies(Box<ShowPasswordPoliciesPlan>),
+
+    // Settings profile
+    CreateSettingsProfile(Box<CreateSettingsProfilePlan>),
+    AlterSettingsProfile(Box<AlterSettingsProfilePlan>),
+    DropSettingsProfile(Box<DropSettingsProfilePlan>),
+    DescSettingsProfile(Box<DescSettingsProfilePlan>),
+    ShowSettingsProfiles(Box<ShowSettingsProfilesPlan>),
+
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
+    // External volume
+    CreateExternalVolume(Box<CreateExternalVolumePlan>),
+    AlterExternalVolume(Box<AlterExternalVolumePlan>),
+    DropExternalVolume(Box<DropExternalVolumePlan>),
+    DescExternalVolume(Box<DescExternalVolumePlan>),
+    ShowExternalVolumes(Box<ShowExternalVolumesPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -392,6 +455,7 @@ impl Plan {
             Plan::ShowRoles(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
+            Plan::ShowCreateStage(plan) => plan.schema(),
 
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
@@ -409,7 +473,22 @@ impl Plan {
             Plan::DropNetworkPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
+            Plan::CreatePasswordPolicy(plan) => plan.schema(),
+            Plan::AlterPasswordPolicy(plan) => plan.schema(),
+            Plan::DropPasswordPolicy(plan) => plan.schema(),
+            Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::ShowPasswordPolicies(plan) => plan.schema(),
+            Plan::CreateSettingsProfile(plan) => plan.schema(),
+            Plan::AlterSettingsProfile(plan) => plan.schema(),
+            Plan::DropSettingsProfile(plan) => plan.schema(),
+            Plan::DescSettingsProfile(plan) => plan.schema(),
+            Plan::ShowSettingsProfiles(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescExternalVolume(plan) => plan.schema(),
+            Plan::ShowExternalVolumes(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
+            Plan::CopyIntoLocation(plan) => plan.schema(),
+            Plan::OptimizeTable(plan) => plan.schema(),
             Plan::MergeInto(plan) => plan.schema(),
             Plan::CreateTask(plan) => plan.schema(),
             Plan::DescribeTask(plan) => plan.schema(),
@@ -438,6 +517,7 @@ impl Plan {
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowCreateCatalog(_)
                 | Plan::ShowFileFormats(_)
+                | Plan::ShowCreateStage(_)
                 | Plan::ShowRoles(_)
                 | Plan::DescShare(_)
                 | Plan::ShowShares(_)
@@ -452,7 +532,25 @@ impl Plan {
                 | Plan::DescDatamaskPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::ShowPasswordPolicies(_)
+                | Plan::DescSettingsProfile(_)
+                | Plan::ShowSettingsProfiles(_)
+                | Plan::DescRowAccessPolicy(_)
+                | Plan::DescExternalVolume(_)
+                | Plan::ShowExternalVolumes(_)
                 | Plan::CopyIntoTable(_)
+                | Plan::CopyIntoLocation(CopyIntoLocationPlan {
+                    file_name_template: Some(_),
+                    ..
+                })
+                | Plan::OptimizeTable(box OptimizeTablePlan {
+                    action: OptimizeTableAction::Purge {
+                        before: Some(_),
+                        ..
+                    },
+                    ..
+                })
                 | Plan::ShowTasks(_)
                 | Plan::DescribeTask(_)
                 | Plan::DescConnection(_)
@@ -77,6 +77,13 @@ impl AggIndexInfo {
     }
 }
 
+/// The bucket keys of a bucketed table, see [`Distribution::Bucket`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketInfo {
+    pub keys: Vec<IndexType>,
+    pub num_buckets: u64,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -94,6 +101,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub bucket_info: Option<BucketInfo>,
 
     pub statistics: Statistics,
 }
@@ -120,6 +128,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            bucket_info: self.bucket_info.clone(),
         }
     }
 
@@ -173,9 +182,14 @@ impl Operator for Scan {
     }
 
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        Ok(PhysicalProperty {
-            distribution: Distribution::Random,
-        })
+        let distribution = match &self.bucket_info {
+            Some(bucket_info) => Distribution::Bucket {
+                keys: bucket_info.keys.clone(),
+                num_buckets: bucket_info.num_buckets,
+            },
+            None => Distribution::Random,
+        };
+        Ok(PhysicalProperty { distribution })
     }
 
     fn derive_cardinality(&self, _rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
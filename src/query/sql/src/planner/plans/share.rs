@@ -20,6 +20,7 @@ use common_expression::types::DataType;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::share::CreateShareEndpointReq;
 use common_meta_app::share::CreateShareReq;
 use common_meta_app::share::DropShareEndpointReq;
@@ -205,18 +206,24 @@ impl ShowSharesPlan {
     }
 }
 
-// Show object grant privileges.
+// Show the roles, users and shares holding privileges on an object.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowObjectGrantPrivilegesPlan {
-    pub object: ShareGrantObjectName,
+    pub object: GrantObject,
 }
 
 impl ShowObjectGrantPrivilegesPlan {
     pub fn schema(&self) -> DataSchemaRef {
         Arc::new(DataSchema::new(vec![
-            DataField::new("Granted_on", DataType::String),
-            DataField::new("Privilege", DataType::String),
-            DataField::new("Share_name", DataType::String),
+            DataField::new("privilege", DataType::String),
+            DataField::new("grantee_type", DataType::String),
+            DataField::new("grantee", DataType::String),
+            DataField::new("grant_option", DataType::Boolean),
+            DataField::new("granted_by", DataType::Nullable(Box::new(DataType::String))),
+            DataField::new(
+                "granted_at",
+                DataType::Nullable(Box::new(DataType::Timestamp)),
+            ),
         ]))
     }
 }
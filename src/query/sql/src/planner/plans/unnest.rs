@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `Unnest` expands each input row into one row per element of the array column,
+/// it's the plan of `UNNEST` in the `FROM` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Unnest {
+    /// The array column to expand.
+    pub array: IndexType,
+    /// The output column of the elements.
+    pub element: IndexType,
+    /// The output column of the 1-based positions of the elements, if `WITH ORDINALITY`.
+    pub ordinality: Option<IndexType>,
+    /// Whether to keep the rows with NULL or empty arrays, the new columns are NULL.
+    pub outer: bool,
+}
+
+impl Unnest {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.insert(self.array);
+        used_columns.insert(self.element);
+        used_columns.extend(self.ordinality);
+        Ok(used_columns)
+    }
+
+    /// The type of the elements of the array type `array_type`, the elements are
+    /// nullable under `outer` since the rows of NULL or empty arrays are kept.
+    pub fn element_type(array_type: &DataType, outer: bool) -> Result<DataType> {
+        let element_type = match array_type.remove_nullable() {
+            DataType::Array(ty) => *ty,
+            DataType::EmptyArray | DataType::Null => DataType::Null,
+            ty => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "UNNEST expects an array argument, but got {}",
+                    ty
+                )));
+            }
+        };
+        if outer {
+            Ok(element_type.wrap_nullable())
+        } else {
+            Ok(element_type)
+        }
+    }
+
+    /// The type of the ordinality column.
+    pub fn ordinality_type(outer: bool) -> DataType {
+        let ty = DataType::Number(NumberDataType::UInt64);
+        if outer { ty.wrap_nullable() } else { ty }
+    }
+}
+
+impl Operator for Unnest {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Unnest
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns.clone();
+        output_columns.insert(self.element);
+        output_columns.extend(self.ordinality);
+
+        // Derive outer columns
+        let mut outer_columns = input_prop.outer_columns.clone();
+        if !output_columns.contains(&self.array) {
+            outer_columns.insert(self.array);
+        }
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        let mut input_stat = rel_expr.derive_cardinality_child(0)?.deref().clone();
+        // The number of rows depends on the lengths of the arrays.
+        input_stat.statistics.precise_cardinality = None;
+        Ok(Arc::new(input_stat))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
@@ -82,6 +82,7 @@ impl VisitorMut for DistinctToGroupBy {
                         order_by: vec![],
                         limit: vec![],
                         offset: None,
+                        into: None,
                         ignore_result: false,
                     };
 
@@ -39,5 +39,16 @@ pub use type_check::TypeChecker;
 pub use view_rewriter::ViewRewriter;
 pub use window_check::WindowChecker;
 
-pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 5] =
-    ["sum", "min", "max", "avg", "approx_count_distinct"];
+pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 11] = [
+    "sum",
+    "min",
+    "max",
+    "avg",
+    "approx_count_distinct",
+    "bitmap_union",
+    "bitmap_intersect",
+    "bitmap_and_count",
+    "bitmap_or_count",
+    "bitmap_xor_count",
+    "bitmap_intersect_count",
+];
@@ -1851,6 +1851,26 @@ impl<'a> TypeChecker<'a> {
             )));
         }
 
+        // `match` can be pushed down to inverted indexes only if it
+        // looks like `match(column, 'query')`.
+        if func_name == "match" {
+            let valid = args.len() == 2
+                && matches!(args[0], ScalarExpr::BoundColumnRef(_))
+                && matches!(
+                    &args[1],
+                    ScalarExpr::ConstantExpr(ConstantExpr {
+                        value: Scalar::String(_),
+                        ..
+                    })
+                );
+            if !valid {
+                return Err(ErrorCode::SemanticError(
+                    "match function expects a column and a constant string query",
+                )
+                .set_span(span));
+            }
+        }
+
         // rewrite_collation
         let func_name = if self.function_need_collation(func_name, &args)?
             && self.ctx.get_settings().get_collation()? == "utf8"
@@ -2223,6 +2243,8 @@ impl<'a> TypeChecker<'a> {
             "currentuser",
             "current_user",
             "current_role",
+            "is_role_in_session",
+            "invoker_user",
             "connection_id",
             "timezone",
             "nullif",
@@ -2286,6 +2308,51 @@ impl<'a> TypeChecker<'a> {
                 })
                 .await,
             ),
+            ("is_role_in_session", &[arg]) => {
+                // is_role_in_session(role) returns whether the role is in effect in the
+                // current session, either as the current role or one of the secondary roles,
+                // with the roles granted to them. It is folded into a constant on binding.
+                let res: Result<bool> = try {
+                    let box (scalar, _) = self.resolve(arg).await?;
+                    let role_name = match ConstantExpr::try_from(scalar) {
+                        Ok(ConstantExpr {
+                            value: Scalar::String(val),
+                            ..
+                        }) => String::from_utf8(val)?,
+                        _ => {
+                            return Some(Err(ErrorCode::BadArguments(
+                                "is_role_in_session argument only support constant string",
+                            )
+                            .set_span(span)));
+                        }
+                    };
+                    let roles = self.ctx.get_all_effective_roles().await?;
+                    roles.iter().any(|r| r.name == role_name)
+                };
+
+                Some(match res {
+                    Ok(in_session) => {
+                        self.resolve(&Expr::Literal {
+                            span,
+                            lit: Literal::Boolean(in_session),
+                        })
+                        .await
+                    }
+                    Err(e) => Err(e),
+                })
+            }
+            ("invoker_user", &[]) => match self.ctx.get_current_user() {
+                // the name of the user who runs the query, which can be compared with
+                // the user names stored in tables.
+                Ok(user) => Some(
+                    self.resolve(&Expr::Literal {
+                        span,
+                        lit: Literal::String(user.name),
+                    })
+                    .await,
+                ),
+                Err(e) => Some(Err(e)),
+            },
             ("connection_id", &[]) => Some(
                 self.resolve(&Expr::Literal {
                     span,
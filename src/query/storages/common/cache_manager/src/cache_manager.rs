@@ -34,6 +34,7 @@ use crate::caches::BloomIndexMetaCache;
 use crate::caches::ColumnArrayCache;
 use crate::caches::CompactSegmentInfoCache;
 use crate::caches::FileMetaDataCache;
+use crate::caches::SegmentStatsIndexCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 use crate::BloomIndexFilterMeter;
@@ -47,6 +48,7 @@ static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
 pub struct CacheManager {
     table_snapshot_cache: Option<TableSnapshotCache>,
     table_statistic_cache: Option<TableSnapshotStatisticCache>,
+    segment_stats_index_cache: Option<SegmentStatsIndexCache>,
     segment_info_cache: Option<CompactSegmentInfoCache>,
     bloom_index_filter_cache: Option<BloomIndexFilterCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
@@ -110,6 +112,7 @@ impl CacheManager {
                 prune_partitions_cache: None,
                 file_meta_data_cache: None,
                 table_statistic_cache: None,
+                segment_stats_index_cache: None,
                 table_data_cache,
                 table_column_array_cache,
             }));
@@ -118,6 +121,8 @@ impl CacheManager {
                 Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
             let table_statistic_cache =
                 Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
+            let segment_stats_index_cache =
+                Self::new_item_cache(config.table_meta_statistic_count, "segment_stats_index");
             let segment_info_cache = Self::new_in_memory_cache(
                 config.table_meta_segment_bytes,
                 CompactSegmentInfoMeter {},
@@ -145,6 +150,7 @@ impl CacheManager {
                 prune_partitions_cache,
                 file_meta_data_cache,
                 table_statistic_cache,
+                segment_stats_index_cache,
                 table_data_cache,
                 table_column_array_cache,
             }));
@@ -165,6 +171,10 @@ impl CacheManager {
         self.table_statistic_cache.clone()
     }
 
+    pub fn get_segment_stats_index_cache(&self) -> Option<SegmentStatsIndexCache> {
+        self.segment_stats_index_cache.clone()
+    }
+
     pub fn get_table_segment_cache(&self) -> Option<CompactSegmentInfoCache> {
         self.segment_info_cache.clone()
     }
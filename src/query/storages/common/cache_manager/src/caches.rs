@@ -30,6 +30,7 @@ use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SegmentStatsIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
@@ -44,6 +45,8 @@ pub type CompactSegmentInfoCache = NamedCache<
 pub type TableSnapshotCache = NamedCache<InMemoryItemCacheHolder<TableSnapshot>>;
 /// In memory object cache of TableSnapshotStatistics
 pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableSnapshotStatistics>>;
+/// In memory object cache of SegmentStatsIndex
+pub type SegmentStatsIndexCache = NamedCache<InMemoryItemCacheHolder<SegmentStatsIndex>>;
 /// In memory object cache of bloom filter.
 /// For each indexed data block, the bloom xor8 filter of column is cached individually
 pub type BloomIndexFilterCache =
@@ -108,6 +111,13 @@ impl CachedObject<TableSnapshotStatistics> for TableSnapshotStatistics {
     }
 }
 
+impl CachedObject<SegmentStatsIndex> for SegmentStatsIndex {
+    type Cache = SegmentStatsIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_segment_stats_index_cache()
+    }
+}
+
 impl CachedObject<BloomIndexMeta> for BloomIndexMeta {
     type Cache = BloomIndexMetaCache;
     fn cache() -> Option<Self::Cache> {
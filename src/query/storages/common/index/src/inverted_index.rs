@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::ScalarRef;
+use common_functions::scalars::tokenize;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Inverted index of the string columns in one block.
+///
+/// For every indexed column, maps each term to the sorted offsets of the rows containing it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    pub num_rows: u32,
+    pub postings: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>>,
+}
+
+impl InvertedIndex {
+    /// Build the index of the given columns, `columns` are pairs of column id and
+    /// the offset of that column in the `block`.
+    pub fn try_create(block: &DataBlock, columns: &[(ColumnId, FieldIndex)]) -> Result<Self> {
+        let num_rows = block.num_rows();
+        let mut postings = BTreeMap::new();
+        for (column_id, offset) in columns {
+            let entry = block.get_by_offset(*offset);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+
+            let mut terms: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+            for (row, value) in column.iter().enumerate() {
+                let text = match value {
+                    ScalarRef::String(text) => String::from_utf8_lossy(text),
+                    ScalarRef::Null => continue,
+                    other => {
+                        return Err(ErrorCode::Internal(format!(
+                            "Inverted index only support String column, but got {:?}",
+                            other
+                        )));
+                    }
+                };
+                for term in tokenize(&text) {
+                    let rows = terms.entry(term).or_default();
+                    if rows.last() != Some(&(row as u32)) {
+                        rows.push(row as u32);
+                    }
+                }
+            }
+            postings.insert(*column_id, terms);
+        }
+
+        Ok(Self {
+            num_rows: num_rows as u32,
+            postings,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to serialize inverted index: {}", e))
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to deserialize inverted index: {}", e))
+        })
+    }
+
+    /// Returns the offsets of the rows containing all the terms of `query`.
+    ///
+    /// `None` means the column is not covered by this index.
+    pub fn search(&self, column_id: ColumnId, query: &str) -> Option<Vec<u32>> {
+        let terms = self.postings.get(&column_id)?;
+
+        let mut matched: Option<BTreeSet<u32>> = None;
+        for term in tokenize(query) {
+            let rows = match terms.get(&term) {
+                Some(rows) => rows,
+                None => return Some(vec![]),
+            };
+            matched = Some(match matched {
+                None => rows.iter().copied().collect(),
+                Some(matched) => rows
+                    .iter()
+                    .filter(|r| matched.contains(r))
+                    .copied()
+                    .collect(),
+            });
+        }
+        // An empty query matches nothing, the same as the `match` function.
+        Some(matched.map(|m| m.into_iter().collect()).unwrap_or_default())
+    }
+}
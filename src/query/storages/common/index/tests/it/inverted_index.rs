@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let block = DataBlock::new_from_columns(vec![
+        StringType::from_data(vec![
+            "connection timeout",
+            "Timeout, retry",
+            "ok",
+            "connection reset",
+        ]),
+        StringType::from_opt_data(vec![Some("a b"), None, Some("b c"), Some("")]),
+    ]);
+
+    let index = InvertedIndex::try_create(&block, &[(1, 0), (2, 1)])?;
+    assert_eq!(index.num_rows, 4);
+
+    assert_eq!(index.search(1, "timeout"), Some(vec![0, 1]));
+    assert_eq!(index.search(1, "CONNECTION timeout"), Some(vec![0]));
+    assert_eq!(index.search(1, "connection ok"), Some(vec![]));
+    assert_eq!(index.search(1, "missing"), Some(vec![]));
+    assert_eq!(index.search(1, ""), Some(vec![]));
+    assert_eq!(index.search(2, "b"), Some(vec![0, 2]));
+    assert_eq!(index.search(3, "b"), None);
+
+    let bytes = index.to_bytes()?;
+    assert_eq!(InvertedIndex::from_bytes(&bytes)?, index);
+
+    Ok(())
+}
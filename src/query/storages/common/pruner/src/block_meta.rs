@@ -36,6 +36,8 @@ pub struct BlockMetaIndex {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    /// The offsets of the rows matched by inverted indexes, `None` means all rows.
+    pub matched_rows: Option<Vec<usize>>,
 }
 
 #[typetag::serde(name = "block_meta_index")]
@@ -202,6 +202,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        bucket_id: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -21,6 +21,8 @@ pub use v2::ColumnStatistics;
 pub use v2::Statistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
+pub use v4::SegmentStats;
+pub use v4::SegmentStatsIndex;
 pub use v4::TableSnapshot;
 pub use v4::TableSnapshotLite;
 
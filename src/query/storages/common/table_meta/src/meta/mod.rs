@@ -41,6 +41,7 @@ pub(crate) use testing::*;
 pub(crate) use utils::*;
 pub use versions::testify_version;
 pub use versions::SegmentInfoVersion;
+pub use versions::SegmentStatsIndexVersion;
 pub use versions::SnapshotVersion;
 pub use versions::TableSnapshotStatisticsVersion;
 pub use versions::Versioned;
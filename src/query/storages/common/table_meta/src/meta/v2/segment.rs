@@ -80,6 +80,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// The bucket that all the rows of this block belong to, only set for bucketed tables.
+    #[serde(default)]
+    pub bucket_id: Option<u64>,
 }
 
 impl BlockMeta {
@@ -109,6 +113,7 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            bucket_id: None,
         }
     }
 
@@ -254,6 +259,7 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            bucket_id: None,
         }
     }
 
@@ -285,6 +291,7 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            bucket_id: None,
         }
     }
 }
@@ -62,6 +62,7 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
             create_on: None,
+            bucket_id: None,
         }
     }
 }
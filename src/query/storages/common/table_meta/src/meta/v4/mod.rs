@@ -13,9 +13,12 @@
 // limitations under the License.
 
 mod segment;
+mod segment_stats_index;
 mod snapshot;
 
 pub use segment::CompactSegmentInfo;
 pub use segment::SegmentInfo;
+pub use segment_stats_index::SegmentStats;
+pub use segment_stats_index::SegmentStatsIndex;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
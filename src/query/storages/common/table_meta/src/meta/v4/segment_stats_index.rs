@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::format::compress;
+use crate::meta::format::encode;
+use crate::meta::format::read_and_deserialize;
+use crate::meta::format::MetaCompression;
+use crate::meta::FormatVersion;
+use crate::meta::MetaEncoding;
+use crate::meta::SnapshotId;
+use crate::meta::StatisticsOfColumns;
+use crate::meta::Versioned;
+
+/// The summary statistics of a segment kept in the [`SegmentStatsIndex`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SegmentStats {
+    pub uncompressed_byte_size: u64,
+    pub col_stats: StatisticsOfColumns,
+}
+
+/// Consolidated segment level statistics of a snapshot.
+///
+/// The entries are keyed by the (immutable) segment locations, so an index can only tell the
+/// truth about the segments it knows. Segments that are missing from the index must be
+/// checked by reading the segment itself.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SegmentStatsIndex {
+    /// format version of the index
+    pub format_version: FormatVersion,
+
+    /// id of the snapshot which this index is written for
+    pub snapshot_id: SnapshotId,
+
+    pub segments: HashMap<String, SegmentStats>,
+}
+
+impl SegmentStatsIndex {
+    pub fn new(snapshot_id: SnapshotId, segments: HashMap<String, SegmentStats>) -> Self {
+        Self {
+            format_version: SegmentStatsIndex::VERSION,
+            snapshot_id,
+            segments,
+        }
+    }
+
+    pub fn get(&self, segment_location: &str) -> Option<&SegmentStats> {
+        self.segments.get(segment_location)
+    }
+
+    /// Serializes the index in the same layout as [`crate::meta::TableSnapshot`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let encoding = MetaEncoding::MessagePack;
+        let compression = MetaCompression::default();
+
+        let data = encode(&encoding, &self)?;
+        let data_compress = compress(&compression, data)?;
+
+        let data_size = self.format_version.to_le_bytes().len()
+            + 2
+            + data_compress.len().to_le_bytes().len()
+            + data_compress.len();
+        let mut buf = Vec::with_capacity(data_size);
+
+        buf.extend_from_slice(&self.format_version.to_le_bytes());
+        buf.push(encoding as u8);
+        buf.push(compression as u8);
+        buf.extend_from_slice(&data_compress.len().to_le_bytes());
+
+        buf.extend(data_compress);
+
+        Ok(buf)
+    }
+
+    pub fn from_slice(buffer: &[u8]) -> Result<SegmentStatsIndex> {
+        let mut cursor = Cursor::new(buffer);
+        let version = cursor.read_scalar::<u64>()?;
+        assert_eq!(version, SegmentStatsIndex::VERSION);
+        let encoding = MetaEncoding::try_from(cursor.read_scalar::<u8>()?)?;
+        let compression = MetaCompression::try_from(cursor.read_scalar::<u8>()?)?;
+        let index_size: u64 = cursor.read_scalar::<u64>()?;
+
+        read_and_deserialize(&mut cursor, index_size, &encoding, &compression)
+    }
+}
@@ -83,6 +83,12 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// Location of the consolidated segment statistics, see [`crate::meta::SegmentStatsIndex`].
+    ///
+    /// None if the index has not been written, e.g. snapshots written by older versions.
+    #[serde(default)]
+    pub segment_stats_index_location: Option<String>,
 }
 
 impl TableSnapshot {
@@ -114,6 +120,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            segment_stats_index_location: None,
         }
     }
 
@@ -134,7 +141,7 @@ impl TableSnapshot {
         let id = Uuid::new_v4();
         let clone = previous.clone();
         // the timestamp of the new snapshot will be adjusted by the `new` method
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -143,7 +150,10 @@ impl TableSnapshot {
             clone.segments,
             clone.cluster_key_meta,
             clone.table_statistics_location,
-        )
+        );
+        // the segment statistics index is keyed by segment locations, it is still valid
+        snapshot.segment_stats_index_location = clone.segment_stats_index_location;
+        snapshot
     }
 
     /// Serializes the struct to a byte vector.
@@ -221,6 +231,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            segment_stats_index_location: None,
         }
     }
 }
@@ -242,6 +253,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            segment_stats_index_location: None,
         }
     }
 }
@@ -111,6 +111,8 @@ impl SnapshotVersion {
 
 impl Versioned<0> for v1::TableSnapshotStatistics {}
 
+impl Versioned<0> for v4::SegmentStatsIndex {}
+
 impl Versioned<2> for DataBlock {}
 
 pub enum TableSnapshotStatisticsVersion {
@@ -129,6 +131,22 @@ impl TableSnapshotStatisticsVersion {
     }
 }
 
+pub enum SegmentStatsIndexVersion {
+    V0(PhantomData<v4::SegmentStatsIndex>),
+}
+
+impl SegmentStatsIndexVersion {
+    pub fn version(&self) -> u64 {
+        match self {
+            SegmentStatsIndexVersion::V0(a) => Self::ver(a),
+        }
+    }
+
+    fn ver<const V: u64, T: Versioned<V>>(_v: &PhantomData<T>) -> u64 {
+        V
+    }
+}
+
 /// Statically check that if T implements Versioned<U> where U equals V
 #[inline]
 pub fn testify_version<T, const V: u64>(t: PhantomData<T>) -> PhantomData<T>
@@ -189,4 +207,18 @@ mod converters {
             }
         }
     }
+
+    impl TryFrom<u64> for SegmentStatsIndexVersion {
+        type Error = ErrorCode;
+        fn try_from(value: u64) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(SegmentStatsIndexVersion::V0(testify_version::<_, 0>(
+                    PhantomData,
+                ))),
+                _ => Err(ErrorCode::Internal(format!(
+                    "unknown segment statistics index version {value}, versions supported: 0"
+                ))),
+            }
+        }
+    }
 }
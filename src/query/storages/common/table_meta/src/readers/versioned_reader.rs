@@ -17,6 +17,8 @@ use futures::AsyncRead;
 use futures_util::AsyncReadExt;
 
 use crate::meta::load_json;
+use crate::meta::SegmentStatsIndex;
+use crate::meta::SegmentStatsIndexVersion;
 use crate::meta::TableSnapshotStatistics;
 use crate::meta::TableSnapshotStatisticsVersion;
 
@@ -41,3 +43,18 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
         Ok(r)
     }
 }
+
+#[async_trait::async_trait]
+impl VersionedReader<SegmentStatsIndex> for SegmentStatsIndexVersion {
+    type TargetType = SegmentStatsIndex;
+    #[async_backtrace::framed]
+    async fn read<R>(&self, mut reader: R) -> Result<SegmentStatsIndex>
+    where R: AsyncRead + Unpin + Send {
+        let mut buffer: Vec<u8> = vec![];
+        reader.read_to_end(&mut buffer).await?;
+        let r = match self {
+            SegmentStatsIndexVersion::V0(_) => SegmentStatsIndex::from_slice(&buffer)?,
+        };
+        Ok(r)
+    }
+}
@@ -24,6 +24,8 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+// Set to false to exclude the table from background compaction.
+pub const OPT_KEY_BACKGROUND_COMPACTION: &str = "background_compaction";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -32,6 +34,9 @@ pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
 pub const OPT_KEY_LOCATION: &str = "location";
 pub const OPT_KEY_CONNECTION_NAME: &str = "connection_name";
+// External volume options, the storage params of the table are resolved through the volume.
+pub const OPT_KEY_EXTERNAL_VOLUME: &str = "external_volume";
+pub const OPT_KEY_EXTERNAL_VOLUME_PATH: &str = "external_volume_path";
 
 /// Legacy table snapshot location key
 ///
@@ -50,6 +55,8 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_EXTERNAL_VOLUME);
+    r.insert(OPT_KEY_EXTERNAL_VOLUME_PATH);
     r
 });
 
@@ -45,6 +45,7 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    pub bucket_id: Option<u64>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -64,6 +65,10 @@ impl PartInfo for FusePartInfo {
         self.location.hash(&mut s);
         s.finish()
     }
+
+    fn bucket_id(&self) -> Option<u64> {
+        self.bucket_id
+    }
 }
 
 impl FusePartInfo {
@@ -75,6 +80,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        bucket_id: Option<u64>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -84,6 +90,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            bucket_id,
         }))
     }
 
@@ -78,6 +78,7 @@ use uuid::Uuid;
 use crate::fuse_column::FuseTableColumnStatisticsProvider;
 use crate::fuse_type::FuseTableType;
 use crate::io::MetaReaders;
+use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
 use crate::table_functions::unwrap_tuple;
@@ -108,6 +109,8 @@ pub struct FuseTable {
     pub(crate) data_metrics: Arc<StorageMetrics>,
 
     table_type: FuseTableType,
+    // the snapshot location resolved once for a query, see `pin_snapshot`.
+    pinned_snapshot_loc: Option<String>,
 }
 
 impl FuseTable {
@@ -193,6 +196,7 @@ impl FuseTable {
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
             table_compression: table_compression.as_str().try_into()?,
             table_type,
+            pinned_snapshot_loc: None,
         }))
     }
 
@@ -324,8 +328,45 @@ impl FuseTable {
         }))
     }
 
+    /// Returns the table with its snapshot location resolved, so that all the scans
+    /// of the returned table read the same snapshot.
+    ///
+    /// Only the tables whose snapshot location is read from the hint file need to be
+    /// pinned, `None` is returned for the others.
+    #[async_backtrace::framed]
+    pub async fn pin_snapshot(&self) -> Result<Option<Arc<FuseTable>>> {
+        if self.pinned_snapshot_loc.is_some() || !self.is_snapshot_loc_from_hint() {
+            return Ok(None);
+        }
+        let Some(loc) = self.latest_snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let mut table = self.clone();
+        table.pinned_snapshot_loc = Some(loc);
+        Ok(Some(Arc::new(table)))
+    }
+
+    pub fn is_snapshot_loc_from_hint(&self) -> bool {
+        match self.table_info.db_type {
+            DatabaseType::ShareDB(_) => true,
+            DatabaseType::NormalDB => self
+                .table_info
+                .options()
+                .contains_key(OPT_KEY_TABLE_ATTACHED_READ_ONLY),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn snapshot_loc(&self) -> Result<Option<String>> {
+        if let Some(loc) = &self.pinned_snapshot_loc {
+            return Ok(Some(loc.clone()));
+        }
+        self.latest_snapshot_loc().await
+    }
+
+    // Resolves the snapshot location, ignoring the pinned one.
+    #[async_backtrace::framed]
+    async fn latest_snapshot_loc(&self) -> Result<Option<String>> {
         match self.table_info.db_type {
             DatabaseType::ShareDB(_) => {
                 let url = FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -353,7 +394,13 @@ impl FuseTable {
                         let operator_info = self.operator.info();
                         snapshot_full_path[operator_info.root().len()..].to_string()
                     };
-                    Ok(Some(snapshot_loc))
+                    match options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+                        Some(meta_loc) if meta_loc != &snapshot_loc => Ok(Some(
+                            self.newer_snapshot_loc(snapshot_loc, meta_loc.clone())
+                                .await,
+                        )),
+                        _ => Ok(Some(snapshot_loc)),
+                    }
                 } else {
                     Ok(options
                         .get(OPT_KEY_SNAPSHOT_LOCATION)
@@ -365,6 +412,37 @@ impl FuseTable {
         }
     }
 
+    // The hint might be stale if writing it failed after a commit, validates it against
+    // the snapshot location of the table meta and prefers the newer one.
+    #[async_backtrace::framed]
+    async fn newer_snapshot_loc(&self, hint_loc: String, meta_loc: String) -> String {
+        let read_timestamp = |loc: String| async move {
+            SnapshotsIO::read_snapshot(loc, self.operator.clone())
+                .await
+                .map(|(snapshot, _)| snapshot.timestamp)
+        };
+        match (
+            read_timestamp(hint_loc.clone()).await,
+            read_timestamp(meta_loc.clone()).await,
+        ) {
+            (Ok(hint_ts), Ok(meta_ts)) if meta_ts > hint_ts => {
+                warn!(
+                    "the last snapshot hint {} of table {} is older than the snapshot {} of the table meta, use the latter",
+                    hint_loc, self.table_info.desc, meta_loc
+                );
+                meta_loc
+            }
+            (Ok(_), _) => hint_loc,
+            (Err(e), _) => {
+                warn!(
+                    "read the snapshot {} of the last snapshot hint of table {} failed, use the snapshot {} of the table meta. {}",
+                    hint_loc, self.table_info.desc, meta_loc, e
+                );
+                meta_loc
+            }
+        }
+    }
+
     pub fn get_operator(&self) -> Operator {
         self.operator.clone()
     }
@@ -511,13 +589,16 @@ impl Table for FuseTable {
         let prev_statistics_location = prev
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
+        let prev_segment_stats_index_location = prev
+            .as_ref()
+            .and_then(|v| v.segment_stats_index_location.clone());
         let (summary, segments) = if let Some(v) = prev {
             (v.summary.clone(), v.segments.clone())
         } else {
             (FuseStatistics::default(), vec![])
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -527,6 +608,7 @@ impl Table for FuseTable {
             cluster_key_meta,
             prev_statistics_location,
         );
+        new_snapshot.segment_stats_index_location = prev_segment_stats_index_location;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -561,6 +643,9 @@ impl Table for FuseTable {
         let prev_statistics_location = prev
             .as_ref()
             .and_then(|v| v.table_statistics_location.clone());
+        let prev_segment_stats_index_location = prev
+            .as_ref()
+            .and_then(|v| v.segment_stats_index_location.clone());
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
         let (summary, segments) = if let Some(v) = prev {
             (v.summary.clone(), v.segments.clone())
@@ -568,7 +653,7 @@ impl Table for FuseTable {
             (FuseStatistics::default(), vec![])
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -578,6 +663,7 @@ impl Table for FuseTable {
             None,
             prev_statistics_location,
         );
+        new_snapshot.segment_stats_index_location = prev_segment_stats_index_location;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
@@ -742,7 +828,8 @@ impl Table for FuseTable {
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
-        let snapshot_location = if let Some(loc) = self.snapshot_loc().await? {
+        // explicit time travel is not bound to the snapshot pinned for the query.
+        let snapshot_location = if let Some(loc) = self.latest_snapshot_loc().await? {
             loc
         } else {
             // not an error?
@@ -758,17 +845,26 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => Ok(self
                 .navigate_to_time_point(snapshot_location, *time_point)
                 .await?),
+            NavigationPoint::StreamInfo(stream_info) => Ok(self
+                .navigate_to_stream(snapshot_location, stream_info)
+                .await?),
         }
     }
 
-    fn get_block_thresholds(&self) -> BlockThresholds {
-        let max_rows_per_block =
-            self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, DEFAULT_BLOCK_MAX_ROWS);
+    fn get_block_thresholds(&self, ctx: Arc<dyn TableContext>) -> BlockThresholds {
+        // The table options take precedence over the settings.
+        let settings = ctx.get_settings();
+        let default_max_rows = settings
+            .get_max_rows_per_block()
+            .map_or(DEFAULT_BLOCK_MAX_ROWS, |v| v as usize);
+        let default_max_bytes = settings
+            .get_max_bytes_per_block()
+            .map_or(DEFAULT_BLOCK_BUFFER_SIZE, |v| v as usize);
+
+        let max_rows_per_block = self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, default_max_rows);
         let min_rows_per_block = (max_rows_per_block as f64 * 0.8) as usize;
-        let max_bytes_per_block = self.get_option(
-            FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
-            DEFAULT_BLOCK_BUFFER_SIZE,
-        );
+        let max_bytes_per_block =
+            self.get_option(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD, default_max_bytes);
         BlockThresholds::new(max_rows_per_block, min_rows_per_block, max_bytes_per_block)
     }
 
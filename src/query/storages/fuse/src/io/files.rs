@@ -13,13 +13,61 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::runtime::execute_futures_in_parallel;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use futures::StreamExt;
 use log::info;
+use log::warn;
 use opendal::Operator;
 
+// Max attempts of deleting a batch or an object, transient failures are retried.
+const DELETE_MAX_ATTEMPTS: u32 = 3;
+// Max number of the failed paths kept in a `DeletionReport`.
+const MAX_FAILED_SAMPLES: usize = 10;
+// Max number of the concurrent per-object requests of a batch.
+const OBJECT_REQUEST_CONCURRENCY: usize = 16;
+
+/// The outcome of deleting a set of files.
+#[derive(Clone, Debug, Default)]
+pub struct DeletionReport {
+    pub deleted: usize,
+    pub failed: usize,
+    /// Samples of the failed paths with their error messages.
+    pub failed_samples: Vec<(String, String)>,
+}
+
+impl DeletionReport {
+    pub fn merge(&mut self, other: DeletionReport) {
+        self.deleted += other.deleted;
+        self.failed += other.failed;
+        let remain = MAX_FAILED_SAMPLES.saturating_sub(self.failed_samples.len());
+        self.failed_samples
+            .extend(other.failed_samples.into_iter().take(remain));
+    }
+
+    fn add_failure(&mut self, path: String, error: String) {
+        self.failed += 1;
+        if self.failed_samples.len() < MAX_FAILED_SAMPLES {
+            self.failed_samples.push((path, error));
+        }
+    }
+
+    /// Returns an error if any of the files failed to be deleted.
+    pub fn check(&self) -> Result<()> {
+        match self.failed_samples.first() {
+            Some((path, error)) => Err(ErrorCode::StorageOther(format!(
+                "failed to remove {} files, e.g. {}: {}",
+                self.failed, path, error
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
 // File related operations.
 pub struct Files {
     ctx: Arc<dyn TableContext>,
@@ -79,4 +127,166 @@ impl Files {
         op.remove(locations).await?;
         Ok(())
     }
+
+    /// Removes a batch of files like [`Files::remove_file_in_batch`], but tracks the
+    /// outcome of every file instead of failing on the first error.
+    ///
+    /// If `verify` is true, the files are checked to be absent after the deletion.
+    #[async_backtrace::framed]
+    pub async fn remove_file_in_batch_with_report(
+        &self,
+        file_locations: impl IntoIterator<Item = impl AsRef<str>>,
+        verify: bool,
+    ) -> Result<DeletionReport> {
+        let batch_size = 1000;
+        let locations = Vec::from_iter(file_locations.into_iter().map(|v| v.as_ref().to_string()));
+
+        let mut report = DeletionReport::default();
+        if locations.len() <= batch_size {
+            report.merge(
+                Self::delete_files_with_report(self.operator.clone(), locations, verify).await,
+            );
+        } else {
+            let mut chunks = locations.chunks(batch_size);
+
+            let tasks = std::iter::from_fn(move || {
+                chunks.next().map(|location| {
+                    Self::delete_files_with_report(self.operator.clone(), location.to_vec(), verify)
+                })
+            });
+
+            let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
+
+            let reports = execute_futures_in_parallel(
+                tasks,
+                threads_nums,
+                threads_nums * 2,
+                "batch-remove-files-worker".to_owned(),
+            )
+            .await?;
+            for r in reports {
+                report.merge(r);
+            }
+        }
+
+        Ok(report)
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_files_with_report(
+        op: Operator,
+        locations: Vec<String>,
+        verify: bool,
+    ) -> DeletionReport {
+        let locations = locations
+            .into_iter()
+            .map(|loc| loc.trim_start_matches('/').to_owned())
+            .filter(|loc| !loc.is_empty())
+            .collect::<Vec<_>>();
+        info!("deleting files: {:?}", &locations);
+
+        // The batch delete may succeed while some of the objects are not removed,
+        // or fail as a whole without telling which objects are left. In both cases,
+        // the remaining objects are deleted one by one to find out the outcome of each.
+        let removed = Self::retry(|| op.remove(locations.clone())).await;
+        let (mut report, remaining) = match removed {
+            Ok(_) if !verify => {
+                return DeletionReport {
+                    deleted: locations.len(),
+                    ..Default::default()
+                };
+            }
+            Ok(_) => {
+                let mut report = DeletionReport::default();
+                let mut remaining = vec![];
+                let checks = futures::stream::iter(locations)
+                    .map(|loc| {
+                        let op = op.clone();
+                        async move {
+                            let exists = op.is_exist(&loc).await;
+                            (loc, exists)
+                        }
+                    })
+                    .buffer_unordered(OBJECT_REQUEST_CONCURRENCY)
+                    .collect::<Vec<_>>()
+                    .await;
+                for (loc, exists) in checks {
+                    match exists {
+                        Ok(false) => report.deleted += 1,
+                        Ok(true) => remaining.push(loc),
+                        Err(e) => report.add_failure(loc, e.to_string()),
+                    }
+                }
+                if remaining.is_empty() {
+                    return report;
+                }
+                (report, remaining)
+            }
+            Err(e) => {
+                warn!(
+                    "batch delete of {} files failed, fall back to delete them one by one: {}",
+                    locations.len(),
+                    e
+                );
+                (DeletionReport::default(), locations)
+            }
+        };
+
+        let outcomes = futures::stream::iter(remaining)
+            .map(|loc| {
+                let op = op.clone();
+                async move {
+                    let outcome = Self::delete_object(&op, &loc, verify).await;
+                    (loc, outcome)
+                }
+            })
+            .buffer_unordered(OBJECT_REQUEST_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+        for (loc, outcome) in outcomes {
+            match outcome {
+                Ok(_) => report.deleted += 1,
+                Err(e) => report.add_failure(loc, e),
+            }
+        }
+        report
+    }
+
+    async fn delete_object(
+        op: &Operator,
+        location: &str,
+        verify: bool,
+    ) -> std::result::Result<(), String> {
+        Self::retry(|| op.delete(location))
+            .await
+            .map_err(|e| e.to_string())?;
+        if verify {
+            match op.is_exist(location).await {
+                Ok(false) => {}
+                Ok(true) => return Err("object still exists after deletion".to_string()),
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        Ok(())
+    }
+
+    // Retries the operation on transient failures, up to `DELETE_MAX_ATTEMPTS` attempts.
+    async fn retry<F, Fut>(f: F) -> opendal::Result<()>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = opendal::Result<()>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Err(e) if e.is_temporary() && attempt < DELETE_MAX_ATTEMPTS => {
+                    warn!("delete failed, attempt {}: {}", attempt, e);
+                    let delay = Duration::from_millis(100 * 2u64.pow(attempt));
+                    common_base::base::tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
 }
@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SegmentStatsIndexVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
 use storages_common_table_meta::meta::TableSnapshotStatisticsVersion;
 use storages_common_table_meta::meta::Versioned;
@@ -25,11 +26,13 @@ use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
+use crate::constants::FUSE_TBL_SEGMENT_STATS_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -42,6 +45,8 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
 
+static SEGMENT_STATS_INDEX_V0: SegmentStatsIndexVersion = SegmentStatsIndexVersion::V0(PhantomData);
+
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
     prefix: String,
@@ -139,6 +144,19 @@ impl TableMetaLocationGenerator {
         SNAPSHOT_STATISTICS_V0.version()
     }
 
+    pub fn segment_stats_index_location_from_uuid(
+        &self,
+        id: &Uuid,
+        version: u64,
+    ) -> Result<String> {
+        let index_version = SegmentStatsIndexVersion::try_from(version)?;
+        Ok(index_version.create(id, &self.prefix))
+    }
+
+    pub fn segment_stats_index_version(_location: impl AsRef<str>) -> u64 {
+        SEGMENT_STATS_INDEX_V0.version()
+    }
+
     pub fn gen_last_snapshot_hint_location(&self) -> String {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
@@ -154,6 +172,15 @@ impl TableMetaLocationGenerator {
         let block_name = splits[len - 1];
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
+
+    pub fn gen_inverted_index_location_from_block_location(loc: &str, version: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        let id = block_name.split('.').next().unwrap_or(block_name);
+        format!("{prefix}/{FUSE_TBL_INVERTED_INDEX_PREFIX}/{version}/{id}.idx")
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -201,3 +228,21 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
         }
     }
 }
+
+impl SnapshotLocationCreator for SegmentStatsIndexVersion {
+    fn create(&self, id: &Uuid, prefix: impl AsRef<str>) -> String {
+        format!(
+            "{}/{}/{}{}",
+            prefix.as_ref(),
+            FUSE_TBL_SEGMENT_STATS_INDEX_PREFIX,
+            id.simple(),
+            self.suffix(),
+        )
+    }
+
+    fn suffix(&self) -> String {
+        match self {
+            SegmentStatsIndexVersion::V0(_) => "_si_v0.mpk".to_string(),
+        }
+    }
+}
@@ -19,6 +19,7 @@ mod segments;
 mod snapshots;
 mod write;
 
+pub use files::DeletionReport;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::AggIndexReader;
@@ -44,6 +45,8 @@ pub use write::write_data;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
+pub use write::InvertedIndexBuilder;
+pub use write::InvertedIndexState;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
 pub use write::WriteSettings;
@@ -32,6 +32,8 @@ use storages_common_cache_manager::CompactSegmentInfoMeter;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfoVersion;
+use storages_common_table_meta::meta::SegmentStatsIndex;
+use storages_common_table_meta::meta::SegmentStatsIndexVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -42,6 +44,8 @@ use self::thrift_file_meta_read::read_thrift_file_metadata;
 
 pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
+pub type SegmentStatsIndexReader =
+    InMemoryItemCacheReader<SegmentStatsIndex, LoaderWrapper<Operator>>;
 pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
 pub type CompactSegmentInfoReader = InMemoryItemCacheReader<
@@ -75,6 +79,13 @@ impl MetaReaders {
         )
     }
 
+    pub fn segment_stats_index_reader(dal: Operator) -> SegmentStatsIndexReader {
+        SegmentStatsIndexReader::new(
+            CacheManager::instance().get_segment_stats_index_cache(),
+            LoaderWrapper(dal),
+        )
+    }
+
     pub fn bloom_index_meta_reader(dal: Operator) -> BloomIndexMetaReader {
         BloomIndexMetaReader::new(
             CacheManager::instance().get_bloom_index_meta_cache(),
@@ -107,6 +118,16 @@ impl Loader<TableSnapshotStatistics> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<SegmentStatsIndex> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<SegmentStatsIndex> {
+        let version = SegmentStatsIndexVersion::try_from(params.ver)?;
+        let reader = bytes_reader(&self.0, params.location.as_str(), params.len_hint).await?;
+        version.read(reader).await
+    }
+}
+
 #[async_trait::async_trait]
 impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef)> {
     #[async_backtrace::framed]
@@ -51,6 +51,7 @@ pub struct SnapshotLiteExtended {
     pub timestamp: Option<DateTime<Utc>>,
     pub segments: HashSet<Location>,
     pub table_statistics_location: Option<String>,
+    pub segment_stats_index_location: Option<String>,
 }
 
 // Read snapshot related operations.
@@ -278,6 +279,13 @@ impl SnapshotsIO {
             } else {
                 None
             };
+        let segment_stats_index_location = if snapshot.segment_stats_index_location
+            != root_snapshot.segment_stats_index_location
+        {
+            snapshot.segment_stats_index_location.clone()
+        } else {
+            None
+        };
 
         Ok(SnapshotLiteExtended {
             format_version: ver,
@@ -285,6 +293,7 @@ impl SnapshotsIO {
             timestamp: snapshot.timestamp,
             segments,
             table_statistics_location,
+            segment_stats_index_location,
         })
     }
 
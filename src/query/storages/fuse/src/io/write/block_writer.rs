@@ -25,12 +25,15 @@ use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use common_meta_app::schema::TableIndex;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -39,6 +42,7 @@ use storages_common_table_meta::table::TableCompression;
 
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::BucketHasher;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
@@ -155,6 +159,7 @@ pub struct BlockSerialization {
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_states: Vec<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,6 +170,66 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub bucket_hasher: Option<BucketHasher>,
+}
+
+/// The columns of an inverted index, resolved against the schema of the blocks to write.
+#[derive(Clone)]
+pub struct InvertedIndexBuilder {
+    pub version: String,
+    pub columns: Vec<(ColumnId, FieldIndex)>,
+}
+
+impl InvertedIndexBuilder {
+    pub fn create_builders(
+        indexes: &BTreeMap<String, TableIndex>,
+        schema: &TableSchema,
+    ) -> Vec<InvertedIndexBuilder> {
+        indexes
+            .values()
+            .map(|index| {
+                let columns = index
+                    .column_ids
+                    .iter()
+                    .filter_map(|column_id| {
+                        schema
+                            .fields()
+                            .iter()
+                            .position(|f| f.column_id() == *column_id)
+                            .map(|offset| (*column_id, offset))
+                    })
+                    .collect();
+                InvertedIndexBuilder {
+                    version: index.version.clone(),
+                    columns,
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        block_location: &str,
+        builder: &InvertedIndexBuilder,
+    ) -> Result<Self> {
+        let index = InvertedIndex::try_create(block, &builder.columns)?;
+        let location = TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            block_location,
+            &builder.version,
+        );
+        Ok(Self {
+            data: index.to_bytes()?,
+            location,
+        })
+    }
 }
 
 impl BlockBuilder {
@@ -185,6 +250,17 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let inverted_index_states = self
+            .inverted_index_builders
+            .iter()
+            .map(|builder| InvertedIndexState::try_create(&data_block, &block_location.0, builder))
+            .collect::<Result<Vec<_>>>()?;
+
+        let bucket_id = match &self.bucket_hasher {
+            Some(hasher) => hasher.block_bucket_id(&data_block)?,
+            None => None,
+        };
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -213,6 +289,7 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            bucket_id,
         };
 
         let serialized = BlockSerialization {
@@ -220,6 +297,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_states,
         };
         Ok(serialized)
     }
@@ -20,6 +20,7 @@ use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SegmentStatsIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
@@ -92,6 +93,14 @@ impl Marshal for TableSnapshot {
     }
 }
 
+impl Marshal for SegmentStatsIndex {
+    fn marshal(&self) -> Result<Vec<u8>> {
+        // make sure the table meta we write down to object store always has the current version
+        assert_eq!(self.format_version, SegmentStatsIndex::VERSION);
+        self.to_bytes()
+    }
+}
+
 impl Marshal for TableSnapshotStatistics {
     fn marshal(&self) -> Result<Vec<u8>> {
         // make sure the table meta we write down to object store always has the current version
@@ -28,6 +28,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::create_dummy_items;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
 use common_pipeline_transforms::processors::BlockCompactor;
 use common_pipeline_transforms::processors::BlockCompactorForCopy;
 use common_pipeline_transforms::processors::TransformCompact;
@@ -36,6 +37,8 @@ use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::physical_plans::MutationKind;
 
+use crate::operations::common::BucketHasher;
+use crate::operations::common::TransformBucketScatter;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -47,7 +50,7 @@ impl FuseTable {
         pipeline: &mut Pipeline,
         append_mode: AppendMode,
     ) -> Result<()> {
-        let block_thresholds = self.get_block_thresholds();
+        let block_thresholds = self.get_block_thresholds(ctx.clone());
 
         match append_mode {
             AppendMode::Normal => {
@@ -72,6 +75,16 @@ impl FuseTable {
             }
         }
 
+        if let Some(hasher) = self.bucket_hasher(ctx.clone())? {
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    input,
+                    output,
+                    TransformBucketScatter::new(hasher.clone()),
+                )))
+            })?;
+        }
+
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, None)?;
         pipeline.add_transform(|input, output| {
@@ -108,6 +121,25 @@ impl FuseTable {
             output_lens - specified_mid_len - specified_last_len,
             output_lens,
         );
+        if let Some(hasher) = self.bucket_hasher(ctx.clone())? {
+            let mut builder = pipeline.add_transform_with_specified_len(
+                |input, output| {
+                    Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                        input,
+                        output,
+                        TransformBucketScatter::new(hasher.clone()),
+                    )))
+                },
+                specified_mid_len,
+            )?;
+            builder.add_items_prepend(create_dummy_items(
+                output_lens - specified_mid_len - specified_last_len,
+                output_lens,
+            ));
+            builder.add_items(create_dummy_items(specified_last_len, specified_last_len));
+            pipeline.add_pipe(builder.finalize());
+        }
+
         let operators = cluster_stats_gen.operators.clone();
         if !operators.is_empty() {
             let num_input_columns = self.table_info.schema().fields().len();
@@ -209,6 +241,20 @@ impl FuseTable {
         Ok(cluster_stats_gen)
     }
 
+    /// Returns the hasher of the bucket keys if the table is bucketed.
+    pub fn bucket_hasher(&self, ctx: Arc<dyn TableContext>) -> Result<Option<BucketHasher>> {
+        let Some(bucket_spec) = &self.table_info.meta.bucket_spec else {
+            return Ok(None);
+        };
+        // The blocks to write do not contain the virtual computed columns.
+        let schema = self.schema().remove_virtual_computed_fields();
+        Ok(Some(BucketHasher::try_create(
+            ctx.get_function_context()?,
+            bucket_spec,
+            &schema,
+        )?))
+    }
+
     pub fn get_cluster_stats_gen(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -24,6 +24,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_meta_app::schema::DeduplicatedLabel;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateStreamMetaReq;
@@ -59,7 +60,9 @@ use crate::operations::common::AbortOperation;
 use crate::operations::common::AppendGenerator;
 use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
+use crate::operations::common::DeduplicatedResult;
 use crate::operations::common::TableMutationAggregator;
+use crate::operations::common::TenantStorageUsage;
 use crate::operations::common::TransformSerializeSegment;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
@@ -75,7 +78,7 @@ impl FuseTable {
         overwrite: bool,
         prev_snapshot_id: Option<SnapshotId>,
     ) -> Result<()> {
-        let block_thresholds = self.get_block_thresholds();
+        let block_thresholds = self.get_block_thresholds(ctx.clone());
 
         pipeline.try_resize(1)?;
 
@@ -215,7 +218,7 @@ impl FuseTable {
             seq: MatchSeq::Exact(table_version),
             new_table_meta,
             copied_files: copied_files.clone(),
-            deduplicated_label: unsafe { ctx.get_settings().get_deduplicate_label()? },
+            deduplicated_label: Self::deduplicated_label(ctx)?,
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
@@ -223,6 +226,11 @@ impl FuseTable {
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
+                TenantStorageUsage::instance().apply_delta(
+                    &ctx.get_tenant(),
+                    table_info.meta.statistics.data_bytes,
+                    snapshot.summary.uncompressed_byte_size,
+                );
                 TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
@@ -256,12 +264,31 @@ impl FuseTable {
         // Just try our best to write down the hint file of last snapshot
         // - will retry in the case of temporary failure
         // but
-        // - errors are ignored if writing is eventually failed
+        // - errors are ignored if writing is eventually failed,
+        //   the stale hint can be repaired by `FUSE_REPAIR_HINT`
         // - errors (if any) will not be propagated to caller
         // - "data race" ignored
         //   if multiple different versions of hints are written concurrently
         //   it is NOT guaranteed that the latest version will be kept
+        if let Err(e) =
+            Self::try_write_last_snapshot_hint(operator, location_generator, &last_snapshot_path)
+                .await
+        {
+            metrics_inc_commit_last_snapshot_hint_failures();
+            warn!(
+                "write last snapshot hint of {} failure, the hint might be stale. {}",
+                last_snapshot_path, e
+            );
+        }
+    }
 
+    // Writes the hint file of the last snapshot, retried with backoff.
+    #[async_backtrace::framed]
+    pub async fn try_write_last_snapshot_hint(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        last_snapshot_path: &str,
+    ) -> Result<()> {
         let hint_path = location_generator.gen_last_snapshot_hint_location();
         let last_snapshot_path = {
             let operator_meta_data = operator.info();
@@ -269,12 +296,29 @@ impl FuseTable {
             format!("{}{}", storage_prefix, last_snapshot_path)
         };
 
-        operator
-            .write(&hint_path, last_snapshot_path)
-            .await
-            .unwrap_or_else(|e| {
-                warn!("write last snapshot hint failure. {}", e);
-            });
+        // The hint is written after the commit, keep the retries short.
+        let mut backoff = set_backoff(
+            None,
+            Some(Duration::from_secs(1)),
+            Some(Duration::from_secs(10)),
+        );
+        loop {
+            match operator.write(&hint_path, last_snapshot_path.clone()).await {
+                Ok(_) => return Ok(()),
+                Err(e) => match backoff.next_backoff() {
+                    Some(d) => {
+                        metrics_inc_commit_last_snapshot_hint_retry();
+                        info!(
+                            "write last snapshot hint failure, will retry {} ms later. {}",
+                            d.as_millis(),
+                            e
+                        );
+                        common_base::base::tokio::time::sleep(d).await;
+                    }
+                    None => return Err(e.into()),
+                },
+            }
+        }
     }
 
     // TODO refactor, it is called by segment compaction
@@ -461,9 +505,23 @@ impl FuseTable {
 
     #[inline]
     pub fn no_side_effects_in_meta_store(e: &ErrorCode) -> bool {
-        // currently, the only error that we know,  which indicates there are no side effects
-        // is TABLE_VERSION_MISMATCHED
-        e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+        // currently, the only errors that we know,  which indicate there are no side effects
+        // are TABLE_VERSION_MISMATCHED and DUPLICATED_COMMIT
+        e.code() == ErrorCode::TABLE_VERSION_MISMATCHED || e.code() == ErrorCode::DUPLICATED_COMMIT
+    }
+
+    // the label of the statement, recorded with its result in the same transaction as the table meta
+    fn deduplicated_label(ctx: &dyn TableContext) -> Result<Option<DeduplicatedLabel>> {
+        let Some(label) = (unsafe { ctx.get_settings().get_deduplicate_label()? }) else {
+            return Ok(None);
+        };
+        let expire_hours = ctx.get_settings().get_deduplicate_label_expire_hours()?;
+        let expire_at = Utc::now().timestamp() as u64 + expire_hours * 60 * 60;
+        Ok(Some(DeduplicatedLabel {
+            label,
+            result: DeduplicatedResult::from_ctx(ctx).to_bytes()?,
+            expire_at: Some(expire_at),
+        }))
     }
 
     // check if there are any fuse table legacy options
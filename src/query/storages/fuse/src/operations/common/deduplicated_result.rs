@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storage::CopyStatus;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The result of a statement committed with a deduplicate label.
+///
+/// It is stored as the value of the label, so that a retry of the statement with the
+/// same label reports what the committed statement did instead of doing it twice.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct DeduplicatedResult {
+    pub write_progress: ProgressValues,
+    #[serde(default)]
+    pub copy_status: CopyStatus,
+}
+
+impl DeduplicatedResult {
+    pub fn from_ctx(ctx: &dyn TableContext) -> Self {
+        DeduplicatedResult {
+            write_progress: ctx.get_write_progress_value(),
+            copy_status: ctx.get_copy_status().as_ref().clone(),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Returns `None` for the labels written without a result.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    /// Reports the result in the context of the retried statement.
+    pub fn apply_to(self, ctx: &dyn TableContext) {
+        ctx.get_write_progress().incr(&self.write_progress);
+        ctx.get_copy_status().merge(self.copy_status);
+    }
+}
@@ -13,11 +13,16 @@
 // limitations under the License.
 
 mod abort_operation;
+mod deduplicated_result;
 mod mutation_log;
 mod processors;
+mod segment_stats_index;
 mod snapshot_generator;
+mod storage_quota;
 
 pub use abort_operation::AbortOperation;
+pub use deduplicated_result::DeduplicatedResult;
 pub use mutation_log::*;
 pub use processors::*;
 pub use snapshot_generator::*;
+pub use storage_quota::TenantStorageUsage;
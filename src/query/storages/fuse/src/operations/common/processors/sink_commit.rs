@@ -52,6 +52,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::CommitMeta;
 use crate::operations::common::SnapshotGenerator;
+use crate::operations::common::TenantStorageUsage;
 use crate::FuseTable;
 
 enum State {
@@ -67,6 +68,7 @@ enum State {
     TryCommit {
         data: Vec<u8>,
         snapshot: TableSnapshot,
+        previous: Option<Arc<TableSnapshot>>,
         table_info: TableInfo,
     },
     AbortOperation,
@@ -245,12 +247,15 @@ where F: SnapshotGenerator + Send + 'static
                     match self.snapshot_gen.generate_new_snapshot(
                         schema,
                         cluster_key_meta,
-                        previous,
+                        previous.clone(),
                     ) {
-                        Ok(snapshot) => {
+                        Ok(mut snapshot) => {
+                            FuseTable::try_from_table(self.table.as_ref())?
+                                .gen_segment_stats_index_location(&mut snapshot)?;
                             self.state = State::TryCommit {
                                 data: snapshot.to_bytes()?,
                                 snapshot,
+                                previous,
                                 table_info,
                             };
                         }
@@ -322,8 +327,30 @@ where F: SnapshotGenerator + Send + 'static
             State::TryCommit {
                 data,
                 snapshot,
+                previous,
                 table_info,
             } => {
+                // Size-increasing mutations must stay within the storage quota of the tenant.
+                // Nothing has been committed yet, so it is safe to abort the operation here.
+                if let Err(e) = TenantStorageUsage::instance()
+                    .check_quota(
+                        self.ctx.as_ref(),
+                        &table_info,
+                        snapshot.summary.uncompressed_byte_size,
+                    )
+                    .await
+                {
+                    metrics_inc_commit_aborts();
+                    let op = self.abort_operation.clone();
+                    op.abort(self.ctx.clone(), self.dal.clone()).await?;
+                    return Err(e);
+                }
+
+                // the segment statistics index must be in place before the snapshot referencing it
+                FuseTable::try_from_table(self.table.as_ref())?
+                    .write_segment_stats_index(self.ctx.clone(), previous.as_deref(), &snapshot)
+                    .await?;
+
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
@@ -395,6 +422,15 @@ where F: SnapshotGenerator + Send + 'static
                         }
                         self.state = State::Finish;
                     }
+                    Err(e) if e.code() == ErrorCode::DUPLICATED_COMMIT => {
+                        // the statement has been committed with the same deduplicate label,
+                        // e.g. by an attempt the client timed out on. drop the data written
+                        // by this attempt and report success.
+                        info!("{}, discard the data of this attempt", e.message());
+                        let op = self.abort_operation.clone();
+                        op.abort(self.ctx.clone(), self.dal.clone()).await?;
+                        self.state = State::Finish;
+                    }
                     Err(e) if self.is_error_recoverable(&e) => {
                         let table_info = self.table.get_table_info();
                         match self.backoff.next_backoff() {
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::BucketSpec;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+
+// Multiplier used to fold the hashes of multiple bucket keys into one value.
+const BUCKET_HASH_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Computes the bucket of each row of a bucketed table.
+///
+/// The bucket of a row is `siphash(key) % num_buckets`, the hashes of multiple keys are
+/// folded in key order. NULL keys hash to 0. The bucket ids are persisted in the block
+/// metas, so the function must never change.
+#[derive(Clone)]
+pub struct BucketHasher {
+    func_ctx: FunctionContext,
+    hash_exprs: Vec<Expr>,
+    num_buckets: u64,
+}
+
+impl BucketHasher {
+    /// `schema` is the schema of the blocks to hash.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        bucket_spec: &BucketSpec,
+        schema: &TableSchema,
+    ) -> Result<Self> {
+        let hash_exprs = bucket_spec
+            .key_indices(schema)?
+            .into_iter()
+            .map(|index| {
+                let field = schema.field(index);
+                let column = Expr::ColumnRef {
+                    span: None,
+                    id: index,
+                    data_type: DataType::from(field.data_type()),
+                    display_name: field.name().clone(),
+                };
+                check_function(None, "siphash", &[], &[column], &BUILTIN_FUNCTIONS)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            func_ctx,
+            hash_exprs,
+            num_buckets: bucket_spec.num_buckets,
+        })
+    }
+
+    pub fn num_buckets(&self) -> u64 {
+        self.num_buckets
+    }
+
+    /// Returns the bucket of each row of the block.
+    pub fn bucket_ids(&self, block: &DataBlock) -> Result<Vec<u64>> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut hashes = vec![0u64; num_rows];
+        for expr in &self.hash_exprs {
+            let value = evaluator.run(expr)?;
+            fold_hashes(&mut hashes, value);
+        }
+        Ok(hashes.into_iter().map(|h| h % self.num_buckets).collect())
+    }
+
+    /// Returns the bucket shared by all the rows of the block, or `None` if the rows
+    /// belong to different buckets.
+    pub fn block_bucket_id(&self, block: &DataBlock) -> Result<Option<u64>> {
+        let bucket_ids = self.bucket_ids(block)?;
+        let first = bucket_ids.first().cloned();
+        if bucket_ids.iter().all(|id| Some(*id) == first) {
+            Ok(first)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn fold_hashes(hashes: &mut [u64], value: Value<AnyType>) {
+    let fold = |hash: &mut u64, v: u64| *hash = hash.wrapping_mul(BUCKET_HASH_SEED) ^ v;
+    match value {
+        Value::Scalar(Scalar::Number(NumberScalar::UInt64(v))) => {
+            hashes.iter_mut().for_each(|hash| fold(hash, v))
+        }
+        Value::Scalar(_) => hashes.iter_mut().for_each(|hash| fold(hash, 0)),
+        Value::Column(column) => {
+            if let Some(column) = NumberType::<u64>::try_downcast_column(&column) {
+                for (hash, v) in hashes.iter_mut().zip(column.iter()) {
+                    fold(hash, *v);
+                }
+            } else if let Some(column) =
+                NullableType::<NumberType<u64>>::try_downcast_column(&column)
+            {
+                for ((hash, v), valid) in hashes
+                    .iter_mut()
+                    .zip(column.column.iter())
+                    .zip(column.validity.iter())
+                {
+                    fold(hash, if valid { *v } else { 0 });
+                }
+            } else {
+                unreachable!("siphash must return UInt64")
+            }
+        }
+    }
+}
+
+/// Splits the blocks to append to a bucketed table, so that every output block holds
+/// the rows of a single bucket.
+pub struct TransformBucketScatter {
+    hasher: BucketHasher,
+}
+
+impl TransformBucketScatter {
+    pub fn new(hasher: BucketHasher) -> Self {
+        Self { hasher }
+    }
+}
+
+impl AccumulatingTransform for TransformBucketScatter {
+    const NAME: &'static str = "TransformBucketScatter";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if data.is_empty() {
+            return Ok(vec![data]);
+        }
+        let bucket_ids = self.hasher.bucket_ids(&data)?;
+        let first = bucket_ids[0];
+        if bucket_ids.iter().all(|id| *id == first) {
+            return Ok(vec![data]);
+        }
+
+        let block_meta = data.get_meta().cloned();
+        let scattered = DataBlock::scatter(&data, &bucket_ids, self.hasher.num_buckets as usize)?;
+        scattered
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(|block| block.add_meta(block_meta.clone()))
+            .collect()
+    }
+}
@@ -113,7 +113,7 @@ impl TableMutationAggregator {
             schema: table.schema(),
             dal: table.get_operator(),
             location_gen: table.meta_location_generator().clone(),
-            thresholds: table.get_block_thresholds(),
+            thresholds: table.get_block_thresholds(ctx.clone()),
             default_cluster_key_id: table.cluster_key_id(),
             mutations: HashMap::new(),
             appended_segments: vec![],
@@ -39,6 +39,7 @@ use storages_common_index::BloomIndex;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::InvertedIndexBuilder;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -101,6 +102,9 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let inverted_index_builders =
+            InvertedIndexBuilder::create_builders(&table.table_info.meta.indexes, &source_schema);
+        let bucket_hasher = table.bucket_hasher(ctx.clone())?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -108,6 +112,8 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            inverted_index_builders,
+            bucket_hasher,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -305,6 +311,14 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                for inverted_index_state in serialized.inverted_index_states {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::warn;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::LoadParams;
+use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::SegmentStats;
+use storages_common_table_meta::meta::SegmentStatsIndex;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+
+use crate::io::MetaReaders;
+use crate::io::MetaWriter;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Reads the segment statistics index referenced by the snapshot.
+    ///
+    /// Returns None if the snapshot does not reference an index, or the index file is missing.
+    #[async_backtrace::framed]
+    pub async fn read_segment_stats_index(
+        &self,
+        snapshot: &TableSnapshot,
+    ) -> Result<Option<Arc<SegmentStatsIndex>>> {
+        let Some(loc) = &snapshot.segment_stats_index_location else {
+            return Ok(None);
+        };
+
+        let reader = MetaReaders::segment_stats_index_reader(self.get_operator());
+        let load_params = LoadParams {
+            location: loc.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::segment_stats_index_version(loc),
+            put_cache: true,
+        };
+
+        match reader.read(&load_params).await {
+            Ok(index) => Ok(Some(index)),
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                warn!("segment statistics index {} not found, ignored", loc);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Builds the segment statistics index of `snapshot` incrementally.
+    ///
+    /// The entries of the segments which are still referenced are taken from the index of
+    /// `previous`, only the segments newly added by `snapshot` are read. Segments of `previous`
+    /// which are not indexed (e.g. written by older versions) are left out, the pruner reads
+    /// them as usual.
+    #[async_backtrace::framed]
+    pub async fn build_segment_stats_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        previous: Option<&TableSnapshot>,
+        snapshot: &TableSnapshot,
+    ) -> Result<SegmentStatsIndex> {
+        let previous_index = match previous {
+            Some(previous) => self.read_segment_stats_index(previous).await?,
+            None => None,
+        };
+        let previous_segments: HashSet<&String> = previous
+            .map(|previous| previous.segments.iter().map(|(path, _)| path).collect())
+            .unwrap_or_default();
+
+        let mut segments = HashMap::with_capacity(snapshot.segments.len());
+        let mut new_segments = Vec::new();
+        for location in snapshot.segments.iter() {
+            if let Some(stats) = previous_index
+                .as_ref()
+                .and_then(|index| index.get(&location.0))
+            {
+                segments.insert(location.0.clone(), stats.clone());
+            } else if !previous_segments.contains(&location.0) {
+                new_segments.push(location.clone());
+            }
+        }
+
+        // the newly written segments are most likely still cached.
+        let segments_io = SegmentsIO::create(ctx, self.get_operator(), self.schema());
+        let infos = segments_io
+            .read_segments::<Arc<CompactSegmentInfo>>(&new_segments, true)
+            .await?;
+        for (location, info) in new_segments.into_iter().zip(infos.into_iter()) {
+            let info = info?;
+            segments.insert(location.0, SegmentStats {
+                uncompressed_byte_size: info.summary.uncompressed_byte_size,
+                col_stats: info.summary.col_stats.clone(),
+            });
+        }
+
+        Ok(SegmentStatsIndex::new(snapshot.snapshot_id, segments))
+    }
+
+    /// Writes down the segment statistics index of `snapshot`, at the location it references.
+    #[async_backtrace::framed]
+    pub async fn write_segment_stats_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        previous: Option<&TableSnapshot>,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let Some(loc) = &snapshot.segment_stats_index_location else {
+            return Ok(());
+        };
+
+        let index = self
+            .build_segment_stats_index(ctx, previous, snapshot)
+            .await?;
+        index.write_meta(&self.get_operator(), loc).await?;
+        if let Some(cache) = SegmentStatsIndex::cache() {
+            cache.put(loc.clone(), Arc::new(index));
+        }
+        Ok(())
+    }
+
+    /// Assigns the location of the segment statistics index to a newly generated snapshot.
+    pub fn gen_segment_stats_index_location(&self, snapshot: &mut TableSnapshot) -> Result<()> {
+        snapshot.segment_stats_index_location = if snapshot.segments.is_empty() {
+            None
+        } else {
+            Some(
+                self.meta_location_generator
+                    .segment_stats_index_location_from_uuid(
+                        &snapshot.snapshot_id,
+                        SegmentStatsIndex::VERSION,
+                    )?,
+            )
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog::Catalog;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+// The cached quota and usage are refreshed after this interval, so that the changes of
+// the quota and the commits made by other query nodes are eventually taken into account.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+struct CachedQuota {
+    max_storage_bytes: u64,
+    refreshed_at: Instant,
+}
+
+struct CachedUsage {
+    bytes: u64,
+    refreshed_at: Instant,
+}
+
+/// Tracks the logical bytes stored by each tenant.
+///
+/// The usage of a tenant is the sum of the snapshot summary sizes(`uncompressed_byte_size`,
+/// which is kept in the `data_bytes` of the table statistics) of all its fuse tables.
+/// It is computed from the table metas in the background, and then maintained incrementally
+/// by the commits made on this node.
+///
+/// The storage quota is a soft limit: the usage is cached per node and can be up to
+/// `REFRESH_INTERVAL`(5 minutes) stale, it misses the commits made by the other nodes
+/// meanwhile, and the commits are not checked until the usage of the tenant is computed.
+pub struct TenantStorageUsage {
+    quotas: RwLock<HashMap<String, CachedQuota>>,
+    usages: RwLock<HashMap<String, CachedUsage>>,
+    // The tenants whose usage is being computed in the background.
+    refreshing: Mutex<HashSet<String>>,
+}
+
+impl TenantStorageUsage {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(TenantStorageUsage {
+            quotas: RwLock::new(HashMap::new()),
+            usages: RwLock::new(HashMap::new()),
+            refreshing: Mutex::new(HashSet::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TenantStorageUsage> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the logical bytes stored by the tenant of the context, it's computed if the
+    /// cached usage is missing or stale.
+    #[async_backtrace::framed]
+    pub async fn get_usage(&self, ctx: &dyn TableContext) -> Result<u64> {
+        let tenant = ctx.get_tenant();
+        if let Some(usage) = self.usages.read().get(&tenant) {
+            if usage.refreshed_at.elapsed() < REFRESH_INTERVAL {
+                return Ok(usage.bytes);
+            }
+        }
+
+        let bytes = Self::compute_usage(ctx.get_default_catalog()?, &tenant).await?;
+        self.usages.write().insert(tenant, CachedUsage {
+            bytes,
+            refreshed_at: Instant::now(),
+        });
+        Ok(bytes)
+    }
+
+    /// Applies the change of a table's size, made by a successful commit, to the cached usage.
+    pub fn apply_delta(&self, tenant: &str, prev_bytes: u64, new_bytes: u64) {
+        if let Some(usage) = self.usages.write().get_mut(tenant) {
+            usage.bytes = usage
+                .bytes
+                .saturating_sub(prev_bytes)
+                .saturating_add(new_bytes);
+        }
+    }
+
+    /// Drops the cached usage of the tenant, it will be recomputed on next access.
+    ///
+    /// Used when tables appear or disappear as a whole(drop/undrop), which are not
+    /// reflected by the commits.
+    pub fn invalidate(&self, tenant: &str) {
+        self.usages.write().remove(tenant);
+    }
+
+    /// Drops the cached quota of the tenant, used when the quota is changed on this node.
+    pub fn invalidate_quota(&self, tenant: &str) {
+        self.quotas.write().remove(tenant);
+    }
+
+    /// Checks that committing a snapshot of `new_bytes` logical bytes to the table
+    /// does not exceed the storage quota of the tenant.
+    ///
+    /// Mutations that do not increase the size of the table are always allowed, and so are
+    /// the mutations of the tenants without a storage quota or whose usage is not computed yet.
+    #[async_backtrace::framed]
+    pub async fn check_quota(
+        &self,
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        new_bytes: u64,
+    ) -> Result<()> {
+        let prev_bytes = table_info.meta.statistics.data_bytes;
+        if new_bytes <= prev_bytes {
+            return Ok(());
+        }
+
+        let tenant = ctx.get_tenant();
+        let max_storage_bytes = self.get_max_storage_bytes(&tenant).await?;
+        if max_storage_bytes == 0 {
+            return Ok(());
+        }
+
+        // The usage is not computed in the commit, which may be retried.
+        let usage = self
+            .usages
+            .read()
+            .get(&tenant)
+            .map(|usage| (usage.bytes, usage.refreshed_at.elapsed() < REFRESH_INTERVAL));
+        let usage = match usage {
+            Some((bytes, fresh)) => {
+                if !fresh {
+                    self.spawn_refresh(ctx, &tenant)?;
+                }
+                bytes
+            }
+            None => {
+                self.spawn_refresh(ctx, &tenant)?;
+                return Ok(());
+            }
+        };
+
+        let expected = usage.saturating_sub(prev_bytes).saturating_add(new_bytes);
+        if expected > max_storage_bytes {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max storage bytes quota exceeded: {}, current usage: {} bytes, table {} would grow by {} bytes",
+                max_storage_bytes,
+                usage,
+                table_info.desc,
+                new_bytes - prev_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_max_storage_bytes(&self, tenant: &str) -> Result<u64> {
+        if let Some(quota) = self.quotas.read().get(tenant) {
+            if quota.refreshed_at.elapsed() < REFRESH_INTERVAL {
+                return Ok(quota.max_storage_bytes);
+            }
+        }
+
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(tenant)?;
+        let max_storage_bytes = quota_api
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data
+            .max_storage_bytes;
+        self.quotas.write().insert(tenant.to_string(), CachedQuota {
+            max_storage_bytes,
+            refreshed_at: Instant::now(),
+        });
+        Ok(max_storage_bytes)
+    }
+
+    // Computes the usage of the tenant in the background, if it's not being computed.
+    fn spawn_refresh(&self, ctx: &dyn TableContext, tenant: &str) -> Result<()> {
+        if !self.refreshing.lock().insert(tenant.to_string()) {
+            return Ok(());
+        }
+
+        let catalog = ctx.get_default_catalog()?;
+        let tenant = tenant.to_string();
+        GlobalIORuntime::instance().spawn("storage_usage_refresh", async move {
+            let usage = TenantStorageUsage::instance();
+            match Self::compute_usage(catalog, &tenant).await {
+                Ok(bytes) => {
+                    usage.usages.write().insert(tenant.clone(), CachedUsage {
+                        bytes,
+                        refreshed_at: Instant::now(),
+                    });
+                }
+                Err(e) => warn!(
+                    "fail to compute the storage usage of tenant {}: {}",
+                    tenant, e
+                ),
+            }
+            usage.refreshing.lock().remove(&tenant);
+        });
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn compute_usage(catalog: Arc<dyn Catalog>, tenant: &str) -> Result<u64> {
+        let mut bytes = 0u64;
+        for database in catalog.list_databases(tenant).await? {
+            // tables of shared databases are stored by the provider tenant.
+            if database.engine() == "SHARE" {
+                continue;
+            }
+            for table in catalog.list_tables(tenant, database.name()).await? {
+                if table.engine() == "FUSE" {
+                    bytes = bytes.saturating_add(table.get_table_info().meta.statistics.data_bytes);
+                }
+            }
+        }
+        info!(
+            "storage usage of tenant {} refreshed: {} bytes",
+            tenant, bytes
+        );
+        Ok(bytes)
+    }
+}
@@ -89,7 +89,7 @@ impl FuseTable {
             return Ok(None);
         };
 
-        let thresholds = self.get_block_thresholds();
+        let thresholds = self.get_block_thresholds(ctx.clone());
         let mut mutator = BlockCompactMutator::new(
             ctx.clone(),
             thresholds,
@@ -117,7 +117,7 @@ impl FuseTable {
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let is_lazy = parts.is_lazy;
-        let thresholds = self.get_block_thresholds();
+        let thresholds = self.get_block_thresholds(ctx.clone());
         let cluster_key_id = self.cluster_key_id();
         let mut max_threads = ctx.get_settings().get_max_threads()? as usize;
         if is_lazy {
@@ -31,9 +31,11 @@ use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentStatsIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::DeletionReport;
 use crate::io::Files;
 use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
@@ -52,6 +54,81 @@ impl FuseTable {
         limit: Option<usize>,
         keep_last_snapshot: bool,
         dry_run: bool,
+    ) -> Result<Option<Vec<String>>> {
+        let mut counter = PurgeCounter::new(false);
+        let purge_files = self
+            .purge_snapshots(
+                ctx,
+                snapshot_files,
+                limit,
+                keep_last_snapshot,
+                dry_run,
+                &mut counter,
+            )
+            .await?;
+        counter.deletion.check()?;
+        Ok(purge_files)
+    }
+
+    /// Purges like [`FuseTable::do_purge`], and returns the numbers of the removed files.
+    #[async_backtrace::framed]
+    pub async fn do_purge_with_stats(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_files: Vec<String>,
+        limit: Option<usize>,
+        keep_last_snapshot: bool,
+    ) -> Result<PurgeStats> {
+        let mut counter = PurgeCounter::new(false);
+        self.purge_snapshots(
+            ctx,
+            snapshot_files,
+            limit,
+            keep_last_snapshot,
+            false,
+            &mut counter,
+        )
+        .await?;
+        counter.deletion.check()?;
+        Ok(PurgeStats {
+            snapshots: counter.snapshots,
+            segments: counter.segments,
+            blocks: counter.blocks,
+        })
+    }
+
+    /// Purges like [`FuseTable::do_purge`], but keeps going on the files failed to be
+    /// removed, and returns the outcome of the deletions.
+    ///
+    /// The files are verified to be absent after the deletion.
+    #[async_backtrace::framed]
+    pub async fn do_purge_with_report(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_files: Vec<String>,
+        keep_last_snapshot: bool,
+    ) -> Result<DeletionReport> {
+        let mut counter = PurgeCounter::new(true);
+        self.purge_snapshots(
+            ctx,
+            snapshot_files,
+            None,
+            keep_last_snapshot,
+            false,
+            &mut counter,
+        )
+        .await?;
+        Ok(counter.deletion)
+    }
+
+    async fn purge_snapshots(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_files: Vec<String>,
+        limit: Option<usize>,
+        keep_last_snapshot: bool,
+        dry_run: bool,
+        counter: &mut PurgeCounter,
     ) -> Result<Option<Vec<String>>> {
         // 1. Read the root snapshot.
         let root_snapshot_info_op = self.read_root_snapshot(ctx, keep_last_snapshot).await?;
@@ -76,7 +153,6 @@ impl FuseTable {
 
         let mut read_snapshot_count = 0;
         let mut remain_snapshots = Vec::<SnapshotLiteExtended>::new();
-        let mut counter = PurgeCounter::new();
         let mut dry_run_purge_files = vec![];
         let mut purged_snapshot_count = 0;
 
@@ -116,6 +192,7 @@ impl FuseTable {
             let base_segments = base_snapshot.segments.clone();
             let base_timestamp = base_snapshot.timestamp;
             let base_ts_location_opt = base_snapshot.table_statistics_location.clone();
+            let base_si_location_opt = base_snapshot.segment_stats_index_location.clone();
             remain_snapshots.push(base_snapshot);
 
             let mut snapshots_to_be_purged = HashSet::new();
@@ -145,6 +222,12 @@ impl FuseTable {
                 {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
+
+                if s.segment_stats_index_location.is_some()
+                    && s.segment_stats_index_location != base_si_location_opt
+                {
+                    ts_to_be_purged.insert(s.segment_stats_index_location.unwrap());
+                }
             }
 
             // Refresh status.
@@ -179,7 +262,7 @@ impl FuseTable {
                 } else {
                     self.partial_purge(
                         ctx,
-                        &mut counter,
+                        counter,
                         &root_snapshot_info.referenced_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
@@ -215,6 +298,10 @@ impl FuseTable {
                 if s.table_statistics_location.is_some() {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
+
+                if s.segment_stats_index_location.is_some() {
+                    ts_to_be_purged.insert(s.segment_stats_index_location.unwrap());
+                }
             }
             if dry_run {
                 self.dry_run_purge(
@@ -230,7 +317,7 @@ impl FuseTable {
             } else {
                 self.partial_purge(
                     ctx,
-                    &mut counter,
+                    counter,
                     &root_snapshot_info.referenced_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
@@ -249,7 +336,7 @@ impl FuseTable {
         if !keep_last_snapshot {
             self.purge_root_snapshot(
                 ctx,
-                &mut counter,
+                counter,
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
@@ -302,6 +389,7 @@ impl FuseTable {
             timestamp: root_snapshot.timestamp,
             segments: HashSet::from_iter(root_snapshot.segments.clone()),
             table_statistics_location: root_snapshot.table_statistics_location.clone(),
+            segment_stats_index_location: root_snapshot.segment_stats_index_location.clone(),
         });
         Ok(Some(RootSnapshotInfo {
             snapshot_location,
@@ -342,6 +430,14 @@ impl FuseTable {
                         ),
                     )
                 }
+                for index in self.table_info.meta.indexes.values() {
+                    purge_files.push(
+                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                            loc,
+                            &index.version,
+                        ),
+                    )
+                }
             }
 
             for loc in &locations.bloom_location {
@@ -394,6 +490,14 @@ impl FuseTable {
                         ),
                     );
                 }
+                for index in self.table_info.meta.indexes.values() {
+                    agg_indexes_to_be_purged.insert(
+                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                            loc,
+                            &index.version,
+                        ),
+                    );
+                }
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -463,6 +567,14 @@ impl FuseTable {
                 )
             }));
         }
+        for index in self.table_info.meta.indexes.values() {
+            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    loc,
+                    &index.version,
+                )
+            }));
+        }
 
         self.purge_block_segments(
             ctx,
@@ -478,6 +590,9 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
+        if let Some(si) = root_snapshot.segment_stats_index_location.clone() {
+            ts_to_be_purged.insert(si);
+        }
         self.purge_ts_snapshots(
             ctx,
             counter,
@@ -500,37 +615,47 @@ impl FuseTable {
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
-            self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
+            let report = self
+                .try_purge_location_files(ctx.clone(), blocks_to_be_purged, counter.verify)
                 .await?;
+            counter.deletion.merge(report);
         }
 
         let agg_index_count = agg_indexes_to_be_purged.len();
         if agg_index_count > 0 {
             counter.agg_indexes += agg_index_count;
-            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
+            let report = self
+                .try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged, counter.verify)
                 .await?;
+            counter.deletion.merge(report);
         }
 
         // 2. Try to purge bloom index file chunks.
         let blooms_count = blooms_to_be_purged.len();
         if blooms_count > 0 {
             counter.blooms += blooms_count;
-            self.try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(
-                ctx.clone(),
-                blooms_to_be_purged,
-            )
-            .await?;
+            let report = self
+                .try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(
+                    ctx.clone(),
+                    blooms_to_be_purged,
+                    counter.verify,
+                )
+                .await?;
+            counter.deletion.merge(report);
         }
 
         // 3. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
-            self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
-                ctx.clone(),
-                segments_to_be_purged,
-            )
-            .await?;
+            let report = self
+                .try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
+                    ctx.clone(),
+                    segments_to_be_purged,
+                    counter.verify,
+                )
+                .await?;
+            counter.deletion.merge(report);
         }
         Ok(())
     }
@@ -542,26 +667,47 @@ impl FuseTable {
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
     ) -> Result<()> {
-        // 3. Purge table statistic files
+        // 3. Purge table statistic files, including the segment statistics indexes
         let ts_count = ts_to_be_purged.len();
         if ts_count > 0 {
             counter.table_statistics += ts_count;
-            self.try_purge_location_files_and_cache::<TableSnapshotStatistics, _, _>(
-                ctx.clone(),
-                ts_to_be_purged,
-            )
-            .await?;
+            if let Some(cache) = SegmentStatsIndex::cache() {
+                for loc in ts_to_be_purged.iter() {
+                    cache.evict(loc);
+                }
+            }
+            let report = self
+                .try_purge_location_files_and_cache::<TableSnapshotStatistics, _, _>(
+                    ctx.clone(),
+                    ts_to_be_purged,
+                    counter.verify,
+                )
+                .await?;
+            counter.deletion.merge(report);
         }
 
         // 4. Purge snapshots.
+        // Keep the snapshots if any of the files referenced by them failed to be removed,
+        // so that the files can be purged again by the next purge.
         let snapshots_count = snapshots_to_be_purged.len();
-        if snapshots_count > 0 {
+        if snapshots_count > 0 && counter.deletion.failed > 0 {
+            warn!(
+                "gc: keep {} snapshots, because {} files failed to be removed. table: {}, ident {}",
+                snapshots_count,
+                counter.deletion.failed,
+                self.table_info.desc,
+                self.table_info.ident,
+            );
+        } else if snapshots_count > 0 {
             counter.snapshots += snapshots_count;
-            self.try_purge_location_files_and_cache::<TableSnapshot, _, _>(
-                ctx.clone(),
-                snapshots_to_be_purged,
-            )
-            .await?;
+            let report = self
+                .try_purge_location_files_and_cache::<TableSnapshot, _, _>(
+                    ctx.clone(),
+                    snapshots_to_be_purged,
+                    counter.verify,
+                )
+                .await?;
+            counter.deletion.merge(report);
         }
 
         // 5. Refresh status.
@@ -586,10 +732,13 @@ impl FuseTable {
         &self,
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
-    ) -> Result<()> {
+        verify: bool,
+    ) -> Result<DeletionReport> {
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
         let locations = Vec::from_iter(locations_to_be_purged);
-        fuse_file.remove_file_in_batch(&locations).await
+        fuse_file
+            .remove_file_in_batch_with_report(&locations, verify)
+            .await
     }
 
     // Purge file by location chunks.
@@ -598,7 +747,8 @@ impl FuseTable {
         &self,
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
-    ) -> Result<()>
+        verify: bool,
+    ) -> Result<DeletionReport>
     where
         T: CachedObject<T, H, M>,
         H: BuildHasher,
@@ -609,7 +759,7 @@ impl FuseTable {
                 cache.evict(loc);
             }
         }
-        self.try_purge_location_files(ctx, locations_to_be_purged)
+        self.try_purge_location_files(ctx, locations_to_be_purged, verify)
             .await
     }
 
@@ -696,8 +846,19 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     }
 }
 
+/// The numbers of the files removed by a purge.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PurgeStats {
+    pub snapshots: usize,
+    pub segments: usize,
+    pub blocks: usize,
+}
+
 struct PurgeCounter {
     start: Instant,
+    // Whether to verify the files are absent after the deletion.
+    verify: bool,
+    deletion: DeletionReport,
     blocks: usize,
     agg_indexes: usize,
     blooms: usize,
@@ -707,9 +868,11 @@ struct PurgeCounter {
 }
 
 impl PurgeCounter {
-    fn new() -> Self {
+    fn new(verify: bool) -> Self {
         Self {
             start: Instant::now(),
+            verify,
+            deletion: DeletionReport::default(),
             blocks: 0,
             agg_indexes: 0,
             blooms: 0,
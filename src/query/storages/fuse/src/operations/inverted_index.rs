@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::TableIndex;
+use storages_common_cache::LoadParams;
+
+use crate::io::write_data;
+use crate::io::InvertedIndexBuilder;
+use crate::io::InvertedIndexState;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the missing index files of the blocks in the current snapshot,
+    /// at most `limit` blocks are indexed. Returns the number of blocks indexed.
+    #[async_backtrace::framed]
+    pub async fn refresh_inverted_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &TableIndex,
+        limit: Option<u64>,
+    ) -> Result<u64> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            // no snapshot
+            return Ok(0);
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let mut field_indices = table_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| index.column_ids.contains(&f.column_id()))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if field_indices.is_empty() {
+            return Ok(0);
+        }
+        field_indices.sort();
+
+        // The blocks read contain only the indexed columns, in the order of `field_indices`.
+        let builder = InvertedIndexBuilder {
+            version: index.version.clone(),
+            columns: field_indices
+                .iter()
+                .enumerate()
+                .map(|(offset, i)| (table_schema.field(*i).column_id(), offset))
+                .collect(),
+        };
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(field_indices),
+            false,
+            false,
+            false,
+        )?;
+
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+        let operator = self.get_operator_ref();
+
+        let mut refreshed = 0;
+        for (location, ver) in &snapshot.segments {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                if limit.is_some_and(|limit| refreshed >= limit) {
+                    return Ok(refreshed);
+                }
+
+                let index_location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index.version,
+                    );
+                match operator.stat(&index_location).await {
+                    Ok(_) => continue,
+                    Err(e) if e.kind() == opendal::ErrorKind::NotFound => {}
+                    Err(e) => return Err(e.into()),
+                }
+
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let state =
+                    InvertedIndexState::try_create(&block, &block_meta.location.0, &builder)?;
+                write_data(state.data, operator, &state.location).await?;
+                refreshed += 1;
+            }
+        }
+
+        Ok(refreshed)
+    }
+}
@@ -20,6 +20,7 @@ pub mod common;
 mod compact;
 mod delete;
 mod gc;
+mod inverted_index;
 mod merge;
 mod merge_into;
 mod mutation;
@@ -38,6 +39,7 @@ pub use agg_index_sink::AggIndexSink;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
+pub use gc::PurgeStats;
 pub use merge_into::*;
 pub use mutation::*;
 pub use read::build_row_fetcher_pipeline;
@@ -177,7 +177,9 @@ impl BlockCompactMutator {
 
         let cluster = self.ctx.get_cluster();
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
-        let partitions = if cluster.is_empty() || parts.len() < cluster.nodes.len() * max_threads {
+        let partitions = if cluster.is_empty()
+            || parts.len() < cluster.get_executor_nodes().len() * max_threads
+        {
             let column_ids = self
                 .compact_params
                 .base_snapshot
@@ -518,10 +520,22 @@ impl CompactTaskBuilder {
             blocks.sort_by(|a, b| {
                 sort_by_cluster_stats(&a.cluster_stats, &b.cluster_stats, default_cluster_key)
             });
+        } else if blocks.iter().any(|v| v.bucket_id.is_some()) {
+            // The blocks of a bucketed table can only be compacted within the same bucket.
+            blocks.sort_by_key(|v| v.bucket_id);
         }
 
         let mut tasks = VecDeque::new();
         for block in blocks.iter() {
+            if self
+                .blocks
+                .last()
+                .is_some_and(|v| v.bucket_id != block.bucket_id)
+            {
+                let blocks = self.take_blocks();
+                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                block_idx += 1;
+            }
             let (unchanged, need_take) = self.add(block, self.thresholds);
             if need_take {
                 let blocks = self.take_blocks();
@@ -537,9 +551,20 @@ impl CompactTaskBuilder {
 
         if !self.is_empty() {
             let tail = self.take_blocks();
+            let latest_bucket_id = if latest_flag {
+                unchanged_blocks.last().map(|(_, v)| v.bucket_id)
+            } else {
+                tasks
+                    .back()
+                    .and_then(|(_, v)| v.last())
+                    .map(|v| v.bucket_id)
+            };
             if self.cluster_key_id.is_some() && latest_flag {
                 // The clustering table cannot compact different level blocks.
                 self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail);
+            } else if latest_bucket_id.is_some_and(|v| v != tail[0].bucket_id) {
+                // The tail belongs to another bucket.
+                self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail);
             } else {
                 let mut blocks = if latest_flag {
                     unchanged_blocks.pop().map_or(vec![], |(_, v)| vec![v])
@@ -157,7 +157,7 @@ impl ReclusterAggregator {
             dal: table.get_operator(),
             location_gen: table.meta_location_generator().clone(),
             default_cluster_key,
-            block_thresholds: table.get_block_thresholds(),
+            block_thresholds: table.get_block_thresholds(ctx.clone()),
             block_per_seg,
             merged_blocks,
             removed_segment_indexes,
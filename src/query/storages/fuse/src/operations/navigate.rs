@@ -21,17 +21,20 @@ use common_catalog::table::NavigationPoint;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
 use futures::TryStreamExt;
 use log::warn;
 use opendal::EntryMode;
 use opendal::Metakey;
 use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
 use crate::io::SnapshotHistoryReader;
+use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
@@ -43,14 +46,36 @@ impl FuseTable {
         location: String,
         time_point: DateTime<Utc>,
     ) -> Result<Arc<FuseTable>> {
-        self.find(location, |snapshot| {
+        let pred = |snapshot: &TableSnapshot| {
             if let Some(ts) = snapshot.timestamp {
                 ts <= time_point
             } else {
                 false
             }
-        })
-        .await
+        };
+        let earliest = match self.search_history(location, pred).await? {
+            HistorySearch::Found(snapshot, format_version) => {
+                return self.load_table_at(&snapshot, format_version);
+            }
+            HistorySearch::NotFound(earliest) => earliest,
+        };
+
+        match earliest {
+            Some(earliest) if earliest.prev_snapshot_id.is_none() => {
+                Err(ErrorCode::TableTimeTravelBeforeCreation(format!(
+                    "Time point {} is before the creation of the table",
+                    time_point
+                )))
+            }
+            Some(earliest) => Err(ErrorCode::TableHistoricalDataPurged(format!(
+                "Historical data at time point {} has been purged, the earliest available time point is {}",
+                time_point,
+                display_timestamp(&earliest),
+            ))),
+            None => Err(ErrorCode::TableHistoricalDataNotFound(
+                "No historical data found at given point",
+            )),
+        }
     }
 
     #[async_backtrace::framed]
@@ -59,19 +84,112 @@ impl FuseTable {
         location: String,
         snapshot_id: &str,
     ) -> Result<Arc<FuseTable>> {
-        self.find(location, |snapshot| {
+        let pred = |snapshot: &TableSnapshot| {
             snapshot
                 .snapshot_id
                 .simple()
                 .to_string()
                 .as_str()
                 .starts_with(snapshot_id)
+        };
+        let earliest = match self.search_history(location, pred).await? {
+            HistorySearch::Found(snapshot, format_version) => {
+                return self.load_table_at(&snapshot, format_version);
+            }
+            HistorySearch::NotFound(earliest) => earliest,
+        };
+
+        match earliest {
+            // the snapshot might be purged, but it can not be told from an unknown one.
+            Some(earliest) if earliest.prev_snapshot_id.is_some() => {
+                Err(ErrorCode::UnknownTableSnapshot(format!(
+                    "Unknown snapshot '{}', the history before {} might have been purged",
+                    snapshot_id,
+                    display_timestamp(&earliest),
+                )))
+            }
+            _ => Err(ErrorCode::UnknownTableSnapshot(format!(
+                "Unknown snapshot '{}'",
+                snapshot_id
+            ))),
+        }
+    }
+
+    /// Returns the earliest and the latest snapshots that the table can travel to,
+    /// or `None` if the table has no snapshot.
+    #[async_backtrace::framed]
+    pub async fn time_travel_boundaries(
+        &self,
+    ) -> Result<Option<(Arc<TableSnapshot>, Arc<TableSnapshot>)>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+
+        let mut latest = None;
+        let mut earliest = None;
+        while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
+            if latest.is_none() {
+                latest = Some(snapshot.clone());
+            }
+            earliest = Some(snapshot);
+        }
+        Ok(earliest.zip(latest))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_stream(
+        &self,
+        location: String,
+        stream_info: &TableInfo,
+    ) -> Result<Arc<FuseTable>> {
+        let Some(stream_location) = stream_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at stream '{}'",
+                stream_info.name
+            )));
+        };
+        let stream_snapshot =
+            match SnapshotsIO::read_snapshot(stream_location.clone(), self.get_operator()).await {
+                Ok((snapshot, _)) => snapshot,
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                        "No historical data found at stream '{}'",
+                        stream_info.name
+                    )));
+                }
+                Err(e) => return Err(e),
+            };
+
+        // the stream may be created on another table, walk the history to make sure
+        // the snapshot belongs to this table.
+        self.find(location, |snapshot| {
+            snapshot.snapshot_id == stream_snapshot.snapshot_id
         })
         .await
     }
 
     #[async_backtrace::framed]
-    pub async fn find<P>(&self, location: String, mut pred: P) -> Result<Arc<FuseTable>>
+    pub async fn find<P>(&self, location: String, pred: P) -> Result<Arc<FuseTable>>
+    where P: FnMut(&TableSnapshot) -> bool {
+        match self.search_history(location, pred).await? {
+            HistorySearch::Found(snapshot, format_version) => {
+                self.load_table_at(&snapshot, format_version)
+            }
+            HistorySearch::NotFound(_) => Err(ErrorCode::TableHistoricalDataNotFound(
+                "No historical data found at given point",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn search_history<P>(&self, location: String, mut pred: P) -> Result<HistorySearch>
     where P: FnMut(&TableSnapshot) -> bool {
         let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
         let reader = MetaReaders::table_snapshot_reader(self.get_operator());
@@ -84,57 +202,58 @@ impl FuseTable {
         );
 
         // Find the instant which matches the given `time_point`.
-        let mut instant = None;
-        while let Some(snapshot_with_version) = snapshot_stream.try_next().await? {
-            if pred(snapshot_with_version.0.as_ref()) {
-                instant = Some(snapshot_with_version);
-                break;
+        let mut earliest = None;
+        while let Some((snapshot, format_version)) = snapshot_stream.try_next().await? {
+            if pred(snapshot.as_ref()) {
+                return Ok(HistorySearch::Found(snapshot, format_version));
             }
+            earliest = Some(snapshot);
         }
+        Ok(HistorySearch::NotFound(earliest))
+    }
 
-        if let Some((snapshot, format_version)) = instant {
-            // Load the table instance by the snapshot
-
-            // The `seq` of ident that we cloned here is JUST a place holder
-            // we should NOT use it other than a pure place holder.
-            let mut table_info = self.table_info.clone();
-
-            // There are more to be kept in snapshot, like engine_options, ordering keys...
-            // or we could just keep a clone of TableMeta in the snapshot.
-            //
-            // currently, here are what we can recovery from the snapshot:
-
-            // 1. the table schema
-            table_info.meta.schema = Arc::new(snapshot.schema.clone());
-
-            // 2. the table option `snapshot_location`
-            let loc = self
-                .meta_location_generator
-                .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
-            table_info
-                .meta
-                .options
-                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
-
-            // 3. The statistics
-            let summary = &snapshot.summary;
-            table_info.meta.statistics = TableStatistics {
-                number_of_rows: summary.row_count,
-                data_bytes: summary.uncompressed_byte_size,
-                compressed_data_bytes: summary.compressed_byte_size,
-                index_data_bytes: summary.index_size,
-                number_of_segments: Some(snapshot.segments.len() as u64),
-                number_of_blocks: Some(summary.block_count),
-            };
+    fn load_table_at(
+        &self,
+        snapshot: &TableSnapshot,
+        format_version: FormatVersion,
+    ) -> Result<Arc<FuseTable>> {
+        // Load the table instance by the snapshot
 
-            // let's instantiate it
-            let table = FuseTable::do_create(table_info)?;
-            Ok(table.into())
-        } else {
-            Err(ErrorCode::TableHistoricalDataNotFound(
-                "No historical data found at given point",
-            ))
-        }
+        // The `seq` of ident that we cloned here is JUST a place holder
+        // we should NOT use it other than a pure place holder.
+        let mut table_info = self.table_info.clone();
+
+        // There are more to be kept in snapshot, like engine_options, ordering keys...
+        // or we could just keep a clone of TableMeta in the snapshot.
+        //
+        // currently, here are what we can recovery from the snapshot:
+
+        // 1. the table schema
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+
+        // 2. the table option `snapshot_location`
+        let loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
+
+        // 3. The statistics
+        let summary = &snapshot.summary;
+        table_info.meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(summary.block_count),
+        };
+
+        // let's instantiate it
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
     }
 
     #[async_backtrace::framed]
@@ -164,6 +283,9 @@ impl FuseTable {
                 self.list_by_snapshot_id(snapshot_id.as_str(), time_point)
                     .await
             }
+            Some(point @ NavigationPoint::StreamInfo(_)) => {
+                return self.navigate_for_purge_before(&point).await;
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -172,6 +294,45 @@ impl FuseTable {
         Ok((table, files))
     }
 
+    /// Navigates to the snapshot at `point`, the history before which is to be purged
+    /// regardless of the retention period.
+    ///
+    /// Returns the table at the snapshot, and the snapshot files to be checked.
+    #[async_backtrace::framed]
+    pub async fn navigate_for_purge_before(
+        &self,
+        point: &NavigationPoint,
+    ) -> Result<(Arc<FuseTable>, Vec<String>)> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "Empty Table has no historical data",
+            ));
+        };
+
+        let table = match point {
+            NavigationPoint::TimePoint(time_point) => {
+                let (location, files) = self.list_by_time_point(*time_point).await?;
+                let table = self.navigate_to_time_point(location, *time_point).await?;
+                return Ok((table, files));
+            }
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                self.navigate_to_snapshot(location, snapshot_id).await?
+            }
+            NavigationPoint::StreamInfo(stream_info) => {
+                self.navigate_to_stream(location, stream_info).await?
+            }
+        };
+
+        // the snapshots newer than the navigated one are filtered out while purging.
+        let prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator().prefix(),
+            FUSE_TBL_SNAPSHOT_PREFIX,
+        );
+        let files = self.list_files(prefix, |_, _| true).await?;
+        Ok((table, files))
+    }
+
     #[async_backtrace::framed]
     pub async fn list_by_time_point(
         &self,
@@ -278,3 +439,18 @@ impl FuseTable {
         Ok(file_list.into_iter().map(|v| v.0).collect())
     }
 }
+
+enum HistorySearch {
+    Found(Arc<TableSnapshot>, FormatVersion),
+    /// The snapshot is not found, with the earliest snapshot of the history walked through.
+    ///
+    /// If the `prev_snapshot_id` of the earliest snapshot is not `None`, the history is
+    /// truncated by purging.
+    NotFound(Option<Arc<TableSnapshot>>),
+}
+
+fn display_timestamp(snapshot: &TableSnapshot) -> String {
+    snapshot
+        .timestamp
+        .map_or_else(|| "unknown".to_string(), |ts| ts.to_string())
+}
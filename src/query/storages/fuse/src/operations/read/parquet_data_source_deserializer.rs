@@ -216,6 +216,15 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Only keep the rows matched by inverted indexes.
+                    let matched_rows = part
+                        .block_meta_index()
+                        .and_then(|index| index.matched_rows.clone())
+                        .filter(|_| !self.block_reader.update_stream_columns());
+                    if let Some(rows) = &matched_rows {
+                        data_block = data_block.take(rows, &mut None)?;
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -238,7 +247,7 @@ impl Processor for DeserializeDataTransform {
                         data_block = fill_internal_column_meta(
                             data_block,
                             part,
-                            None,
+                            matched_rows,
                             self.base_block_ids.clone(),
                         )?;
                     }
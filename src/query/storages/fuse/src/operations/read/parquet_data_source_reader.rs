@@ -13,21 +13,25 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::StealablePartitions;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_metrics::storage::*;
 use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
+use log::info;
 
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
@@ -50,6 +54,81 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+
+    prefetching: PrefetchQueue<PartInfoPtr, DataSource>,
+}
+
+/// A partition whose block data is being read ahead of the decoding processors.
+struct PrefetchTask<P, T> {
+    part: P,
+    bytes: usize,
+    handle: JoinHandle<Result<T>>,
+}
+
+/// The partitions being prefetched, bounded by the number of partitions and the byte size
+/// of their block data in flight.
+struct PrefetchQueue<P, T> {
+    max_parts: usize,
+    max_bytes: usize,
+    bytes: usize,
+    tasks: VecDeque<PrefetchTask<P, T>>,
+    // The prefetched partitions which were ready, or still being read, when they were taken.
+    hits: usize,
+    misses: usize,
+}
+
+impl<P, T> PrefetchQueue<P, T> {
+    fn create(max_parts: usize, max_bytes: usize) -> Self {
+        PrefetchQueue {
+            max_parts,
+            max_bytes,
+            bytes: 0,
+            tasks: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.max_parts > 0
+    }
+
+    /// Whether one more partition can be prefetched. At least one partition is always
+    /// prefetched, even if its block data exceeds the byte budget.
+    fn has_room(&self) -> bool {
+        self.tasks.len() < self.max_parts && (self.tasks.is_empty() || self.bytes < self.max_bytes)
+    }
+
+    fn push(&mut self, part: P, bytes: usize, handle: JoinHandle<Result<T>>) {
+        metrics_inc_remote_io_prefetch_parts(1);
+        self.bytes += bytes;
+        self.tasks.push_back(PrefetchTask {
+            part,
+            bytes,
+            handle,
+        });
+    }
+
+    /// Takes the earliest prefetched partition.
+    fn pop(&mut self) -> Option<PrefetchTask<P, T>> {
+        let task = self.tasks.pop_front()?;
+        self.bytes -= task.bytes;
+        if task.handle.is_finished() {
+            self.hits += 1;
+            metrics_inc_remote_io_prefetch_hits(1);
+        } else {
+            self.misses += 1;
+            metrics_inc_remote_io_prefetch_misses(1);
+        }
+        Some(task)
+    }
+
+    /// Cancels the in-flight prefetches, the partitions are not read anymore.
+    fn abort(&self) {
+        for task in &self.tasks {
+            task.handle.abort();
+        }
+    }
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -63,6 +142,8 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         virtual_reader: Arc<Option<VirtualColumnReader>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let prefetch_part_num = ctx.get_settings().get_storage_prefetch_part_num()? as usize;
+        let prefetch_max_bytes = ctx.get_settings().get_storage_prefetch_max_bytes()? as usize;
 
         if BLOCKING_IO {
             SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -75,6 +156,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                prefetching: PrefetchQueue::create(0, 0),
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -89,6 +171,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                prefetching: PrefetchQueue::create(prefetch_part_num, prefetch_max_bytes),
             })))
         }
     }
@@ -155,6 +238,122 @@ impl SyncSource for ReadParquetDataSource<true> {
     }
 }
 
+impl ReadParquetDataSource<false> {
+    fn spawn_read(&self, part: PartInfoPtr) -> Result<JoinHandle<Result<DataSource>>> {
+        let block_reader = self.block_reader.clone();
+        let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
+        let index_reader = self.index_reader.clone();
+        let virtual_reader = self.virtual_reader.clone();
+
+        Ok(tokio::spawn(async_backtrace::location!().frame(
+            async move {
+                let part = FusePartInfo::from_part(&part)?;
+
+                if let Some(index_reader) = index_reader.as_ref() {
+                    let loc =
+                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                            &part.location,
+                            index_reader.index_id(),
+                        );
+                    if let Some(data) = index_reader
+                        .read_parquet_data_by_merge_io(&settings, &loc)
+                        .await
+                    {
+                        // Read from aggregating index.
+                        return Ok::<_, ErrorCode>(DataSource::AggIndex(data));
+                    }
+                }
+
+                // If virtual column file exists, read the data from the virtual columns directly.
+                let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
+                    let loc =
+                        TableMetaLocationGenerator::gen_virtual_block_location(&part.location);
+
+                    virtual_reader
+                        .read_parquet_data_by_merge_io(&settings, &loc)
+                        .await
+                } else {
+                    None
+                };
+
+                let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
+                    &virtual_source.ignore_column_ids
+                } else {
+                    &None
+                };
+
+                let source = block_reader
+                    .read_columns_data_by_merge_io(
+                        &settings,
+                        &part.location,
+                        &part.columns_meta,
+                        ignore_column_ids,
+                    )
+                    .await?;
+
+                Ok(DataSource::Normal((source, virtual_source)))
+            },
+        )))
+    }
+
+    /// Starts reading the next partitions until `prefetch_part_num` partitions or
+    /// `prefetch_max_bytes` bytes are in flight.
+    fn fill_prefetch(&mut self) -> Result<()> {
+        while self.prefetching.has_room() {
+            let Some(part) = self.partitions.steal_one(self.id) else {
+                break;
+            };
+
+            let bytes = FusePartInfo::from_part(&part)?
+                .columns_meta
+                .values()
+                .map(|meta| meta.offset_length().1 as usize)
+                .sum();
+            let handle = self.spawn_read(part.clone())?;
+            self.prefetching.push(part, bytes, handle);
+        }
+        Ok(())
+    }
+
+    async fn prefetch_process(&mut self) -> Result<()> {
+        self.fill_prefetch()?;
+
+        let Some(task) = self.prefetching.pop() else {
+            self.finished = true;
+            return Ok(());
+        };
+
+        // Keep the queue full while the current partition is being decoded.
+        self.fill_prefetch()?;
+
+        let data = task
+            .handle
+            .await
+            .map_err(|cause| match cause.is_cancelled() {
+                true => ErrorCode::AbortedQuery(
+                    "Aborted query, because the prefetch of block data is cancelled.",
+                ),
+                false => ErrorCode::TokioError(format!("Prefetch of block data failed: {cause}")),
+            })??;
+        self.output_data = Some((vec![task.part], vec![data]));
+        Ok(())
+    }
+}
+
+impl<const BLOCKING_IO: bool> Drop for ReadParquetDataSource<BLOCKING_IO> {
+    fn drop(&mut self) {
+        self.prefetching.abort();
+        if self.prefetching.hits + self.prefetching.misses > 0 {
+            info!(
+                "Prefetched the block data of {} partitions, hits: {}, misses: {}",
+                self.prefetching.hits + self.prefetching.misses,
+                self.prefetching.hits,
+                self.prefetching.misses
+            );
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl Processor for ReadParquetDataSource<false> {
     fn name(&self) -> String {
@@ -172,6 +371,7 @@ impl Processor for ReadParquetDataSource<false> {
         }
 
         if self.output.is_finished() {
+            self.prefetching.abort();
             return Ok(Event::Finished);
         }
 
@@ -189,71 +389,23 @@ impl Processor for ReadParquetDataSource<false> {
         Ok(Event::Async)
     }
 
+    fn interrupt(&self) {
+        self.prefetching.abort();
+    }
+
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if self.prefetching.is_enabled() {
+            return self.prefetch_process().await;
+        }
+
         let parts = self.partitions.steal(self.id, self.batch_size);
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
             for part in &parts {
-                let part = part.clone();
-                let block_reader = self.block_reader.clone();
-                let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
-                let index_reader = self.index_reader.clone();
-                let virtual_reader = self.virtual_reader.clone();
-
-                chunks.push(async move {
-                    tokio::spawn(async_backtrace::location!().frame(async move {
-                        let part = FusePartInfo::from_part(&part)?;
-
-                        if let Some(index_reader) = index_reader.as_ref() {
-                            let loc =
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            &part.location,
-                            index_reader.index_id(),
-                        );
-                            if let Some(data) = index_reader
-                                .read_parquet_data_by_merge_io(&settings, &loc)
-                                .await
-                            {
-                                // Read from aggregating index.
-                                return Ok::<_, ErrorCode>(DataSource::AggIndex(data));
-                            }
-                        }
-
-                        // If virtual column file exists, read the data from the virtual columns directly.
-                        let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
-                            let loc = TableMetaLocationGenerator::gen_virtual_block_location(
-                                &part.location,
-                            );
-
-                            virtual_reader
-                                .read_parquet_data_by_merge_io(&settings, &loc)
-                                .await
-                        } else {
-                            None
-                        };
-
-                        let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
-                            &virtual_source.ignore_column_ids
-                        } else {
-                            &None
-                        };
-
-                        let source = block_reader
-                            .read_columns_data_by_merge_io(
-                                &settings,
-                                &part.location,
-                                &part.columns_meta,
-                                ignore_column_ids,
-                            )
-                            .await?;
-
-                        Ok(DataSource::Normal((source, virtual_source)))
-                    }))
-                    .await
-                    .unwrap()
-                });
+                let handle = self.spawn_read(part.clone())?;
+                chunks.push(async move { handle.await.unwrap() });
             }
 
             self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
@@ -264,3 +416,93 @@ impl Processor for ReadParquetDataSource<false> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_base::base::tokio::sync::oneshot;
+
+    use super::*;
+
+    // Prefetches a partition which is being read until the returned sender is dropped.
+    fn prefetch_pending(
+        queue: &mut PrefetchQueue<usize, usize>,
+        part: usize,
+        bytes: usize,
+    ) -> oneshot::Sender<()> {
+        let (tx, rx) = oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            let _ = rx.await;
+            Ok(part)
+        });
+        queue.push(part, bytes, handle);
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_in_flight_bound() {
+        let mut queue = PrefetchQueue::create(2, usize::MAX);
+        let mut reads = vec![];
+        while queue.has_room() {
+            reads.push(prefetch_pending(&mut queue, reads.len(), 1));
+        }
+        assert_eq!(queue.tasks.len(), 2);
+
+        queue.pop().unwrap();
+        assert!(queue.has_room());
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_byte_budget() {
+        let mut queue = PrefetchQueue::create(10, 100);
+        // The first partition is prefetched even if it exceeds the budget.
+        assert!(queue.has_room());
+        let _read = prefetch_pending(&mut queue, 0, 200);
+        assert!(!queue.has_room());
+        queue.pop().unwrap();
+        assert_eq!(queue.bytes, 0);
+
+        let mut reads = vec![];
+        while queue.has_room() {
+            reads.push(prefetch_pending(&mut queue, reads.len(), 60));
+        }
+        assert_eq!(queue.tasks.len(), 2);
+        assert_eq!(queue.bytes, 120);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_hits_and_misses() {
+        let mut queue = PrefetchQueue::create(2, usize::MAX);
+        let read = prefetch_pending(&mut queue, 0, 1);
+        queue.push(1, 1, tokio::spawn(async { Ok(1) }));
+
+        // The first partition is still being read.
+        let task = queue.pop().unwrap();
+        assert_eq!((queue.hits, queue.misses), (0, 1));
+        drop(read);
+        assert_eq!(task.handle.await.unwrap().unwrap(), 0);
+
+        // The second partition is ready.
+        while !queue.tasks[0].handle.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        let task = queue.pop().unwrap();
+        assert_eq!((queue.hits, queue.misses), (1, 1));
+        assert_eq!(task.handle.await.unwrap().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_abort() {
+        let mut queue = PrefetchQueue::create(2, usize::MAX);
+        let _reads = vec![
+            prefetch_pending(&mut queue, 0, 1),
+            prefetch_pending(&mut queue, 1, 1),
+        ];
+
+        // The query is aborted while the partitions are being read.
+        queue.abort();
+        while let Some(task) = queue.pop() {
+            let cause = task.handle.await.unwrap_err();
+            assert!(cause.is_cancelled());
+        }
+    }
+}
@@ -75,10 +75,13 @@ impl FuseTable {
                 let cluster = ctx.get_cluster();
 
                 if !cluster.is_empty() {
-                    nodes_num = cluster.nodes.len();
+                    nodes_num = cluster.get_executor_nodes().len();
                 }
 
-                if (!dry_run && snapshot.segments.len() > nodes_num) || is_lazy {
+                // The blocks of a bucketed table must be pruned before they are distributed,
+                // so that they can be routed by bucket.
+                let bucketed = self.table_info.meta.bucket_spec.is_some();
+                if (!dry_run && !bucketed && snapshot.segments.len() > nodes_num) || is_lazy {
                     let mut segments = Vec::with_capacity(snapshot.segments.len());
                     for (idx, segment_location) in snapshot.segments.iter().enumerate() {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
@@ -187,6 +190,19 @@ impl FuseTable {
             )?
         };
 
+        // The segment statistics index is only useful if there are filters to check.
+        if push_downs.as_ref().is_some_and(|p| p.filters.is_some()) {
+            if let Some(snapshot) = self.read_table_snapshot().await? {
+                if let Some(index) = self.read_segment_stats_index(&snapshot).await? {
+                    pruner.set_segment_stats_index(index);
+                }
+            }
+        }
+
+        if !self.table_info.meta.indexes.is_empty() {
+            pruner.set_inverted_indexes(&self.table_info.meta.indexes);
+        }
+
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
@@ -239,9 +255,13 @@ impl FuseTable {
             .and_then(|p| p.top_k(self.schema().as_ref(), RangeIndex::supported_type))
             .map(|topk| field_default_value(ctx.clone(), &topk.field).map(|d| (topk, d)))
             .transpose()?;
+        let has_top_k = top_k.is_some();
 
-        let (mut statistics, parts) =
+        let (mut statistics, mut parts) =
             Self::to_partitions(Some(&schema), block_metas, &column_nodes, top_k, push_downs);
+        if self.table_info.meta.bucket_spec.is_some() && !has_top_k {
+            parts.kind = PartitionsShuffleKind::Bucket;
+        }
 
         // Update planner statistics.
         statistics.partitions_total = partitions_total;
@@ -467,6 +487,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bucket_id,
         )
     }
 
@@ -510,6 +531,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bucket_id,
         )
     }
 }
@@ -82,12 +82,12 @@ impl FuseTable {
         let mut max_tasks = 1;
         let cluster = ctx.get_cluster();
         if !cluster.is_empty() && settings.get_enable_distributed_recluster()? {
-            max_tasks = cluster.nodes.len();
+            max_tasks = cluster.get_executor_nodes().len();
         }
 
         let schema = self.schema_with_stream();
         let default_cluster_key_id = self.cluster_key_meta.clone().unwrap().0;
-        let block_thresholds = self.get_block_thresholds();
+        let block_thresholds = self.get_block_thresholds(ctx.clone());
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
         let avg_depth_threshold = self.get_option(
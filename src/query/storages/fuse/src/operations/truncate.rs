@@ -25,6 +25,7 @@ use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use uuid::Uuid;
 
+use crate::operations::common::TenantStorageUsage;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -82,6 +83,11 @@ impl FuseTable {
                     update_stream_meta: vec![],
                 })
                 .await?;
+            TenantStorageUsage::instance().apply_delta(
+                &ctx.get_tenant(),
+                self.table_info.meta.statistics.data_bytes,
+                0,
+            );
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq {
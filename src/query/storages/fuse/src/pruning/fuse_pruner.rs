@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_base::base::tokio::sync::Semaphore;
@@ -25,6 +26,7 @@ use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIndex;
 use common_sql::field_default_value;
 use common_sql::BloomIndexColumns;
 use log::warn;
@@ -42,6 +44,7 @@ use storages_common_pruner::TopNPrunner;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::SegmentStatsIndex;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::operations::DeletedSegmentInfo;
@@ -50,6 +53,7 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -179,6 +183,8 @@ pub struct FusePruner {
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
+    pub segment_stats_index: Option<Arc<SegmentStatsIndex>>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
 }
 
 impl FusePruner {
@@ -242,6 +248,8 @@ impl FusePruner {
             pruning_ctx,
             inverse_range_index: None,
             deleted_segments: vec![],
+            segment_stats_index: None,
+            inverted_index_pruner: None,
         })
     }
 
@@ -273,6 +281,11 @@ impl FusePruner {
             SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
         let block_pruner = Arc::new(BlockPruner::create(self.pruning_ctx.clone())?);
 
+        // Discard the segments with the consolidated statistics first, so that they are not read.
+        if let Some(segment_stats_index) = &self.segment_stats_index {
+            segment_locs = segment_pruner.pruning_by_stats_index(segment_stats_index, segment_locs);
+        }
+
         let mut remain = segment_locs.len() % self.max_concurrency;
         let batch_size = segment_locs.len() / self.max_concurrency;
         let mut works = Vec::with_capacity(self.max_concurrency);
@@ -291,6 +304,7 @@ impl FusePruner {
                         let block_pruner = block_pruner.clone();
                         let segment_pruner = segment_pruner.clone();
                         let pruning_ctx = self.pruning_ctx.clone();
+                        let inverted_index_pruner = self.inverted_index_pruner.clone();
 
                         async move {
                             // Build pruning tasks.
@@ -349,7 +363,11 @@ impl FusePruner {
                                 }
                             } else {
                                 for (location, info) in pruned_segments {
-                                    res.extend(block_pruner.pruning(location, &info).await?);
+                                    let blocks = block_pruner.pruning(location, &info).await?;
+                                    match &inverted_index_pruner {
+                                        Some(pruner) => res.extend(pruner.prune(blocks).await?),
+                                        None => res.extend(blocks),
+                                    }
                                 }
                             }
                             Result::<_, ErrorCode>::Ok((res, deleted_segments))
@@ -417,6 +435,11 @@ impl FusePruner {
         let blocks_bloom_pruning_before = stats.get_blocks_bloom_pruning_before() as usize;
         let blocks_bloom_pruning_after = stats.get_blocks_bloom_pruning_after() as usize;
 
+        let blocks_inverted_index_pruning_before =
+            stats.get_blocks_inverted_index_pruning_before() as usize;
+        let blocks_inverted_index_pruning_after =
+            stats.get_blocks_inverted_index_pruning_after() as usize;
+
         common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -424,6 +447,8 @@ impl FusePruner {
             blocks_range_pruning_after,
             blocks_bloom_pruning_before,
             blocks_bloom_pruning_after,
+            blocks_inverted_index_pruning_before,
+            blocks_inverted_index_pruning_after,
         }
     }
 
@@ -434,4 +459,18 @@ impl FusePruner {
     pub fn get_inverse_range_index(&self) -> Option<RangeIndex> {
         self.inverse_range_index.clone()
     }
+
+    pub fn set_segment_stats_index(&mut self, index: Arc<SegmentStatsIndex>) {
+        self.segment_stats_index = Some(index)
+    }
+
+    pub fn set_inverted_indexes(&mut self, indexes: &BTreeMap<String, TableIndex>) {
+        self.inverted_index_pruner = InvertedIndexPruner::try_create(
+            self.pruning_ctx.clone(),
+            &self.table_schema,
+            &self.push_down,
+            indexes,
+        )
+        .map(Arc::new);
+    }
 }
@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::acquire_task_permit;
+use crate::pruning::PruningContext;
+
+/// A `match(column, 'query')` conjunct of the filter, on a column covered by an inverted index.
+struct MatchPredicate {
+    version: String,
+    column_id: ColumnId,
+    query: String,
+}
+
+/// Prunes the blocks without any row matching the `match` predicates,
+/// and records the matched rows of the remaining blocks.
+pub struct InvertedIndexPruner {
+    pruning_ctx: Arc<PruningContext>,
+    predicates: Vec<MatchPredicate>,
+}
+
+impl InvertedIndexPruner {
+    /// Returns None if there are no `match` predicates the indexes can serve.
+    pub fn try_create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        indexes: &BTreeMap<String, TableIndex>,
+    ) -> Option<Self> {
+        let filter = push_down
+            .as_ref()?
+            .filters
+            .as_ref()?
+            .filter
+            .as_expr(&BUILTIN_FUNCTIONS);
+
+        let mut conjuncts = vec![];
+        collect_conjuncts(&filter, &mut conjuncts);
+
+        let predicates = conjuncts
+            .into_iter()
+            .filter_map(|expr| {
+                let (column, query) = match_predicate(expr)?;
+                let column_id = schema.field_with_name(&column).ok()?.column_id();
+                let index = indexes
+                    .values()
+                    .find(|index| index.column_ids.contains(&column_id))?;
+                Some(MatchPredicate {
+                    version: index.version.clone(),
+                    column_id,
+                    query,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if predicates.is_empty() {
+            None
+        } else {
+            Some(Self {
+                pruning_ctx,
+                predicates,
+            })
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        blocks: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_stats = self.pruning_ctx.pruning_stats.clone();
+        pruning_stats.set_blocks_inverted_index_pruning_before(blocks.len() as u64);
+
+        let futures = blocks
+            .into_iter()
+            .map(|(mut index, block_meta)| async move {
+                let matched_rows = self.matched_rows(&block_meta).await?;
+                Ok::<_, ErrorCode>(match matched_rows {
+                    None => Some((index, block_meta)),
+                    Some(rows) if rows.is_empty() => None,
+                    Some(rows) if rows.len() as u64 == block_meta.row_count => {
+                        Some((index, block_meta))
+                    }
+                    Some(rows) => {
+                        index.matched_rows = Some(rows.into_iter().map(|r| r as usize).collect());
+                        Some((index, block_meta))
+                    }
+                })
+            });
+        let blocks = futures::future::try_join_all(futures)
+            .await?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        pruning_stats.set_blocks_inverted_index_pruning_after(blocks.len() as u64);
+        Ok(blocks)
+    }
+
+    /// Returns the rows matching all the predicates, `None` if no index file of the block is available.
+    async fn matched_rows(&self, block_meta: &BlockMeta) -> Result<Option<BTreeSet<u32>>> {
+        let mut indexes: BTreeMap<&str, InvertedIndex> = BTreeMap::new();
+        let mut matched: Option<BTreeSet<u32>> = None;
+        for predicate in &self.predicates {
+            if !indexes.contains_key(predicate.version.as_str()) {
+                let location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &predicate.version,
+                    );
+                let _permit =
+                    acquire_task_permit(self.pruning_ctx.pruning_semaphore.clone()).await?;
+                match self.pruning_ctx.dal.read(&location).await {
+                    Ok(bytes) => {
+                        indexes.insert(&predicate.version, InvertedIndex::from_bytes(&bytes)?);
+                    }
+                    // The block was written before the index is created and not refreshed yet.
+                    Err(e) if e.kind() == opendal::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            let Some(rows) =
+                indexes[predicate.version.as_str()].search(predicate.column_id, &predicate.query)
+            else {
+                continue;
+            };
+            matched = Some(match matched {
+                None => rows.into_iter().collect(),
+                Some(matched) => rows.into_iter().filter(|r| matched.contains(r)).collect(),
+            });
+        }
+        Ok(matched)
+    }
+}
+
+fn collect_conjuncts<'a>(expr: &'a Expr<String>, conjuncts: &mut Vec<&'a Expr<String>>) {
+    match expr {
+        Expr::FunctionCall { function, args, .. }
+            if function.signature.name == "and" || function.signature.name == "and_filters" =>
+        {
+            for arg in args {
+                collect_conjuncts(arg, conjuncts);
+            }
+        }
+        _ => conjuncts.push(expr),
+    }
+}
+
+/// Returns the column name and the query of `match(column, 'query')`.
+fn match_predicate(expr: &Expr<String>) -> Option<(String, String)> {
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return None;
+    };
+    if function.signature.name != "match" || args.len() != 2 {
+        return None;
+    }
+    let column = match unwrap_cast(&args[0]) {
+        Expr::ColumnRef { id, .. } => id.clone(),
+        _ => return None,
+    };
+    let query = match unwrap_cast(&args[1]) {
+        Expr::Constant {
+            scalar: Scalar::String(query),
+            ..
+        } => String::from_utf8_lossy(query).to_string(),
+        _ => return None,
+    };
+    Some((column, query))
+}
+
+fn unwrap_cast(expr: &Expr<String>) -> &Expr<String> {
+    match expr {
+        Expr::Cast { expr, .. } => unwrap_cast(expr),
+        _ => expr,
+    }
+}
@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_metrics::storage::*;
 use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::SegmentStatsIndex;
 
 use crate::io::SegmentsIO;
 use crate::pruning::PruningContext;
@@ -85,4 +86,38 @@ impl SegmentPruner {
         }
         Ok(res)
     }
+
+    /// Prunes the segments with the consolidated segment statistics, without reading them.
+    ///
+    /// The segments which are not indexed are kept, they are checked by [`Self::pruning`].
+    pub fn pruning_by_stats_index(
+        &self,
+        index: &SegmentStatsIndex,
+        segment_locs: Vec<SegmentLocation>,
+    ) -> Vec<SegmentLocation> {
+        let pruning_stats = self.pruning_ctx.pruning_stats.clone();
+        let range_pruner = self.pruning_ctx.range_pruner.clone();
+
+        segment_locs
+            .into_iter()
+            .filter(|segment_location| {
+                let Some(stats) = index.get(&segment_location.location.0) else {
+                    return true;
+                };
+                if range_pruner.should_keep(&stats.col_stats, None) {
+                    return true;
+                }
+
+                // Perf.
+                // The kept segments are accounted by `pruning`.
+                {
+                    metrics_inc_segments_range_pruning_before(1);
+                    metrics_inc_bytes_segment_range_pruning_before(stats.uncompressed_byte_size);
+
+                    pruning_stats.set_segments_range_pruning_before(1);
+                }
+                false
+            })
+            .collect()
+    }
 }
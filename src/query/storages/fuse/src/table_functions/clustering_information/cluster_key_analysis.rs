@@ -0,0 +1,313 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::parse_exprs;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::sessions::TableContext;
+use crate::table_functions::cmp_with_null;
+use crate::table_functions::unwrap_tuple;
+use crate::FuseTable;
+
+/// The max number of blocks read to analyze a cluster key.
+const ANALYZE_SAMPLE_BLOCK_COUNT: usize = 32;
+
+/// The max number of distinct values counted for an expression of a cluster key.
+const ANALYZE_MAX_DISTINCT_VALUES: usize = 10000;
+
+/// The functions whose result order has no relation with the order of their input.
+const HASH_FUNCTIONS: &[&str] = &[
+    "siphash64",
+    "xxhash64",
+    "xxhash32",
+    "city64withseed",
+    "md5",
+    "sha",
+    "sha2",
+    "blake3",
+    "crc32",
+];
+
+/// A cluster key evaluated over a sample of the blocks of a table.
+pub struct ClusterKeyAnalysis {
+    pub cluster_key: String,
+    /// The min and max key of each sampled block.
+    pub block_ranges: Vec<(Vec<Scalar>, Vec<Scalar>)>,
+    /// The components of the key which destroy the order of their input.
+    pub warnings: Vec<String>,
+}
+
+// A hash or modulo inside a component of the cluster key.
+struct OrderDestroyingExpr {
+    component: usize,
+    expr: Expr,
+    is_hash: bool,
+    input_values: HashSet<Scalar>,
+    output_values: HashSet<Scalar>,
+}
+
+impl OrderDestroyingExpr {
+    fn collect(component: usize, expr: &Expr, exprs: &mut Vec<OrderDestroyingExpr>) {
+        let args = match expr {
+            Expr::Cast { expr, .. } => std::slice::from_ref(expr.as_ref()),
+            Expr::FunctionCall { function, args, .. } => {
+                let name = function.signature.name.as_str();
+                let is_hash = HASH_FUNCTIONS.contains(&name);
+                if is_hash || name == "modulo" {
+                    exprs.push(OrderDestroyingExpr {
+                        component,
+                        expr: expr.clone(),
+                        is_hash,
+                        input_values: HashSet::new(),
+                        output_values: HashSet::new(),
+                    });
+                }
+                args.as_slice()
+            }
+            Expr::LambdaFunctionCall { args, .. } => args.as_slice(),
+            Expr::Constant { .. } | Expr::ColumnRef { .. } => &[],
+        };
+        for arg in args {
+            Self::collect(component, arg, exprs);
+        }
+    }
+
+    fn input(&self) -> &Expr {
+        match &self.expr {
+            Expr::FunctionCall { args, .. } => &args[0],
+            _ => unreachable!("order destroying expr must be a function call"),
+        }
+    }
+
+    fn sample(&mut self, evaluator: &Evaluator, num_rows: usize) -> Result<()> {
+        let input = eval_column(evaluator, self.input(), num_rows)?;
+        add_distinct_values(&mut self.input_values, &input);
+        let output = eval_column(evaluator, &self.expr, num_rows)?;
+        add_distinct_values(&mut self.output_values, &output);
+        Ok(())
+    }
+
+    // A hash scatters any input, a modulo only scatters the input wrapping around it.
+    fn warning(&self, keys: &[Expr]) -> Option<String> {
+        let key = keys[self.component].sql_display();
+        let expr = self.expr.sql_display();
+        if self.is_hash {
+            Some(format!(
+                "cluster key component {} `{}` does not preserve the order of its input, `{}` is a hash",
+                self.component + 1,
+                key,
+                expr
+            ))
+        } else if self.input_values.len() > self.output_values.len() {
+            Some(format!(
+                "cluster key component {} `{}` does not preserve the order of its input, `{}` maps {}{} distinct values of `{}` to {} in the sampled blocks",
+                self.component + 1,
+                key,
+                expr,
+                if self.input_values.len() >= ANALYZE_MAX_DISTINCT_VALUES {
+                    "over "
+                } else {
+                    ""
+                },
+                self.input_values.len(),
+                self.input().sql_display(),
+                self.output_values.len()
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl FuseTable {
+    /// Evaluates the cluster key over a sample of the blocks of the table, the table is
+    /// not required to be clustered by the key.
+    #[async_backtrace::framed]
+    pub async fn analyze_cluster_key(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        cluster_key: &str,
+    ) -> Result<ClusterKeyAnalysis> {
+        let table: Arc<dyn Table> = Arc::new(self.clone());
+        let keys = parse_exprs(ctx.clone(), table, cluster_key)?;
+        let keys = if keys.len() == 1 {
+            unwrap_tuple(&keys[0]).unwrap_or(keys)
+        } else {
+            keys
+        };
+
+        // Read only the columns of the key, in the order of the table schema.
+        let field_indices = keys
+            .iter()
+            .flat_map(|key| key.column_refs().into_keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let offsets = field_indices
+            .iter()
+            .enumerate()
+            .map(|(offset, index)| (*index, offset))
+            .collect::<HashMap<_, _>>();
+        let projected_keys = keys
+            .iter()
+            .map(|key| key.project_column_ref(|index| offsets[index]))
+            .collect::<Vec<_>>();
+
+        let mut order_destroying = vec![];
+        for (component, key) in projected_keys.iter().enumerate() {
+            OrderDestroyingExpr::collect(component, key, &mut order_destroying);
+        }
+
+        let mut block_ranges = vec![];
+        let blocks = self.sample_blocks(ctx.clone()).await?;
+        if !blocks.is_empty() && !field_indices.is_empty() {
+            let block_reader = self.create_block_reader(
+                ctx.clone(),
+                Projection::Columns(field_indices),
+                false,
+                false,
+                false,
+            )?;
+            let settings = ReadSettings::from_ctx(&ctx)?;
+            let storage_format = self.get_write_settings().storage_format;
+            let func_ctx = ctx.get_function_context()?;
+            for block_meta in blocks {
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let num_rows = block.num_rows();
+                let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+                for expr in order_destroying.iter_mut() {
+                    expr.sample(&evaluator, num_rows)?;
+                }
+                if let Some(range) = key_range(&block, &func_ctx, &projected_keys)? {
+                    block_ranges.push(range);
+                }
+            }
+        }
+
+        let warnings = order_destroying
+            .iter()
+            .filter_map(|expr| expr.warning(&keys))
+            .collect();
+        Ok(ClusterKeyAnalysis {
+            cluster_key: cluster_key.to_string(),
+            block_ranges,
+            warnings,
+        })
+    }
+
+    // Picks the blocks evenly from the segments of the current snapshot.
+    async fn sample_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<Arc<BlockMeta>>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+        if snapshot.segments.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let step = snapshot.segments.len().div_ceil(ANALYZE_SAMPLE_BLOCK_COUNT);
+        let locations = snapshot
+            .segments
+            .iter()
+            .step_by(step)
+            .cloned()
+            .collect::<Vec<_>>();
+        let blocks_per_segment = ANALYZE_SAMPLE_BLOCK_COUNT.div_ceil(locations.len());
+
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&locations, false)
+            .await?;
+        let mut blocks = vec![];
+        for segment in segments {
+            let segment = segment?;
+            let step = segment.blocks.len().div_ceil(blocks_per_segment).max(1);
+            blocks.extend(segment.blocks.iter().step_by(step).cloned());
+        }
+        blocks.truncate(ANALYZE_SAMPLE_BLOCK_COUNT);
+        Ok(blocks)
+    }
+}
+
+fn eval_column(evaluator: &Evaluator, expr: &Expr, num_rows: usize) -> Result<Column> {
+    Ok(evaluator
+        .run(expr)?
+        .convert_to_full_column(expr.data_type(), num_rows))
+}
+
+fn add_distinct_values(values: &mut HashSet<Scalar>, column: &Column) {
+    for value in column.iter() {
+        if values.len() >= ANALYZE_MAX_DISTINCT_VALUES {
+            return;
+        }
+        values.insert(value.to_owned());
+    }
+}
+
+// The min and max key of the rows of the block.
+fn key_range(
+    block: &DataBlock,
+    func_ctx: &FunctionContext,
+    keys: &[Expr],
+) -> Result<Option<(Vec<Scalar>, Vec<Scalar>)>> {
+    let num_rows = block.num_rows();
+    if num_rows == 0 {
+        return Ok(None);
+    }
+    let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+    let columns = keys
+        .iter()
+        .map(|key| eval_column(&evaluator, key, num_rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    let row = |i: usize| -> Vec<Scalar> {
+        columns
+            .iter()
+            .map(|column| column.index(i).unwrap().to_owned())
+            .collect()
+    };
+    let cmp_rows = |a: &[Scalar], b: &[Scalar]| a.iter().cmp_by(b.iter(), cmp_with_null);
+
+    let mut min = row(0);
+    let mut max = min.clone();
+    for i in 1..num_rows {
+        let current = row(i);
+        if cmp_rows(&current, &min) == Ordering::Less {
+            min = current;
+        } else if cmp_rows(&current, &max) == Ordering::Greater {
+            max = current;
+        }
+    }
+    Ok(Some((min, max)))
+}
@@ -46,6 +46,8 @@ use crate::Table;
 pub struct ClusteringInformation<'a> {
     pub ctx: Arc<dyn TableContext>,
     pub table: &'a FuseTable,
+    /// A cluster key to evaluate instead of the cluster key of the table.
+    pub proposed_key: Option<String>,
 }
 
 struct ClusteringStatistics {
@@ -71,12 +73,24 @@ impl Default for ClusteringStatistics {
 }
 
 impl<'a> ClusteringInformation<'a> {
-    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
-        Self { ctx, table }
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        table: &'a FuseTable,
+        proposed_key: Option<String>,
+    ) -> Self {
+        Self {
+            ctx,
+            table,
+            proposed_key,
+        }
     }
 
     #[async_backtrace::framed]
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
+        if let Some(proposed_key) = &self.proposed_key {
+            return self.get_proposed_key_clustering_info(proposed_key).await;
+        }
+
         if self.table.cluster_key_meta.is_none() {
             return Err(ErrorCode::UnclusteredTable(format!(
                 "Unclustered table {}",
@@ -84,9 +98,13 @@ impl<'a> ClusteringInformation<'a> {
             )));
         }
 
+        let cluster_key = self
+            .table
+            .cluster_key_str()
+            .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
         let snapshot = self.table.read_table_snapshot().await?;
         if snapshot.is_none() {
-            return self.build_block(ClusteringStatistics::default());
+            return self.build_block(cluster_key, ClusteringStatistics::default());
         }
         let snapshot = snapshot.unwrap();
 
@@ -139,81 +157,54 @@ impl<'a> ClusteringInformation<'a> {
         }
         drop(snapshot);
 
-        // calculate overlaps and depth.
-        let mut stats = Vec::new();
-        // key: the block index.
-        // value: (overlaps, depth).
-        let mut unfinished_parts: HashMap<u64, (usize, usize)> = HashMap::new();
-        for (_, (start, end)) in points_map
-            .into_iter()
-            .sorted_by(|(a, _), (b, _)| a.iter().cmp_by(b.iter(), cmp_with_null))
-        {
-            let point_depth = unfinished_parts.len() + start.len();
-
-            unfinished_parts.values_mut().for_each(|(overlaps, depth)| {
-                *overlaps += start.len();
-                *depth = cmp::max(*depth, point_depth);
-            });
-
-            start.iter().for_each(|&idx| {
-                unfinished_parts.insert(idx, (point_depth - 1, point_depth));
-            });
+        let info = calculate_statistics(
+            points_map,
+            total_block_count,
+            constant_block_count,
+            unclustered_block_count,
+        );
+        self.build_block(cluster_key, info)
+    }
 
-            end.iter().for_each(|idx| {
-                if let Some(v) = unfinished_parts.remove(idx) {
-                    stats.push(v);
-                }
-            });
+    // The statistics of the proposed key are estimated over a sample of the blocks, as if
+    // the blocks were sorted by the key.
+    #[async_backtrace::framed]
+    async fn get_proposed_key_clustering_info(&self, proposed_key: &str) -> Result<DataBlock> {
+        let analysis = self
+            .table
+            .analyze_cluster_key(self.ctx.clone(), proposed_key)
+            .await?;
+        for warning in &analysis.warnings {
+            warn!("clustering_information: {}", warning);
+            self.ctx.push_warning(warning.clone());
         }
-        if !unfinished_parts.is_empty() {
-            warn!(
-                "clustering_information: unfinished_parts is not empty after calculate the blocks overlaps"
-            );
+        if analysis.block_ranges.is_empty() {
+            return self.build_block(&analysis.cluster_key, ClusteringStatistics::default());
         }
 
-        let mut sum_overlap = 0;
-        let mut sum_depth = 0;
-        let length = stats.len();
-        let mp = stats
-            .into_iter()
-            .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
-                sum_overlap += overlap;
-                sum_depth += depth;
-
-                let bucket = get_buckets(depth);
-                acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
-                acc
-            });
-        // round the float to 4 decimal places.
-        let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
-        let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
-
-        let map_len = mp.len();
-        let objects = mp.into_iter().fold(
-            serde_json::Map::with_capacity(map_len),
-            |mut acc, (bucket, count)| {
-                acc.insert(format!("{:05}", bucket), json!(count));
-                acc
-            },
-        );
-        let block_depth_histogram = JsonValue::Object(objects);
-        let info = ClusteringStatistics {
-            total_block_count,
-            constant_block_count,
-            unclustered_block_count,
-            average_overlaps,
-            average_depth,
-            block_depth_histogram,
-        };
+        let mut points_map: HashMap<Vec<Scalar>, (Vec<u64>, Vec<u64>)> = HashMap::new();
+        let mut constant_block_count = 0;
+        let total_block_count = analysis.block_ranges.len() as u64;
+        for (index, (min, max)) in analysis.block_ranges.into_iter().enumerate() {
+            let index = index as u64;
+            if min == max {
+                constant_block_count += 1;
+            }
+            points_map
+                .entry(min)
+                .and_modify(|v| v.0.push(index))
+                .or_insert((vec![index], vec![]));
+            points_map
+                .entry(max)
+                .and_modify(|v| v.1.push(index))
+                .or_insert((vec![], vec![index]));
+        }
 
-        self.build_block(info)
+        let info = calculate_statistics(points_map, total_block_count, constant_block_count, 0);
+        self.build_block(&analysis.cluster_key, info)
     }
 
-    fn build_block(&self, info: ClusteringStatistics) -> Result<DataBlock> {
-        let cluster_key = self
-            .table
-            .cluster_key_str()
-            .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
+    fn build_block(&self, cluster_key: &str, info: ClusteringStatistics) -> Result<DataBlock> {
         Ok(DataBlock::new(
             vec![
                 BlockEntry::new(
@@ -287,6 +278,86 @@ impl<'a> ClusteringInformation<'a> {
     }
 }
 
+// Calculates the overlaps and depth of the blocks.
+//
+// Key of `points_map`: The cluster statistics points.
+// Value: 0: The block indexes with key as min value;
+//        1: The block indexes with key as max value;
+fn calculate_statistics(
+    points_map: HashMap<Vec<Scalar>, (Vec<u64>, Vec<u64>)>,
+    total_block_count: u64,
+    constant_block_count: u64,
+    unclustered_block_count: u64,
+) -> ClusteringStatistics {
+    // calculate overlaps and depth.
+    let mut stats = Vec::new();
+    // key: the block index.
+    // value: (overlaps, depth).
+    let mut unfinished_parts: HashMap<u64, (usize, usize)> = HashMap::new();
+    for (_, (start, end)) in points_map
+        .into_iter()
+        .sorted_by(|(a, _), (b, _)| a.iter().cmp_by(b.iter(), cmp_with_null))
+    {
+        let point_depth = unfinished_parts.len() + start.len();
+
+        unfinished_parts.values_mut().for_each(|(overlaps, depth)| {
+            *overlaps += start.len();
+            *depth = cmp::max(*depth, point_depth);
+        });
+
+        start.iter().for_each(|&idx| {
+            unfinished_parts.insert(idx, (point_depth - 1, point_depth));
+        });
+
+        end.iter().for_each(|idx| {
+            if let Some(v) = unfinished_parts.remove(idx) {
+                stats.push(v);
+            }
+        });
+    }
+    if !unfinished_parts.is_empty() {
+        warn!(
+            "clustering_information: unfinished_parts is not empty after calculate the blocks overlaps"
+        );
+    }
+
+    let mut sum_overlap = 0;
+    let mut sum_depth = 0;
+    let length = stats.len();
+    let mp = stats
+        .into_iter()
+        .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
+            sum_overlap += overlap;
+            sum_depth += depth;
+
+            let bucket = get_buckets(depth);
+            acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
+            acc
+        });
+    // round the float to 4 decimal places.
+    let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
+    let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
+
+    let map_len = mp.len();
+    let objects = mp.into_iter().fold(
+        serde_json::Map::with_capacity(map_len),
+        |mut acc, (bucket, count)| {
+            acc.insert(format!("{:05}", bucket), json!(count));
+            acc
+        },
+    );
+    let block_depth_histogram = JsonValue::Object(objects);
+
+    ClusteringStatistics {
+        total_block_count,
+        constant_block_count,
+        unclustered_block_count,
+        average_overlaps,
+        average_depth,
+        block_depth_histogram,
+    }
+}
+
 /// The histogram contains buckets with widths:
 /// 1 to 16 with increments of 1.
 /// For buckets larger than 16, increments of twice the width of the previous bucket (e.g. 32, 64, 128, …).
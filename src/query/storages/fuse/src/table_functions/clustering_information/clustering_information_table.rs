@@ -20,6 +20,7 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_meta_app::schema::TableIdent;
@@ -33,8 +34,8 @@ use common_pipeline_sources::AsyncSourcer;
 
 use super::clustering_information::ClusteringInformation;
 use crate::sessions::TableContext;
-use crate::table_functions::parse_db_tb_args;
 use crate::table_functions::string_literal;
+use crate::table_functions::string_value;
 use crate::table_functions::TableArgs;
 use crate::table_functions::TableFunction;
 use crate::FuseTable;
@@ -46,6 +47,7 @@ pub struct ClusteringInformationTable {
     table_info: TableInfo,
     arg_database_name: String,
     arg_table_name: String,
+    arg_proposed_key: Option<String>,
 }
 
 impl ClusteringInformationTable {
@@ -55,8 +57,16 @@ impl ClusteringInformationTable {
         table_id: u64,
         table_args: TableArgs,
     ) -> Result<Arc<dyn TableFunction>> {
-        let (arg_database_name, arg_table_name) =
-            parse_db_tb_args(&table_args, FUSE_FUNC_CLUSTERING)?;
+        let args = table_args.expect_all_positioned(FUSE_FUNC_CLUSTERING, None)?;
+        if !matches!(args.len(), 2 | 3) {
+            return Err(ErrorCode::BadArguments(format!(
+                "expecting <database>, <table_name> and optional <proposed_key> (as string literals), but got {:?}",
+                args
+            )));
+        }
+        let arg_database_name = string_value(&args[0])?;
+        let arg_table_name = string_value(&args[1])?;
+        let arg_proposed_key = args.get(2).map(string_value).transpose()?;
 
         let engine = FUSE_FUNC_CLUSTERING.to_owned();
 
@@ -76,6 +86,7 @@ impl ClusteringInformationTable {
             table_info,
             arg_database_name,
             arg_table_name,
+            arg_proposed_key,
         }))
     }
 }
@@ -101,10 +112,14 @@ impl Table for ClusteringInformationTable {
     }
 
     fn table_args(&self) -> Option<TableArgs> {
-        Some(TableArgs::new_positioned(vec![
+        let mut args = vec![
             string_literal(self.arg_database_name.as_str()),
             string_literal(self.arg_table_name.as_str()),
-        ]))
+        ];
+        if let Some(proposed_key) = &self.arg_proposed_key {
+            args.push(string_literal(proposed_key.as_str()));
+        }
+        Some(TableArgs::new_positioned(args))
     }
 
     fn read_data(
@@ -121,6 +136,7 @@ impl Table for ClusteringInformationTable {
                     output,
                     self.arg_database_name.to_owned(),
                     self.arg_table_name.to_owned(),
+                    self.arg_proposed_key.to_owned(),
                 )
             },
             1,
@@ -135,6 +151,7 @@ struct ClusteringInformationSource {
     ctx: Arc<dyn TableContext>,
     arg_database_name: String,
     arg_table_name: String,
+    arg_proposed_key: Option<String>,
 }
 
 impl ClusteringInformationSource {
@@ -143,12 +160,14 @@ impl ClusteringInformationSource {
         output: Arc<OutputPort>,
         arg_database_name: String,
         arg_table_name: String,
+        arg_proposed_key: Option<String>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, ClusteringInformationSource {
             ctx,
             finish: false,
             arg_table_name,
             arg_database_name,
+            arg_proposed_key,
         })
     }
 }
@@ -180,7 +199,7 @@ impl AsyncSource for ClusteringInformationSource {
         let tbl = FuseTable::try_from_table(tbl.as_ref())?;
 
         Ok(Some(
-            ClusteringInformation::new(self.ctx.clone(), tbl)
+            ClusteringInformation::new(self.ctx.clone(), tbl, self.arg_proposed_key.clone())
                 .get_clustering_info()
                 .await?,
         ))
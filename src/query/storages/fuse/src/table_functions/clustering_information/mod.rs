@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cluster_key_analysis;
 #[allow(clippy::module_inception)]
 mod clustering_information;
 mod clustering_information_table;
 
+pub use cluster_key_analysis::ClusterKeyAnalysis;
 pub use clustering_information::ClusteringInformation;
 pub use clustering_information_table::ClusteringInformationTable;
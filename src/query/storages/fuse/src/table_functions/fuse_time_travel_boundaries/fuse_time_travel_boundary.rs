@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+
+use crate::FuseTable;
+
+pub struct FuseTimeTravelBoundary<'a> {
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseTimeTravelBoundary<'a> {
+    pub fn new(table: &'a FuseTable) -> Self {
+        Self { table }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_boundaries(self) -> Result<DataBlock> {
+        let Some((earliest, latest)) = self.table.time_travel_boundaries().await? else {
+            return Ok(DataBlock::empty_with_schema(Arc::new(
+                FuseTimeTravelBoundary::schema().into(),
+            )));
+        };
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![earliest.snapshot_id.simple().to_string()]),
+            TimestampType::from_opt_data(vec![earliest.timestamp.map(|ts| ts.timestamp_micros())]),
+            StringType::from_data(vec![latest.snapshot_id.simple().to_string()]),
+            TimestampType::from_opt_data(vec![latest.timestamp.map(|ts| ts.timestamp_micros())]),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("earliest_snapshot_id", TableDataType::String),
+            TableField::new(
+                "earliest_timestamp",
+                TableDataType::Timestamp.wrap_nullable(),
+            ),
+            TableField::new("latest_snapshot_id", TableDataType::String),
+            TableField::new("latest_timestamp", TableDataType::Timestamp.wrap_nullable()),
+        ])
+    }
+}
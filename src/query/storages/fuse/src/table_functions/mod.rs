@@ -19,8 +19,10 @@ mod fuse_encodings;
 mod fuse_segments;
 mod fuse_snapshots;
 mod fuse_statistics;
+mod fuse_time_travel_boundaries;
 mod table_args;
 
+pub use clustering_information::ClusterKeyAnalysis;
 pub use clustering_information::ClusteringInformation;
 pub use clustering_information::ClusteringInformationTable;
 use common_catalog::table_args::TableArgs;
@@ -36,4 +38,5 @@ pub use fuse_segments::FuseSegmentTable;
 pub use fuse_snapshots::FuseSnapshot;
 pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
+pub use fuse_time_travel_boundaries::FuseTimeTravelBoundaryTable;
 pub use table_args::*;
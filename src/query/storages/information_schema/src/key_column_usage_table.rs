@@ -26,19 +26,22 @@ pub struct KeyColumnUsageTable {}
 
 impl KeyColumnUsageTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
-        let query = "SELECT \
-        NULL as constraint_catalog, \
-        NULL as constraint_schema, \
-        NULL as constraint_name, \
-        NULL as table_catalog, \
-        NULL as table_schema, \
-        NULL as table_name, \
-        NULL as column_name, \
-        NULL as ordinal_position, \
-        NULL as position_in_unique_constraint, \
-        NULL as referenced_table_schema, \
-        NULL as referenced_table_name, \
-        NULL as referenced_column_name"
+        // Constraints are not supported, the view is always empty.
+        let query = "SELECT
+            CAST(NULL AS VARCHAR NULL) AS constraint_catalog,
+            CAST(NULL AS VARCHAR NULL) AS constraint_schema,
+            CAST(NULL AS VARCHAR NULL) AS constraint_name,
+            CAST(NULL AS VARCHAR NULL) AS table_catalog,
+            CAST(NULL AS VARCHAR NULL) AS table_schema,
+            CAST(NULL AS VARCHAR NULL) AS table_name,
+            CAST(NULL AS VARCHAR NULL) AS column_name,
+            CAST(NULL AS INT UNSIGNED NULL) AS ordinal_position,
+            CAST(NULL AS INT UNSIGNED NULL) AS position_in_unique_constraint,
+            CAST(NULL AS VARCHAR NULL) AS referenced_table_schema,
+            CAST(NULL AS VARCHAR NULL) AS referenced_table_name,
+            CAST(NULL AS VARCHAR NULL) AS referenced_column_name
+        FROM system.one
+        WHERE FALSE"
             .to_string();
 
         let mut options = BTreeMap::new();
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_view::view_table::ViewTable;
+use common_storages_view::view_table::QUERY;
+
+pub struct ReferentialConstraintsTable {}
+
+impl ReferentialConstraintsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        // Foreign keys are not supported, the view is always empty.
+        let query = "SELECT
+            CAST(NULL AS VARCHAR NULL) AS constraint_catalog,
+            CAST(NULL AS VARCHAR NULL) AS constraint_schema,
+            CAST(NULL AS VARCHAR NULL) AS constraint_name,
+            CAST(NULL AS VARCHAR NULL) AS unique_constraint_catalog,
+            CAST(NULL AS VARCHAR NULL) AS unique_constraint_schema,
+            CAST(NULL AS VARCHAR NULL) AS unique_constraint_name,
+            CAST(NULL AS VARCHAR NULL) AS match_option,
+            CAST(NULL AS VARCHAR NULL) AS update_rule,
+            CAST(NULL AS VARCHAR NULL) AS delete_rule,
+            CAST(NULL AS VARCHAR NULL) AS table_name,
+            CAST(NULL AS VARCHAR NULL) AS referenced_table_name
+        FROM system.one
+        WHERE FALSE"
+            .to_string();
+
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), query);
+        let table_info = TableInfo {
+            desc: "'information_schema'.'referential_constraints'".to_string(),
+            name: "referential_constraints".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        ViewTable::create(table_info)
+    }
+}
@@ -30,11 +30,12 @@ impl SchemataTable {
             name AS catalog_name,
             name AS schema_name,
             'default' AS schema_owner,
-            NULL AS default_character_set_catalog,
-            NULL AS default_character_set_schema,
-            NULL AS default_character_set_name,
-            NULL AS default_collation_name,
-            NULL AS sql_path
+            CAST(NULL AS VARCHAR NULL) AS default_character_set_catalog,
+            CAST(NULL AS VARCHAR NULL) AS default_character_set_schema,
+            'utf8mb4' AS default_character_set_name,
+            'utf8mb4_general_ci' AS default_collation_name,
+            CAST(NULL AS VARCHAR NULL) AS sql_path,
+            'NO' AS default_encryption
         FROM system.databases;";
 
         let mut options = BTreeMap::new();
@@ -30,15 +30,14 @@ impl ViewsTable {
             database AS table_catalog,
             database AS table_schema,
             name AS table_name,
-            NULL AS view_definition,
+            view_query AS view_definition,
             'NONE' AS check_option,
-            0 AS is_updatable,
-            engine = 'MaterializedView' AS is_insertable_into,
-            0 AS is_trigger_updatable,
-            0 AS is_trigger_deletable,
-            0 AS is_trigger_insertable_into
+            'NO' AS is_updatable,
+            owner AS definer,
+            'utf8mb4' AS character_set_client,
+            'utf8mb4_general_ci' AS collation_connection
         FROM system.tables
-        WHERE engine LIKE '%View';";
+        WHERE engine = 'VIEW';";
 
         let mut options = BTreeMap::new();
         options.insert(QUERY.to_string(), query.to_string());
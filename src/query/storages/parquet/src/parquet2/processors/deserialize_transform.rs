@@ -201,6 +201,8 @@ impl Parquet2DeserializeTransform {
             self.copy_status.add_chunk(path, FileStatus {
                 num_rows_loaded,
                 error: None,
+                num_rows_skipped: 0,
+                errors: vec![],
             })
         }
         Ok(res)
@@ -255,6 +255,8 @@ impl PartitionPruner {
                 copy_status.add_chunk(path, FileStatus {
                     num_rows_loaded: file_meta.num_rows,
                     error: None,
+                    num_rows_skipped: 0,
+                    errors: vec![],
                 });
             }
             stats.partitions_total += file_meta.row_groups.len();
@@ -428,6 +428,8 @@ fn prune_and_generate_partitions(
             copy_status.add_chunk(location, FileStatus {
                 num_rows_loaded: rows_read,
                 error: None,
+                num_rows_skipped: 0,
+                errors: vec![],
             });
         }
     }
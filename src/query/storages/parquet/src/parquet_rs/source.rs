@@ -165,6 +165,8 @@ impl Processor for ParquetSource {
                         self.copy_status.add_chunk(path.as_str(), FileStatus {
                             num_rows_loaded: num_rows,
                             error: None,
+                            num_rows_skipped: 0,
+                            errors: vec![],
                         });
                         blocks.extend(bs);
                     }
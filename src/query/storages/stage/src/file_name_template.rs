@@ -0,0 +1,235 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::uuid;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_compress::CompressAlgorithm;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::stage_table::unload_format_name;
+use crate::stage_table::unload_path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Placeholder {
+    QueryId,
+    NodeId,
+    ThreadId,
+    Part,
+    Date,
+    Ext,
+}
+
+impl Placeholder {
+    fn parse(name: &str) -> Option<Placeholder> {
+        match name {
+            "query_id" => Some(Placeholder::QueryId),
+            "node_id" => Some(Placeholder::NodeId),
+            "thread_id" => Some(Placeholder::ThreadId),
+            "part" => Some(Placeholder::Part),
+            "date" => Some(Placeholder::Date),
+            "ext" => Some(Placeholder::Ext),
+            _ => None,
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        matches!(self, Placeholder::ThreadId | Placeholder::Part)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    /// The numbers are left padded with zeros to `width`, e.g. `{part:05d}`.
+    Placeholder {
+        placeholder: Placeholder,
+        width: usize,
+    },
+}
+
+/// Template of the names of the files unloaded by `COPY INTO <location>`, e.g.
+/// `export/{query_id}/{part:05d}.{ext}`.
+///
+/// The supported placeholders are `query_id`, `node_id`, `thread_id`, `part`, `date` and `ext`.
+/// `part` is numbered across all the writers of the query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileNameTemplate {
+    segments: Vec<Segment>,
+}
+
+impl FileNameTemplate {
+    pub fn parse(template: &str) -> Result<FileNameTemplate> {
+        let mut segments = vec![];
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find(['{', '}']) {
+            literal.push_str(&rest[..start]);
+            if rest[start..].starts_with('}') {
+                return Err(invalid_template(template, "unmatched '}'"));
+            }
+            let Some(len) = rest[start..].find('}') else {
+                return Err(invalid_template(template, "unmatched '{'"));
+            };
+            let (name, width) = match rest[start + 1..start + len].split_once(':') {
+                None => (&rest[start + 1..start + len], 0),
+                Some((name, spec)) => {
+                    let width = spec
+                        .strip_prefix('0')
+                        .and_then(|spec| spec.strip_suffix('d'))
+                        .and_then(|width| width.parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            invalid_template(template, &format!("invalid format '{spec}'"))
+                        })?;
+                    (name, width)
+                }
+            };
+            let placeholder = Placeholder::parse(name).ok_or_else(|| {
+                invalid_template(template, &format!("unknown placeholder '{name}'"))
+            })?;
+            if width > 0 && !placeholder.is_number() {
+                return Err(invalid_template(
+                    template,
+                    &format!("placeholder '{name}' can not be padded"),
+                ));
+            }
+            if !literal.is_empty() {
+                segments.push(Segment::Literal(std::mem::take(&mut literal)));
+            }
+            segments.push(Segment::Placeholder { placeholder, width });
+            rest = &rest[start + len + 1..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        if segments.is_empty() {
+            return Err(invalid_template(template, "empty template"));
+        }
+        Ok(FileNameTemplate { segments })
+    }
+
+    /// Checks the names generated by the template can not collide.
+    ///
+    /// A template without `part` can only name a single file.
+    pub fn check(&self, single: bool) -> Result<()> {
+        if !single && !self.contains(Placeholder::Part) {
+            return Err(ErrorCode::BadArguments(
+                "FILE_NAME_TEMPLATE must contain the placeholder {part} to name multiple files, or use SINGLE = true",
+            ));
+        }
+        Ok(())
+    }
+
+    fn contains(&self, placeholder: Placeholder) -> bool {
+        self.segments.iter().any(|segment| {
+            matches!(segment, Segment::Placeholder { placeholder: p, .. } if *p == placeholder)
+        })
+    }
+
+    fn render(
+        &self,
+        naming: &UnloadFileNaming,
+        thread_id: usize,
+        part: usize,
+        ext: &str,
+    ) -> String {
+        let mut name = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => name.push_str(literal),
+                Segment::Placeholder { placeholder, width } => {
+                    let width = *width;
+                    let value = match placeholder {
+                        Placeholder::QueryId => naming.query_id.clone(),
+                        Placeholder::NodeId => naming.node_id.clone(),
+                        Placeholder::Date => naming.date.clone(),
+                        Placeholder::Ext => ext.to_string(),
+                        Placeholder::ThreadId => format!("{:0>width$}", thread_id),
+                        Placeholder::Part => format!("{:0>width$}", part),
+                    };
+                    name.push_str(&value);
+                }
+            }
+        }
+        name
+    }
+}
+
+fn invalid_template(template: &str, reason: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!("Invalid FILE_NAME_TEMPLATE '{template}': {reason}"))
+}
+
+/// Names the files unloaded by one `COPY INTO <location>`.
+pub struct UnloadFileNaming {
+    uuid: String,
+    template: Option<FileNameTemplate>,
+    query_id: String,
+    node_id: String,
+    date: String,
+    next_part: AtomicUsize,
+}
+
+impl UnloadFileNaming {
+    pub fn create(
+        ctx: &Arc<dyn TableContext>,
+        table_info: &StageTableInfo,
+    ) -> Result<Arc<UnloadFileNaming>> {
+        let template = table_info
+            .file_name_template
+            .as_deref()
+            .map(FileNameTemplate::parse)
+            .transpose()?;
+        Ok(Arc::new(UnloadFileNaming {
+            uuid: uuid::Uuid::new_v4().to_string(),
+            template,
+            query_id: ctx.get_id(),
+            node_id: ctx.get_cluster().local_id.clone(),
+            date: Utc::now().format("%Y-%m-%d").to_string(),
+            next_part: AtomicUsize::new(0),
+        }))
+    }
+
+    /// Returns the path of the next file written by the writer `group_id`.
+    pub fn next_path(
+        &self,
+        table_info: &StageTableInfo,
+        group_id: usize,
+        batch_id: usize,
+        compression: Option<CompressAlgorithm>,
+    ) -> String {
+        let Some(template) = &self.template else {
+            return unload_path(table_info, &self.uuid, group_id, batch_id, compression);
+        };
+
+        let mut ext = unload_format_name(table_info);
+        if let Some(c) = compression {
+            ext = format!("{}.{}", ext, c.extension());
+        }
+        let part = self.next_part.fetch_add(1, Ordering::Relaxed);
+        let name = template.render(self, group_id, part, &ext);
+        let path = &table_info.files_info.path;
+        if path.ends_with('/') {
+            format!("{}{}", path, name)
+        } else {
+            format!("{}/{}", path, name)
+        }
+    }
+}
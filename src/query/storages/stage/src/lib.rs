@@ -14,8 +14,10 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod file_name_template;
 mod parquet_file;
 mod row_based_file;
 mod stage_table;
 
+pub use file_name_template::FileNameTemplate;
 pub use stage_table::StageTable;
@@ -21,6 +21,7 @@ use common_formats::FileFormatOptionsExt;
 use common_pipeline_core::Pipeline;
 use opendal::Operator;
 
+use crate::file_name_template::UnloadFileNaming;
 use crate::parquet_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::parquet_file::sink_processor::ParquetFileSink;
 
@@ -34,7 +35,7 @@ pub(crate) fn append_data_to_parquet_files(
     op: Operator,
     max_file_size: usize,
     max_threads: usize,
-    uuid: String,
+    naming: Arc<UnloadFileNaming>,
     group_id: &std::sync::atomic::AtomicUsize,
 ) -> Result<()> {
     pipeline.try_resize(1)?;
@@ -57,7 +58,8 @@ pub(crate) fn append_data_to_parquet_files(
             table_info.clone(),
             output_format,
             op.clone(),
-            uuid.clone(),
+            ctx.get_copy_status(),
+            naming.clone(),
             gid,
         )
     })?;
@@ -26,10 +26,11 @@ use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
+use common_storage::CopyStatus;
 use opendal::Operator;
 
+use crate::file_name_template::UnloadFileNaming;
 use crate::parquet_file::block_batch::BlockBatch;
-use crate::stage_table::unload_path;
 
 pub struct ParquetFileSink {
     input: Arc<InputPort>,
@@ -40,10 +41,12 @@ pub struct ParquetFileSink {
     output_data: Vec<u8>,
 
     data_accessor: Operator,
+    copy_status: Arc<CopyStatus>,
 
-    uuid: String,
+    naming: Arc<UnloadFileNaming>,
     group_id: usize,
     batch_id: usize,
+    num_rows: usize,
 }
 
 impl ParquetFileSink {
@@ -52,7 +55,8 @@ impl ParquetFileSink {
         table_info: StageTableInfo,
         output_format: Box<dyn OutputFormat>,
         data_accessor: Operator,
-        uuid: String,
+        copy_status: Arc<CopyStatus>,
+        naming: Arc<UnloadFileNaming>,
         group_id: usize,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(ParquetFileSink {
@@ -62,9 +66,11 @@ impl ParquetFileSink {
             input_data: None,
             output_data: vec![],
             data_accessor,
-            uuid,
+            copy_status,
+            naming,
             group_id,
             batch_id: 0,
+            num_rows: 0,
         })))
     }
 }
@@ -103,7 +109,9 @@ impl Processor for ParquetFileSink {
         let block = self.input_data.take().unwrap();
         let block_meta = block.get_owned_meta().unwrap();
         let blocks = BlockBatch::downcast_from(block_meta).unwrap();
+        self.num_rows = 0;
         for b in blocks.blocks {
+            self.num_rows += b.num_rows();
             self.output_format.serialize_block(&b)?;
         }
         self.output_data = self.output_format.finalize()?;
@@ -113,15 +121,14 @@ impl Processor for ParquetFileSink {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         assert!(!self.output_data.is_empty());
-        let path = unload_path(
-            &self.table_info,
-            &self.uuid,
-            self.group_id,
-            self.batch_id,
-            None,
-        );
+        let path = self
+            .naming
+            .next_path(&self.table_info, self.group_id, self.batch_id, None);
         let data = mem::take(&mut self.output_data);
+        let size = data.len();
         self.data_accessor.write(&path, data).await?;
+        self.copy_status
+            .add_unloaded_file(&path, self.num_rows, size);
         self.batch_id += 1;
         Ok(())
     }
@@ -15,13 +15,19 @@
 use common_expression::BlockMetaInfo;
 use common_expression::DataBlock;
 
+#[derive(Debug)]
+pub struct FileOutputBuffer {
+    pub buffer: Vec<u8>,
+    pub num_rows: usize,
+}
+
 #[derive(Debug)]
 pub struct FileOutputBuffers {
-    pub buffers: Vec<Vec<u8>>,
+    pub buffers: Vec<FileOutputBuffer>,
 }
 
 impl FileOutputBuffers {
-    pub fn create_block(buffers: Vec<Vec<u8>>) -> DataBlock {
+    pub fn create_block(buffers: Vec<FileOutputBuffer>) -> DataBlock {
         DataBlock::empty_with_meta(Box::new(FileOutputBuffers { buffers }))
     }
 }
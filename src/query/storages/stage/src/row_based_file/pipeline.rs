@@ -22,6 +22,7 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_sources::input_formats::InputContext;
 use opendal::Operator;
 
+use crate::file_name_template::UnloadFileNaming;
 use crate::row_based_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::row_based_file::serialize_processor::SerializeProcessor;
 use crate::row_based_file::sink_processor::RowBasedFileSink;
@@ -37,7 +38,7 @@ pub(crate) fn append_data_to_row_based_files(
     op: Operator,
     max_file_size: usize,
     max_threads: usize,
-    uuid: String,
+    naming: Arc<UnloadFileNaming>,
     group_id: &std::sync::atomic::AtomicUsize,
 ) -> Result<()> {
     let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
@@ -77,8 +78,9 @@ pub(crate) fn append_data_to_row_based_files(
             input,
             table_info.clone(),
             op.clone(),
+            ctx.get_copy_status(),
             prefix.clone(),
-            uuid.clone(),
+            naming.clone(),
             gid,
             compression,
         )
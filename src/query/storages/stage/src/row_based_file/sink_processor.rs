@@ -27,10 +27,11 @@ use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
+use common_storage::CopyStatus;
 use opendal::Operator;
 
+use crate::file_name_template::UnloadFileNaming;
 use crate::row_based_file::buffers::FileOutputBuffers;
-use crate::stage_table::unload_path;
 
 pub struct RowBasedFileSink {
     input: Arc<InputPort>,
@@ -42,11 +43,13 @@ pub struct RowBasedFileSink {
     output_data: Vec<u8>,
 
     data_accessor: Operator,
+    copy_status: Arc<CopyStatus>,
     prefix: Vec<u8>,
 
-    uuid: String,
+    naming: Arc<UnloadFileNaming>,
     group_id: usize,
     batch_id: usize,
+    num_rows: usize,
 
     compression: Option<CompressAlgorithm>,
 }
@@ -56,8 +59,9 @@ impl RowBasedFileSink {
         input: Arc<InputPort>,
         table_info: StageTableInfo,
         data_accessor: Operator,
+        copy_status: Arc<CopyStatus>,
         prefix: Vec<u8>,
-        uuid: String,
+        naming: Arc<UnloadFileNaming>,
         group_id: usize,
         compression: Option<CompressAlgorithm>,
     ) -> Result<ProcessorPtr> {
@@ -66,10 +70,12 @@ impl RowBasedFileSink {
             input,
             input_data: None,
             data_accessor,
+            copy_status,
             prefix,
-            uuid,
+            naming,
             group_id,
             batch_id: 0,
+            num_rows: 0,
             output_data: vec![],
             compression,
         })))
@@ -110,11 +116,17 @@ impl Processor for RowBasedFileSink {
         let block = self.input_data.take().unwrap();
         let block_meta = block.get_owned_meta().unwrap();
         let buffers = FileOutputBuffers::downcast_from(block_meta).unwrap();
-        let size = buffers.buffers.iter().map(|b| b.len()).sum::<usize>();
+        let size = buffers
+            .buffers
+            .iter()
+            .map(|b| b.buffer.len())
+            .sum::<usize>();
         let mut output = Vec::with_capacity(self.prefix.len() + size);
         output.extend_from_slice(self.prefix.as_slice());
+        self.num_rows = 0;
         for b in buffers.buffers {
-            output.extend_from_slice(b.as_slice());
+            self.num_rows += b.num_rows;
+            output.extend_from_slice(b.buffer.as_slice());
         }
         if let Some(compression) = self.compression {
             output = CompressCodec::from(compression).compress_all(&output)?;
@@ -125,15 +137,17 @@ impl Processor for RowBasedFileSink {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let path = unload_path(
+        let path = self.naming.next_path(
             &self.table_info,
-            &self.uuid,
             self.group_id,
             self.batch_id,
             self.compression,
         );
         let data = mem::take(&mut self.output_data);
+        let size = data.len();
         self.data_accessor.write(&path, data).await?;
+        self.copy_status
+            .add_unloaded_file(&path, self.num_rows, size);
         self.batch_id += 1;
         Ok(())
     }
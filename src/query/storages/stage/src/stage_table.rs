@@ -18,7 +18,6 @@ use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
-use common_base::base::uuid;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfo;
@@ -51,6 +50,7 @@ use opendal::Operator;
 use opendal::Scheme;
 use parking_lot::Mutex;
 
+use crate::file_name_template::UnloadFileNaming;
 use crate::parquet_file::append_data_to_parquet_files;
 use crate::row_based_file::append_data_to_row_based_files;
 /// TODO: we need to track the data metrics in stage table.
@@ -226,6 +226,7 @@ impl Table for StageTable {
             self.table_info.is_select,
             projection,
             self.table_info.default_values.clone(),
+            stage_table_info.collect_all_errors,
         )?);
         debug!("start copy splits feeder in {}", ctx.get_cluster().local_id);
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
@@ -257,7 +258,7 @@ impl Table for StageTable {
 
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let fmt = self.table_info.stage_info.file_format_params.clone();
-        let uuid = uuid::Uuid::new_v4().to_string();
+        let naming = UnloadFileNaming::create(&ctx, &self.table_info)?;
         let group_id = AtomicUsize::new(0);
         match fmt {
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
@@ -267,7 +268,7 @@ impl Table for StageTable {
                 op,
                 max_file_size,
                 max_threads,
-                uuid,
+                naming.clone(),
                 &group_id,
             )?,
             _ => append_data_to_row_based_files(
@@ -277,7 +278,7 @@ impl Table for StageTable {
                 op,
                 max_file_size,
                 max_threads,
-                uuid,
+                naming,
                 &group_id,
             )?,
         };
@@ -292,7 +293,7 @@ impl Table for StageTable {
         ))
     }
 
-    fn get_block_thresholds(&self) -> BlockThresholds {
+    fn get_block_thresholds(&self, _ctx: Arc<dyn TableContext>) -> BlockThresholds {
         let guard = self.block_compact_threshold.lock();
         (*guard).expect("must success")
     }
@@ -310,11 +311,7 @@ pub fn unload_path(
     batch_id: usize,
     compression: Option<CompressAlgorithm>,
 ) -> String {
-    let format_name = format!(
-        "{:?}",
-        stage_table_info.stage_info.file_format_params.get_type()
-    )
-    .to_ascii_lowercase();
+    let format_name = unload_format_name(stage_table_info);
 
     let suffix: &str = &compression
         .map(|c| format!(".{}", c.extension()))
@@ -334,3 +331,11 @@ pub fn unload_path(
         )
     }
 }
+
+pub fn unload_format_name(stage_table_info: &StageTableInfo) -> String {
+    format!(
+        "{:?}",
+        stage_table_info.stage_info.file_format_params.get_type()
+    )
+    .to_ascii_lowercase()
+}
@@ -179,6 +179,11 @@ impl StreamPruner {
         let blocks_bloom_pruning_before = stats.get_blocks_bloom_pruning_before() as usize;
         let blocks_bloom_pruning_after = stats.get_blocks_bloom_pruning_after() as usize;
 
+        let blocks_inverted_index_pruning_before =
+            stats.get_blocks_inverted_index_pruning_before() as usize;
+        let blocks_inverted_index_pruning_after =
+            stats.get_blocks_inverted_index_pruning_after() as usize;
+
         common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -186,6 +191,8 @@ impl StreamPruner {
             blocks_range_pruning_after,
             blocks_bloom_pruning_before,
             blocks_bloom_pruning_after,
+            blocks_inverted_index_pruning_before,
+            blocks_inverted_index_pruning_after,
         }
     }
 
@@ -320,6 +327,7 @@ impl StreamPruner {
                         block_location: block_location.clone(),
                         segment_location: "".to_string(),
                         snapshot_location: None,
+                        matched_rows: None,
                     },
                     block,
                 ))
@@ -383,6 +391,7 @@ impl StreamPruner {
                             block_location: block_meta.as_ref().location.0.clone(),
                             segment_location: "".to_string(),
                             snapshot_location: None,
+                            matched_rows: None,
                         },
                         block_meta.clone(),
                     ))
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An audit event of a DDL or grant/revoke statement.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub tenant_id: String,
+    pub node_id: String,
+    pub query_id: String,
+    pub query_tag: String,
+    pub sql_user: String,
+    pub client_address: String,
+
+    // What is changed.
+    pub action: String,
+    pub object_type: String,
+    pub object_name: String,
+
+    // Result.
+    pub success: bool,
+    pub exception_code: i32,
+    pub exception_text: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("object_type", TableDataType::String),
+            TableField::new("object_name", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new(
+                "exception_code",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new("exception_text", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [
+            &self.tenant_id,
+            &self.node_id,
+            &self.query_id,
+            &self.query_tag,
+            &self.sql_user,
+            &self.client_address,
+            &self.action,
+            &self.object_type,
+            &self.object_name,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.exception_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.exception_text.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
@@ -60,6 +60,7 @@ impl SyncSystemTable for CachesTable {
         let table_snapshot_cache = cache_manager.get_table_snapshot_cache();
         let table_snapshot_statistic_cache = cache_manager.get_table_snapshot_statistics_cache();
         let segment_info_cache = cache_manager.get_table_segment_cache();
+        let segment_stats_index_cache = cache_manager.get_segment_stats_index_cache();
         let bloom_index_filter_cache = cache_manager.get_bloom_index_filter_cache();
         let bloom_index_meta_cache = cache_manager.get_bloom_index_meta_cache();
         let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
@@ -87,6 +88,13 @@ impl SyncSystemTable for CachesTable {
             size.push(segment_info_cache.size());
         }
 
+        if let Some(segment_stats_index_cache) = segment_stats_index_cache {
+            nodes.push(local_node.clone().into_bytes());
+            names.push("segment_stats_index_cache");
+            num_items.push(segment_stats_index_cache.len() as u64);
+            size.push(segment_stats_index_cache.size());
+        }
+
         if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
             nodes.push(local_node.clone().into_bytes());
             names.push("bloom_index_filter_cache");
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::catalog::Catalog;
@@ -25,15 +26,20 @@ use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
 
 pub struct DatabasesTable {
     table_info: TableInfo,
@@ -51,7 +57,7 @@ impl AsyncSystemTable for DatabasesTable {
     async fn get_full_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
         let catalogs = CatalogManager::instance();
@@ -69,8 +75,51 @@ impl AsyncSystemTable for DatabasesTable {
 
         let visibility_checker = ctx.get_visibility_checker().await?;
 
+        // The owners recorded by GRANT OWNERSHIP, which take precedence over the creators.
+        let db_owners = UserApiProvider::instance()
+            .get_ownerships(&tenant)
+            .await?
+            .into_iter()
+            .filter_map(|ownership| match ownership.object {
+                GrantObjectByID::Database { db_id, .. } => Some((db_id, ownership.role)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        // Only get the databases of the `name = 'xx'` filters, e.g. the filters on
+        // `schema_name` of `information_schema.schemata`.
+        let mut filtered_db_names = vec![];
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if col_name == "name" {
+                    if let Scalar::String(s) = scalar {
+                        if let Ok(name) = String::from_utf8(s.clone()) {
+                            if !filtered_db_names.contains(&name) {
+                                filtered_db_names.push(name);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
         for (ctl_name, catalog) in catalogs.into_iter() {
-            let databases = catalog.list_databases(tenant.as_str()).await?;
+            let databases = if filtered_db_names.is_empty() {
+                catalog.list_databases(tenant.as_str()).await?
+            } else {
+                let mut databases = vec![];
+                for name in &filtered_db_names {
+                    if let Ok(database) = catalog.get_database(tenant.as_str(), name).await {
+                        databases.push(database);
+                    }
+                }
+                databases
+            };
             let final_dbs = databases
                 .into_iter()
                 .filter(|db| visibility_checker.check_database_visibility(&ctl_name, db.name()))
@@ -82,13 +131,16 @@ impl AsyncSystemTable for DatabasesTable {
                 db_names.push(db_name);
                 let id = db.get_db_info().ident.db_id;
                 db_id.push(id);
-                owners.push(
-                    db.get_db_info()
+                let owner = match db_owners.get(&id) {
+                    Some(role) => Some(role.as_bytes().to_vec()),
+                    None => db
+                        .get_db_info()
                         .meta
                         .owner
                         .as_ref()
                         .map(|v| v.owner_role_name.as_bytes().to_vec()),
-                );
+                };
+                owners.push(owner);
             }
         }
 
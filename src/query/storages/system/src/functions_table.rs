@@ -22,17 +22,21 @@ use common_expression::types::BooleanType;
 use common_expression::types::StringType;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
+use common_expression::FunctionKind;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::GENERAL_WINDOW_FUNCTIONS;
+use common_meta_app::principal::UDFDefinition;
 use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_sql::TypeChecker;
 use common_users::UserApiProvider;
+use itertools::Itertools;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -68,7 +72,8 @@ impl AsyncSystemTable for FunctionsTable {
         );
         scalar_func_names.sort();
         let aggregate_function_factory = AggregateFunctionFactory::instance();
-        let aggr_func_names = aggregate_function_factory.registered_names();
+        let mut aggr_func_names = aggregate_function_factory.registered_names();
+        aggr_func_names.sort();
         let enable_experimental_rbac_check =
             ctx.get_settings().get_enable_experimental_rbac_check()?;
         let udfs = if enable_experimental_rbac_check {
@@ -81,79 +86,154 @@ impl AsyncSystemTable for FunctionsTable {
             FunctionsTable::get_udfs(ctx).await?
         };
 
-        let names: Vec<&str> = scalar_func_names
-            .iter()
-            .chain(&aggr_func_names)
-            .chain(udfs.iter().map(|udf| &udf.name))
-            .map(|x| x.as_str())
-            .collect();
-
-        let builtin_func_len = scalar_func_names.len() + aggr_func_names.len();
-
-        let is_builtin = (0..names.len())
-            .map(|i| i < builtin_func_len)
-            .collect::<Vec<bool>>();
-
-        let is_aggregate = (0..names.len())
-            .map(|i| i >= scalar_func_names.len() && i < builtin_func_len)
-            .collect::<Vec<bool>>();
-
-        let definitions = (0..names.len())
-            .map(|i| {
-                if i < builtin_func_len {
-                    "".to_string()
-                } else {
-                    udfs.get(i - builtin_func_len)
-                        .map_or("".to_string(), |udf| udf.definition.to_string())
-                }
-            })
-            .collect::<Vec<String>>();
-
-        let categories = (0..names.len())
-            .map(|i| if i < builtin_func_len { "" } else { "UDF" })
-            .collect::<Vec<&str>>();
-
-        let descriptions = (0..names.len())
-            .map(|i| {
-                if i < builtin_func_len {
-                    ""
-                } else {
-                    udfs.get(i - builtin_func_len)
-                        .map_or("", |udf| udf.description.as_str())
-                }
-            })
-            .collect::<Vec<&str>>();
-
-        let syntaxes = (0..names.len())
-            .map(|i| {
-                if i < builtin_func_len {
-                    "".to_string()
-                } else {
-                    udfs.get(i - builtin_func_len)
-                        .map_or("".to_string(), |udf| udf.definition.to_string())
+        let mut rows = Vec::new();
+        for name in &scalar_func_names {
+            let property = BUILTIN_FUNCTIONS.get_property(name).unwrap_or_default();
+            let kind = match property.kind {
+                FunctionKind::Scalar => "scalar",
+                FunctionKind::SRF => "set-returning",
+            };
+            let builtin_row = |arguments: String, return_type: String| FunctionRow {
+                name: name.clone(),
+                is_builtin: true,
+                is_aggregate: false,
+                kind,
+                arguments,
+                return_type,
+                is_variadic: BUILTIN_FUNCTIONS.is_variadic(name),
+                is_deterministic: !property.non_deterministic,
+                example: BUILTIN_FUNCTIONS
+                    .get_example(name)
+                    .unwrap_or_default()
+                    .to_string(),
+                ..Default::default()
+            };
+
+            // Functions built by factories, sugar functions and function-like syntaxes
+            // have no fixed signatures, list them once.
+            let signatures = BUILTIN_FUNCTIONS.registered_signatures(name);
+            if signatures.is_empty() {
+                rows.push(builtin_row(String::new(), String::new()));
+            }
+            for signature in signatures {
+                rows.push(builtin_row(
+                    signature.args_type.iter().join(", "),
+                    signature.return_type.to_string(),
+                ));
+            }
+        }
+        for name in aggr_func_names {
+            let example = BUILTIN_FUNCTIONS.get_example(&name).unwrap_or_default();
+            rows.push(FunctionRow {
+                is_builtin: true,
+                is_aggregate: true,
+                kind: "aggregate",
+                is_deterministic: true,
+                example: example.to_string(),
+                name,
+                ..Default::default()
+            });
+        }
+        for name in GENERAL_WINDOW_FUNCTIONS {
+            rows.push(FunctionRow {
+                name: name.to_string(),
+                is_builtin: true,
+                kind: "window",
+                is_deterministic: true,
+                example: BUILTIN_FUNCTIONS
+                    .get_example(name)
+                    .unwrap_or_default()
+                    .to_string(),
+                ..Default::default()
+            });
+        }
+        for udf in udfs {
+            let (arguments, return_type, is_deterministic) = match &udf.definition {
+                UDFDefinition::LambdaUDF(lambda) => {
+                    (lambda.parameters.join(", "), String::new(), true)
                 }
-            })
-            .collect::<Vec<String>>();
+                UDFDefinition::UDFServer(server) => (
+                    server.arg_types.iter().join(", "),
+                    server.return_type.to_string(),
+                    false,
+                ),
+            };
+            rows.push(FunctionRow {
+                name: udf.name,
+                definition: udf.definition.to_string(),
+                category: "UDF",
+                description: udf.description,
+                kind: "udf",
+                arguments,
+                return_type,
+                is_deterministic,
+                ..Default::default()
+            });
+        }
 
-        let examples = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
         Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(names),
-            BooleanType::from_data(is_builtin),
-            BooleanType::from_data(is_aggregate),
+            StringType::from_data(rows.iter().map(|row| row.name.as_str()).collect::<Vec<_>>()),
+            BooleanType::from_data(rows.iter().map(|row| row.is_builtin).collect::<Vec<_>>()),
+            BooleanType::from_data(rows.iter().map(|row| row.is_aggregate).collect::<Vec<_>>()),
+            StringType::from_data(
+                rows.iter()
+                    .map(|row| row.definition.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(rows.iter().map(|row| row.category).collect::<Vec<_>>()),
+            StringType::from_data(
+                rows.iter()
+                    .map(|row| row.description.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(
+                rows.iter()
+                    .map(|row| row.definition.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(
+                rows.iter()
+                    .map(|row| row.example.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(rows.iter().map(|row| row.kind).collect::<Vec<_>>()),
             StringType::from_data(
-                definitions
-                    .iter()
-                    .map(String::as_str)
-                    .collect::<Vec<&str>>(),
+                rows.iter()
+                    .map(|row| row.arguments.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(
+                rows.iter()
+                    .map(|row| row.return_type.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            BooleanType::from_data(rows.iter().map(|row| row.is_variadic).collect::<Vec<_>>()),
+            BooleanType::from_data(
+                rows.iter()
+                    .map(|row| row.is_deterministic)
+                    .collect::<Vec<_>>(),
             ),
-            StringType::from_data(categories),
-            StringType::from_data(descriptions),
-            StringType::from_data(syntaxes.iter().map(String::as_str).collect::<Vec<&str>>()),
-            StringType::from_data(examples),
         ]))
     }
 }
 
+/// A row of `system.functions`, which describes an overload of a function.
+#[derive(Default)]
+struct FunctionRow {
+    name: String,
+    is_builtin: bool,
+    is_aggregate: bool,
+    definition: String,
+    category: &'static str,
+    description: String,
+    example: String,
+    kind: &'static str,
+    arguments: String,
+    return_type: String,
+    is_variadic: bool,
+    is_deterministic: bool,
+}
+
 impl FunctionsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
@@ -165,6 +245,11 @@ impl FunctionsTable {
             TableField::new("description", TableDataType::String),
             TableField::new("syntax", TableDataType::String),
             TableField::new("example", TableDataType::String),
+            TableField::new("kind", TableDataType::String),
+            TableField::new("arguments", TableDataType::String),
+            TableField::new("return_type", TableDataType::String),
+            TableField::new("is_variadic", TableDataType::Boolean),
+            TableField::new("is_deterministic", TableDataType::Boolean),
         ]);
 
         let table_info = TableInfo {
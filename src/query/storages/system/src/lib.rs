@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -55,10 +56,14 @@ mod tables_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod tenant_quotas_table;
 mod tracing_table;
 mod users_table;
 mod util;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -108,5 +113,6 @@ pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use tenant_quotas_table::TenantQuotasTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
@@ -12,10 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Duration;
 
+use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -33,29 +32,32 @@ use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
 
 pub struct ProcessesTable {
     table_info: TableInfo,
 }
 
 #[async_trait::async_trait]
-impl SyncSystemTable for ProcessesTable {
+impl AsyncSystemTable for ProcessesTable {
     const NAME: &'static str = "system.processes";
 
-    const IS_LOCAL: bool = false;
-
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
     }
 
-    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let processes_info = ctx.get_processes_info();
-
-        let local_node = ctx.get_cluster().local_id.clone();
-
-        let mut nodes: Vec<Vec<u8>> = Vec::with_capacity(processes_info.len());
+    // The processes of the other nodes are collected by the coordinator,
+    // so the table is read on the local node only.
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let processes_info = ctx.get_cluster_processes_info().await?;
+
+        let mut nodes = Vec::with_capacity(processes_info.len());
         let mut processes_id = Vec::with_capacity(processes_info.len());
         let mut processes_type = Vec::with_capacity(processes_info.len());
         let mut processes_host = Vec::with_capacity(processes_info.len());
@@ -68,56 +70,44 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_data_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_write_progress_rows = Vec::with_capacity(processes_info.len());
+        let mut processes_write_progress_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
-
-        for process_info in &processes_info {
-            let data_metrics = &process_info.data_metrics;
-            let scan_progress = process_info.scan_progress_value.clone().unwrap_or_default();
-            let time = process_info
-                .created_time
-                .elapsed()
-                .unwrap_or(Duration::from_secs(0))
-                .as_secs();
-
-            nodes.push(local_node.clone().into_bytes());
-            processes_id.push(process_info.id.clone().into_bytes());
-            processes_type.push(process_info.typ.clone().into_bytes());
-            processes_state.push(process_info.state.to_string().into_bytes());
-            processes_database.push(process_info.database.clone().into_bytes());
-            processes_host.push(ProcessesTable::process_host(&process_info.client_address));
-            processes_user.push(
-                ProcessesTable::process_option_value(process_info.user.clone())
-                    .name
-                    .into_bytes(),
-            );
-            processes_extra_info.push(
-                ProcessesTable::process_option_value(process_info.session_extra_info.clone())
-                    .into_bytes(),
-            );
+        let mut processes_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_query_tag = Vec::with_capacity(processes_info.len());
+        let mut processes_fragment_ids = Vec::with_capacity(processes_info.len());
+
+        for process_info in processes_info {
+            let fragment_ids = process_info
+                .fragment_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            nodes.push(process_info.node_id.into_bytes());
+            processes_id.push(process_info.id.into_bytes());
+            processes_type.push(process_info.typ.into_bytes());
+            processes_host.push(process_info.host.map(|host| host.into_bytes()));
+            processes_user.push(process_info.user.into_bytes());
+            processes_state.push(process_info.state.into_bytes());
+            processes_database.push(process_info.database.into_bytes());
+            processes_extra_info.push(process_info.extra_info.into_bytes());
             processes_memory_usage.push(process_info.memory_usage);
-            processes_scan_progress_read_rows.push(scan_progress.rows as u64);
-            processes_scan_progress_read_bytes.push(scan_progress.bytes as u64);
+            processes_data_read_bytes.push(process_info.data_read_bytes);
+            processes_data_write_bytes.push(process_info.data_write_bytes);
+            processes_scan_progress_read_rows.push(process_info.scan_progress_read_rows);
+            processes_scan_progress_read_bytes.push(process_info.scan_progress_read_bytes);
+            processes_write_progress_rows.push(process_info.write_progress_rows);
+            processes_write_progress_bytes.push(process_info.write_progress_bytes);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
-            processes_time.push(time);
-
-            if let Some(data_metrics) = data_metrics {
-                processes_data_read_bytes.push(data_metrics.get_read_bytes() as u64);
-                processes_data_write_bytes.push(data_metrics.get_write_bytes() as u64);
-            } else {
-                processes_data_read_bytes.push(0);
-                processes_data_write_bytes.push(0);
-            }
-
-            // Status info.
-            processes_status.push(
-                process_info
-                    .status_info
-                    .clone()
-                    .unwrap_or("".to_owned())
-                    .into_bytes(),
-            );
+            processes_time.push(process_info.time);
+            processes_status.push(process_info.status.into_bytes());
+            processes_query_id.push(process_info.query_id.into_bytes());
+            processes_query_tag.push(process_info.query_tag.into_bytes());
+            processes_fragment_ids.push(fragment_ids.into_bytes());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -134,9 +124,14 @@ impl SyncSystemTable for ProcessesTable {
             UInt64Type::from_data(processes_data_write_bytes),
             UInt64Type::from_data(processes_scan_progress_read_rows),
             UInt64Type::from_data(processes_scan_progress_read_bytes),
+            UInt64Type::from_data(processes_write_progress_rows),
+            UInt64Type::from_data(processes_write_progress_bytes),
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_data(processes_query_id),
+            StringType::from_data(processes_query_tag),
+            StringType::from_data(processes_fragment_ids),
         ]))
     }
 }
@@ -172,12 +167,24 @@ impl ProcessesTable {
                 "scan_progress_read_bytes",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new(
+                "write_progress_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "write_progress_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
             TableField::new(
                 "mysql_connection_id",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
+            // ids of the plan fragments executed by the node, separated by comma
+            TableField::new("fragment_ids", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -193,15 +200,6 @@ impl ProcessesTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(ProcessesTable { table_info })
-    }
-
-    fn process_host(client_address: &Option<SocketAddr>) -> Option<Vec<u8>> {
-        client_address.as_ref().map(|s| s.to_string().into_bytes())
-    }
-
-    fn process_option_value<T>(opt: Option<T>) -> T
-    where T: Default {
-        opt.unwrap_or_default()
+        AsyncOneBlockSystemTable::create(ProcessesTable { table_info })
     }
 }
@@ -56,7 +56,7 @@ where S: Serializer {
     s.serialize_str(t.format("%Y-%m-%d").to_string().as_str())
 }
 
-fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
+pub(crate) fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
 where S: Serializer {
     let t = NaiveDateTime::from_timestamp_opt(
         dt / 1_000_000,
@@ -88,6 +88,7 @@ pub struct QueryLogElement {
     pub query_id: String,
     pub query_kind: String,
     pub query_text: String,
+    pub query_tag: String,
 
     #[serde(serialize_with = "date_str")]
     pub event_date: i32,
@@ -166,6 +167,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("query_id", TableDataType::String),
             TableField::new("query_kind", TableDataType::String),
             TableField::new("query_text", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
             TableField::new("event_date", TableDataType::Date),
             TableField::new("event_time", TableDataType::Timestamp),
             TableField::new("query_start_time", TableDataType::Timestamp),
@@ -316,6 +318,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.query_text.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_tag.as_bytes().to_vec()).as_ref());
         columns
             .next()
             .unwrap()
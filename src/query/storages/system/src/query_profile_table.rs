@@ -55,6 +55,7 @@ impl QueryProfileTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("query_id", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
             TableField::new("operator_id", TableDataType::Number(NumberDataType::UInt32)),
             TableField::new("execution_info", TableDataType::Variant),
         ]);
@@ -87,12 +88,14 @@ impl SyncSystemTable for QueryProfileTable {
         let query_profs = profile_mgr.list_all();
 
         let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(query_profs.len());
+        let mut query_tags: Vec<Vec<u8>> = Vec::with_capacity(query_profs.len());
         let mut operator_ids: Vec<u32> = Vec::with_capacity(query_profs.len());
         let mut execution_infos: Vec<Vec<u8>> = Vec::with_capacity(query_profs.len());
 
         for prof in query_profs.iter() {
             for plan_prof in prof.operator_profiles.iter() {
                 query_ids.push(prof.query_id.clone().into_bytes());
+                query_tags.push(prof.query_tag.clone().into_bytes());
                 operator_ids.push(plan_prof.id);
 
                 let execution_info = encode_operator_execution_info(&plan_prof.execution_info);
@@ -103,6 +106,8 @@ impl SyncSystemTable for QueryProfileTable {
         let block = DataBlock::new_from_columns(vec![
             // query_id
             StringType::from_data(query_ids),
+            // query_tag
+            StringType::from_data(query_tags),
             // operator_id
             UInt32Type::from_data(operator_ids),
             // execution_info
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::catalog::Catalog;
@@ -32,13 +33,22 @@ use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::schema::ListTableOptions;
+use common_meta_app::schema::ListTableWithOptionsReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_storages_view::view_table::QUERY;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+use futures::stream;
+use futures::StreamExt;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
+use crate::util::find_like_filter;
 
 pub struct TablesTable<const WITH_HISTORY: bool> {
     table_info: TableInfo,
@@ -47,6 +57,19 @@ pub struct TablesTable<const WITH_HISTORY: bool> {
 pub type TablesTableWithHistory = TablesTable<true>;
 pub type TablesTableWithoutHistory = TablesTable<false>;
 
+// The maximum number of the meta requests on the databases in flight.
+const MAX_CONCURRENT_META_REQUESTS: usize = 16;
+
+// The columns filled by the table statistics, which might be read from the storage.
+const STATISTICS_COLUMNS: [&str; 6] = [
+    "num_rows",
+    "data_size",
+    "data_compressed_size",
+    "index_size",
+    "number_of_segments",
+    "number_of_blocks",
+];
+
 #[async_trait::async_trait]
 pub trait HistoryAware {
     const TABLE_NAME: &'static str;
@@ -54,6 +77,7 @@ pub trait HistoryAware {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         db_name: &str,
+        options: ListTableOptions,
     ) -> Result<Vec<Arc<dyn Table>>>;
 }
 
@@ -65,8 +89,15 @@ impl HistoryAware for TablesTable<true> {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         database_name: &str,
+        options: ListTableOptions,
     ) -> Result<Vec<Arc<dyn Table>>> {
-        catalog.list_tables_history(tenant, database_name).await
+        let options = ListTableOptions {
+            include_dropped: true,
+            ..options
+        };
+        let req = ListTableWithOptionsReq::new(tenant, database_name, options);
+        let (tables, _) = catalog.list_tables_with_options(req).await?;
+        Ok(tables)
     }
 }
 
@@ -78,8 +109,15 @@ impl HistoryAware for TablesTable<false> {
         catalog: &Arc<dyn Catalog>,
         tenant: &str,
         database_name: &str,
+        options: ListTableOptions,
     ) -> Result<Vec<Arc<dyn Table>>> {
-        catalog.list_tables(tenant, database_name).await
+        let options = ListTableOptions {
+            include_dropped: false,
+            ..options
+        };
+        let req = ListTableWithOptionsReq::new(tenant, database_name, options);
+        let (tables, _) = catalog.list_tables_with_options(req).await?;
+        Ok(tables)
     }
 }
 
@@ -108,6 +146,37 @@ where TablesTable<T>: HistoryAware
             .map(|e| (e.name(), e.clone()))
             .collect();
 
+        let require_columns = |columns: &[&str]| {
+            push_downs
+                .as_ref()
+                .and_then(|push_downs| push_downs.projection.as_ref())
+                .map_or(true, |projection| {
+                    projection
+                        .project_schema(&Self::schema())
+                        .fields()
+                        .iter()
+                        .any(|field| columns.contains(&field.name().as_str()))
+                })
+        };
+        // Only fetch the statistics of the tables if they are required, e.g. `SHOW TABLES`
+        // lists the tables from the meta only.
+        let require_statistics = require_columns(&STATISTICS_COLUMNS);
+
+        // The owners recorded by GRANT OWNERSHIP, which take precedence over the creators.
+        let table_owners = if require_columns(&["owner"]) {
+            UserApiProvider::instance()
+                .get_ownerships(&tenant)
+                .await?
+                .into_iter()
+                .filter_map(|ownership| match ownership.object {
+                    GrantObjectByID::Table { table_id, .. } => Some((table_id, ownership.role)),
+                    _ => None,
+                })
+                .collect::<HashMap<_, _>>()
+        } else {
+            HashMap::new()
+        };
+
         let mut catalogs = vec![];
         let mut databases = vec![];
 
@@ -115,6 +184,33 @@ where TablesTable<T>: HistoryAware
 
         let visibility_checker = ctx.get_visibility_checker().await?;
 
+        // Push the filters on the table name and engine down to the catalogs, so that
+        // only the matched tables are loaded from the meta.
+        let mut options = ListTableOptions::default();
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(s) = scalar {
+                    if let Ok(value) = String::from_utf8(s.clone()) {
+                        match col_name {
+                            "name" => options.name_prefix = Some(value),
+                            "engine" => options.engine = Some(value),
+                            _ => {}
+                        }
+                    }
+                }
+            });
+            find_like_filter(&expr, &mut |col_name, pattern| {
+                if col_name == "name" {
+                    options.name_pattern = Some(pattern.to_string());
+                }
+            });
+        }
+
         for (ctl_name, ctl) in ctls.into_iter() {
             let mut dbs = Vec::new();
             if let Some(push_downs) = &push_downs {
@@ -132,11 +228,16 @@ where TablesTable<T>: HistoryAware
                             }
                         }
                     });
-                    for db in db_name {
-                        if let Ok(database) = ctl.get_database(tenant.as_str(), db.as_str()).await {
-                            dbs.push(database);
+                    let results = stream::iter(db_name.iter())
+                        .map(|db| ctl.get_database(tenant.as_str(), db.as_str()))
+                        .buffered(MAX_CONCURRENT_META_REQUESTS)
+                        .collect::<Vec<_>>()
+                        .await;
+                    for (db, result) in db_name.iter().zip(results) {
+                        match result {
+                            Ok(database) => dbs.push(database),
+                            Err(_) => ctx.push_warning(format!("get database failed: {}", db)),
                         }
-                        ctx.push_warning(format!("get database failed: {}", db))
                     }
                 }
             }
@@ -150,10 +251,25 @@ where TablesTable<T>: HistoryAware
                 .into_iter()
                 .filter(|db| visibility_checker.check_database_visibility(ctl_name, db.name()))
                 .collect::<Vec<_>>();
-            for db in final_dbs {
+            // List the tables of the databases concurrently, each listing fetches the table
+            // metas of a database in batches.
+            let listed = stream::iter(final_dbs)
+                .map(|db| {
+                    let ctl = ctl.clone();
+                    let tenant = tenant.as_str();
+                    let options = options.clone();
+                    async move {
+                        let tables = Self::list_tables(&ctl, tenant, db.name(), options).await;
+                        (db, tables)
+                    }
+                })
+                .buffered(MAX_CONCURRENT_META_REQUESTS)
+                .collect::<Vec<_>>()
+                .await;
+            for (db, tables) in listed {
                 let name = db.name().to_string().into_boxed_str();
                 let name: &str = Box::leak(name);
-                let tables = match Self::list_tables(&ctl, tenant.as_str(), name).await {
+                let tables = match tables {
                     Ok(tables) => tables,
                     Err(err) => {
                         // swallow the errors related with remote database or tables, avoid ANY of bad table config corrupt ALL of the results.
@@ -195,14 +311,20 @@ where TablesTable<T>: HistoryAware
         let mut index_size: Vec<Option<u64>> = Vec::new();
 
         for tbl in &database_tables {
-            owner.push(
-                tbl.get_table_info()
+            let table_info = tbl.get_table_info();
+            owner.push(match table_owners.get(&table_info.ident.table_id) {
+                Some(role) => Some(role.as_bytes().to_vec()),
+                None => table_info
                     .meta
                     .owner
                     .as_ref()
                     .map(|v| v.owner_role_name.as_bytes().to_vec()),
-            );
-            let stats = tbl.table_statistics().await?;
+            });
+            let stats = if require_statistics {
+                tbl.table_statistics().await?
+            } else {
+                None
+            };
             num_rows.push(stats.as_ref().and_then(|v| v.num_rows));
             number_of_blocks.push(stats.as_ref().and_then(|v| v.number_of_blocks));
             number_of_segments.push(stats.as_ref().and_then(|v| v.number_of_segments));
@@ -263,6 +385,21 @@ where TablesTable<T>: HistoryAware
                 }
             })
             .collect();
+        let comments: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| v.get_table_info().meta.comment.as_bytes().to_vec())
+            .collect();
+        let view_queries: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| match v.engine() {
+                VIEW_ENGINE => v
+                    .options()
+                    .get(QUERY)
+                    .map(|query| query.as_bytes().to_vec())
+                    .unwrap_or_default(),
+                _ => vec![],
+            })
+            .collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
             StringType::from_data(databases),
@@ -282,6 +419,8 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
             StringType::from_opt_data(owner),
+            StringType::from_data(comments),
+            StringType::from_data(view_queries),
         ]))
     }
 }
@@ -333,6 +472,8 @@ where TablesTable<T>: HistoryAware
                 "owner",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("view_query", TableDataType::String),
         ])
     }
 
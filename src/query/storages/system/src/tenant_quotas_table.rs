@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_types::MatchSeq;
+use common_storages_fuse::operations::TenantStorageUsage;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TenantQuotasTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TenantQuotasTable {
+    const NAME: &'static str = "system.tenant_quotas";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
+        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
+        let usage = TenantStorageUsage::instance()
+            .get_usage(ctx.as_ref())
+            .await?;
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![tenant.into_bytes()]),
+            UInt32Type::from_data(vec![quota.max_databases]),
+            UInt32Type::from_data(vec![quota.max_tables_per_database]),
+            UInt32Type::from_data(vec![quota.max_stages]),
+            UInt32Type::from_data(vec![quota.max_files_per_stage]),
+            UInt32Type::from_data(vec![quota.max_users]),
+            UInt64Type::from_data(vec![quota.max_storage_bytes]),
+            UInt64Type::from_data(vec![usage]),
+        ]))
+    }
+}
+
+impl TenantQuotasTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("tenant", TableDataType::String),
+            TableField::new(
+                "max_databases",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new(
+                "max_tables_per_database",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new("max_stages", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_files_per_stage",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            // 0 means unlimited
+            TableField::new(
+                "max_storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            // logical(uncompressed) bytes of the fuse tables of the tenant
+            TableField::new(
+                "storage_usage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tenant_quotas'".to_string(),
+            name: "tenant_quotas".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTenantQuotas".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TenantQuotasTable { table_info })
+    }
+}
@@ -27,6 +27,7 @@ use common_expression::TableSchemaRefExt;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_users::is_password_expired;
 use common_users::UserApiProvider;
 use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 
@@ -75,6 +76,48 @@ impl AsyncSystemTable for UsersTable {
             })
             .collect();
         let mut is_configureds: Vec<Vec<u8>> = vec!["NO".as_bytes().to_vec(); users.len()];
+        let mut password_policies: Vec<Vec<u8>> = users
+            .iter()
+            .map(|x| {
+                x.option
+                    .password_policy()
+                    .cloned()
+                    .unwrap_or_default()
+                    .as_bytes()
+                    .to_vec()
+            })
+            .collect();
+        let mut settings_profiles: Vec<Vec<u8>> = users
+            .iter()
+            .map(|x| {
+                x.option
+                    .settings_profile()
+                    .cloned()
+                    .unwrap_or_default()
+                    .as_bytes()
+                    .to_vec()
+            })
+            .collect();
+
+        let policies = UserApiProvider::instance()
+            .get_password_policies(&tenant)
+            .await?;
+        let mut password_expireds: Vec<Vec<u8>> = users
+            .iter()
+            .map(|x| {
+                let expired = x
+                    .option
+                    .password_policy()
+                    .and_then(|name| policies.iter().find(|p| &p.name == name))
+                    .map(|p| is_password_expired(p, x))
+                    .unwrap_or(false);
+                if expired {
+                    "YES".as_bytes().to_vec()
+                } else {
+                    "NO".as_bytes().to_vec()
+                }
+            })
+            .collect();
 
         let configured_users = UserApiProvider::instance().get_configured_users();
         for (name, auth_info) in configured_users {
@@ -83,6 +126,9 @@ impl AsyncSystemTable for UsersTable {
             auth_types.push(auth_info.get_type().to_str().as_bytes().to_vec());
             default_roles.push(BUILTIN_ROLE_ACCOUNT_ADMIN.as_bytes().to_vec());
             is_configureds.push("YES".as_bytes().to_vec());
+            password_policies.push("".as_bytes().to_vec());
+            password_expireds.push("NO".as_bytes().to_vec());
+            settings_profiles.push("".as_bytes().to_vec());
         }
 
         // please note that do NOT display the auth_string field in the result, because there're risks of
@@ -93,13 +139,16 @@ impl AsyncSystemTable for UsersTable {
             StringType::from_data(auth_types),
             StringType::from_data(default_roles),
             StringType::from_data(is_configureds),
+            StringType::from_data(password_policies),
+            StringType::from_data(password_expireds),
+            StringType::from_data(settings_profiles),
         ]))
     }
 }
 
 impl UsersTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
-        // QUERY show user is rewrite to `SELECT name, hostname, auth_type, is_configured FROM system.users ORDER BY name`
+        // QUERY show user is rewrite to `SELECT name, hostname, auth_type, is_configured, password_policy, password_expired FROM system.users ORDER BY name`
         // If users table column has been modified, need to check the show user query.
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("name", TableDataType::String),
@@ -107,6 +156,9 @@ impl UsersTable {
             TableField::new("auth_type", TableDataType::String),
             TableField::new("default_role", TableDataType::String),
             TableField::new("is_configured", TableDataType::String),
+            TableField::new("password_policy", TableDataType::String),
+            TableField::new("password_expired", TableDataType::String),
+            TableField::new("settings_profile", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -47,3 +47,33 @@ pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scala
         }
     }
 }
+
+/// Find the `column LIKE 'pattern'` filters combined with `AND`, e.g. the filter
+/// of `SHOW TABLES LIKE 'pattern'`.
+pub fn find_like_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &str)) {
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
+        Expr::Cast { expr, .. } => find_like_filter(expr, visitor),
+        Expr::FunctionCall { function, args, .. } => {
+            if function.signature.name == "like" {
+                if let [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(pattern),
+                        ..
+                    },
+                ] = args.as_slice()
+                {
+                    if let Ok(pattern) = std::str::from_utf8(pattern) {
+                        visitor(id, pattern);
+                    }
+                }
+            } else if function.signature.name == "and_filters" {
+                for arg in args {
+                    find_like_filter(arg, visitor)
+                }
+            }
+        }
+        Expr::LambdaFunctionCall { .. } => {}
+    }
+}
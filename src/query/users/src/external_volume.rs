@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// external volume operations.
+impl UserApiProvider {
+    // Add a new external volume.
+    #[async_backtrace::framed]
+    pub async fn add_external_volume(
+        &self,
+        tenant: &str,
+        volume: ExternalVolume,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_external_volume_api_client(tenant)?;
+        match client.add_external_volume(volume).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::EXTERNAL_VOLUME_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e.add_message_back(" (while add external volume)"))
+                }
+            }
+        }
+    }
+
+    // Get an external volume by name.
+    #[async_backtrace::framed]
+    pub async fn get_external_volume(&self, tenant: &str, name: &str) -> Result<ExternalVolume> {
+        let client = self.get_external_volume_api_client(tenant)?;
+        let volume = client
+            .get_external_volume(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(volume)
+    }
+
+    // Get the tenant all external volume list.
+    #[async_backtrace::framed]
+    pub async fn get_external_volumes(&self, tenant: &str) -> Result<Vec<ExternalVolume>> {
+        let client = self.get_external_volume_api_client(tenant)?;
+        client
+            .get_external_volumes()
+            .await
+            .map_err(|e| e.add_message_back(" (while get external volumes)"))
+    }
+
+    // Update an external volume by name, the volume is written back only if it is not
+    // changed meanwhile.
+    #[async_backtrace::framed]
+    pub async fn update_external_volume_with<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        f: F,
+    ) -> Result<u64>
+    where
+        F: FnOnce(&mut ExternalVolume) -> Result<()>,
+    {
+        let client = self.get_external_volume_api_client(tenant)?;
+        let seq_volume = client.get_external_volume(name, MatchSeq::GE(0)).await?;
+        let mut volume = seq_volume.data;
+        f(&mut volume)?;
+        client
+            .update_external_volume(volume, MatchSeq::Exact(seq_volume.seq))
+            .await
+            .map_err(|e| e.add_message_back(" (while update external volume)"))
+    }
+
+    // Drop an external volume by name.
+    //
+    // The caller must make sure that no stage or table is on the volume.
+    #[async_backtrace::framed]
+    pub async fn drop_external_volume(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_external_volume_api_client(tenant)?;
+        match client.drop_external_volume(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_EXTERNAL_VOLUME {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop external volume)"))
+                }
+            }
+        }
+    }
+}
@@ -84,4 +84,31 @@ impl UserApiProvider {
             }
         }
     }
+
+    // Update a file_format by name, the file_format is written back only if it is not changed
+    // meanwhile.
+    #[async_backtrace::framed]
+    pub async fn update_file_format_with<F>(&self, tenant: &str, name: &str, f: F) -> Result<u64>
+    where F: FnOnce(&mut UserDefinedFileFormat) {
+        let file_format_api_provider = self.get_file_format_api_client(tenant)?;
+        let seq_file_format = file_format_api_provider
+            .get_file_format(name, MatchSeq::GE(0))
+            .await?;
+        let mut file_format = seq_file_format.data;
+        f(&mut file_format);
+        file_format_api_provider
+            .update_file_format(file_format, MatchSeq::Exact(seq_file_format.seq))
+            .await
+            .map_err(|e| e.add_message_back(" (while update file_format)"))
+    }
+
+    // Rename a file_format.
+    #[async_backtrace::framed]
+    pub async fn rename_file_format(&self, tenant: &str, name: &str, new_name: &str) -> Result<()> {
+        let file_format_api_provider = self.get_file_format_api_client(tenant)?;
+        file_format_api_provider
+            .rename_file_format(name, new_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while rename file_format)"))
+    }
 }
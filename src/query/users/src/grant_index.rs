@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::UserGrantSet;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::BitFlags;
+
+/// A grant found in [`GrantObjectIndex`], which gives the principal some privileges on the
+/// looked up object.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObjectGrant {
+    /// The object the privileges are granted on, it is the looked up object or an object
+    /// containing it, like its database.
+    pub granted_on: GrantObject,
+    pub principal: PrincipalIdentity,
+    pub privileges: BitFlags<UserPrivilegeType>,
+}
+
+/// GrantObjectIndex is a reverse index of the grant sets, from the granted objects to the
+/// principals holding privileges on them.
+///
+/// Finding who can access an object only probes the object and the objects containing it,
+/// instead of walking through the grants of every role and user.
+#[derive(Clone, Debug, Default)]
+pub struct GrantObjectIndex {
+    grants: HashMap<GrantObject, Vec<(PrincipalIdentity, BitFlags<UserPrivilegeType>)>>,
+}
+
+impl GrantObjectIndex {
+    pub fn insert(&mut self, principal: PrincipalIdentity, grants: &UserGrantSet) {
+        for entry in grants.entries() {
+            self.grants
+                .entry(entry.object().clone())
+                .or_default()
+                .push((principal.clone(), *entry.privileges()));
+        }
+    }
+
+    /// Returns the grants giving privileges on the object, including the ones inherited from
+    /// the global, database and future tables grants. The privileges which are not available
+    /// on the object are left out, e.g. `CREATE USER` of a global grant on a table.
+    pub fn find(&self, object: &GrantObject) -> Vec<ObjectGrant> {
+        let available: BitFlags<UserPrivilegeType> = object.available_privileges().into();
+        let mut result = vec![];
+        for granted_on in containing_objects(object) {
+            let Some(grants) = self.grants.get(&granted_on) else {
+                continue;
+            };
+            for (principal, privileges) in grants {
+                let privileges = *privileges & available;
+                if privileges.is_empty() {
+                    continue;
+                }
+                result.push(ObjectGrant {
+                    granted_on: granted_on.clone(),
+                    principal: principal.clone(),
+                    privileges,
+                });
+            }
+        }
+        result
+    }
+}
+
+/// The objects whose grants apply on the object, from the outermost to the object itself.
+fn containing_objects(object: &GrantObject) -> Vec<GrantObject> {
+    match object {
+        GrantObject::Global => vec![GrantObject::Global],
+        GrantObject::Database(catalog, db) => vec![
+            GrantObject::Global,
+            GrantObject::Database(catalog.clone(), db.clone()),
+        ],
+        GrantObject::FutureTables(catalog, db) => vec![
+            GrantObject::Global,
+            GrantObject::Database(catalog.clone(), db.clone()),
+            GrantObject::FutureTables(catalog.clone(), db.clone()),
+        ],
+        GrantObject::Table(catalog, db, _) => vec![
+            GrantObject::Global,
+            GrantObject::Database(catalog.clone(), db.clone()),
+            GrantObject::FutureTables(catalog.clone(), db.clone()),
+            object.clone(),
+        ],
+        GrantObject::UDF(_) | GrantObject::Stage(_) | GrantObject::Volume(_) => {
+            vec![GrantObject::Global, object.clone()]
+        }
+    }
+}
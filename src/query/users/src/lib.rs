@@ -13,14 +13,16 @@
 // limitations under the License.
 
 #![feature(let_chains)]
-#![feature(ip_in_core)]
 #![allow(clippy::uninlined_format_args)]
 
-extern crate core;
-
+mod external_volume;
+mod grant_index;
 mod jwt;
 mod network_policy;
+mod password_policy;
 mod role_mgr;
+mod row_access_policy;
+mod settings_profile;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -35,10 +37,21 @@ pub mod idm_config;
 pub mod role_cache_mgr;
 pub mod role_util;
 
+pub use grant_index::GrantObjectIndex;
+pub use grant_index::ObjectGrant;
 pub use jwt::*;
+pub use network_policy::display_network_policy_ip_list;
+pub use network_policy::network_contains_ip;
+pub use network_policy::normalize_network_policy_ip_list;
+pub use network_policy::parse_client_ip;
+pub use network_policy::parse_network_policy_ip;
+pub use password_policy::check_password_complexity;
+pub use password_policy::check_password_policy;
+pub use password_policy::is_password_expired;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
+pub use row_access_policy::check_row_access_policy;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
 pub use visibility_checker::GrantObjectVisibilityChecker;
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
 use chrono::Utc;
+use cidr::IpCidr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_management::NetworkPolicyApi;
@@ -21,6 +25,71 @@ use common_meta_types::MatchSeq;
 
 use crate::UserApiProvider;
 
+/// Parses an entry of the allowed or blocked ip list of a network policy.
+///
+/// Both IPv4 and IPv6 are accepted, either in CIDR notation(`192.168.1.0/24`, `2001:db8::/32`)
+/// or as a single address, which is treated as a host network.
+pub fn parse_network_policy_ip(ip: &str) -> Result<IpCidr> {
+    ip.trim()
+        .parse::<IpCidr>()
+        .map_err(|e| ErrorCode::IllegalNetworkPolicy(format!("invalid ip address `{}`: {}", ip, e)))
+}
+
+/// Validates every entry of the ip list and returns them in the normalized form,
+/// with the network length always present, e.g. `192.168.1.10/32`, `2001:db8::/32`.
+pub fn normalize_network_policy_ip_list(ip_list: &[String]) -> Result<Vec<String>> {
+    ip_list
+        .iter()
+        .map(|ip| parse_network_policy_ip(ip).map(|cidr| format!("{:#}", cidr)))
+        .collect()
+}
+
+/// Formats the ip list for display, entries are shown in the normalized form.
+///
+/// Entries which can't be parsed(e.g. stored by an old version) are displayed as is.
+pub fn display_network_policy_ip_list(ip_list: &[String]) -> String {
+    ip_list
+        .iter()
+        .map(|ip| match parse_network_policy_ip(ip) {
+            Ok(cidr) => format!("{:#}", cidr),
+            Err(_) => ip.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses the client address of a connection, which can be a bare ip address
+/// or a socket address with port, e.g. `[2001:db8::1]:3307`.
+pub fn parse_client_ip(client_ip: &str) -> Result<IpAddr> {
+    if let Ok(ip) = client_ip.parse::<IpAddr>() {
+        return Ok(ip);
+    }
+    match client_ip.parse::<SocketAddr>() {
+        Ok(addr) => Ok(addr.ip()),
+        Err(_) => Err(ErrorCode::AuthenticateFailure(format!(
+            "invalid client ip `{}`",
+            client_ip
+        ))),
+    }
+}
+
+/// Checks whether the network contains the address.
+///
+/// IPv4 clients connected through a dual-stack socket show up as IPv4-mapped IPv6
+/// addresses(`::ffff:192.168.1.10`), so the address is also checked in its other
+/// family representation.
+pub fn network_contains_ip(cidr: &IpCidr, ip: &IpAddr) -> bool {
+    if cidr.contains(ip) {
+        return true;
+    }
+    match ip {
+        IpAddr::V4(v4) => cidr.contains(&IpAddr::V6(v4.to_ipv6_mapped())),
+        IpAddr::V6(v6) => v6
+            .to_ipv4_mapped()
+            .map_or(false, |v4| cidr.contains(&IpAddr::V4(v4))),
+    }
+}
+
 impl UserApiProvider {
     // Add a new network policy.
     #[async_backtrace::framed]
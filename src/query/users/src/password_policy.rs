@@ -0,0 +1,413 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::PasswordPolicyApi;
+use common_management::UserApi;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
+use common_meta_types::MatchSeq;
+use log::info;
+
+use crate::UserApiProvider;
+
+/// Checks that the options of a password policy are consistent with each other.
+pub fn check_password_policy(password_policy: &PasswordPolicy) -> Result<()> {
+    if password_policy.min_length > password_policy.max_length {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "PASSWORD_MIN_LENGTH({}) is greater than PASSWORD_MAX_LENGTH({})",
+            password_policy.min_length, password_policy.max_length
+        )));
+    }
+    let min_chars = password_policy.min_upper_case_chars
+        + password_policy.min_lower_case_chars
+        + password_policy.min_numeric_chars
+        + password_policy.min_special_chars;
+    if min_chars > password_policy.max_length {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "the sum of the minimum required characters({}) is greater than PASSWORD_MAX_LENGTH({})",
+            min_chars, password_policy.max_length
+        )));
+    }
+    Ok(())
+}
+
+/// Checks the password against the complexity requirements of the policy.
+pub fn check_password_complexity(password_policy: &PasswordPolicy, password: &str) -> Result<()> {
+    let mut length = 0;
+    let mut upper_case_chars = 0;
+    let mut lower_case_chars = 0;
+    let mut numeric_chars = 0;
+    let mut special_chars = 0;
+    for c in password.chars() {
+        length += 1;
+        if c.is_ascii_uppercase() {
+            upper_case_chars += 1;
+        } else if c.is_ascii_lowercase() {
+            lower_case_chars += 1;
+        } else if c.is_ascii_digit() {
+            numeric_chars += 1;
+        } else if c.is_ascii_punctuation() {
+            special_chars += 1;
+        }
+    }
+
+    let requirements = [
+        (length, password_policy.min_length, "characters"),
+        (
+            upper_case_chars,
+            password_policy.min_upper_case_chars,
+            "upper case characters",
+        ),
+        (
+            lower_case_chars,
+            password_policy.min_lower_case_chars,
+            "lower case characters",
+        ),
+        (
+            numeric_chars,
+            password_policy.min_numeric_chars,
+            "numeric characters",
+        ),
+        (
+            special_chars,
+            password_policy.min_special_chars,
+            "special characters",
+        ),
+    ];
+    for (count, min_count, name) in requirements {
+        if count < min_count {
+            return Err(ErrorCode::InvalidPassword(format!(
+                "password must contain at least {} {}, as required by password policy `{}`",
+                min_count, name, password_policy.name
+            )));
+        }
+    }
+    if length > password_policy.max_length {
+        return Err(ErrorCode::InvalidPassword(format!(
+            "password must contain at most {} characters, as required by password policy `{}`",
+            password_policy.max_length, password_policy.name
+        )));
+    }
+    Ok(())
+}
+
+/// Returns whether the password of the user is expired, according to `max_age_days` of the policy.
+///
+/// Users created before the password states are stored have no `password_update_on`,
+/// their passwords never expire.
+pub fn is_password_expired(password_policy: &PasswordPolicy, user_info: &UserInfo) -> bool {
+    if password_policy.max_age_days == 0 {
+        return false;
+    }
+    match user_info.password_update_on {
+        Some(update_on) => {
+            update_on + Duration::days(password_policy.max_age_days as i64) <= Utc::now()
+        }
+        None => false,
+    }
+}
+
+impl UserApiProvider {
+    // Add a new password policy.
+    #[async_backtrace::framed]
+    pub async fn add_password_policy(
+        &self,
+        tenant: &str,
+        password_policy: PasswordPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        check_password_policy(&password_policy)?;
+        if if_not_exists
+            && self
+                .exists_password_policy(tenant, password_policy.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        let add_password_policy = client.add_password_policy(password_policy);
+        match add_password_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add password policy)"))
+                }
+            }
+        }
+    }
+
+    // Update password policy, the options are changed by `update_fn`.
+    #[async_backtrace::framed]
+    pub async fn update_password_policy<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        update_fn: F,
+        if_exists: bool,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut PasswordPolicy) + Send,
+    {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let seq_password_policy = match client.get_password_policy(name, MatchSeq::GE(0)).await {
+            Ok(seq_password_policy) => seq_password_policy,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter password policy)"));
+                }
+            }
+        };
+
+        let seq = seq_password_policy.seq;
+        let mut password_policy = seq_password_policy.data;
+        update_fn(&mut password_policy);
+        check_password_policy(&password_policy)?;
+        password_policy.update_on = Some(Utc::now());
+
+        match client
+            .update_password_policy(password_policy, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter password policy).")),
+        }
+    }
+
+    // Drop a password policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_password_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if let Some(password_policy) = user_info.option.password_policy() {
+                if password_policy == name {
+                    return Err(ErrorCode::PasswordPolicyIsUsedByUser(format!(
+                        "password policy `{}` is used by user",
+                        name,
+                    )));
+                }
+            }
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        match client.drop_password_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop password policy)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a password policy is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_password_policy(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_password_policy(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a password_policy by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policy(&self, tenant: &str, name: &str) -> Result<PasswordPolicy> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policy = client
+            .get_password_policy(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(password_policy)
+    }
+
+    // Get all password policies by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policies(&self, tenant: &str) -> Result<Vec<PasswordPolicy>> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policies = client
+            .get_password_policies()
+            .await
+            .map_err(|e| e.add_message_back(" (while get password policies)."))?;
+        Ok(password_policies)
+    }
+
+    // Check a new password of the user against the password policy in the user option.
+    // The history passwords are checked too if the user already exists.
+    #[async_backtrace::framed]
+    pub async fn verify_password(
+        &self,
+        tenant: &str,
+        user_option: &UserOption,
+        password: &str,
+        user_info: Option<&UserInfo>,
+    ) -> Result<()> {
+        let Some(name) = user_option.password_policy() else {
+            return Ok(());
+        };
+        let password_policy = self.get_password_policy(tenant, name).await?;
+        check_password_complexity(&password_policy, password)?;
+
+        if let Some(user_info) = user_info {
+            if password_policy.history > 0 {
+                let recent_auth_infos = std::iter::once(&user_info.auth_info).chain(
+                    user_info
+                        .history_auth_infos
+                        .iter()
+                        .take(password_policy.history as usize - 1),
+                );
+                for auth_info in recent_auth_infos {
+                    if let AuthInfo::Password {
+                        hash_value,
+                        hash_method,
+                    } = auth_info
+                    {
+                        if *hash_value == hash_method.hash(password.as_bytes()) {
+                            return Err(ErrorCode::InvalidPassword(format!(
+                                "password must not be the same as the last {} passwords, as required by password policy `{}`",
+                                password_policy.history, password_policy.name
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Check the result of a password authentication against the password policy of the user.
+    //
+    // The user is locked for `lockout_time_mins` after `max_retries` consecutive failures, the
+    // failures and the lockout are stored in the user info, so they are shared by all the nodes.
+    // Returns whether the password is expired, the user needs to change the password before
+    // running any other statements.
+    #[async_backtrace::framed]
+    pub async fn check_login_password(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+        authed: bool,
+    ) -> Result<bool> {
+        let Some(name) = user_info.option.password_policy() else {
+            return Ok(false);
+        };
+        if self.get_configured_user(&user_info.name).is_some() {
+            return Ok(false);
+        }
+
+        let now = Utc::now();
+        check_lockout(user_info, now)?;
+
+        let password_policy = self.get_password_policy(tenant, name).await?;
+        let client = self.get_user_api_client(tenant)?;
+        if !authed {
+            let max_retries = password_policy.max_retries as usize;
+            let lockout_time_mins = password_policy.lockout_time_mins as i64;
+            client
+                .update_user_with(
+                    user_info.identity(),
+                    MatchSeq::GE(1),
+                    |ui: &mut UserInfo| {
+                        ui.password_fails.push(now);
+                        if max_retries > 0 && ui.password_fails.len() >= max_retries {
+                            ui.password_fails.clear();
+                            ui.lockout_time = Some(now + Duration::minutes(lockout_time_mins));
+                        }
+                    },
+                )
+                .await
+                .map_err(|e| e.add_message_back(" (while update user password fails)."))?;
+            return Ok(false);
+        }
+
+        if !user_info.password_fails.is_empty() || user_info.lockout_time.is_some() {
+            client
+                .update_user_with(
+                    user_info.identity(),
+                    MatchSeq::GE(1),
+                    |ui: &mut UserInfo| {
+                        ui.password_fails.clear();
+                        ui.lockout_time = None;
+                    },
+                )
+                .await
+                .map_err(|e| e.add_message_back(" (while update user password fails)."))?;
+        }
+
+        let expired = is_password_expired(&password_policy, user_info);
+        if expired {
+            info!(
+                "password of user `{}` is expired, it must be changed",
+                user_info.name
+            );
+        }
+        Ok(expired)
+    }
+
+    // Check a login without the password, e.g. with a session token of the http handler,
+    // against the password policy of the user, the same as `check_login_password` except that
+    // nothing is updated. Returns whether the password is expired.
+    #[async_backtrace::framed]
+    pub async fn check_login_without_password(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+    ) -> Result<bool> {
+        let Some(name) = user_info.option.password_policy() else {
+            return Ok(false);
+        };
+        if self.get_configured_user(&user_info.name).is_some() {
+            return Ok(false);
+        }
+        check_lockout(user_info, Utc::now())?;
+
+        let password_policy = self.get_password_policy(tenant, name).await?;
+        Ok(is_password_expired(&password_policy, user_info))
+    }
+}
+
+fn check_lockout(user_info: &UserInfo, now: DateTime<Utc>) -> Result<()> {
+    if let Some(lockout_time) = user_info.lockout_time {
+        if lockout_time > now {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "user `{}` is locked until {}, because of too many failed login attempts",
+                user_info.name, lockout_time
+            )));
+        }
+    }
+    Ok(())
+}
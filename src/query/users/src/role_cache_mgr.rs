@@ -21,16 +21,21 @@ use common_base::base::tokio;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::GlobalInstance;
 use common_exception::Result;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::RoleInfo;
 use log::warn;
 use parking_lot::RwLock;
 
 use crate::role_util::find_all_related_roles;
+use crate::GrantObjectIndex;
+use crate::ObjectGrant;
 use crate::UserApiProvider;
 
 struct CachedRoles {
     roles: HashMap<String, RoleInfo>,
+    grant_index: GrantObjectIndex,
     cached_at: Instant,
 }
 
@@ -145,6 +150,23 @@ impl RoleCacheManager {
         Ok(find_all_related_roles(&cached_roles.roles, roles))
     }
 
+    // find_object_grants is called on `SHOW GRANTS ON <object>`, it returns the grants of the roles
+    // giving any privilege on the object.
+    #[async_backtrace::framed]
+    pub async fn find_object_grants(
+        &self,
+        tenant: &str,
+        object: &GrantObject,
+    ) -> Result<Vec<ObjectGrant>> {
+        self.maybe_reload(tenant).await?;
+        let cached = self.cache.read();
+        let cached_roles = match cached.get(tenant) {
+            None => return Ok(vec![]),
+            Some(cached_roles) => cached_roles,
+        };
+        Ok(cached_roles.grant_index.find(object))
+    }
+
     #[async_backtrace::framed]
     pub async fn force_reload(&self, tenant: &str) -> Result<()> {
         let data = load_roles_data(&self.user_manager, tenant).await?;
@@ -180,12 +202,17 @@ impl RoleCacheManager {
 
 async fn load_roles_data(user_api: &Arc<UserApiProvider>, tenant: &str) -> Result<CachedRoles> {
     let roles = user_api.get_roles(tenant).await?;
+    let mut grant_index = GrantObjectIndex::default();
+    for role in &roles {
+        grant_index.insert(PrincipalIdentity::Role(role.name.clone()), &role.grants);
+    }
     let roles_map = roles
         .into_iter()
         .map(|r| (r.identity().to_string(), r))
         .collect::<HashMap<_, _>>();
     Ok(CachedRoles {
         roles: roles_map,
+        grant_index,
         cached_at: Instant::now(),
     })
 }
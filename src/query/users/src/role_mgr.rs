@@ -177,6 +177,16 @@ impl UserApiProvider {
         Ok(ownership)
     }
 
+    #[async_backtrace::framed]
+    pub async fn get_ownerships(&self, tenant: &str) -> Result<Vec<OwnershipInfo>> {
+        let client = self.get_role_api_client(tenant)?;
+        let ownerships = client
+            .get_ownerships()
+            .await
+            .map_err(|e| e.add_message_back("(while get ownerships)"))?;
+        Ok(ownerships.into_iter().map(|v| v.data).collect())
+    }
+
     #[async_backtrace::framed]
     pub async fn grant_privileges_to_role(
         &self,
@@ -255,21 +265,34 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role from role)"))
     }
 
-    // Drop a role by name
+    // Drop a role by name. The objects owned by the dropped role are handed over to
+    // ACCOUNT_ADMIN, so that no object is left without an owner.
     #[async_backtrace::framed]
     pub async fn drop_role(&self, tenant: &str, role: String, if_exists: bool) -> Result<()> {
         let client = self.get_role_api_client(tenant)?;
-        let drop_role = client.drop_role(role, MatchSeq::GE(1));
+        let drop_role = client.drop_role(role.clone(), MatchSeq::GE(1));
         match drop_role.await {
-            Ok(res) => Ok(res),
+            Ok(_) => {}
             Err(e) => {
-                if if_exists && e.code() == ErrorCode::UNKNOWN_ROLE {
+                return if if_exists && e.code() == ErrorCode::UNKNOWN_ROLE {
                     Ok(())
                 } else {
                     Err(e.add_message_back("(while set drop role)"))
-                }
+                };
+            }
+        }
+
+        for ownership in client.get_ownerships().await? {
+            if ownership.data.role == role {
+                client
+                    .grant_ownership(&ownership.data.object, BUILTIN_ROLE_ACCOUNT_ADMIN)
+                    .await
+                    .map_err(|e| {
+                        e.add_message_back("(while hand over the objects of the dropped role)")
+                    })?;
             }
         }
+        Ok(())
     }
 
     // Find all related roles by role names. Every role have a PUBLIC role, and ACCOUNT_ADMIN
@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::RowAccessPolicyApi;
+use common_meta_app::principal::RowAccessPolicy;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Checks the arguments of a row access policy, they must be non-empty and unique
+/// (case-insensitive, as they are bound to column names).
+pub fn check_row_access_policy(policy: &RowAccessPolicy) -> Result<()> {
+    if policy.args.is_empty() {
+        return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+            "row access policy `{}` must have at least one argument",
+            policy.name
+        )));
+    }
+    let mut names = HashSet::with_capacity(policy.args.len());
+    for (arg_name, _) in policy.args.iter() {
+        if !names.insert(arg_name.to_lowercase()) {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "duplicated argument `{}` in row access policy `{}`",
+                arg_name, policy.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl UserApiProvider {
+    // Add a new row access policy.
+    #[async_backtrace::framed]
+    pub async fn add_row_access_policy(
+        &self,
+        tenant: &str,
+        policy: RowAccessPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        check_row_access_policy(&policy)?;
+
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        match client.add_row_access_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::ROW_ACCESS_POLICY_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add row access policy)"))
+                }
+            }
+        }
+    }
+
+    // Drop a row access policy by name.
+    //
+    // Tables which the policy is still attached to can't be read by the users
+    // without the `APPLY ROW ACCESS POLICY` privilege until it is detached.
+    #[async_backtrace::framed]
+    pub async fn drop_row_access_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        match client.drop_row_access_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop row access policy)"))
+                }
+            }
+        }
+    }
+
+    // Get a row access policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_row_access_policy(&self, tenant: &str, name: &str) -> Result<RowAccessPolicy> {
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        let policy = client
+            .get_row_access_policy(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(policy)
+    }
+}
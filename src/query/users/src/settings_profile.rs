@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::RoleApi;
+use common_management::SettingsProfileApi;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::SettingsProfile;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new settings profile.
+    #[async_backtrace::framed]
+    pub async fn add_settings_profile(
+        &self,
+        tenant: &str,
+        settings_profile: SettingsProfile,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        if if_not_exists
+            && self
+                .exists_settings_profile(tenant, settings_profile.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_settings_profile_api_client(tenant)?;
+        let add_settings_profile = client.add_settings_profile(settings_profile);
+        match add_settings_profile.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SETTINGS_PROFILE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add settings profile)"))
+                }
+            }
+        }
+    }
+
+    // Update settings profile, the settings are changed by `update_fn`.
+    #[async_backtrace::framed]
+    pub async fn update_settings_profile<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        update_fn: F,
+        if_exists: bool,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut SettingsProfile) + Send,
+    {
+        let client = self.get_settings_profile_api_client(tenant)?;
+        let seq_settings_profile = match client.get_settings_profile(name, MatchSeq::GE(0)).await {
+            Ok(seq_settings_profile) => seq_settings_profile,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SETTINGS_PROFILE {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter settings profile)"));
+                }
+            }
+        };
+
+        let seq = seq_settings_profile.seq;
+        let mut settings_profile = seq_settings_profile.data;
+        update_fn(&mut settings_profile);
+        settings_profile.update_on = Some(Utc::now());
+
+        match client
+            .update_settings_profile(settings_profile, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter settings profile).")),
+        }
+    }
+
+    // Drop a settings profile by name, the profile can't be dropped while any user or
+    // role is using it.
+    #[async_backtrace::framed]
+    pub async fn drop_settings_profile(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let mut used_by = vec![];
+        for user_info in self.get_users(tenant).await? {
+            if user_info.option.settings_profile().map(String::as_str) == Some(name) {
+                used_by.push(format!("user `{}`", user_info.name));
+            }
+        }
+        for role_info in self.get_roles(tenant).await? {
+            if role_info.settings_profile.as_deref() == Some(name) {
+                used_by.push(format!("role `{}`", role_info.name));
+            }
+        }
+        if !used_by.is_empty() {
+            return Err(ErrorCode::SettingsProfileIsInUse(format!(
+                "settings profile `{}` is used by {}, unset it from them before dropping",
+                name,
+                used_by.join(", ")
+            )));
+        }
+
+        let client = self.get_settings_profile_api_client(tenant)?;
+        match client.drop_settings_profile(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SETTINGS_PROFILE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop settings profile)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a settings profile is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_settings_profile(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_settings_profile(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_SETTINGS_PROFILE {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a settings profile by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_settings_profile(&self, tenant: &str, name: &str) -> Result<SettingsProfile> {
+        let client = self.get_settings_profile_api_client(tenant)?;
+        let settings_profile = client
+            .get_settings_profile(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(settings_profile)
+    }
+
+    // Get all settings profiles by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_settings_profiles(&self, tenant: &str) -> Result<Vec<SettingsProfile>> {
+        let client = self.get_settings_profile_api_client(tenant)?;
+        let settings_profiles = client
+            .get_settings_profiles()
+            .await
+            .map_err(|e| e.add_message_back(" (while get settings profiles)."))?;
+        Ok(settings_profiles)
+    }
+
+    // Set or unset the settings profile of a role.
+    #[async_backtrace::framed]
+    pub async fn set_role_settings_profile(
+        &self,
+        tenant: &str,
+        role: &String,
+        settings_profile: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &settings_profile {
+            if self.get_settings_profile(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownSettingsProfile(format!(
+                    "settings profile `{}` is not exist",
+                    name
+                )));
+            }
+        }
+
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.settings_profile = settings_profile
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role settings profile)"))
+    }
+}
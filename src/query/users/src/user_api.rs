@@ -20,16 +20,26 @@ use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_management::ConnectionApi;
 use common_management::ConnectionMgr;
+use common_management::ExternalVolumeApi;
+use common_management::ExternalVolumeMgr;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
 use common_management::NetworkPolicyApi;
 use common_management::NetworkPolicyMgr;
+use common_management::PasswordPolicyApi;
+use common_management::PasswordPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::SessionTokenApi;
+use common_management::SessionTokenMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
+use common_management::SettingsProfileApi;
+use common_management::SettingsProfileMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
 use common_management::UdfApi;
@@ -117,6 +127,16 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_external_volume_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn ExternalVolumeApi>> {
+        Ok(Arc::new(ExternalVolumeMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_udf_api_client(&self, tenant: &str) -> Result<Arc<dyn UdfApi>> {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
@@ -129,6 +149,13 @@ impl UserApiProvider {
         Ok(Arc::new(SettingMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_session_token_api_client(&self, tenant: &str) -> Result<Arc<dyn SessionTokenApi>> {
+        Ok(Arc::new(SessionTokenMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_network_policy_api_client(
         &self,
         tenant: &str,
@@ -139,6 +166,36 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_password_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl PasswordPolicyApi>> {
+        Ok(Arc::new(PasswordPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_settings_profile_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl SettingsProfileApi>> {
+        Ok(Arc::new(SettingsProfileMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -12,9 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core::net::Ipv4Addr;
-
-use cidr::Ipv4Cidr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_management::UserApi;
@@ -26,6 +23,9 @@ use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_types::MatchSeq;
 
+use crate::network_contains_ip;
+use crate::parse_client_ip;
+use crate::parse_network_policy_ip;
 use crate::role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use crate::UserApiProvider;
 
@@ -74,8 +74,8 @@ impl UserApiProvider {
         let user_info = self.get_user(tenant, user).await?;
 
         if let Some(name) = user_info.option.network_policy() {
-            let ip_addr: Ipv4Addr = match client_ip {
-                Some(client_ip) => client_ip.parse().unwrap(),
+            let ip_addr = match client_ip {
+                Some(client_ip) => parse_client_ip(client_ip)?,
                 None => {
                     return Err(ErrorCode::AuthenticateFailure("Unknown client ip"));
                 }
@@ -83,8 +83,8 @@ impl UserApiProvider {
 
             let network_policy = self.get_network_policy(tenant, name.as_str()).await?;
             for blocked_ip in network_policy.blocked_ip_list {
-                let blocked_cidr: Ipv4Cidr = blocked_ip.parse().unwrap();
-                if blocked_cidr.contains(&ip_addr) {
+                let blocked_cidr = parse_network_policy_ip(&blocked_ip)?;
+                if network_contains_ip(&blocked_cidr, &ip_addr) {
                     return Err(ErrorCode::AuthenticateFailure(format!(
                         "client ip `{}` is blocked",
                         ip_addr
@@ -93,8 +93,8 @@ impl UserApiProvider {
             }
             let mut allow = false;
             for allowed_ip in network_policy.allowed_ip_list {
-                let allowed_cidr: Ipv4Cidr = allowed_ip.parse().unwrap();
-                if allowed_cidr.contains(&ip_addr) {
+                let allowed_cidr = parse_network_policy_ip(&allowed_ip)?;
+                if network_contains_ip(&allowed_cidr, &ip_addr) {
                     allow = true;
                     break;
                 }
@@ -144,6 +144,22 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.password_policy() {
+            if self.get_password_policy(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                    "password policy `{}` is not exist",
+                    name
+                )));
+            }
+        }
+        if let Some(name) = user_info.option.settings_profile() {
+            if self.get_settings_profile(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownSettingsProfile(format!(
+                    "settings profile `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -295,6 +311,22 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.password_policy() {
+                if self.get_password_policy(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                        "password policy `{}` is not exist",
+                        name
+                    )));
+                }
+            }
+            if let Some(name) = user_option.settings_profile() {
+                if self.get_settings_profile(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownSettingsProfile(format!(
+                        "settings profile `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
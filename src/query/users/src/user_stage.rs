@@ -14,6 +14,7 @@
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::ExternalVolume;
 use common_meta_app::principal::StageInfo;
 use common_meta_types::MatchSeq;
 
@@ -44,11 +45,19 @@ impl UserApiProvider {
     }
 
     // Get one stage from by tenant.
+    //
+    // The stage params of a stage on an external volume are resolved through the volume,
+    // so that the stage always uses the current configuration of the volume.
     #[async_backtrace::framed]
     pub async fn get_stage(&self, tenant: &str, stage_name: &str) -> Result<StageInfo> {
         let stage_api_provider = self.get_stage_api_client(tenant)?;
         let get_stage = stage_api_provider.get_stage(stage_name, MatchSeq::GE(0));
-        Ok(get_stage.await?.data)
+        let mut stage = get_stage.await?.data;
+        if let Some(location) = &stage.volume_location {
+            let volume = self.get_external_volume(tenant, &location.volume).await?;
+            stage.stage_params.storage = volume.resolve(location);
+        }
+        Ok(stage)
     }
 
     #[async_backtrace::framed]
@@ -71,10 +80,29 @@ impl UserApiProvider {
         let stage_api_provider = self.get_stage_api_client(tenant)?;
         let get_stages = stage_api_provider.get_stages();
 
-        match get_stages.await {
-            Err(e) => Err(e.add_message_back(" (while get stages)")),
-            Ok(seq_stages_info) => Ok(seq_stages_info),
+        let mut stages = match get_stages.await {
+            Err(e) => return Err(e.add_message_back(" (while get stages)")),
+            Ok(seq_stages_info) => seq_stages_info,
+        };
+        if stages.iter().any(|stage| stage.volume_location.is_some()) {
+            let volumes: Vec<ExternalVolume> = self.get_external_volumes(tenant).await?;
+            for stage in stages.iter_mut() {
+                let Some(location) = &stage.volume_location else {
+                    continue;
+                };
+                let volume = volumes
+                    .iter()
+                    .find(|volume| volume.name == location.volume)
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownExternalVolume(format!(
+                            "Unknown external volume {} of stage {}",
+                            location.volume, stage.stage_name
+                        ))
+                    })?;
+                stage.stage_params.storage = volume.resolve(location);
+            }
         }
+        Ok(stages)
     }
 
     // Drop a stage by name.
@@ -93,4 +121,28 @@ impl UserApiProvider {
             }
         }
     }
+
+    // Update a stage by name, the stage is written back only if it is not changed meanwhile.
+    #[async_backtrace::framed]
+    pub async fn update_stage_with<F>(&self, tenant: &str, name: &str, f: F) -> Result<u64>
+    where F: FnOnce(&mut StageInfo) {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let seq_stage = stage_api_provider.get_stage(name, MatchSeq::GE(0)).await?;
+        let mut stage = seq_stage.data;
+        f(&mut stage);
+        stage_api_provider
+            .update_stage(stage, MatchSeq::Exact(seq_stage.seq))
+            .await
+            .map_err(|e| e.add_message_back(" (while update stage)"))
+    }
+
+    // Rename a stage.
+    #[async_backtrace::framed]
+    pub async fn rename_stage(&self, tenant: &str, name: &str, new_name: &str) -> Result<()> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        stage_api_provider
+            .rename_stage(name, new_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while rename stage)"))
+    }
 }
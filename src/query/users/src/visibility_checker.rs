@@ -28,10 +28,12 @@ pub struct GrantObjectVisibilityChecker {
     granted_global: bool,
     granted_databases: HashSet<(String, String)>,
     granted_tables: HashSet<(String, String, String)>,
+    granted_future_tables: HashSet<(String, String)>,
     extra_databases: HashSet<(String, String)>,
     granted_udfs: HashSet<String>,
     granted_write_stages: HashSet<String>,
     granted_read_stages: HashSet<String>,
+    granted_volumes: HashSet<String>,
 }
 
 impl GrantObjectVisibilityChecker {
@@ -39,9 +41,11 @@ impl GrantObjectVisibilityChecker {
         let mut granted_global = false;
         let mut granted_databases = HashSet::new();
         let mut granted_tables = HashSet::new();
+        let mut granted_future_tables = HashSet::new();
         let mut granted_udfs = HashSet::new();
         let mut granted_write_stages = HashSet::new();
         let mut granted_read_stages = HashSet::new();
+        let mut granted_volumes = HashSet::new();
         let mut extra_databases = HashSet::new();
 
         let mut grant_sets: Vec<&UserGrantSet> = vec![&user.grants];
@@ -67,6 +71,11 @@ impl GrantObjectVisibilityChecker {
                         // if table is visible, the table's database is also treated as visible
                         extra_databases.insert((catalog.to_string(), db.to_string()));
                     }
+                    GrantObject::FutureTables(catalog, db) => {
+                        granted_future_tables.insert((catalog.to_string(), db.to_string()));
+                        // the tables in the database are visible, so is the database
+                        extra_databases.insert((catalog.to_string(), db.to_string()));
+                    }
                     GrantObject::UDF(udf) => {
                         granted_udfs.insert(udf.to_string());
                     }
@@ -84,6 +93,9 @@ impl GrantObjectVisibilityChecker {
                             granted_read_stages.insert(stage.to_string());
                         }
                     }
+                    GrantObject::Volume(volume) => {
+                        granted_volumes.insert(volume.to_string());
+                    }
                 }
             }
         }
@@ -92,10 +104,12 @@ impl GrantObjectVisibilityChecker {
             granted_global,
             granted_databases,
             granted_tables,
+            granted_future_tables,
             extra_databases,
             granted_udfs,
             granted_write_stages,
             granted_read_stages,
+            granted_volumes,
         }
     }
 
@@ -121,6 +135,17 @@ impl GrantObjectVisibilityChecker {
         false
     }
 
+    pub fn check_volume_visibility(&self, volume: &str) -> bool {
+        if self.granted_global {
+            return true;
+        }
+
+        if self.granted_volumes.contains(volume) {
+            return true;
+        }
+        false
+    }
+
     pub fn check_udf_visibility(&self, udf: &str) -> bool {
         if self.granted_global {
             return true;
@@ -176,6 +201,14 @@ impl GrantObjectVisibilityChecker {
             return true;
         }
 
+        // future tables grants cover all the tables in the database, whenever they are created
+        if self
+            .granted_future_tables
+            .contains(&(catalog.to_string(), database.to_string()))
+        {
+            return true;
+        }
+
         false
     }
 }
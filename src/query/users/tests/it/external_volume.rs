@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::ExternalVolume;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageParams;
+use common_meta_app::principal::StageType;
+use common_meta_app::principal::VolumeLocation;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+fn s3_params(root: &str, role_arn: &str) -> StorageParams {
+    StorageParams::S3(StorageS3Config {
+        bucket: "bucket".to_string(),
+        root: root.to_string(),
+        role_arn: role_arn.to_string(),
+        ..Default::default()
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stage_on_external_volume() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+    let tenant = "test";
+
+    let volume = ExternalVolume {
+        name: "vol".to_string(),
+        storage_params: s3_params("/data/", "role1"),
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    };
+    user_mgr
+        .add_external_volume(tenant, volume.clone(), false)
+        .await?;
+    let err = user_mgr
+        .add_external_volume(tenant, volume.clone(), false)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::EXTERNAL_VOLUME_ALREADY_EXISTS);
+
+    let location = VolumeLocation {
+        volume: "vol".to_string(),
+        path: "logs/".to_string(),
+    };
+    let stage = StageInfo {
+        stage_name: "s1".to_string(),
+        stage_type: StageType::External,
+        stage_params: StageParams {
+            storage: volume.resolve(&location),
+        },
+        volume_location: Some(location),
+        ..Default::default()
+    };
+    user_mgr.add_stage(tenant, stage, false).await?;
+    assert_eq!(
+        user_mgr.get_stage(tenant, "s1").await?.stage_params.storage,
+        s3_params("/data/logs/", "role1")
+    );
+
+    // the stage follows the credentials rotated on the volume.
+    user_mgr
+        .update_external_volume_with(tenant, "vol", |volume| {
+            volume.storage_params = s3_params("/data/", "role2");
+            Ok(())
+        })
+        .await?;
+    assert_eq!(
+        user_mgr.get_stage(tenant, "s1").await?.stage_params.storage,
+        s3_params("/data/logs/", "role2")
+    );
+    let stages = user_mgr.get_stages(tenant).await?;
+    assert_eq!(
+        stages[0].stage_params.storage,
+        s3_params("/data/logs/", "role2")
+    );
+
+    user_mgr.drop_external_volume(tenant, "vol", false).await?;
+    user_mgr.drop_external_volume(tenant, "vol", true).await?;
+    let err = user_mgr
+        .get_external_volume(tenant, "vol")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_EXTERNAL_VOLUME);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserGrantSet;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserPrivilegeType;
+use common_users::GrantObjectIndex;
+use common_users::ObjectGrant;
+use enumflags2::make_bitflags;
+
+#[test]
+fn test_grant_object_index() {
+    let table = GrantObject::Table("default".into(), "db1".into(), "t1".into());
+    let database = GrantObject::Database("default".into(), "db1".into());
+    let future_tables = GrantObject::FutureTables("default".into(), "db1".into());
+
+    let mut admin = RoleInfo::new("admin");
+    admin
+        .grants
+        .grant_privileges(&GrantObject::Global, UserPrivilegeSet::all_privileges());
+    let mut reader = RoleInfo::new("reader");
+    reader
+        .grants
+        .grant_privileges(&table, UserPrivilegeType::Select.into());
+    let mut writer = RoleInfo::new("writer");
+    writer
+        .grants
+        .grant_privileges(&future_tables, UserPrivilegeType::Insert.into());
+    let mut other = RoleInfo::new("other");
+    other.grants.grant_privileges(
+        &GrantObject::Table("default".into(), "db2".into(), "t1".into()),
+        UserPrivilegeType::Select.into(),
+    );
+    let user = UserIdentity::new("u1", "%");
+    let mut user_grants = UserGrantSet::empty();
+    user_grants.grant_privileges(&database, UserPrivilegeType::Create.into());
+
+    let mut index = GrantObjectIndex::default();
+    for role in [&admin, &reader, &writer, &other] {
+        index.insert(PrincipalIdentity::Role(role.name.clone()), &role.grants);
+    }
+    index.insert(PrincipalIdentity::User(user.clone()), &user_grants);
+
+    // the table inherits the grants of its database and the future tables in the database.
+    let grants = index.find(&table);
+    assert_eq!(grants.len(), 4);
+    assert_eq!(grants[0], ObjectGrant {
+        granted_on: GrantObject::Global,
+        principal: PrincipalIdentity::Role("admin".to_string()),
+        privileges: UserPrivilegeSet::available_privileges_on_table().into(),
+    });
+    assert_eq!(grants[1], ObjectGrant {
+        granted_on: database.clone(),
+        principal: PrincipalIdentity::User(user.clone()),
+        privileges: make_bitflags!(UserPrivilegeType::{ Create }),
+    });
+    assert_eq!(grants[2], ObjectGrant {
+        granted_on: future_tables,
+        principal: PrincipalIdentity::Role("writer".to_string()),
+        privileges: make_bitflags!(UserPrivilegeType::{ Insert }),
+    });
+    assert_eq!(grants[3], ObjectGrant {
+        granted_on: table,
+        principal: PrincipalIdentity::Role("reader".to_string()),
+        privileges: make_bitflags!(UserPrivilegeType::{ Select }),
+    });
+
+    // the database does not inherit the grants of its tables.
+    let grants = index.find(&database);
+    assert_eq!(grants.len(), 2);
+    assert_eq!(grants[1].principal, PrincipalIdentity::User(user));
+
+    // only the global grants apply on a stage.
+    let grants = index.find(&GrantObject::Stage("s1".to_string()));
+    assert_eq!(grants.len(), 1);
+    assert_eq!(
+        grants[0].privileges,
+        make_bitflags!(UserPrivilegeType::{ Read | Write })
+    );
+}
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod external_volume;
+mod grant_index;
 mod jwt;
+mod network_policy_ip;
+mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
+mod row_access_policy;
 mod user_mgr;
 mod user_udf;
@@ -0,0 +1,93 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::network_contains_ip;
+use common_users::normalize_network_policy_ip_list;
+use common_users::parse_client_ip;
+use common_users::parse_network_policy_ip;
+
+#[test]
+fn test_normalize_network_policy_ip_list() -> Result<()> {
+    let ip_list = vec![
+        "192.168.1.0/24".to_string(),
+        "192.168.1.99".to_string(),
+        "2001:db8::/32".to_string(),
+        "::1".to_string(),
+    ];
+    assert_eq!(normalize_network_policy_ip_list(&ip_list)?, vec![
+        "192.168.1.0/24",
+        "192.168.1.99/32",
+        "2001:db8::/32",
+        "::1/128",
+    ]);
+
+    let ip_list = vec!["192.168.1.0/24".to_string(), "192.168.1.1/24".to_string()];
+    let err = normalize_network_policy_ip_list(&ip_list).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_NETWORK_POLICY);
+    assert!(err.message().contains("192.168.1.1/24"));
+
+    let ip_list = vec!["2001:db8::/129".to_string()];
+    let err = normalize_network_policy_ip_list(&ip_list).unwrap_err();
+    assert!(err.message().contains("2001:db8::/129"));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_client_ip() -> Result<()> {
+    assert_eq!(parse_client_ip("192.168.1.10")?.to_string(), "192.168.1.10");
+    assert_eq!(
+        parse_client_ip("192.168.1.10:3307")?.to_string(),
+        "192.168.1.10"
+    );
+    assert_eq!(parse_client_ip("2001:db8::1")?.to_string(), "2001:db8::1");
+    assert_eq!(
+        parse_client_ip("[2001:db8::1]:3307")?.to_string(),
+        "2001:db8::1"
+    );
+    assert!(parse_client_ip("localhost").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_network_contains_ip() -> Result<()> {
+    let v4 = parse_network_policy_ip("192.168.1.0/24")?;
+    let v6 = parse_network_policy_ip("2001:db8::/32")?;
+    let mapped = parse_network_policy_ip("::ffff:10.0.0.0/104")?;
+
+    assert!(network_contains_ip(&v4, &parse_client_ip("192.168.1.10")?));
+    assert!(!network_contains_ip(&v4, &parse_client_ip("192.168.2.10")?));
+    assert!(network_contains_ip(&v6, &parse_client_ip("2001:db8::1")?));
+    assert!(!network_contains_ip(&v6, &parse_client_ip("2001:db9::1")?));
+
+    // mixed families never match
+    assert!(!network_contains_ip(&v4, &parse_client_ip("2001:db8::1")?));
+    assert!(!network_contains_ip(&v6, &parse_client_ip("192.168.1.10")?));
+
+    // IPv4-mapped IPv6 addresses
+    assert!(network_contains_ip(
+        &v4,
+        &parse_client_ip("[::ffff:192.168.1.10]:3307")?
+    ));
+    assert!(!network_contains_ip(
+        &v4,
+        &parse_client_ip("::ffff:192.168.2.10")?
+    ));
+    assert!(network_contains_ip(&mapped, &parse_client_ip("10.1.2.3")?));
+
+    Ok(())
+}
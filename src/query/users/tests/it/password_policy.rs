@@ -0,0 +1,102 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+use common_users::check_password_complexity;
+use common_users::check_password_policy;
+use common_users::is_password_expired;
+
+#[test]
+fn test_check_password_policy() -> Result<()> {
+    let password_policy = PasswordPolicy::default();
+    check_password_policy(&password_policy)?;
+
+    let password_policy = PasswordPolicy {
+        min_length: 20,
+        max_length: 16,
+        ..Default::default()
+    };
+    let err = check_password_policy(&password_policy).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_PASSWORD_POLICY);
+
+    let password_policy = PasswordPolicy {
+        max_length: 8,
+        min_upper_case_chars: 3,
+        min_lower_case_chars: 3,
+        min_numeric_chars: 3,
+        ..Default::default()
+    };
+    let err = check_password_policy(&password_policy).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_PASSWORD_POLICY);
+
+    Ok(())
+}
+
+#[test]
+fn test_check_password_complexity() -> Result<()> {
+    let password_policy = PasswordPolicy {
+        name: "test_policy".to_string(),
+        min_length: 10,
+        max_length: 16,
+        min_special_chars: 1,
+        ..Default::default()
+    };
+    check_password_complexity(&password_policy, "Abc123456!")?;
+
+    for password in [
+        "Abc12345!",
+        "Abc12345678901234!",
+        "abc123456!",
+        "ABC123456!",
+        "Abcdefghi!",
+        "Abc1234567",
+    ] {
+        let err = check_password_complexity(&password_policy, password).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::INVALID_PASSWORD);
+        assert!(err.message().contains("test_policy"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_is_password_expired() -> Result<()> {
+    let password_policy = PasswordPolicy {
+        max_age_days: 30,
+        ..Default::default()
+    };
+    let mut user_info = UserInfo::new("test", "%", AuthInfo::None);
+    // users created before the password states are stored never expire.
+    assert!(!is_password_expired(&password_policy, &user_info));
+
+    user_info.password_update_on = Some(Utc::now() - Duration::days(10));
+    assert!(!is_password_expired(&password_policy, &user_info));
+
+    user_info.password_update_on = Some(Utc::now() - Duration::days(31));
+    assert!(is_password_expired(&password_policy, &user_info));
+
+    let password_policy = PasswordPolicy {
+        max_age_days: 0,
+        ..Default::default()
+    };
+    assert!(!is_password_expired(&password_policy, &user_info));
+
+    Ok(())
+}
@@ -17,10 +17,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
 use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use common_users::BUILTIN_ROLE_PUBLIC;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -103,3 +106,57 @@ async fn test_role_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_manager_ownership() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let role_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "tenant2";
+    let role_name = "test-owner".to_string();
+    let table = GrantObjectByID::Table {
+        catalog_name: "default".to_string(),
+        db_id: 1,
+        table_id: 2,
+    };
+    let database = GrantObjectByID::Database {
+        catalog_name: "default".to_string(),
+        db_id: 3,
+    };
+
+    role_mgr
+        .add_role(tenant, RoleInfo::new(&role_name), false)
+        .await?;
+    role_mgr
+        .grant_ownership_to_role(tenant, &table, &role_name)
+        .await?;
+    role_mgr
+        .grant_ownership_to_role(tenant, &database, BUILTIN_ROLE_PUBLIC)
+        .await?;
+
+    // transfer to a role which does not exist, error
+    {
+        let res = role_mgr
+            .grant_ownership_to_role(tenant, &table, "test-unknown")
+            .await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UNKNOWN_ROLE);
+    }
+
+    {
+        let owner = role_mgr.get_ownership(tenant, &table).await?.unwrap();
+        assert_eq!(owner.role, role_name);
+        assert_eq!(role_mgr.get_ownerships(tenant).await?.len(), 2);
+    }
+
+    // the objects owned by the dropped role fall back to account_admin
+    {
+        role_mgr.drop_role(tenant, role_name.clone(), false).await?;
+
+        let owner = role_mgr.get_ownership(tenant, &table).await?.unwrap();
+        assert_eq!(owner.role, BUILTIN_ROLE_ACCOUNT_ADMIN);
+        let owner = role_mgr.get_ownership(tenant, &database).await?.unwrap();
+        assert_eq!(owner.role, BUILTIN_ROLE_PUBLIC);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicy;
+use common_users::check_row_access_policy;
+
+fn new_policy(args: Vec<(&str, &str)>) -> RowAccessPolicy {
+    RowAccessPolicy {
+        name: "rap1".to_string(),
+        args: args
+            .into_iter()
+            .map(|(name, ty)| (name.to_string(), ty.to_string()))
+            .collect(),
+        body: "region = current_role()".to_string(),
+        comment: "".to_string(),
+        create_on: Utc::now(),
+        update_on: None,
+    }
+}
+
+#[test]
+fn test_check_row_access_policy() -> Result<()> {
+    check_row_access_policy(&new_policy(vec![("region", "STRING"), ("id", "INT")]))?;
+
+    let err = check_row_access_policy(&new_policy(vec![])).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_ROW_ACCESS_POLICY);
+
+    let err = check_row_access_policy(&new_policy(vec![("region", "STRING"), ("REGION", "INT")]))
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_ROW_ACCESS_POLICY);
+    assert!(err.message().contains("REGION"));
+
+    Ok(())
+}
@@ -77,7 +77,9 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 source: Some(source),
                 engine: Some(Engine::Fuse),
                 uri_location: None,
+                volume_location: None,
                 cluster_by: vec![],
+                bucket_by: None,
                 table_options: BTreeMap::new(),
                 as_query: None,
                 transient: false,
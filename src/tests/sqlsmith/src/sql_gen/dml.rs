@@ -88,6 +88,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             source,
             // TODO
             overwrite: false,
+            idempotent_token: None,
         }
     }
 
@@ -155,6 +156,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             columns,
             source,
             delete_when,
+            idempotent_token: None,
         }
     }
 
@@ -497,6 +499,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                idempotent_token: None,
             })
         } else {
             None
@@ -64,6 +64,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             order_by,
             limit,
             offset,
+            into: None,
             ignore_result: false,
         }
     }
@@ -131,6 +132,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             order_by: vec![],
             limit: vec![],
             offset: None,
+            into: None,
             ignore_result: false,
         };
 
@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+echo "create table t12_0005(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3)" | $BENDSQL_CLIENT_CONNECT
+
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3 " | $BENDSQL_CLIENT_CONNECT)
+
+echo "the travel point of the scan should be explained"
+echo "explain select c from t12_0005 at (snapshot => '$SNAPSHOT_ID') as t join t12_0005 on t.c = t12_0005.c" | $BENDSQL_CLIENT_CONNECT | grep -c "travel point: AT (SNAPSHOT => '$SNAPSHOT_ID')"
+
+echo "drop table t12_0005" | $BENDSQL_CLIENT_CONNECT
@@ -39,17 +39,17 @@ echo "checking that after flashback to the first snapshot, there should be only
 echo "select count(*)=1  from fuse_snapshot('default', 't16')" | $BENDSQL_CLIENT_CONNECT
 
 # flash back to point that not exist should fail
-echo "flash back to snapshot id that not exist should report error 1105"
+echo "flash back to snapshot id that not exist should report error 2019"
 echo "alter table t16 flashback to (snapshot => 'NOTE_EXIST')" | $BENDSQL_CLIENT_CONNECT
 
-echo "flash back to timestamp that not exist should report error 1105"
+echo "flash back to timestamp that not exist should report error 2017"
 echo "alter table t16 flashback to (TIMESTAMP => '2000-12-06 04:35:17.856848'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT;
 
 # flash back to point that does not visible to the current snapshot will also fail
 #  although $SNAPSHOT_ID has been in the history of table `t16`, but
 #  after reverted to the $FST_SNAPSHOT_ID, it no longer visible to the table `t16`
-echo "flash back to point that does not visible to the current snapshot should report error 1105"
-echo "alter table t16 flashback to (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+echo "flash back to point that does not visible to the current snapshot should report error 2019"
+echo "alter table t16 flashback to (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT 2>&1 | sed "s/$SNAPSHOT_ID/SNAPSHOT_ID/"
 
 
 ## Drop table.
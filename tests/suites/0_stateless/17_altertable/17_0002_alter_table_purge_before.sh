@@ -72,7 +72,7 @@ echo "alter table add a column"
 echo "alter table t17_0002 add column a float default 1.01" | $BENDSQL_CLIENT_CONNECT
 
 ## verify
-echo "set retention_period=0; optimize table t17_0002 purge before (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0; optimize table t17_0002 purge before (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT > /dev/null
 echo "checking that after purge (by timestamp) there should be at least 2 snapshots left"
 echo "select count(*)>=2  from fuse_snapshot('default', 't17_0002')" | $BENDSQL_CLIENT_CONNECT
 echo "checking that after purge (by timestamp) there should be 5 rows left"
@@ -83,7 +83,7 @@ echo "alter table drop a column"
 echo "alter table t17_0002 drop column a" | $BENDSQL_CLIENT_CONNECT
 
 ## verify
-echo "set retention_period=0; optimize table t17_0002 purge before (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0; optimize table t17_0002 purge before (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT > /dev/null
 echo "checking that after purge (by timestamp) there should be at least 2 snapshots left"
 echo "select count(*)>=2  from fuse_snapshot('default', 't17_0002')" | $BENDSQL_CLIENT_CONNECT
 echo "checking that after purge (by timestamp) there should be 5 rows left"
@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "=== test row access policy"
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=test-user --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_rap;" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_rap_src;" | $BENDSQL_CLIENT_CONNECT
+echo "drop row access policy if exists rap_region;" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table default.t_rap(id int, region string);" | $BENDSQL_CLIENT_CONNECT
+echo "insert into default.t_rap values (1, 'us'), (2, 'cn'), (3, 'eu');" | $BENDSQL_CLIENT_CONNECT
+echo "create row access policy rap_region as (r string) returns boolean -> r <> 'cn';" | $BENDSQL_CLIENT_CONNECT
+echo "alter table default.t_rap add row access policy rap_region on (region);" | $BENDSQL_CLIENT_CONNECT
+echo "create table default.t_rap_src(id int, region string);" | $BENDSQL_CLIENT_CONNECT
+echo "insert into default.t_rap_src values (2, 'cn'), (4, 'us'), (5, 'jp');" | $BENDSQL_CLIENT_CONNECT
+
+## create user
+echo "create user 'test-user' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "grant select, insert, update, delete on default.t_rap to 'test-user';" | $BENDSQL_CLIENT_CONNECT
+echo "grant select on default.t_rap_src to 'test-user';" | $BENDSQL_CLIENT_CONNECT
+sleep 2;
+
+echo "=== filtered scan ==="
+echo "select * from default.t_rap order by id;" | $TEST_USER_CONNECT
+echo "select count(*) from default.t_rap where region = 'cn';" | $TEST_USER_CONNECT
+echo "explain analyze select * from default.t_rap;" | $TEST_USER_CONNECT
+
+echo "=== filtered mutations ==="
+echo "update default.t_rap set id = id + 10;" | $TEST_USER_CONNECT
+echo "select * from default.t_rap order by id;" | $BENDSQL_CLIENT_CONNECT
+echo "delete from default.t_rap;" | $TEST_USER_CONNECT
+echo "select * from default.t_rap order by id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== merge into ==="
+echo "insert into default.t_rap values (5, 'eu');" | $BENDSQL_CLIENT_CONNECT
+echo "set enable_experimental_merge_into = 1; merge into default.t_rap using default.t_rap_src as s on t_rap.id = s.id when matched then update set region = s.region when not matched then insert *;" | $TEST_USER_CONNECT
+echo "select * from default.t_rap order by id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== policy administrator ==="
+echo "grant apply row access policy on *.* to 'test-user';" | $BENDSQL_CLIENT_CONNECT
+sleep 1;
+echo "select * from default.t_rap order by id;" | $TEST_USER_CONNECT
+echo "set enable_experimental_merge_into = 1; merge into default.t_rap using default.t_rap_src as s on t_rap.id = s.id when matched then update set region = s.region when not matched then insert *;" | $TEST_USER_CONNECT
+echo "select * from default.t_rap order by id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_rap;" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_rap_src;" | $BENDSQL_CLIENT_CONNECT
+echo "drop row access policy if exists rap_region;" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser18_3 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset user, roles, and tables'
+echo "DROP USER IF EXISTS 'testuser18_3'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'analyst'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'auditor'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'manager'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE IF EXISTS default.t18_0003" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user, roles, and tables for tests'
+echo "CREATE USER 'testuser18_3' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo 'CREATE ROLE `analyst`' | $BENDSQL_CLIENT_CONNECT
+echo 'CREATE ROLE `auditor`' | $BENDSQL_CLIENT_CONNECT
+echo 'CREATE ROLE `manager`' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE analyst to ROLE manager' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE analyst to testuser18_3' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE auditor to testuser18_3' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE manager to testuser18_3' | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE default.t18_0003(id int, owner string)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO default.t18_0003 VALUES (1, 'testuser18_3'), (2, 'someone')" | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT SELECT ON default.t18_0003 TO ROLE public' | $BENDSQL_CLIENT_CONNECT
+
+echo '-- test 1: invoker_user() and current_role()'
+echo "SET ROLE analyst; SELECT invoker_user(), current_role();" | $TEST_USER_CONNECT
+echo "SELECT id FROM default.t18_0003 WHERE owner = invoker_user();" | $TEST_USER_CONNECT
+
+echo '-- test 2: secondary roles as NONE, only the current role and its related roles are in session'
+echo "SET ROLE analyst; SET SECONDARY ROLES NONE; SELECT is_role_in_session('analyst'), is_role_in_session('auditor'), is_role_in_session('public');" | $TEST_USER_CONNECT
+echo "SET ROLE manager; SET SECONDARY ROLES NONE; SELECT is_role_in_session('analyst'), is_role_in_session('auditor');" | $TEST_USER_CONNECT
+
+echo '-- test 3: secondary roles as ALL, all the granted roles are in session'
+echo "SET ROLE analyst; SET SECONDARY ROLES ALL; SELECT is_role_in_session('analyst'), is_role_in_session('auditor'), is_role_in_session('manager');" | $TEST_USER_CONNECT
+
+echo '-- test 4: argument must be a constant string'
+echo "SELECT is_role_in_session(owner) FROM default.t18_0003;" | $TEST_USER_CONNECT
+
+echo '-- test 5: role-based conditional expressions'
+echo "SET ROLE analyst; SET SECONDARY ROLES NONE; SELECT id, IF(is_role_in_session('auditor'), owner, '***') FROM default.t18_0003 ORDER BY id;" | $TEST_USER_CONNECT
+echo "SET ROLE auditor; SET SECONDARY ROLES NONE; SELECT id, IF(is_role_in_session('auditor'), owner, '***') FROM default.t18_0003 ORDER BY id;" | $TEST_USER_CONNECT
+
+echo '-- test 6: changing roles in the session does not hit the cached result of other roles'
+echo "SET enable_query_result_cache = 1; SET ROLE analyst; SET SECONDARY ROLES NONE; SELECT id, is_role_in_session('auditor') FROM default.t18_0003 ORDER BY id; SET ROLE auditor; SELECT id, is_role_in_session('auditor') FROM default.t18_0003 ORDER BY id; SET SECONDARY ROLES ALL; SET ROLE analyst; SELECT id, is_role_in_session('auditor') FROM default.t18_0003 ORDER BY id;" | $TEST_USER_CONNECT
+
+echo "DROP USER IF EXISTS 'testuser18_3'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'analyst'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'auditor'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'manager'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE IF EXISTS default.t18_0003" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,61 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export ANALYST_CONNECT="bendsql --user=u18_0004_analyst --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+export CREATOR_CONNECT="bendsql --user=u18_0004_creator --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+## cleanup
+echo "drop database if exists d18_0004" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database d18_0004" | $BENDSQL_CLIENT_CONNECT
+echo "create table d18_0004.t1(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into d18_0004.t1 values(1)" | $BENDSQL_CLIENT_CONNECT
+
+echo "create role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "create role r18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+echo "create user u18_0004_analyst identified by 'password' with DEFAULT_ROLE='r18_0004_analyst'" | $BENDSQL_CLIENT_CONNECT
+echo "create user u18_0004_creator identified by 'password' with DEFAULT_ROLE='r18_0004_creator'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r18_0004_analyst to u18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r18_0004_creator to u18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+echo "grant create on d18_0004.* to role r18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- future tables grant"
+echo "grant select on future tables in database d18_0004 to role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- tables of the database are readable, whenever they are created"
+echo "create table d18_0004.t2(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into d18_0004.t2 values(2)" | $BENDSQL_CLIENT_CONNECT
+echo "select * from d18_0004.t1" | $ANALYST_CONNECT
+echo "select * from d18_0004.t2" | $ANALYST_CONNECT
+echo "show tables from d18_0004" | $ANALYST_CONNECT
+echo "insert into d18_0004.t2 values(3)" | $ANALYST_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "-- ownership of future tables can not be granted"
+echo "grant ownership on future tables in database d18_0004 to role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "Illegal GRANT/REVOKE"
+
+echo "-- the creating role owns the new table, future grants only add privileges to other roles"
+echo "create table d18_0004.t3(c int)" | $CREATOR_CONNECT
+echo "insert into d18_0004.t3 values(4)" | $CREATOR_CONNECT
+echo "select owner from system.tables where database = 'd18_0004' and name = 't3'" | $BENDSQL_CLIENT_CONNECT
+echo "select * from d18_0004.t3" | $ANALYST_CONNECT
+echo "insert into d18_0004.t3 values(5)" | $ANALYST_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "-- revoke"
+echo "revoke select on future tables in database d18_0004 from role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "select * from d18_0004.t2" | $ANALYST_CONNECT 2>&1 | grep -c "Permission denied"
+echo "select * from d18_0004.t3" | $CREATOR_CONNECT
+
+## cleanup
+echo "drop database d18_0004" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u18_0004_creator" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0004_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0004_creator" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,52 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export USER_A_CONNECT="bendsql --user=u18_0005_a --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+export USER_B_CONNECT="bendsql --user=u18_0005_b --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+## cleanup
+echo "drop database if exists d18_0005" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u18_0005_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u18_0005_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0005_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0005_b" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database d18_0005" | $BENDSQL_CLIENT_CONNECT
+echo "create role r18_0005_a" | $BENDSQL_CLIENT_CONNECT
+echo "create role r18_0005_b" | $BENDSQL_CLIENT_CONNECT
+echo "create user u18_0005_a identified by 'password' with DEFAULT_ROLE='r18_0005_a'" | $BENDSQL_CLIENT_CONNECT
+echo "create user u18_0005_b identified by 'password' with DEFAULT_ROLE='r18_0005_b'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r18_0005_a to u18_0005_a" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r18_0005_b to u18_0005_b" | $BENDSQL_CLIENT_CONNECT
+echo "grant create on d18_0005.* to role r18_0005_a" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- the creating role owns the table"
+echo "create table d18_0005.t1(c int)" | $USER_A_CONNECT
+echo "insert into d18_0005.t1 values(1)" | $USER_A_CONNECT
+echo "select owner from system.tables where database = 'd18_0005' and name = 't1'" | $BENDSQL_CLIENT_CONNECT
+echo "drop table d18_0005.t1" | $USER_B_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "-- the owner could grant privileges on the table"
+echo "grant select on d18_0005.t1 to role r18_0005_b" | $USER_A_CONNECT
+echo "select * from d18_0005.t1" | $USER_B_CONNECT
+
+echo "-- hand the table over to another role"
+echo "grant ownership on table d18_0005.t1 to role r18_0005_b revoke current grants" | $USER_A_CONNECT
+echo "select owner from system.tables where database = 'd18_0005' and name = 't1'" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role r18_0005_b" | $BENDSQL_CLIENT_CONNECT | grep -c "t1"
+echo "insert into d18_0005.t1 values(2)" | $USER_B_CONNECT
+echo "select * from d18_0005.t1 order by c" | $USER_B_CONNECT
+echo "insert into d18_0005.t1 values(3)" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "grant ownership on table d18_0005.t1 to role r18_0005_a" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "-- the objects of a dropped role are owned by account_admin"
+echo "drop role r18_0005_b" | $BENDSQL_CLIENT_CONNECT
+echo "select owner from system.tables where database = 'd18_0005' and name = 't1'" | $BENDSQL_CLIENT_CONNECT
+
+## cleanup
+echo "drop database d18_0005" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u18_0005_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u18_0005_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0005_a" | $BENDSQL_CLIENT_CONNECT
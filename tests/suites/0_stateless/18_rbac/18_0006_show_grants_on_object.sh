@@ -0,0 +1,57 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export USER_CONNECT="bendsql --user=u18_0006 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+## cleanup
+echo "drop database if exists d18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage if exists s18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop function if exists f18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0006_writer" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r18_0006_future" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database d18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "create table d18_0006.t1(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "create stage s18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "create function f18_0006 as (p) -> (p)" | $BENDSQL_CLIENT_CONNECT
+
+echo "create role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "create role r18_0006_writer" | $BENDSQL_CLIENT_CONNECT
+echo "create role r18_0006_future" | $BENDSQL_CLIENT_CONNECT
+echo "create user u18_0006 identified by 'password'" | $BENDSQL_CLIENT_CONNECT
+echo "grant select on d18_0006.t1 to role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "grant read on stage s18_0006 to role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "grant usage on udf f18_0006 to role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "grant insert, grant on d18_0006.* to role r18_0006_writer" | $BENDSQL_CLIENT_CONNECT
+echo "grant update on future tables in database d18_0006 to role r18_0006_future" | $BENDSQL_CLIENT_CONNECT
+echo "grant delete on d18_0006.t1 to u18_0006" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- the table inherits the grants on its database and the future tables"
+echo "show grants on table d18_0006.t1" | $BENDSQL_CLIENT_CONNECT | grep "18_0006" | sort
+echo "-- the database does not inherit the grants on its tables"
+echo "show grants on database d18_0006" | $BENDSQL_CLIENT_CONNECT | grep "18_0006" | sort
+echo "-- stage and udf"
+echo "show grants on stage s18_0006" | $BENDSQL_CLIENT_CONNECT | grep "18_0006" | sort
+echo "show grants on udf f18_0006" | $BENDSQL_CLIENT_CONNECT | grep "18_0006" | sort
+echo "-- the owner role"
+echo "show grants on table d18_0006.t1" | $BENDSQL_CLIENT_CONNECT | grep "OWNERSHIP"
+
+echo "-- revoked grants are not listed"
+echo "revoke select on d18_0006.t1 from role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "show grants on table d18_0006.t1" | $BENDSQL_CLIENT_CONNECT | grep -c "r18_0006_reader"
+
+echo "-- the GRANT privilege on the object is required"
+echo "show grants on table d18_0006.t1" | $USER_CONNECT 2>&1 | grep -c "Permission denied"
+
+## cleanup
+echo "drop database d18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage s18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop function f18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u18_0006" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0006_reader" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0006_writer" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r18_0006_future" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP DATABASE IF EXISTS test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_explain_dryrun.t(a int, b int)" | $BENDSQL_CLIENT_CONNECT
+
+echo "EXPLAIN DRYRUN DELETE FROM test_explain_dryrun.t WHERE a = 1" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO test_explain_dryrun.t VALUES(1, 1), (2, 2)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_explain_dryrun.t VALUES(3, 3), (4, 4)" | $BENDSQL_CLIENT_CONNECT
+
+# the base snapshot changes from run to run
+echo "EXPLAIN DRYRUN DELETE FROM test_explain_dryrun.t" | $BENDSQL_CLIENT_CONNECT | grep -v "base snapshot"
+echo "EXPLAIN DRYRUN DELETE FROM test_explain_dryrun.t WHERE a < 3" | $BENDSQL_CLIENT_CONNECT | grep -v "base snapshot"
+echo "EXPLAIN DRYRUN DELETE FROM test_explain_dryrun.t WHERE a = 1" | $BENDSQL_CLIENT_CONNECT | grep -v "base snapshot"
+echo "EXPLAIN DRYRUN UPDATE test_explain_dryrun.t SET b = 0 WHERE a > 100" | $BENDSQL_CLIENT_CONNECT | grep -v "base snapshot"
+echo "EXPLAIN DRYRUN UPDATE test_explain_dryrun.t SET b = 0" | $BENDSQL_CLIENT_CONNECT | grep -v "base snapshot"
+
+# nothing is changed
+echo "SELECT count(*) FROM test_explain_dryrun.t" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM test_explain_dryrun.t WHERE b = 0" | $BENDSQL_CLIENT_CONNECT
+
+echo "DROP STAGE IF EXISTS test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE STAGE test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
+echo "COPY INTO @test_explain_dryrun FROM test_explain_dryrun.t FILE_FORMAT = (type = CSV)" | $BENDSQL_CLIENT_CONNECT > /dev/null
+echo "EXPLAIN DRYRUN COPY INTO test_explain_dryrun.t FROM @test_explain_dryrun FILE_FORMAT = (type = CSV)" | $BENDSQL_CLIENT_CONNECT | grep "files"
+echo "COPY INTO test_explain_dryrun.t FROM @test_explain_dryrun FILE_FORMAT = (type = CSV)" | $BENDSQL_CLIENT_CONNECT > /dev/null
+echo "EXPLAIN DRYRUN COPY INTO test_explain_dryrun.t FROM @test_explain_dryrun FILE_FORMAT = (type = CSV)" | $BENDSQL_CLIENT_CONNECT | grep "files"
+
+echo "EXPLAIN DRYRUN SELECT * FROM test_explain_dryrun.t" | $BENDSQL_CLIENT_CONNECT
+
+echo "DROP STAGE IF EXISTS test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS test_explain_dryrun" | $BENDSQL_CLIENT_CONNECT
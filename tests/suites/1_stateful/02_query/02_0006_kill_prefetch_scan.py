@@ -0,0 +1,68 @@
+#!/usr/bin/env python3
+
+import threading
+import time
+import mysql.connector
+
+# client1 scans a table on the object storage with the prefetch of block data, client2 kills
+# the scan in the middle. The prefetches of the killed query must not keep it running.
+
+
+def connect():
+    return mysql.connector.connect(
+        host="127.0.0.1", user="root", passwd="root", port="3307"
+    )
+
+
+mydb = connect()
+mycursor = mydb.cursor()
+mycursor.execute("DROP TABLE IF EXISTS t_kill_prefetch_scan;")
+mycursor.execute("CREATE TABLE t_kill_prefetch_scan(a INT) row_per_block = 1;")
+mycursor.execute(
+    "INSERT INTO t_kill_prefetch_scan SELECT number FROM numbers(200);"
+)
+
+scan_error = []
+
+
+def scan():
+    client1 = connect()
+    cursor = client1.cursor()
+    cursor.execute("SET storage_prefetch_part_num = 4;")
+    cursor.execute("SET max_threads = 1;")
+    try:
+        # Sleeps for each block, the scan takes about 100 seconds.
+        cursor.execute(
+            "SELECT count(*) FROM t_kill_prefetch_scan WHERE sleep(0.5) = 0;"
+        )
+        cursor.fetchall()
+    except mysql.connector.Error as err:
+        scan_error.append(err)
+    client1.close()
+
+
+scan_thread = threading.Thread(target=scan)
+scan_thread.start()
+time.sleep(2)
+
+mycursor.execute(
+    "SELECT mysql_connection_id FROM system.processes WHERE extra_info LIKE '%FROM t_kill_prefetch_scan WHERE%' AND extra_info NOT LIKE '%system.processes%';"
+)
+res = mycursor.fetchone()
+mycursor.execute("kill query " + str(res[0]) + ";")
+
+scan_thread.join(30)
+print("scan stopped:", not scan_thread.is_alive())
+print("scan aborted:", len(scan_error) == 1)
+
+mycursor.execute(
+    "SELECT * FROM system.processes WHERE extra_info LIKE '%FROM t_kill_prefetch_scan WHERE%' AND extra_info NOT LIKE '%system.processes%';"
+)
+print("scan removed:", mycursor.fetchone() is None)
+
+# The table is still readable with the prefetch of block data.
+mycursor.execute("SET storage_prefetch_part_num = 4;")
+mycursor.execute("SELECT count(*), sum(a) FROM t_kill_prefetch_scan;")
+print(mycursor.fetchone())
+
+mycursor.execute("DROP TABLE t_kill_prefetch_scan;")
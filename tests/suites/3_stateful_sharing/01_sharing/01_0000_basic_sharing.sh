@@ -65,7 +65,7 @@ echo "SELECT * FROM shared_db.t1" | $MYSQL_CLIENT_SHARE_3_CONNECT
 echo "drop shared database and query data from share"
 echo "SELECT * FROM shared_db.t2" | $MYSQL_CLIENT_SHARE_2_CONNECT
 echo "SELECT * FROM shared_db.t2" | $MYSQL_CLIENT_SHARE_3_CONNECT
-echo "drop database if exists test_database" | $MYSQL_CLIENT_SHARE_1_CONNECT
+echo "drop database if exists test_database cascade" | $MYSQL_CLIENT_SHARE_1_CONNECT
 echo "SELECT * FROM shared_db.t2" | $MYSQL_CLIENT_SHARE_2_CONNECT
 echo "SELECT * FROM shared_db.t2" | $MYSQL_CLIENT_SHARE_3_CONNECT
 
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop database if exists test_show_tables_lazy;" | $BENDSQL_CLIENT_CONNECT
+echo "create database test_show_tables_lazy;" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table test_show_tables_lazy.t(a int) 's3://testbucket/admin/data/' connection=(access_key_id ='minioadmin' secret_access_key ='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $BENDSQL_CLIENT_CONNECT
+echo "insert into test_show_tables_lazy.t values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+storage_prefix=$(mysql -uroot -h127.0.0.1 -P3307  -e "set global hide_options_in_show_create_table=0;show create table test_show_tables_lazy.t" | grep -i snapshot_location | awk -F'SNAPSHOT_LOCATION='"'"'|_ss' '{print $2}')
+echo "attach table test_show_tables_lazy.t_read_only 's3://testbucket/admin/data/$storage_prefix' connection=(access_key_id ='minioadmin' secret_access_key ='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}') READ_ONLY;" | $BENDSQL_CLIENT_CONNECT
+
+echo "statistics of read only attached table are read from the storage"
+echo "select name, num_rows from system.tables where database = 'test_show_tables_lazy' order by name" | $BENDSQL_CLIENT_CONNECT
+
+# remove the snapshot hint, any read of the statistics of the attached table fails from now on
+echo "create stage test_show_tables_lazy_stage url='s3://testbucket/admin/data/$storage_prefix' connection=(access_key_id ='minioadmin' secret_access_key ='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $BENDSQL_CLIENT_CONNECT
+echo "remove @test_show_tables_lazy_stage pattern = 'last_snapshot_location_hint'" | $BENDSQL_CLIENT_CONNECT
+
+echo "show tables should not read the storage"
+echo "show tables from test_show_tables_lazy" | $BENDSQL_CLIENT_CONNECT
+echo "select name, engine from system.tables where database = 'test_show_tables_lazy' order by name" | $BENDSQL_CLIENT_CONNECT
+
+echo "reading statistics should fail"
+echo "select name, num_rows from system.tables where database = 'test_show_tables_lazy'" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "^Error"
+
+echo "drop stage test_show_tables_lazy_stage" | $BENDSQL_CLIENT_CONNECT
+echo "drop database test_show_tables_lazy" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop database if exists db_stream_base cascade" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_stream_other" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database db_stream_base" | $BENDSQL_CLIENT_CONNECT
+echo "create database db_stream_other" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_stream_base.t(a int) change_tracking = true" | $BENDSQL_CLIENT_CONNECT
+echo "create stream db_stream_base.s_local on table db_stream_base.t" | $BENDSQL_CLIENT_CONNECT
+echo "create stream db_stream_other.s_remote on table db_stream_base.t" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- the stream in another database blocks the drop"
+echo "drop database db_stream_base" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "stream 'db_stream_other'.'s_remote'"
+echo "drop database db_stream_base" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "s_local"
+echo "select name from system.streams where database = 'db_stream_other'" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from db_stream_base.t" | $BENDSQL_CLIENT_CONNECT
+
+echo "-- cascade drops the dependent streams"
+echo "drop database db_stream_base cascade" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from system.streams where database = 'db_stream_other'" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from system.databases where name = 'db_stream_base'" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database if exists db_stream_other" | $BENDSQL_CLIENT_CONNECT